@@ -0,0 +1,8 @@
+(function () {
+  let sum = 0;
+  for (let i = 1; i <= 10000000; i++) {
+    sum = sum + i;
+  }
+
+  return sum;
+})();
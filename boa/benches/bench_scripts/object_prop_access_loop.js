@@ -0,0 +1,13 @@
+(function () {
+  let test = {
+    x: 1,
+    y: 2,
+  };
+
+  let sum = 0;
+  for (let i = 0; i < 1000000; i++) {
+    sum += test.x;
+  }
+
+  return sum;
+})();
@@ -1,6 +1,6 @@
 //! Benchmarks of the whole execution engine in Boa.
 
-use boa::{exec::Executable, realm::Realm, syntax::Parser, Context};
+use boa::{exec::Executable, realm::Realm, syntax::Parser, Context, Intrinsics};
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 
 #[cfg(all(target_arch = "x86_64", target_os = "linux", target_env = "gnu"))]
@@ -14,6 +14,18 @@ fn create_realm(c: &mut Criterion) {
     c.bench_function("Create Realm", move |b| b.iter(Realm::create));
 }
 
+fn create_context(c: &mut Criterion) {
+    c.bench_function("Create Context", move |b| b.iter(Context::new));
+}
+
+fn create_context_from_intrinsics(c: &mut Criterion) {
+    let intrinsics = Intrinsics::new();
+
+    c.bench_function("Create Context (from Intrinsics)", move |b| {
+        b.iter(|| Context::from_intrinsics(black_box(&intrinsics)))
+    });
+}
+
 static SYMBOL_CREATION: &str = include_str!("bench_scripts/symbol_creation.js");
 
 fn symbol_creation(c: &mut Criterion) {
@@ -92,6 +104,22 @@ fn object_prop_access_const(c: &mut Criterion) {
     });
 }
 
+static OBJECT_PROP_ACCESS_LOOP: &str = include_str!("bench_scripts/object_prop_access_loop.js");
+
+fn object_prop_access_loop(c: &mut Criterion) {
+    let mut context = Context::new();
+
+    // Parse the AST nodes.
+    let nodes = Parser::new(OBJECT_PROP_ACCESS_LOOP.as_bytes(), false)
+        .parse_all()
+        .unwrap();
+
+    // Execute the parsed nodes, passing them through a black box, to avoid over-optimizing by the compiler
+    c.bench_function("Repeated Object Property Access (Execution)", move |b| {
+        b.iter(|| black_box(&nodes).run(&mut context).unwrap())
+    });
+}
+
 static OBJECT_PROP_ACCESS_DYN: &str = include_str!("bench_scripts/object_prop_access_dyn.js");
 
 fn object_prop_access_dyn(c: &mut Criterion) {
@@ -310,6 +338,24 @@ fn arithmetic_operations(c: &mut Criterion) {
     });
 }
 
+static INTEGER_SUM: &str = include_str!("bench_scripts/integer_sum.js");
+
+fn integer_sum(c: &mut Criterion) {
+    let mut context = Context::new();
+
+    let nodes = Parser::new(INTEGER_SUM.as_bytes(), false)
+        .parse_all()
+        .unwrap();
+
+    // Every run sums ten million integers, so less samples are taken than by default.
+    let mut group = c.benchmark_group("Integer sum");
+    group.sample_size(10);
+    group.bench_function("Integer sum (Execution)", move |b| {
+        b.iter(|| black_box(&nodes).run(&mut context).unwrap())
+    });
+    group.finish();
+}
+
 static CLEAN_JS: &str = include_str!("bench_scripts/clean_js.js");
 
 fn clean_js(c: &mut Criterion) {
@@ -333,6 +379,8 @@ fn mini_js(c: &mut Criterion) {
 criterion_group!(
     execution,
     create_realm,
+    create_context,
+    create_context_from_intrinsics,
     symbol_creation,
     for_loop_execution,
     fibonacci,
@@ -341,6 +389,7 @@ criterion_group!(
     array_pop,
     object_creation,
     object_prop_access_const,
+    object_prop_access_loop,
     object_prop_access_dyn,
     regexp_literal_creation,
     regexp_creation,
@@ -353,6 +402,7 @@ criterion_group!(
     boolean_object_access,
     string_object_access,
     arithmetic_operations,
+    integer_sum,
     clean_js,
     mini_js,
 );
@@ -37,6 +37,14 @@ fn fibonacci(c: &mut Criterion) {
     });
 }
 
+fn fibonacci_precompiled(c: &mut Criterion) {
+    // Execute the code by taking into account realm creation, but not lexing and parsing
+    let script = Context::new().compile(FIBONACCI).unwrap();
+    c.bench_function("Fibonacci (Precompiled)", move |b| {
+        b.iter(|| Context::new().execute(black_box(&script)))
+    });
+}
+
 static OBJECT_CREATION: &str = include_str!("bench_scripts/object_creation.js");
 
 fn object_creation(c: &mut Criterion) {
@@ -201,6 +209,7 @@ criterion_group!(
     symbol_creation,
     for_loop,
     fibonacci,
+    fibonacci_precompiled,
     array_access,
     array_creation,
     array_pop,
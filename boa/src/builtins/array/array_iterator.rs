@@ -23,7 +23,7 @@ pub enum ArrayIterationKind {
 #[derive(Debug, Clone, Finalize, Trace)]
 pub struct ArrayIterator {
     array: Value,
-    next_index: u32,
+    next_index: usize,
     kind: ArrayIterationKind,
 }
 
@@ -69,47 +69,53 @@ impl ArrayIterator {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-%arrayiteratorprototype%.next
     pub(crate) fn next(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
-        if let Value::Object(ref object) = this {
-            let mut object = object.borrow_mut();
-            if let Some(array_iterator) = object.as_array_iterator_mut() {
-                let index = array_iterator.next_index;
-                if array_iterator.array.is_undefined() {
-                    return Ok(create_iter_result_object(context, Value::undefined(), true));
-                }
-                let len = array_iterator
-                    .array
-                    .get_field("length", context)?
-                    .as_number()
-                    .ok_or_else(|| context.construct_type_error("Not an array"))?
-                    as u32;
-                if array_iterator.next_index >= len {
-                    array_iterator.array = Value::undefined();
-                    return Ok(create_iter_result_object(context, Value::undefined(), true));
-                }
-                array_iterator.next_index = index + 1;
-                match array_iterator.kind {
-                    ArrayIterationKind::Key => {
-                        Ok(create_iter_result_object(context, index.into(), false))
-                    }
-                    ArrayIterationKind::Value => {
-                        let element_value = array_iterator.array.get_field(index, context)?;
-                        Ok(create_iter_result_object(context, element_value, false))
-                    }
-                    ArrayIterationKind::KeyAndValue => {
-                        let element_value = array_iterator.array.get_field(index, context)?;
-                        let result = Array::constructor(
-                            &Value::new_object(context),
-                            &[index.into(), element_value],
-                            context,
-                        )?;
-                        Ok(create_iter_result_object(context, result, false))
-                    }
-                }
-            } else {
-                context.throw_type_error("`this` is not an ArrayIterator")
+        // The iterator isn't borrowed while the array is accessed, since the getters of the
+        // array can use the iterator as well.
+        let (object, array, index, kind) = match this {
+            Value::Object(ref object) => match object.borrow().as_array_iterator() {
+                Some(iterator) => (
+                    object.clone(),
+                    iterator.array.clone(),
+                    iterator.next_index,
+                    iterator.kind.clone(),
+                ),
+                None => return context.throw_type_error("`this` is not an ArrayIterator"),
+            },
+            _ => return context.throw_type_error("`this` is not an ArrayIterator"),
+        };
+
+        if array.is_undefined() {
+            return Ok(create_iter_result_object(context, Value::undefined(), true));
+        }
+
+        // The length is read again for every element, so that the iteration sees the elements
+        // added or removed by the previous iterations.
+        let len = array.get_field("length", context)?.to_length(context)?;
+        if index >= len {
+            if let Some(iterator) = object.borrow_mut().as_array_iterator_mut() {
+                iterator.array = Value::undefined();
+            }
+            return Ok(create_iter_result_object(context, Value::undefined(), true));
+        }
+        if let Some(iterator) = object.borrow_mut().as_array_iterator_mut() {
+            iterator.next_index = index + 1;
+        }
+
+        match kind {
+            ArrayIterationKind::Key => Ok(create_iter_result_object(context, index.into(), false)),
+            ArrayIterationKind::Value => {
+                let element_value = array.get_field(index, context)?;
+                Ok(create_iter_result_object(context, element_value, false))
+            }
+            ArrayIterationKind::KeyAndValue => {
+                let element_value = array.get_field(index, context)?;
+                let result = Array::construct_array(
+                    &Array::new_array(context),
+                    &[index.into(), element_value],
+                    context,
+                )?;
+                Ok(create_iter_result_object(context, result, false))
             }
-        } else {
-            context.throw_type_error("`this` is not an ArrayIterator")
         }
     }
 
@@ -15,6 +15,7 @@ mod tests;
 
 use crate::{
     builtins::array::array_iterator::{ArrayIterationKind, ArrayIterator},
+    builtins::iterable::IteratorRecord,
     builtins::BuiltIn,
     builtins::Number,
     object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData, PROTOTYPE},
@@ -25,7 +26,7 @@ use crate::{
 };
 use num_traits::*;
 use std::{
-    cmp::{max, min},
+    cmp::{max, min, Ordering},
     convert::{TryFrom, TryInto},
 };
 
@@ -86,11 +87,12 @@ impl BuiltIn for Array {
             values_function,
             Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
         )
+        .method(Self::at, "at", 1)
         .method(Self::concat, "concat", 1)
         .method(Self::push, "push", 1)
         .method(Self::index_of, "indexOf", 1)
         .method(Self::last_index_of, "lastIndexOf", 1)
-        .method(Self::includes_value, "includes", 1)
+        .method(Self::includes, "includes", 1)
         .method(Self::map, "map", 1)
         .method(Self::fill, "fill", 1)
         .method(Self::for_each, "forEach", 1)
@@ -99,14 +101,18 @@ impl BuiltIn for Array {
         .method(Self::join, "join", 1)
         .method(Self::to_string, "toString", 0)
         .method(Self::reverse, "reverse", 0)
+        .method(Self::sort, "sort", 1)
         .method(Self::shift, "shift", 0)
         .method(Self::unshift, "unshift", 1)
         .method(Self::every, "every", 1)
         .method(Self::find, "find", 1)
         .method(Self::find_index, "findIndex", 1)
+        .method(Self::find_last, "findLast", 1)
+        .method(Self::find_last_index, "findLastIndex", 1)
         .method(Self::flat, "flat", 0)
         .method(Self::flat_map, "flatMap", 1)
         .method(Self::slice, "slice", 2)
+        .method(Self::splice, "splice", 2)
         .method(Self::some, "some", 2)
         .method(Self::reduce, "reduce", 2)
         .method(Self::reduce_right, "reduceRight", 2)
@@ -114,10 +120,19 @@ impl BuiltIn for Array {
         .method(Self::entries, "entries", 0)
         .method(Self::copy_within, "copyWithin", 3)
         // Static Methods
+        .static_method(Self::from, "from", 1)
         .static_method(Self::is_array, "isArray", 1)
         .static_method(Self::of, "of", 0)
         .build();
 
+        // The prototype is itself an array, so `Array.isArray(Array.prototype)` is `true`.
+        context
+            .standard_objects()
+            .array_object()
+            .prototype()
+            .borrow_mut()
+            .data = ObjectData::Array;
+
         (Self::NAME, array.into(), Self::attribute())
     }
 }
@@ -246,6 +261,32 @@ impl Array {
         array
     }
 
+    /// `CreateArrayFromList ( elements )`
+    ///
+    /// Creates a new `Array` instance holding the given elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createarrayfromlist
+    pub(crate) fn create_array_from_list<I>(elements: I, context: &Context) -> Value
+    where
+        I: IntoIterator<Item = Value>,
+    {
+        let array = Self::new_array(context);
+        let mut length = 0;
+        for (index, element) in elements.into_iter().enumerate() {
+            array.set_property(index, DataDescriptor::new(element, Attribute::all()));
+            length = index + 1;
+        }
+        let length = DataDescriptor::new(
+            length,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+        array.set_property("length", length);
+        array
+    }
+
     /// Utility function for creating array objects.
     ///
     /// `array_obj` can be any array with prototype already set (it will be wiped and
@@ -300,7 +341,7 @@ impl Array {
         length: u32,
         context: &mut Context,
     ) -> Result<Value> {
-        if !original_array.is_array() {
+        if !original_array.is_array_abstract() {
             return Ok(Self::array_create(length, None, context));
         }
         let c = original_array.get(
@@ -363,7 +404,8 @@ impl Array {
     /// `Array.isArray( arg )`
     ///
     /// The isArray function takes one argument arg, and returns the Boolean value true
-    /// if the argument is an object whose class internal property is "Array"; otherwise it returns false.
+    /// if the argument is an Array object, or a Proxy whose target is an Array object;
+    /// otherwise it returns false.
     ///
     /// More information:
     ///  - [ECMAScript reference][spec]
@@ -373,7 +415,7 @@ impl Array {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/isArray
     pub(crate) fn is_array(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
         match args.get(0).and_then(|x| x.as_object()) {
-            Some(object) => Ok(Value::from(object.borrow().is_array())),
+            Some(object) => Ok(Value::from(object.is_array_abstract())),
             None => Ok(Value::from(false)),
         }
     }
@@ -390,24 +432,174 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.of
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/of
     pub(crate) fn of(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let len be the number of elements in items.
+        let len = args.len();
+
+        // 4. If IsConstructor(C) is true, then let A be ? Construct(C, « 𝔽(len) »).
+        // 5. Else, let A be ? ArrayCreate(len).
         let array = match this.as_object() {
-            Some(object) if object.is_constructable() => {
-                object.construct(&[args.len().into()], this, context)?
+            Some(constructor) if constructor.is_constructable() => {
+                constructor.construct(&[len.into()], this, context)?
             }
-            _ => Array::array_create(args.len() as u32, None, context),
+            _ => Self::array_create(len as u32, None, context),
         };
-
-        // add properties
-        for (i, value) in args.iter().enumerate() {
-            array.set_property(i, DataDescriptor::new(value, Attribute::all()));
+        let mut array = array
+            .as_object()
+            .expect("Array.of: the constructor returned a non-object");
+
+        // 6. Let k be 0.
+        // 7. Repeat, while k < len,
+        for (k, value) in args.iter().enumerate() {
+            // c. Perform ? CreateDataPropertyOrThrow(A, Pk, kValue).
+            array.define_property_or_throw(
+                k,
+                DataDescriptor::new(value.clone(), Attribute::all()),
+                context,
+            )?;
         }
 
-        // set length
-        array.set_field("length", args.len(), true, context)?;
+        // 8. Perform ? Set(A, "length", len, true).
+        let array = Value::from(array);
+        array.set_field("length", len, true, context)?;
 
+        // 9. Return A.
         Ok(array)
     }
 
+    /// `Array.from(items[, mapFn[, thisArg]])`
+    ///
+    /// The Array.from method creates a new Array instance from an iterable or an array-like
+    /// object, calling `mapFn` with each value and its index if it is given.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/from
+    pub(crate) fn from(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let items = args.get(0).cloned().unwrap_or_default();
+        let map_fn = args.get(1).cloned().unwrap_or_default();
+        let this_arg = args.get(2).cloned().unwrap_or_default();
+
+        // 2. If mapfn is undefined, let mapping be false.
+        // 3. Else, if IsCallable(mapfn) is false, throw a TypeError exception.
+        let mapping = match map_fn {
+            Value::Undefined => false,
+            Value::Object(ref object) if object.is_callable() => true,
+            _ => return context.throw_type_error("Array.from: mapFn is not callable"),
+        };
+        let constructor = this
+            .as_object()
+            .filter(|constructor| constructor.is_constructable());
+
+        // 4. Let usingIterator be ? GetMethod(items, @@iterator).
+        let array_like = items.to_object(context)?;
+        let using_iterator = array_like.get_method(context, WellKnownSymbols::iterator())?;
+
+        // 5. If usingIterator is not undefined, then
+        if let Some(using_iterator) = using_iterator {
+            // a. If IsConstructor(C) is true, then let A be ? Construct(C).
+            // b. Else, let A be ! ArrayCreate(0).
+            let array = match constructor {
+                Some(ref constructor) => constructor.construct(&[], this, context)?,
+                None => Self::array_create(0, None, context),
+            };
+            let mut array = array
+                .as_object()
+                .expect("Array.from: the constructor returned a non-object");
+
+            // c. Let iteratorRecord be ? GetIterator(items, sync, usingIterator).
+            let iterator_object = using_iterator.call(&items, &[], context)?;
+            let next_function = iterator_object.get_field("next", context)?;
+            let iterator = IteratorRecord::new(iterator_object, next_function);
+
+            // d. Let k be 0.
+            // e. Repeat,
+            for k in 0_usize.. {
+                // iii. Let next be ? IteratorStep(iteratorRecord).
+                let next = iterator.next(context)?;
+
+                // iv. If next is false, then
+                if next.is_done() {
+                    // 1. Perform ? Set(A, "length", 𝔽(k), true).
+                    array.set("length".into(), k.into(), array.clone().into(), context)?;
+                    // 2. Return A.
+                    return Ok(array.into());
+                }
+
+                // vi. If mapping is true, then
+                //     1. Let mappedValue be Call(mapfn, thisArg, « nextValue, 𝔽(k) »).
+                //     2. IfAbruptCloseIterator(mappedValue, iteratorRecord).
+                let value = next.value();
+                let mapped_value = if mapping {
+                    match context.call(&map_fn, &this_arg, &[value, k.into()]) {
+                        Ok(mapped_value) => mapped_value,
+                        Err(error) => return iterator.close(Err(error), context),
+                    }
+                } else {
+                    value
+                };
+
+                // viii. Let defineStatus be CreateDataPropertyOrThrow(A, Pk, mappedValue).
+                // ix. If defineStatus is an abrupt completion, return ? IteratorClose(iteratorRecord, defineStatus).
+                if let Err(error) = array.define_property_or_throw(
+                    k,
+                    DataDescriptor::new(mapped_value, Attribute::all()),
+                    context,
+                ) {
+                    return iterator.close(Err(error), context);
+                }
+            }
+        }
+
+        // 6. NOTE: items is not an Iterable so assume it is an array-like object.
+        // 8. Let len be ? LengthOfArrayLike(arrayLike).
+        let len = array_like
+            .get(&"length".into(), array_like.clone().into(), context)?
+            .to_length(context)?;
+
+        // 9. If IsConstructor(C) is true, then let A be ? Construct(C, « 𝔽(len) »).
+        // 10. Else, let A be ? ArrayCreate(len).
+        let array = if let Some(ref constructor) = constructor {
+            constructor.construct(&[len.into()], this, context)?
+        } else {
+            let len = u32::try_from(len)
+                .map_err(|_| context.construct_range_error("Invalid array length"))?;
+            Self::array_create(len, None, context)
+        };
+        let mut array = array
+            .as_object()
+            .expect("Array.from: the constructor returned a non-object");
+
+        // 11. Let k be 0.
+        // 12. Repeat, while k < len,
+        for k in 0..len {
+            // b. Let kValue be ? Get(arrayLike, Pk).
+            let k_value = array_like.get(&k.into(), array_like.clone().into(), context)?;
+
+            // c. If mapping is true, then let mappedValue be ? Call(mapfn, thisArg, « kValue, 𝔽(k) »).
+            let mapped_value = if mapping {
+                context.call(&map_fn, &this_arg, &[k_value, k.into()])?
+            } else {
+                k_value
+            };
+
+            // e. Perform ? CreateDataPropertyOrThrow(A, Pk, mappedValue).
+            array.define_property_or_throw(
+                k,
+                DataDescriptor::new(mapped_value, Attribute::all()),
+                context,
+            )?;
+        }
+
+        // 13. Perform ? Set(A, "length", 𝔽(len), true).
+        array.set("length".into(), len.into(), array.clone().into(), context)?;
+
+        // 14. Return A.
+        Ok(array.into())
+    }
+
     /// `Array.prototype.concat(...arguments)`
     ///
     /// When the concat method is called with zero or more arguments, it returns an
@@ -436,17 +628,41 @@ impl Array {
         }
 
         for concat_array in args {
-            let concat_length = concat_array
-                .get_field("length", context)?
-                .to_length(context)?;
-            for n in 0..concat_length {
-                new_values.push(concat_array.get_field(n, context)?);
+            if Self::is_concat_spreadable(concat_array, context)? {
+                let concat_length = concat_array
+                    .get_field("length", context)?
+                    .to_length(context)?;
+                for n in 0..concat_length {
+                    new_values.push(concat_array.get_field(n, context)?);
+                }
+            } else {
+                new_values.push(concat_array.clone());
             }
         }
 
         Self::construct_array(this, &new_values, context)
     }
 
+    /// `IsConcatSpreadable ( O )`
+    ///
+    /// Checks if the elements of an argument of `concat` are added one by one.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isconcatspreadable
+    fn is_concat_spreadable(value: &Value, context: &mut Context) -> Result<bool> {
+        let object = match value.as_object() {
+            Some(object) => object,
+            None => return Ok(false),
+        };
+        let spreadable = value.get_field(WellKnownSymbols::is_concat_spreadable(), context)?;
+        if !spreadable.is_undefined() {
+            return Ok(spreadable.to_boolean());
+        }
+        Ok(object.is_array())
+    }
+
     /// `Array.prototype.push( ...items )`
     ///
     /// The arguments are appended to the end of the array, in the order in which
@@ -460,8 +676,31 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.push
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/push
     pub(crate) fn push(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let new_array = Self::add_to_array_object(this, args, context)?;
-        new_array.get_field("length", context)
+        // 1. Let O be ? ToObject(this value).
+        let this: Value = this.to_object(context)?.into();
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = this.get_field("length", context)?.to_length(context)?;
+
+        // 4. If len + argCount > 2^53 - 1, throw a TypeError exception.
+        if (len + args.len()) as f64 > Number::MAX_SAFE_INTEGER {
+            return context
+                .throw_type_error("Pushing the items would exceed the maximum array length");
+        }
+
+        // 5. For each element E of items, do
+        //   a. Perform ? Set(O, ! ToString(𝔽(len)), E, true).
+        //   b. Set len to len + 1.
+        for (n, item) in args.iter().enumerate() {
+            this.set_field(len + n, item.clone(), true, context)?;
+        }
+
+        // 6. Perform ? Set(O, "length", 𝔽(len), true).
+        let len = len + args.len();
+        this.set_field("length", len, true, context)?;
+
+        // 7. Return 𝔽(len).
+        Ok(len.into())
     }
 
     /// `Array.prototype.pop()`
@@ -475,16 +714,33 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.pop
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/pop
     pub(crate) fn pop(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
-        let curr_length = this.get_field("length", context)?.to_length(context)?;
+        // 1. Let O be ? ToObject(this value).
+        let mut o = this.to_object(context)?;
+        let this: Value = o.clone().into();
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = this.get_field("length", context)?.to_length(context)?;
 
-        if curr_length < 1 {
+        // 3. If len = 0, then
+        if len == 0 {
+            // a. Perform ? Set(O, "length", +0𝔽, true).
+            this.set_field("length", 0, true, context)?;
+            // b. Return undefined.
             return Ok(Value::undefined());
         }
-        let pop_index = curr_length.wrapping_sub(1);
-        let pop_value: Value = this.get_field(pop_index.to_string(), context)?;
-        this.remove_property(pop_index);
-        this.set_field("length", Value::from(pop_index), true, context)?;
-        Ok(pop_value)
+
+        // 4. Else,
+        //   a. Let newLen be 𝔽(len - 1).
+        //   b. Let index be ! ToString(newLen).
+        let index = len - 1;
+        //   c. Let element be ? Get(O, index).
+        let element = this.get_field(index, context)?;
+        //   d. Perform ? DeletePropertyOrThrow(O, index).
+        o.delete_property_or_throw(index, context)?;
+        //   e. Perform ? Set(O, "length", newLen, true).
+        this.set_field("length", index, true, context)?;
+        //   f. Return element.
+        Ok(element)
     }
 
     /// `Array.prototype.forEach( callbackFn [ , thisArg ] )`
@@ -498,22 +754,38 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.foreach
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/forEach
     pub(crate) fn for_each(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        if args.is_empty() {
-            return Err(Value::from("Missing argument for Array.prototype.forEach"));
-        }
-
-        let callback_arg = args.get(0).expect("Could not get `callbackFn` argument.");
-        let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
-
-        let length = this.get_field("length", context)?.to_length(context)?;
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
 
-        for i in 0..length {
-            let element = this.get_field(i, context)?;
-            let arguments = [element, Value::from(i), this.clone()];
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let length = o
+            .get(&"length".into(), Value::from(o.clone()), context)?
+            .to_length(context)?;
 
-            context.call(callback_arg, &this_arg, &arguments)?;
+        // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
+        let callback = args.get(0).cloned().unwrap_or_default();
+        if !callback.is_function() {
+            return context.throw_type_error("Array.prototype.forEach: callback is not callable");
+        }
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+
+        // 4. Let k be 0.
+        // 5. Repeat, while k < len,
+        for k in 0..length {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kPresent be ? HasProperty(O, Pk).
+            // c. If kPresent is true, then
+            if o.has_property(&k.into(), context)? {
+                // i. Let kValue be ? Get(O, Pk).
+                let k_value = o.get(&k.into(), Value::from(o.clone()), context)?;
+
+                // ii. Perform ? Call(callbackfn, thisArg, « kValue, 𝔽(k), O »).
+                let arguments = [k_value, Value::from(k), Value::from(o.clone())];
+                context.call(&callback, &this_arg, &arguments)?;
+            }
         }
 
+        // 6. Return undefined.
         Ok(Value::undefined())
     }
 
@@ -530,19 +802,20 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.join
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/join
     pub(crate) fn join(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let separator = if args.is_empty() {
-            String::from(",")
-        } else {
-            args.get(0)
-                .expect("Could not get argument")
-                .to_string(context)?
-                .to_string()
+        let separator = match args.get(0) {
+            None | Some(Value::Undefined) => String::from(","),
+            Some(separator) => separator.to_string(context)?.to_string(),
         };
 
         let mut elem_strs = Vec::new();
         let length = this.get_field("length", context)?.to_length(context)?;
         for n in 0..length {
-            let elem_str = this.get_field(n, context)?.to_string(context)?.to_string();
+            let element = this.get_field(n, context)?;
+            let elem_str = if element.is_null_or_undefined() {
+                String::new()
+            } else {
+                element.to_string(context)?.to_string()
+            };
             elem_strs.push(elem_str);
         }
 
@@ -603,32 +876,172 @@ impl Array {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/reverse
     #[allow(clippy::else_if_without_else)]
     pub(crate) fn reverse(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? ToObject(this value).
+        let mut o = this.to_object(context)?;
+        let this: Value = o.clone().into();
+
+        // 2. Let len be ? LengthOfArrayLike(O).
         let len = this.get_field("length", context)?.to_length(context)?;
 
-        let middle = len.wrapping_div(2);
+        let middle = len / 2;
 
         for lower in 0..middle {
-            let upper = len.wrapping_sub(lower).wrapping_sub(1);
+            let upper = len - lower - 1;
 
-            let upper_exists = this.has_field(upper);
-            let lower_exists = this.has_field(lower);
+            let lower_exists = o.has_property(&lower.into(), context)?;
+            let lower_value = if lower_exists {
+                this.get_field(lower, context)?
+            } else {
+                Value::undefined()
+            };
 
-            let upper_value = this.get_field(upper, context)?;
-            let lower_value = this.get_field(lower, context)?;
+            let upper_exists = o.has_property(&upper.into(), context)?;
+            let upper_value = if upper_exists {
+                this.get_field(upper, context)?
+            } else {
+                Value::undefined()
+            };
 
-            if upper_exists && lower_exists {
-                this.set_property(upper, DataDescriptor::new(lower_value, Attribute::all()));
-                this.set_property(lower, DataDescriptor::new(upper_value, Attribute::all()));
+            if lower_exists && upper_exists {
+                this.set_field(lower, upper_value, true, context)?;
+                this.set_field(upper, lower_value, true, context)?;
             } else if upper_exists {
-                this.set_property(lower, DataDescriptor::new(upper_value, Attribute::all()));
-                this.remove_property(upper);
+                this.set_field(lower, upper_value, true, context)?;
+                o.delete_property_or_throw(upper, context)?;
             } else if lower_exists {
-                this.set_property(upper, DataDescriptor::new(lower_value, Attribute::all()));
-                this.remove_property(lower);
+                o.delete_property_or_throw(lower, context)?;
+                this.set_field(upper, lower_value, true, context)?;
             }
         }
 
-        Ok(this.clone())
+        Ok(this)
+    }
+
+    /// `Array.prototype.sort( [ comparefn ] )`
+    ///
+    /// The elements of the array are sorted in place, and the array is returned. The sort is
+    /// stable, so elements that compare as equal keep their original order.
+    ///
+    /// Without a comparator, elements are sorted by their string representation. `undefined`
+    /// values are sorted to the end of the array, followed by the holes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.sort
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/sort
+    pub(crate) fn sort(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
+        let compare_fn = args.get(0).cloned().unwrap_or_default();
+        if !compare_fn.is_undefined() && !compare_fn.is_function() {
+            return context.throw_type_error(
+                "The comparison function must be either a function or undefined",
+            );
+        }
+
+        // 2. Let obj be ? ToObject(this value).
+        let mut o = this.to_object(context)?;
+        let obj: Value = o.clone().into();
+
+        // 3. Let len be ? LengthOfArrayLike(obj).
+        let len = obj.get_field("length", context)?.to_length(context)?;
+
+        // 4. Let items be a new empty List.
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        //   a. Let Pk be ! ToString(𝔽(k)).
+        //   b. Let kPresent be ? HasProperty(obj, Pk).
+        //   c. If kPresent is true, then
+        //     i. Let kValue be ? Get(obj, Pk).
+        //     ii. Append kValue to items.
+        let mut items = Vec::new();
+        let mut undefined_count = 0;
+        for k in 0..len {
+            if obj.has_field(k) {
+                let value = obj.get_field(k, context)?;
+                // The undefined values always compare greater than any other value, so they are
+                // kept out of the merge sort and appended after the sorted items.
+                if value.is_undefined() {
+                    undefined_count += 1;
+                } else {
+                    items.push(value);
+                }
+            }
+        }
+
+        // 7. Sort items using an implementation-defined sequence of calls to SortCompare.
+        let mut sort_compare = |x: &Value, y: &Value| -> Result<Ordering> {
+            if compare_fn.is_undefined() {
+                // SortCompare, steps 5-13: compare the code units of the string representations.
+                let x = x.to_string(context)?;
+                let y = y.to_string(context)?;
+                Ok(x.encode_utf16().cmp(y.encode_utf16()))
+            } else {
+                // SortCompare, step 4: If comparefn is not undefined, then
+                //   a. Let v be ? ToNumber(? Call(comparefn, undefined, « x, y »)).
+                //   b. If v is NaN, return +0𝔽.
+                let v = context
+                    .call(&compare_fn, &Value::undefined(), &[x.clone(), y.clone()])?
+                    .to_number(context)?;
+                Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal))
+            }
+        };
+        let items = Self::merge_sort(items, &mut sort_compare)?;
+
+        // 8. Let itemCount be the number of elements in items.
+        // 9. Let j be 0.
+        // 10. Repeat, while j < itemCount,
+        //   a. Perform ? Set(obj, ! ToString(𝔽(j)), items[j], true).
+        let mut j = 0;
+        for item in items {
+            obj.set_field(j, item, true, context)?;
+            j += 1;
+        }
+        for _ in 0..undefined_count {
+            obj.set_field(j, Value::undefined(), true, context)?;
+            j += 1;
+        }
+
+        // 11. Repeat, while j < len,
+        //   a. Perform ? DeletePropertyOrThrow(obj, ! ToString(𝔽(j))).
+        for j in j..len {
+            o.delete_property_or_throw(j, context)?;
+        }
+
+        // 12. Return obj.
+        Ok(obj)
+    }
+
+    /// Stable merge sort of `items`, propagating the errors of the `compare` function.
+    fn merge_sort<F>(mut items: Vec<Value>, compare: &mut F) -> Result<Vec<Value>>
+    where
+        F: FnMut(&Value, &Value) -> Result<Ordering>,
+    {
+        if items.len() <= 1 {
+            return Ok(items);
+        }
+
+        let right = items.split_off(items.len() / 2);
+        let left = Self::merge_sort(items, compare)?;
+        let right = Self::merge_sort(right, compare)?;
+
+        let mut merged = Vec::with_capacity(left.len() + right.len());
+        let mut left = left.into_iter().peekable();
+        let mut right = right.into_iter().peekable();
+        while let (Some(l), Some(r)) = (left.peek(), right.peek()) {
+            // Only take from the right half if it is strictly smaller, to keep the sort stable.
+            let next = if compare(r, l)? == Ordering::Less {
+                right.next()
+            } else {
+                left.next()
+            };
+            merged.extend(next);
+        }
+        merged.extend(left);
+        merged.extend(right);
+
+        Ok(merged)
     }
 
     /// `Array.prototype.shift()`
@@ -642,31 +1055,48 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.shift
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/shift
     pub(crate) fn shift(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? ToObject(this value).
+        let mut o = this.to_object(context)?;
+        let this: Value = o.clone().into();
+
+        // 2. Let len be ? LengthOfArrayLike(O).
         let len = this.get_field("length", context)?.to_length(context)?;
 
+        // 3. If len = 0, then
         if len == 0 {
+            // a. Perform ? Set(O, "length", +0𝔽, true).
             this.set_field("length", 0, true, context)?;
+            // b. Return undefined.
             return Ok(Value::undefined());
         }
 
-        let first: Value = this.get_field(0, context)?;
+        // 4. Let first be ? Get(O, "0").
+        let first = this.get_field(0, context)?;
 
+        // 5. Let k be 1.
+        // 6. Repeat, while k < len,
         for k in 1..len {
-            let from = k;
-            let to = k.wrapping_sub(1);
-
-            let from_value = this.get_field(from, context)?;
-            if from_value.is_undefined() {
-                this.remove_property(to);
+            let (from, to) = (k, k - 1);
+            // c. Let fromPresent be ? HasProperty(O, from).
+            if o.has_property(&from.into(), context)? {
+                // d. If fromPresent is true, then
+                //   i. Let fromVal be ? Get(O, from).
+                //   ii. Perform ? Set(O, to, fromVal, true).
+                let from_value = this.get_field(from, context)?;
+                this.set_field(to, from_value, true, context)?;
             } else {
-                this.set_property(to, DataDescriptor::new(from_value, Attribute::all()));
+                // e. Else,
+                //   i. Perform ? DeletePropertyOrThrow(O, to).
+                o.delete_property_or_throw(to, context)?;
             }
         }
 
-        let final_index = len.wrapping_sub(1);
-        this.remove_property(final_index);
-        this.set_field("length", Value::from(final_index), true, context)?;
+        // 7. Perform ? DeletePropertyOrThrow(O, ! ToString(𝔽(len - 1))).
+        o.delete_property_or_throw(len - 1, context)?;
+        // 8. Perform ? Set(O, "length", 𝔽(len - 1), true).
+        this.set_field("length", len - 1, true, context)?;
 
+        // 9. Return first.
         Ok(first)
     }
 
@@ -683,36 +1113,57 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.unshift
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/unshift
     pub(crate) fn unshift(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? ToObject(this value).
+        let mut o = this.to_object(context)?;
+        let this: Value = o.clone().into();
+
+        // 2. Let len be ? LengthOfArrayLike(O).
         let len = this.get_field("length", context)?.to_length(context)?;
 
-        let arg_c = args.len();
+        // 3. Let argCount be the number of elements in items.
+        let arg_count = args.len();
 
-        if arg_c > 0 {
-            for k in (1..=len).rev() {
-                let from = k.wrapping_sub(1);
-                let to = k.wrapping_add(arg_c).wrapping_sub(1);
+        // 4. If argCount > 0, then
+        if arg_count > 0 {
+            // a. If len + argCount > 2^53 - 1, throw a TypeError exception.
+            if (len + arg_count) as f64 > Number::MAX_SAFE_INTEGER {
+                return context.throw_type_error(
+                    "Unshifting the items would exceed the maximum array length",
+                );
+            }
 
-                let from_value = this.get_field(from, context)?;
-                if from_value.is_undefined() {
-                    this.remove_property(to);
+            // b. Let k be len.
+            // c. Repeat, while k > 0,
+            for k in (1..=len).rev() {
+                let (from, to) = (k - 1, k + arg_count - 1);
+                // iii. Let fromPresent be ? HasProperty(O, from).
+                if o.has_property(&from.into(), context)? {
+                    // iv. If fromPresent is true, then
+                    //   1. Let fromValue be ? Get(O, from).
+                    //   2. Perform ? Set(O, to, fromValue, true).
+                    let from_value = this.get_field(from, context)?;
+                    this.set_field(to, from_value, true, context)?;
                 } else {
-                    this.set_property(to, DataDescriptor::new(from_value, Attribute::all()));
+                    // v. Else,
+                    //   1. Perform ? DeletePropertyOrThrow(O, to).
+                    o.delete_property_or_throw(to, context)?;
                 }
             }
-            for j in 0..arg_c {
-                this.set_property(
-                    j,
-                    DataDescriptor::new(
-                        args.get(j).expect("Could not get argument").clone(),
-                        Attribute::all(),
-                    ),
-                );
+
+            // d. Let j be +0𝔽.
+            // e. For each element E of items, do
+            //   i. Perform ? Set(O, ! ToString(j), E, true).
+            for (j, item) in args.iter().enumerate() {
+                this.set_field(j, item.clone(), true, context)?;
             }
         }
 
-        let temp = len.wrapping_add(arg_c);
-        this.set_field("length", Value::from(temp), true, context)?;
-        Ok(Value::from(temp))
+        // 5. Perform ? Set(O, "length", 𝔽(len + argCount), true).
+        let len = len + arg_count;
+        this.set_field("length", len, true, context)?;
+
+        // 6. Return 𝔽(len + argCount).
+        Ok(len.into())
     }
 
     /// `Array.prototype.every( callback, [ thisArg ] )`
@@ -771,33 +1222,57 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.map
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/map
     pub(crate) fn map(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        if args.is_empty() {
-            return Err(Value::from(
-                "missing argument 0 when calling function Array.prototype.map",
-            ));
-        }
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
 
-        let callback = args.get(0).cloned().unwrap_or_else(Value::undefined);
-        let this_val = args.get(1).cloned().unwrap_or_else(Value::undefined);
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let length = o
+            .get(&"length".into(), Value::from(o.clone()), context)?
+            .to_length(context)?;
 
-        let length = this.get_field("length", context)?.to_length(context)?;
+        // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
+        let callback = args.get(0).cloned().unwrap_or_default();
+        if !callback.is_function() {
+            return context.throw_type_error("Array.prototype.map: callback is not callable");
+        }
+        let this_arg = args.get(1).cloned().unwrap_or_default();
 
         if length > 2usize.pow(32) - 1 {
             return context.throw_range_error("Invalid array length");
         }
 
-        let new = Self::new_array(context);
-
-        let values = (0..length)
-            .map(|idx| {
-                let element = this.get_field(idx, context)?;
-                let args = [element, Value::from(idx), new.clone()];
+        // 4. Let A be ? ArraySpeciesCreate(O, len).
+        let mut a = Self::array_species_create(&o, length as u32, context)?
+            .as_object()
+            .expect("array_species_create must create an object");
 
-                context.call(&callback, &this_val, &args)
-            })
-            .collect::<Result<Vec<Value>>>()?;
+        // 5. Let k be 0.
+        // 6. Repeat, while k < len,
+        for k in 0..length {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kPresent be ? HasProperty(O, Pk).
+            // c. If kPresent is true, then
+            if o.has_property(&k.into(), context)? {
+                // i. Let kValue be ? Get(O, Pk).
+                let k_value = o.get(&k.into(), Value::from(o.clone()), context)?;
+
+                // ii. Let mappedValue be ? Call(callbackfn, thisArg, « kValue, 𝔽(k), O »).
+                let arguments = [k_value, Value::from(k), Value::from(o.clone())];
+                let mapped_value = context.call(&callback, &this_arg, &arguments)?;
+
+                // iii. Perform ? CreateDataPropertyOrThrow(A, Pk, mappedValue).
+                if !a.define_own_property(
+                    k,
+                    DataDescriptor::new(mapped_value, Attribute::all()).into(),
+                    context,
+                )? {
+                    return context.throw_type_error("cannot set property in array");
+                }
+            }
+        }
 
-        Self::construct_array(&new, &values, context)
+        // 7. Return A.
+        Ok(a.into())
     }
 
     /// `Array.prototype.indexOf( searchElement[, fromIndex ] )`
@@ -993,6 +1468,92 @@ impl Array {
         Ok(Value::integer(-1))
     }
 
+    /// `Array.prototype.findLast( predicate [ , thisArg ] )`
+    ///
+    /// This method executes the provided predicate function for each element of the array,
+    /// starting from the last one. If the predicate function returns `true` for an element,
+    /// this method returns the element. If all elements return `false`, `undefined` is returned.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-find-from-last/#sec-array.prototype.findlast
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findLast
+    pub(crate) fn find_last(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let found = Self::find_last_element(this, args, "findLast", context)?;
+        Ok(found.map_or_else(Value::undefined, |(_, element)| element))
+    }
+
+    /// `Array.prototype.findLastIndex( predicate [ , thisArg ] )`
+    ///
+    /// This method executes the provided predicate function for each element of the array,
+    /// starting from the last one. If the predicate function returns `true` for an element,
+    /// this method returns the index of the element. If all elements return `false`, the value
+    /// `-1` is returned.
+    ///
+    /// More information:
+    ///  - [ECMAScript proposal][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-find-from-last/#sec-array.prototype.findlastindex
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findLastIndex
+    pub(crate) fn find_last_index(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let found = Self::find_last_element(this, args, "findLastIndex", context)?;
+        Ok(found.map_or_else(|| Value::integer(-1), |(index, _)| Value::from(index)))
+    }
+
+    /// Shared implementation of `findLast` and `findLastIndex`, returning the index and the
+    /// value of the last element for which the predicate returns `true`.
+    fn find_last_element(
+        this: &Value,
+        args: &[Value],
+        method: &str,
+        context: &mut Context,
+    ) -> Result<Option<(usize, Value)>> {
+        // 1. Let O be ? ToObject(this value).
+        let o: Value = this.to_object(context)?.into();
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.get_field("length", context)?.to_length(context)?;
+
+        // 3. If IsCallable(predicate) is false, throw a TypeError exception.
+        let predicate = args.get(0).cloned().unwrap_or_default();
+        if !predicate.is_function() {
+            context.throw_type_error(format!(
+                "Array.prototype.{}: predicate is not callable",
+                method
+            ))?;
+        }
+        let this_arg = args.get(1).cloned().unwrap_or_default();
+
+        // 4. Let k be len - 1.
+        // 5. Repeat, while k ≥ 0,
+        for k in (0..len).rev() {
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Let kValue be ? Get(O, Pk).
+            let k_value = o.get_field(k, context)?;
+
+            // c. Let testResult be ! ToBoolean(? Call(predicate, thisArg, « kValue, 𝔽(k), O »)).
+            let arguments = [k_value.clone(), Value::from(k), o.clone()];
+            let test_result = context.call(&predicate, &this_arg, &arguments)?;
+
+            // d. If testResult is true, return kValue (or 𝔽(k)).
+            if test_result.to_boolean() {
+                return Ok(Some((k, k_value)));
+            }
+
+            // e. Set k to k - 1.
+        }
+
+        // 6. Return undefined (or -1𝔽).
+        Ok(None)
+    }
+
     /// `Array.prototype.flat( [depth] )`
     ///
     /// This method creates a new array with all sub-array elements concatenated into it
@@ -1012,18 +1573,16 @@ impl Array {
         let source_len = this.get_field("length", context)?.to_length(context)? as u32;
 
         // 3. Let depthNum be 1
-        let depth = args.get(0);
-        let default_depth = Value::Integer(1);
-
         // 4. If depth is not undefined, then set depthNum to IntegerOrInfinity(depth)
         // 4.a. Set depthNum to ToIntegerOrInfinity(depth)
         // 4.b. If depthNum < 0, set depthNum to 0
-        let depth_num = match depth
-            .unwrap_or(&default_depth)
-            .to_integer_or_infinity(context)?
-        {
-            IntegerOrInfinity::Integer(i) if i < 0 => IntegerOrInfinity::Integer(0),
-            num => num,
+        let depth_num = match args.get(0) {
+            Some(depth) if !depth.is_undefined() => match depth.to_integer_or_infinity(context)? {
+                IntegerOrInfinity::Integer(i) if i < 0 => IntegerOrInfinity::Integer(0),
+                IntegerOrInfinity::NegativeInfinity => IntegerOrInfinity::Integer(0),
+                num => num,
+            },
+            _ => IntegerOrInfinity::Integer(1),
         };
 
         // 5. Let A be ArraySpeciesCreate(O, 0)
@@ -1063,27 +1622,26 @@ impl Array {
         let o: Value = this.to_object(context)?.into();
 
         // 2. Let sourceLen be LengthOfArrayLike(O)
-        let source_len = this.get_field("length", context)?.to_length(context)? as u32;
+        let source_len = o.get_field("length", context)?.to_length(context)? as u32;
 
         // 3. If IsCallable(mapperFunction) is false, throw a TypeError exception
         let mapper_function = args.get(0).cloned().unwrap_or_else(Value::undefined);
         if !mapper_function.is_function() {
             return context.throw_type_error("flatMap mapper function is not callable");
         }
-        let this_arg = args.get(1).cloned().unwrap_or(o);
+        let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
 
         // 4. Let A be ArraySpeciesCreate(O, 0)
         let new_array = Self::new_array(context);
 
         // 5. Perform FlattenIntoArray(A, O, sourceLen, 0, 1, mapperFunction, thisArg)
-        let depth = Value::Integer(1).to_integer_or_infinity(context)?;
         let len = Self::flatten_into_array(
             context,
             &new_array,
-            &this,
+            &o,
             source_len,
             0,
-            depth,
+            IntegerOrInfinity::Integer(1),
             &mapper_function,
             &this_arg,
         )?;
@@ -1139,7 +1697,7 @@ impl Array {
                 // 6.c.ii. If mapperFunction is present, then
                 if !mapper_function.is_undefined() {
                     // 6.c.ii.1. Set element to Call(mapperFunction, thisArg, <<element, sourceIndex, source>>)
-                    let args = [element, Value::from(source_index), target.clone()];
+                    let args = [element, Value::from(source_index), source.clone()];
                     element = context.call(&mapper_function, &this_arg, &args)?;
                 }
                 let element_as_object = element.as_object();
@@ -1156,7 +1714,7 @@ impl Array {
                 if depth_is_positive {
                     // 6.c.iv.1. Set shouldFlatten is IsArray(element)
                     should_flatten = match element_as_object {
-                        Some(obj) => obj.is_array(),
+                        Some(obj) => obj.is_array_abstract(),
                         _ => false,
                     };
                 }
@@ -1211,6 +1769,53 @@ impl Array {
         Ok(Value::Integer(target_index.try_into().unwrap_or(0)))
     }
 
+    /// `Array.prototype.at( index )`
+    ///
+    /// The method returns the element at the given index, counting back from the end of the array
+    /// for negative indices. It returns `undefined` if the index is out of range.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-relative-indexing-method/#sec-array.prototype.at
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/at
+    pub(crate) fn at(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? ToObject(this value).
+        let o: Value = this.to_object(context)?.into();
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.get_field("length", context)?.to_length(context)?;
+
+        // 3. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        let relative_index = args
+            .get(0)
+            .cloned()
+            .unwrap_or_else(Value::undefined)
+            .to_integer_or_infinity(context)?;
+
+        // 4. If relativeIndex ≥ 0, then
+        //     a. Let k be relativeIndex.
+        // 5. Else,
+        //     a. Let k be len + relativeIndex.
+        let k = match relative_index {
+            IntegerOrInfinity::Integer(i) if i >= 0 => i as u64,
+            IntegerOrInfinity::Integer(i) => match (len as u64).checked_sub(i.unsigned_abs()) {
+                Some(k) => k,
+                None => return Ok(Value::undefined()),
+            },
+            _ => return Ok(Value::undefined()),
+        };
+
+        // 6. If k < 0 or k ≥ len, return undefined.
+        if k >= len as u64 {
+            return Ok(Value::undefined());
+        }
+
+        // 7. Return ? Get(O, ! ToString(𝔽(k))).
+        o.get_field(k as usize, context)
+    }
+
     /// `Array.prototype.fill( value[, start[, end]] )`
     ///
     /// The method fills (modifies) all the elements of an array from start index (default 0)
@@ -1223,18 +1828,30 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.fill
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/fill
     pub(crate) fn fill(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let len = this.get_field("length", context)?.to_length(context)?;
+        // 1. Let O be ? ToObject(this value).
+        let o: Value = this.to_object(context)?.into();
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.get_field("length", context)?.to_length(context)?;
 
         let default_value = Value::undefined();
         let value = args.get(0).unwrap_or(&default_value);
+
+        // 3-5. Let k be the relative start, clamped to the bounds of O.
         let start = Self::get_relative_start(context, args.get(1), len)?;
+
+        // 6-8. Let final be the relative end, clamped to the bounds of O.
         let fin = Self::get_relative_end(context, args.get(2), len)?;
 
+        // 9. Repeat, while k < final,
         for i in start..fin {
-            this.set_property(i, DataDescriptor::new(value.clone(), Attribute::all()));
+            // a. Let Pk be ! ToString(𝔽(k)).
+            // b. Perform ? Set(O, Pk, value, true).
+            o.set_field(i, value.clone(), true, context)?;
         }
 
-        Ok(this.clone())
+        // 10. Return O.
+        Ok(o)
     }
 
     /// `Array.prototype.includes( valueToFind [, fromIndex] )`
@@ -1247,23 +1864,57 @@ impl Array {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.includes
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/includes
-    pub(crate) fn includes_value(
-        this: &Value,
-        args: &[Value],
-        context: &mut Context,
-    ) -> Result<Value> {
-        let search_element = args.get(0).cloned().unwrap_or_else(Value::undefined);
+    pub(crate) fn includes(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? ToObject(this value).
+        let o: Value = this.to_object(context)?.into();
 
-        let length = this.get_field("length", context)?.to_length(context)?;
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = o.get_field("length", context)?.to_length(context)?;
 
-        for idx in 0..length {
-            let check_element = this.get_field(idx, context)?.clone();
+        // 3. If len is 0, return false.
+        if len == 0 {
+            return Ok(Value::from(false));
+        }
+
+        // 4. Let n be ? ToIntegerOrInfinity(fromIndex).
+        // 5. Assert: If fromIndex is undefined, then n is 0.
+        let n = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_integer_or_infinity(context)?;
 
-            if Value::same_value_zero(&check_element, &search_element) {
+        let mut k = match n {
+            // 6. If n is +∞, return false.
+            IntegerOrInfinity::PositiveInfinity => return Ok(Value::from(false)),
+            // 7. Else if n is -∞, set n to 0.
+            IntegerOrInfinity::NegativeInfinity => 0,
+            // 8. If n ≥ 0, then
+            //     a. Let k be n.
+            IntegerOrInfinity::Integer(n) if n >= 0 => n as u64,
+            // 9. Else,
+            //     a. Let k be len + n.
+            //     b. If k < 0, set k to 0.
+            IntegerOrInfinity::Integer(n) => (len as i64 + n).max(0) as u64,
+        };
+
+        // 10. Repeat, while k < len,
+        let search_element = args.get(0).cloned().unwrap_or_default();
+        while k < len as u64 {
+            // a. Let elementK be ? Get(O, ! ToString(𝔽(k))).
+            // Holes are read as undefined.
+            let element_k = o.get_field(k as usize, context)?;
+
+            // b. If SameValueZero(searchElement, elementK) is true, return true.
+            if Value::same_value_zero(&search_element, &element_k) {
                 return Ok(Value::from(true));
             }
+
+            // c. Set k to k + 1.
+            k += 1;
         }
 
+        // 11. Return false.
         Ok(Value::from(false))
     }
 
@@ -1304,6 +1955,155 @@ impl Array {
         Ok(new_array)
     }
 
+    /// `Array.prototype.splice( start, deleteCount, ...items )`
+    ///
+    /// The splice method removes `deleteCount` elements of the array starting at index `start`,
+    /// replaces them with `items`, and returns an array containing the removed elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.splice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/splice
+    pub(crate) fn splice(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? ToObject(this value).
+        let mut o = this.to_object(context)?;
+        let this: Value = o.clone().into();
+
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = this.get_field("length", context)?.to_length(context)?;
+
+        // 3-4. Let actualStart be the relative start, clamped to the bounds of O.
+        let actual_start = Self::get_relative_start(context, args.get(0), len)?;
+
+        // 5. If start is not present, then
+        //   a. Let insertCount be 0.
+        //   b. Let actualDeleteCount be 0.
+        // 6. Else if deleteCount is not present, then
+        //   a. Let insertCount be 0.
+        //   b. Let actualDeleteCount be len - actualStart.
+        // 7. Else,
+        //   a. Let insertCount be the number of elements in items.
+        //   b. Let dc be ? ToIntegerOrInfinity(deleteCount).
+        //   c. Let actualDeleteCount be the result of clamping dc between 0 and len - actualStart.
+        let items = args.get(2..).unwrap_or_default();
+        let actual_delete_count = match args.get(1) {
+            None if args.is_empty() => 0,
+            None => len - actual_start,
+            Some(delete_count) => match delete_count.to_integer_or_infinity(context)? {
+                IntegerOrInfinity::Integer(dc) if dc > 0 => {
+                    (dc as u64).min((len - actual_start) as u64) as usize
+                }
+                IntegerOrInfinity::PositiveInfinity => len - actual_start,
+                _ => 0,
+            },
+        };
+        let item_count = items.len();
+
+        // 8. If len + insertCount - actualDeleteCount > 2^53 - 1, throw a TypeError exception.
+        if (len + item_count - actual_delete_count) as f64 > Number::MAX_SAFE_INTEGER {
+            return context
+                .throw_type_error("Splicing the items would exceed the maximum array length");
+        }
+
+        if actual_delete_count > 2usize.pow(32) - 1 {
+            return context.throw_range_error("Invalid array length");
+        }
+
+        // 9. Let A be ? ArraySpeciesCreate(O, actualDeleteCount).
+        let mut a = Self::array_species_create(&o, actual_delete_count as u32, context)?
+            .as_object()
+            .expect("array_species_create must create an object");
+
+        // 10. Let k be 0.
+        // 11. Repeat, while k < actualDeleteCount,
+        for k in 0..actual_delete_count {
+            // a. Let from be ! ToString(𝔽(actualStart + k)).
+            let from = actual_start + k;
+            // b. Let fromPresent be ? HasProperty(O, from).
+            // c. If fromPresent is true, then
+            if o.has_property(&from.into(), context)? {
+                // i. Let fromValue be ? Get(O, from).
+                let from_value = this.get_field(from, context)?;
+                // ii. Perform ? CreateDataPropertyOrThrow(A, ! ToString(𝔽(k)), fromValue).
+                a.define_property_or_throw(
+                    k,
+                    DataDescriptor::new(from_value, Attribute::all()),
+                    context,
+                )?;
+            }
+        }
+
+        // 12. Perform ? Set(A, "length", 𝔽(actualDeleteCount), true).
+        Value::from(a.clone()).set_field("length", actual_delete_count, true, context)?;
+
+        // 14. If itemCount < actualDeleteCount, then
+        if item_count < actual_delete_count {
+            // a. Set k to actualStart.
+            // b. Repeat, while k < (len - actualDeleteCount),
+            for k in actual_start..(len - actual_delete_count) {
+                let (from, to) = (k + actual_delete_count, k + item_count);
+                // iii. Let fromPresent be ? HasProperty(O, from).
+                if o.has_property(&from.into(), context)? {
+                    // iv. If fromPresent is true, then
+                    //   1. Let fromValue be ? Get(O, from).
+                    //   2. Perform ? Set(O, to, fromValue, true).
+                    let from_value = this.get_field(from, context)?;
+                    this.set_field(to, from_value, true, context)?;
+                } else {
+                    // v. Else,
+                    //   1. Perform ? DeletePropertyOrThrow(O, to).
+                    o.delete_property_or_throw(to, context)?;
+                }
+            }
+
+            // c. Set k to len.
+            // d. Repeat, while k > (len - actualDeleteCount + itemCount),
+            //   i. Perform ? DeletePropertyOrThrow(O, ! ToString(𝔽(k - 1))).
+            for k in ((len - actual_delete_count + item_count)..len).rev() {
+                o.delete_property_or_throw(k, context)?;
+            }
+        // 15. Else if itemCount > actualDeleteCount, then
+        } else if item_count > actual_delete_count {
+            // a. Set k to (len - actualDeleteCount).
+            // b. Repeat, while k > actualStart,
+            for k in ((actual_start + 1)..=(len - actual_delete_count)).rev() {
+                let (from, to) = (k + actual_delete_count - 1, k + item_count - 1);
+                // iii. Let fromPresent be ? HasProperty(O, from).
+                if o.has_property(&from.into(), context)? {
+                    // iv. If fromPresent is true, then
+                    //   1. Let fromValue be ? Get(O, from).
+                    //   2. Perform ? Set(O, to, fromValue, true).
+                    let from_value = this.get_field(from, context)?;
+                    this.set_field(to, from_value, true, context)?;
+                } else {
+                    // v. Else,
+                    //   1. Perform ? DeletePropertyOrThrow(O, to).
+                    o.delete_property_or_throw(to, context)?;
+                }
+            }
+        }
+
+        // 16. Set k to actualStart.
+        // 17. For each element E of items, do
+        //   a. Perform ? Set(O, ! ToString(𝔽(k)), E, true).
+        for (k, item) in items.iter().enumerate() {
+            this.set_field(actual_start + k, item.clone(), true, context)?;
+        }
+
+        // 18. Perform ? Set(O, "length", 𝔽(len - actualDeleteCount + itemCount), true).
+        this.set_field(
+            "length",
+            len - actual_delete_count + item_count,
+            true,
+            context,
+        )?;
+
+        // 19. Return A.
+        Ok(a.into())
+    }
+
     /// `Array.prototype.filter( callback, [ thisArg ] )`
     ///
     /// For each element in the array the callback function is called, and a new
@@ -1342,7 +2142,7 @@ impl Array {
 
         let mut to = 0u32;
         for idx in 0..length {
-            if o.has_property(&idx.into()) {
+            if o.has_property(&idx.into(), context)? {
                 let element = o.get(&idx.into(), Value::from(o.clone()), context)?;
 
                 let args = [element.clone(), Value::from(idx), Value::from(o.clone())];
@@ -1428,40 +2228,43 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.reduce
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/reduce
     pub(crate) fn reduce(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let this: Value = this.to_object(context)?.into();
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+        let this: Value = o.clone().into();
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let length = this.get_field("length", context)?.to_length(context)?;
+        // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
         let callback = match args.get(0) {
             Some(value) if value.is_function() => value,
             _ => return context.throw_type_error("Reduce was called without a callback"),
         };
-        let initial_value = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        let mut length = this.get_field("length", context)?.to_length(context)?;
-        if length == 0 && initial_value.is_undefined() {
-            return context
-                .throw_type_error("Reduce was called on an empty array and with no initial value");
-        }
+
+        // 5. Let k be 0.
         let mut k = 0;
-        let mut accumulator = if initial_value.is_undefined() {
-            let mut k_present = false;
-            while k < length {
-                if this.has_field(k) {
-                    k_present = true;
-                    break;
+        // 6. Let accumulator be undefined.
+        // 7. If initialValue is present, then set accumulator to initialValue.
+        let mut accumulator = if let Some(initial_value) = args.get(1) {
+            initial_value.clone()
+        } else {
+            // 8. Else, set accumulator to the value of the first element present in the array,
+            //    or throw a TypeError exception if there is none.
+            loop {
+                if k >= length {
+                    return context.throw_type_error(
+                        "Reduce was called on an empty array and with no initial value",
+                    );
                 }
+                let present = o.has_property(&k.into(), context)?;
                 k += 1;
+                if present {
+                    break this.get_field(k - 1, context)?;
+                }
             }
-            if !k_present {
-                return context.throw_type_error(
-                    "Reduce was called on an empty array and with no initial value",
-                );
-            }
-            let result = this.get_field(k, context)?;
-            k += 1;
-            result
-        } else {
-            initial_value
         };
+
+        // 9. Repeat, while k < len, calling the callback with the elements present in the array.
         while k < length {
-            if this.has_field(k) {
+            if o.has_property(&k.into(), context)? {
                 let arguments = [
                     accumulator,
                     this.get_field(k, context)?,
@@ -1469,16 +2272,11 @@ impl Array {
                     this.clone(),
                 ];
                 accumulator = context.call(&callback, &Value::undefined(), &arguments)?;
-                /* We keep track of possibly shortened length in order to prevent unnecessary iteration.
-                It may also be necessary to do this since shortening the array length does not
-                delete array elements. See: https://github.com/boa-dev/boa/issues/557 */
-                length = min(
-                    length,
-                    this.get_field("length", context)?.to_length(context)?,
-                );
             }
             k += 1;
         }
+
+        // 10. Return accumulator.
         Ok(accumulator)
     }
 
@@ -1498,51 +2296,44 @@ impl Array {
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        let this: Value = this.to_object(context)?.into();
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+        let this: Value = o.clone().into();
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let length = this.get_field("length", context)?.to_length(context)?;
+        // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
         let callback = match args.get(0) {
             Some(value) if value.is_function() => value,
             _ => return context.throw_type_error("reduceRight was called without a callback"),
         };
-        let initial_value = args.get(1).cloned().unwrap_or_else(Value::undefined);
-        let mut length = this.get_field("length", context)?.to_length(context)?;
-        if length == 0 {
-            return if initial_value.is_undefined() {
-                context.throw_type_error(
-                    "reduceRight was called on an empty array and with no initial value",
-                )
-            } else {
-                // early return to prevent usize subtraction errors
-                Ok(initial_value)
-            };
-        }
-        let mut k = length - 1;
-        let mut accumulator = if initial_value.is_undefined() {
-            let mut k_present = false;
+
+        // 5. Let k be len - 1. Since `k` can't be negative, it is the number of elements that
+        //    haven't been visited yet, and the next element to visit is `k - 1`.
+        let mut k = length;
+        // 6. Let accumulator be undefined.
+        // 7. If initialValue is present, then set accumulator to initialValue.
+        let mut accumulator = if let Some(initial_value) = args.get(1) {
+            initial_value.clone()
+        } else {
+            // 8. Else, set accumulator to the value of the last element present in the array,
+            //    or throw a TypeError exception if there is none.
             loop {
-                if this.has_field(k) {
-                    k_present = true;
-                    break;
-                }
-                // check must be done at the end to prevent usize subtraction error
                 if k == 0 {
-                    break;
+                    return context.throw_type_error(
+                        "reduceRight was called on an empty array and with no initial value",
+                    );
                 }
                 k -= 1;
+                if o.has_property(&k.into(), context)? {
+                    break this.get_field(k, context)?;
+                }
             }
-            if !k_present {
-                return context.throw_type_error(
-                    "reduceRight was called on an empty array and with no initial value",
-                );
-            }
-            let result = this.get_field(k, context)?;
-            k = k.overflowing_sub(1).0;
-            result
-        } else {
-            initial_value
         };
-        // usize::MAX is bigger than the maximum array size so we can use it check for integer undeflow
-        while k != usize::MAX {
-            if this.has_field(k) {
+
+        // 9. Repeat, while k ≥ 0, calling the callback with the elements present in the array.
+        while k > 0 {
+            k -= 1;
+            if o.has_property(&k.into(), context)? {
                 let arguments = [
                     accumulator,
                     this.get_field(k, context)?,
@@ -1550,32 +2341,12 @@ impl Array {
                     this.clone(),
                 ];
                 accumulator = context.call(&callback, &Value::undefined(), &arguments)?;
-                /* We keep track of possibly shortened length in order to prevent unnecessary iteration.
-                It may also be necessary to do this since shortening the array length does not
-                delete array elements. See: https://github.com/boa-dev/boa/issues/557 */
-                length = min(
-                    length,
-                    this.get_field("length", context)?.to_length(context)?,
-                );
-
-                // move k to the last defined element if necessary or return if the length was set to 0
-                if k >= length {
-                    if length == 0 {
-                        return Ok(accumulator);
-                    } else {
-                        k = length - 1;
-                        continue;
-                    }
-                }
             }
-            if k == 0 {
-                break;
-            }
-            k = k.overflowing_sub(1).0;
         }
+
+        // 10. Return accumulator.
         Ok(accumulator)
     }
-
     /// `Array.prototype.copyWithin ( target, start [ , end ] )`
     ///
     /// The copyWithin() method shallow copies part of an array to another location
@@ -1592,49 +2363,34 @@ impl Array {
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        enum Direction {
-            Forward,
-            Backward,
-        }
-        let this: Value = this.to_object(context)?.into();
+        let mut o = this.to_object(context)?;
+        let this: Value = o.clone().into();
 
         let length = this.get_field("length", context)?.to_length(context)?;
 
-        let mut to = Self::get_relative_start(context, args.get(0), length)?;
-        let mut from = Self::get_relative_start(context, args.get(1), length)?;
+        let to = Self::get_relative_start(context, args.get(0), length)?;
+        let from = Self::get_relative_start(context, args.get(1), length)?;
         let finale = Self::get_relative_end(context, args.get(2), length)?;
 
         // saturating sub accounts for the case from > finale, which would cause an overflow
         // can skip the check for length - to, because we assert to <= length in get_relative_start
         let count = (finale.saturating_sub(from)).min(length - to);
 
-        let direction = if from < to && to < from + count {
-            from += count - 1;
-            to += count - 1;
-            Direction::Backward
-        } else {
-            Direction::Forward
-        };
-
-        // the original spec uses a while-loop from count to 1,
-        // but count is not used inside the loop, so we can safely replace it
-        // with a for-loop from 0 to count - 1
-        for _ in 0..count {
-            if this.has_field(from) {
+        // When the target range starts inside the source range, the elements are copied from
+        // the last one to the first one, so that the source isn't overwritten before it's read.
+        let backward = from < to && to < from + count;
+
+        // the original spec uses a while-loop from count to 1, moving `from` and `to` by one
+        // in each iteration, but here the offset of both is computed from the iteration
+        // instead, so that they never step out of the range
+        for i in 0..count {
+            let offset = if backward { count - 1 - i } else { i };
+            let (from, to) = (from + offset, to + offset);
+            if o.has_property(&from.into(), context)? {
                 let val = this.get_field(from, context)?;
                 this.set_field(to, val, true, context)?;
             } else {
-                this.remove_property(to);
-            }
-            match direction {
-                Direction::Forward => {
-                    from += 1;
-                    to += 1;
-                }
-                Direction::Backward => {
-                    from -= 1;
-                    to -= 1;
-                }
+                o.delete_property_or_throw(to, context)?;
             }
         }
 
@@ -1652,9 +2408,13 @@ impl Array {
     /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.values
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/values
     pub(crate) fn values(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 2. Return CreateArrayIterator(O, value).
         Ok(ArrayIterator::create_array_iterator(
             context,
-            this.clone(),
+            o.into(),
             ArrayIterationKind::Value,
         ))
     }
@@ -1667,12 +2427,16 @@ impl Array {
     ///  - [ECMAScript reference][spec]
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.values
-    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/values
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.keys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/keys
     pub(crate) fn keys(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 2. Return CreateArrayIterator(O, key).
         Ok(ArrayIterator::create_array_iterator(
             context,
-            this.clone(),
+            o.into(),
             ArrayIterationKind::Key,
         ))
     }
@@ -1685,18 +2449,22 @@ impl Array {
     ///  - [ECMAScript reference][spec]
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.values
-    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/values
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/entries
     pub(crate) fn entries(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? ToObject(this value).
+        let o = this.to_object(context)?;
+
+        // 2. Return CreateArrayIterator(O, key+value).
         Ok(ArrayIterator::create_array_iterator(
             context,
-            this.clone(),
+            o.into(),
             ArrayIterationKind::KeyAndValue,
         ))
     }
 
     /// Represents the algorithm to calculate `relativeStart` (or `k`) in array functions.
-    pub(super) fn get_relative_start(
+    pub(crate) fn get_relative_start(
         context: &mut Context,
         arg: Option<&Value>,
         len: usize,
@@ -1726,7 +2494,7 @@ impl Array {
     }
 
     /// Represents the algorithm to calculate `relativeEnd` (or `final`) in array functions.
-    pub(super) fn get_relative_end(
+    pub(crate) fn get_relative_end(
         context: &mut Context,
         arg: Option<&Value>,
         len: usize,
@@ -66,6 +66,27 @@ fn is_array() {
         context.eval("Array.isArray({ length: 0 })").unwrap(),
         Value::Boolean(false)
     );
+    assert_eq!(
+        context.eval("Array.isArray(Array.prototype)").unwrap(),
+        Value::Boolean(true)
+    );
+}
+
+#[test]
+fn is_array_proxy() {
+    let mut context = Context::new();
+    let init = r#"
+        var proxy = new Proxy([1, 2], {});
+        var nested = new Proxy(proxy, {});
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "Array.isArray(proxy)"), "true");
+    assert_eq!(forward(&mut context, "Array.isArray(nested)"), "true");
+    assert_eq!(
+        forward(&mut context, "Array.isArray(new Proxy({ length: 0 }, {}))"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "[0, nested].flat().length"), "3");
 }
 
 #[test]
@@ -115,6 +136,149 @@ fn of() {
     assert_eq!(context.eval("a[1]").unwrap(), Value::undefined());
     assert_eq!(context.eval("a[2]").unwrap(), Value::from(3));
     assert_eq!(context.eval("a.length").unwrap(), Value::from(3));
+
+    assert_eq!(forward(&mut context, "Array.of(7).length"), "1");
+    assert_eq!(forward(&mut context, "Array.of(7)[0]"), "7");
+    assert_eq!(forward(&mut context, "Array(7).length"), "7");
+    assert_eq!(forward(&mut context, "Array.of().length"), "0");
+    assert_eq!(forward(&mut context, "Array.isArray(Array.of())"), "true");
+    assert_eq!(forward(&mut context, "Array.of.length"), "0");
+
+    let subclass = r#"
+        function List(length) { this.created = length; }
+        List.of = Array.of;
+        let list = List.of(1, 2, 3);
+        list instanceof List && list.created === 3 && list.length === 3 && list[2] === 3
+    "#;
+    assert_eq!(forward(&mut context, subclass), "true");
+
+    let frozen = r#"
+        function Frozen() { return Object.freeze([]); }
+        try {
+            Array.of.call(Frozen, 1);
+        } catch (e) {
+            e instanceof TypeError
+        }
+    "#;
+    assert_eq!(forward(&mut context, frozen), "true");
+}
+
+#[test]
+fn from() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "Array.from('abc').join()"),
+        "\"a,b,c\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from({ length: 3 }, (_, i) => i).join()"
+        ),
+        "\"0,1,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "Array.from(new Set([1, 2, 2, 3])).join()"),
+        "\"1,2,3\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from({ length: 2, 0: 'x', 1: 'y' }).join()"
+        ),
+        "\"x,y\""
+    );
+    assert_eq!(
+        forward(&mut context, "Array.isArray(Array.from([]))"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Array.from(5).length"), "0");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Array.from(null) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Array.from([], 1) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn from_with_map_fn_and_this_arg() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var calls = [];
+        var scale = { factor: 10 };
+        var mapped = Array.from([1, 2, 3], function (value, index) {
+            calls.push(index);
+            return value * this.factor;
+        }, scale);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "mapped.join()"), "\"10,20,30\"");
+    assert_eq!(forward(&mut context, "calls.join()"), "\"0,1,2\"");
+}
+
+#[test]
+fn from_closes_iterator_on_map_error() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var closed = false;
+        var iterable = {
+            [Symbol.iterator]() {
+                return {
+                    next() { return { value: 1, done: false }; },
+                    return() { closed = true; return {}; },
+                };
+            },
+        };
+        var error;
+        try {
+            Array.from(iterable, () => { throw "map failed"; });
+        } catch (e) {
+            error = e;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "error"), "\"map failed\"");
+    assert_eq!(forward(&mut context, "closed"), "true");
+}
+
+#[test]
+fn from_with_constructor() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function Container(length) { this.constructedWith = length; }
+        var fromArrayLike = Array.from.call(Container, { length: 2, 0: "a", 1: "b" });
+        var fromIterable = Array.from.call(Container, "xy");
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "fromArrayLike instanceof Container"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "fromArrayLike.constructedWith"), "2");
+    assert_eq!(forward(&mut context, "fromArrayLike.length"), "2");
+    assert_eq!(forward(&mut context, "fromArrayLike[1]"), "\"b\"");
+    assert_eq!(
+        forward(&mut context, "fromIterable.constructedWith"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut context, "fromIterable.length"), "2");
+    assert_eq!(forward(&mut context, "fromIterable[0]"), "\"x\"");
 }
 
 #[ignore]
@@ -156,6 +320,22 @@ fn concat() {
     assert_eq!(nn, "a.b.c");
 }
 
+#[test]
+fn concat_spreads_only_arrays() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "[1].concat(2, [3], 'ab').join()"),
+        "\"1,2,3,ab\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1].concat({ length: 1, 0: 2, [Symbol.isConcatSpreadable]: true }).join()"
+        ),
+        "\"1,2\""
+    );
+}
+
 #[test]
 fn copy_within() {
     let mut context = Context::new();
@@ -171,6 +351,36 @@ fn copy_within() {
 
     let negatives = forward(&mut context, "[1,2,3,4,5].copyWithin(-2, -3, -1).join('.')");
     assert_eq!(negatives, String::from("\"1.2.3.3.4\""));
+
+    let clamped = forward(
+        &mut context,
+        "[1,2,3,4,5].copyWithin(-10, -Infinity, 10).join('.')",
+    );
+    assert_eq!(clamped, String::from("\"1.2.3.4.5\""));
+
+    let empty = forward(&mut context, "[1,2,3,4,5].copyWithin(0, 4, 2).join('.')");
+    assert_eq!(empty, String::from("\"1.2.3.4.5\""));
+}
+
+#[test]
+fn copy_within_overlapping() {
+    let mut context = Context::new();
+
+    // The target starts inside the source, so the elements are copied from the end.
+    let backward = forward(&mut context, "[1,2,3,4,5].copyWithin(1, 0).join('.')");
+    assert_eq!(backward, String::from("\"1.1.2.3.4\""));
+
+    let backward_end = forward(&mut context, "[1,2,3,4,5].copyWithin(2, 0, 4).join('.')");
+    assert_eq!(backward_end, String::from("\"1.2.1.2.3\""));
+
+    let forward_copy = forward(&mut context, "[1,2,3,4,5].copyWithin(0, 1).join('.')");
+    assert_eq!(forward_copy, String::from("\"2.3.4.5.5\""));
+
+    // Holes in the source are copied as holes.
+    forward(&mut context, "var holes = [1, 2, 3, 4]; delete holes[1];");
+    forward(&mut context, "holes.copyWithin(2, 0);");
+    assert_eq!(forward(&mut context, "holes[2]"), "1");
+    assert_eq!(forward(&mut context, "holes.hasOwnProperty(3)"), "false");
 }
 
 #[test]
@@ -193,6 +403,32 @@ fn join() {
     assert_eq!(many, String::from("\"a.b.c\""));
 }
 
+#[test]
+fn join_separator_and_nullish_elements() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "[1, null, 3].join('-')"), "\"1--3\"");
+    assert_eq!(forward(&mut context, "[1, 2].join()"), "\"1,2\"");
+    assert_eq!(forward(&mut context, "[1, 2].join(undefined)"), "\"1,2\"");
+    assert_eq!(
+        forward(&mut context, "[undefined, 1, null].join()"),
+        "\",1,\""
+    );
+    assert_eq!(forward(&mut context, "[1, 2, 3].join(0)"), "\"10203\"");
+    assert_eq!(forward(&mut context, "[1, 2].join(null)"), "\"1null2\"");
+    assert_eq!(forward(&mut context, "[1, , 3].join()"), "\"1,,3\"");
+    assert_eq!(
+        forward(&mut context, "[1, [2, [3, null]], 4].join(';')"),
+        "\"1;2,3,;4\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, 2].join({ toString() { return ' and '; } })"
+        ),
+        "\"1 and 2\""
+    );
+}
+
 #[test]
 fn to_string() {
     let mut context = Context::new();
@@ -296,6 +532,71 @@ fn find_index() {
     assert_eq!(missing, String::from("-1"));
 }
 
+#[test]
+fn find_last() {
+    let mut context = Context::new();
+
+    let code = r#"
+        function isEven(item) {
+            return item % 2 == 0;
+        }
+        var many = [1, 2, 3, 4, 5];
+        var odd = [1, 3, 5];
+        "#;
+
+    forward(&mut context, code);
+
+    assert_eq!(forward(&mut context, "many.findLast(isEven)"), "4");
+    assert_eq!(forward(&mut context, "odd.findLast(isEven)"), "undefined");
+    assert_eq!(forward(&mut context, "[].findLast(isEven)"), "undefined");
+}
+
+#[test]
+fn find_last_index() {
+    let mut context = Context::new();
+
+    let code = r#"
+        function isEven(item) {
+            return item % 2 == 0;
+        }
+        var many = [1, 2, 3, 4, 5];
+        var odd = [1, 3, 5];
+        "#;
+
+    forward(&mut context, code);
+
+    assert_eq!(forward(&mut context, "many.findLastIndex(isEven)"), "3");
+    assert_eq!(forward(&mut context, "odd.findLastIndex(isEven)"), "-1");
+    assert_eq!(forward(&mut context, "[].findLastIndex(isEven)"), "-1");
+}
+
+#[test]
+fn find_last_iteration_order() {
+    let mut context = Context::new();
+
+    let code = r#"
+        var visited = [];
+        var arr = [1, 2];
+        arr[4] = 5;
+        arr.findLast(function (item, index, array) {
+            visited.push(index + ":" + item + ":" + (array === arr));
+        });
+        var indices = [];
+        arr.findLastIndex(function (item, index) {
+            indices.push(index);
+            return index == 3;
+        });
+        "#;
+
+    forward(&mut context, code);
+
+    assert_eq!(
+        forward(&mut context, "visited.join()"),
+        "\"4:5:true,3:undefined:true,2:undefined:true,1:2:true,0:1:true\""
+    );
+    assert_eq!(forward(&mut context, "indices.join()"), "\"4,3\"");
+}
+
 #[test]
 fn flat() {
     let mut context = Context::new();
@@ -348,6 +649,44 @@ fn flat_infinity() {
     assert_eq!(forward(&mut context, "flat_arr.length"), "1");
 }
 
+#[test]
+fn flat_depth_argument() {
+    let mut context = Context::new();
+
+    let code = r#"
+        var nested = [1, [2, [3, [4]]]];
+        "#;
+    forward(&mut context, code);
+
+    assert_eq!(forward(&mut context, "nested.flat(undefined).length"), "3");
+    assert_eq!(forward(&mut context, "nested.flat(0).length"), "2");
+    assert_eq!(forward(&mut context, "nested.flat(-Infinity).length"), "2");
+    assert_eq!(forward(&mut context, "nested.flat(Infinity).length"), "4");
+    assert_eq!(forward(&mut context, "nested.flat(Infinity)[3]"), "4");
+    assert_eq!(forward(&mut context, "Array.prototype.flat.length"), "0");
+}
+
+#[test]
+fn flat_sparse() {
+    let mut context = Context::new();
+
+    let code = r#"
+        var inner = [2, 0, 3];
+        delete inner[1];
+        var sparse = [1, 0, inner, undefined];
+        delete sparse[1];
+        var flat_sparse = sparse.flat();
+        "#;
+    forward(&mut context, code);
+
+    assert_eq!(forward(&mut context, "flat_sparse.length"), "4");
+    assert_eq!(forward(&mut context, "flat_sparse[0]"), "1");
+    assert_eq!(forward(&mut context, "flat_sparse[1]"), "2");
+    assert_eq!(forward(&mut context, "flat_sparse[2]"), "3");
+    assert_eq!(forward(&mut context, "3 in flat_sparse"), "true");
+    assert_eq!(forward(&mut context, "flat_sparse[3]"), "undefined");
+}
+
 #[test]
 fn flat_map() {
     let mut context = Context::new();
@@ -389,6 +728,46 @@ fn flat_map_with_hole() {
     assert_eq!(forward(&mut context, "arr_flattened.length"), "2");
 }
 
+#[test]
+fn flat_map_callback_arguments() {
+    let mut context = Context::new();
+
+    let code = r#"
+        var arr = ['a', 'b'];
+        var calls = [];
+        var thisArg = { prefix: '_' };
+        var result = arr.flatMap(function (element, index, array) {
+            calls.push(array === arr && this === thisArg);
+            return this.prefix + element + index;
+        }, thisArg);
+        var this_value = [1].flatMap(function () { return this; })[0];
+    "#;
+    forward(&mut context, code);
+
+    assert_eq!(forward(&mut context, "calls.length"), "2");
+    assert_eq!(forward(&mut context, "calls[0] && calls[1]"), "true");
+    assert_eq!(forward(&mut context, "result.length"), "2");
+    assert_eq!(forward(&mut context, "result[0]"), "\"_a0\"");
+    assert_eq!(forward(&mut context, "result[1]"), "\"_b1\"");
+    assert_eq!(forward(&mut context, "this_value === arr"), "false");
+    assert_eq!(forward(&mut context, "Array.prototype.flatMap.length"), "1");
+}
+
+#[test]
+fn flat_map_non_array_result() {
+    let mut context = Context::new();
+
+    let code = r#"
+        var result = [1, 2].flatMap(i => i % 2 ? i : [[i]]);
+    "#;
+    forward(&mut context, code);
+
+    assert_eq!(forward(&mut context, "result.length"), "2");
+    assert_eq!(forward(&mut context, "result[0]"), "1");
+    assert_eq!(forward(&mut context, "Array.isArray(result[1])"), "true");
+    assert_eq!(forward(&mut context, "result[1][0]"), "2");
+}
+
 #[test]
 fn flat_map_not_callable() {
     let mut context = Context::new();
@@ -489,6 +868,89 @@ fn reverse() {
     assert_eq!(forward(&mut context, "arr[1]"), "1");
 }
 
+#[test]
+fn sort() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "[10, 9, 1].sort().join()"),
+        "\"1,10,9\""
+    );
+    assert_eq!(
+        forward(&mut context, r#"["b", "a", "B", "c"].sort().join()"#),
+        "\"B,a,b,c\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[10, 9, 1].sort(function (a, b) { return a - b; }).join()"
+        ),
+        "\"1,9,10\""
+    );
+    assert_eq!(
+        forward(&mut context, "var arr = [2, 1]; arr.sort() === arr"),
+        "true"
+    );
+}
+
+#[test]
+fn sort_is_stable() {
+    let mut context = Context::new();
+    let init = r#"
+        var items = [
+            { key: 1, value: "a" },
+            { key: 0, value: "b" },
+            { key: 1, value: "c" },
+            { key: 0, value: "d" },
+            { key: 1, value: "e" },
+        ];
+        items.sort(function (x, y) {
+            return x.key - y.key;
+        });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "items.map(function (item) { return item.value; }).join()"
+        ),
+        "\"b,d,a,c,e\""
+    );
+}
+
+#[test]
+fn sort_undefined_and_holes() {
+    let mut context = Context::new();
+    let init = r#"
+        var calls = 0;
+        var arr = [3, undefined, 1];
+        arr[5] = 2;
+        arr.sort(function (a, b) {
+            calls++;
+            return a - b;
+        });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "arr.length"), "6");
+    assert_eq!(forward(&mut context, "arr.slice(0, 3).join()"), "\"1,2,3\"");
+    assert_eq!(forward(&mut context, "3 in arr"), "true");
+    assert_eq!(forward(&mut context, "arr[3] === undefined"), "true");
+    assert_eq!(forward(&mut context, "4 in arr || 5 in arr"), "false");
+    assert_eq!(forward(&mut context, "calls > 0"), "true");
+}
+
+#[test]
+fn sort_invalid_comparator() {
+    let mut context = Context::new();
+    let init = r#"
+        try {
+            [1, 2].sort(1);
+        } catch (e) {
+            e instanceof TypeError;
+        }
+        "#;
+    assert_eq!(forward(&mut context, init), "true");
+}
+
 #[test]
 fn index_of() {
     let mut context = Context::new();
@@ -615,6 +1077,42 @@ fn last_index_of() {
     assert_eq!(second_in_many, String::from("1"));
 }
 
+#[test]
+fn fill_generic() {
+    let mut context = Context::new();
+
+    forward(
+        &mut context,
+        "var o = { length: 3 }; Array.prototype.fill.call(o, 'x', 1);",
+    );
+    assert_eq!(forward(&mut context, "o[0]"), "undefined");
+    assert_eq!(forward(&mut context, "o[1] + o[2]"), "\"xx\"");
+
+    // The elements are set, which calls setters and fails on read-only elements.
+    forward(
+        &mut context,
+        r#"
+        var set = [];
+        var a = [1, 2];
+        Object.defineProperty(a, 0, { set: function(value) { set.push(value); } });
+        a.fill(3);
+        "#,
+    );
+    assert_eq!(forward(&mut context, "set.join()"), "\"3\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            var b = [1, 2];
+            Object.defineProperty(b, 1, { value: 2, writable: false });
+            try { b.fill(0); } catch (e) { e.name }
+            "#
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "b[0]"), "0");
+}
+
 #[test]
 fn fill_obj_ref() {
     let mut context = Context::new();
@@ -667,6 +1165,18 @@ fn fill() {
         String::from("\"4,2,3\"")
     );
 
+    forward(&mut context, "a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.fill(0, -2).join()"),
+        String::from("\"1,2,3,0,0\"")
+    );
+
+    forward(&mut context, "a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.fill(0, -10, -3).join()"),
+        String::from("\"0,0,3,4,5\"")
+    );
+
     forward(&mut context, "a = [1, 2, 3];");
     assert_eq!(
         forward(&mut context, "a.fill(4, NaN, NaN).join()"),
@@ -711,7 +1221,7 @@ fn fill() {
 
     assert_eq!(
         forward(&mut context, "a.fill().join()"),
-        String::from("\"undefined,undefined,undefined\"")
+        String::from("\",,\"")
     );
 
     // test object reference
@@ -758,6 +1268,47 @@ fn includes_value() {
     assert_eq!(second_in_many, String::from("false"));
 }
 
+#[test]
+fn includes_same_value_zero() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "[NaN].includes(NaN)"), "true");
+    assert_eq!(forward(&mut context, "[NaN].indexOf(NaN)"), "-1");
+    assert_eq!(forward(&mut context, "[0].includes(-0)"), "true");
+    assert_eq!(forward(&mut context, "[-0].includes(0)"), "true");
+    assert_eq!(forward(&mut context, "[1, 2, 3].includes('2')"), "false");
+    assert_eq!(forward(&mut context, "[, ].includes(undefined)"), "true");
+    assert_eq!(forward(&mut context, "[].includes(undefined)"), "false");
+}
+
+#[test]
+fn includes_from_index() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "[1, 2, 3].includes(2, 2)"), "false");
+    assert_eq!(forward(&mut context, "[1, 2, 3].includes(3, 2)"), "true");
+    assert_eq!(forward(&mut context, "[1, 2, 3].includes(1, -1)"), "false");
+    assert_eq!(forward(&mut context, "[1, 2, 3].includes(2, -2)"), "true");
+    assert_eq!(forward(&mut context, "[1, 2, 3].includes(1, -10)"), "true");
+    assert_eq!(forward(&mut context, "[1, 2, 3].includes(1, 10)"), "false");
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3].includes(3, Infinity)"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3].includes(1, -Infinity)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "[1, 2, 3].includes(2, 1.9)"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype.includes.call({ length: 2, 1: 'b' }, 'b')"
+        ),
+        "true"
+    );
+}
+
 #[test]
 fn map() {
     let mut context = Context::new();
@@ -847,6 +1398,73 @@ fn slice() {
     assert_eq!(forward(&mut context, "many3.length"), "0");
 }
 
+#[test]
+fn splice() {
+    let mut context = Context::new();
+
+    let replace = forward(
+        &mut context,
+        "var a = [1, 2, 3, 4, 5]; var r = a.splice(1, 2, 'x'); a.join('.') + '|' + r.join('.')",
+    );
+    assert_eq!(replace, "\"1.x.4.5|2.3\"");
+
+    let insert = forward(
+        &mut context,
+        "var a = [1, 2, 3]; var r = a.splice(1, 0, 'x', 'y'); a.join('.') + '|' + r.length",
+    );
+    assert_eq!(insert, "\"1.x.y.2.3|0\"");
+
+    let to_end = forward(
+        &mut context,
+        "var a = [1, 2, 3]; var r = a.splice(-2); a.join('.') + '|' + r.join('.')",
+    );
+    assert_eq!(to_end, "\"1|2.3\"");
+
+    let nothing = forward(
+        &mut context,
+        "var a = [1, 2, 3]; var r = a.splice(); a.join('.') + '|' + r.length",
+    );
+    assert_eq!(nothing, "\"1.2.3|0\"");
+
+    // Holes in the removed range stay holes in the returned array.
+    let holes = forward(
+        &mut context,
+        "var a = [1, , 3]; var r = a.splice(0, 2); r.length + '|' + (1 in r) + '|' + a.join('.')",
+    );
+    assert_eq!(holes, "\"2|false|3\"");
+}
+
+#[test]
+fn mutators_throw_on_frozen_array() {
+    let mut context = Context::new();
+    let init = r#"
+        var frozen = Object.freeze([1, 2]);
+        function mutate(method) {
+            var args = Array.prototype.slice.call(arguments, 1);
+            try {
+                frozen[method].apply(frozen, args);
+                return "no error";
+            } catch (e) {
+                return e.constructor.name;
+            }
+        }
+        "#;
+    forward(&mut context, init);
+
+    for call in &[
+        "mutate('push', 3)",
+        "mutate('pop')",
+        "mutate('shift')",
+        "mutate('unshift', 0)",
+        "mutate('splice', 0, 1)",
+        "mutate('fill', 0)",
+    ] {
+        assert_eq!(forward(&mut context, call), "\"TypeError\"", "{}", call);
+    }
+    assert_eq!(forward(&mut context, "frozen.join('.')"), "\"1.2\"");
+    assert_eq!(forward(&mut context, "frozen.length"), "2");
+}
+
 #[test]
 fn for_each() {
     let mut context = Context::new();
@@ -1237,6 +1855,84 @@ fn reduce_right() {
     assert_eq!(result, "\"reduceRight was called without a callback\"");
 }
 
+#[test]
+fn reduce_initial_value_and_holes() {
+    let mut context = Context::new();
+    let init = r#"
+        function log(calls) {
+            return function (acc, cur, index, array) {
+                calls.push(acc + ":" + cur + ":" + index + ":" + (array === sparse));
+                return cur;
+            };
+        }
+        var sparse = [];
+        sparse[1] = 1;
+        sparse[3] = 2;
+        sparse.length = 5;
+        var left = [];
+        var right = [];
+        sparse.reduce(log(left));
+        sparse.reduceRight(log(right));
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "left.join()"),
+        "\"1:2:3:true\"",
+        "holes are skipped, and the first element present is the initial accumulator"
+    );
+    assert_eq!(forward(&mut context, "right.join()"), "\"2:1:1:true\"");
+
+    // An initial value that is explicitly `undefined` is still an initial value.
+    assert_eq!(
+        forward(&mut context, "[].reduce(log([]), undefined)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "[].reduceRight(log([]), undefined)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, 2].reduce((acc, cur) => acc + ',' + cur, undefined)"
+        ),
+        "\"undefined,1,2\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[1, 2].reduceRight((acc, cur) => acc + ',' + cur, undefined)"
+        ),
+        "\"undefined,2,1\""
+    );
+
+    // Arrays with only holes have no initial accumulator.
+    for source in &[
+        "new Array(2).reduce((acc, cur) => cur)",
+        "new Array(2).reduceRight((acc, cur) => cur)",
+    ] {
+        let source = format!(
+            "try {{ {} }} catch (e) {{ e instanceof TypeError }}",
+            source
+        );
+        assert_eq!(forward(&mut context, &source), "true", "{}", source);
+    }
+
+    // Array-like objects are reduced in index order, and the callback is checked before the
+    // initial accumulator is searched for.
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype.reduceRight.call({ length: 3, 0: 'a', 2: 'c' }, (acc, cur) => acc + cur)"
+        ),
+        "\"ca\""
+    );
+    assert_eq!(
+        forward(&mut context, "try { [].reduce() } catch (e) { e.message }"),
+        "\"Reduce was called without a callback\""
+    );
+}
+
 #[test]
 fn call_array_constructor_with_one_argument() {
     let mut context = Context::new();
@@ -1390,6 +2086,103 @@ fn array_values_symbol_iterator() {
     assert_eq!(forward(&mut context, init), "true");
 }
 
+#[test]
+fn array_iterator_sees_pushed_elements() {
+    let mut context = Context::new();
+    let init = r#"
+        var array = [1, 2];
+        var seen = [];
+        for (var value of array) {
+            seen.push(value);
+            if (array.length < 4) {
+                array.push(value * 10);
+            }
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "seen.join()"), "\"1,2,10,20\"");
+
+    // Shrinking the array ends the iteration early.
+    let init = r#"
+        var array = [1, 2, 3, 4];
+        var seen = [];
+        for (var value of array) {
+            seen.push(value);
+            array.length = 2;
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "seen.join()"), "\"1,2\"");
+}
+
+#[test]
+fn array_iterator_done_is_final() {
+    let mut context = Context::new();
+    let init = r#"
+        var array = [1];
+        var iterator = array.values();
+        iterator.next();
+        iterator.next();
+        array.push(2);
+        var next = iterator.next();
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "next.value"), "undefined");
+    assert_eq!(forward(&mut context, "next.done"), "true");
+}
+
+#[test]
+fn array_entries_pairs() {
+    let mut context = Context::new();
+    let init = r#"
+        var array = ["a", "b"];
+        var pairs = [];
+        for (var entry of array.entries()) {
+            pairs.push(entry[0] + ":" + entry[1]);
+            if (entry[0] === 0) {
+                array[1] = "changed";
+            }
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "pairs.join()"), "\"0:a,1:changed\"");
+    assert_eq!(
+        forward(&mut context, "Array.isArray(array.entries().next().value)"),
+        "true"
+    );
+}
+
+#[test]
+fn array_iterator_array_like() {
+    let mut context = Context::new();
+    let init = r#"
+        var arrayLike = { length: "2", 0: "x", 1: "y" };
+        var values = [...Array.prototype.values.call(arrayLike)];
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "values.join()"), "\"x,y\"");
+    assert_eq!(
+        forward(&mut context, "[...Array.prototype.keys.call('ab')].join()"),
+        "\"0,1\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Array.prototype.entries.call(null) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+
+    // The getters of the array can use the iterator while it's iterating.
+    let init = r#"
+        var iterator;
+        var reentrant = { length: 2, get 0() { return iterator.next().value; }, 1: "y" };
+        iterator = Array.prototype.values.call(reentrant);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "iterator.next().value"), "\"y\"");
+}
+
 #[test]
 fn array_spread_arrays() {
     let mut context = Context::new();
@@ -1544,3 +2337,174 @@ fn array_length_is_not_enumerable() {
     let desc = array.get_property("length").unwrap();
     assert!(!desc.enumerable());
 }
+
+#[test]
+fn at() {
+    let mut context = Context::new();
+    let init = r#"
+        var array = [1, 2, 3];
+        var arrayLike = { length: 2, 0: 'a', 1: 'b' };
+    "#;
+    eprintln!("{}", forward(&mut context, init));
+
+    assert_eq!(forward(&mut context, "array.at(0)"), "1");
+    assert_eq!(forward(&mut context, "array.at(-1)"), "3");
+    assert_eq!(forward(&mut context, "array.at(-3)"), "1");
+    assert_eq!(forward(&mut context, "array.at(3)"), "undefined");
+    assert_eq!(forward(&mut context, "array.at(-4)"), "undefined");
+    assert_eq!(forward(&mut context, "array.at(-Infinity)"), "undefined");
+    assert_eq!(forward(&mut context, "array.at()"), "1");
+    assert_eq!(forward(&mut context, "array.at(1.7)"), "2");
+    assert_eq!(forward(&mut context, "[].at(0)"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Array.prototype.at.call(arrayLike, -1)"),
+        "\"b\""
+    );
+    assert_eq!(forward(&mut context, "Array.prototype.at.length"), "1");
+}
+
+#[test]
+fn length_truncation_deletes_elements() {
+    let mut context = Context::new();
+    let init = r#"
+        var arr = [1, 2, 3, 4, 5];
+        arr.length = 2;
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "arr.length"), "2");
+    assert_eq!(forward(&mut context, "arr[2]"), "undefined");
+    assert_eq!(forward(&mut context, "2 in arr"), "false");
+    assert_eq!(forward(&mut context, "4 in arr"), "false");
+    assert_eq!(forward(&mut context, "arr.join()"), "\"1,2\"");
+    assert_eq!(forward(&mut context, "arr.length = 4; 2 in arr"), "false");
+    assert_eq!(forward(&mut context, "arr.length"), "4");
+}
+
+#[test]
+fn length_grows_with_index_assignment() {
+    let mut context = Context::new();
+    let init = r#"
+        var arr = [1, 2];
+        arr[5] = 6;
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "arr.length"), "6");
+    assert_eq!(forward(&mut context, "3 in arr"), "false");
+    assert_eq!(forward(&mut context, "arr[1] = 'b'; arr.length"), "6");
+    assert_eq!(forward(&mut context, "arr['7'] = 8; arr.length"), "8");
+    assert_eq!(forward(&mut context, "arr.foo = 1; arr.length"), "8");
+}
+
+#[test]
+fn invalid_length_throws_range_error() {
+    let mut context = Context::new();
+    forward(&mut context, "var arr = [1, 2, 3];");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { arr.length = -1 } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { arr.length = 1.5 } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { arr.length = 4294967296 } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "arr.length"), "3");
+    assert_eq!(forward(&mut context, "arr.length = '1'; arr.length"), "1");
+}
+
+#[test]
+fn sparse_iteration_skips_holes() {
+    let mut context = Context::new();
+    let init = r#"
+        var sparse = [1, , 3];
+        var forEachIndices = [];
+        sparse.forEach((value, index) => forEachIndices.push(index));
+        var mapCalls = 0;
+        var mapped = sparse.map(value => { mapCalls++; return value * 2; });
+        var filterCalls = 0;
+        var filtered = sparse.filter(value => { filterCalls++; return true; });
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "forEachIndices.join()"), "\"0,2\"");
+    assert_eq!(forward(&mut context, "mapCalls"), "2");
+    assert_eq!(forward(&mut context, "mapped.length"), "3");
+    assert_eq!(forward(&mut context, "1 in mapped"), "false");
+    assert_eq!(forward(&mut context, "mapped[0] + mapped[2]"), "8");
+    assert_eq!(forward(&mut context, "filterCalls"), "2");
+    assert_eq!(forward(&mut context, "filtered.length"), "2");
+    assert_eq!(forward(&mut context, "filtered.join()"), "\"1,3\"");
+}
+
+#[test]
+fn map_and_for_each_pass_the_source_object() {
+    let mut context = Context::new();
+    let init = r#"
+        var source = [1, 2];
+        var lists = [];
+        source.forEach((value, index, list) => lists.push(list === source));
+        source.map((value, index, list) => lists.push(list === source));
+        var arrayLike = { length: 3, 0: "a", 2: "c" };
+        var mappedLike = Array.prototype.map.call(arrayLike, value => value.toUpperCase());
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "lists.join()"),
+        "\"true,true,true,true\""
+    );
+    assert_eq!(forward(&mut context, "Array.isArray(mappedLike)"), "true");
+    assert_eq!(forward(&mut context, "mappedLike.length"), "3");
+    assert_eq!(forward(&mut context, "1 in mappedLike"), "false");
+    assert_eq!(forward(&mut context, "mappedLike[2]"), "\"C\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { [1].map(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { [1].forEach() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn delete_element_leaves_hole() {
+    let mut context = Context::new();
+    let init = r#"
+        var arr = [1, 2, 3];
+        var deleted = delete arr[1];
+        var last = [1, 2, 3];
+        delete last[2];
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "deleted"), "true");
+    assert_eq!(forward(&mut context, "arr.length"), "3");
+    assert_eq!(forward(&mut context, "1 in arr"), "false");
+    assert_eq!(forward(&mut context, "arr[1]"), "undefined");
+    assert_eq!(forward(&mut context, "arr[2]"), "3");
+    assert_eq!(forward(&mut context, "Object.keys(arr).join()"), "\"0,2\"");
+    assert_eq!(forward(&mut context, "last.length"), "3");
+    assert_eq!(forward(&mut context, "2 in last"), "false");
+    assert_eq!(forward(&mut context, "delete [1, 2, 3][1]"), "true");
+    assert_eq!(forward(&mut context, "delete arr.length"), "false");
+    assert_eq!(forward(&mut context, "arr.length"), "3");
+}
@@ -0,0 +1,238 @@
+//! This module implements the global `ArrayBuffer` object.
+//!
+//! An `ArrayBuffer` is a fixed-length buffer of raw binary data. Its contents can't be accessed
+//! directly, but through typed array views that interpret the bytes as numbers.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-arraybuffer-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer
+
+use crate::{
+    builtins::{Array, BuiltIn},
+    gc::{Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The largest buffer that can be allocated, in bytes.
+const MAX_BYTE_LENGTH: usize = u32::MAX as usize;
+
+/// The internal representation of an `ArrayBuffer` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ArrayBuffer {
+    data: Vec<u8>,
+}
+
+impl ArrayBuffer {
+    /// The bytes of the buffer.
+    #[inline]
+    pub fn bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// The bytes of the buffer, mutably.
+    #[inline]
+    pub fn bytes_mut(&mut self) -> &mut [u8] {
+        &mut self.data
+    }
+
+    /// The length of the buffer, in bytes.
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        self.data.len()
+    }
+}
+
+impl BuiltIn for ArrayBuffer {
+    const NAME: &'static str = "ArrayBuffer";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let byte_length_getter = FunctionBuilder::new(context, Self::byte_length_getter)
+            .name("get byteLength")
+            .constructable(false)
+            .callable(true)
+            .build();
+
+        let array_buffer_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().array_buffer_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::is_view, "isView", 1)
+        .accessor(
+            "byteLength",
+            Some(byte_length_getter),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::slice, "slice", 2)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        (Self::NAME, array_buffer_object.into(), Self::attribute())
+    }
+}
+
+impl ArrayBuffer {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// `ArrayBuffer( length )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer-length
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin ArrayBuffer constructor without new is forbidden",
+            );
+        }
+        let byte_length = args.get(0).cloned().unwrap_or_default().to_index(context)?;
+
+        let array_buffer_prototype = context.standard_objects().array_buffer_object().prototype();
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or(array_buffer_prototype);
+
+        let mut buffer = Self::allocate(byte_length, context)?;
+        buffer.set_prototype_instance(prototype.into());
+        Ok(buffer.into())
+    }
+
+    /// Creates a new `ArrayBuffer` object of `byte_length` zeroed bytes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatearraybuffer
+    pub(crate) fn allocate(byte_length: usize, context: &mut Context) -> Result<GcObject> {
+        Self::from_bytes(
+            vec![0; Self::check_byte_length(byte_length, context)?],
+            context,
+        )
+    }
+
+    /// Creates a new `ArrayBuffer` object holding `bytes`.
+    pub(crate) fn from_bytes(bytes: Vec<u8>, context: &mut Context) -> Result<GcObject> {
+        let mut buffer = context.construct_object();
+        buffer.set_prototype_instance(
+            context
+                .standard_objects()
+                .array_buffer_object()
+                .prototype()
+                .into(),
+        );
+        buffer.borrow_mut().data = ObjectData::ArrayBuffer(ArrayBuffer { data: bytes });
+        Ok(buffer)
+    }
+
+    /// Throws a `RangeError` if a buffer of `byte_length` bytes is too large to be allocated.
+    fn check_byte_length(byte_length: usize, context: &mut Context) -> Result<usize> {
+        if byte_length > MAX_BYTE_LENGTH {
+            return Err(context.construct_range_error("Array buffer allocation failed"));
+        }
+        Ok(byte_length)
+    }
+
+    /// `ArrayBuffer.isView( arg )`
+    ///
+    /// Returns `true` if the argument is a view of an `ArrayBuffer`, like a typed array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.isview
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/isView
+    pub(crate) fn is_view(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(args
+            .get(0)
+            .and_then(Value::as_object)
+            .map_or(false, |object| object.borrow().as_typed_array().is_some())
+            .into())
+    }
+
+    /// `get ArrayBuffer.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-arraybuffer.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/byteLength
+    pub(crate) fn byte_length_getter(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if let Some(object) = this.as_object() {
+            if let Some(buffer) = object.borrow().as_array_buffer() {
+                return Ok(buffer.byte_length().into());
+            }
+        }
+        context.throw_type_error("'this' is not an ArrayBuffer")
+    }
+
+    /// `ArrayBuffer.prototype.slice( start, end )`
+    ///
+    /// Returns a new `ArrayBuffer` with a copy of the bytes of this buffer from `start` up to,
+    /// but not including, `end`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arraybuffer.prototype.slice
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer/slice
+    pub(crate) fn slice(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = match this.as_object() {
+            Some(object) if object.borrow().as_array_buffer().is_some() => object,
+            _ => return context.throw_type_error("'this' is not an ArrayBuffer"),
+        };
+        let len = object
+            .borrow()
+            .as_array_buffer()
+            .map_or(0, ArrayBuffer::byte_length);
+
+        let first = Array::get_relative_start(context, args.get(0), len)?;
+        let last = Array::get_relative_end(context, args.get(1), len)?;
+
+        let bytes = object
+            .borrow()
+            .as_array_buffer()
+            .map(|buffer| buffer.bytes()[first..first.max(last)].to_vec())
+            .unwrap_or_default();
+        Ok(Self::from_bytes(bytes, context)?.into())
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{forward, Context};
+
+#[test]
+fn construct() {
+    let mut context = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var bytes = new Uint8Array(buffer);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "buffer.byteLength"), "8");
+    assert_eq!(
+        forward(&mut context, "[...bytes].join()"),
+        "\"0,0,0,0,0,0,0,0\""
+    );
+    assert_eq!(forward(&mut context, "new ArrayBuffer().byteLength"), "0");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(buffer)"),
+        "\"[object ArrayBuffer]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new ArrayBuffer(-1) } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { ArrayBuffer(8) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn slice() {
+    let mut context = Context::new();
+    let init = r#"
+        var buffer = new Uint8Array([1, 2, 3, 4, 5]).buffer;
+        var copy = buffer.slice(1, -1);
+        new Uint8Array(copy)[0] = 9;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "copy.byteLength"), "3");
+    assert_eq!(
+        forward(&mut context, "[...new Uint8Array(copy)].join()"),
+        "\"9,3,4\""
+    );
+    assert_eq!(
+        forward(&mut context, "[...new Uint8Array(buffer)].join()"),
+        "\"1,2,3,4,5\"",
+        "the slice is a copy of the bytes"
+    );
+    assert_eq!(forward(&mut context, "buffer.slice(3, 1).byteLength"), "0");
+    assert_eq!(forward(&mut context, "buffer.slice(-2).byteLength"), "2");
+}
+
+#[test]
+fn is_view() {
+    let mut context = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "ArrayBuffer.isView(new Float64Array(buffer))"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "ArrayBuffer.isView(buffer)"), "false");
+    assert_eq!(forward(&mut context, "ArrayBuffer.isView([])"), "false");
+    assert_eq!(forward(&mut context, "ArrayBuffer.isView()"), "false");
+}
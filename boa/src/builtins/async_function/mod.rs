@@ -0,0 +1,171 @@
+//! This module implements the execution of async functions.
+//!
+//! The body of an async function is executed like the body of a generator: an `await` expression
+//! suspends it, unwinding its evaluation, and once the awaited promise is settled a job replays
+//! the body up to that expression, which evaluates to the value of the promise or throws its
+//! reason.
+//!
+//! Calling an async function returns a promise, which is settled when its body completes.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-async-function-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function
+
+use crate::{
+    builtins::{
+        generator::{BodyCompletion, GeneratorContext, Resumption},
+        promise::{Promise, PromiseCapability},
+    },
+    environment::lexical_environment::Environment,
+    gc::{Finalize, Trace},
+    object::{FunctionBuilder, NativeObject},
+    syntax::ast::node::RcStatementList,
+    Context, Result, Value,
+};
+use gc::{Gc, GcCell};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a call to an async function, shared by the functions that resume it.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct AsyncFunction {
+    /// The execution context of the body, which is taken while it's executing, and dropped once
+    /// it's completed.
+    context: GcCell<Option<GeneratorContext>>,
+    /// The promise returned by the call.
+    capability: PromiseCapability,
+}
+
+impl AsyncFunction {
+    /// Starts the execution of the body of an async function, for a call whose environments were
+    /// prepared, and returns the promise settled by the body.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncfunctionstart
+    pub(crate) fn start(
+        function_name: Box<str>,
+        body: RcStatementList,
+        environments: Vec<Environment>,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let constructor = context.standard_objects().promise_object().constructor();
+        let capability = Promise::new_promise_capability(&constructor.into(), context)?;
+        let promise = capability.promise.clone();
+
+        let function = Gc::new(Self {
+            context: GcCell::new(None),
+            capability,
+        });
+        let generator_context = GeneratorContext::new(function_name, body, environments, true);
+        Self::step(&function, generator_context, None, context)?;
+        Ok(promise)
+    }
+
+    /// Executes the body until it awaits a promise, or settles the promise of the call once it
+    /// completes.
+    fn step(
+        function: &Gc<Self>,
+        mut generator_context: GeneratorContext,
+        resumption: Option<Resumption>,
+        context: &mut Context,
+    ) -> Result<()> {
+        match generator_context.resume(resumption, context) {
+            Ok(BodyCompletion::Await(promise)) => {
+                *function.context.borrow_mut() = Some(generator_context);
+                await_promise(
+                    &promise,
+                    function.clone(),
+                    |function, resumption, context| {
+                        let generator_context = function
+                            .context
+                            .borrow_mut()
+                            .take()
+                            .expect("a suspended async function has an execution context");
+                        Self::step(function, generator_context, Some(resumption), context)
+                    },
+                    context,
+                );
+                Ok(())
+            }
+            Ok(BodyCompletion::Return(value)) => context
+                .call(&function.capability.resolve, &Value::undefined(), &[value])
+                .map(|_| ()),
+            Ok(BodyCompletion::Yield { .. }) => {
+                unreachable!("yield expression in an async function")
+            }
+            Err(error) => context
+                .call(&function.capability.reject, &Value::undefined(), &[error])
+                .map(|_| ()),
+        }
+    }
+}
+
+/// The shared steps of the `await` expressions, which suspend the async function being executed
+/// at the given node, until the given value is settled.
+///
+/// This returns an error, which unwinds the body of the async function, unless the value can't
+/// be converted to a promise.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#await
+pub(crate) fn await_value<T>(node: &T, value: Value, context: &mut Context) -> Result<Value> {
+    let constructor = context.standard_objects().promise_object().constructor();
+    let promise = Promise::promise_resolve(&constructor.into(), value, context)?;
+    context
+        .executor()
+        .generator_frame()
+        .expect("await outside of an async function")
+        .suspend_await(node, promise);
+    Err(Value::undefined())
+}
+
+/// Calls `resume` once the given promise is settled, with the resumption of the expression that
+/// awaited it.
+pub(crate) fn await_promise<C>(
+    promise: &Value,
+    captures: C,
+    resume: fn(&C, Resumption, &mut Context) -> Result<()>,
+    context: &mut Context,
+) where
+    C: NativeObject + Clone,
+{
+    let promise = promise.as_object().expect("the awaited value is a promise");
+    let on_fulfilled = FunctionBuilder::closure_with_captures(
+        context,
+        move |_, args, captures: &C, context| {
+            let value = args.get(0).cloned().unwrap_or_default();
+            resume(captures, Resumption::Next(value), context)?;
+            Ok(Value::undefined())
+        },
+        captures.clone(),
+    )
+    .length(1)
+    .build();
+    let on_rejected = FunctionBuilder::closure_with_captures(
+        context,
+        move |_, args, captures: &C, context| {
+            let reason = args.get(0).cloned().unwrap_or_default();
+            resume(captures, Resumption::Throw(reason), context)?;
+            Ok(Value::undefined())
+        },
+        captures,
+    )
+    .length(1)
+    .build();
+
+    Promise::perform_promise_then(
+        &promise,
+        on_fulfilled.into(),
+        on_rejected.into(),
+        None,
+        context,
+    );
+}
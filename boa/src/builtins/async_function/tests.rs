@@ -0,0 +1,99 @@
+use crate::{forward, Context};
+
+#[test]
+fn returns_promise() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        let f = async function(x) {
+            log.push("body");
+            return x * 2;
+        };
+        let promise = f(21);
+        promise.then(function (value) {
+            log.push(value);
+        });
+        log.push("sync");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "promise instanceof Promise"), "true");
+    assert_eq!(forward(&mut context, "log.join()"), "\"body,sync,42\"");
+}
+
+#[test]
+fn await_suspends_body() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        let f = async function() {
+            log.push("start");
+            let a = await 1;
+            log.push("a = " + a);
+            let b = await Promise.resolve(a + 1);
+            log.push("b = " + b);
+        };
+        f();
+        log.push("sync");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"start,sync,a = 1,b = 2\""
+    );
+}
+
+#[test]
+fn await_rejection_throws() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        let f = async function() {
+            try {
+                await Promise.reject("reason");
+            } catch (e) {
+                log.push("caught " + e);
+            }
+            await Promise.reject("uncaught");
+        };
+        f().catch(function (e) {
+            log.push("rejected " + e);
+        });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"caught reason,rejected uncaught\""
+    );
+}
+
+#[test]
+fn thrown_error_rejects() {
+    let mut context = Context::new();
+    let init = r#"
+        let result;
+        let f = async function() {
+            throw new TypeError("thrown");
+        };
+        f().catch(function (e) {
+            result = e.message;
+        });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"thrown\"");
+}
+
+#[test]
+fn declaration_is_hoisted() {
+    let mut context = Context::new();
+    let init = r#"
+        let result;
+        f().then(function (value) {
+            result = value;
+        });
+        async function f() {
+            return await "hoisted";
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result"), "\"hoisted\"");
+}
@@ -0,0 +1,509 @@
+//! This module implements async generator objects, the async iterators returned by async
+//! generator functions.
+//!
+//! The body of an async generator is executed like the body of a generator, and suspended by its
+//! `await` expressions like the body of an async function. The `next`, `return` and `throw`
+//! methods return promises, and the requests they make are queued until the body can handle
+//! them, one at a time.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-asyncgenerator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncGenerator
+
+use crate::{
+    builtins::{
+        async_function::await_promise,
+        function::make_builtin_fn,
+        generator::{BodyCompletion, GeneratorContext, Resumption},
+        iterable::create_iter_result_object,
+        promise::{Promise, PromiseCapability},
+    },
+    environment::lexical_environment::Environment,
+    gc::{empty_trace, Finalize, Trace},
+    object::{GcObject, ObjectData, PROTOTYPE},
+    property::{Attribute, DataDescriptor},
+    symbol::WellKnownSymbols,
+    syntax::ast::node::RcStatementList,
+    BoaProfiler, Context, Result, Value,
+};
+use std::collections::VecDeque;
+
+#[cfg(test)]
+mod tests;
+
+/// The state of an async generator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-internal-slots-of-asyncgenerator-instances
+#[derive(Debug, Clone, Copy, PartialEq, Finalize)]
+enum AsyncGeneratorState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    AwaitingReturn,
+    Completed,
+}
+
+unsafe impl Trace for AsyncGeneratorState {
+    empty_trace!();
+}
+
+/// A call to the `next`, `return` or `throw` method of an async generator, waiting to be handled.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-asyncgeneratorrequest-records
+#[derive(Debug, Trace, Finalize)]
+struct AsyncGeneratorRequest {
+    completion: Resumption,
+    /// The promise returned by the call.
+    capability: PromiseCapability,
+}
+
+/// The internal representation of an async generator object.
+#[derive(Debug, Trace, Finalize)]
+pub struct AsyncGenerator {
+    state: AsyncGeneratorState,
+    /// The execution context of the body, which is taken while it's executing, and dropped once
+    /// the async generator is completed.
+    context: Option<GeneratorContext>,
+    /// The requests that were not handled yet, from the oldest to the newest.
+    queue: VecDeque<AsyncGeneratorRequest>,
+}
+
+impl AsyncGenerator {
+    pub(crate) const NAME: &'static str = "AsyncGenerator";
+
+    /// Creates a new async generator object, for a call to the given async generator function
+    /// whose environments were prepared.
+    pub(crate) fn create(
+        function: &GcObject,
+        function_name: Box<str>,
+        body: RcStatementList,
+        environments: Vec<Environment>,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let prototype = function.get(&PROTOTYPE.into(), function.clone().into(), context)?;
+        let prototype = if prototype.is_object() {
+            prototype
+        } else {
+            context.iterator_prototypes().async_generator().into()
+        };
+
+        let generator = Value::new_object(context);
+        generator
+            .as_object()
+            .expect("async generator object")
+            .set_prototype_instance(prototype);
+        generator.set_data(ObjectData::AsyncGenerator(Self {
+            state: AsyncGeneratorState::SuspendedStart,
+            context: Some(GeneratorContext::new(
+                function_name,
+                body,
+                environments,
+                true,
+            )),
+            queue: VecDeque::new(),
+        }));
+        Ok(generator)
+    }
+
+    /// %AsyncGeneratorPrototype%.next( value )
+    ///
+    /// Requests the next value of the async generator, returning a promise for the iterator
+    /// result.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgenerator-prototype-next
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncGenerator/next
+    pub(crate) fn next(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::enqueue(this, Resumption::Next(value), context)
+    }
+
+    /// %AsyncGeneratorPrototype%.return( value )
+    ///
+    /// Requests the async generator to return, as if a `return` statement was at the `yield`
+    /// expression it's suspended at.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgenerator-prototype-return
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncGenerator/return
+    pub(crate) fn r#return(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::enqueue(this, Resumption::Return(value), context)
+    }
+
+    /// %AsyncGeneratorPrototype%.throw( exception )
+    ///
+    /// Requests the async generator to throw the given exception at the `yield` expression it's
+    /// suspended at.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgenerator-prototype-throw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/AsyncGenerator/throw
+    pub(crate) fn throw(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let exception = args.get(0).cloned().unwrap_or_default();
+        Self::enqueue(this, Resumption::Throw(exception), context)
+    }
+
+    /// AsyncGeneratorEnqueue( generator, completion )
+    ///
+    /// Queues a request, and handles it right away if the async generator isn't executing.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgeneratorenqueue
+    fn enqueue(this: &Value, completion: Resumption, context: &mut Context) -> Result<Value> {
+        let constructor = context.standard_objects().promise_object().constructor();
+        let capability = Promise::new_promise_capability(&constructor.into(), context)?;
+        let promise = capability.promise.clone();
+
+        let generator = match this.as_object() {
+            Some(object) if object.borrow().as_async_generator().is_some() => object,
+            _ => {
+                let error = context.construct_type_error("'this' is not an AsyncGenerator");
+                context.call(&capability.reject, &Value::undefined(), &[error])?;
+                return Ok(promise);
+            }
+        };
+
+        let state = {
+            let mut object = generator.borrow_mut();
+            let generator = object
+                .as_async_generator_mut()
+                .expect("'this' is an AsyncGenerator");
+            generator.queue.push_back(AsyncGeneratorRequest {
+                completion,
+                capability,
+            });
+            generator.state
+        };
+        if state != AsyncGeneratorState::Executing {
+            Self::resume_next(&generator, context)?;
+        }
+        Ok(promise)
+    }
+
+    /// AsyncGeneratorResumeNext( generator )
+    ///
+    /// Handles the oldest request, if the async generator can handle one.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgeneratorresumenext
+    fn resume_next(generator: &GcObject, context: &mut Context) -> Result<()> {
+        let (state, completion) = {
+            let object = generator.borrow();
+            let async_generator = object
+                .as_async_generator()
+                .expect("the object is an AsyncGenerator");
+            let completion = match async_generator.queue.front() {
+                Some(request) => request.completion.clone(),
+                None => return Ok(()),
+            };
+            (async_generator.state, completion)
+        };
+
+        match (state, completion) {
+            (AsyncGeneratorState::Executing, _) | (AsyncGeneratorState::AwaitingReturn, _) => {
+                Ok(())
+            }
+            // An async generator that didn't start is completed by an abrupt request, without
+            // executing its body.
+            (AsyncGeneratorState::SuspendedStart, Resumption::Return(value))
+            | (AsyncGeneratorState::Completed, Resumption::Return(value)) => {
+                Self::take_context(generator, AsyncGeneratorState::AwaitingReturn);
+                Self::await_return(generator, value, context)
+            }
+            (AsyncGeneratorState::SuspendedStart, Resumption::Throw(exception))
+            | (AsyncGeneratorState::Completed, Resumption::Throw(exception)) => {
+                Self::take_context(generator, AsyncGeneratorState::Completed);
+                Self::settle(generator, Err(exception), true, context)
+            }
+            (AsyncGeneratorState::Completed, Resumption::Next(_)) => {
+                Self::settle(generator, Ok(Value::undefined()), true, context)
+            }
+            (AsyncGeneratorState::SuspendedStart, Resumption::Next(_)) => {
+                let generator_context =
+                    Self::take_context(generator, AsyncGeneratorState::Executing)
+                        .expect("a suspended async generator has an execution context");
+                Self::step(generator, generator_context, None, context)
+            }
+            // The value of a `return` request is awaited before the body returns.
+            (AsyncGeneratorState::SuspendedYield, Resumption::Return(value)) => {
+                let generator_context =
+                    Self::take_context(generator, AsyncGeneratorState::Executing)
+                        .expect("a suspended async generator has an execution context");
+                Self::resume_return(generator, generator_context, value, context)
+            }
+            (AsyncGeneratorState::SuspendedYield, completion) => {
+                let generator_context =
+                    Self::take_context(generator, AsyncGeneratorState::Executing)
+                        .expect("a suspended async generator has an execution context");
+                Self::step(generator, generator_context, Some(completion), context)
+            }
+        }
+    }
+
+    /// Executes the body until it's suspended or it completes, and settles the oldest request
+    /// with the value it produces.
+    fn step(
+        generator: &GcObject,
+        mut generator_context: GeneratorContext,
+        resumption: Option<Resumption>,
+        context: &mut Context,
+    ) -> Result<()> {
+        match generator_context.resume(resumption, context) {
+            Ok(BodyCompletion::Await(promise)) => {
+                Self::restore_context(generator, generator_context);
+                await_promise(
+                    &promise,
+                    generator.clone(),
+                    |generator, resumption, context| {
+                        let generator_context =
+                            Self::take_context(generator, AsyncGeneratorState::Executing)
+                                .expect("an executing async generator has an execution context");
+                        Self::step(generator, generator_context, Some(resumption), context)
+                    },
+                    context,
+                );
+                Ok(())
+            }
+            // The values produced by the iterator a `yield*` expression delegates to were
+            // already awaited.
+            Ok(BodyCompletion::Yield {
+                value,
+                delegated: true,
+            }) => {
+                Self::restore_context(generator, generator_context);
+                Self::yield_value(generator, value, context)
+            }
+            // The other values are awaited before they are produced, and a rejection is thrown at
+            // the `yield` expression.
+            Ok(BodyCompletion::Yield { value, .. }) => {
+                let constructor = context.standard_objects().promise_object().constructor();
+                let promise = match Promise::promise_resolve(&constructor.into(), value, context) {
+                    Ok(promise) => promise,
+                    Err(error) => {
+                        return Self::step(
+                            generator,
+                            generator_context,
+                            Some(Resumption::Throw(error)),
+                            context,
+                        )
+                    }
+                };
+                Self::restore_context(generator, generator_context);
+                await_promise(
+                    &promise,
+                    generator.clone(),
+                    |generator, resumption, context| match resumption {
+                        Resumption::Next(value) => Self::yield_value(generator, value, context),
+                        resumption => {
+                            let generator_context =
+                                Self::take_context(generator, AsyncGeneratorState::Executing)
+                                    .expect("a suspended async generator has an execution context");
+                            Self::step(generator, generator_context, Some(resumption), context)
+                        }
+                    },
+                    context,
+                );
+                Ok(())
+            }
+            Ok(BodyCompletion::Return(value)) => {
+                Self::take_context(generator, AsyncGeneratorState::AwaitingReturn);
+                Self::await_return(generator, value, context)
+            }
+            Err(error) => {
+                Self::take_context(generator, AsyncGeneratorState::Completed);
+                Self::settle(generator, Err(error), true, context)
+            }
+        }
+    }
+
+    /// Awaits the value of a `return` request, before resuming the body with it, or throwing the
+    /// reason of its rejection.
+    fn resume_return(
+        generator: &GcObject,
+        generator_context: GeneratorContext,
+        value: Value,
+        context: &mut Context,
+    ) -> Result<()> {
+        let constructor = context.standard_objects().promise_object().constructor();
+        let promise = match Promise::promise_resolve(&constructor.into(), value, context) {
+            Ok(promise) => promise,
+            Err(error) => {
+                return Self::step(
+                    generator,
+                    generator_context,
+                    Some(Resumption::Throw(error)),
+                    context,
+                )
+            }
+        };
+        Self::restore_context(generator, generator_context);
+        await_promise(
+            &promise,
+            generator.clone(),
+            |generator, resumption, context| {
+                let resumption = match resumption {
+                    Resumption::Next(value) => Resumption::Return(value),
+                    resumption => resumption,
+                };
+                let generator_context =
+                    Self::take_context(generator, AsyncGeneratorState::Executing)
+                        .expect("an executing async generator has an execution context");
+                Self::step(generator, generator_context, Some(resumption), context)
+            },
+            context,
+        );
+        Ok(())
+    }
+
+    /// Produces a value of the async generator, which is suspended until the next request.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgeneratoryield
+    fn yield_value(generator: &GcObject, value: Value, context: &mut Context) -> Result<()> {
+        generator
+            .borrow_mut()
+            .as_async_generator_mut()
+            .expect("the object is an AsyncGenerator")
+            .state = AsyncGeneratorState::SuspendedYield;
+        Self::settle(generator, Ok(value), false, context)
+    }
+
+    /// Awaits the value an async generator returns, before settling the oldest request with it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgeneratorresumenext-return-processor-fulfilled
+    fn await_return(generator: &GcObject, value: Value, context: &mut Context) -> Result<()> {
+        let constructor = context.standard_objects().promise_object().constructor();
+        let promise = match Promise::promise_resolve(&constructor.into(), value, context) {
+            Ok(promise) => promise,
+            Err(error) => {
+                Self::take_context(generator, AsyncGeneratorState::Completed);
+                return Self::settle(generator, Err(error), true, context);
+            }
+        };
+        await_promise(
+            &promise,
+            generator.clone(),
+            |generator, resumption, context| {
+                Self::take_context(generator, AsyncGeneratorState::Completed);
+                match resumption {
+                    Resumption::Next(value) => Self::settle(generator, Ok(value), true, context),
+                    Resumption::Throw(reason) | Resumption::Return(reason) => {
+                        Self::settle(generator, Err(reason), true, context)
+                    }
+                }
+            },
+            context,
+        );
+        Ok(())
+    }
+
+    /// AsyncGeneratorResolve( generator, value, done ) and AsyncGeneratorReject( generator,
+    /// exception )
+    ///
+    /// Settles the promise of the oldest request, and handles the next one.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncgeneratorresolve
+    fn settle(
+        generator: &GcObject,
+        result: Result<Value>,
+        done: bool,
+        context: &mut Context,
+    ) -> Result<()> {
+        let request = generator
+            .borrow_mut()
+            .as_async_generator_mut()
+            .expect("the object is an AsyncGenerator")
+            .queue
+            .pop_front()
+            .expect("a request is queued");
+        let capability = &request.capability;
+        match result {
+            Ok(value) => {
+                let result = create_iter_result_object(context, value, done);
+                context.call(&capability.resolve, &Value::undefined(), &[result])?;
+            }
+            Err(exception) => {
+                context.call(&capability.reject, &Value::undefined(), &[exception])?;
+            }
+        }
+        Self::resume_next(generator, context)
+    }
+
+    /// Takes the execution context of the async generator, setting its new state.
+    fn take_context(object: &GcObject, state: AsyncGeneratorState) -> Option<GeneratorContext> {
+        let mut object = object.borrow_mut();
+        let generator = object
+            .as_async_generator_mut()
+            .expect("the object is an AsyncGenerator");
+        generator.state = state;
+        generator.context.take()
+    }
+
+    /// Gives back the execution context of an async generator whose body is suspended.
+    fn restore_context(object: &GcObject, generator_context: GeneratorContext) {
+        object
+            .borrow_mut()
+            .as_async_generator_mut()
+            .expect("the object is an AsyncGenerator")
+            .context = Some(generator_context);
+    }
+
+    /// Create the %AsyncGeneratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-properties-of-asyncgenerator-prototype
+    pub(crate) fn create_prototype(
+        context: &mut Context,
+        async_iterator_prototype: Value,
+    ) -> GcObject {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let mut async_generator = context.construct_object();
+        make_builtin_fn(Self::next, "next", &async_generator, 1, context);
+        make_builtin_fn(Self::r#return, "return", &async_generator, 1, context);
+        make_builtin_fn(Self::throw, "throw", &async_generator, 1, context);
+        async_generator.set_prototype_instance(async_iterator_prototype);
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+        let to_string_tag_property = DataDescriptor::new(
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+        async_generator.insert(to_string_tag, to_string_tag_property);
+        async_generator
+    }
+}
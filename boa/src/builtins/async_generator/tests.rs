@@ -0,0 +1,83 @@
+use crate::{forward, Context};
+
+#[test]
+fn next_returns_promises() {
+    let mut context = Context::new();
+    let init = r#"
+        let results = [];
+        let gen = async function*() {
+            yield 1;
+            yield await Promise.resolve(2);
+            return 3;
+        };
+        let generator = gen();
+        let push = function (result) {
+            results.push(result.value + ":" + result.done);
+        };
+        generator.next().then(push);
+        generator.next().then(push);
+        generator.next().then(push);
+        generator.next().then(push);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "results.join()"),
+        "\"1:false,2:false,3:true,undefined:true\""
+    );
+}
+
+#[test]
+fn return_and_throw_are_queued() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        let gen = async function*() {
+            try {
+                yield 1;
+                yield 2;
+            } finally {
+                log.push("finally");
+            }
+        };
+        let generator = gen();
+        generator.next().then(function (result) {
+            log.push(result.value);
+        });
+        generator.return(Promise.resolve("early")).then(function (result) {
+            log.push(result.value + ":" + result.done);
+        });
+        generator.throw("ignored").catch(function (e) {
+            log.push("rejected " + e);
+        });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"1,finally,early:true,rejected ignored\""
+    );
+}
+
+#[test]
+fn yield_delegates_to_async_iterable() {
+    let mut context = Context::new();
+    let init = r#"
+        let values = [];
+        let inner = async function*() {
+            yield "a";
+            return "inner done";
+        };
+        let outer = async function*() {
+            let result = yield* inner();
+            yield result;
+            yield* [Promise.resolve("b")];
+        };
+        let run = async function() {
+            for await (const value of outer()) {
+                values.push(value);
+            }
+        };
+        run();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "values.join()"), "\"a,inner done,b\"");
+}
@@ -411,3 +411,38 @@ fn remainder_by_zero() {
     let mut context = Context::new();
     assert_throws(&mut context, "1n % 0n", "RangeError");
 }
+
+#[test]
+fn pow_beyond_u64() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "2n ** 64n"), "18446744073709551616n");
+}
+
+#[test]
+fn mixing_with_number() {
+    let mut context = Context::new();
+
+    assert_throws(&mut context, "1n + 2", "TypeError");
+    assert_throws(&mut context, "2 * 1n", "TypeError");
+    assert_throws(&mut context, "1n ** 2", "TypeError");
+}
+
+#[test]
+fn strict_equality() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "10n == 10"), "true");
+    assert_eq!(forward(&mut context, "10n === 10"), "false");
+    assert_eq!(forward(&mut context, "10n === 10n"), "true");
+}
+
+#[test]
+fn comparison_with_number() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "1n < 2"), "true");
+    assert_eq!(forward(&mut context, "1n < 1.5"), "true");
+    assert_eq!(forward(&mut context, "2 > 1n"), "true");
+    assert_eq!(forward(&mut context, "2n >= 2.5"), "false");
+}
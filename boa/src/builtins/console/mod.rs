@@ -27,14 +27,56 @@ use rustc_hash::FxHashMap;
 use std::time::SystemTime;
 
 /// This represents the different types of log messages.
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LogMessage {
     Log(String),
+    Debug(String),
     Info(String),
     Warn(String),
     Error(String),
 }
 
+impl LogMessage {
+    /// The formatted text of the message.
+    #[inline]
+    pub fn message(&self) -> &str {
+        match self {
+            Self::Log(msg)
+            | Self::Debug(msg)
+            | Self::Info(msg)
+            | Self::Warn(msg)
+            | Self::Error(msg) => msg,
+        }
+    }
+}
+
+/// The destination of the messages logged with the `console` object.
+///
+/// The default logger, [`StdLogger`], prints the messages to the standard output and the
+/// standard error. An embedder can capture them instead by setting its own logger with
+/// [`Context::set_logger`].
+pub trait Logger: std::fmt::Debug {
+    /// Writes a message, which is already formatted and indented for the current group.
+    fn log(&mut self, message: LogMessage);
+}
+
+/// A [`Logger`] that prints errors to the standard error and everything else to the standard
+/// output.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct StdLogger;
+
+impl Logger for StdLogger {
+    fn log(&mut self, message: LogMessage) {
+        match message {
+            LogMessage::Error(msg) => eprintln!("{}", msg),
+            LogMessage::Log(msg)
+            | LogMessage::Debug(msg)
+            | LogMessage::Info(msg)
+            | LogMessage::Warn(msg) => println!("{}", msg),
+        }
+    }
+}
+
 /// Helper function that returns the argument at a specified index.
 fn get_arg_at_index<'a, T>(args: &'a [Value], index: usize) -> Option<T>
 where
@@ -44,17 +86,18 @@ where
 }
 
 /// Helper function for logging messages.
-pub(crate) fn logger(msg: LogMessage, console_state: &Console) {
-    let indent = 2 * console_state.groups.len();
-
-    match msg {
-        LogMessage::Error(msg) => {
-            eprintln!("{:>width$}", msg, width = indent);
-        }
-        LogMessage::Log(msg) | LogMessage::Info(msg) | LogMessage::Warn(msg) => {
-            println!("{:>width$}", msg, width = indent);
-        }
-    }
+pub(crate) fn logger(msg: LogMessage, console_state: &mut Console) {
+    let indent = " ".repeat(2 * console_state.groups.len());
+    let indent_message = |msg: String| format!("{}{}", indent, msg);
+
+    let msg = match msg {
+        LogMessage::Log(msg) => LogMessage::Log(indent_message(msg)),
+        LogMessage::Debug(msg) => LogMessage::Debug(indent_message(msg)),
+        LogMessage::Info(msg) => LogMessage::Info(indent_message(msg)),
+        LogMessage::Warn(msg) => LogMessage::Warn(indent_message(msg)),
+        LogMessage::Error(msg) => LogMessage::Error(indent_message(msg)),
+    };
+    console_state.logger.log(msg);
 }
 
 /// This represents the `console` formatter.
@@ -135,11 +178,23 @@ pub fn formatter(data: &[Value], context: &mut Context) -> Result<String> {
 }
 
 /// This is the internal console object state.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub(crate) struct Console {
     count_map: FxHashMap<RcString, u32>,
     timer_map: FxHashMap<RcString, u128>,
     groups: Vec<String>,
+    logger: Box<dyn Logger>,
+}
+
+impl Default for Console {
+    fn default() -> Self {
+        Self {
+            count_map: FxHashMap::default(),
+            timer_map: FxHashMap::default(),
+            groups: Vec::new(),
+            logger: Box::new(StdLogger),
+        }
+    }
 }
 
 impl BuiltIn for Console {
@@ -181,6 +236,12 @@ impl Console {
     /// The name of the object.
     pub(crate) const NAME: &'static str = "console";
 
+    /// Replaces the logger that receives the messages.
+    #[inline]
+    pub(crate) fn set_logger(&mut self, logger: Box<dyn Logger>) {
+        self.logger = logger;
+    }
+
     /// `console.assert(condition, ...data)`
     ///
     /// Prints a JavaScript value to the standard error if first argument evaluates to `false` or there
@@ -209,7 +270,7 @@ impl Console {
 
             logger(
                 LogMessage::Error(formatter(&args, context)?),
-                context.console(),
+                context.console_mut(),
             );
         }
 
@@ -243,8 +304,8 @@ impl Console {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/console/debug
     pub(crate) fn debug(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         logger(
-            LogMessage::Log(formatter(args, context)?),
-            context.console(),
+            LogMessage::Debug(formatter(args, context)?),
+            context.console_mut(),
         );
         Ok(Value::undefined())
     }
@@ -262,7 +323,7 @@ impl Console {
     pub(crate) fn error(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         logger(
             LogMessage::Error(formatter(args, context)?),
-            context.console(),
+            context.console_mut(),
         );
         Ok(Value::undefined())
     }
@@ -280,7 +341,7 @@ impl Console {
     pub(crate) fn info(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         logger(
             LogMessage::Info(formatter(args, context)?),
-            context.console(),
+            context.console_mut(),
         );
         Ok(Value::undefined())
     }
@@ -298,7 +359,7 @@ impl Console {
     pub(crate) fn log(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         logger(
             LogMessage::Log(formatter(args, context)?),
-            context.console(),
+            context.console_mut(),
         );
         Ok(Value::undefined())
     }
@@ -317,13 +378,13 @@ impl Console {
         if !args.is_empty() {
             logger(
                 LogMessage::Log(formatter(args, context)?),
-                context.console(),
+                context.console_mut(),
             );
 
             /* TODO: get and print stack trace */
             logger(
                 LogMessage::Log("Not implemented: <stack trace>".to_string()),
-                context.console(),
+                context.console_mut(),
             )
         }
 
@@ -343,7 +404,7 @@ impl Console {
     pub(crate) fn warn(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         logger(
             LogMessage::Warn(formatter(args, context)?),
-            context.console(),
+            context.console_mut(),
         );
         Ok(Value::undefined())
     }
@@ -370,7 +431,7 @@ impl Console {
 
         logger(
             LogMessage::Info(format!("{} {}", msg, c)),
-            context.console(),
+            context.console_mut(),
         );
         Ok(Value::undefined())
     }
@@ -395,7 +456,7 @@ impl Console {
 
         logger(
             LogMessage::Warn(format!("countReset {}", label)),
-            context.console(),
+            context.console_mut(),
         );
 
         Ok(Value::undefined())
@@ -428,7 +489,7 @@ impl Console {
         if context.console().timer_map.get(&label).is_some() {
             logger(
                 LogMessage::Warn(format!("Timer '{}' already exist", label)),
-                context.console(),
+                context.console_mut(),
             );
         } else {
             let time = Self::system_time_in_ms();
@@ -460,11 +521,11 @@ impl Console {
             for msg in args.iter().skip(1) {
                 concat = concat + " " + &msg.display().to_string();
             }
-            logger(LogMessage::Log(concat), context.console());
+            logger(LogMessage::Log(concat), context.console_mut());
         } else {
             logger(
                 LogMessage::Warn(format!("Timer '{}' doesn't exist", label)),
-                context.console(),
+                context.console_mut(),
             );
         }
 
@@ -491,12 +552,12 @@ impl Console {
             let time = Self::system_time_in_ms();
             logger(
                 LogMessage::Info(format!("{}: {} ms - timer removed", label, time - t)),
-                context.console(),
+                context.console_mut(),
             );
         } else {
             logger(
                 LogMessage::Warn(format!("Timer '{}' doesn't exist", label)),
-                context.console(),
+                context.console_mut(),
             );
         }
 
@@ -518,7 +579,7 @@ impl Console {
 
         logger(
             LogMessage::Info(format!("group: {}", &group_label)),
-            context.console(),
+            context.console_mut(),
         );
         context.console_mut().groups.push(group_label);
 
@@ -555,7 +616,7 @@ impl Console {
         let undefined = Value::undefined();
         logger(
             LogMessage::Info(display_obj(args.get(0).unwrap_or(&undefined), true)),
-            context.console(),
+            context.console_mut(),
         );
 
         Ok(Value::undefined())
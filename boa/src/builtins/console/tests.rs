@@ -1,4 +1,8 @@
-use crate::{builtins::console::formatter, Context, Value};
+use crate::{
+    builtins::console::{formatter, LogMessage, Logger},
+    forward, Context, Value,
+};
+use std::{cell::RefCell, rc::Rc};
 
 #[test]
 fn formatter_no_args_is_empty_string() {
@@ -69,3 +73,83 @@ fn formatter_float_format_works() {
     let res = formatter(&val, &mut context).unwrap();
     assert_eq!(res, "3.141500");
 }
+
+/// A logger that keeps the messages so the tests can inspect them.
+#[derive(Debug, Default, Clone)]
+struct CapturingLogger {
+    messages: Rc<RefCell<Vec<LogMessage>>>,
+}
+
+impl Logger for CapturingLogger {
+    fn log(&mut self, message: LogMessage) {
+        self.messages.borrow_mut().push(message);
+    }
+}
+
+#[test]
+fn custom_logger_receives_messages() {
+    let mut context = Context::new();
+    let logger = CapturingLogger::default();
+    context.set_logger(logger.clone());
+
+    forward(&mut context, r#"console.log("x", 1)"#);
+    forward(&mut context, r#"console.error("%s is %d", "answer", 42.5)"#);
+
+    assert_eq!(
+        *logger.messages.borrow(),
+        vec![
+            LogMessage::Log("x 1".to_string()),
+            LogMessage::Error("answer is 42".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn custom_logger_receives_levels() {
+    let mut context = Context::new();
+    let logger = CapturingLogger::default();
+    context.set_logger(logger.clone());
+
+    let init = r#"
+        console.log("log");
+        console.info("info");
+        console.warn("warn");
+        console.error("error");
+        console.debug("debug");
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        *logger.messages.borrow(),
+        vec![
+            LogMessage::Log("log".to_string()),
+            LogMessage::Info("info".to_string()),
+            LogMessage::Warn("warn".to_string()),
+            LogMessage::Error("error".to_string()),
+            LogMessage::Debug("debug".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn custom_logger_messages_are_indented_by_groups() {
+    let mut context = Context::new();
+    let logger = CapturingLogger::default();
+    context.set_logger(logger.clone());
+
+    let init = r#"
+        console.group("outer");
+        console.log("inside");
+        console.groupEnd();
+        console.log("outside");
+        "#;
+    forward(&mut context, init);
+
+    let messages: Vec<String> = logger
+        .messages
+        .borrow()
+        .iter()
+        .map(|message| message.message().to_string())
+        .collect();
+    assert_eq!(messages, vec!["group: outer", "  inside", "outside"]);
+}
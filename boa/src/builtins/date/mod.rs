@@ -4,13 +4,14 @@ mod tests;
 use crate::{
     builtins::BuiltIn,
     gc::{empty_trace, Finalize, Trace},
-    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
     property::Attribute,
-    value::{PreferredType, Value},
+    symbol::WellKnownSymbols,
+    value::{PreferredType, RcString, Value},
     BoaProfiler, Context, Result,
 };
 use chrono::{prelude::*, Duration, LocalResult};
-use std::fmt::Display;
+use std::{error, fmt, fmt::Display, str::FromStr};
 
 /// The number of nanoseconds in a millisecond.
 const NANOS_PER_MS: i64 = 1_000_000;
@@ -44,6 +45,13 @@ fn ignore_ambiguity<T>(result: LocalResult<T>) -> Option<T> {
 }
 
 macro_rules! getter_method {
+    (local $name:ident) => {{
+        fn get_value(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+            let zone = context.time_zone();
+            Ok(Value::from(this_time_value(this, context)?.$name(zone)))
+        }
+        get_value
+    }};
     ($name:ident) => {{
         fn get_value(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
             Ok(Value::from(this_time_value(this, context)?.$name()))
@@ -51,35 +59,46 @@ macro_rules! getter_method {
         get_value
     }};
     (Self::$name:ident) => {{
-        fn get_value(_: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-            Ok(Value::from(Date::$name()))
+        fn get_value(_: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+            Ok(Value::from(Date::$name(context.time_zone())))
         }
         get_value
     }};
 }
 
+/// Converts the argument `index` of a setter to a date component, which is `None` if it is missing
+/// or if it is not a finite number.
+fn component_arg(args: &[Value], index: usize, context: &mut Context) -> Option<f64> {
+    args.get(index).and_then(|value| {
+        value.to_numeric_number(context).map_or_else(
+            |_| None,
+            |value| {
+                if value == 0f64 || value.is_normal() {
+                    Some(value)
+                } else {
+                    None
+                }
+            },
+        )
+    })
+}
+
 macro_rules! setter_method {
+    (local $name:ident($($e:expr),* $(,)?)) => {{
+        fn set_value(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+            let mut result = this_time_value(this, context)?;
+            let zone = context.time_zone();
+            result.$name(zone, $(component_arg(args, $e, context)),*);
+
+            this.set_data(ObjectData::Date(result));
+            Ok(Value::from(result.get_time()))
+        }
+        set_value
+    }};
     ($name:ident($($e:expr),* $(,)?)) => {{
         fn set_value(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
             let mut result = this_time_value(this, context)?;
-            result.$name(
-                $(
-                    args
-                        .get($e)
-                        .and_then(|value| {
-                            value.to_numeric_number(context).map_or_else(
-                                |_| None,
-                                |value| {
-                                    if value == 0f64 || value.is_normal() {
-                                        Some(value)
-                                    } else {
-                                        None
-                                    }
-                                },
-                            )
-                        })
-                ),*
-            );
+            result.$name($(component_arg(args, $e, context)),*);
 
             this.set_data(ObjectData::Date(result));
             Ok(Value::from(result.get_time()))
@@ -88,15 +107,304 @@ macro_rules! setter_method {
     }};
 }
 
+/// Parses a string in the [Date Time String Format][spec], a simplification of the ISO 8601
+/// calendar date extended format.
+///
+/// Date-only forms are interpreted as UTC, while date-time forms without an offset are interpreted
+/// as local time in `zone`. Returns `None` if the string is not in the format, or if it represents
+/// an invalid date or time.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date-time-string-format
+fn parse_date_time_string(string: &str, zone: LocalTimeZone) -> Option<NaiveDateTime> {
+    DateTimeParser::new(string, zone).parse()
+}
+
+/// A parser for the Date Time String Format.
+#[derive(Debug)]
+struct DateTimeParser<'a> {
+    input: &'a [u8],
+    position: usize,
+    zone: LocalTimeZone,
+}
+
+impl<'a> DateTimeParser<'a> {
+    fn new(input: &'a str, zone: LocalTimeZone) -> Self {
+        Self {
+            input: input.as_bytes(),
+            position: 0,
+            zone,
+        }
+    }
+
+    /// Consumes the next byte if it is equal to `byte`.
+    fn next_if(&mut self, byte: u8) -> bool {
+        if self.input.get(self.position) == Some(&byte) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn is_done(&self) -> bool {
+        self.position == self.input.len()
+    }
+
+    /// Parses exactly `count` decimal digits.
+    fn digits(&mut self, count: usize) -> Option<u32> {
+        let digits = self.input.get(self.position..self.position + count)?;
+        let mut value = 0;
+        for digit in digits {
+            value = value * 10 + char::from(*digit).to_digit(10)?;
+        }
+        self.position += count;
+        Some(value)
+    }
+
+    /// Parses `HH:mm`, the hours and minutes of a time or of a time zone offset.
+    fn hours_and_minutes(&mut self) -> Option<(u32, u32)> {
+        let hour = self.digits(2)?;
+        if !self.next_if(b':') {
+            return None;
+        }
+        Some((hour, self.digits(2)?))
+    }
+
+    fn parse(mut self) -> Option<NaiveDateTime> {
+        // YYYY, or the expanded years ±YYYYYY, where -000000 is not allowed.
+        let year = if self.next_if(b'+') {
+            self.digits(6)? as i32
+        } else if self.next_if(b'-') {
+            match self.digits(6)? {
+                0 => return None,
+                year => -(year as i32),
+            }
+        } else {
+            self.digits(4)? as i32
+        };
+
+        // -MM and -DD are optional, and default to 01.
+        let mut month = 1;
+        let mut day = 1;
+        if self.next_if(b'-') {
+            month = self.digits(2)?;
+            if self.next_if(b'-') {
+                day = self.digits(2)?;
+            }
+        }
+        let date = NaiveDate::from_ymd_opt(year, month, day)?;
+
+        // Date-only forms are interpreted as a UTC time.
+        if self.is_done() {
+            return Some(date.and_hms(0, 0, 0));
+        }
+
+        // THH:mm, followed by the optional :ss and .sss
+        if !self.next_if(b'T') {
+            return None;
+        }
+        let (hour, minute) = self.hours_and_minutes()?;
+        let mut second = 0;
+        let mut millisecond = 0;
+        if self.next_if(b':') {
+            second = self.digits(2)?;
+            if self.next_if(b'.') {
+                // Only the first three digits are significant.
+                let start = self.position;
+                while self.digits(1).is_some() {}
+                let fraction = &self.input[start..self.position];
+                if fraction.is_empty() {
+                    return None;
+                }
+                millisecond = fraction
+                    .iter()
+                    .chain(b"00")
+                    .take(3)
+                    .fold(0, |ms, digit| ms * 10 + u32::from(digit - b'0'));
+            }
+        }
+
+        // 24:00 is the end of the day, which is the midnight of the next day.
+        let end_of_day = hour == 24 && minute == 0 && second == 0 && millisecond == 0;
+        if (hour > 23 && !end_of_day) || minute > 59 || second > 59 {
+            return None;
+        }
+        let mut date_time = date.and_hms_milli(hour % 24, minute, second, millisecond);
+        if end_of_day {
+            date_time = date_time.checked_add_signed(Duration::days(1))?;
+        }
+
+        // Z, ±HH:mm or nothing, in which case the time is a local time.
+        let offset = if self.next_if(b'Z') {
+            Some(0)
+        } else if self.next_if(b'+') {
+            Some(self.offset_minutes()?)
+        } else if self.next_if(b'-') {
+            Some(-self.offset_minutes()?)
+        } else {
+            None
+        };
+        if !self.is_done() {
+            return None;
+        }
+
+        match offset {
+            Some(offset) => date_time.checked_sub_signed(Duration::minutes(offset)),
+            None => ignore_ambiguity(self.zone.from_local_datetime(&date_time))
+                .map(|date_time| date_time.naive_utc()),
+        }
+    }
+
+    /// Parses the `HH:mm` of a time zone offset, returning the offset in minutes.
+    fn offset_minutes(&mut self) -> Option<i64> {
+        let (hours, minutes) = self.hours_and_minutes()?;
+        if hours > 23 || minutes > 59 {
+            return None;
+        }
+        Some(i64::from(hours * 60 + minutes))
+    }
+}
+
+/// The time zone used by the local time methods of `Date`, like `getHours` or `toString`.
+///
+/// The methods with `UTC` in their name don't depend on it. It is the time zone of the host by
+/// default, and can be changed with [`Context::set_time_zone`].
+///
+/// A time zone can be parsed from `"local"` for the host time zone, `"UTC"`, `"GMT"` or `"Z"`, and
+/// from fixed offsets like `"+05:30"`, `"-0800"` or `"UTC+01:00"`. Named time zones like
+/// `"Europe/Paris"` are not supported, as they need a time zone database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LocalTimeZone {
+    /// The time zone of the host, including its daylight saving time.
+    Host,
+    /// A time zone with a fixed offset from UTC.
+    Fixed(FixedOffset),
+}
+
+impl LocalTimeZone {
+    /// The UTC time zone.
+    #[inline]
+    pub fn utc() -> Self {
+        Self::Fixed(FixedOffset::east(0))
+    }
+}
+
+impl Default for LocalTimeZone {
+    #[inline]
+    fn default() -> Self {
+        Self::Host
+    }
+}
+
+impl From<FixedOffset> for LocalTimeZone {
+    #[inline]
+    fn from(offset: FixedOffset) -> Self {
+        Self::Fixed(offset)
+    }
+}
+
+impl TimeZone for LocalTimeZone {
+    type Offset = FixedOffset;
+
+    fn from_offset(offset: &FixedOffset) -> Self {
+        Self::Fixed(*offset)
+    }
+
+    fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset> {
+        match self {
+            Self::Host => Local.offset_from_local_date(local),
+            Self::Fixed(offset) => LocalResult::Single(*offset),
+        }
+    }
+
+    fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset> {
+        match self {
+            Self::Host => Local.offset_from_local_datetime(local),
+            Self::Fixed(offset) => LocalResult::Single(*offset),
+        }
+    }
+
+    fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset {
+        match self {
+            Self::Host => Local.offset_from_utc_date(utc),
+            Self::Fixed(offset) => *offset,
+        }
+    }
+
+    fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset {
+        match self {
+            Self::Host => Local.offset_from_utc_datetime(utc),
+            Self::Fixed(offset) => *offset,
+        }
+    }
+}
+
+impl FromStr for LocalTimeZone {
+    type Err = ParseTimeZoneError;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("local") {
+            return Ok(Self::Host);
+        }
+
+        let offset = s
+            .strip_prefix("UTC")
+            .or_else(|| s.strip_prefix("GMT"))
+            .unwrap_or(s);
+        if offset.is_empty() || offset == "Z" {
+            return Ok(Self::utc());
+        }
+
+        // ±HH, ±HHMM or ±HH:MM
+        let sign = match offset.as_bytes()[0] {
+            b'+' => 1,
+            b'-' => -1,
+            _ => return Err(ParseTimeZoneError),
+        };
+        let digits = &offset[1..];
+        if !digits
+            .bytes()
+            .all(|byte| byte.is_ascii_digit() || byte == b':')
+        {
+            return Err(ParseTimeZoneError);
+        }
+        let (hours, minutes) = match (digits.len(), digits.find(':')) {
+            (2, None) => (&digits[..2], "00"),
+            (4, None) => (&digits[..2], &digits[2..]),
+            (5, Some(2)) => (&digits[..2], &digits[3..]),
+            _ => return Err(ParseTimeZoneError),
+        };
+        let hours: i32 = hours.parse().map_err(|_| ParseTimeZoneError)?;
+        let minutes: i32 = minutes.parse().map_err(|_| ParseTimeZoneError)?;
+        if hours > 23 || minutes > 59 {
+            return Err(ParseTimeZoneError);
+        }
+
+        Ok(Self::Fixed(FixedOffset::east(
+            sign * (hours * 3600 + minutes * 60),
+        )))
+    }
+}
+
+/// The error returned when a string is not a supported [`LocalTimeZone`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseTimeZoneError;
+
+impl Display for ParseTimeZoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid or unsupported time zone")
+    }
+}
+
+impl error::Error for ParseTimeZoneError {}
+
 #[derive(Debug, Finalize, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Date(Option<NaiveDateTime>);
 
 impl Display for Date {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self.to_local() {
-            Some(v) => write!(f, "{}", v.format("%a %b %d %Y %H:%M:%S GMT%:z")),
-            _ => write!(f, "Invalid Date"),
-        }
+    /// Formats the date like `Date.prototype.toString`, in the time zone of the host.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_zoned_string(LocalTimeZone::Host))
     }
 }
 
@@ -122,74 +430,98 @@ impl BuiltIn for Date {
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
-        let date_object = ConstructorBuilder::new(context, Self::constructor)
-            .name(Self::NAME)
-            .length(Self::LENGTH)
-            .method(getter_method!(get_date), "getDate", 0)
-            .method(getter_method!(get_day), "getDay", 0)
-            .method(getter_method!(get_full_year), "getFullYear", 0)
-            .method(getter_method!(get_hours), "getHours", 0)
-            .method(getter_method!(get_milliseconds), "getMilliseconds", 0)
-            .method(getter_method!(get_minutes), "getMinutes", 0)
-            .method(getter_method!(get_month), "getMonth", 0)
-            .method(getter_method!(get_seconds), "getSeconds", 0)
-            .method(getter_method!(get_time), "getTime", 0)
-            .method(getter_method!(get_year), "getYear", 0)
-            .method(
-                getter_method!(Self::get_timezone_offset),
-                "getTimezoneOffset",
-                0,
-            )
-            .method(getter_method!(get_utc_date), "getUTCDate", 0)
-            .method(getter_method!(get_utc_day), "getUTCDay", 0)
-            .method(getter_method!(get_utc_full_year), "getUTCFullYear", 0)
-            .method(getter_method!(get_utc_hours), "getUTCHours", 0)
-            .method(
-                getter_method!(get_utc_milliseconds),
-                "getUTCMilliseconds",
-                0,
-            )
-            .method(getter_method!(get_utc_minutes), "getUTCMinutes", 0)
-            .method(getter_method!(get_utc_month), "getUTCMonth", 0)
-            .method(getter_method!(get_utc_seconds), "getUTCSeconds", 0)
-            .method(setter_method!(set_date(0)), "setDate", 1)
-            .method(setter_method!(set_full_year(0, 1, 2)), "setFullYear", 1)
-            .method(setter_method!(set_hours(0, 1, 2, 3)), "setHours", 1)
-            .method(setter_method!(set_milliseconds(0)), "setMilliseconds", 1)
-            .method(setter_method!(set_minutes(0, 1, 2)), "setMinutes", 1)
-            .method(setter_method!(set_month(0, 1)), "setMonth", 1)
-            .method(setter_method!(set_seconds(0, 1)), "setSeconds", 1)
-            .method(setter_method!(set_year(0, 1, 2)), "setYear", 1)
-            .method(setter_method!(set_time(0)), "setTime", 1)
-            .method(setter_method!(set_utc_date(0)), "setUTCDate", 1)
-            .method(
-                setter_method!(set_utc_full_year(0, 1, 2)),
-                "setUTCFullYear",
-                1,
-            )
-            .method(setter_method!(set_utc_hours(0, 1, 2, 3)), "setUTCHours", 1)
-            .method(
-                setter_method!(set_utc_milliseconds(0)),
-                "setUTCMilliseconds",
-                1,
-            )
-            .method(setter_method!(set_utc_minutes(0, 1, 2)), "setUTCMinutes", 1)
-            .method(setter_method!(set_utc_month(0, 1)), "setUTCMonth", 1)
-            .method(setter_method!(set_utc_seconds(0, 1)), "setUTCSeconds", 1)
-            .method(getter_method!(to_date_string), "toDateString", 0)
-            .method(getter_method!(to_gmt_string), "toGMTString", 0)
-            .method(getter_method!(to_iso_string), "toISOString", 0)
-            .method(getter_method!(to_json), "toJSON", 0)
-            // Locale strings
-            .method(getter_method!(to_string), "toString", 0)
-            .method(getter_method!(to_time_string), "toTimeString", 0)
-            .method(getter_method!(to_utc_string), "toUTCString", 0)
-            .method(getter_method!(value_of), "valueOf", 0)
-            .static_method(Self::now, "now", 0)
-            .static_method(Self::parse, "parse", 1)
-            .static_method(Self::utc, "UTC", 7)
+        let to_primitive = FunctionBuilder::new(context, Self::to_primitive)
+            .name("[Symbol.toPrimitive]")
+            .length(1)
+            .callable(true)
+            .constructable(false)
             .build();
 
+        let date_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().date_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(getter_method!(local get_date), "getDate", 0)
+        .method(getter_method!(local get_day), "getDay", 0)
+        .method(getter_method!(local get_full_year), "getFullYear", 0)
+        .method(getter_method!(local get_hours), "getHours", 0)
+        .method(getter_method!(local get_milliseconds), "getMilliseconds", 0)
+        .method(getter_method!(local get_minutes), "getMinutes", 0)
+        .method(getter_method!(local get_month), "getMonth", 0)
+        .method(getter_method!(local get_seconds), "getSeconds", 0)
+        .method(getter_method!(get_time), "getTime", 0)
+        .method(getter_method!(local get_year), "getYear", 0)
+        .method(
+            getter_method!(Self::get_timezone_offset),
+            "getTimezoneOffset",
+            0,
+        )
+        .method(getter_method!(get_utc_date), "getUTCDate", 0)
+        .method(getter_method!(get_utc_day), "getUTCDay", 0)
+        .method(getter_method!(get_utc_full_year), "getUTCFullYear", 0)
+        .method(getter_method!(get_utc_hours), "getUTCHours", 0)
+        .method(
+            getter_method!(get_utc_milliseconds),
+            "getUTCMilliseconds",
+            0,
+        )
+        .method(getter_method!(get_utc_minutes), "getUTCMinutes", 0)
+        .method(getter_method!(get_utc_month), "getUTCMonth", 0)
+        .method(getter_method!(get_utc_seconds), "getUTCSeconds", 0)
+        .method(setter_method!(local set_date(0)), "setDate", 1)
+        .method(
+            setter_method!(local set_full_year(0, 1, 2)),
+            "setFullYear",
+            1,
+        )
+        .method(setter_method!(local set_hours(0, 1, 2, 3)), "setHours", 1)
+        .method(
+            setter_method!(local set_milliseconds(0)),
+            "setMilliseconds",
+            1,
+        )
+        .method(setter_method!(local set_minutes(0, 1, 2)), "setMinutes", 1)
+        .method(setter_method!(local set_month(0, 1)), "setMonth", 1)
+        .method(setter_method!(local set_seconds(0, 1)), "setSeconds", 1)
+        .method(setter_method!(local set_year(0, 1, 2)), "setYear", 1)
+        .method(setter_method!(set_time(0)), "setTime", 1)
+        .method(setter_method!(set_utc_date(0)), "setUTCDate", 1)
+        .method(
+            setter_method!(set_utc_full_year(0, 1, 2)),
+            "setUTCFullYear",
+            1,
+        )
+        .method(setter_method!(set_utc_hours(0, 1, 2, 3)), "setUTCHours", 1)
+        .method(
+            setter_method!(set_utc_milliseconds(0)),
+            "setUTCMilliseconds",
+            1,
+        )
+        .method(setter_method!(set_utc_minutes(0, 1, 2)), "setUTCMinutes", 1)
+        .method(setter_method!(set_utc_month(0, 1)), "setUTCMonth", 1)
+        .method(setter_method!(set_utc_seconds(0, 1)), "setUTCSeconds", 1)
+        .method(getter_method!(local to_date_string), "toDateString", 0)
+        .method(getter_method!(to_gmt_string), "toGMTString", 0)
+        .method(Self::to_iso_string, "toISOString", 0)
+        .method(Self::to_json, "toJSON", 1)
+        // Locale strings
+        .method(getter_method!(local to_zoned_string), "toString", 0)
+        .method(getter_method!(local to_time_string), "toTimeString", 0)
+        .method(getter_method!(to_utc_string), "toUTCString", 0)
+        .method(getter_method!(value_of), "valueOf", 0)
+        .property(
+            WellKnownSymbols::to_primitive(),
+            to_primitive,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .static_method(Self::now, "now", 0)
+        .static_method(Self::parse, "parse", 1)
+        .static_method(Self::utc, "UTC", 7)
+        .build();
+
         (Self::NAME, date_object.into(), Self::attribute())
     }
 }
@@ -207,19 +539,19 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-timeclip
     #[inline]
     pub fn time_clip(time: f64) -> Option<f64> {
-        if time.abs() > 8.64e15 {
+        if !time.is_finite() || time.abs() > 8.64e15 {
             None
         } else {
-            Some(time)
+            // Adding `+0` turns `-0` into `+0`.
+            Some(time.trunc() + 0.0)
         }
     }
 
-    /// Converts the `Date` to a local `DateTime`.
+    /// Converts the `Date` to a `DateTime` in the local time zone `zone`.
     ///
     /// If the `Date` is invalid (i.e. NAN), this function will return `None`.
-    pub fn to_local(&self) -> Option<DateTime<Local>> {
-        self.0
-            .map(|utc| Local::now().timezone().from_utc_datetime(&utc))
+    pub fn to_local(&self, zone: LocalTimeZone) -> Option<DateTime<LocalTimeZone>> {
+        self.0.map(|utc| zone.from_utc_datetime(&utc))
     }
 
     /// Converts the `Date` to a UTC `DateTime`.
@@ -232,12 +564,14 @@ impl Date {
 
     /// Optionally sets the individual components of the `Date`.
     ///
+    /// The components are in the time zone `zone`, which is [`LocalTimeZone::utc`] for the UTC setters.
+    ///
     /// Each component does not have to be within the range of valid values. For example, if `month` is too large
     /// then `year` will be incremented by the required amount.
     #[allow(clippy::too_many_arguments)]
     pub fn set_components(
         &mut self,
-        utc: bool,
+        zone: LocalTimeZone,
         year: Option<f64>,
         month: Option<f64>,
         day: Option<f64>,
@@ -313,11 +647,7 @@ impl Date {
             return;
         }
 
-        let naive = if utc {
-            self.to_utc().map(|dt| dt.naive_utc())
-        } else {
-            self.to_local().map(|dt| dt.naive_local())
-        };
+        let naive = self.to_local(zone).map(|dt| dt.naive_local());
 
         self.0 = naive.and_then(|naive| {
             let year = year.unwrap_or_else(|| naive.year() as f64) as i32;
@@ -344,13 +674,8 @@ impl Date {
 
             NaiveDate::from_ymd_opt(year, month + 1, day + 1)
                 .and_then(|dt| dt.and_hms(0, 0, 0).checked_add_signed(duration))
-                .and_then(|dt| {
-                    if utc {
-                        Some(Utc.from_utc_datetime(&dt).naive_utc())
-                    } else {
-                        ignore_ambiguity(Local.from_local_datetime(&dt)).map(|dt| dt.naive_utc())
-                    }
-                })
+                .and_then(|dt| ignore_ambiguity(zone.from_local_datetime(&dt)))
+                .map(|dt| dt.naive_utc())
                 .filter(|dt| Self::time_clip(dt.timestamp_millis() as f64).is_some())
         });
     }
@@ -371,7 +696,7 @@ impl Date {
         context: &mut Context,
     ) -> Result<Value> {
         if new_target.is_undefined() {
-            Ok(Self::make_date_string())
+            Ok(Self::make_date_string(context.time_zone()))
         } else {
             let prototype = new_target
                 .as_object()
@@ -381,7 +706,7 @@ impl Date {
                         .transpose()
                 })
                 .transpose()?
-                .unwrap_or_else(|| context.standard_objects().object_object().prototype());
+                .unwrap_or_else(|| context.standard_objects().date_object().prototype());
             let mut obj = context.construct_object();
             obj.set_prototype_instance(prototype.into());
             let this = obj.into();
@@ -405,8 +730,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date-constructor
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/Date
-    pub(crate) fn make_date_string() -> Value {
-        Value::from(Local::now().to_rfc3339())
+    pub(crate) fn make_date_string(zone: LocalTimeZone) -> Value {
+        Value::from(Utc::now().with_timezone(&zone).to_rfc3339())
     }
 
     /// `Date()`
@@ -444,15 +769,14 @@ impl Date {
         let tv = match this_time_value(value, context) {
             Ok(dt) => dt.0,
             _ => match value.to_primitive(context, PreferredType::Default)? {
-                Value::String(ref str) => match chrono::DateTime::parse_from_rfc3339(&str) {
-                    Ok(dt) => Some(dt.naive_utc()),
-                    _ => None,
-                },
+                Value::String(ref str) => parse_date_time_string(&str, context.time_zone()),
                 tv => {
-                    let tv = tv.to_number(context)?;
-                    let secs = (tv / 1_000f64) as i64;
-                    let nsecs = ((tv % 1_000f64) * 1_000_000f64) as u32;
-                    NaiveDateTime::from_timestamp_opt(secs, nsecs)
+                    // `NaN`, and the times out of range, are invalid dates.
+                    Self::time_clip(tv.to_number(context)?).and_then(|tv| {
+                        let secs = tv.div_euclid(1_000f64) as i64;
+                        let nsecs = (tv.rem_euclid(1_000f64) * 1_000_000f64) as u32;
+                        NaiveDateTime::from_timestamp_opt(secs, nsecs)
+                    })
                 }
             },
         };
@@ -519,7 +843,7 @@ impl Date {
 
         let final_date = NaiveDate::from_ymd_opt(year, month + 1, day)
             .and_then(|naive_date| naive_date.and_hms_milli_opt(hour, min, sec, milli))
-            .and_then(|local| ignore_ambiguity(Local.from_local_datetime(&local)))
+            .and_then(|local| ignore_ambiguity(context.time_zone().from_local_datetime(&local)))
             .map(|local| local.naive_utc())
             .filter(|time| Self::time_clip(time.timestamp_millis() as f64).is_some());
 
@@ -538,8 +862,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getdate
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getDate
-    pub fn get_date(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.day() as f64)
+    pub fn get_date(&self, zone: LocalTimeZone) -> f64 {
+        self.to_local(zone).map_or(f64::NAN, |dt| dt.day() as f64)
     }
 
     /// `Date.prototype.getDay()`
@@ -553,8 +877,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getday
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getDay
-    pub fn get_day(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| {
+    pub fn get_day(&self, zone: LocalTimeZone) -> f64 {
+        self.to_local(zone).map_or(f64::NAN, |dt| {
             let weekday = dt.weekday() as u32;
             let weekday = (weekday + 1) % 7; // 0 represents Monday in Chrono
             weekday as f64
@@ -571,8 +895,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getfullyear
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getFullYear
-    pub fn get_full_year(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.year() as f64)
+    pub fn get_full_year(&self, zone: LocalTimeZone) -> f64 {
+        self.to_local(zone).map_or(f64::NAN, |dt| dt.year() as f64)
     }
 
     /// `Date.prototype.getHours()`
@@ -585,8 +909,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.gethours
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getHours
-    pub fn get_hours(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.hour() as f64)
+    pub fn get_hours(&self, zone: LocalTimeZone) -> f64 {
+        self.to_local(zone).map_or(f64::NAN, |dt| dt.hour() as f64)
     }
 
     /// `Date.prototype.getMilliseconds()`
@@ -599,8 +923,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getmilliseconds
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMilliseconds
-    pub fn get_milliseconds(&self) -> f64 {
-        self.to_local()
+    pub fn get_milliseconds(&self, zone: LocalTimeZone) -> f64 {
+        self.to_local(zone)
             .map_or(f64::NAN, |dt| dt.nanosecond() as f64 / NANOS_PER_MS as f64)
     }
 
@@ -614,8 +938,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getminutes
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMinutes
-    pub fn get_minutes(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.minute() as f64)
+    pub fn get_minutes(&self, zone: LocalTimeZone) -> f64 {
+        self.to_local(zone)
+            .map_or(f64::NAN, |dt| dt.minute() as f64)
     }
 
     /// `Date.prototype.getMonth()`
@@ -629,8 +954,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getmonth
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getMonth
-    pub fn get_month(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.month0() as f64)
+    pub fn get_month(&self, zone: LocalTimeZone) -> f64 {
+        self.to_local(zone)
+            .map_or(f64::NAN, |dt| dt.month0() as f64)
     }
 
     /// `Date.prototype.getSeconds()`
@@ -643,8 +969,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getseconds
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getSeconds
-    pub fn get_seconds(&self) -> f64 {
-        self.to_local().map_or(f64::NAN, |dt| dt.second() as f64)
+    pub fn get_seconds(&self, zone: LocalTimeZone) -> f64 {
+        self.to_local(zone)
+            .map_or(f64::NAN, |dt| dt.second() as f64)
     }
 
     /// `Date.prototype.getYear()`
@@ -658,8 +985,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.getyear
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getYear
-    pub fn get_year(&self) -> f64 {
-        self.to_local()
+    pub fn get_year(&self, zone: LocalTimeZone) -> f64 {
+        self.to_local(zone)
             .map_or(f64::NAN, |dt| dt.year() as f64 - 1900f64)
     }
 
@@ -690,8 +1017,9 @@ impl Date {
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.gettimezoneoffset
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/getTimezoneOffset
     #[inline]
-    pub fn get_timezone_offset() -> f64 {
-        let offset_seconds = chrono::Local::now().offset().local_minus_utc() as f64;
+    pub fn get_timezone_offset(zone: LocalTimeZone) -> f64 {
+        // The offset is the difference between UTC and local time, so it is negative east of UTC.
+        let offset_seconds = -Utc::now().with_timezone(&zone).offset().local_minus_utc() as f64;
         offset_seconds / 60f64
     }
 
@@ -825,9 +1153,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setdate
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setDate
-    pub fn set_date(&mut self, day: Option<f64>) {
+    pub fn set_date(&mut self, zone: LocalTimeZone, day: Option<f64>) {
         if let Some(day) = day {
-            self.set_components(false, None, None, Some(day), None, None, None, None)
+            self.set_components(zone, None, None, Some(day), None, None, None, None)
         } else {
             self.0 = None
         }
@@ -844,9 +1172,15 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setfullyear
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setFullYear
-    pub fn set_full_year(&mut self, year: Option<f64>, month: Option<f64>, day: Option<f64>) {
+    pub fn set_full_year(
+        &mut self,
+        zone: LocalTimeZone,
+        year: Option<f64>,
+        month: Option<f64>,
+        day: Option<f64>,
+    ) {
         if let Some(year) = year {
-            self.set_components(false, Some(year), month, day, None, None, None, None)
+            self.set_components(zone, Some(year), month, day, None, None, None, None)
         } else {
             self.0 = None
         }
@@ -866,6 +1200,7 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setHours
     pub fn set_hours(
         &mut self,
+        zone: LocalTimeZone,
         hour: Option<f64>,
         minute: Option<f64>,
         second: Option<f64>,
@@ -873,7 +1208,7 @@ impl Date {
     ) {
         if let Some(hour) = hour {
             self.set_components(
-                false,
+                zone,
                 None,
                 None,
                 None,
@@ -897,9 +1232,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setmilliseconds
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMilliseconds
-    pub fn set_milliseconds(&mut self, millisecond: Option<f64>) {
+    pub fn set_milliseconds(&mut self, zone: LocalTimeZone, millisecond: Option<f64>) {
         if let Some(millisecond) = millisecond {
-            self.set_components(false, None, None, None, None, None, None, Some(millisecond))
+            self.set_components(zone, None, None, None, None, None, None, Some(millisecond))
         } else {
             self.0 = None
         }
@@ -917,13 +1252,14 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMinutes
     pub fn set_minutes(
         &mut self,
+        zone: LocalTimeZone,
         minute: Option<f64>,
         second: Option<f64>,
         millisecond: Option<f64>,
     ) {
         if let Some(minute) = minute {
             self.set_components(
-                false,
+                zone,
                 None,
                 None,
                 None,
@@ -947,9 +1283,9 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setmonth
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setMonth
-    pub fn set_month(&mut self, month: Option<f64>, day: Option<f64>) {
+    pub fn set_month(&mut self, zone: LocalTimeZone, month: Option<f64>, day: Option<f64>) {
         if let Some(month) = month {
-            self.set_components(false, None, Some(month), day, None, None, None, None)
+            self.set_components(zone, None, Some(month), day, None, None, None, None)
         } else {
             self.0 = None
         }
@@ -965,10 +1301,15 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setseconds
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setSeconds
-    pub fn set_seconds(&mut self, second: Option<f64>, millisecond: Option<f64>) {
+    pub fn set_seconds(
+        &mut self,
+        zone: LocalTimeZone,
+        second: Option<f64>,
+        millisecond: Option<f64>,
+    ) {
         if let Some(second) = second {
             self.set_components(
-                false,
+                zone,
                 None,
                 None,
                 None,
@@ -992,14 +1333,20 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.setyear
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setYear
-    pub fn set_year(&mut self, year: Option<f64>, month: Option<f64>, day: Option<f64>) {
+    pub fn set_year(
+        &mut self,
+        zone: LocalTimeZone,
+        year: Option<f64>,
+        month: Option<f64>,
+        day: Option<f64>,
+    ) {
         if let Some(mut year) = year {
             year += if (0f64..100f64).contains(&year) {
                 1900f64
             } else {
                 0f64
             };
-            self.set_components(false, Some(year), month, day, None, None, None, None)
+            self.set_components(zone, Some(year), month, day, None, None, None, None)
         } else {
             self.0 = None
         }
@@ -1038,7 +1385,16 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCDate
     pub fn set_utc_date(&mut self, day: Option<f64>) {
         if let Some(day) = day {
-            self.set_components(true, None, None, Some(day), None, None, None, None)
+            self.set_components(
+                LocalTimeZone::utc(),
+                None,
+                None,
+                Some(day),
+                None,
+                None,
+                None,
+                None,
+            )
         } else {
             self.0 = None
         }
@@ -1057,7 +1413,16 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCFullYear
     pub fn set_utc_full_year(&mut self, year: Option<f64>, month: Option<f64>, day: Option<f64>) {
         if let Some(year) = year {
-            self.set_components(true, Some(year), month, day, None, None, None, None)
+            self.set_components(
+                LocalTimeZone::utc(),
+                Some(year),
+                month,
+                day,
+                None,
+                None,
+                None,
+                None,
+            )
         } else {
             self.0 = None
         }
@@ -1084,7 +1449,7 @@ impl Date {
     ) {
         if let Some(hour) = hour {
             self.set_components(
-                true,
+                LocalTimeZone::utc(),
                 None,
                 None,
                 None,
@@ -1110,7 +1475,16 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCMilliseconds
     pub fn set_utc_milliseconds(&mut self, millisecond: Option<f64>) {
         if let Some(millisecond) = millisecond {
-            self.set_components(true, None, None, None, None, None, None, Some(millisecond))
+            self.set_components(
+                LocalTimeZone::utc(),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                Some(millisecond),
+            )
         } else {
             self.0 = None
         }
@@ -1134,7 +1508,7 @@ impl Date {
     ) {
         if let Some(minute) = minute {
             self.set_components(
-                true,
+                LocalTimeZone::utc(),
                 None,
                 None,
                 None,
@@ -1160,7 +1534,16 @@ impl Date {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/setUTCMonth
     pub fn set_utc_month(&mut self, month: Option<f64>, day: Option<f64>) {
         if let Some(month) = month {
-            self.set_components(true, None, Some(month), day, None, None, None, None)
+            self.set_components(
+                LocalTimeZone::utc(),
+                None,
+                Some(month),
+                day,
+                None,
+                None,
+                None,
+                None,
+            )
         } else {
             self.0 = None
         }
@@ -1179,7 +1562,7 @@ impl Date {
     pub fn set_utc_seconds(&mut self, second: Option<f64>, millisecond: Option<f64>) {
         if let Some(second) = second {
             self.set_components(
-                true,
+                LocalTimeZone::utc(),
                 None,
                 None,
                 None,
@@ -1203,8 +1586,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.todatestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toDateString
-    pub fn to_date_string(&self) -> String {
-        self.to_local()
+    pub fn to_date_string(&self, zone: LocalTimeZone) -> String {
+        self.to_local(zone)
             .map(|date_time| date_time.format("%a %b %d %Y").to_string())
             .unwrap_or_else(|| "Invalid Date".to_string())
     }
@@ -1235,11 +1618,28 @@ impl Date {
     /// [iso8601]: http://en.wikipedia.org/wiki/ISO_8601
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.toisostring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toISOString
-    pub fn to_iso_string(&self) -> String {
-        self.to_utc()
-            // RFC 3389 uses +0.00 for UTC, where JS expects Z, so we can't use the built-in chrono function.
-            .map(|f| f.format("%Y-%m-%dT%H:%M:%S.%3fZ").to_string())
-            .unwrap_or_else(|| "Invalid Date".to_string())
+    pub(crate) fn to_iso_string(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let tv be ? thisTimeValue(this value).
+        // 3. If tv is NaN, throw a RangeError exception.
+        let date_time = match this_time_value(this, context)?.to_utc() {
+            Some(date_time) => date_time,
+            None => return context.throw_range_error("Invalid time value"),
+        };
+
+        // Years outside of 0 to 9999 use the expanded format, with a sign and six digits.
+        let year = date_time.year();
+        let year = if (0..=9999).contains(&year) {
+            format!("{:04}", year)
+        } else {
+            format!("{:+07}", year)
+        };
+
+        // RFC 3389 uses +0.00 for UTC, where JS expects Z, so we can't use the built-in chrono function.
+        Ok(Value::from(format!(
+            "{}-{}",
+            year,
+            date_time.format("%m-%dT%H:%M:%S.%3fZ")
+        )))
     }
 
     /// `Date.prototype.toJSON()`
@@ -1252,8 +1652,40 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tojson
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toJSON
-    pub fn to_json(&self) -> String {
-        self.to_iso_string()
+    pub(crate) fn to_json(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? ToObject(this value).
+        let object = this.to_object(context)?;
+
+        // 2. Let tv be ? ToPrimitive(O, number).
+        let time_value =
+            Value::from(object.clone()).to_primitive(context, PreferredType::Number)?;
+
+        // 3. If Type(tv) is Number and tv is not finite, return null.
+        if let Value::Rational(time_value) = time_value {
+            if !time_value.is_finite() {
+                return Ok(Value::null());
+            }
+        }
+
+        // 4. Return ? Invoke(O, "toISOString").
+        let to_iso_string = object.get(&"toISOString".into(), object.clone().into(), context)?;
+        context.call(&to_iso_string, &object.into(), &[])
+    }
+
+    /// `Date.prototype.toString()`
+    ///
+    /// The `toString()` method returns a string representing the specified Date object in the time zone `zone`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.tostring
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toString
+    pub fn to_zoned_string(&self, zone: LocalTimeZone) -> String {
+        self.to_local(zone)
+            .map(|date_time| date_time.format("%a %b %d %Y %H:%M:%S GMT%:z").to_string())
+            .unwrap_or_else(|| "Invalid Date".to_string())
     }
 
     /// `Date.prototype.toTimeString()`
@@ -1267,8 +1699,8 @@ impl Date {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-date.prototype.totimestring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/toTimeString
-    pub fn to_time_string(&self) -> String {
-        self.to_local()
+    pub fn to_time_string(&self, zone: LocalTimeZone) -> String {
+        self.to_local(zone)
             .map(|date_time| date_time.format("%H:%M:%S GMT%:z").to_string())
             .unwrap_or_else(|| "Invalid Date".to_string())
     }
@@ -1303,6 +1735,47 @@ impl Date {
         self.get_time()
     }
 
+    /// `Date.prototype[@@toPrimitive]( hint )`
+    ///
+    /// The `[@@toPrimitive]()` method converts a `Date` object to a primitive value. Unlike other
+    /// objects, dates are converted to strings when no type is preferred, like in `date + ""`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-date.prototype-@@toprimitive
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Date/@@toPrimitive
+    pub(crate) fn to_primitive(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1. Let O be the this value.
+        // 2. If Type(O) is not Object, throw a TypeError exception.
+        let object = match this {
+            Value::Object(ref object) => object,
+            _ => {
+                return context
+                    .throw_type_error("Date.prototype[@@toPrimitive] called on non-object")
+            }
+        };
+
+        // 3. If hint is "string" or "default", then
+        //    a. Let tryFirst be string.
+        // 4. Else if hint is "number", then
+        //    a. Let tryFirst be number.
+        // 5. Else, throw a TypeError exception.
+        let try_first = match args.get(0).and_then(Value::as_string).map(RcString::as_str) {
+            Some("string") | Some("default") => PreferredType::String,
+            Some("number") => PreferredType::Number,
+            _ => return context.throw_type_error("Date.prototype[@@toPrimitive]: invalid hint"),
+        };
+
+        // 6. Return ? OrdinaryToPrimitive(O, tryFirst).
+        object.ordinary_to_primitive(context, try_first)
+    }
+
     /// `Date.now()`
     ///
     /// The static `Date.now()` method returns the number of milliseconds elapsed since January 1, 1970 00:00:00 UTC.
@@ -1337,10 +1810,10 @@ impl Date {
             return Ok(Value::number(f64::NAN));
         }
 
-        match DateTime::parse_from_rfc3339(&args[0].to_string(context)?) {
-            Ok(v) => Ok(Value::number(v.naive_utc().timestamp_millis() as f64)),
-            _ => Ok(Value::number(f64::NAN)),
-        }
+        let time = parse_date_time_string(&args[0].to_string(context)?, context.time_zone())
+            .map(|date_time| date_time.timestamp_millis() as f64)
+            .and_then(Self::time_clip);
+        Ok(Value::number(time.unwrap_or(f64::NAN)))
     }
 
     /// `Date.UTC()`
@@ -45,7 +45,9 @@ fn date_display() {
     assert_eq!(
         format!(
             "[{}]",
-            cd.to_local().unwrap().format("%a %b %d %Y %H:%M:%S GMT%:z")
+            cd.to_local(super::LocalTimeZone::Host)
+                .unwrap()
+                .format("%a %b %d %Y %H:%M:%S GMT%:z")
         ),
         format!("[{}]", cd)
     );
@@ -121,6 +123,97 @@ fn date_ctor_call_string_invalid() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn date_ctor_call_string_iso_variants() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    let date_time = forward_dt_utc(&mut context, "new Date('2024-01-02T03:04:05.678Z')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2024, 01, 02).and_hms_milli(03, 04, 05, 678)),
+        date_time
+    );
+
+    // Date-only forms are UTC times.
+    let date_time = forward_dt_utc(&mut context, "new Date('2024-01-02')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2024, 01, 02).and_hms(0, 0, 0)),
+        date_time
+    );
+
+    let date_time = forward_dt_utc(&mut context, "new Date('2024-03')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2024, 03, 01).and_hms(0, 0, 0)),
+        date_time
+    );
+
+    let date_time = forward_dt_utc(&mut context, "new Date('2024')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2024, 01, 01).and_hms(0, 0, 0)),
+        date_time
+    );
+
+    let date_time = forward_dt_utc(&mut context, "new Date('+002024-01-02T03:04Z')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2024, 01, 02).and_hms(03, 04, 0)),
+        date_time
+    );
+
+    let date_time = forward_dt_utc(&mut context, "new Date('2024-01-02T03:04:05+01:30')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2024, 01, 02).and_hms(01, 34, 05)),
+        date_time
+    );
+
+    let date_time = forward_dt_utc(&mut context, "new Date('2024-01-02T24:00:00Z')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2024, 01, 03).and_hms(0, 0, 0)),
+        date_time
+    );
+
+    // Date-time forms without an offset are local times.
+    let date_time = forward_dt_local(&mut context, "new Date('2024-01-02T03:04:05')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2024, 01, 02).and_hms(03, 04, 05)),
+        date_time
+    );
+    Ok(())
+}
+
+#[test]
+fn date_ctor_call_string_leap_year() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    let date_time = forward_dt_utc(&mut context, "new Date('2024-02-29')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2024, 02, 29).and_hms(0, 0, 0)),
+        date_time
+    );
+
+    let date_time = forward_dt_utc(&mut context, "new Date('2023-02-29')");
+    assert_eq!(None, date_time);
+    Ok(())
+}
+
+#[test]
+fn date_ctor_call_string_invalid_iso() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    for invalid in &[
+        "2024-13-01",
+        "2024-01-02T25:00Z",
+        "2024-01-02T24:01Z",
+        "2024-01-02T03:04:05.Z",
+        "2024-01-02T03:04:05+0100",
+        "2024-01-02 03:04:05Z",
+        "-000000-01-01",
+        "20240102",
+    ] {
+        let date_time = forward_dt_utc(&mut context, &format!("new Date('{}')", invalid));
+        assert_eq!(None, date_time, "{}", invalid);
+    }
+    Ok(())
+}
+
 #[test]
 fn date_ctor_call_number() -> Result<(), Box<dyn std::error::Error>> {
     let mut context = Context::new();
@@ -217,6 +310,21 @@ fn date_ctor_parse_call() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn date_ctor_parse_call_iso_variants() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    let date_time = forward_val(&mut context, "Date.parse('2024-01-02T03:04:05.678Z')");
+    assert_eq!(Ok(Value::Rational(1704164645678f64)), date_time);
+
+    let date_time = forward_val(&mut context, "Date.parse('2024-02-29')");
+    assert_eq!(Ok(Value::Rational(1709164800000f64)), date_time);
+
+    let date_time = forward(&mut context, "Date.parse('invalid')");
+    assert_eq!("NaN", date_time);
+    Ok(())
+}
+
 #[test]
 fn date_ctor_utc_call() -> Result<(), Box<dyn std::error::Error>> {
     let mut context = Context::new();
@@ -421,7 +529,7 @@ fn date_proto_get_timezone_offset() -> Result<(), Box<dyn std::error::Error>> {
     );
 
     // The value of now().offset() depends on the host machine, so we have to replicate the method code here.
-    let offset_seconds = chrono::Local::now().offset().local_minus_utc() as f64;
+    let offset_seconds = -Local::now().offset().local_minus_utc() as f64;
     let offset_minutes = offset_seconds / 60f64;
     assert_eq!(Ok(Value::Rational(offset_minutes)), actual);
 
@@ -433,6 +541,59 @@ fn date_proto_get_timezone_offset() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn date_fixed_time_zone() {
+    let mut context = Context::new();
+    context.set_time_zone("+05:30".parse::<super::LocalTimeZone>().unwrap());
+    forward(
+        &mut context,
+        "let date = new Date(Date.UTC(2020, 6, 8, 22, 45, 30));",
+    );
+
+    assert_eq!(forward(&mut context, "date.getTimezoneOffset()"), "-330");
+    assert_eq!(forward(&mut context, "date.getHours()"), "4");
+    assert_eq!(forward(&mut context, "date.getMinutes()"), "15");
+    assert_eq!(forward(&mut context, "date.getDate()"), "9");
+    assert_eq!(
+        forward(&mut context, "date.toString()"),
+        "\"Thu Jul 09 2020 04:15:30 GMT+05:30\""
+    );
+
+    // The UTC methods don't depend on the time zone.
+    assert_eq!(forward(&mut context, "date.getUTCHours()"), "22");
+    assert_eq!(forward(&mut context, "date.getUTCDate()"), "8");
+
+    // Local components are interpreted in the time zone.
+    assert_eq!(
+        forward(&mut context, "new Date(2020, 0, 1).toISOString()"),
+        "\"2019-12-31T18:30:00.000Z\""
+    );
+    assert_eq!(
+        forward(&mut context, "date.setHours(0); date.toISOString()"),
+        "\"2020-07-08T18:45:30.000Z\""
+    );
+
+    context.set_time_zone("UTC".parse::<super::LocalTimeZone>().unwrap());
+    assert_eq!(forward(&mut context, "date.getTimezoneOffset()"), "0");
+    assert_eq!(forward(&mut context, "date.getHours()"), "18");
+}
+
+#[test]
+fn parse_time_zones() {
+    use super::LocalTimeZone;
+
+    let offset = |seconds| Ok(LocalTimeZone::Fixed(FixedOffset::east(seconds)));
+    assert_eq!("local".parse(), Ok(LocalTimeZone::Host));
+    assert_eq!("UTC".parse(), offset(0));
+    assert_eq!("Z".parse(), offset(0));
+    assert_eq!("+05:30".parse(), offset(19800));
+    assert_eq!("-0800".parse(), offset(-28800));
+    assert_eq!("GMT+01".parse(), offset(3600));
+    assert!("Europe/Paris".parse::<LocalTimeZone>().is_err());
+    assert!("+24:00".parse::<LocalTimeZone>().is_err());
+    assert!("+5:30".parse::<LocalTimeZone>().is_err());
+}
+
 #[test]
 fn date_proto_get_utc_date_call() -> Result<(), Box<dyn std::error::Error>> {
     let mut context = Context::new();
@@ -1261,9 +1422,56 @@ fn date_proto_to_json() -> Result<(), Box<dyn std::error::Error>> {
     .expect("Successful eval");
     assert_eq!(Value::string("2020-07-08T09:16:15.779Z"), actual);
 
+    assert_eq!(forward(&mut context, "new Date(NaN).toJSON()"), "null");
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"JSON.stringify({ d: new Date(0) }) === '{"d":"1970-01-01T00:00:00.000Z"}'"#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Date.prototype.toJSON.call({ toISOString() { return 'iso'; } })"
+        ),
+        r#""iso""#
+    );
+
     Ok(())
 }
 
+#[test]
+fn date_proto_to_iso_string_padding_and_invalid() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "new Date(0).toISOString()"),
+        r#""1970-01-01T00:00:00.000Z""#
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Date(Date.UTC(2020, 0, 2, 3, 4, 5, 6)).toISOString()"
+        ),
+        r#""2020-01-02T03:04:05.006Z""#
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Date(Date.UTC(10000, 0, 1)).toISOString()"
+        ),
+        r#""+010000-01-01T00:00:00.000Z""#
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Date(NaN).toISOString() } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+}
+
 #[test]
 fn date_proto_to_string() -> Result<(), Box<dyn std::error::Error>> {
     let mut context = Context::new();
@@ -1362,6 +1570,41 @@ fn date_neg() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn date_proto_to_primitive() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    forward(
+        &mut context,
+        "var date = new Date(Date.UTC(2020, 06, 08, 09, 16, 15, 779));",
+    );
+    assert_eq!(
+        forward(&mut context, "date + 1 === date.toString() + '1'"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "date - 1"), "1594199775778");
+    assert_eq!(
+        forward(&mut context, "date[Symbol.toPrimitive]('number')"),
+        "1594199775779"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "date[Symbol.toPrimitive]('default') === date.toString()"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { date[Symbol.toPrimitive]('other') } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+
+    Ok(())
+}
+
 #[test]
 fn date_json() -> Result<(), Box<dyn std::error::Error>> {
     let mut context = Context::new();
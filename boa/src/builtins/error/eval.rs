@@ -13,10 +13,10 @@
 
 use crate::object::PROTOTYPE;
 use crate::{
-    builtins::BuiltIn,
+    builtins::{error::ErrorKind, BuiltIn},
     object::{ConstructorBuilder, ObjectData},
     profiler::BoaProfiler,
-    property::Attribute,
+    property::{Attribute, DataDescriptor},
     Context, Result, Value,
 };
 
@@ -76,13 +76,21 @@ impl EvalError {
         let this = Value::from(obj);
         if let Some(message) = args.get(0) {
             if !message.is_undefined() {
-                this.set_field("message", message.to_string(context)?, false, context)?;
+                let message = message.to_string(context)?;
+                this.set_property(
+                    "message",
+                    DataDescriptor::new(
+                        message,
+                        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                    ),
+                );
             }
         }
 
         // This value is used by console.log and other routines to match Object type
         // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::Error);
+        this.set_data(ObjectData::Error(ErrorKind::Eval));
+        super::Error::capture_stack(&this, context)?;
         Ok(this)
     }
 }
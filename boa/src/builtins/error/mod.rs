@@ -12,9 +12,10 @@
 
 use crate::{
     builtins::BuiltIn,
+    gc::{empty_trace, Finalize, Trace},
     object::{ConstructorBuilder, ObjectData, PROTOTYPE},
     profiler::BoaProfiler,
-    property::Attribute,
+    property::{Attribute, DataDescriptor},
     Context, Result, Value,
 };
 
@@ -35,6 +36,29 @@ pub(crate) use self::reference::ReferenceError;
 pub(crate) use self::syntax::SyntaxError;
 pub(crate) use self::uri::UriError;
 
+/// The standard type of an error object, which is the constructor that created it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+pub enum ErrorKind {
+    /// An object created by the `Error` constructor.
+    Error,
+    /// An object created by the `EvalError` constructor.
+    Eval,
+    /// An object created by the `RangeError` constructor.
+    Range,
+    /// An object created by the `ReferenceError` constructor.
+    Reference,
+    /// An object created by the `SyntaxError` constructor.
+    Syntax,
+    /// An object created by the `TypeError` constructor.
+    Type,
+    /// An object created by the `URIError` constructor.
+    Uri,
+}
+
+unsafe impl Trace for ErrorKind {
+    empty_trace!();
+}
+
 /// Built-in `Error` object.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct Error;
@@ -92,16 +116,56 @@ impl Error {
         let this = Value::from(obj);
         if let Some(message) = args.get(0) {
             if !message.is_undefined() {
-                this.set_field("message", message.to_string(context)?, false, context)?;
+                let message = message.to_string(context)?;
+                this.set_property(
+                    "message",
+                    DataDescriptor::new(
+                        message,
+                        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                    ),
+                );
             }
         }
 
         // This value is used by console.log and other routines to match Object type
         // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::Error);
+        this.set_data(ObjectData::Error(ErrorKind::Error));
+        Self::capture_stack(&this, context)?;
         Ok(this)
     }
 
+    /// Captures the call stack in the non-standard `stack`, `lineNumber` and `columnNumber`
+    /// properties of a new error object.
+    ///
+    /// The `stack` contains the string representation of the error, followed by a
+    /// `at functionName (line:column)` line for each frame, from the innermost one. The line
+    /// and column numbers are the ones of the statement being executed in the innermost frame.
+    pub(crate) fn capture_stack(error: &Value, context: &mut Context) -> Result<()> {
+        let mut stack = Self::to_string(error, &[], context)?
+            .to_string(context)?
+            .to_string();
+        let mut frames = context.executor().stack_frames().peekable();
+        let position = frames.peek().and_then(|frame| frame.position());
+        for frame in frames {
+            stack.push_str("\n    ");
+            stack.push_str(&frame.to_string());
+        }
+
+        let attribute = Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        error.set_property("stack", DataDescriptor::new(stack, attribute));
+        if let Some(position) = position {
+            error.set_property(
+                "lineNumber",
+                DataDescriptor::new(position.line_number(), attribute),
+            );
+            error.set_property(
+                "columnNumber",
+                DataDescriptor::new(position.column_number(), attribute),
+            );
+        }
+        Ok(())
+    }
+
     /// `Error.prototype.toString()`
     ///
     /// The toString() method returns a string representing the specified Error object.
@@ -10,10 +10,10 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RangeError
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{error::ErrorKind, BuiltIn},
     object::{ConstructorBuilder, ObjectData, PROTOTYPE},
     profiler::BoaProfiler,
-    property::Attribute,
+    property::{Attribute, DataDescriptor},
     Context, Result, Value,
 };
 
@@ -73,13 +73,21 @@ impl RangeError {
         let this = Value::from(obj);
         if let Some(message) = args.get(0) {
             if !message.is_undefined() {
-                this.set_field("message", message.to_string(context)?, false, context)?;
+                let message = message.to_string(context)?;
+                this.set_property(
+                    "message",
+                    DataDescriptor::new(
+                        message,
+                        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                    ),
+                );
             }
         }
 
         // This value is used by console.log and other routines to match Object type
         // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::Error);
+        this.set_data(ObjectData::Error(ErrorKind::Range));
+        super::Error::capture_stack(&this, context)?;
         Ok(this)
     }
 }
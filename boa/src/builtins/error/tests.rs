@@ -1,3 +1,4 @@
+use super::ErrorKind;
 use crate::{forward, Context};
 
 #[test]
@@ -84,3 +85,213 @@ fn uri_error_to_string() {
         "\"URIError\""
     );
 }
+
+#[test]
+fn uri_error_prototype() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(new URIError()) === URIError.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(URIError()) === URIError.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(URIError.prototype) === Error.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new URIError('x') instanceof Error"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "new URIError('x').message"), "\"x\"");
+    assert_eq!(forward(&mut context, "new URIError().name"), "\"URIError\"");
+}
+
+#[test]
+fn uri_error_thrown_by_context() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { decodeURI('%') } catch (e) { e instanceof URIError && e.message }"
+        ),
+        "\"URI malformed\""
+    );
+}
+
+#[test]
+fn line_and_column_number() {
+    let mut context = Context::new();
+    let init = r#"
+        function f() {
+            throw new Error("boom");
+        }
+        let error;
+        try {
+            f();
+        } catch (e) {
+            error = e;
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error.lineNumber"), "3");
+    assert_eq!(forward(&mut context, "error.columnNumber"), "13");
+}
+
+#[test]
+fn thrown_by_context_has_position() {
+    let mut context = Context::new();
+    let init = r#"
+        let error;
+        try {
+            null.x;
+        } catch (e) {
+            error = e;
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error instanceof TypeError"), "true");
+    assert_eq!(forward(&mut context, "error.lineNumber"), "4");
+}
+
+#[test]
+fn stack() {
+    let mut context = Context::new();
+    let init = r#"
+        function inner() {
+            return new RangeError("r");
+        }
+        function outer() {
+            return inner();
+        }
+        let error = outer();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "error.stack"),
+        "\"RangeError: r\n    at inner (3:13)\n    at outer (6:13)\n    at <global> (8:9)\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(error, 'stack').enumerable"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn error_kind_of_thrown_values() {
+    let mut context = Context::new();
+
+    let error = context.eval("null.x").unwrap_err();
+    assert_eq!(error.as_error_kind(), Some(ErrorKind::Type));
+    let message = error.error_message(&mut context).unwrap().unwrap();
+    assert!(!message.is_empty());
+
+    let error = context
+        .eval("throw new RangeError('out of range')")
+        .unwrap_err();
+    assert_eq!(error.as_error_kind(), Some(ErrorKind::Range));
+    assert_eq!(
+        error.error_message(&mut context).unwrap().unwrap().as_str(),
+        "out of range"
+    );
+
+    let error = context.eval("throw 'not an error'").unwrap_err();
+    assert_eq!(error.as_error_kind(), None);
+    assert_eq!(error.error_message(&mut context).unwrap(), None);
+}
+
+#[test]
+fn error_to_string_generic() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "Error.prototype.toString.call({ name: 'Custom', message: 'oops' })"
+        ),
+        "\"Custom: oops\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Error.prototype.toString.call({ message: 'oops' })"
+        ),
+        "\"Error: oops\""
+    );
+    assert_eq!(
+        forward(&mut context, "Error.prototype.toString.call({ name: '' })"),
+        "\"\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Error.prototype.toString.call(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn error_message_is_not_enumerable() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "Object.keys(new RangeError('oops')).length"),
+        "0"
+    );
+    assert_eq!(
+        forward(&mut context, "new Error('oops').hasOwnProperty('message')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "new Error().hasOwnProperty('message')"),
+        "false"
+    );
+}
+
+#[test]
+fn error_subclass() {
+    let mut context = Context::new();
+    let init = r#"
+        function MyError(message) {
+            return Reflect.construct(Error, [message], MyError);
+        }
+        MyError.prototype = Object.create(Error.prototype);
+        MyError.prototype.constructor = MyError;
+
+        function NamedError(message) {
+            return Reflect.construct(Error, [message], NamedError);
+        }
+        NamedError.prototype = Object.create(Error.prototype);
+        NamedError.prototype.name = "NamedError";
+
+        let error = new MyError("oops");
+        let named = new NamedError("oops");
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error instanceof MyError"), "true");
+    assert_eq!(forward(&mut context, "error instanceof Error"), "true");
+    assert_eq!(forward(&mut context, "error.name"), "\"Error\"");
+    assert_eq!(forward(&mut context, "error.message"), "\"oops\"");
+    assert_eq!(forward(&mut context, "error.toString()"), "\"Error: oops\"");
+    assert_eq!(
+        forward(&mut context, "typeof error.stack === 'string'"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "named.toString()"),
+        "\"NamedError: oops\""
+    );
+}
@@ -11,14 +11,14 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/URIError
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{error::ErrorKind, BuiltIn},
     object::{ConstructorBuilder, ObjectData, PROTOTYPE},
     profiler::BoaProfiler,
-    property::Attribute,
+    property::{Attribute, DataDescriptor},
     Context, Result, Value,
 };
 
-/// JavaScript `URIError` impleentation.
+/// JavaScript `URIError` implementation.
 #[derive(Debug, Clone, Copy)]
 pub(crate) struct UriError;
 
@@ -68,19 +68,27 @@ impl UriError {
                     .transpose()
             })
             .transpose()?
-            .unwrap_or_else(|| context.standard_objects().error_object().prototype());
+            .unwrap_or_else(|| context.standard_objects().uri_error_object().prototype());
         let mut obj = context.construct_object();
         obj.set_prototype_instance(prototype.into());
         let this = Value::from(obj);
         if let Some(message) = args.get(0) {
             if !message.is_undefined() {
-                this.set_field("message", message.to_string(context)?, false, context)?;
+                let message = message.to_string(context)?;
+                this.set_property(
+                    "message",
+                    DataDescriptor::new(
+                        message,
+                        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                    ),
+                );
             }
         }
 
         // This value is used by console.log and other routines to match Object type
         // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::Error);
+        this.set_data(ObjectData::Error(ErrorKind::Uri));
+        super::Error::capture_stack(&this, context)?;
         Ok(this)
     }
 }
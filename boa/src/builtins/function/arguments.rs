@@ -0,0 +1,168 @@
+//! This module implements the arguments exotic objects, which hold the arguments of a call to a
+//! function.
+//!
+//! In sloppy mode functions with a simple parameter list the arguments object is mapped: its
+//! indices are aliased to the parameters, so assigning to `arguments[0]` changes the first
+//! parameter and the other way around. In other functions it is unmapped, and only holds a copy
+//! of the arguments.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/arguments
+
+use crate::{
+    builtins::function::BuiltInFunctionObject,
+    environment::lexical_environment::Environment,
+    gc::{Finalize, Trace},
+    object::{GcObject, Object, ObjectData},
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyKey},
+    symbol::WellKnownSymbols,
+    syntax::ast::node::FormalParameter,
+    Context, Value,
+};
+
+/// The parameter map of a mapped arguments object, which aliases its indices to the bindings of
+/// the parameters.
+#[derive(Debug, Trace, Finalize)]
+pub struct ParameterMap {
+    /// The environment of the call, with the bindings of the parameters.
+    environment: Environment,
+    /// The name of the parameter each index is mapped to, if it is still mapped.
+    names: Vec<Option<Box<str>>>,
+}
+
+impl ParameterMap {
+    /// Gets the name of the parameter the property `key` is mapped to, if it is mapped.
+    pub(crate) fn mapped_name(&self, key: &PropertyKey) -> Option<&str> {
+        match key {
+            PropertyKey::Index(index) => self.names.get(*index as usize)?.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Gets the current value of the parameter `name`.
+    pub(crate) fn get(&self, name: &str) -> Option<Value> {
+        self.environment.get_initialized_binding_value(name)
+    }
+
+    /// Gets the environment with the bindings of the parameters.
+    pub(crate) fn environment(&self) -> &Environment {
+        &self.environment
+    }
+
+    /// Removes the mapping of the property `key`, so that it stops being aliased to a parameter.
+    pub(crate) fn unmap(&mut self, key: &PropertyKey) {
+        if let PropertyKey::Index(index) = key {
+            if let Some(name) = self.names.get_mut(*index as usize) {
+                *name = None;
+            }
+        }
+    }
+}
+
+/// Creates an arguments object with the properties shared by mapped and unmapped ones.
+fn create_arguments_object(
+    arguments_list: &[Value],
+    map: Option<ParameterMap>,
+    context: &mut Context,
+) -> GcObject {
+    let prototype = context.standard_objects().object_object().prototype();
+    let mut object = GcObject::new(Object::create(prototype.into()));
+    object.borrow_mut().data = ObjectData::Arguments(map);
+
+    object.insert(
+        "length",
+        DataDescriptor::new(
+            arguments_list.len(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
+    for (index, value) in arguments_list.iter().enumerate() {
+        object.insert(index, DataDescriptor::new(value.clone(), Attribute::all()));
+    }
+
+    // The iterator of arguments objects is `%Array.prototype.values%`.
+    let values = context
+        .standard_objects()
+        .array_object()
+        .prototype()
+        .get_own_property(&"values".into())
+        .and_then(|values| values.as_data_descriptor().map(DataDescriptor::value))
+        .unwrap_or_default();
+    object.insert(
+        WellKnownSymbols::iterator(),
+        DataDescriptor::new(
+            values,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
+
+    object
+}
+
+/// Creates the arguments object of a call to a strict mode function, or to a function without a
+/// simple parameter list.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createunmappedargumentsobject
+pub fn create_unmapped_arguments_object(arguments_list: &[Value], context: &mut Context) -> Value {
+    let mut object = create_arguments_object(arguments_list, None, context);
+
+    // The `callee` property throws when it's accessed, instead of exposing the function.
+    let throw_type_error = BuiltInFunctionObject::throw_type_error_function(context);
+    object.insert(
+        "callee",
+        AccessorDescriptor::new(
+            Some(throw_type_error.clone()),
+            Some(throw_type_error),
+            Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        ),
+    );
+
+    object.into()
+}
+
+/// Creates the arguments object of a call to a sloppy mode function with a simple parameter
+/// list, whose indices are aliased to the bindings of the parameters in `environment`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createmappedargumentsobject
+pub fn create_mapped_arguments_object(
+    function: &GcObject,
+    formals: &[FormalParameter],
+    arguments_list: &[Value],
+    environment: &Environment,
+    context: &mut Context,
+) -> Value {
+    // The indices past the last parameter, or whose parameter name is repeated by a later
+    // parameter, are not mapped.
+    let mut names: Vec<Option<Box<str>>> = vec![None; arguments_list.len().min(formals.len())];
+    for (index, formal) in formals.iter().enumerate().take(names.len()).rev() {
+        let name = formal.names()[0];
+        if !names.iter().flatten().any(|mapped| mapped.as_ref() == name) {
+            names[index] = Some(name.into());
+        }
+    }
+
+    let map = ParameterMap {
+        environment: environment.clone(),
+        names,
+    };
+    let mut object = create_arguments_object(arguments_list, Some(map), context);
+    object.insert(
+        "callee",
+        DataDescriptor::new(
+            function.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        ),
+    );
+
+    object.into()
+}
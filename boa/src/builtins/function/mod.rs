@@ -15,18 +15,29 @@ use crate::object::PROTOTYPE;
 use crate::{
     builtins::{Array, BuiltIn},
     environment::lexical_environment::Environment,
-    gc::{empty_trace, Finalize, Trace},
-    object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData},
-    property::{Attribute, DataDescriptor},
+    gc::{custom_trace, empty_trace, Finalize, Trace},
+    object::{
+        ConstructorBuilder, FunctionBuilder, GcObject, IntegrityLevel, NativeObject, Object,
+        ObjectData,
+    },
+    property::{Attribute, DataDescriptor, PropertyKey},
+    symbol::WellKnownSymbols,
     syntax::ast::node::{FormalParameter, RcStatementList},
     BoaProfiler, Context, Result, Value,
 };
 use bitflags::bitflags;
-use std::fmt::{self, Debug};
+use gc::{Gc, GcCell};
+use std::{
+    fmt::{self, Debug},
+    rc::Rc,
+};
 
+pub mod arguments;
 #[cfg(test)]
 mod tests;
 
+pub use arguments::{create_mapped_arguments_object, create_unmapped_arguments_object};
+
 /// _fn(this, arguments, context) -> ResultValue_ - The signature of a built-in function
 pub type NativeFunction = fn(&Value, &[Value], &mut Context) -> Result<Value>;
 
@@ -49,12 +60,101 @@ impl Debug for BuiltInFunction {
     }
 }
 
+/// _fn(this, arguments, captures, context) -> ResultValue_ - The signature of a closure built-in
+/// function
+pub type ClosureFunctionSignature =
+    dyn Fn(&Value, &[Value], &Captures, &mut Context) -> Result<Value>;
+
+/// The state captured by a closure built-in function, shared by every call of the function.
+///
+/// Unlike the environment of the closure, the captures are traced by the garbage collector, so
+/// they can hold garbage collected values.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Captures(Gc<GcCell<Box<dyn NativeObject>>>);
+
+impl Captures {
+    /// Creates new `Captures` from a captured value.
+    fn new<C>(captures: C) -> Self
+    where
+        C: NativeObject,
+    {
+        Self(Gc::new(GcCell::new(Box::new(captures))))
+    }
+}
+
+/// A built-in function implemented by a Rust closure, that can capture its environment.
+///
+/// The garbage collector doesn't trace the captured environment, so the closure must not capture
+/// garbage collected values, like `Value`s or `GcObject`s. Those have to be part of the
+/// `Captures` of the function instead.
+#[derive(Clone, Finalize)]
+pub struct ClosureFunction {
+    function: Rc<ClosureFunctionSignature>,
+    captures: Captures,
+}
+
+// Only safe while the closure doesn't capture any garbage collected value.
+unsafe impl Trace for ClosureFunction {
+    custom_trace!(this, mark(&this.captures));
+}
+
+impl ClosureFunction {
+    /// Creates a new `ClosureFunction` from a Rust closure.
+    pub(crate) fn new<F>(function: F) -> Self
+    where
+        F: Fn(&Value, &[Value], &mut Context) -> Result<Value> + 'static,
+    {
+        Self {
+            function: Rc::new(move |this, args, _, context| function(this, args, context)),
+            captures: Captures::new(()),
+        }
+    }
+
+    /// Creates a new `ClosureFunction` from a Rust closure and the state it captures.
+    pub(crate) fn with_captures<F, C>(function: F, captures: C) -> Self
+    where
+        F: Fn(&Value, &[Value], &C, &mut Context) -> Result<Value> + 'static,
+        C: NativeObject,
+    {
+        Self {
+            function: Rc::new(move |this, args, captures, context| {
+                let captures = captures.0.borrow();
+                let captures = (**captures)
+                    .as_any()
+                    .downcast_ref::<C>()
+                    .expect("the captures of a closure have the type it was created with");
+                function(this, args, captures, context)
+            }),
+            captures: Captures::new(captures),
+        }
+    }
+
+    /// Calls the closure.
+    pub(crate) fn call(
+        &self,
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        (self.function)(this, args, &self.captures, context)
+    }
+}
+
+impl Debug for ClosureFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[closure]")
+    }
+}
+
 bitflags! {
     #[derive(Finalize, Default)]
     pub struct FunctionFlags: u8 {
         const CALLABLE = 0b0000_0001;
         const CONSTRUCTABLE = 0b0000_0010;
         const LEXICAL_THIS_MODE = 0b0000_0100;
+        const GENERATOR = 0b0000_1000;
+        const CLASS_CONSTRUCTOR = 0b0001_0000;
+        const ASYNC = 0b0010_0000;
     }
 }
 
@@ -86,6 +186,21 @@ impl FunctionFlags {
     pub(crate) fn is_lexical_this_mode(&self) -> bool {
         self.contains(Self::LEXICAL_THIS_MODE)
     }
+
+    #[inline]
+    pub(crate) fn is_generator(&self) -> bool {
+        self.contains(Self::GENERATOR)
+    }
+
+    #[inline]
+    pub(crate) fn is_class_constructor(&self) -> bool {
+        self.contains(Self::CLASS_CONSTRUCTOR)
+    }
+
+    #[inline]
+    pub(crate) fn is_async(&self) -> bool {
+        self.contains(Self::ASYNC)
+    }
 }
 
 unsafe impl Trace for FunctionFlags {
@@ -100,11 +215,14 @@ unsafe impl Trace for FunctionFlags {
 #[derive(Debug, Clone, Finalize, Trace)]
 pub enum Function {
     BuiltIn(BuiltInFunction, FunctionFlags),
+    Closure(ClosureFunction, FunctionFlags),
     Ordinary {
         flags: FunctionFlags,
         body: RcStatementList,
         params: Box<[FormalParameter]>,
         environment: Environment,
+        /// The object whose prototype `super` refers to, if the function is a method.
+        home_object: Option<GcObject>,
     },
 }
 
@@ -117,47 +235,36 @@ impl Function {
         args_list: &[Value],
         context: &mut Context,
         local_env: &Environment,
-    ) {
+    ) -> Result<()> {
         // Create array of values
         let array = Array::new_array(context);
-        Array::add_to_array_object(&array, &args_list.get(index..).unwrap_or_default(), context)
-            .unwrap();
-
-        // Create binding
-        local_env
-            // Function parameters can share names in JavaScript...
-            .create_mutable_binding(param.name().to_owned(), false, true, context)
-            .expect("Failed to create binding for rest param");
+        Array::add_to_array_object(&array, &args_list.get(index..).unwrap_or_default(), context)?;
 
-        // Set Binding to value
-        local_env
-            .initialize_binding(param.name(), array, context)
-            .expect("Failed to initialize rest param");
+        self.add_arguments_to_environment(param, array, local_env, context)
     }
 
-    // Adds an argument to the environment
+    // Adds an argument to the environment, destructuring it if the parameter is a pattern
     pub(crate) fn add_arguments_to_environment(
         &self,
         param: &FormalParameter,
         value: Value,
         local_env: &Environment,
         context: &mut Context,
-    ) {
-        // Create binding
-        local_env
-            .create_mutable_binding(param.name().to_owned(), false, true, context)
-            .expect("Failed to create binding");
-
-        // Set Binding to value
-        local_env
-            .initialize_binding(param.name(), value, context)
-            .expect("Failed to intialize binding");
+    ) -> Result<()> {
+        param
+            .binding()
+            .initialize(value, context, &mut |name, value, context| {
+                // Function parameters can share names in JavaScript, so the binding is
+                // recreated before it is initialized.
+                local_env.create_mutable_binding(name.to_owned(), false, true, context)?;
+                local_env.initialize_binding(name, value, context)
+            })
     }
 
     /// Returns true if the function object is callable.
     pub fn is_callable(&self) -> bool {
         match self {
-            Self::BuiltIn(_, flags) => flags.is_callable(),
+            Self::BuiltIn(_, flags) | Self::Closure(_, flags) => flags.is_callable(),
             Self::Ordinary { flags, .. } => flags.is_callable(),
         }
     }
@@ -165,38 +272,19 @@ impl Function {
     /// Returns true if the function object is constructable.
     pub fn is_constructable(&self) -> bool {
         match self {
-            Self::BuiltIn(_, flags) => flags.is_constructable(),
+            Self::BuiltIn(_, flags) | Self::Closure(_, flags) => flags.is_constructable(),
             Self::Ordinary { flags, .. } => flags.is_constructable(),
         }
     }
-}
 
-/// Arguments.
-///
-/// <https://tc39.es/ecma262/#sec-createunmappedargumentsobject>
-pub fn create_unmapped_arguments_object(arguments_list: &[Value]) -> Value {
-    let len = arguments_list.len();
-    let mut obj = GcObject::new(Object::default());
-    // Set length
-    let length = DataDescriptor::new(
-        len,
-        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
-    );
-    // Define length as a property
-    obj.ordinary_define_own_property("length", length.into());
-    let mut index: usize = 0;
-    while index < len {
-        let val = arguments_list.get(index).expect("Could not get argument");
-        let prop = DataDescriptor::new(
-            val.clone(),
-            Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
-        );
-
-        obj.insert(index, prop);
-        index += 1;
+    /// Returns true if the function object is the constructor of a class, which throws a
+    /// `TypeError` when it's called without `new`.
+    pub fn is_class_constructor(&self) -> bool {
+        match self {
+            Self::Ordinary { flags, .. } => flags.is_class_constructor(),
+            _ => false,
+        }
     }
-
-    Value::from(obj)
 }
 
 /// Creates a new member function of a `Object` or `prototype`.
@@ -248,6 +336,133 @@ pub fn make_builtin_fn<N>(
     );
 }
 
+/// Sets the `name` property of a function to the property key it is defined with.
+///
+/// The name of a function whose key is a symbol is the description of the symbol in brackets,
+/// and the prefix of getters and setters, `"get"` or `"set"`, is put before the name.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-setfunctionname
+pub(crate) fn set_function_name(function: &mut GcObject, key: &PropertyKey, prefix: Option<&str>) {
+    let name = match key {
+        PropertyKey::Symbol(ref symbol) => match symbol.description() {
+            Some(description) => format!("[{}]", description),
+            None => String::new(),
+        },
+        key => key.to_string(),
+    };
+    let name = match prefix {
+        Some(prefix) if name.is_empty() => prefix.to_owned(),
+        Some(prefix) => format!("{} {}", prefix, name),
+        None => name,
+    };
+    function.insert_property("name", name, Attribute::CONFIGURABLE);
+}
+
+/// Makes a function a method of `home_object`, so the `super` property accesses of its body read
+/// the properties of the prototype of `home_object`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-makemethod
+pub(crate) fn make_method(function: &GcObject, home_object: GcObject) {
+    if let ObjectData::Function(Function::Ordinary {
+        home_object: ref mut slot,
+        ..
+    }) = function.borrow_mut().data
+    {
+        *slot = Some(home_object);
+    }
+}
+
+/// A bound function exotic object, created by `Function.prototype.bind`.
+///
+/// It wraps a target function, calling it with a fixed `this` value and with the bound
+/// arguments before the arguments of the call.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct BoundFunction {
+    target_function: GcObject,
+    this: Value,
+    args: Vec<Value>,
+}
+
+impl BoundFunction {
+    /// Abstract operation `BoundFunctionCreate`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-boundfunctioncreate
+    pub(crate) fn create(target_function: GcObject, this: Value, args: Vec<Value>) -> GcObject {
+        // 1. Let proto be ? targetFunction.[[GetPrototypeOf]]().
+        let prototype = target_function.get_prototype_of();
+
+        let mut object = Object::create(prototype);
+        object.data = ObjectData::BoundFunction(Self {
+            target_function,
+            this,
+            args,
+        });
+        GcObject::new(object)
+    }
+
+    /// The function wrapped by this bound function.
+    #[inline]
+    pub fn target_function(&self) -> &GcObject {
+        &self.target_function
+    }
+
+    /// The arguments of the call, after the bound arguments.
+    fn arguments(&self, args: &[Value]) -> Vec<Value> {
+        self.args.iter().chain(args).cloned().collect()
+    }
+
+    /// `[[Call]]` of a bound function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects-call-thisargument-argumentslist
+    pub(crate) fn call(&self, args: &[Value], context: &mut Context) -> Result<Value> {
+        self.target_function
+            .call(&self.this, &self.arguments(args), context)
+    }
+
+    /// `[[Construct]]` of a bound function, `this_object` being the bound function itself.
+    ///
+    /// The bound `this` value is ignored, and `new_target` is replaced with the target function
+    /// if it's the bound function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-bound-function-exotic-objects-construct-argumentslist-newtarget
+    pub(crate) fn construct(
+        &self,
+        this_object: &GcObject,
+        args: &[Value],
+        new_target: &Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let new_target = match new_target.as_object() {
+            Some(ref new_target) if GcObject::equals(new_target, this_object) => {
+                self.target_function.clone().into()
+            }
+            _ => new_target.clone(),
+        };
+        self.target_function
+            .construct(&self.arguments(args), &new_target, context)
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct BuiltInFunctionObject;
 
@@ -329,6 +544,138 @@ impl BuiltInFunctionObject {
         // TODO?: 5. PrepareForTailCall
         context.call(this, &this_arg, &arg_list)
     }
+
+    /// `Function.prototype.bind( thisArg, ...args )`
+    ///
+    /// The bind() method creates a new function that calls self with the given `this` value,
+    /// and with the given arguments before the arguments of the call.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype.bind
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/bind
+    fn bind(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let Target be the this value.
+        // 2. If IsCallable(Target) is false, throw a TypeError exception.
+        let target = match this.as_object() {
+            Some(target) if target.is_callable() => target,
+            _ => return context.throw_type_error("Bind must be called on a function"),
+        };
+
+        let this_arg = args.get(0).cloned().unwrap_or_default();
+        let bound_args = args.get(1..).unwrap_or(&[]).to_vec();
+        let arg_count = bound_args.len() as f64;
+
+        // 3. Let F be ? BoundFunctionCreate(Target, thisArg, args).
+        let mut function = BoundFunction::create(target.clone(), this_arg, bound_args);
+
+        // 4. Let L be 0.
+        let mut length = 0.0;
+
+        // 5. Let targetHasLength be ? HasOwnProperty(Target, "length").
+        // 6. If targetHasLength is true, then
+        if target.has_own_property("length") {
+            // a. Let targetLen be ? Get(Target, "length").
+            // b. If Type(targetLen) is Number, then
+            if let Some(target_length) = target
+                .get(&"length".into(), target.clone().into(), context)?
+                .as_number()
+            {
+                // i. If targetLen is +∞𝔽, set L to +∞.
+                // ii. Else if targetLen is -∞𝔽, set L to 0.
+                // iii. Else, set L to max(ToIntegerOrInfinity(targetLen) - argCount, 0).
+                length = if target_length.is_nan() {
+                    0.0
+                } else if target_length.is_infinite() {
+                    target_length.max(0.0)
+                } else {
+                    (target_length.trunc() - arg_count).max(0.0)
+                };
+            }
+        }
+
+        // 7. Perform ! SetFunctionLength(F, L).
+        function.define_property_or_throw(
+            "length",
+            DataDescriptor::new(length, Attribute::CONFIGURABLE),
+            context,
+        )?;
+
+        // 8. Let targetName be ? Get(Target, "name").
+        // 9. If Type(targetName) is not String, set targetName to the empty String.
+        let target_name = target.get(&"name".into(), target.clone().into(), context)?;
+        let target_name = target_name.as_string().map_or("", |name| name.as_str());
+
+        // 10. Perform SetFunctionName(F, targetName, "bound").
+        function.define_property_or_throw(
+            "name",
+            DataDescriptor::new(format!("bound {}", target_name), Attribute::CONFIGURABLE),
+            context,
+        )?;
+
+        // 11. Return F.
+        Ok(function.into())
+    }
+
+    /// `Function.prototype [ @@hasInstance ] ( V )`
+    ///
+    /// The `[Symbol.hasInstance]()` method determines if a value is an instance of the function,
+    /// by looking for the `prototype` of the function in the prototype chain of the value. It's
+    /// what the `instanceof` operator uses for functions that don't define their own.
+    ///
+    /// More information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-function.prototype-@@hasinstance
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Function/@@hasInstance
+    fn has_instance(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let F be the this value.
+        // 2. Return ? OrdinaryHasInstance(F, V).
+        match this.as_object() {
+            Some(function) => Ok(function
+                .ordinary_has_instance(context, &args.get(0).cloned().unwrap_or_default())?
+                .into()),
+            None => Ok(false.into()),
+        }
+    }
+
+    /// `%ThrowTypeError%`
+    ///
+    /// The function used as the getter and the setter of the properties that can't be accessed
+    /// in strict mode code, like the `callee` property of unmapped arguments objects.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%throwtypeerror%
+    fn throw_type_error(_: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        context.throw_type_error(
+            "'caller', 'callee', and 'arguments' properties may not be accessed on strict mode functions or the arguments objects for calls to them",
+        )
+    }
+
+    /// Gets the `%ThrowTypeError%` function of the realm, creating it the first time it's used.
+    pub(crate) fn throw_type_error_function(context: &mut Context) -> GcObject {
+        if let Some(ref function) = context.realm.throw_type_error {
+            return function.clone();
+        }
+
+        let mut function = FunctionBuilder::new(context, Self::throw_type_error)
+            .name("")
+            .length(0)
+            .callable(true)
+            .constructable(false)
+            .build();
+        function
+            .set_integrity_level(IntegrityLevel::Frozen, context)
+            .expect("freezing a new function can't fail");
+
+        context.realm.throw_type_error = Some(function.clone());
+        function
+    }
 }
 
 impl BuiltIn for BuiltInFunctionObject {
@@ -341,6 +688,15 @@ impl BuiltIn for BuiltInFunctionObject {
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event("function", "init");
 
+        // The `[Symbol.hasInstance]` method can't be overwritten, so that a function can't be
+        // made to pretend that values inherit from it.
+        let has_instance = FunctionBuilder::new(context, Self::has_instance)
+            .name("[Symbol.hasInstance]")
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+
         let function_prototype = context.standard_objects().function_object().prototype();
         FunctionBuilder::new(context, Self::prototype)
             .name("")
@@ -358,6 +714,12 @@ impl BuiltIn for BuiltInFunctionObject {
         .length(Self::LENGTH)
         .method(Self::call, "call", 1)
         .method(Self::apply, "apply", 1)
+        .method(Self::bind, "bind", 1)
+        .property(
+            WellKnownSymbols::has_instance(),
+            has_instance,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        )
         .build();
 
         (Self::NAME, function_object.into(), Self::attribute())
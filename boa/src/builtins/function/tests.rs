@@ -1,4 +1,5 @@
-use crate::{forward, forward_val, Context};
+use crate::{forward, forward_val, object::FunctionBuilder, property::Attribute, Context, Value};
+use std::{cell::Cell, rc::Rc};
 
 #[allow(clippy::float_cmp)]
 #[test]
@@ -24,6 +25,147 @@ fn arguments_object() {
     );
 }
 
+#[test]
+fn arguments_object_properties() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function f(a, b) {
+            return arguments;
+        }
+        var args = f(1, 2, 3);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "args.length"), "3");
+    assert_eq!(forward(&mut context, "args[2]"), "3");
+    assert_eq!(forward(&mut context, "args.callee === f"), "true");
+    assert_eq!(forward(&mut context, "[...args].join()"), "\"1,2,3\"");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(args)"),
+        "\"[object Arguments]\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(args).join()"),
+        "\"0,1,2\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "function g() { return (() => arguments[0])(); } g(7)"
+        ),
+        "7"
+    );
+}
+
+#[test]
+fn mapped_arguments_object() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function setParameter(a, b) {
+            a = 10;
+            return arguments[0];
+        }
+        function setArgument(a, b) {
+            arguments[1] = 20;
+            return b;
+        }
+        function unmapped(a) {
+            delete arguments[0];
+            arguments[0] = 30;
+            return a;
+        }
+        function missing(a, b) {
+            b = 40;
+            return arguments[1];
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "setParameter(1, 2)"), "10");
+    assert_eq!(forward(&mut context, "setArgument(1, 2)"), "20");
+    assert_eq!(forward(&mut context, "unmapped(1)"), "1");
+    assert_eq!(forward(&mut context, "missing(1)"), "undefined");
+}
+
+#[test]
+fn unmapped_arguments_object() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function strict(a) {
+            'use strict';
+            a = 10;
+            arguments.length = 5;
+            return [arguments[0], arguments.length].join();
+        }
+        function defaults(a = 0) {
+            arguments[0] = 20;
+            return a;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "strict(1)"), "\"1,5\"");
+    assert_eq!(forward(&mut context, "defaults(1)"), "1");
+}
+
+#[test]
+fn unmapped_arguments_callee_throws() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function strict() {
+            'use strict';
+            return arguments;
+        }
+        function access(f) {
+            try {
+                f();
+                return "no error";
+            } catch (e) {
+                return e.constructor.name;
+            }
+        }
+        var args = strict();
+        var callee = Object.getOwnPropertyDescriptor(args, "callee");
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "access(() => args.callee)"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "access(() => { 'use strict'; args.callee = 1; })"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "access(function (a = 1) { arguments.callee; })"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "callee.get === callee.set"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "callee.get === Object.getOwnPropertyDescriptor(strict(), 'callee').get"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "callee.enumerable || callee.configurable"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "Object.isFrozen(callee.get)"), "true");
+}
+
 #[test]
 fn self_mutating_function_when_calling() {
     let mut context = Context::new();
@@ -212,3 +354,322 @@ fn function_prototype_apply_on_object() {
         .unwrap();
     assert!(boolean);
 }
+
+#[test]
+fn function_prototype_bind() {
+    let mut context = Context::new();
+    let init = r#"
+        function f(a, b, c) {
+            return this.base + a + b + c;
+        }
+        var bound = f.bind({ base: 100 }, 1);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "bound(10, 20)"), "131");
+    assert_eq!(
+        forward(&mut context, "bound.call({ base: 0 }, 2, 3)"),
+        "106"
+    );
+    assert_eq!(
+        forward(&mut context, "Array.bind(null, 1, 2)(3).join()"),
+        "\"1,2,3\""
+    );
+    assert_eq!(forward(&mut context, "typeof bound"), "\"function\"");
+}
+
+#[test]
+fn function_prototype_bind_name_and_length() {
+    let mut context = Context::new();
+    let init = r#"
+        function f(a, b, c) {}
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "f.bind().name"), "\"bound f\"");
+    assert_eq!(
+        forward(&mut context, "f.bind().bind().name"),
+        "\"bound bound f\""
+    );
+    assert_eq!(
+        forward(&mut context, "(() => {}).bind().name"),
+        "\"bound \""
+    );
+    assert_eq!(forward(&mut context, "f.bind().length"), "3");
+    assert_eq!(forward(&mut context, "f.bind(null, 1).length"), "2");
+    assert_eq!(
+        forward(&mut context, "f.bind(null, 1, 2, 3, 4).length"),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(f.bind(), 'name').writable"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn function_prototype_bind_constructor() {
+    let mut context = Context::new();
+    let init = r#"
+        function Point(x, y) {
+            this.x = x;
+            this.y = y;
+        }
+        var BoundPoint = Point.bind({ x: "ignored" }, 1);
+        var point = new BoundPoint(2);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "point.x"), "1");
+    assert_eq!(forward(&mut context, "point.y"), "2");
+    assert_eq!(forward(&mut context, "point instanceof Point"), "true");
+    assert_eq!(forward(&mut context, "point instanceof BoundPoint"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new (Math.max.bind(null))() } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Function.prototype.bind.call({}) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn function_prototype_has_instance() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        "function F() {} var f = new F(); var hasInstance = Function.prototype[Symbol.hasInstance];",
+    );
+
+    assert_eq!(forward(&mut context, "hasInstance.call(F, f)"), "true");
+    assert_eq!(forward(&mut context, "hasInstance.call(F, {})"), "false");
+    assert_eq!(forward(&mut context, "hasInstance.call(F, 1)"), "false");
+    assert_eq!(forward(&mut context, "hasInstance.call({}, f)"), "false");
+    assert_eq!(
+        forward(&mut context, "hasInstance.call(F.bind(null), f)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "hasInstance.name"),
+        "\"[Symbol.hasInstance]\""
+    );
+    assert_eq!(forward(&mut context, "hasInstance.length"), "1");
+    // The method can't be replaced by assignment.
+    forward(
+        &mut context,
+        "F[Symbol.hasInstance] = function() { return true; };",
+    );
+    assert_eq!(forward(&mut context, "({}) instanceof F"), "false");
+}
+
+#[test]
+fn closure_function() {
+    let mut context = Context::new();
+    let counter = Rc::new(Cell::new(0));
+
+    let calls = counter.clone();
+    let function = FunctionBuilder::closure(&mut context, move |this, args, context| {
+        calls.set(calls.get() + 1);
+        let first = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_number(context)?;
+        let this = this.get_field("base", context)?.to_number(context)?;
+        Ok(Value::from(first + this))
+    })
+    .name("add")
+    .length(1)
+    .build();
+    context.register_global_property("add", function, Attribute::all());
+
+    assert_eq!(forward(&mut context, "add.name"), "\"add\"");
+    assert_eq!(forward(&mut context, "add.length"), "1");
+    assert_eq!(forward(&mut context, "({ base: 1, add: add }).add(2)"), "3");
+    assert_eq!(forward(&mut context, "add.call({ base: 10 }, 5)"), "15");
+    assert_eq!(counter.get(), 2);
+}
+
+#[test]
+fn function_name_inference() {
+    let mut context = Context::new();
+    let init = r#"
+        const arrow = () => {};
+        let expression = function () {};
+        var generator = function* () {};
+        const named = function inner() {};
+        const cls = class {};
+        const withStaticName = class { static name() {} };
+        let assigned;
+        assigned = () => {};
+        function withDefault(parameter = () => {}) { return parameter.name; }
+        const symbol = Symbol("description");
+        const object = {
+            method() {},
+            property: () => {},
+            get accessor() { return 1; },
+            [symbol]: function () {},
+            1: () => {},
+        };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "arrow.name"), "\"arrow\"");
+    assert_eq!(forward(&mut context, "expression.name"), "\"expression\"");
+    assert_eq!(forward(&mut context, "generator.name"), "\"generator\"");
+    assert_eq!(forward(&mut context, "named.name"), "\"inner\"");
+    assert_eq!(forward(&mut context, "cls.name"), "\"cls\"");
+    assert_eq!(
+        forward(&mut context, "typeof withStaticName.name"),
+        "\"function\""
+    );
+    assert_eq!(forward(&mut context, "assigned.name"), "\"assigned\"");
+    assert_eq!(forward(&mut context, "withDefault()"), "\"parameter\"");
+    assert_eq!(forward(&mut context, "object.method.name"), "\"method\"");
+    assert_eq!(
+        forward(&mut context, "object.property.name"),
+        "\"property\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(object, 'accessor').get.name"
+        ),
+        "\"get accessor\""
+    );
+    assert_eq!(
+        forward(&mut context, "object[symbol].name"),
+        "\"[description]\""
+    );
+    assert_eq!(forward(&mut context, "object[1].name"), "\"1\"");
+    assert_eq!(forward(&mut context, "(() => {}).name"), "\"\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(arrow, 'name').configurable"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(arrow, 'name').writable"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn arrow_function_lexical_this() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var object = {
+            value: 42,
+            getter() {
+                return () => this.value;
+            },
+            first() {
+                return (() => arguments[0])("arrow");
+            },
+        };
+        var arrow = object.getter();
+        var other = { value: 1, arrow };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "arrow()"), "42");
+    assert_eq!(forward(&mut context, "other.arrow()"), "42");
+    assert_eq!(forward(&mut context, "arrow.call({ value: 2 })"), "42");
+    assert_eq!(forward(&mut context, "arrow.bind({ value: 3 })()"), "42");
+    assert_eq!(
+        forward(&mut context, "object.first('method')"),
+        "\"method\""
+    );
+    assert_eq!(
+        forward(&mut context, "(() => this)() === globalThis"),
+        "true"
+    );
+}
+
+#[test]
+fn arrow_function_not_constructor() {
+    let mut context = Context::new();
+
+    forward(&mut context, "var arrow = () => {};");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new arrow(); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "arrow.hasOwnProperty('prototype')"),
+        "false"
+    );
+}
+
+#[test]
+fn new_target() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function f() {
+            return new.target;
+        }
+        function Constructor() {
+            this.target = new.target;
+            this.arrow = (() => new.target)();
+        }
+        function Other() {}
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "f()"), "undefined");
+    assert_eq!(forward(&mut context, "f.call({})"), "undefined");
+    assert_eq!(forward(&mut context, "new f() === f"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "var object = new Constructor(); object.target === Constructor"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "object.arrow === Constructor"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.construct(Constructor, [], Other).target === Other"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new (Constructor.bind(null))().target === Constructor"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.construct(f.bind(null), [], Other) === Other"
+        ),
+        "true"
+    );
+    assert!(forward(&mut context, "new.target").starts_with("Uncaught \"SyntaxError\": "));
+}
@@ -0,0 +1,651 @@
+//! This module implements generator objects, the iterators returned by generator functions.
+//!
+//! The interpreter walks the AST recursively, so it can't pause in the middle of the evaluation
+//! of a node. When a generator is suspended by a `yield` expression, the evaluation of its body
+//! is unwound instead, and when it's resumed the body is replayed up to that `yield` expression.
+//!
+//! While replaying, the nodes that completed before the generator was suspended are not evaluated
+//! again, the value they had is used instead, so their side effects only happen once. The nodes
+//! holding state that can't be rebuilt, like block environments or the iterator of a `for...of`
+//! loop, save it when they are unwound and restore it when they are replayed.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-generator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator
+
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        iterable::{create_iter_result_object, IteratorRecord},
+    },
+    environment::lexical_environment::Environment,
+    exec::InterpreterState,
+    gc::{custom_trace, empty_trace, Finalize, Trace},
+    object::{GcObject, ObjectData, PROTOTYPE},
+    property::{Attribute, DataDescriptor},
+    symbol::WellKnownSymbols,
+    syntax::ast::node::{Node, RcStatementList},
+    BoaProfiler, Context, Result, Value,
+};
+use rustc_hash::FxHashMap;
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a generator.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-internal-slots-of-generator-instances
+#[derive(Debug, Clone, Copy, PartialEq, Finalize)]
+enum GeneratorState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+unsafe impl Trace for GeneratorState {
+    empty_trace!();
+}
+
+/// The internal representation of a generator object.
+#[derive(Debug, Trace, Finalize)]
+pub struct Generator {
+    state: GeneratorState,
+    /// The execution context of the body, which is taken while it's executing, and dropped once
+    /// the generator is completed.
+    context: Option<GeneratorContext>,
+}
+
+/// The execution context of the body of a generator, or of an async function.
+#[derive(Debug, Trace, Finalize)]
+pub(crate) struct GeneratorContext {
+    function_name: Box<str>,
+    body: RcStatementList,
+    /// The environments of the generator function, from the outermost to the innermost.
+    environments: Vec<Environment>,
+    frame: GeneratorFrame,
+}
+
+/// How the execution of the body of a generator or of an async function stopped.
+#[derive(Debug)]
+pub(crate) enum BodyCompletion {
+    /// The body is suspended by a `yield` expression, producing a value.
+    ///
+    /// `delegated` is `true` if the value was produced by the iterator a `yield*` expression
+    /// delegates to.
+    Yield { value: Value, delegated: bool },
+    /// The body is suspended by an `await` expression, until the given promise is settled.
+    Await(Value),
+    /// The body completed, returning a value.
+    Return(Value),
+}
+
+/// How a suspended generator is resumed.
+///
+/// An async function suspended by an `await` expression is resumed with `Next` when the promise
+/// is fulfilled, and with `Throw` when it's rejected.
+#[derive(Debug, Clone, Finalize)]
+pub(crate) enum Resumption {
+    /// The generator is resumed by `next( value )`.
+    Next(Value),
+    /// The generator is resumed by `throw( exception )`.
+    Throw(Value),
+    /// The generator is resumed by `return( value )`.
+    Return(Value),
+}
+
+// The state of the generator is moved out of these enums, so the `Drop` implementation added by
+// `#[derive(Trace)]` can't be used.
+unsafe impl Trace for Resumption {
+    custom_trace!(this, {
+        match this {
+            Self::Next(value) | Self::Throw(value) | Self::Return(value) => mark(value),
+        }
+    });
+}
+
+/// Why the body of a generator is being unwound.
+#[derive(Debug, Finalize)]
+enum Unwinding {
+    /// The generator is suspended by a `yield` expression.
+    ///
+    /// `delegated` is `true` if the value is the result of the iterator a `yield*` expression
+    /// delegates to, which is returned as is.
+    Yield { value: Value, delegated: bool },
+    /// The async function is suspended by an `await` expression, until the promise is settled.
+    Await(Value),
+    /// The generator returns early, because it was resumed by `return( value )`.
+    Return(Value),
+}
+
+unsafe impl Trace for Unwinding {
+    custom_trace!(this, {
+        match this {
+            Self::Yield { value, .. } | Self::Await(value) | Self::Return(value) => mark(value),
+        }
+    });
+}
+
+/// The state a node of the body of a generator saves when the generator is suspended, to resume
+/// its evaluation when it is replayed.
+#[derive(Debug, Finalize)]
+pub(crate) enum ResumeState {
+    /// The environment of a block, of a `for` loop or of a `with` statement.
+    Environment(Environment),
+    /// The iterator and the environment of the current iteration of a `for...of` or a `for...in`
+    /// loop.
+    Iteration {
+        iterator: IteratorRecord,
+        environment: Environment,
+    },
+    /// The number of declarations of a declaration list that were already evaluated.
+    Declaration(usize),
+    /// A `try` statement suspended in its `catch` block, with the environment of the block.
+    CatchBlock(Environment),
+    /// A `try` statement suspended in its `finally` block, with the completion of the statement
+    /// before the `finally` block, and the value of the early return of the generator, if any.
+    FinallyBlock {
+        completion: Result<Value>,
+        returning: Option<Value>,
+    },
+    /// The iterator a `yield*` expression delegates to.
+    Delegate(IteratorRecord),
+    /// The async iterator of a `for await...of` loop, with the step of the iteration it's
+    /// suspended at.
+    AsyncIteration {
+        iterator: IteratorRecord,
+        step: AsyncIterationStep,
+    },
+    /// The async iterator a `yield*` expression of an async generator delegates to, with what
+    /// the expression is suspended for.
+    AsyncDelegate {
+        iterator: IteratorRecord,
+        step: AsyncDelegateStep,
+    },
+}
+
+/// The step of a `for await...of` loop that suspended its async function.
+#[derive(Debug, Finalize)]
+pub(crate) enum AsyncIterationStep {
+    /// The loop awaits the result of the `next` method of the iterator.
+    Next,
+    /// The body of the loop is suspended, in the environment of the current iteration.
+    Body(Environment),
+    /// The loop awaits the result of the `return` method of the iterator, before completing
+    /// with the given completion and interpreter state.
+    ///
+    /// `returning` is the value of the early return of the generator being executed, which
+    /// resumes once the iterator is closed.
+    Close {
+        completion: Result<Value>,
+        state: InterpreterState,
+        returning: Option<Value>,
+    },
+}
+
+unsafe impl Trace for AsyncIterationStep {
+    custom_trace!(this, {
+        match this {
+            Self::Next => {}
+            Self::Body(environment) => mark(environment),
+            Self::Close {
+                completion,
+                returning,
+                ..
+            } => {
+                mark(completion);
+                mark(returning);
+            }
+        }
+    });
+}
+
+/// What a `yield*` expression of an async generator is suspended for.
+#[derive(Debug, Clone, Copy, PartialEq, Finalize)]
+pub(crate) enum AsyncDelegateStep {
+    /// A value of the iterator was produced.
+    Yielded,
+    /// The result of the `next` or the `throw` method of the iterator is awaited.
+    Result,
+    /// The result of the `return` method of the iterator is awaited.
+    ReturnResult,
+    /// The result of the `return` method of an iterator without a `throw` method is awaited,
+    /// before throwing a `TypeError`.
+    Close,
+}
+
+unsafe impl Trace for AsyncDelegateStep {
+    empty_trace!();
+}
+
+unsafe impl Trace for ResumeState {
+    custom_trace!(this, {
+        match this {
+            Self::Environment(environment) | Self::CatchBlock(environment) => mark(environment),
+            Self::Iteration {
+                iterator,
+                environment,
+            } => {
+                mark(iterator);
+                mark(environment);
+            }
+            Self::Declaration(_) => {}
+            Self::FinallyBlock {
+                completion,
+                returning,
+            } => {
+                mark(completion);
+                mark(returning);
+            }
+            Self::Delegate(iterator) => mark(iterator),
+            Self::AsyncIteration { iterator, step } => {
+                mark(iterator);
+                mark(step);
+            }
+            Self::AsyncDelegate { iterator, .. } => mark(iterator),
+        }
+    });
+}
+
+/// The state the interpreter uses to suspend and resume the body of a generator.
+#[derive(Debug, Default, Trace, Finalize)]
+pub(crate) struct GeneratorFrame {
+    /// The values of the completed nodes of the body, with the interpreter state they left.
+    completed: FxHashMap<usize, (Value, InterpreterState)>,
+    /// The states saved by the nodes that were unwound when the generator was suspended, from the
+    /// innermost node to the outermost one.
+    resume_states: Vec<(usize, ResumeState)>,
+    /// The `yield` expression the generator is suspended at.
+    suspended_at: Option<usize>,
+    /// The `yield` expression the body is being replayed up to.
+    replaying: Option<usize>,
+    /// How the generator is being resumed.
+    resumption: Option<Resumption>,
+    /// Why the body of the generator is being unwound, if it is.
+    unwinding: Option<Unwinding>,
+    /// Whether the body is the body of an async function or of an async generator.
+    is_async: bool,
+}
+
+/// Gets the identity of a node of the AST, which doesn't move while its function exists.
+fn node_key<T>(node: &T) -> usize {
+    node as *const T as usize
+}
+
+impl GeneratorFrame {
+    /// Gets the value of a node that completed before the generator was suspended, and the
+    /// interpreter state it left, if the body is being replayed.
+    pub(crate) fn replayed_completion(&self, node: &Node) -> Option<(Value, InterpreterState)> {
+        self.replaying?;
+        self.completed.get(&node_key(node)).cloned()
+    }
+
+    /// Forgets the previous completion of a node whose evaluation is starting.
+    pub(crate) fn start_node(&mut self, node: &Node) {
+        self.completed.remove(&node_key(node));
+    }
+
+    /// Records the completion of a node.
+    pub(crate) fn complete_node(&mut self, node: &Node, value: Value, state: InterpreterState) {
+        self.completed.insert(node_key(node), (value, state));
+    }
+
+    /// Returns `true` if the body is being unwound, because the generator is suspended or returns
+    /// early. Exceptions can't be caught while the body is unwound.
+    pub(crate) fn is_unwinding(&self) -> bool {
+        self.unwinding.is_some()
+    }
+
+    /// Returns `true` if the body is being unwound because the generator is suspended.
+    pub(crate) fn is_suspending(&self) -> bool {
+        matches!(
+            self.unwinding,
+            Some(Unwinding::Yield { .. }) | Some(Unwinding::Await(_))
+        )
+    }
+
+    /// Returns `true` if the body is the body of an async function or of an async generator,
+    /// where `await` expressions can be evaluated.
+    pub(crate) fn is_async(&self) -> bool {
+        self.is_async
+    }
+
+    /// Saves the state of a node that is unwound, if the generator is suspended.
+    pub(crate) fn save_resume_state<T>(&mut self, node: &T, state: ResumeState) {
+        if self.is_suspending() {
+            self.resume_states.push((node_key(node), state));
+        }
+    }
+
+    /// Takes the state a node saved when the generator was suspended, if it's being replayed.
+    pub(crate) fn take_resume_state<T>(&mut self, node: &T) -> Option<ResumeState> {
+        match self.resume_states.last() {
+            Some((key, _)) if *key == node_key(node) => {
+                self.resume_states.pop().map(|(_, state)| state)
+            }
+            _ => None,
+        }
+    }
+
+    /// Takes how the generator is resumed, if it was suspended at the given `yield` expression,
+    /// which ends the replay of the body.
+    pub(crate) fn take_resumption<T>(&mut self, node: &T) -> Option<Resumption> {
+        if self.replaying == Some(node_key(node)) {
+            self.replaying = None;
+            self.resumption.take()
+        } else {
+            None
+        }
+    }
+
+    /// Suspends the generator at the given `yield` expression, producing the given value.
+    pub(crate) fn suspend<T>(&mut self, node: &T, value: Value, delegated: bool) {
+        self.suspended_at = Some(node_key(node));
+        self.unwinding = Some(Unwinding::Yield { value, delegated });
+    }
+
+    /// Suspends the async function at the given node, until the given promise is settled.
+    pub(crate) fn suspend_await<T>(&mut self, node: &T, promise: Value) {
+        self.suspended_at = Some(node_key(node));
+        self.unwinding = Some(Unwinding::Await(promise));
+    }
+
+    /// Makes the generator return early with the given value.
+    pub(crate) fn start_return(&mut self, value: Value) {
+        self.unwinding = Some(Unwinding::Return(value));
+    }
+
+    /// Takes the value of the early return of the generator, if it's returning early.
+    pub(crate) fn take_return(&mut self) -> Option<Value> {
+        match self.unwinding.take() {
+            Some(Unwinding::Return(value)) => Some(value),
+            unwinding => {
+                self.unwinding = unwinding;
+                None
+            }
+        }
+    }
+}
+
+impl GeneratorContext {
+    /// Creates the execution context of the body of a generator or of an async function, for a
+    /// call whose environments were prepared.
+    pub(crate) fn new(
+        function_name: Box<str>,
+        body: RcStatementList,
+        environments: Vec<Environment>,
+        is_async: bool,
+    ) -> Self {
+        Self {
+            function_name,
+            body,
+            environments,
+            frame: GeneratorFrame {
+                completed: FxHashMap::default(),
+                resume_states: Vec::new(),
+                suspended_at: None,
+                replaying: None,
+                resumption: None,
+                unwinding: None,
+                is_async,
+            },
+        }
+    }
+
+    /// Executes the body until it's suspended or it completes.
+    ///
+    /// The body starts if `resumption` is `None`, otherwise it's resumed at the expression it's
+    /// suspended at.
+    pub(crate) fn resume(
+        &mut self,
+        resumption: Option<Resumption>,
+        context: &mut Context,
+    ) -> Result<BodyCompletion> {
+        let starting = resumption.is_none();
+        if let Some(resumption) = resumption {
+            self.frame.replaying = self.frame.suspended_at.take();
+            self.frame.resumption = Some(resumption);
+        }
+
+        for environment in self.environments.iter() {
+            context.push_environment(environment.clone());
+        }
+        context
+            .executor()
+            .push_generator_frame(self.function_name.clone(), std::mem::take(&mut self.frame));
+
+        // The `var` declarations of the body are only hoisted when it starts.
+        let result = if starting {
+            self.body.run_body(context)
+        } else {
+            self.body.resume_body(context)
+        };
+
+        self.frame = context.executor().pop_generator_frame();
+        let returned = *context.executor().get_current_state() == InterpreterState::Return;
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+        for _ in self.environments.iter() {
+            context.pop_environment();
+        }
+        debug_assert!(
+            self.frame.replaying.is_none(),
+            "the replay of the body ended before the expression it's suspended at"
+        );
+
+        match self.frame.unwinding.take() {
+            Some(Unwinding::Yield { value, delegated }) => {
+                Ok(BodyCompletion::Yield { value, delegated })
+            }
+            Some(Unwinding::Await(promise)) => Ok(BodyCompletion::Await(promise)),
+            Some(Unwinding::Return(value)) => Ok(BodyCompletion::Return(value)),
+            None => {
+                let value = result?;
+                Ok(BodyCompletion::Return(if returned {
+                    value
+                } else {
+                    Value::undefined()
+                }))
+            }
+        }
+    }
+}
+
+impl Generator {
+    pub(crate) const NAME: &'static str = "Generator";
+
+    /// Creates a new generator object, for a call to the given generator function whose
+    /// environments were prepared.
+    pub(crate) fn create(
+        function: &GcObject,
+        function_name: Box<str>,
+        body: RcStatementList,
+        environments: Vec<Environment>,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let prototype = function.get(&PROTOTYPE.into(), function.clone().into(), context)?;
+        let prototype = if prototype.is_object() {
+            prototype
+        } else {
+            context.iterator_prototypes().generator().into()
+        };
+
+        let generator = Value::new_object(context);
+        generator
+            .as_object()
+            .expect("generator object")
+            .set_prototype_instance(prototype);
+        generator.set_data(ObjectData::Generator(Self {
+            state: GeneratorState::SuspendedStart,
+            context: Some(GeneratorContext::new(
+                function_name,
+                body,
+                environments,
+                false,
+            )),
+        }));
+        Ok(generator)
+    }
+
+    /// %GeneratorPrototype%.next( value )
+    ///
+    /// Resumes the generator, making the `yield` expression it's suspended at evaluate to the
+    /// given value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.next
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/next
+    pub(crate) fn next(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, Resumption::Next(value), context)
+    }
+
+    /// %GeneratorPrototype%.return( value )
+    ///
+    /// Resumes the generator as if a `return` statement was at the `yield` expression it's
+    /// suspended at, so only its `finally` blocks are executed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.return
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/return
+    pub(crate) fn r#return(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, Resumption::Return(value), context)
+    }
+
+    /// %GeneratorPrototype%.throw( exception )
+    ///
+    /// Resumes the generator, making the `yield` expression it's suspended at throw the given
+    /// exception.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generator.prototype.throw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Generator/throw
+    pub(crate) fn throw(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let exception = args.get(0).cloned().unwrap_or_default();
+        Self::resume(this, Resumption::Throw(exception), context)
+    }
+
+    /// GeneratorResume( generator, value ) and GeneratorResumeAbrupt( generator, abruptCompletion )
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-generatorresume
+    fn resume(this: &Value, resumption: Resumption, context: &mut Context) -> Result<Value> {
+        let object = match this.as_object() {
+            Some(object) if object.borrow().as_generator().is_some() => object,
+            _ => return context.throw_type_error("'this' is not a Generator"),
+        };
+
+        let state = object
+            .borrow()
+            .as_generator()
+            .expect("'this' is a Generator")
+            .state;
+        let (mut generator_context, resumption) = match (state, resumption) {
+            (GeneratorState::Executing, _) => {
+                return context.throw_type_error("generator is already running")
+            }
+            (GeneratorState::SuspendedYield, resumption) => (
+                Self::take_context(&object, GeneratorState::Executing)
+                    .expect("a suspended generator has an execution context"),
+                Some(resumption),
+            ),
+            (GeneratorState::SuspendedStart, Resumption::Next(_)) => (
+                Self::take_context(&object, GeneratorState::Executing)
+                    .expect("a suspended generator has an execution context"),
+                None,
+            ),
+            // A generator that didn't start is completed by an abrupt resumption, without
+            // executing its body.
+            (GeneratorState::SuspendedStart, Resumption::Return(value))
+            | (GeneratorState::Completed, Resumption::Return(value)) => {
+                Self::take_context(&object, GeneratorState::Completed);
+                return Ok(create_iter_result_object(context, value, true));
+            }
+            (GeneratorState::SuspendedStart, Resumption::Throw(exception))
+            | (GeneratorState::Completed, Resumption::Throw(exception)) => {
+                Self::take_context(&object, GeneratorState::Completed);
+                return Err(exception);
+            }
+            (GeneratorState::Completed, Resumption::Next(_)) => {
+                return Ok(create_iter_result_object(context, Value::undefined(), true));
+            }
+        };
+
+        match generator_context.resume(resumption, context) {
+            Ok(BodyCompletion::Yield { value, delegated }) => {
+                let mut object = object.borrow_mut();
+                let generator = object.as_generator_mut().expect("'this' is a Generator");
+                generator.state = GeneratorState::SuspendedYield;
+                generator.context = Some(generator_context);
+                drop(object);
+
+                if delegated {
+                    Ok(value)
+                } else {
+                    Ok(create_iter_result_object(context, value, false))
+                }
+            }
+            Ok(BodyCompletion::Await(_)) => unreachable!("await expression in a generator"),
+            Ok(BodyCompletion::Return(value)) => {
+                Self::take_context(&object, GeneratorState::Completed);
+                Ok(create_iter_result_object(context, value, true))
+            }
+            Err(error) => {
+                Self::take_context(&object, GeneratorState::Completed);
+                Err(error)
+            }
+        }
+    }
+
+    /// Takes the execution context of the generator, setting its new state.
+    fn take_context(object: &GcObject, state: GeneratorState) -> Option<GeneratorContext> {
+        let mut object = object.borrow_mut();
+        let generator = object.as_generator_mut().expect("'this' is a Generator");
+        generator.state = state;
+        generator.context.take()
+    }
+
+    /// Create the %GeneratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-properties-of-generator-prototype
+    pub(crate) fn create_prototype(context: &mut Context, iterator_prototype: Value) -> GcObject {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let mut generator = context.construct_object();
+        make_builtin_fn(Self::next, "next", &generator, 1, context);
+        make_builtin_fn(Self::r#return, "return", &generator, 1, context);
+        make_builtin_fn(Self::throw, "throw", &generator, 1, context);
+        generator.set_prototype_instance(iterator_prototype);
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+        let to_string_tag_property = DataDescriptor::new(
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+        generator.insert(to_string_tag, to_string_tag_property);
+        generator
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{forward, Context};
+
+#[test]
+fn counting_generator() {
+    let mut context = Context::new();
+    let init = r#"
+        function* count(n) {
+            for (let i = 0; i < n; i++) {
+                yield i;
+            }
+            return "done";
+        }
+        let generator = count(3);
+        let results = [];
+        let result;
+        while (!(result = generator.next()).done) {
+            results.push(result.value);
+        }
+        results.push(result.value);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "results.join()"), "\"0,1,2,done\"");
+    assert_eq!(forward(&mut context, "generator.next().done"), "true");
+    assert_eq!(forward(&mut context, "generator.next().value"), "undefined");
+}
+
+#[test]
+fn early_return() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        function* gen() {
+            try {
+                yield 1;
+                log.push("not executed");
+                yield 2;
+            } finally {
+                log.push("finally");
+            }
+        }
+        let generator = gen();
+        generator.next();
+        let result = generator.return(42);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result.value"), "42");
+    assert_eq!(forward(&mut context, "result.done"), "true");
+    assert_eq!(forward(&mut context, "log.join()"), "\"finally\"");
+    assert_eq!(forward(&mut context, "generator.next().done"), "true");
+}
+
+#[test]
+fn return_before_start() {
+    let mut context = Context::new();
+    let init = r#"
+        let executed = false;
+        function* gen() {
+            executed = true;
+            yield 1;
+        }
+        let generator = gen();
+        let result = generator.return(5);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result.value"), "5");
+    assert_eq!(forward(&mut context, "executed"), "false");
+    assert_eq!(forward(&mut context, "generator.next().done"), "true");
+}
+
+#[test]
+fn throw_into_body() {
+    let mut context = Context::new();
+    let init = r#"
+        function* gen() {
+            try {
+                yield 1;
+            } catch (e) {
+                yield "caught " + e;
+            }
+            yield "after";
+        }
+        let generator = gen();
+        generator.next();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "generator.throw('boom').value"),
+        "\"caught boom\""
+    );
+    assert_eq!(forward(&mut context, "generator.next().value"), "\"after\"");
+    assert_eq!(forward(&mut context, "generator.next().done"), "true");
+}
+
+#[test]
+fn uncaught_throw() {
+    let mut context = Context::new();
+    let init = r#"
+        function* gen() {
+            yield 1;
+            yield 2;
+        }
+        let generator = gen();
+        generator.next();
+        let error;
+        try {
+            generator.throw(new Error("boom"));
+        } catch (e) {
+            error = e;
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error.message"), "\"boom\"");
+    assert_eq!(forward(&mut context, "generator.next().done"), "true");
+}
+
+#[test]
+fn delegate_to_array() {
+    let mut context = Context::new();
+    let init = r#"
+        function* gen() {
+            yield 0;
+            yield* [1, 2, 3];
+            yield 4;
+        }
+        let values = [];
+        for (let value of gen()) {
+            values.push(value);
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "values.join()"), "\"0,1,2,3,4\"");
+}
+
+#[test]
+fn delegate_to_generator() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        function* inner() {
+            let received = yield "inner";
+            log.push(received);
+            return "inner result";
+        }
+        function* outer() {
+            let result = yield* inner();
+            log.push(result);
+        }
+        let generator = outer();
+        generator.next();
+        generator.next("sent");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.join()"), "\"sent,inner result\"");
+}
+
+#[test]
+fn next_value_is_the_yield_result() {
+    let mut context = Context::new();
+    let init = r#"
+        function* gen() {
+            let a = yield "first";
+            let b = yield a * 2;
+            return a + b;
+        }
+        let generator = gen();
+        let results = [generator.next().value, generator.next(5).value, generator.next(10).value];
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "results.join()"), "\"first,10,15\"");
+}
+
+#[test]
+fn side_effects_are_not_replayed() {
+    let mut context = Context::new();
+    let init = r#"
+        let calls = 0;
+        function f() {
+            calls++;
+            return calls;
+        }
+        function* gen() {
+            let a = f();
+            while (true) {
+                let b = f();
+                yield a + b;
+            }
+        }
+        let generator = gen();
+        let results = [generator.next().value, generator.next().value, generator.next().value];
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "results.join()"), "\"3,4,5\"");
+    assert_eq!(forward(&mut context, "calls"), "4");
+}
+
+#[test]
+fn already_running() {
+    let mut context = Context::new();
+    let init = r#"
+        let generator;
+        function* gen() {
+            generator.next();
+        }
+        generator = gen();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { generator.next() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn generator_objects() {
+    let mut context = Context::new();
+    let init = r#"
+        function* gen() {}
+        let generator = gen();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(generator)"),
+        "\"[object Generator]\""
+    );
+    assert_eq!(
+        forward(&mut context, "generator[Symbol.iterator]() === generator"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(generator) === gen.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new gen() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
@@ -8,3 +8,40 @@ fn global_this_exists_on_global_object_and_evaluates_to_an_object() {
 
     assert_eq!(&exec(scenario), "\"object\"");
 }
+
+#[test]
+fn global_this_round_trip() {
+    let scenario = r#"
+        globalThis.x = 1;
+        var y = 2;
+        [x === 1, globalThis.y === 2, "x" in globalThis].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,true,true\"");
+}
+
+#[test]
+fn global_this_is_the_global_this_value() {
+    let scenario = r#"
+        let sloppy = function () { return this; };
+        [
+            this === globalThis,
+            globalThis.globalThis === globalThis,
+            sloppy() === globalThis,
+        ].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,true,true\"");
+}
+
+#[test]
+fn global_this_attributes() {
+    let scenario = r#"
+        let descriptor = Object.getOwnPropertyDescriptor(globalThis, "globalThis");
+        let attributes = [descriptor.writable, descriptor.enumerable, descriptor.configurable];
+        globalThis.globalThis = 1;
+        attributes.concat(globalThis === 1).join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,false,true,true\"");
+}
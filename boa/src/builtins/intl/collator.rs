@@ -0,0 +1,166 @@
+//! This module implements the collator used by `String.prototype.localeCompare`.
+//!
+//! Only the `en-US` locale is supported, every other requested locale falls back to it. Strings
+//! are compared at three levels, like the Unicode Collation Algorithm does: first by their base
+//! characters, then by their accents and last by their case, so that `"a" < "á" < "A" < "b"`.
+//! Base characters are ordered with whitespace and punctuation first, then digits, then letters
+//! in alphabetical order. Letters without a decomposition, like `ß` or `ø`, are ordered after the
+//! basic Latin letters.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#collator-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/Collator
+
+use crate::{
+    builtins::intl::{canonicalize_locale_list, get_option},
+    Context, Result, Value,
+};
+use std::cmp::Ordering;
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+/// The differences between strings that make them compare as unequal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Sensitivity {
+    /// Only strings with different base letters are unequal, so `a = á = A`.
+    Base,
+    /// Strings with different base letters or accents are unequal, so `a ≠ á` but `a = A`.
+    Accent,
+    /// Strings with different base letters or case are unequal, so `a = á` but `a ≠ A`.
+    Case,
+    /// Strings that are not canonically equivalent are unequal.
+    Variant,
+}
+
+/// A collator, which compares strings in the order of the `en-US` locale.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Collator {
+    sensitivity: Sensitivity,
+}
+
+impl Collator {
+    /// Creates a collator from the `locales` and `options` arguments of the `Intl.Collator`
+    /// constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializecollator
+    pub(crate) fn new(locales: Value, options: Value, context: &mut Context) -> Result<Self> {
+        canonicalize_locale_list(locales, context)?;
+        let options = if options.is_undefined() {
+            Value::new_object(context)
+        } else {
+            options.to_object(context)?.into()
+        };
+
+        get_option(&options, "usage", &["sort", "search"], context)?;
+        get_option(&options, "localeMatcher", &["lookup", "best fit"], context)?;
+        let sensitivity = match get_option(
+            &options,
+            "sensitivity",
+            &["base", "accent", "case", "variant"],
+            context,
+        )?
+        .as_deref()
+        {
+            Some("base") => Sensitivity::Base,
+            Some("accent") => Sensitivity::Accent,
+            Some("case") => Sensitivity::Case,
+            _ => Sensitivity::Variant,
+        };
+
+        Ok(Self { sensitivity })
+    }
+
+    /// Compares two strings.
+    ///
+    /// This is a total order for every sensitivity, so it can be used to sort strings.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-collator-comparestrings
+    pub(crate) fn compare(&self, x: &str, y: &str) -> Ordering {
+        let (x_nfd, y_nfd): (String, String) = (x.nfd().collect(), y.nfd().collect());
+        let (x, y) = (collation_elements(&x_nfd), collation_elements(&y_nfd));
+
+        let primary = x
+            .iter()
+            .map(CollationElement::primary)
+            .cmp(y.iter().map(CollationElement::primary));
+        let secondary = || match self.sensitivity {
+            Sensitivity::Accent | Sensitivity::Variant => x
+                .iter()
+                .map(|element| element.accents)
+                .cmp(y.iter().map(|element| element.accents)),
+            Sensitivity::Base | Sensitivity::Case => Ordering::Equal,
+        };
+        let tertiary = || match self.sensitivity {
+            Sensitivity::Case | Sensitivity::Variant => x
+                .iter()
+                .map(|element| element.uppercase)
+                .cmp(y.iter().map(|element| element.uppercase)),
+            Sensitivity::Base | Sensitivity::Accent => Ordering::Equal,
+        };
+        // Strings that only differ by characters with the same weights, like `k` and the Kelvin
+        // sign, are ordered by code point so that only canonically equivalent strings are equal.
+        let identical = || match self.sensitivity {
+            Sensitivity::Variant => x_nfd.cmp(&y_nfd),
+            _ => Ordering::Equal,
+        };
+
+        primary
+            .then_with(secondary)
+            .then_with(tertiary)
+            .then_with(identical)
+    }
+}
+
+/// A base character of a decomposed string, with the accents that follow it.
+#[derive(Debug, Clone, Copy)]
+struct CollationElement<'string> {
+    base: char,
+    accents: &'string str,
+    uppercase: bool,
+}
+
+impl CollationElement<'_> {
+    /// The weight of the base character, which ignores its case.
+    fn primary(&self) -> (u8, char) {
+        let class = if self.base.is_alphabetic() {
+            2
+        } else if self.base.is_numeric() {
+            1
+        } else {
+            0
+        };
+        let lowercase = self.base.to_lowercase().next().unwrap_or(self.base);
+        (class, lowercase)
+    }
+}
+
+/// Splits a string in the NFD form into its collation elements.
+fn collation_elements(string: &str) -> Vec<CollationElement<'_>> {
+    let mut elements = Vec::new();
+    let mut chars = string.char_indices().peekable();
+    while let Some((index, base)) = chars.next() {
+        let start = index + base.len_utf8();
+        let mut end = start;
+        while let Some(&(index, accent)) = chars.peek() {
+            if !is_combining_mark(accent) {
+                break;
+            }
+            end = index + accent.len_utf8();
+            chars.next();
+        }
+        elements.push(CollationElement {
+            base,
+            accents: &string[start..end],
+            uppercase: base.is_uppercase(),
+        });
+    }
+    elements
+}
@@ -0,0 +1,141 @@
+//! This module implements the global `Intl` object.
+//!
+//! The `Intl` object is the namespace of the constructors for language sensitive formatting.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#intl-object
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl
+
+use crate::{
+    builtins::BuiltIn, object::ObjectInitializer, property::Attribute, symbol::WellKnownSymbols,
+    BoaProfiler, Context, Result, Value,
+};
+
+pub(crate) mod collator;
+pub mod number_format;
+
+pub(crate) use self::{collator::Collator, number_format::NumberFormat};
+
+/// JavaScript `Intl` object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Intl;
+
+impl BuiltIn for Intl {
+    const NAME: &'static str = "Intl";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let (number_format_name, number_format, number_format_attribute) =
+            NumberFormat::init(context);
+
+        let object = ObjectInitializer::new(context)
+            .property(number_format_name, number_format, number_format_attribute)
+            .property(
+                WellKnownSymbols::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .build();
+
+        (Self::NAME, object.into(), Self::attribute())
+    }
+}
+
+/// Converts the `locales` argument of the `Intl` constructors to a list of language tags.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-canonicalizelocalelist
+pub(crate) fn canonicalize_locale_list(
+    locales: Value,
+    context: &mut Context,
+) -> Result<Vec<String>> {
+    let tags = if locales.is_undefined() {
+        return Ok(Vec::new());
+    } else if locales.is_string() {
+        vec![locales]
+    } else {
+        let object = locales.to_object(context)?;
+        let length = object
+            .get(&"length".into(), object.clone().into(), context)?
+            .to_length(context)?;
+        let mut tags = Vec::with_capacity(length);
+        for index in 0..length {
+            if object.has_property(&index.into(), context)? {
+                tags.push(object.get(&index.into(), object.clone().into(), context)?);
+            }
+        }
+        tags
+    };
+
+    let mut list = Vec::with_capacity(tags.len());
+    for tag in tags {
+        if !tag.is_string() && !tag.is_object() {
+            return Err(context.construct_type_error("Language ID should be string or object."));
+        }
+        let tag = tag.to_string(context)?;
+        if !is_structurally_valid_language_tag(&tag) {
+            return Err(context
+                .construct_range_error(format!("Incorrect locale information provided: {}", tag)));
+        }
+        let tag = tag.to_string();
+        if !list.contains(&tag) {
+            list.push(tag);
+        }
+    }
+    Ok(list)
+}
+
+/// Checks that a language tag is made of a language subtag followed by alphanumeric subtags,
+/// like `en-US`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-isstructurallyvalidlanguagetag
+fn is_structurally_valid_language_tag(tag: &str) -> bool {
+    let mut subtags = tag.split('-');
+    let valid_language = subtags.next().map_or(false, |language| {
+        matches!(language.len(), 2..=3 | 5..=8)
+            && language.bytes().all(|byte| byte.is_ascii_alphabetic())
+    });
+    valid_language
+        && subtags.all(|subtag| {
+            matches!(subtag.len(), 1..=8) && subtag.bytes().all(|byte| byte.is_ascii_alphanumeric())
+        })
+}
+
+/// Reads a string option, which must be one of `values` if there are any.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getoption
+pub(crate) fn get_option(
+    options: &Value,
+    property: &str,
+    values: &[&str],
+    context: &mut Context,
+) -> Result<Option<String>> {
+    let value = options.get_field(property, context)?;
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    let value = value.to_string(context)?;
+    if !values.is_empty() && !values.contains(&value.as_str()) {
+        return Err(context.construct_range_error(format!(
+            "Value {} out of range for options property {}",
+            value, property
+        )));
+    }
+    Ok(Some(value.to_string()))
+}
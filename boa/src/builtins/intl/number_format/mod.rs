@@ -0,0 +1,592 @@
+//! This module implements the `Intl.NumberFormat` constructor.
+//!
+//! A `NumberFormat` object formats numbers as decimals, currency amounts or percentages. Only the
+//! `en-US` locale is supported, every other requested locale falls back to it.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma402/#numberformat-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat
+
+use crate::{
+    builtins::{
+        intl::{canonicalize_locale_list, get_option},
+        Array, BuiltIn,
+    },
+    gc::{Finalize, Trace},
+    object::{
+        ConstructorBuilder, FunctionBuilder, GcObject, ObjectData, ObjectInitializer, PROTOTYPE,
+    },
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    value::Numeric,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The locale every `NumberFormat` resolves to.
+const DEFAULT_LOCALE: &str = "en-US";
+
+/// The formatting style of a `NumberFormat`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Decimal,
+    Currency,
+    Percent,
+}
+
+impl Style {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Decimal => "decimal",
+            Self::Currency => "currency",
+            Self::Percent => "percent",
+        }
+    }
+}
+
+/// How the currency of a currency `NumberFormat` is displayed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CurrencyDisplay {
+    Symbol,
+    NarrowSymbol,
+    Code,
+    Name,
+}
+
+impl CurrencyDisplay {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Symbol => "symbol",
+            Self::NarrowSymbol => "narrowSymbol",
+            Self::Code => "code",
+            Self::Name => "name",
+        }
+    }
+}
+
+/// The internal representation of an `Intl.NumberFormat` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct NumberFormat {
+    #[unsafe_ignore_trace]
+    style: Style,
+    currency: Option<String>,
+    #[unsafe_ignore_trace]
+    currency_display: CurrencyDisplay,
+    minimum_fraction_digits: usize,
+    maximum_fraction_digits: usize,
+    use_grouping: bool,
+    /// The function returned by the `format` getter, created the first time it is used.
+    bound_format: Option<GcObject>,
+}
+
+impl BuiltIn for NumberFormat {
+    const NAME: &'static str = "NumberFormat";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let format_getter = FunctionBuilder::new(context, Self::format_getter)
+            .name("get format")
+            .constructable(false)
+            .callable(true)
+            .build();
+
+        let number_format_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().number_format_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_method(Self::supported_locales_of, "supportedLocalesOf", 1)
+        .accessor("format", Some(format_getter), None, Attribute::CONFIGURABLE)
+        .method(Self::resolved_options, "resolvedOptions", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            "Intl.NumberFormat",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        (Self::NAME, number_format_object.into(), Self::attribute())
+    }
+}
+
+impl NumberFormat {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `Intl.NumberFormat( [ locales [ , options ] ] )`
+    ///
+    /// It can be called with or without `new`, both create a new `NumberFormat`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/NumberFormat
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let number_format_prototype = context
+            .standard_objects()
+            .number_format_object()
+            .prototype();
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or(number_format_prototype);
+
+        // The requested locales are validated, but they all resolve to the default locale.
+        canonicalize_locale_list(args.get(0).cloned().unwrap_or_default(), context)?;
+        let number_format = Self::from_options(args.get(1).cloned().unwrap_or_default(), context)?;
+
+        let mut object = context.construct_object();
+        object.set_prototype_instance(prototype.into());
+        object.borrow_mut().data = ObjectData::NumberFormat(Box::new(number_format));
+        Ok(object.into())
+    }
+
+    /// Reads the options of a new `NumberFormat`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-initializenumberformat
+    fn from_options(options: Value, context: &mut Context) -> Result<Self> {
+        let options = if options.is_undefined() {
+            Value::new_object(context)
+        } else {
+            options.to_object(context)?.into()
+        };
+
+        let style = match get_option(
+            &options,
+            "style",
+            &["decimal", "currency", "percent"],
+            context,
+        )?
+        .as_deref()
+        {
+            Some("currency") => Style::Currency,
+            Some("percent") => Style::Percent,
+            _ => Style::Decimal,
+        };
+
+        let currency =
+            match get_option(&options, "currency", &[], context)? {
+                Some(currency) if is_well_formed_currency_code(&currency) => {
+                    Some(currency.to_ascii_uppercase())
+                }
+                Some(currency) => {
+                    return Err(context
+                        .construct_range_error(format!("Invalid currency code : {}", currency)))
+                }
+                None if style == Style::Currency => {
+                    return Err(context
+                        .construct_type_error("Currency code is required with currency style."))
+                }
+                None => None,
+            };
+
+        let currency_display = match get_option(
+            &options,
+            "currencyDisplay",
+            &["code", "symbol", "narrowSymbol", "name"],
+            context,
+        )?
+        .as_deref()
+        {
+            Some("code") => CurrencyDisplay::Code,
+            Some("narrowSymbol") => CurrencyDisplay::NarrowSymbol,
+            Some("name") => CurrencyDisplay::Name,
+            _ => CurrencyDisplay::Symbol,
+        };
+
+        let (default_minimum, default_maximum) = match (style, currency.as_deref()) {
+            (Style::Currency, Some(currency)) => {
+                let digits = currency_digits(currency);
+                (digits, digits)
+            }
+            (Style::Percent, _) => (0, 0),
+            _ => (0, 3),
+        };
+        let minimum_fraction_digits = get_number_option(
+            &options,
+            "minimumFractionDigits",
+            0,
+            20,
+            default_minimum,
+            context,
+        )?;
+        let maximum_fraction_digits = get_number_option(
+            &options,
+            "maximumFractionDigits",
+            minimum_fraction_digits,
+            20,
+            default_maximum.max(minimum_fraction_digits),
+            context,
+        )?;
+
+        let use_grouping = options.get_field("useGrouping", context)?;
+        let use_grouping = use_grouping.is_undefined() || use_grouping.to_boolean();
+
+        Ok(Self {
+            style,
+            currency,
+            currency_display,
+            minimum_fraction_digits,
+            maximum_fraction_digits,
+            use_grouping,
+            bound_format: None,
+        })
+    }
+
+    /// Gets the `NumberFormat` data of `this`.
+    fn this_number_format(this: &Value, context: &mut Context) -> Result<NumberFormat> {
+        if let Some(object) = this.as_object() {
+            if let Some(number_format) = object.borrow().as_number_format() {
+                return Ok(number_format.clone());
+            }
+        }
+        Err(context.construct_type_error("'this' is not an Intl.NumberFormat"))
+    }
+
+    /// `get Intl.NumberFormat.prototype.format`
+    ///
+    /// Returns a function that formats a number with this `NumberFormat`, which is bound to it
+    /// so it can be passed around, for example to `Array.prototype.map`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.format
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/format
+    pub(crate) fn format_getter(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let number_format = Self::this_number_format(this, context)?;
+        if let Some(ref bound_format) = number_format.bound_format {
+            return Ok(bound_format.clone().into());
+        }
+
+        let object = this.as_object().expect("checked by this_number_format");
+        let bound_format = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, object: &GcObject, context| {
+                let number_format = object
+                    .borrow()
+                    .as_number_format()
+                    .cloned()
+                    .expect("the bound object is a NumberFormat");
+                let value = args.get(0).cloned().unwrap_or_default();
+                Ok(number_format.format(&value, context)?.into())
+            },
+            object.clone(),
+        )
+        .length(1)
+        .build();
+
+        if let Some(number_format) = object.borrow_mut().as_number_format_mut() {
+            number_format.bound_format = Some(bound_format.clone());
+        }
+        Ok(bound_format.into())
+    }
+
+    /// `Intl.NumberFormat.prototype.resolvedOptions()`
+    ///
+    /// Returns a new object with the locale and the options used by this `NumberFormat`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.prototype.resolvedoptions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/resolvedOptions
+    pub(crate) fn resolved_options(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let number_format = Self::this_number_format(this, context)?;
+
+        let mut options = ObjectInitializer::new(context);
+        options
+            .property("locale", DEFAULT_LOCALE, Attribute::all())
+            .property("numberingSystem", "latn", Attribute::all())
+            .property("style", number_format.style.name(), Attribute::all());
+        if let Some(ref currency) = number_format.currency {
+            options
+                .property("currency", currency.as_str(), Attribute::all())
+                .property(
+                    "currencyDisplay",
+                    number_format.currency_display.name(),
+                    Attribute::all(),
+                );
+        }
+        options
+            .property(
+                "minimumFractionDigits",
+                number_format.minimum_fraction_digits,
+                Attribute::all(),
+            )
+            .property(
+                "maximumFractionDigits",
+                number_format.maximum_fraction_digits,
+                Attribute::all(),
+            )
+            .property("useGrouping", number_format.use_grouping, Attribute::all());
+        Ok(options.build().into())
+    }
+
+    /// `Intl.NumberFormat.supportedLocalesOf( locales [ , options ] )`
+    ///
+    /// Returns the requested locales that are supported without falling back to the default
+    /// locale.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-intl.numberformat.supportedlocalesof
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Intl/NumberFormat/supportedLocalesOf
+    pub(crate) fn supported_locales_of(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let locales = canonicalize_locale_list(args.get(0).cloned().unwrap_or_default(), context)?;
+        let supported: Vec<Value> = locales
+            .into_iter()
+            .filter(|locale| locale.eq_ignore_ascii_case(DEFAULT_LOCALE))
+            .map(Value::from)
+            .collect();
+        let array = Array::new_array(context);
+        Array::add_to_array_object(&array, &supported, context)?;
+        Ok(array)
+    }
+
+    /// Formats `value` with this `NumberFormat`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sec-formatnumber
+    fn format(&self, value: &Value, context: &mut Context) -> Result<String> {
+        let (negative, number) = match value.to_numeric(context)? {
+            Numeric::Number(number) if number.is_nan() => (false, "NaN".to_string()),
+            Numeric::Number(number) if number.is_infinite() => {
+                (number.is_sign_negative(), "∞".to_string())
+            }
+            Numeric::Number(number) => {
+                // The shortest representation of the number, like `1.2345e6`.
+                let scientific = format!("{:e}", number.abs());
+                let (mantissa, exponent) = scientific
+                    .split_once('e')
+                    .expect("scientific notation has an exponent");
+                let digits = mantissa.replace('.', "");
+                let exponent: i32 = exponent.parse().expect("invalid exponent");
+                (
+                    number.is_sign_negative(),
+                    self.format_digits(&digits, exponent + 1),
+                )
+            }
+            Numeric::BigInt(bigint) => {
+                let digits = bigint.to_string();
+                let negative = digits.starts_with('-');
+                let digits = digits.trim_start_matches('-');
+                (negative, self.format_digits(digits, digits.len() as i32))
+            }
+        };
+
+        let mut result = String::new();
+        if negative {
+            result.push('-');
+        }
+        match (self.style, self.currency.as_deref()) {
+            (Style::Currency, Some(currency)) => match self.currency_display {
+                CurrencyDisplay::Symbol | CurrencyDisplay::NarrowSymbol => {
+                    if let Some(symbol) = currency_symbol(currency, self.currency_display) {
+                        result.push_str(symbol);
+                    } else {
+                        result.push_str(currency);
+                        result.push('\u{a0}');
+                    }
+                    result.push_str(&number);
+                }
+                CurrencyDisplay::Code => {
+                    result.push_str(currency);
+                    result.push('\u{a0}');
+                    result.push_str(&number);
+                }
+                CurrencyDisplay::Name => {
+                    result.push_str(&number);
+                    result.push(' ');
+                    result.push_str(currency_name(currency).unwrap_or(currency));
+                }
+            },
+            (Style::Percent, _) => {
+                result.push_str(&number);
+                result.push('%');
+            }
+            _ => result.push_str(&number),
+        }
+        Ok(result)
+    }
+
+    /// Formats the decimal number `0.digits × 10^point`, rounded to the fraction digits of this
+    /// `NumberFormat` and grouped by thousands.
+    fn format_digits(&self, digits: &str, point: i32) -> String {
+        let mut digits: Vec<u8> = digits.bytes().map(|digit| digit - b'0').collect();
+        let mut point = if self.style == Style::Percent {
+            point + 2
+        } else {
+            point
+        };
+
+        // Round half away from zero to the maximum number of fraction digits.
+        let kept = point + self.maximum_fraction_digits as i32;
+        if kept < 0 {
+            digits.clear();
+        } else if (kept as usize) < digits.len() {
+            let round_up = digits[kept as usize] >= 5;
+            digits.truncate(kept as usize);
+            if round_up {
+                if let Some(index) = digits.iter().rposition(|digit| *digit < 9) {
+                    digits[index] += 1;
+                    digits.truncate(index + 1);
+                } else {
+                    digits = vec![1];
+                    point += 1;
+                }
+            }
+        }
+
+        let digit_at = |index: i32| {
+            if index < 0 {
+                b'0'
+            } else {
+                digits
+                    .get(index as usize)
+                    .map_or(b'0', |digit| digit + b'0')
+            }
+        };
+
+        let integer: Vec<u8> = if point > 0 {
+            (0..point).map(digit_at).collect()
+        } else {
+            vec![b'0']
+        };
+        let mut fraction: Vec<u8> = (point..point + self.maximum_fraction_digits as i32)
+            .map(digit_at)
+            .collect();
+        while fraction.len() > self.minimum_fraction_digits && fraction.last() == Some(&b'0') {
+            fraction.pop();
+        }
+
+        let mut result = String::new();
+        for (index, digit) in integer.iter().enumerate() {
+            if self.use_grouping && index > 0 && (integer.len() - index) % 3 == 0 {
+                result.push(',');
+            }
+            result.push(char::from(*digit));
+        }
+        if !fraction.is_empty() {
+            result.push('.');
+            result.extend(fraction.into_iter().map(char::from));
+        }
+        result
+    }
+}
+
+/// Reads an integer option, which must be between `minimum` and `maximum`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-getnumberoption
+fn get_number_option(
+    options: &Value,
+    property: &str,
+    minimum: usize,
+    maximum: usize,
+    fallback: usize,
+    context: &mut Context,
+) -> Result<usize> {
+    let value = options.get_field(property, context)?;
+    if value.is_undefined() {
+        return Ok(fallback);
+    }
+    let value = value.to_number(context)?;
+    if value.is_nan() || value < minimum as f64 || value > maximum as f64 {
+        return Err(context.construct_range_error(format!("{} value is out of range.", property)));
+    }
+    Ok(value.floor() as usize)
+}
+
+/// Checks that a currency code is made of three letters.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma402/#sec-iswellformedcurrencycode
+fn is_well_formed_currency_code(currency: &str) -> bool {
+    currency.len() == 3 && currency.bytes().all(|byte| byte.is_ascii_alphabetic())
+}
+
+/// The number of fraction digits used by a currency.
+fn currency_digits(currency: &str) -> usize {
+    match currency {
+        "JPY" | "KRW" | "VND" | "CLP" | "ISK" => 0,
+        "BHD" | "KWD" | "OMR" | "JOD" | "TND" => 3,
+        _ => 2,
+    }
+}
+
+/// The symbol of a currency in the `en-US` locale, if it has one.
+fn currency_symbol(currency: &str, display: CurrencyDisplay) -> Option<&'static str> {
+    let narrow = display == CurrencyDisplay::NarrowSymbol;
+    Some(match currency {
+        "USD" => "$",
+        "EUR" => "€",
+        "GBP" => "£",
+        "JPY" => "¥",
+        "INR" => "₹",
+        "KRW" => "₩",
+        "CAD" if narrow => "$",
+        "CAD" => "CA$",
+        "AUD" if narrow => "$",
+        "AUD" => "A$",
+        "CNY" if narrow => "¥",
+        "CNY" => "CN¥",
+        _ => return None,
+    })
+}
+
+/// The plural name of a currency in the `en-US` locale, if it is known.
+fn currency_name(currency: &str) -> Option<&'static str> {
+    Some(match currency {
+        "USD" => "US dollars",
+        "EUR" => "euros",
+        "GBP" => "British pounds",
+        "JPY" => "Japanese yen",
+        "CAD" => "Canadian dollars",
+        "AUD" => "Australian dollars",
+        _ => return None,
+    })
+}
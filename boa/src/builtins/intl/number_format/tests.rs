@@ -0,0 +1,236 @@
+use crate::{forward, Context};
+
+#[test]
+fn format_decimal() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"new Intl.NumberFormat("en-US").format(1234567.891)"#
+        ),
+        "\"1,234,567.891\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Intl.NumberFormat().format(0.12345)"),
+        "\"0.123\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Intl.NumberFormat().format(-0.9999)"),
+        "\"-1\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Intl.NumberFormat().format(1e21)"),
+        "\"1,000,000,000,000,000,000,000\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat().format(12345678901234567890n)"
+        ),
+        "\"12,345,678,901,234,567,890\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Intl.NumberFormat().format(NaN)"),
+        "\"NaN\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Intl.NumberFormat().format(-Infinity)"),
+        "\"-∞\""
+    );
+}
+
+#[test]
+fn format_currency() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"new Intl.NumberFormat("en-US", { style: "currency", currency: "USD" }).format(1234.5)"#
+        ),
+        "\"$1,234.50\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"new Intl.NumberFormat("en-US", { style: "currency", currency: "jpy" }).format(1234.5)"#
+        ),
+        "\"¥1,235\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"new Intl.NumberFormat("en-US", { style: "currency", currency: "CHF" }).format(-3)"#
+        ),
+        "\"-CHF\u{a0}3.00\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"new Intl.NumberFormat("en-US", { style: "currency", currency: "EUR", currencyDisplay: "name" }).format(2)"#
+        ),
+        "\"2.00 euros\""
+    );
+}
+
+#[test]
+fn format_percent() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"new Intl.NumberFormat("en-US", { style: "percent" }).format(0.256)"#
+        ),
+        "\"26%\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"new Intl.NumberFormat("en-US", { style: "percent", minimumFractionDigits: 1 }).format(12.5)"#
+        ),
+        "\"1,250.0%\""
+    );
+}
+
+#[test]
+fn fraction_digits_and_grouping() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en-US', { minimumFractionDigits: 2 }).format(5)"
+        ),
+        "\"5.00\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en-US', { maximumFractionDigits: 0 }).format(2.5)"
+        ),
+        "\"3\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en-US', { maximumFractionDigits: 1 }).format(0.04)"
+        ),
+        "\"0\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "new Intl.NumberFormat('en-US', { useGrouping: false }).format(1234567)"
+        ),
+        "\"1234567\""
+    );
+}
+
+#[test]
+fn unsupported_locale_falls_back() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var nf = Intl.NumberFormat(["de-DE", "en-US"]);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "nf.format(1234.5)"), "\"1,234.5\"");
+    assert_eq!(
+        forward(&mut context, "nf.resolvedOptions().locale"),
+        "\"en-US\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Intl.NumberFormat.supportedLocalesOf(['de-DE', 'en-US']).join()"
+        ),
+        "\"en-US\""
+    );
+}
+
+#[test]
+fn bound_format() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var nf = new Intl.NumberFormat();
+        var format = nf.format;
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "format === nf.format"), "true");
+    assert_eq!(
+        forward(&mut context, "[1000, 2000.5].map(format).join(' ')"),
+        "\"1,000 2,000.5\""
+    );
+}
+
+#[test]
+fn resolved_options() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var options = new Intl.NumberFormat("en-US", { style: "currency", currency: "eur" })
+            .resolvedOptions();
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "options.style"), "\"currency\"");
+    assert_eq!(forward(&mut context, "options.currency"), "\"EUR\"");
+    assert_eq!(forward(&mut context, "options.minimumFractionDigits"), "2");
+    assert_eq!(forward(&mut context, "options.maximumFractionDigits"), "2");
+}
+
+#[test]
+fn invalid_options() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.NumberFormat('en-US', { style: 'currency' }) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.NumberFormat('en-US', { style: 'unit' }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.NumberFormat('en-US', { maximumFractionDigits: 21 }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Intl.NumberFormat('not a locale') } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn to_string_tag() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(Intl)"),
+        "\"[object Intl]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.prototype.toString.call(new Intl.NumberFormat())"
+        ),
+        "\"[object Intl.NumberFormat]\""
+    );
+}
@@ -0,0 +1,214 @@
+use crate::{
+    builtins::{
+        function::make_builtin_fn,
+        iterable::{create_iter_result_object, IteratorRecord},
+        promise::{Promise, PromiseCapability},
+    },
+    gc::{Finalize, Trace},
+    object::{FunctionBuilder, GcObject, ObjectData},
+    BoaProfiler, Context, Result, Value,
+};
+
+/// The AsyncFromSyncIterator object wraps a sync iterator, so it can be used as an async
+/// iterator by `for await...of` loops and by the `yield*` expressions of async generators.
+///
+/// The values of the sync iterator are awaited before they are produced.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-async-from-sync-iterator-objects
+#[derive(Debug, Trace, Finalize)]
+pub struct AsyncFromSyncIterator {
+    sync_iterator: IteratorRecord,
+}
+
+impl AsyncFromSyncIterator {
+    pub(crate) const NAME: &'static str = "AsyncFromSyncIterator";
+
+    /// CreateAsyncFromSyncIterator( syncIteratorRecord )
+    ///
+    /// Creates an async iterator wrapping the given sync iterator.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createasyncfromsynciterator
+    pub(crate) fn create(context: &mut Context, sync_iterator: IteratorRecord) -> IteratorRecord {
+        let async_iterator = Value::new_object(context);
+        async_iterator.set_data(ObjectData::AsyncFromSyncIterator(Self { sync_iterator }));
+        async_iterator
+            .as_object()
+            .expect("async from sync iterator object")
+            .set_prototype_instance(
+                context
+                    .iterator_prototypes()
+                    .async_from_sync_iterator()
+                    .into(),
+            );
+        let next_function = async_iterator
+            .get_field("next", context)
+            .expect("the prototype has a next method");
+        IteratorRecord::new(async_iterator, next_function)
+    }
+
+    /// %AsyncFromSyncIteratorPrototype%.next( value )
+    ///
+    /// Gets the next result of the sync iterator, returning a promise for it.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.next
+    pub(crate) fn next(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (iterator, next_function) = Self::sync_iterator(this, context)?;
+        let capability = Self::new_capability(context)?;
+
+        let result = context.call(&next_function, &iterator, args);
+        Self::continuation(result, capability, context)
+    }
+
+    /// %AsyncFromSyncIteratorPrototype%.return( value )
+    ///
+    /// Calls the `return` method of the sync iterator, returning a promise for its result.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.return
+    pub(crate) fn r#return(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (iterator, _) = Self::sync_iterator(this, context)?;
+        let capability = Self::new_capability(context)?;
+
+        let result = iterator.get_field("return", context).and_then(|r#return| {
+            if r#return.is_null_or_undefined() {
+                // An iterator without a `return` method is done.
+                let value = args.get(0).cloned().unwrap_or_default();
+                Ok(create_iter_result_object(context, value, true))
+            } else {
+                context.call(&r#return, &iterator, args)
+            }
+        });
+        Self::continuation(result, capability, context)
+    }
+
+    /// %AsyncFromSyncIteratorPrototype%.throw( value )
+    ///
+    /// Calls the `throw` method of the sync iterator, returning a promise for its result.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%.throw
+    pub(crate) fn throw(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let (iterator, _) = Self::sync_iterator(this, context)?;
+        let capability = Self::new_capability(context)?;
+
+        let result = iterator.get_field("throw", context).and_then(|throw| {
+            if throw.is_null_or_undefined() {
+                // An iterator without a `throw` method throws the exception.
+                Err(args.get(0).cloned().unwrap_or_default())
+            } else {
+                context.call(&throw, &iterator, args)
+            }
+        });
+        Self::continuation(result, capability, context)
+    }
+
+    /// Gets the sync iterator wrapped by `this`, with its `next` method.
+    fn sync_iterator(this: &Value, context: &mut Context) -> Result<(Value, Value)> {
+        if let Some(object) = this.as_object() {
+            if let Some(iterator) = object.borrow().as_async_from_sync_iterator() {
+                let iterator = &iterator.sync_iterator;
+                return Ok((
+                    iterator.iterator_object().clone(),
+                    iterator.next_function().clone(),
+                ));
+            }
+        }
+        Err(context.construct_type_error("`this` is not an AsyncFromSyncIterator"))
+    }
+
+    /// Creates the promise returned by the methods of the prototype.
+    fn new_capability(context: &mut Context) -> Result<PromiseCapability> {
+        let constructor = context.standard_objects().promise_object().constructor();
+        Promise::new_promise_capability(&constructor.into(), context)
+    }
+
+    /// AsyncFromSyncIteratorContinuation( result, promiseCapability )
+    ///
+    /// Settles the promise of the capability with the given result of the sync iterator, once
+    /// its value is settled.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asyncfromsynciteratorcontinuation
+    fn continuation(
+        result: Result<Value>,
+        capability: PromiseCapability,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let value_wrapper = result.and_then(|result| {
+            if !result.is_object() {
+                return Err(context.construct_type_error("iterator result is not an object"));
+            }
+            let done = result.get_field("done", context)?.to_boolean();
+            let value = result.get_field("value", context)?;
+            let constructor = context.standard_objects().promise_object().constructor();
+            let value_wrapper = Promise::promise_resolve(&constructor.into(), value, context)?;
+            Ok((value_wrapper, done))
+        });
+        let (value_wrapper, done) = match value_wrapper {
+            Ok(value_wrapper) => value_wrapper,
+            Err(error) => {
+                context.call(&capability.reject, &Value::undefined(), &[error])?;
+                return Ok(capability.promise.clone());
+            }
+        };
+
+        let on_fulfilled = FunctionBuilder::closure(context, move |_, args, context| {
+            let value = args.get(0).cloned().unwrap_or_default();
+            Ok(create_iter_result_object(context, value, done))
+        })
+        .length(1)
+        .build();
+        let value_wrapper = value_wrapper
+            .as_object()
+            .expect("PromiseResolve returns a promise");
+        Ok(Promise::perform_promise_then(
+            &value_wrapper,
+            on_fulfilled.into(),
+            Value::undefined(),
+            Some(capability),
+            context,
+        ))
+    }
+
+    /// Create the %AsyncFromSyncIteratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%asyncfromsynciteratorprototype%-object
+    pub(crate) fn create_prototype(
+        context: &mut Context,
+        async_iterator_prototype: Value,
+    ) -> GcObject {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let mut async_from_sync_iterator = context.construct_object();
+        make_builtin_fn(Self::next, "next", &async_from_sync_iterator, 1, context);
+        make_builtin_fn(
+            Self::r#return,
+            "return",
+            &async_from_sync_iterator,
+            1,
+            context,
+        );
+        make_builtin_fn(Self::throw, "throw", &async_from_sync_iterator, 1, context);
+        async_from_sync_iterator.set_prototype_instance(async_iterator_prototype);
+        async_from_sync_iterator
+    }
+}
@@ -1,14 +1,24 @@
 use crate::{
+    builtins::async_generator::AsyncGenerator,
     builtins::string::string_iterator::StringIterator,
     builtins::ArrayIterator,
     builtins::ForInIterator,
+    builtins::Generator,
     builtins::MapIterator,
+    builtins::RegExpStringIterator,
     builtins::SetIterator,
+    context::copy_intrinsic,
+    gc::{Finalize, Trace},
     object::{GcObject, ObjectInitializer},
     property::{Attribute, DataDescriptor},
     symbol::WellKnownSymbols,
     BoaProfiler, Context, Result, Value,
 };
+use rustc_hash::FxHashMap;
+
+pub mod async_from_sync_iterator;
+
+use async_from_sync_iterator::AsyncFromSyncIterator;
 
 #[derive(Debug, Default)]
 pub struct IteratorPrototypes {
@@ -18,11 +28,17 @@ pub struct IteratorPrototypes {
     string_iterator: GcObject,
     map_iterator: GcObject,
     for_in_iterator: GcObject,
+    regexp_string_iterator: GcObject,
+    generator: GcObject,
+    async_iterator_prototype: GcObject,
+    async_from_sync_iterator: GcObject,
+    async_generator: GcObject,
 }
 
 impl IteratorPrototypes {
     pub(crate) fn init(context: &mut Context) -> Self {
         let iterator_prototype = create_iterator_prototype(context);
+        let async_iterator_prototype = create_async_iterator_prototype(context);
         Self {
             array_iterator: ArrayIterator::create_prototype(
                 context,
@@ -38,7 +54,21 @@ impl IteratorPrototypes {
                 context,
                 iterator_prototype.clone().into(),
             ),
+            regexp_string_iterator: RegExpStringIterator::create_prototype(
+                context,
+                iterator_prototype.clone().into(),
+            ),
+            generator: Generator::create_prototype(context, iterator_prototype.clone().into()),
             iterator_prototype,
+            async_from_sync_iterator: AsyncFromSyncIterator::create_prototype(
+                context,
+                async_iterator_prototype.clone().into(),
+            ),
+            async_generator: AsyncGenerator::create_prototype(
+                context,
+                async_iterator_prototype.clone().into(),
+            ),
+            async_iterator_prototype,
         }
     }
 
@@ -71,6 +101,48 @@ impl IteratorPrototypes {
     pub fn for_in_iterator(&self) -> GcObject {
         self.for_in_iterator.clone()
     }
+
+    #[inline]
+    pub fn regexp_string_iterator(&self) -> GcObject {
+        self.regexp_string_iterator.clone()
+    }
+
+    #[inline]
+    pub fn generator(&self) -> GcObject {
+        self.generator.clone()
+    }
+
+    #[inline]
+    pub fn async_iterator_prototype(&self) -> GcObject {
+        self.async_iterator_prototype.clone()
+    }
+
+    #[inline]
+    pub fn async_from_sync_iterator(&self) -> GcObject {
+        self.async_from_sync_iterator.clone()
+    }
+
+    #[inline]
+    pub fn async_generator(&self) -> GcObject {
+        self.async_generator.clone()
+    }
+
+    /// Copies the prototypes with [`copy_intrinsic`].
+    pub(crate) fn copy(&self, copies: &mut FxHashMap<usize, GcObject>) -> Self {
+        Self {
+            iterator_prototype: copy_intrinsic(&self.iterator_prototype, copies),
+            array_iterator: copy_intrinsic(&self.array_iterator, copies),
+            set_iterator: copy_intrinsic(&self.set_iterator, copies),
+            string_iterator: copy_intrinsic(&self.string_iterator, copies),
+            map_iterator: copy_intrinsic(&self.map_iterator, copies),
+            for_in_iterator: copy_intrinsic(&self.for_in_iterator, copies),
+            regexp_string_iterator: copy_intrinsic(&self.regexp_string_iterator, copies),
+            generator: copy_intrinsic(&self.generator, copies),
+            async_iterator_prototype: copy_intrinsic(&self.async_iterator_prototype, copies),
+            async_from_sync_iterator: copy_intrinsic(&self.async_from_sync_iterator, copies),
+            async_generator: copy_intrinsic(&self.async_generator, copies),
+        }
+    }
 }
 
 /// CreateIterResultObject( value, done )
@@ -88,7 +160,15 @@ pub fn create_iter_result_object(context: &mut Context, value: Value, done: bool
 
 /// Get an iterator record
 pub fn get_iterator(context: &mut Context, iterable: Value) -> Result<IteratorRecord> {
-    let iterator_function = iterable.get_field(WellKnownSymbols::iterator(), context)?;
+    if iterable.is_null_or_undefined() {
+        return Err(context.construct_type_error("Not an iterable"));
+    }
+    // Primitives like strings are iterable through their prototype.
+    let iterator_function = iterable.to_object(context)?.get(
+        &WellKnownSymbols::iterator().into(),
+        iterable.clone(),
+        context,
+    )?;
     if iterator_function.is_null_or_undefined() {
         return Err(context.construct_type_error("Not an iterable"));
     }
@@ -100,6 +180,123 @@ pub fn get_iterator(context: &mut Context, iterable: Value) -> Result<IteratorRe
     Ok(IteratorRecord::new(iterator_object, next_function))
 }
 
+/// GetIterator( obj, async )
+///
+/// Gets an async iterator record, wrapping the sync iterator of the iterable if it doesn't have
+/// an async one.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getiterator
+pub(crate) fn get_async_iterator(context: &mut Context, iterable: Value) -> Result<IteratorRecord> {
+    if iterable.is_null_or_undefined() {
+        return Err(context.construct_type_error("Not an async iterable"));
+    }
+    let iterator_function = iterable.to_object(context)?.get(
+        &WellKnownSymbols::async_iterator().into(),
+        iterable.clone(),
+        context,
+    )?;
+    if iterator_function.is_null_or_undefined() {
+        let sync_iterator = get_iterator(context, iterable)?;
+        return Ok(AsyncFromSyncIterator::create(context, sync_iterator));
+    }
+    let iterator_object = context.call(&iterator_function, &iterable, &[])?;
+    if !iterator_object.is_object() {
+        return Err(context.construct_type_error("async iterator is not an object"));
+    }
+    let next_function = iterator_object.get_field("next", context)?;
+    Ok(IteratorRecord::new(iterator_object, next_function))
+}
+
+/// How [`group_by`] coerces the keys returned by its callback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GroupKeyCoercion {
+    /// Keys are converted with `ToPropertyKey`, as done by `Object.groupBy`.
+    Property,
+    /// Keys are kept as they are, except that `-0` becomes `+0`, as done by `Map.groupBy`.
+    Zero,
+}
+
+/// GroupBy ( items, callbackfn, keyCoercion )
+///
+/// Partitions the values produced by iterating `items` into groups keyed by the return value of
+/// `callback`. Groups are returned in the order their keys were first seen, and the values of a
+/// group in iteration order.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-groupby
+pub(crate) fn group_by(
+    items: &Value,
+    callback: &Value,
+    coercion: GroupKeyCoercion,
+    context: &mut Context,
+) -> Result<Vec<(Value, Vec<Value>)>> {
+    // 1. Perform ? RequireObjectCoercible(items).
+    items.require_object_coercible(context)?;
+
+    // 2. If IsCallable(callbackfn) is false, throw a TypeError exception.
+    if !callback.is_function() {
+        return Err(context.construct_type_error("groupBy callback is not a function"));
+    }
+
+    // 3. Let groups be a new empty List.
+    let mut groups: Vec<(Value, Vec<Value>)> = Vec::new();
+
+    // 4. Let iteratorRecord be ? GetIterator(items, sync).
+    let iterator_record = get_iterator(context, items.clone())?;
+
+    // 5. Let k be 0.
+    let mut k = 0usize;
+
+    // 6. Repeat,
+    loop {
+        // b. Let next be ? IteratorStep(iteratorRecord).
+        let next = iterator_record.next(context)?;
+
+        // c. If next is false, return groups.
+        if next.is_done() {
+            return Ok(groups);
+        }
+
+        // d. Let value be ? IteratorValue(next).
+        let value = next.value();
+
+        // e. Let key be Completion(Call(callbackfn, undefined, « value, 𝔽(k) »)).
+        // f. IfAbruptCloseIterator(key, iteratorRecord).
+        // g. If keyCoercion is property, then
+        //     i. Set key to Completion(ToPropertyKey(key)).
+        //     ii. IfAbruptCloseIterator(key, iteratorRecord).
+        // h. Else,
+        //     i. Assert: keyCoercion is zero.
+        //     ii. If key is -0𝔽, set key to +0𝔽.
+        let key = context
+            .call(callback, &Value::undefined(), &[value.clone(), k.into()])
+            .and_then(|key| match coercion {
+                GroupKeyCoercion::Property => Ok(key.to_property_key(context)?.into()),
+                GroupKeyCoercion::Zero if key.as_number() == Some(0.0) => Ok(Value::from(0)),
+                GroupKeyCoercion::Zero => Ok(key),
+            });
+        let key = match key {
+            Ok(key) => key,
+            Err(error) => return iterator_record.close(Err(error), context).map(|_| groups),
+        };
+
+        // i. Perform AddValueToKeyedGroup(groups, key, value).
+        if let Some((_, elements)) = groups.iter_mut().find(|(group_key, _)| *group_key == key) {
+            elements.push(value);
+        } else {
+            groups.push((key, vec![value]));
+        }
+
+        // j. Set k to k + 1.
+        k += 1;
+    }
+}
+
 /// Create the %IteratorPrototype% object
 ///
 /// More information:
@@ -120,7 +317,26 @@ fn create_iterator_prototype(context: &mut Context) -> GcObject {
     iterator_prototype
 }
 
-#[derive(Debug)]
+/// Create the %AsyncIteratorPrototype% object
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-asynciteratorprototype
+fn create_async_iterator_prototype(context: &mut Context) -> GcObject {
+    let _timer = BoaProfiler::global().start_event("AsyncIteratorPrototype", "init");
+
+    let symbol_async_iterator = WellKnownSymbols::async_iterator();
+    ObjectInitializer::new(context)
+        .function(
+            |v, _, _| Ok(v.clone()),
+            (symbol_async_iterator, "[Symbol.asyncIterator]"),
+            0,
+        )
+        .build()
+}
+
+#[derive(Debug, Trace, Finalize)]
 pub struct IteratorRecord {
     iterator_object: Value,
     next_function: Value,
@@ -134,6 +350,16 @@ impl IteratorRecord {
         }
     }
 
+    /// Gets the iterator object.
+    pub(crate) fn iterator_object(&self) -> &Value {
+        &self.iterator_object
+    }
+
+    /// Gets the `next` method of the iterator.
+    pub(crate) fn next_function(&self) -> &Value {
+        &self.next_function
+    }
+
     /// Get the next value in the iterator
     ///
     /// More information:
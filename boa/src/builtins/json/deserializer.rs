@@ -0,0 +1,137 @@
+//! Deserialization of JSON text directly into `Value`s.
+
+use crate::{
+    object::{Object, ObjectData},
+    property::{Attribute, DataDescriptor},
+    Context, Value,
+};
+use serde::de::{DeserializeSeed, Deserializer, MapAccess, SeqAccess, Visitor};
+use serde_json::de::{Deserializer as JsonDeserializer, Read};
+use std::{convert::TryFrom, fmt};
+
+/// Parses the JSON text read by `deserializer` into a `Value`, failing if the text holds
+/// anything other than a single JSON value.
+pub(super) fn parse<'de, R>(
+    mut deserializer: JsonDeserializer<R>,
+    context: &mut Context,
+) -> serde_json::Result<Value>
+where
+    R: Read<'de>,
+{
+    let value = JsonValueSeed { context }.deserialize(&mut deserializer)?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+/// Deserializes a JSON value into a `Value`, creating the objects and arrays in `context`.
+///
+/// The values are created while the text is parsed, without building an intermediate
+/// `serde_json::Value` tree, so the properties of the objects are created in the order of their
+/// keys in the text, and a key found twice keeps its first position with its last value.
+struct JsonValueSeed<'a> {
+    context: &'a mut Context,
+}
+
+impl<'de> DeserializeSeed<'de> for JsonValueSeed<'_> {
+    type Value = Value;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+impl<'de> Visitor<'de> for JsonValueSeed<'_> {
+    type Value = Value;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a JSON value")
+    }
+
+    fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(Value::boolean(value))
+    }
+
+    fn visit_i64<E>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(match i32::try_from(value) {
+            Ok(integer) => Value::integer(integer),
+            Err(_) => Value::rational(value as f64),
+        })
+    }
+
+    fn visit_u64<E>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(match i32::try_from(value) {
+            Ok(integer) => Value::integer(integer),
+            Err(_) => Value::rational(value as f64),
+        })
+    }
+
+    fn visit_f64<E>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(Value::rational(value))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Value::string(value))
+    }
+
+    fn visit_string<E>(self, value: String) -> Result<Self::Value, E> {
+        Ok(Value::string(value))
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(Value::null())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let context = self.context;
+        let array_prototype = context.standard_objects().array_object().prototype();
+        let array: Value = Object::with_prototype(array_prototype.into(), ObjectData::Array).into();
+        let mut length: usize = 0;
+        while let Some(value) = seq.next_element_seed(JsonValueSeed {
+            context: &mut *context,
+        })? {
+            array.set_property(
+                length,
+                DataDescriptor::new(
+                    value,
+                    Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+            length += 1;
+        }
+        array.set_property(
+            "length",
+            DataDescriptor::new(
+                length,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+            ),
+        );
+        Ok(array)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let context = self.context;
+        let object = Value::new_object(context);
+        while let Some(key) = map.next_key::<String>()? {
+            let value = map.next_value_seed(JsonValueSeed {
+                context: &mut *context,
+            })?;
+            object.set_property(
+                key,
+                DataDescriptor::new(
+                    value,
+                    Attribute::WRITABLE | Attribute::ENUMERABLE | Attribute::CONFIGURABLE,
+                ),
+            );
+        }
+        Ok(object)
+    }
+}
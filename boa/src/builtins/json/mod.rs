@@ -14,15 +14,21 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON
 
 use crate::{
-    builtins::BuiltIn,
-    object::ObjectInitializer,
+    builtins::{BuiltIn, Number},
+    object::{GcObject, ObjectInitializer},
     property::{Attribute, DataDescriptor, PropertyKey},
-    value::IntegerOrInfinity,
+    symbol::WellKnownSymbols,
+    value::{IntegerOrInfinity, RcString},
     BoaProfiler, Context, Result, Value,
 };
-use crate::{object::Object, symbol::WellKnownSymbols};
 use serde::Serialize;
-use serde_json::{self, ser::PrettyFormatter, Serializer, Value as JSONValue};
+use serde_json::{
+    self, ser::PrettyFormatter, Deserializer, Map, Number as JSONNumber, Serializer,
+    Value as JSONValue,
+};
+use std::{io::Read, str::FromStr};
+
+mod deserializer;
 
 #[cfg(test)]
 mod tests;
@@ -74,53 +80,120 @@ impl Json {
             .unwrap_or_else(Value::undefined)
             .to_string(context)?;
 
-        match serde_json::from_str::<JSONValue>(&arg) {
-            Ok(json) => {
-                let j = Value::from_json(json, context);
-                match args.get(1) {
-                    Some(reviver) if reviver.is_function() => {
-                        let mut holder = Value::object(Object::default());
-                        holder.set_field("", j, true, context)?;
-                        Self::walk(reviver, context, &mut holder, &PropertyKey::from(""))
-                    }
-                    _ => Ok(j),
-                }
+        let unfiltered = match deserializer::parse(Deserializer::from_str(&arg), context) {
+            Ok(value) => value,
+            Err(err) => return context.throw_syntax_error(err.to_string()),
+        };
+        match args.get(1) {
+            // 3. If IsCallable(reviver) is true, then
+            Some(reviver) if reviver.is_function() => {
+                // a. Let root be ! OrdinaryObjectCreate(%Object.prototype%).
+                let mut root = Value::new_object(context)
+                    .as_object()
+                    .expect("new_object must create an object");
+
+                // b. Let rootName be the empty String.
+                // c. Perform ! CreateDataPropertyOrThrow(root, rootName, unfiltered).
+                root.define_own_property(
+                    "",
+                    DataDescriptor::new(unfiltered, Attribute::all()).into(),
+                    context,
+                )?;
+
+                // d. Return ? InternalizeJSONProperty(root, rootName, reviver).
+                Self::internalize_json_property(root, "".into(), reviver, context)
             }
+            // 4. Else, return unfiltered.
+            _ => Ok(unfiltered),
+        }
+    }
+
+    /// Parses the JSON text read from `reader`, like `JSON.parse` without a reviver.
+    ///
+    /// The text is parsed with the grammar of `JSON.parse` while it is read, so it is never
+    /// stored as a whole in a string, and the values are created as they are parsed. Both
+    /// invalid JSON and read errors throw a `SyntaxError`.
+    pub(crate) fn parse_reader<R>(reader: R, context: &mut Context) -> Result<Value>
+    where
+        R: Read,
+    {
+        match deserializer::parse(Deserializer::from_reader(reader), context) {
+            Ok(value) => Ok(value),
             Err(err) => context.throw_syntax_error(err.to_string()),
         }
     }
 
-    /// This is a translation of the [Polyfill implementation][polyfill]
+    /// `InternalizeJSONProperty( holder, name, reviver )`
     ///
-    /// This function recursively walks the structure, passing each key-value pair to the reviver function
-    /// for possible transformation.
+    /// This function walks the parsed structure bottom-up, passing each key-value pair to the
+    /// reviver function, with the object holding the pair as `this`. The value is replaced by the
+    /// result of the reviver, or deleted if the reviver returns `undefined`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
     ///
-    /// [polyfill]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/parse
-    fn walk(
+    /// [spec]: https://tc39.es/ecma262/#sec-internalizejsonproperty
+    fn internalize_json_property(
+        holder: GcObject,
+        name: PropertyKey,
         reviver: &Value,
         context: &mut Context,
-        holder: &mut Value,
-        key: &PropertyKey,
     ) -> Result<Value> {
-        let value = holder.get_field(key.clone(), context)?;
+        // 1. Let val be ? Get(holder, name).
+        let val = holder.get(&name, holder.clone().into(), context)?;
 
-        if let Value::Object(ref object) = value {
-            let keys: Vec<_> = object.borrow().keys().collect();
+        // 2. If Type(val) is Object, then
+        if let Value::Object(ref object) = val {
+            // a. Let isArray be ? IsArray(val).
+            let keys: Vec<PropertyKey> = if object.is_array_abstract() {
+                // b. If isArray is true, then
+                // i. Let len be ? LengthOfArrayLike(val).
+                let len = val.get_field("length", context)?.to_length(context)?;
 
+                // ii. Let I be 0.
+                // iii. Repeat, while I < len,
+                // 1. Let prop be ! ToString(𝔽(I)).
+                (0..len).map(PropertyKey::from).collect()
+            } else {
+                // c. Else,
+                // i. Let keys be ? EnumerableOwnPropertyNames(val, key).
+                object
+                    .own_property_keys()
+                    .into_iter()
+                    .filter(|key| !matches!(key, PropertyKey::Symbol(_)))
+                    .filter(|key| {
+                        object
+                            .get_own_property(key)
+                            .map_or(false, |desc| desc.enumerable())
+                    })
+                    .collect()
+            };
+
+            // ii. For each String P of keys, do
             for key in keys {
-                let v = Self::walk(reviver, context, &mut value.clone(), &key);
-                match v {
-                    Ok(v) if !v.is_undefined() => {
-                        value.set_field(key, v, false, context)?;
-                    }
-                    Ok(_) => {
-                        value.remove_property(key);
-                    }
-                    Err(_v) => {}
+                // 1. Let newElement be ? InternalizeJSONProperty(val, P, reviver).
+                let new_element =
+                    Self::internalize_json_property(object.clone(), key.clone(), reviver, context)?;
+
+                let mut object = object.clone();
+                if new_element.is_undefined() {
+                    // 2. If newElement is undefined, then
+                    // a. Perform ? val.[[Delete]](P).
+                    object.delete(&key, context)?;
+                } else {
+                    // 3. Else,
+                    // a. Perform ? CreateDataProperty(val, P, newElement).
+                    object.define_own_property(
+                        key,
+                        DataDescriptor::new(new_element, Attribute::all()).into(),
+                        context,
+                    )?;
                 }
             }
         }
-        context.call(reviver, holder, &[key.into(), value])
+
+        // 3. Return ? Call(reviver, holder, « name, val »).
+        context.call(reviver, &holder.into(), &[name.into(), val])
     }
 
     /// `JSON.stringify( value[, replacer[, space]] )`
@@ -140,118 +213,371 @@ impl Json {
     /// [spec]: https://tc39.es/ecma262/#sec-json.stringify
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/stringify
     pub(crate) fn stringify(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let object = match args.get(0) {
-            None => return Ok(Value::undefined()),
-            Some(obj) => obj,
-        };
-        const SPACE_INDENT: &str = "          ";
-        let gap = if let Some(space) = args.get(2) {
-            let space = if let Some(space_obj) = space.as_object() {
-                if let Some(space) = space_obj.borrow().as_number() {
-                    Value::from(space)
-                } else if let Some(space) = space_obj.borrow().as_string() {
-                    Value::from(space)
+        // 1. Let stack be a new empty List.
+        // 2. Let indent be the empty String.
+        // 3. Let PropertyList and ReplacerFunction be undefined.
+        let mut property_list = None;
+        let mut replacer_function = None;
+
+        let replacer = args.get(1).cloned().unwrap_or_default();
+
+        // 4. If Type(replacer) is Object, then
+        if let Some(replacer_obj) = replacer.as_object() {
+            // a. If IsCallable(replacer) is true, then
+            if replacer_obj.is_callable() {
+                // i. Set ReplacerFunction to replacer.
+                replacer_function = Some(replacer);
+            // b. Else,
+            // i. Let isArray be ? IsArray(replacer).
+            // ii. If isArray is true, then
+            } else if replacer_obj.is_array_abstract() {
+                // 1. Set PropertyList to a new empty List.
+                let mut property_set: Vec<RcString> = Vec::new();
+
+                // 2. Let len be ? LengthOfArrayLike(replacer).
+                let len = replacer.get_field("length", context)?.to_length(context)?;
+
+                // 3. Let k be 0.
+                // 4. Repeat, while k < len,
+                for k in 0..len {
+                    // a. Let prop be ! ToString(𝔽(k)).
+                    // b. Let v be ? Get(replacer, prop).
+                    let v = replacer.get_field(k, context)?;
+
+                    // c. Let item be undefined.
+                    // d. If Type(v) is String, set item to v.
+                    // e. Else if Type(v) is Number, set item to ! ToString(v).
+                    // f. Else if Type(v) is Object, then
+                    // i. If v has a [[StringData]] or [[NumberData]] internal slot, set item to ? ToString(v).
+                    let item = match v {
+                        Value::String(ref string) => Some(string.clone()),
+                        Value::Integer(_) | Value::Rational(_) => Some(v.to_string(context)?),
+                        Value::Object(ref obj) => {
+                            let is_wrapper = {
+                                let obj = obj.borrow();
+                                obj.as_string().is_some() || obj.as_number().is_some()
+                            };
+                            if is_wrapper {
+                                Some(v.to_string(context)?)
+                            } else {
+                                None
+                            }
+                        }
+                        _ => None,
+                    };
+
+                    // g. If item is not undefined and item is not currently an element of PropertyList, then
+                    if let Some(item) = item {
+                        // i. Append item to the end of PropertyList.
+                        if !property_set.contains(&item) {
+                            property_set.push(item);
+                        }
+                    }
+                }
+                property_list = Some(property_set);
+            }
+        }
+
+        // 5. If Type(space) is Object, then
+        let space = match args.get(2) {
+            Some(Value::Object(ref space_obj)) => {
+                let (is_number, is_string) = {
+                    let space_obj = space_obj.borrow();
+                    (
+                        space_obj.as_number().is_some(),
+                        space_obj.as_string().is_some(),
+                    )
+                };
+                // a. If space has a [[NumberData]] internal slot, then
+                if is_number {
+                    // i. Set space to ? ToNumber(space).
+                    Value::from(Value::from(space_obj.clone()).to_number(context)?)
+                // b. Else if space has a [[StringData]] internal slot, then
+                } else if is_string {
+                    // i. Set space to ? ToString(space).
+                    Value::from(Value::from(space_obj.clone()).to_string(context)?)
                 } else {
-                    space.clone()
+                    Value::undefined()
                 }
-            } else {
-                space.clone()
-            };
-            if space.is_number() {
+            }
+            Some(space) => space.clone(),
+            None => Value::undefined(),
+        };
+
+        const SPACE_INDENT: &str = "          ";
+        let gap = match space {
+            // 6. If Type(space) is Number, then
+            Value::Integer(_) | Value::Rational(_) => {
+                // a. Let spaceMV be ! ToIntegerOrInfinity(space).
+                // b. Set spaceMV to min(10, spaceMV).
+                // c. If spaceMV < 1, let gap be the empty String; otherwise let gap be the String value containing spaceMV occurrences of the code unit 0x0020 (SPACE).
                 let space_mv = match space.to_integer_or_infinity(context)? {
                     IntegerOrInfinity::NegativeInfinity => 0,
                     IntegerOrInfinity::PositiveInfinity => 10,
                     IntegerOrInfinity::Integer(i) if i < 1 => 0,
                     IntegerOrInfinity::Integer(i) => std::cmp::min(i, 10) as usize,
                 };
-                Value::from(&SPACE_INDENT[..space_mv])
-            } else if let Some(string) = space.as_string() {
-                Value::from(&string[..std::cmp::min(string.len(), 10)])
-            } else {
-                Value::from("")
+                SPACE_INDENT[..space_mv].to_owned()
             }
-        } else {
-            Value::from("")
+            // 7. Else if Type(space) is String, then
+            // a. If the length of space is 10 or less, let gap be space; otherwise let gap be the substring of space from 0 to 10.
+            Value::String(ref string) => string.chars().take(10).collect(),
+            // 8. Else,
+            // a. Let gap be the empty String.
+            _ => String::new(),
         };
 
-        let gap = &gap.to_string(context)?;
+        // 9. Let wrapper be ! OrdinaryObjectCreate(%Object.prototype%).
+        let mut wrapper = Value::new_object(context)
+            .as_object()
+            .expect("new_object must create an object");
+
+        // 10. Perform ! CreateDataPropertyOrThrow(wrapper, the empty String, value).
+        wrapper.define_own_property(
+            "",
+            DataDescriptor::new(args.get(0).cloned().unwrap_or_default(), Attribute::all()).into(),
+            context,
+        )?;
 
-        let replacer = match args.get(1) {
-            Some(replacer) if replacer.is_object() => replacer,
-            _ => {
-                if let Some(value) = object.to_json(context)? {
-                    return Ok(Value::from(json_to_pretty_string(&value, gap)));
+        // 11. Let state be the Record { [[ReplacerFunction]]: ReplacerFunction, [[Stack]]: stack, [[Indent]]: indent, [[Gap]]: gap, [[PropertyList]]: PropertyList }.
+        let mut state = StateRecord {
+            replacer_function,
+            stack: Vec::new(),
+            property_list,
+        };
+
+        // 12. Return ? SerializeJSONProperty(state, the empty String, wrapper).
+        // The indentation with the gap is done while writing the serialized JSON value.
+        match Self::serialize_json_property(&mut state, "".into(), wrapper, context)? {
+            Some(json) => Ok(Value::from(json_to_pretty_string(&json, &gap))),
+            None => Ok(Value::undefined()),
+        }
+    }
+
+    /// `SerializeJSONProperty( state, key, holder )`
+    ///
+    /// Serializes the property `key` of `holder`, returning `None` if it is not serializable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonproperty
+    fn serialize_json_property(
+        state: &mut StateRecord,
+        key: PropertyKey,
+        holder: GcObject,
+        context: &mut Context,
+    ) -> Result<Option<JSONValue>> {
+        // 1. Let value be ? Get(holder, key).
+        let mut value = holder.get(&key, holder.clone().into(), context)?;
+
+        // 2. If Type(value) is Object or BigInt, then
+        if value.is_object() || value.is_bigint() {
+            // a. Let toJSON be ? GetV(value, "toJSON").
+            let to_json = value.get_field("toJSON", context)?;
+
+            // b. If IsCallable(toJSON) is true, then
+            if to_json.is_function() {
+                // i. Set value to ? Call(toJSON, value, « key »).
+                value = context.call(&to_json, &value, &[key.clone().into()])?;
+            }
+        }
+
+        // 3. If state.[[ReplacerFunction]] is not undefined, then
+        if let Some(ref replacer_function) = state.replacer_function {
+            // a. Set value to ? Call(state.[[ReplacerFunction]], holder, « key, value »).
+            value = context.call(replacer_function, &holder.into(), &[key.into(), value])?;
+        }
+
+        // 4. If Type(value) is Object, then
+        if let Some(obj) = value.as_object() {
+            let object = obj.borrow();
+            let is_number = object.as_number().is_some();
+            let is_string = object.as_string().is_some();
+            let primitive = object
+                .as_boolean()
+                .map(Value::from)
+                .or_else(|| object.as_bigint().cloned().map(Value::from));
+            drop(object);
+
+            // a. If value has a [[NumberData]] internal slot, then
+            if is_number {
+                // i. Set value to ? ToNumber(value).
+                value = Value::from(value.to_number(context)?);
+            // b. Else if value has a [[StringData]] internal slot, then
+            } else if is_string {
+                // i. Set value to ? ToString(value).
+                value = Value::from(value.to_string(context)?);
+            // c. Else if value has a [[BooleanData]] internal slot, then
+            // i. Set value to value.[[BooleanData]].
+            // d. Else if value has a [[BigIntData]] internal slot, then
+            // i. Set value to value.[[BigIntData]].
+            } else if let Some(primitive) = primitive {
+                value = primitive;
+            }
+        }
+
+        match value {
+            // 5. If value is null, return "null".
+            Value::Null => Ok(Some(JSONValue::Null)),
+            // 6. If value is true, return "true".
+            // 7. If value is false, return "false".
+            Value::Boolean(boolean) => Ok(Some(JSONValue::Bool(boolean))),
+            // 8. If Type(value) is String, return QuoteJSONString(value).
+            Value::String(ref string) => Ok(Some(JSONValue::String(string.to_string()))),
+            // 9. If Type(value) is Number, then
+            // a. If value is finite, return ! ToString(value).
+            // b. Return "null".
+            Value::Integer(integer) => Ok(Some(JSONValue::Number(integer.into()))),
+            Value::Rational(number) if number.is_finite() => Ok(Some(JSONValue::Number(
+                JSONNumber::from_str(&Number::to_native_string(number))
+                    .expect("invalid number found"),
+            ))),
+            Value::Rational(_) => Ok(Some(JSONValue::Null)),
+            // 10. If Type(value) is BigInt, throw a TypeError exception.
+            Value::BigInt(_) => {
+                Err(context.construct_type_error("BigInt value can't be serialized in JSON"))
+            }
+            // 11. If Type(value) is Object and IsCallable(value) is false, then
+            Value::Object(ref obj) if !obj.is_callable() => {
+                // a. Let isArray be ? IsArray(value).
+                // b. If isArray is true, return ? SerializeJSONArray(state, value).
+                if obj.is_array_abstract() {
+                    Self::serialize_json_array(state, obj.clone(), context).map(Some)
+                // c. Return ? SerializeJSONObject(state, value).
                 } else {
-                    return Ok(Value::undefined());
+                    Self::serialize_json_object(state, obj.clone(), context).map(Some)
                 }
             }
-        };
+            // 12. Return undefined.
+            _ => Ok(None),
+        }
+    }
 
-        let replacer_as_object = replacer
-            .as_object()
-            .expect("JSON.stringify replacer was an object");
-        if replacer_as_object.is_callable() {
-            object
-                .as_object()
-                .map(|obj| {
-                    let object_to_return = Value::object(Object::default());
-                    for key in obj.borrow().keys() {
-                        let val = obj.get(&key, obj.clone().into(), context)?;
-                        let this_arg = object.clone();
-                        object_to_return.set_property(
-                            key.to_owned(),
-                            DataDescriptor::new(
-                                context.call(
-                                    replacer,
-                                    &this_arg,
-                                    &[Value::from(key.clone()), val.clone()],
-                                )?,
-                                Attribute::all(),
-                            ),
-                        );
-                    }
-                    if let Some(value) = object_to_return.to_json(context)? {
-                        Ok(Value::from(json_to_pretty_string(&value, gap)))
-                    } else {
-                        Ok(Value::undefined())
-                    }
+    /// `SerializeJSONObject( state, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonobject
+    fn serialize_json_object(
+        state: &mut StateRecord,
+        value: GcObject,
+        context: &mut Context,
+    ) -> Result<JSONValue> {
+        // 1. If state.[[Stack]] contains value, throw a TypeError exception because the structure is cyclical.
+        if state.stack.iter().any(|obj| GcObject::equals(obj, &value)) {
+            return Err(context.construct_type_error("cyclic object value"));
+        }
+
+        // 2. Append value to state.[[Stack]].
+        state.stack.push(value.clone());
+
+        // 5. If state.[[PropertyList]] is not undefined, then
+        // a. Let K be state.[[PropertyList]].
+        // 6. Else,
+        // a. Let K be ? EnumerableOwnPropertyNames(value, key).
+        let keys: Vec<PropertyKey> = match state.property_list {
+            Some(ref property_list) => property_list
+                .iter()
+                .cloned()
+                .map(PropertyKey::from)
+                .collect(),
+            None => value
+                .own_property_keys()
+                .into_iter()
+                .filter(|key| !matches!(key, PropertyKey::Symbol(_)))
+                .filter(|key| {
+                    value
+                        .get_own_property(key)
+                        .map_or(false, |desc| desc.enumerable())
                 })
-                .ok_or_else(Value::undefined)?
-        } else if replacer_as_object.is_array() {
-            let mut obj_to_return = serde_json::Map::new();
-            let replacer_as_object = replacer_as_object.borrow();
-            let fields = replacer_as_object.keys().filter_map(|key| {
-                if key == "length" {
-                    None
-                } else {
-                    Some(
-                        replacer
-                            .get_property(key)
-                            .as_ref()
-                            .and_then(|p| p.as_data_descriptor())
-                            .map(|d| d.value())
-                            .unwrap_or_else(Value::undefined),
-                    )
-                }
-            });
-            for field in fields {
-                let v = object.get_field(field.to_string(context)?, context)?;
-                if !v.is_undefined() {
-                    if let Some(value) = v.to_json(context)? {
-                        obj_to_return.insert(field.to_string(context)?.to_string(), value);
-                    }
-                }
+                .collect(),
+        };
+
+        // 7. Let partial be a new empty List.
+        let mut partial = Map::new();
+
+        // 8. For each element P of K, do
+        for key in keys {
+            // a. Let strP be ? SerializeJSONProperty(state, P, value).
+            // b. If strP is not undefined, then
+            if let Some(str_p) =
+                Self::serialize_json_property(state, key.clone(), value.clone(), context)?
+            {
+                // i. Let member be QuoteJSONString(P).
+                // ii. Set member to the string-concatenation of member and ":".
+                // iv. Set member to the string-concatenation of member and strP.
+                // v. Append member to partial.
+                partial.insert(key.to_string(), str_p);
             }
-            Ok(Value::from(json_to_pretty_string(
-                &JSONValue::Object(obj_to_return),
-                gap,
-            )))
-        } else if let Some(value) = object.to_json(context)? {
-            Ok(Value::from(json_to_pretty_string(&value, gap)))
-        } else {
-            Ok(Value::undefined())
         }
+
+        // 12. Remove the last element of state.[[Stack]].
+        state.stack.pop();
+
+        // 9-11. The gap and indent are applied while writing the serialized JSON value.
+        // 14. Return final.
+        Ok(JSONValue::Object(partial))
     }
+
+    /// `SerializeJSONArray( state, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonarray
+    fn serialize_json_array(
+        state: &mut StateRecord,
+        value: GcObject,
+        context: &mut Context,
+    ) -> Result<JSONValue> {
+        // 1. If state.[[Stack]] contains value, throw a TypeError exception because the structure is cyclical.
+        if state.stack.iter().any(|obj| GcObject::equals(obj, &value)) {
+            return Err(context.construct_type_error("cyclic object value"));
+        }
+
+        // 2. Append value to state.[[Stack]].
+        state.stack.push(value.clone());
+
+        // 5. Let partial be a new empty List.
+        // 6. Let len be ? LengthOfArrayLike(value).
+        let len = Value::from(value.clone())
+            .get_field("length", context)?
+            .to_length(context)?;
+        let mut partial = Vec::with_capacity(len);
+
+        // 7. Let index be 0.
+        // 8. Repeat, while index < len,
+        for index in 0..len {
+            // a. Let strP be ? SerializeJSONProperty(state, ! ToString(𝔽(index)), value).
+            // b. If strP is undefined, then
+            // i. Append "null" to partial.
+            // c. Else,
+            // i. Append strP to partial.
+            let str_p = Self::serialize_json_property(state, index.into(), value.clone(), context)?;
+            partial.push(str_p.unwrap_or(JSONValue::Null));
+        }
+
+        // 11. Remove the last element of state.[[Stack]].
+        state.stack.pop();
+
+        // 9-10. The gap and indent are applied while writing the serialized JSON value.
+        // 13. Return final.
+        Ok(JSONValue::Array(partial))
+    }
+}
+
+/// The state of a `JSON.stringify` call.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-json.stringify
+struct StateRecord {
+    replacer_function: Option<Value>,
+    stack: Vec<GcObject>,
+    property_list: Option<Vec<RcString>>,
 }
 
 fn json_to_pretty_string(json: &JSONValue, gap: &str) -> String {
@@ -1,4 +1,4 @@
-use crate::{forward, forward_val, Context, Value};
+use crate::{forward, forward_val, property::Attribute, Context, Value};
 
 #[test]
 fn json_sanity() {
@@ -77,6 +77,24 @@ fn json_stringify_replacer_array_numbers() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn json_stringify_keeps_property_order() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify({ b: 1, a: { z: 2, y: 3 }, 1: 4, c: 5 })"#,
+    );
+    let expected = forward(&mut context, r#"'{"1":4,"b":1,"a":{"z":2,"y":3},"c":5}'"#);
+    assert_eq!(actual, expected);
+
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify({ a: 1, b: 2, c: 3 }, ["c", "a"])"#,
+    );
+    let expected = forward(&mut context, r#"'{"c":3,"a":1}'"#);
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn json_stringify_replacer_function() {
     let mut context = Context::new();
@@ -103,6 +121,25 @@ fn json_stringify_arrays() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn json_stringify_proxy_of_array() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "JSON.stringify({ list: new Proxy([1, 'a'], {}) })"
+        ),
+        r#""{"list":[1,"a"]}""#
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "JSON.stringify({ a: 1, b: 2 }, new Proxy(['b'], {}))"
+        ),
+        r#""{"b":2}""#
+    );
+}
+
 #[test]
 fn json_stringify_object_array() {
     let mut context = Context::new();
@@ -336,7 +373,7 @@ fn json_parse_array_with_reviver() {
             if (typeof v == 'number') {
                 return v * 2;
             } else {
-                v
+                return v;
         }})"#,
     )
     .unwrap();
@@ -464,3 +501,241 @@ fn json_parse_with_no_args_throws_syntax_error() {
     let result = forward(&mut context, "JSON.parse();");
     assert!(result.contains("SyntaxError"));
 }
+
+#[test]
+fn json_parse_reviver_doubles_numbers() {
+    let mut context = Context::new();
+    let result = forward(
+        &mut context,
+        r#"
+        var parsed = JSON.parse('{"a":1,"b":[2,{"c":3}],"d":"x"}', function(key, value) {
+            return typeof value === 'number' ? value * 2 : value;
+        });
+        JSON.stringify(parsed);
+        "#,
+    );
+    assert_eq!(result, r#""{"a":2,"b":[4,{"c":6}],"d":"x"}""#);
+}
+
+#[test]
+fn json_parse_reviver_deletes_keys() {
+    let mut context = Context::new();
+    let result = forward(
+        &mut context,
+        r#"
+        var parsed = JSON.parse('{"name":"boa","secret":1,"inner":{"secret":2,"ok":true}}', function(key, value) {
+            if (key === 'secret') {
+                return undefined;
+            }
+            return value;
+        });
+        [parsed.name, parsed.hasOwnProperty('secret'), parsed.inner.hasOwnProperty('secret'), parsed.inner.ok];
+        "#,
+    );
+    assert_eq!(result, r#"[ "boa", false, false, true ]"#);
+}
+
+#[test]
+fn json_parse_reviver_order_and_holder() {
+    let mut context = Context::new();
+    let result = forward(
+        &mut context,
+        r#"
+        var calls = [];
+        var root = JSON.parse('{"a":{"b":[1,{"c":3}]}}', function(key, value) {
+            calls.push(key);
+            if (key === 'c') {
+                calls.push(this.c === value);
+            }
+            return value;
+        });
+        calls.join();
+        "#,
+    );
+    assert_eq!(result, r#""0,c,true,1,b,a,""#);
+}
+
+#[test]
+fn json_parse_reviver_propagates_errors() {
+    let mut context = Context::new();
+    let result = forward(
+        &mut context,
+        r#"
+        try {
+            JSON.parse('[1]', function(key, value) { throw new RangeError('revived'); });
+        } catch (e) {
+            e.message;
+        }
+        "#,
+    );
+    assert_eq!(result, r#""revived""#);
+}
+
+#[test]
+fn json_stringify_replacer_array_with_indent() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify({ a: 1, b: { a: 2, c: 3 }, c: [1] }, ["a", "b"], 2)"#,
+    );
+    let expected = forward(
+        &mut context,
+        r#"'{\n  "a": 1,\n  "b": {\n    "a": 2\n  }\n}'"#,
+    );
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_replacer_function_is_recursive() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"
+        var holders = [];
+        var result = JSON.stringify({ a: 1, b: { c: 2 }, d: [3] }, function(key, value) {
+            if (key === 'c') {
+                holders.push(this.c === value);
+            }
+            return typeof value === 'number' ? value * 10 : value;
+        });
+        [result, holders[0]];
+        "#,
+    );
+    assert_eq!(actual, r#"[ "{"a":10,"b":{"c":20},"d":[30]}", true ]"#);
+}
+
+#[test]
+fn json_stringify_to_json_receives_key() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"
+        var obj = { toJSON: function(key) { return "key:" + key; } };
+        JSON.stringify({ x: obj, y: [obj] });
+        "#,
+    );
+    assert_eq!(actual, r#""{"x":"key:x","y":["key:0"]}""#);
+}
+
+#[test]
+fn json_stringify_unwraps_primitive_wrappers() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify([new Number(3), new String("s"), new Boolean(false)])"#,
+    );
+    assert_eq!(actual, r#""[3,"s",false]""#);
+}
+
+#[test]
+fn json_stringify_cyclic_object_throws() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"
+        var obj = { a: 1 };
+        obj.self = obj;
+        try {
+            JSON.stringify(obj);
+        } catch (e) {
+            e instanceof TypeError;
+        }
+        "#,
+    );
+    assert_eq!(actual, "true");
+
+    let actual = forward(
+        &mut context,
+        r#"
+        var arr = [];
+        arr.push({ arr: arr });
+        try {
+            JSON.stringify(arr);
+        } catch (e) {
+            e instanceof TypeError;
+        }
+        "#,
+    );
+    assert_eq!(actual, "true");
+}
+
+#[test]
+fn json_stringify_repeated_object_is_not_cyclic() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"
+        var shared = { a: 1 };
+        JSON.stringify([shared, { b: shared }]);
+        "#,
+    );
+    assert_eq!(actual, r#""[{"a":1},{"b":{"a":1}}]""#);
+}
+
+#[test]
+fn json_parse_reader_large_array() {
+    let mut context = Context::new();
+    let length = 10_000;
+    let source = format!(
+        "[{}]",
+        (0..length)
+            .map(|i| format!("{{\"index\":{}}}", i))
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+
+    let value = context
+        .json_parse_reader(std::io::Cursor::new(source.into_bytes()))
+        .unwrap();
+    context.register_global_property("parsed", value, Attribute::all());
+
+    assert_eq!(forward(&mut context, "parsed.length"), length.to_string());
+    assert_eq!(
+        forward(&mut context, &format!("parsed[{}].index", length - 1)),
+        (length - 1).to_string()
+    );
+    assert_eq!(forward(&mut context, "Array.isArray(parsed)"), "true");
+}
+
+#[test]
+fn json_parse_keeps_key_order() {
+    let mut context = Context::new();
+
+    let value = context
+        .json_parse_reader(r#"{"b": 1, "a": {"z": 2, "y": 3}, "c": 4, "b": 5}"#.as_bytes())
+        .unwrap();
+    context.register_global_property("parsed", value, Attribute::all());
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.keys(parsed).join() + ';' + Object.keys(parsed.a).join()"
+        ),
+        "\"b,a,c;z,y\""
+    );
+    assert_eq!(forward(&mut context, "parsed.b"), "5");
+
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"Object.keys(JSON.parse('{"b": 1, "a": 2}')).join()"#
+        ),
+        "\"b,a\""
+    );
+}
+
+#[test]
+fn json_parse_reader_malformed() {
+    let mut context = Context::new();
+
+    let error = context
+        .json_parse_reader(std::io::Cursor::new(r#"{"a": [1, 2,"#))
+        .unwrap_err();
+    context.register_global_property("error", error, Attribute::all());
+    assert_eq!(
+        forward(&mut context, "error instanceof SyntaxError"),
+        "true"
+    );
+
+    assert!(context.json_parse_reader("[1] 2".as_bytes()).is_err());
+    assert!(context.json_parse_reader("".as_bytes()).is_err());
+}
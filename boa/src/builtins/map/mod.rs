@@ -13,9 +13,12 @@
 #![allow(clippy::mutable_key_type)]
 
 use crate::{
-    builtins::BuiltIn,
-    object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
-    property::{Attribute, DataDescriptor},
+    builtins::{
+        iterable::{get_iterator, group_by, GroupKeyCoercion},
+        Array, BuiltIn,
+    },
+    object::{ConstructorBuilder, FunctionBuilder, Object, ObjectData, PROTOTYPE},
+    property::Attribute,
     symbol::WellKnownSymbols,
     BoaProfiler, Context, Result, Value,
 };
@@ -50,6 +53,12 @@ impl BuiltIn for Map {
             .callable(true)
             .build();
 
+        let size_getter = FunctionBuilder::new(context, Self::size_getter)
+            .callable(true)
+            .constructable(false)
+            .name("get size")
+            .build();
+
         let entries_function = FunctionBuilder::new(context, Self::entries)
             .name("entries")
             .length(0)
@@ -70,6 +79,7 @@ impl BuiltIn for Map {
             None,
             Attribute::CONFIGURABLE,
         )
+        .static_method(Self::group_by, "groupBy", 2)
         .property(
             "entries",
             entries_function.clone(),
@@ -93,6 +103,7 @@ impl BuiltIn for Map {
         .method(Self::has, "has", 1)
         .method(Self::for_each, "forEach", 1)
         .method(Self::values, "values", 0)
+        .accessor("size", Some(size_getter), None, Attribute::CONFIGURABLE)
         .build();
 
         (Self::NAME, map_object.into(), Self::attribute())
@@ -125,51 +136,82 @@ impl Map {
 
         let mut obj = context.construct_object();
         obj.set_prototype_instance(prototype.into());
-        let this = Value::from(obj);
-
-        // add our arguments in
-        let data = match args.len() {
-            0 => OrderedMap::new(),
-            _ => match &args[0] {
-                Value::Object(object) => {
-                    let object = object.borrow();
-                    if let Some(map) = object.as_map_ref().cloned() {
-                        map
-                    } else if object.is_array() {
-                        let mut map = OrderedMap::new();
-                        let len = args[0].get_field("length", context)?.to_integer(context)? as i32;
-                        for i in 0..len {
-                            let val = &args[0].get_field(i, context)?;
-                            let (key, value) =
-                                Self::get_key_value(val, context)?.ok_or_else(|| {
-                                    context.construct_type_error(
-                                        "iterable for Map should have array-like objects",
-                                    )
-                                })?;
-                            map.insert(key, value);
-                        }
-                        map
-                    } else {
-                        return Err(context.construct_type_error(
-                            "iterable for Map should have array-like objects",
-                        ));
-                    }
-                }
-                _ => {
-                    return Err(context
-                        .construct_type_error("iterable for Map should have array-like objects"))
-                }
-            },
-        };
+        let map = Value::from(obj);
+        map.set_data(ObjectData::Map(OrderedMap::new()));
+
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        if iterable.is_null_or_undefined() {
+            return Ok(map);
+        }
+
+        let adder = map.get_field("set", context)?;
+        if !adder.is_function() {
+            return context.throw_type_error("'set' of 'newTarget' is not a function");
+        }
+
+        // AddEntriesFromIterable
+        let iterator_record = get_iterator(context, iterable)?;
+        loop {
+            let next = iterator_record.next(context)?;
+            if next.is_done() {
+                return Ok(map);
+            }
+
+            let next_item = next.value();
+            if !next_item.is_object() {
+                let error = context.construct_type_error("iterator value is not an entry object");
+                return iterator_record.close(Err(error), context);
+            }
+
+            let entry = next_item
+                .get_field(0, context)
+                .and_then(|key| Ok((key, next_item.get_field(1, context)?)));
+            let (key, value) = match entry {
+                Ok(entry) => entry,
+                Err(error) => return iterator_record.close(Err(error), context),
+            };
 
-        // finally create size property
-        Self::set_size(&this, data.len());
+            if let Err(error) = context.call(&adder, &map, &[key, value]) {
+                return iterator_record.close(Err(error), context);
+            }
+        }
+    }
 
-        // This value is used by console.log and other routines to match Object type
-        // to its Javascript Identifier (global constructor method name)
-        this.set_data(ObjectData::Map(data));
+    /// `Map.groupBy( items, callbackFn )`
+    ///
+    /// Groups the values of an iterable into a new `Map`, keyed by the values returned by the
+    /// callback.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-map.groupby
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/groupBy
+    fn group_by(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let items = args.get(0).cloned().unwrap_or_default();
+        let callback = args.get(1).cloned().unwrap_or_default();
+
+        // 1. Let groups be ? GroupBy(items, callbackfn, zero).
+        let groups = group_by(&items, &callback, GroupKeyCoercion::Zero, context)?;
+
+        // 2. Let map be ! Construct(%Map%).
+        // 3. For each Record { [[Key]], [[Elements]] } g of groups, do
+        //     a. Let elements be CreateArrayFromList(g.[[Elements]]).
+        //     b. Let entry be the Record { [[Key]]: g.[[Key]], [[Value]]: elements }.
+        //     c. Append entry to map.[[MapData]].
+        let mut entries = OrderedMap::new();
+        for (key, elements) in groups {
+            let new = Array::new_array(context);
+            entries.insert(key, Array::construct_array(&new, &elements, context)?);
+        }
 
-        Ok(this)
+        // 4. Return map.
+        let prototype = context.standard_objects().map_object().prototype();
+        Ok(Value::object(Object::with_prototype(
+            prototype.into(),
+            ObjectData::Map(entries),
+        )))
     }
 
     /// `get Map [ @@species ]`
@@ -223,16 +265,6 @@ impl Map {
         ))
     }
 
-    /// Helper function to set the size property.
-    fn set_size(this: &Value, size: usize) {
-        let size = DataDescriptor::new(
-            size,
-            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
-        );
-
-        this.set_property("size".to_string(), size);
-    }
-
     /// `Map.prototype.set( key, value )`
     ///
     /// This method associates the value with the key. Returns the map object.
@@ -244,16 +276,15 @@ impl Map {
     /// [spec]: https://tc39.es/ecma262/#sec-map.prototype.set
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/set
     pub(crate) fn set(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let (key, value) = match args.len() {
-            0 => (Value::Undefined, Value::Undefined),
-            1 => (args[0].clone(), Value::Undefined),
-            _ => (args[0].clone(), args[1].clone()),
-        };
+        let mut key = args.get(0).cloned().unwrap_or_default();
+        let value = args.get(1).cloned().unwrap_or_default();
 
-        let size = if let Some(object) = this.as_object() {
+        if let Some(object) = this.as_object() {
             if let Some(map) = object.borrow_mut().as_map_mut() {
+                if key.as_number().map(|n| n == -0f64).unwrap_or(false) {
+                    key = Value::Integer(0);
+                }
                 map.insert(key, value);
-                map.len()
             } else {
                 return Err(context.construct_type_error("'this' is not a Map"));
             }
@@ -261,7 +292,6 @@ impl Map {
             return Err(context.construct_type_error("'this' is not a Map"));
         };
 
-        Self::set_size(this, size);
         Ok(this.clone())
     }
 
@@ -282,17 +312,16 @@ impl Map {
             _ => &args[0],
         };
 
-        let (deleted, size) = if let Some(object) = this.as_object() {
+        let deleted = if let Some(object) = this.as_object() {
             if let Some(map) = object.borrow_mut().as_map_mut() {
-                let deleted = map.remove(key).is_some();
-                (deleted, map.len())
+                map.remove(key).is_some()
             } else {
                 return Err(context.construct_type_error("'this' is not a Map"));
             }
         } else {
             return Err(context.construct_type_error("'this' is not a Map"));
         };
-        Self::set_size(this, size);
+
         Ok(deleted.into())
     }
 
@@ -340,8 +369,6 @@ impl Map {
     pub(crate) fn clear(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
         this.set_data(ObjectData::Map(OrderedMap::new()));
 
-        Self::set_size(this, 0);
-
         Ok(Value::Undefined)
     }
 
@@ -418,6 +445,20 @@ impl Map {
         Ok(Value::Undefined)
     }
 
+    /// `get Map.prototype.size`
+    ///
+    /// This accessor returns the number of entries of the map.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-map.prototype.size
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map/size
+    fn size_getter(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Map::get_size(this, context).map(Value::from)
+    }
+
     /// Helper function to get the size of the map.
     fn get_size(map: &Value, context: &mut Context) -> Result<usize> {
         if let Value::Object(ref object) = map {
@@ -449,23 +490,4 @@ impl Map {
             MapIterationKind::Value,
         ))
     }
-
-    /// Helper function to get a key-value pair from an array.
-    fn get_key_value(value: &Value, context: &mut Context) -> Result<Option<(Value, Value)>> {
-        if let Value::Object(object) = value {
-            if object.is_array() {
-                let (key, value) =
-                    match value.get_field("length", context)?.as_number().unwrap() as i32 {
-                        0 => (Value::Undefined, Value::Undefined),
-                        1 => (value.get_field("0", context)?, Value::Undefined),
-                        _ => (
-                            value.get_field("0", context)?,
-                            value.get_field("1", context)?,
-                        ),
-                    };
-                return Ok(Some((key, value)));
-            }
-        }
-        Ok(None)
-    }
 }
@@ -354,3 +354,114 @@ fn not_a_function() {
         "\"TypeError: calling a builtin Map constructor without new is forbidden\""
     );
 }
+
+#[test]
+fn iteration_order_after_delete() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new Map([["a", 1], ["b", 2], ["c", 3]]);
+        map.delete("a");
+        map.set("d", 4);
+        map.set("a", 5);
+        map.set("b", 6);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "[...map.keys()].join()"),
+        "\"b,c,d,a\""
+    );
+    assert_eq!(
+        forward(&mut context, "[...map.values()].join()"),
+        "\"6,3,4,5\""
+    );
+    let result = forward(
+        &mut context,
+        r#"
+        let entries = [];
+        map.forEach(function (value, key) {
+            entries.push(key + value);
+        });
+        entries.join()"#,
+    );
+    assert_eq!(result, "\"b6,c3,d4,a5\"");
+}
+
+#[test]
+fn same_value_zero_keys() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new Map([[NaN, "nan"], [-0, "zero"]]);
+        map.set(0 / 0, "other nan");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "map.size"), "2");
+    assert_eq!(forward(&mut context, "map.get(NaN)"), "\"other nan\"");
+    assert_eq!(forward(&mut context, "map.get(0)"), "\"zero\"");
+    assert_eq!(forward(&mut context, "map.has(-0)"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.is([...map.keys()][1], -0)"),
+        "false"
+    );
+}
+
+#[test]
+fn size_accessor() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new Map([[1, 1]]);
+        let descriptor = Object.getOwnPropertyDescriptor(Map.prototype, "size");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "typeof descriptor.get"),
+        "\"function\""
+    );
+    assert_eq!(forward(&mut context, "map.hasOwnProperty('size')"), "false");
+    assert_eq!(forward(&mut context, "descriptor.get.call(map)"), "1");
+}
+
+#[test]
+fn construct_from_iterable() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set([[1, "one"], [2, "two"]]);
+        let map = new Map(set);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "map.get(2)"), "\"two\"");
+    let result = forward(
+        &mut context,
+        r#"
+        try {
+            new Map([1]);
+        } catch (e) {
+            e.toString();
+        }"#,
+    );
+    assert_eq!(
+        result,
+        "\"TypeError: iterator value is not an entry object\""
+    );
+}
+
+#[test]
+fn map_group_by() {
+    let mut context = Context::new();
+    let init = r#"
+        var key = {};
+        var groups = Map.groupBy([1, 2, 3, 4, 5], n => n % 2 === 0 ? key : n % 2);
+        var zeros = Map.groupBy([-0, 0, "0"], n => n);
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "groups instanceof Map"), "true");
+    assert_eq!(forward(&mut context, "groups.size"), "2");
+    assert_eq!(forward(&mut context, "groups.get(1).join()"), "\"1,3,5\"");
+    assert_eq!(forward(&mut context, "groups.get(key).join()"), "\"2,4\"");
+    assert_eq!(forward(&mut context, "[...groups.keys()][0]"), "1");
+    assert_eq!(forward(&mut context, "zeros.size"), "2");
+    assert_eq!(forward(&mut context, "zeros.get(0).length"), "2");
+    assert_eq!(
+        forward(&mut context, "Object.is([...zeros.keys()][0], 0)"),
+        "true"
+    );
+}
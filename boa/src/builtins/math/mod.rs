@@ -12,9 +12,13 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math
 
 use crate::{
-    builtins::BuiltIn, object::ObjectInitializer, property::Attribute, symbol::WellKnownSymbols,
+    builtins::{BuiltIn, Number},
+    object::ObjectInitializer,
+    property::Attribute,
+    symbol::WellKnownSymbols,
     BoaProfiler, Context, Result, Value,
 };
+use rand::Rng;
 
 #[cfg(test)]
 mod tests;
@@ -536,7 +540,7 @@ impl Math {
             args.get(0).map(|x| x.to_number(context)).transpose()?,
             args.get(1).map(|x| x.to_number(context)).transpose()?,
         ) {
-            (Some(x), Some(y)) => x.powf(y),
+            (Some(x), Some(y)) => Number::exponentiate(x, y),
             (_, _) => f64::NAN,
         }
         .into())
@@ -550,8 +554,8 @@ impl Math {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-math.random
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Math/random
-    pub(crate) fn random(_: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
-        Ok(rand::random::<f64>().into())
+    pub(crate) fn random(_: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Ok(context.rng().gen::<f64>().into())
     }
 
     /// Round a number to the nearest integer.
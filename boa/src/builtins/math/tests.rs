@@ -585,6 +585,44 @@ fn pow() {
     assert_eq!(d.to_number(&mut context).unwrap(), 0.020_408_163_265_306_12);
 }
 
+#[test]
+fn random() {
+    let mut context = Context::new();
+    let values = forward(
+        &mut context,
+        r#"
+        var inRange = true;
+        for (var i = 0; i < 100; i++) {
+            var value = Math.random();
+            inRange = inRange && value >= 0 && value < 1;
+        }
+        inRange
+        "#,
+    );
+    assert_eq!(values, "true");
+}
+
+#[test]
+fn random_seeded() {
+    let sequence = |seed| {
+        let mut context = Context::new();
+        context.seed_rng(seed);
+        (0..10)
+            .map(|_| {
+                forward_val(&mut context, "Math.random()")
+                    .unwrap()
+                    .to_number(&mut context)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let first = sequence(42);
+    assert_eq!(first, sequence(42));
+    assert_ne!(first, sequence(43));
+    assert!(first.iter().all(|value| (0.0..1.0).contains(value)));
+}
+
 #[test]
 fn round() {
     let mut context = Context::new();
@@ -727,3 +765,128 @@ fn trunc() {
     assert_eq!(a.to_number(&mut context).unwrap(), 13_f64);
     assert_eq!(b.to_number(&mut context).unwrap(), 0_f64);
 }
+
+/// Checks that each source evaluates to the expected display string.
+fn check_edge_values(cases: &[(&str, &str)]) {
+    let mut context = Context::new();
+    for (source, expected) in cases {
+        assert_eq!(&forward(&mut context, source), expected, "{}", source);
+    }
+}
+
+#[test]
+fn hypot_edge_values() {
+    check_edge_values(&[
+        ("Math.hypot()", "0"),
+        ("Object.is(Math.hypot(-0), 0)", "true"),
+        ("Object.is(Math.hypot(-0, -0), 0)", "true"),
+        ("Math.hypot(-3)", "3"),
+        ("Math.hypot(NaN)", "NaN"),
+        ("Math.hypot(NaN, 3)", "NaN"),
+        ("Math.hypot(undefined, 3)", "NaN"),
+        ("Math.hypot(NaN, Infinity)", "Infinity"),
+        ("Math.hypot(Infinity, NaN)", "Infinity"),
+        ("Math.hypot(3, NaN, -Infinity)", "Infinity"),
+        ("Math.hypot(1e200, 1e200)", "1.414213562373095e+200"),
+        ("Math.hypot(1e-200, 1e-200)", "1.414213562373095e-200"),
+        (
+            "Math.hypot(1.7976931348623157e308, 1)",
+            "1.7976931348623157e+308",
+        ),
+        ("Math.hypot.length", "2"),
+    ]);
+}
+
+#[test]
+fn cbrt_edge_values() {
+    check_edge_values(&[
+        ("Math.cbrt()", "NaN"),
+        ("Math.cbrt(NaN)", "NaN"),
+        ("Object.is(Math.cbrt(0), 0)", "true"),
+        ("Object.is(Math.cbrt(-0), -0)", "true"),
+        ("Math.cbrt(Infinity)", "Infinity"),
+        ("Math.cbrt(-Infinity)", "-Infinity"),
+        ("Math.cbrt(-8)", "-2"),
+        ("Math.cbrt(\"8\")", "2"),
+    ]);
+}
+
+#[test]
+fn expm1_edge_values() {
+    check_edge_values(&[
+        ("Math.expm1(NaN)", "NaN"),
+        ("Object.is(Math.expm1(0), 0)", "true"),
+        ("Object.is(Math.expm1(-0), -0)", "true"),
+        ("Math.expm1(Infinity)", "Infinity"),
+        ("Math.expm1(-Infinity)", "-1"),
+        ("Math.expm1(1e-20)", "1e-20"),
+    ]);
+}
+
+#[test]
+fn log1p_edge_values() {
+    check_edge_values(&[
+        ("Math.log1p()", "NaN"),
+        ("Math.log1p(NaN)", "NaN"),
+        ("Object.is(Math.log1p(0), 0)", "true"),
+        ("Object.is(Math.log1p(-0), -0)", "true"),
+        ("Math.log1p(-1)", "-Infinity"),
+        ("Math.log1p(-2)", "NaN"),
+        ("Math.log1p(-Infinity)", "NaN"),
+        ("Math.log1p(Infinity)", "Infinity"),
+        ("Math.log1p(1e-20)", "1e-20"),
+    ]);
+}
+
+#[test]
+fn sinh_edge_values() {
+    check_edge_values(&[
+        ("Math.sinh()", "NaN"),
+        ("Math.sinh(NaN)", "NaN"),
+        ("Object.is(Math.sinh(0), 0)", "true"),
+        ("Object.is(Math.sinh(-0), -0)", "true"),
+        ("Math.sinh(Infinity)", "Infinity"),
+        ("Math.sinh(-Infinity)", "-Infinity"),
+    ]);
+}
+
+#[test]
+fn cosh_edge_values() {
+    check_edge_values(&[
+        ("Math.cosh()", "NaN"),
+        ("Math.cosh(NaN)", "NaN"),
+        ("Math.cosh(0)", "1"),
+        ("Math.cosh(-0)", "1"),
+        ("Math.cosh(Infinity)", "Infinity"),
+        ("Math.cosh(-Infinity)", "Infinity"),
+    ]);
+}
+
+#[test]
+fn tanh_edge_values() {
+    check_edge_values(&[
+        ("Math.tanh()", "NaN"),
+        ("Math.tanh(NaN)", "NaN"),
+        ("Object.is(Math.tanh(0), 0)", "true"),
+        ("Object.is(Math.tanh(-0), -0)", "true"),
+        ("Math.tanh(Infinity)", "1"),
+        ("Math.tanh(-Infinity)", "-1"),
+    ]);
+}
+
+#[test]
+fn clz32_edge_values() {
+    check_edge_values(&[
+        ("Math.clz32(NaN)", "32"),
+        ("Math.clz32(-0)", "32"),
+        ("Math.clz32(Infinity)", "32"),
+        ("Math.clz32(-Infinity)", "32"),
+        ("Math.clz32(1)", "31"),
+        ("Math.clz32(0.5)", "32"),
+        ("Math.clz32(-1)", "0"),
+        ("Math.clz32(2 ** 31)", "0"),
+        ("Math.clz32(2 ** 32)", "32"),
+        ("Math.clz32(2 ** 32 + 1)", "31"),
+        ("Math.clz32(null)", "32"),
+    ]);
+}
@@ -1,6 +1,9 @@
 //! Builtins live here, such as Object, String, Math, etc.
 
 pub mod array;
+pub mod array_buffer;
+pub mod async_function;
+pub mod async_generator;
 pub mod bigint;
 pub mod boolean;
 #[cfg(feature = "console")]
@@ -8,8 +11,10 @@ pub mod console;
 pub mod date;
 pub mod error;
 pub mod function;
+pub mod generator;
 pub mod global_this;
 pub mod infinity;
+pub mod intl;
 pub mod iterable;
 pub mod json;
 pub mod map;
@@ -17,22 +22,33 @@ pub mod math;
 pub mod nan;
 pub mod number;
 pub mod object;
+pub mod promise;
+pub mod proxy;
+pub mod queue_microtask;
 pub mod reflect;
 pub mod regexp;
 pub mod set;
 pub mod string;
+pub mod structured_clone;
 pub mod symbol;
+pub mod text_encoding;
+pub mod typed_array;
 pub mod undefined;
+pub mod uri;
+pub mod weak_map;
 
 pub(crate) use self::{
     array::{array_iterator::ArrayIterator, Array},
+    array_buffer::ArrayBuffer,
     bigint::BigInt,
     boolean::Boolean,
     date::Date,
     error::{Error, EvalError, RangeError, ReferenceError, SyntaxError, TypeError, UriError},
     function::BuiltInFunctionObject,
+    generator::Generator,
     global_this::GlobalThis,
     infinity::Infinity,
+    intl::Intl,
     json::Json,
     map::map_iterator::MapIterator,
     map::Map,
@@ -41,18 +57,30 @@ pub(crate) use self::{
     number::Number,
     object::for_in_iterator::ForInIterator,
     object::Object as BuiltInObjectObject,
+    promise::Promise,
+    proxy::Proxy,
+    queue_microtask::QueueMicrotask,
     reflect::Reflect,
-    regexp::RegExp,
+    regexp::{regexp_string_iterator::RegExpStringIterator, RegExp},
     set::set_iterator::SetIterator,
     set::Set,
     string::String,
+    structured_clone::StructuredClone,
     symbol::Symbol,
+    text_encoding::{TextDecoder, TextEncoder},
+    typed_array::TypedArray,
     undefined::Undefined,
+    uri::Uri,
+    weak_map::WeakMap,
 };
 use crate::{
-    property::{Attribute, DataDescriptor},
+    context::{copy_intrinsic, copy_intrinsic_value},
+    object::{GcObject, Object},
+    property::{Attribute, DataDescriptor, PropertyDescriptor},
     Context, Value,
 };
+use rustc_hash::FxHashMap;
+use std::fmt;
 
 pub(crate) trait BuiltIn {
     /// The binding name of the property.
@@ -62,6 +90,171 @@ pub(crate) trait BuiltIn {
     fn init(context: &mut Context) -> (&'static str, Value, Attribute);
 }
 
+/// The function that initializes a builtin, and returns its global property.
+type BuiltInInit = fn(&mut Context) -> (&'static str, Value, Attribute);
+
+/// The builtins that are only initialized once their global binding is first read, as they are
+/// expensive to initialize and many scripts never use them.
+///
+/// Until then, their global property is a data property holding a placeholder object, which is
+/// replaced by the builtin when a script reads the binding. Scripts can only inspect the global
+/// property itself through the global object, so all the lazy builtins are initialized once the
+/// global object is first read by a script, and the placeholders are never observed.
+///
+/// The code that uses the standard objects of one of these builtins without reading its global
+/// binding first has to initialize it with [`init_lazy`].
+fn lazy_builtins() -> [(&'static str, Attribute, BuiltInInit); 4] {
+    [
+        (Math::NAME, Math::attribute(), Math::init),
+        (Date::NAME, Date::attribute(), Date::init),
+        (RegExp::NAME, RegExp::attribute(), RegExp::init),
+        (Intl::NAME, Intl::attribute(), Intl::init),
+    ]
+}
+
+/// A builtin that is initialized once its global binding is first read.
+pub(crate) struct LazyBuiltIn {
+    name: &'static str,
+    attribute: Attribute,
+    init: BuiltInInit,
+    /// The value of the global property until the builtin is initialized, to know whether the
+    /// property was redefined since.
+    placeholder: GcObject,
+    /// The builtin, once it's initialized.
+    value: Option<Value>,
+}
+
+impl LazyBuiltIn {
+    /// Copies the builtin for a context created from [`Intrinsics`](crate::context::Intrinsics).
+    pub(crate) fn copy(&self, copies: &mut FxHashMap<usize, GcObject>) -> Self {
+        Self {
+            name: self.name,
+            attribute: self.attribute,
+            init: self.init,
+            placeholder: copy_intrinsic(&self.placeholder, copies),
+            value: self
+                .value
+                .as_ref()
+                .map(|value| copy_intrinsic_value(value, copies)),
+        }
+    }
+
+    /// Checks if the builtin was initialized.
+    #[cfg(test)]
+    pub(crate) fn is_initialized(&self) -> bool {
+        self.value.is_some()
+    }
+}
+
+impl fmt::Debug for LazyBuiltIn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LazyBuiltIn")
+            .field("name", &self.name)
+            .field("value", &self.value)
+            .finish()
+    }
+}
+
+/// Initializes a lazy builtin if it isn't initialized yet, and returns it.
+///
+/// Returns `None` if `name` isn't the name of a lazy builtin.
+pub(crate) fn init_lazy(context: &mut Context, name: &str) -> Option<Value> {
+    let index = context
+        .lazy_builtins
+        .iter()
+        .position(|builtin| builtin.name == name)?;
+    let value = if let Some(ref value) = context.lazy_builtins[index].value {
+        value.clone()
+    } else {
+        let (_, value, _) = (context.lazy_builtins[index].init)(context);
+        context.lazy_builtins[index].value = Some(value.clone());
+        value
+    };
+    let mut global_object = context.global_object();
+    let builtin = &context.lazy_builtins[index];
+
+    // The global property is only replaced if it wasn't redefined or deleted in the meantime.
+    let is_lazy = matches!(
+        global_object.get_own_property(&name.into()),
+        Some(PropertyDescriptor::Data(ref data))
+            if data.value().as_object().map_or(false, |object| GcObject::equals(&object, &builtin.placeholder))
+    );
+    if is_lazy {
+        let property = DataDescriptor::new(value.clone(), builtin.attribute);
+        global_object.insert(builtin.name, property);
+    }
+    Some(value)
+}
+
+/// Initializes all the lazy builtins, before the global object is handed to a script.
+pub(crate) fn init_all_lazy(context: &mut Context) {
+    for index in 0..context.lazy_builtins.len() {
+        let builtin = &context.lazy_builtins[index];
+        if builtin.value.is_none() {
+            let name = builtin.name;
+            init_lazy(context, name);
+        }
+    }
+}
+
+/// Replaces the placeholders restored in the global properties of the lazy builtins that were
+/// initialized since the globals were saved.
+pub(crate) fn restore_lazy(context: &mut Context) {
+    for index in 0..context.lazy_builtins.len() {
+        let builtin = &context.lazy_builtins[index];
+        if builtin.value.is_some() {
+            let name = builtin.name;
+            init_lazy(context, name);
+        }
+    }
+}
+
+/// Gets the value of the global binding `name`, initializing the lazy builtin it holds the
+/// placeholder of, if any.
+pub(crate) fn resolve_lazy(context: &mut Context, name: &str, value: Value) -> Value {
+    let is_placeholder = match value {
+        Value::Object(ref object) => context
+            .lazy_builtins
+            .iter()
+            .any(|builtin| builtin.name == name && GcObject::equals(object, &builtin.placeholder)),
+        _ => false,
+    };
+    if is_placeholder {
+        init_lazy(context, name).expect("the builtin is lazy")
+    } else {
+        value
+    }
+}
+
+/// Checks if the global property is the placeholder of a lazy builtin, which has to keep its
+/// identity when the global properties are copied.
+pub(crate) fn is_lazy_placeholder(context: &Context, property: &PropertyDescriptor) -> bool {
+    match property {
+        PropertyDescriptor::Data(data) => match data.value() {
+            Value::Object(ref object) => context
+                .lazy_builtins
+                .iter()
+                .any(|builtin| GcObject::equals(object, &builtin.placeholder)),
+            _ => false,
+        },
+        PropertyDescriptor::Accessor(_) => false,
+    }
+}
+
+/// Defines the global property of a lazy builtin, holding its placeholder.
+fn define_lazy(context: &mut Context, name: &'static str, attribute: Attribute, init: BuiltInInit) {
+    let placeholder = GcObject::new(Object::default());
+    context.lazy_builtins.push(LazyBuiltIn {
+        name,
+        attribute,
+        init,
+        placeholder: placeholder.clone(),
+        value: None,
+    });
+    let property = DataDescriptor::new(placeholder, attribute);
+    context.global_object().insert(name, property);
+}
+
 /// Initializes builtin objects and functions
 #[inline]
 pub fn init(context: &mut Context) {
@@ -73,17 +266,17 @@ pub fn init(context: &mut Context) {
         GlobalThis::init,
         BuiltInFunctionObject::init,
         BuiltInObjectObject::init,
-        Math::init,
         Json::init,
         Array::init,
+        ArrayBuffer::init,
         BigInt::init,
         Boolean::init,
-        Date::init,
         Map::init,
         Number::init,
         Set::init,
+        WeakMap::init,
+        Promise::init,
         String::init,
-        RegExp::init,
         Symbol::init,
         Error::init,
         RangeError::init,
@@ -93,6 +286,11 @@ pub fn init(context: &mut Context) {
         EvalError::init,
         UriError::init,
         Reflect::init,
+        Proxy::init,
+        StructuredClone::init,
+        QueueMicrotask::init,
+        TextEncoder::init,
+        TextDecoder::init,
         #[cfg(feature = "console")]
         console::Console::init,
     ];
@@ -104,4 +302,12 @@ pub fn init(context: &mut Context) {
         let property = DataDescriptor::new(value, attribute);
         global_object.borrow_mut().insert(name, property);
     }
+
+    // Global objects that are not bound to a single global property.
+    for (name, attribute, init) in lazy_builtins().iter() {
+        define_lazy(context, name, *attribute, *init);
+    }
+
+    Uri::init(context);
+    TypedArray::init(context);
 }
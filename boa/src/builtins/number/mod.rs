@@ -22,7 +22,8 @@ use crate::{
     value::{AbstractRelation, IntegerOrInfinity, Value},
     BoaProfiler, Context, Result,
 };
-use num_traits::{float::FloatCore, Num};
+use num_bigint::BigUint;
+use num_traits::{float::FloatCore, ToPrimitive};
 
 mod conversions;
 
@@ -208,15 +209,6 @@ impl Number {
         Err(context.construct_type_error("'this' is not a number"))
     }
 
-    /// Helper function that formats a float as a ES6-style exponential number string.
-    fn num_to_exponential(n: f64) -> String {
-        match n.abs() {
-            x if x > 1.0 => format!("{:e}", n).replace("e", "e+"),
-            x if x == 0.0 => format!("{:e}", n).replace("e", "e+"),
-            _ => format!("{:e}", n),
-        }
-    }
-
     /// `Number.prototype.toExponential( [fractionDigits] )`
     ///
     /// The `toExponential()` method returns a string representing the Number object in exponential notation.
@@ -230,12 +222,99 @@ impl Number {
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_exponential(
         this: &Value,
-        _: &[Value],
+        args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        let this_num = Self::this_number_value(this, context)?;
-        let this_str_num = Self::num_to_exponential(this_num);
-        Ok(Value::from(this_str_num))
+        // 1. Let x be ? thisNumberValue(this value).
+        let mut this_num = Self::this_number_value(this, context)?;
+
+        // 2. Let f be ? ToIntegerOrInfinity(fractionDigits).
+        // 3. Assert: If fractionDigits is undefined, then f is 0.
+        let fraction_digits = args.get(0).cloned().unwrap_or_default();
+        let precision = fraction_digits.to_integer_or_infinity(context)?;
+
+        // 4. If x is not finite, return ! Number::toString(x).
+        if !this_num.is_finite() {
+            return Ok(Value::from(Self::to_native_string(this_num)));
+        }
+
+        // 5. If f < 0 or f > 100, throw a RangeError exception.
+        let precision = match precision {
+            IntegerOrInfinity::Integer(x) if (0..=100).contains(&x) => x as usize,
+            _ => {
+                return context
+                    .throw_range_error("toExponential() argument must be between 0 and 100")
+            }
+        };
+
+        // 6. Set x to ℝ(x).
+        // 7. Let s be the empty String.
+        // 8. If x < 0, then
+        let mut prefix = String::new();
+        if this_num < 0.0 {
+            // a. Set s to "-".
+            prefix.push('-');
+            // b. Set x to -x.
+            this_num = -this_num;
+        }
+
+        let (mut digits, exponent) = if this_num == 0.0 {
+            // 9. If x = 0, then
+            //   a. Let m be the String value consisting of f + 1 occurrences of the code unit 0x0030 (DIGIT ZERO).
+            //   b. Let e be 0.
+            ("0".repeat(precision + 1), 0)
+        } else if fraction_digits.is_undefined() {
+            // 10. Else,
+            //   a. If fractionDigits is not undefined, then
+            //     i. Let e and n be integers such that 10^f ≤ n < 10^(f + 1) and for which
+            //        n × 10^(e - f) - x is as close to zero as possible. If there are two such
+            //        sets of e and n, pick the e and n for which n × 10^(e - f) is larger.
+            //   b. Else,
+            //     i. Let e, n, and f be integers such that f ≥ 0, 10^f ≤ n < 10^(f + 1),
+            //        𝔽(n × 10^(e - f)) is 𝔽(x), and f is as small as possible.
+            //
+            // The exponential formatting of Rust gives the shortest digits that round trip.
+            let formatted = format!("{:e}", this_num);
+            let (mantissa, exponent) = formatted
+                .split_once('e')
+                .expect("exponential format has an exponent");
+            (
+                mantissa.replace('.', ""),
+                exponent.parse().expect("exponent is an integer"),
+            )
+        } else {
+            Self::significant_digits(this_num, precision + 1)
+        };
+
+        // 11. If f ≠ 0, then
+        if digits.len() > 1 {
+            // a. Let a be the first code unit of m.
+            // b. Let b be the remaining f code units of m.
+            // c. Set m to the string-concatenation of a, ".", and b.
+            digits.insert(1, '.');
+        }
+
+        // 12. If e = 0, then
+        //   a. Let c be "+".
+        //   b. Let d be "0".
+        // 13. Else,
+        //   a. If e > 0, let c be "+".
+        //   b. Else,
+        //     i. Assert: e < 0.
+        //     ii. Let c be "-".
+        //     iii. Set e to -e.
+        //   c. Let d be the String value consisting of the digits of the decimal representation of e (in order, with no leading zeroes).
+        let sign = if exponent < 0 { '-' } else { '+' };
+
+        // 14. Set m to the string-concatenation of m, "e", c, and d.
+        // 15. Return the string-concatenation of s and m.
+        Ok(Value::from(format!(
+            "{}{}e{}{}",
+            prefix,
+            digits,
+            sign,
+            exponent.abs()
+        )))
     }
 
     /// `Number.prototype.toFixed( [digits] )`
@@ -250,16 +329,90 @@ impl Number {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toFixed
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_fixed(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let this_num = Self::this_number_value(this, context)?;
-        let precision = match args.get(0) {
-            Some(n) => match n.to_integer(context)? as i32 {
-                x if x > 0 => n.to_integer(context)? as usize,
-                _ => 0,
-            },
-            None => 0,
+        // 1. Let x be ? thisNumberValue(this value).
+        let mut this_num = Self::this_number_value(this, context)?;
+
+        // 2. Let f be ? ToIntegerOrInfinity(fractionDigits).
+        // 3. Assert: If fractionDigits is undefined, then f is 0.
+        let precision = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_integer_or_infinity(context)?;
+
+        // 4. If f is not finite, throw a RangeError exception.
+        // 5. If f < 0 or f > 100, throw a RangeError exception.
+        let precision = match precision {
+            IntegerOrInfinity::Integer(x) if (0..=100).contains(&x) => x as usize,
+            _ => {
+                return context
+                    .throw_range_error("toFixed() digits argument must be between 0 and 100")
+            }
         };
-        let this_fixed_num = format!("{:.*}", precision, this_num);
-        Ok(Value::from(this_fixed_num))
+
+        // 6. If x is not finite, return ! Number::toString(x).
+        if !this_num.is_finite() {
+            return Ok(Value::from(Self::to_native_string(this_num)));
+        }
+
+        // 7. Set x to ℝ(x).
+        //
+        // Mathematical values have no negative zero, so `-0` is formatted like `0`.
+        if this_num == 0.0 {
+            this_num = 0.0;
+        }
+
+        // 8. Let s be the empty String.
+        // 9. If x < 0, then
+        let mut prefix = String::new();
+        if this_num < 0.0 {
+            // a. Set s to "-".
+            prefix.push('-');
+            // b. Set x to -x.
+            this_num = -this_num;
+        }
+
+        // 10. If x ≥ 10^21, then
+        if this_num >= 1e21 {
+            // a. Let m be ! ToString(𝔽(x)).
+            return Ok(Value::from(prefix + &Self::to_native_string(this_num)));
+        }
+
+        // 11. Else,
+        // a. Let n be an integer for which n / 10^f - x is as close to zero as possible. If there
+        //    are two such n, pick the larger n.
+        //
+        // Every finite f64 has a decimal expansion of at most 1074 fractional digits, so formatting
+        // with more digits than that gives the exact value of `x`, which is then rounded half up.
+        let exact = format!("{:.1100}", this_num);
+        let mut digits = exact.replace('.', "");
+        let integer_len = exact
+            .find('.')
+            .expect("formatted float has a decimal point");
+        if Self::round_to_precision(&mut digits, integer_len + precision) {
+            // The number was rounded up to the next power of ten.
+            digits.push('0');
+        }
+
+        // b. If n = 0, let m be the String "0". Otherwise, let m be the String value consisting of
+        //    the digits of the decimal representation of n (in order, with no leading zeroes).
+        // c. If f ≠ 0, then
+        if precision != 0 {
+            // i. Let k be the length of m.
+            // ii. If k ≤ f, then
+            //     1. Let z be the String value consisting of f + 1 - k occurrences of the code unit 0x0030 (DIGIT ZERO).
+            //     2. Set m to the string-concatenation of z and m.
+            //     3. Set k to f + 1.
+            // iii. Let a be the first k - f code units of m.
+            // iv. Let b be the other f code units of m.
+            // v. Set m to the string-concatenation of a, ".", and b.
+            //
+            // The integer part of `exact` is at least "0", so `m` always has more than f digits.
+            digits.insert(digits.len() - precision, '.');
+        }
+
+        // 12. Return the string-concatenation of s and m.
+        Ok(Value::from(prefix + &digits))
     }
 
     /// `Number.prototype.toLocaleString( [locales [, options]] )`
@@ -372,6 +525,30 @@ impl Number {
         }
     }
 
+    /// Gets the `precision` most significant decimal digits of the positive finite number `x`,
+    /// rounded half up, and the exponent of the first digit, so that `x` is about
+    /// `digits × 10^(exponent - precision + 1)`.
+    fn significant_digits(x: f64, precision: usize) -> (String, i32) {
+        // Every finite f64 has a decimal expansion of at most 1074 fractional digits, so formatting
+        // with more digits than that gives the exact value of `x`.
+        let mut digits = format!("{:.1100}", x);
+
+        // Getting the exponent, and the digits from the first significant one.
+        let mut exponent = Self::flt_str_to_exp(&digits);
+        if exponent < 0 {
+            digits = digits.split_off((1 - exponent) as usize);
+        } else if let Some(n) = digits.find('.') {
+            digits.remove(n);
+        }
+
+        // Having exactly `precision` digits, which can round up to the next power of ten.
+        if Self::round_to_precision(&mut digits, precision) {
+            exponent += 1;
+        }
+
+        (digits, exponent)
+    }
+
     /// `Number.prototype.toPrecision( [precision] )`
     ///
     /// The `toPrecision()` method returns a string representing the Number object to the specified precision.
@@ -419,7 +596,7 @@ impl Number {
         // 7
         let mut prefix = String::new(); // spec: 's'
         let mut suffix: String; // spec: 'm'
-        let mut exponent: i32; // spec: 'e'
+        let exponent: i32; // spec: 'e'
 
         // 8
         if this_num < 0.0 {
@@ -433,23 +610,10 @@ impl Number {
             exponent = 0;
         // 10
         } else {
-            // Due to f64 limitations, this part differs a bit from the spec,
-            // but has the same effect. It manipulates the string constructed
-            // by `format`: digits with an optional dot between two of them.
-            suffix = format!("{:.100}", this_num);
-
-            // a: getting an exponent
-            exponent = Self::flt_str_to_exp(&suffix);
-            // b: getting relevant digits only
-            if exponent < 0 {
-                suffix = suffix.split_off((1 - exponent) as usize);
-            } else if let Some(n) = suffix.find('.') {
-                suffix.remove(n);
-            }
-            // impl: having exactly `precision` digits in `suffix`
-            if Self::round_to_precision(&mut suffix, precision) {
-                exponent += 1;
-            }
+            // a, b: getting the exponent and exactly `precision` digits
+            let (digits, e) = Self::significant_digits(this_num, precision);
+            suffix = digits;
+            exponent = e;
 
             // c: switching to scientific notation
             let great_exp = exponent >= precision_i32;
@@ -568,7 +732,7 @@ impl Number {
                         } else {
                             let c: u8 = frac_buf[fraction_cursor];
                             // Reconstruct digit.
-                            let digit_0 = (c as char).to_digit(10).unwrap();
+                            let digit_0 = (c as char).to_digit(radix as u32).unwrap();
                             if digit_0 + 1 >= radix as u32 {
                                 continue;
                             }
@@ -637,17 +801,17 @@ impl Number {
 
         // 2. If radix is undefined, let radixNumber be 10.
         // 3. Else, let radixNumber be ? ToInteger(radix).
-        let radix = args
-            .get(0)
-            .map(|arg| arg.to_integer(context))
-            .transpose()?
-            .map_or(10, |radix| radix as u8);
+        let radix = match args.get(0) {
+            None | Some(Value::Undefined) => 10.0,
+            Some(radix) => radix.to_integer(context)?,
+        };
 
         // 4. If radixNumber < 2 or radixNumber > 36, throw a RangeError exception.
-        if !(2..=36).contains(&radix) {
+        if !(2.0..=36.0).contains(&radix) {
             return context
                 .throw_range_error("radix must be an integer at least 2 and no greater than 36");
         }
+        let radix = radix as u8;
 
         // 5. If radixNumber = 10, return ! ToString(x).
         if radix == 10 {
@@ -788,7 +952,13 @@ impl Number {
             //     mathInt may be an implementation-approximated value representing the integer value that is
             //     represented by Z in radix-R notation.)
             let math_int = u64::from_str_radix(var_z, var_r as u32).map_or_else(
-                |_| f64::from_str_radix(var_z, var_r as u32).expect("invalid_float_conversion"),
+                |_| {
+                    // The value doesn't fit in a `u64`, so it's converted from a big integer
+                    // to be correctly rounded.
+                    BigUint::parse_bytes(var_z.as_bytes(), var_r as u32)
+                        .and_then(|int| int.to_f64())
+                        .expect("invalid_float_conversion")
+                },
                 |i| i as f64,
             );
 
@@ -966,7 +1136,7 @@ impl Number {
     ///  - [ECMAScript reference][spec]
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-isnan-number
+    /// [spec]: https://tc39.es/ecma262/#sec-number.isnan
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isNaN
     pub(crate) fn number_is_nan(_: &Value, args: &[Value], _ctx: &mut Context) -> Result<Value> {
         Ok(Value::from(if let Some(val) = args.get(0) {
@@ -992,8 +1162,8 @@ impl Number {
     ///  - [ECMAScript reference][spec]
     ///  - [MDN documentation][mdn]
     ///
-    /// [spec]: https://tc39.es/ecma262/#sec-isnan-number
-    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isNaN
+    /// [spec]: https://tc39.es/ecma262/#sec-number.issafeinteger
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isSafeInteger
     pub(crate) fn is_safe_integer(_: &Value, args: &[Value], _ctx: &mut Context) -> Result<Value> {
         Ok(Value::from(match args.get(0) {
             Some(Value::Integer(_)) => true,
@@ -1026,6 +1196,22 @@ impl Number {
         number.is_finite() && number.abs().floor() == number.abs()
     }
 
+    /// The abstract operation Number::exponentiate takes arguments
+    /// base (a Number) and exponent (a Number). It performs the following steps when called:
+    ///
+    /// It differs from `f64::powf` when the exponent is `NaN`, or when the base is `1` or `-1`
+    /// and the exponent is infinite, where the result is `NaN`.
+    ///
+    /// <https://tc39.es/ecma262/#sec-numeric-types-number-exponentiate>
+    #[inline]
+    #[allow(clippy::float_cmp)]
+    pub(crate) fn exponentiate(base: f64, exponent: f64) -> f64 {
+        if exponent.is_nan() || (base.abs() == 1.0 && exponent.is_infinite()) {
+            return f64::NAN;
+        }
+        base.powf(exponent)
+    }
+
     /// The abstract operation Number::equal takes arguments
     /// x (a Number) and y (a Number). It performs the following steps when called:
     ///
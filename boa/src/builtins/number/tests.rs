@@ -75,6 +75,34 @@ fn to_exponential() {
     assert_eq!(noop_exp, "\"1.23e+2\"");
 }
 
+#[test]
+fn to_exponential_fraction_digits() {
+    let mut context = Context::new();
+
+    let cases = [
+        ("(0.00001234).toExponential()", "\"1.234e-5\""),
+        ("(0.00001234).toExponential(2)", "\"1.23e-5\""),
+        ("(123.456).toExponential(2)", "\"1.23e+2\""),
+        ("(123.456).toExponential(0)", "\"1e+2\""),
+        ("(0).toExponential(2)", "\"0.00e+0\""),
+        ("(-0).toExponential()", "\"0e+0\""),
+        ("(1.5).toExponential(0)", "\"2e+0\""),
+        ("(1.45).toExponential(1)", "\"1.4e+0\""),
+        ("(9.99).toExponential(1)", "\"1.0e+1\""),
+        ("(-6.02e23).toExponential(1)", "\"-6.0e+23\""),
+        ("(5e-324).toExponential(3)", "\"4.941e-324\""),
+        ("Infinity.toExponential(1000)", "\"Infinity\""),
+    ];
+    for (source, expected) in cases.iter() {
+        assert_eq!(&forward(&mut context, source), expected, "{}", source);
+    }
+
+    let expected =
+        "Uncaught \"RangeError\": \"toExponential() argument must be between 0 and 100\"";
+    assert_eq!(forward(&mut context, "(1).toExponential(101)"), expected);
+    assert_eq!(forward(&mut context, "(1).toExponential(-1)"), expected);
+}
+
 #[test]
 fn to_fixed() {
     let mut context = Context::new();
@@ -100,6 +128,77 @@ fn to_fixed() {
     assert_eq!(nan_fixed, "\"NaN\"");
 }
 
+#[test]
+fn to_fixed_rounding() {
+    let mut context = Context::new();
+
+    // Expected values computed by rounding the exact value of each number half up.
+    let cases = [
+        ("(0).toFixed(2)", "0.00"),
+        ("(-0).toFixed(2)", "0.00"),
+        ("(1.005).toFixed(2)", "1.00"),
+        ("(1.45).toFixed(1)", "1.4"),
+        ("(1.55).toFixed(1)", "1.6"),
+        ("(0.5).toFixed(0)", "1"),
+        ("(1.5).toFixed(0)", "2"),
+        ("(2.5).toFixed(0)", "3"),
+        ("(-1.5).toFixed(0)", "-2"),
+        ("(-2.5).toFixed(0)", "-3"),
+        ("(-0.0001).toFixed(2)", "-0.00"),
+        ("(1.25).toFixed(1)", "1.3"),
+        ("(1.255).toFixed(2)", "1.25"),
+        ("(4.35).toFixed(1)", "4.3"),
+        ("(8.345).toFixed(2)", "8.35"),
+        ("(9.995).toFixed(2)", "9.99"),
+        ("(10.235).toFixed(2)", "10.23"),
+        ("(0.005).toFixed(2)", "0.01"),
+        ("(0.0049).toFixed(2)", "0.00"),
+        ("(99.99).toFixed(1)", "100.0"),
+        ("(999.9999).toFixed(3)", "1000.000"),
+        ("(12345.6789).toFixed()", "12346"),
+        ("(123.456).toFixed(10)", "123.4560000000"),
+        ("(0.1).toFixed(20)", "0.10000000000000000555"),
+        ("(0.000001).toFixed(7)", "0.0000010"),
+        ("(5e-11).toFixed(10)", "0.0000000001"),
+        ("(5e-324).toFixed(2)", "0.00"),
+        ("(1.23e20).toFixed(2)", "123000000000000000000.00"),
+        ("(2 ** 60).toFixed(0)", "1152921504606846976"),
+        ("(1000000000000000128).toFixed(0)", "1000000000000000128"),
+        ("(1000000000000000128).toFixed(2)", "1000000000000000128.00"),
+        ("(1e21).toFixed(2)", "1e+21"),
+        ("(-1e21).toFixed(2)", "-1e+21"),
+        ("(1.5e300).toFixed(3)", "1.5e+300"),
+        ("(1.5).toFixed(0.9)", "2"),
+        ("(1.5).toFixed(\"1\")", "1.5"),
+        ("NaN.toFixed(2)", "NaN"),
+        ("Infinity.toFixed(2)", "Infinity"),
+        ("(-Infinity).toFixed(2)", "-Infinity"),
+    ];
+    for (source, expected) in cases.iter() {
+        assert_eq!(
+            forward(&mut context, source),
+            format!("\"{}\"", expected),
+            "{}",
+            source
+        );
+    }
+
+    let hundred_digits = format!("\"1.{}\"", "0".repeat(100));
+    assert_eq!(forward(&mut context, "(1).toFixed(100)"), hundred_digits);
+}
+
+#[test]
+fn to_fixed_range_error() {
+    let mut context = Context::new();
+
+    let expected =
+        "Uncaught \"RangeError\": \"toFixed() digits argument must be between 0 and 100\"";
+    assert_eq!(forward(&mut context, "(1).toFixed(101)"), expected);
+    assert_eq!(forward(&mut context, "(1).toFixed(-1)"), expected);
+    assert_eq!(forward(&mut context, "(1).toFixed(Infinity)"), expected);
+    assert_eq!(forward(&mut context, "NaN.toFixed(101)"), expected);
+}
+
 #[test]
 fn to_locale_string() {
     let mut context = Context::new();
@@ -170,6 +269,19 @@ fn to_precision() {
         String::from("\"0.333333333333333314829616256247390992939472198486328125000000\"")
     );
 
+    let cases = [
+        ("(123.456).toPrecision(4)", "\"123.5\""),
+        ("(1.25).toPrecision(2)", "\"1.3\""),
+        ("(0.000001).toPrecision(2)", "\"0.0000010\""),
+        ("(1e-7).toPrecision(2)", "\"1.0e-7\""),
+        ("(1e21).toPrecision(3)", "\"1.00e+21\""),
+        ("(99.99).toPrecision(3)", "\"100\""),
+        ("(5e-324).toPrecision(1)", "\"5e-324\""),
+    ];
+    for (source, expected) in cases.iter() {
+        assert_eq!(&forward(&mut context, source), expected, "{}", source);
+    }
+
     let expected = "Uncaught \"RangeError\": \"precision must be an integer at least 1 and no greater than 100\"";
 
     let range_error_1 = r#"(1).toPrecision(101);"#;
@@ -367,6 +479,48 @@ fn to_string() {
     );
 }
 
+#[test]
+fn to_string_radix() {
+    let mut context = Context::new();
+
+    assert_eq!("\"ff\"", &forward(&mut context, "(255).toString(16)"));
+    assert_eq!("\"-ff\"", &forward(&mut context, "(-255).toString(16)"));
+    assert_eq!("\"1010\"", &forward(&mut context, "(10).toString(2)"));
+    assert_eq!("\"0.1\"", &forward(&mut context, "(0.5).toString(2)"));
+    assert_eq!("\"-11.11\"", &forward(&mut context, "(-3.75).toString(2)"));
+    assert_eq!(
+        "\"255\"",
+        &forward(&mut context, "(255).toString(undefined)")
+    );
+    assert_eq!("\"z\"", &forward(&mut context, "(35).toString(36.9)"));
+
+    // The fractional digits stop once they are enough to tell the value apart from its
+    // neighbours, and the last one is rounded, which gives the same digits as V8.
+    assert_eq!(
+        "\"0.eeeeeeeeeeg\"",
+        &forward(&mut context, "(0.4).toString(36)")
+    );
+    assert_eq!(
+        "\"0.p777777777\"",
+        &forward(&mut context, "(0.7).toString(36)")
+    );
+
+    for radix in &["1", "37", "0", "NaN", "Infinity", "-16"] {
+        assert_eq!(
+            "true",
+            &forward(
+                &mut context,
+                &format!(
+                    "try {{ (255).toString({}) }} catch (e) {{ e instanceof RangeError }}",
+                    radix
+                )
+            ),
+            "radix {}",
+            radix
+        );
+    }
+}
+
 #[test]
 fn num_to_string_exponential() {
     let mut context = Context::new();
@@ -526,6 +680,26 @@ fn number_constants() {
     assert!(!forward_val(&mut context, "Number.POSITIVE_INFINITY")
         .unwrap()
         .is_null_or_undefined());
+
+    assert_eq!(
+        &forward(&mut context, "Number.EPSILON === Math.pow(2, -52)"),
+        "true"
+    );
+    assert_eq!(
+        &forward(&mut context, "Number.MAX_SAFE_INTEGER"),
+        "9007199254740991"
+    );
+    assert_eq!(
+        &forward(&mut context, "Number.MIN_SAFE_INTEGER"),
+        "-9007199254740991"
+    );
+    assert_eq!(
+        &forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(Number, 'MAX_SAFE_INTEGER').writable"
+        ),
+        "false"
+    );
 }
 
 #[test]
@@ -713,6 +887,85 @@ fn parse_float_too_many_args() {
     assert_eq!(&forward(&mut context, "parseFloat(\"100.5\", 10)"), "100.5");
 }
 
+#[test]
+fn parse_int_prefixes_and_signs() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\")"), "31");
+    assert_eq!(&forward(&mut context, "parseInt(\"-0x1F\")"), "-31");
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\", 16)"), "31");
+    // The prefix is only stripped when the radix is 16 or isn't given.
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\", 10)"), "0");
+    assert_eq!(&forward(&mut context, "parseInt(\"0x\")"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"0b11\")"), "0");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 2)"), "2");
+    assert_eq!(&forward(&mut context, "parseInt(\"  -42abc\")"), "-42");
+    assert_eq!(&forward(&mut context, "parseInt(\"\\n\\t +7\")"), "7");
+    assert_eq!(&forward(&mut context, "parseInt(\"- 7\")"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"12e3\")"), "12");
+    assert_eq!(&forward(&mut context, "1 / parseInt(\"-0\")"), "-Infinity");
+}
+
+#[test]
+fn parse_int_radix_bounds() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 0)"), "10");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 1)"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 36)"), "36");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 37)"), "NaN");
+    // The radix is converted with ToInt32.
+    assert_eq!(&forward(&mut context, "parseInt(\"11\", 4294967298)"), "3");
+    assert_eq!(&forward(&mut context, "parseInt(null, 36)"), "1112745");
+}
+
+#[test]
+fn parse_int_large_values() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        &forward(&mut context, "parseInt(\"123456789012345678901234567890\")"),
+        "1.2345678901234568e+29"
+    );
+    assert_eq!(
+        &forward(&mut context, "parseInt(\"ffffffffffffffffff\", 16)"),
+        "4.722366482869645e+21"
+    );
+    assert_eq!(
+        &forward(&mut context, "parseInt(\"1\".repeat(400))"),
+        "Infinity"
+    );
+}
+
+#[test]
+fn parse_float_prefixes() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseFloat(\"3.14xyz\")"), "3.14");
+    assert_eq!(&forward(&mut context, "parseFloat(\"  -.5e3x\")"), "-500");
+    assert_eq!(&forward(&mut context, "parseFloat(\"1.e2\")"), "100");
+    assert_eq!(&forward(&mut context, "parseFloat(\"1e\")"), "1");
+    assert_eq!(&forward(&mut context, "parseFloat(\"1e+\")"), "1");
+    assert_eq!(&forward(&mut context, "parseFloat(\".\")"), "NaN");
+    assert_eq!(&forward(&mut context, "parseFloat(\"+-1\")"), "NaN");
+    assert_eq!(&forward(&mut context, "parseFloat(\"0x10\")"), "0");
+    assert_eq!(&forward(&mut context, "parseFloat(\"1_000\")"), "1");
+    assert_eq!(
+        &forward(&mut context, "parseFloat(\"Infinityx\")"),
+        "Infinity"
+    );
+    assert_eq!(
+        &forward(&mut context, "parseFloat(\"-Infinity\")"),
+        "-Infinity"
+    );
+    assert_eq!(&forward(&mut context, "parseFloat(\"infinity\")"), "NaN");
+    assert_eq!(&forward(&mut context, "parseFloat(\"1e1000\")"), "Infinity");
+    assert_eq!(
+        &forward(&mut context, "1 / parseFloat(\"-0\")"),
+        "-Infinity"
+    );
+}
+
 #[test]
 fn global_is_finite() {
     let mut context = Context::new();
@@ -743,9 +996,8 @@ fn global_is_nan() {
     assert_eq!("false", &forward(&mut context, "isNaN('37.37')"));
     assert_eq!("true", &forward(&mut context, "isNaN('37,5')"));
     assert_eq!("true", &forward(&mut context, "isNaN('123ABC')"));
-    // Incorrect due to ToNumber implementation inconsistencies.
-    //assert_eq!("false", &forward(&mut context, "isNaN('')"));
-    //assert_eq!("false", &forward(&mut context, "isNaN(' ')"));
+    assert_eq!("false", &forward(&mut context, "isNaN('')"));
+    assert_eq!("false", &forward(&mut context, "isNaN(' ')"));
     assert_eq!("true", &forward(&mut context, "isNaN('blabla')"));
 }
 
@@ -847,9 +1099,8 @@ fn number_is_nan() {
     assert_eq!("false", &forward(&mut context, "Number.isNaN('37.37')"));
     assert_eq!("false", &forward(&mut context, "Number.isNaN('37,5')"));
     assert_eq!("false", &forward(&mut context, "Number.isNaN('123ABC')"));
-    // Incorrect due to ToNumber implementation inconsistencies.
-    //assert_eq!("false", &forward(&mut context, "Number.isNaN('')"));
-    //assert_eq!("false", &forward(&mut context, "Number.isNaN(' ')"));
+    assert_eq!("false", &forward(&mut context, "Number.isNaN('')"));
+    assert_eq!("false", &forward(&mut context, "Number.isNaN(' ')"));
     assert_eq!("false", &forward(&mut context, "Number.isNaN('blabla')"));
     assert_eq!("false", &forward(&mut context, "Number.isNaN(Number(5))"));
     assert_eq!("true", &forward(&mut context, "Number.isNaN(Number(NaN))"));
@@ -864,6 +1115,25 @@ fn number_is_nan() {
     );
 }
 
+#[test]
+fn number_checks_do_not_coerce() {
+    let mut context = Context::new();
+
+    assert_eq!("false", &forward(&mut context, "Number.isNaN('NaN')"));
+    assert_eq!("true", &forward(&mut context, "isNaN('NaN')"));
+    assert_eq!("false", &forward(&mut context, "Number.isNaN(undefined)"));
+    assert_eq!("true", &forward(&mut context, "isNaN(undefined)"));
+    assert_eq!("false", &forward(&mut context, "Number.isFinite('42')"));
+    assert_eq!("true", &forward(&mut context, "isFinite('42')"));
+    assert_eq!("false", &forward(&mut context, "Number.isFinite(null)"));
+    assert_eq!("true", &forward(&mut context, "isFinite(null)"));
+    assert_eq!("false", &forward(&mut context, "Number.isInteger('42')"));
+    assert_eq!(
+        "false",
+        &forward(&mut context, "Number.isSafeInteger('42')")
+    );
+}
+
 #[test]
 fn number_is_safe_integer() {
     let mut context = Context::new();
@@ -100,13 +100,11 @@ impl ForInIterator {
                             }
                         }
                     }
-                    match object.prototype_instance().to_object(context) {
-                        Ok(o) => {
-                            object = o;
-                        }
-                        _ => {
-                            return Ok(create_iter_result_object(context, Value::undefined(), true))
-                        }
+                    // The keys of the whole prototype chain were visited once it ends in `null`.
+                    if let Some(o) = object.prototype_instance().as_object() {
+                        object = o;
+                    } else {
+                        return Ok(create_iter_result_object(context, Value::undefined(), true));
                     }
                     iterator.object = Value::from(object.clone());
                     iterator.object_was_visited = false;
@@ -14,9 +14,13 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{
+        iterable::{get_iterator, group_by, GroupKeyCoercion},
+        Array, BuiltIn,
+    },
     object::{
-        ConstructorBuilder, Object as BuiltinObject, ObjectData, ObjectInitializer, PROTOTYPE,
+        ConstructorBuilder, GcObject, IntegrityLevel, Object as BuiltinObject, ObjectData,
+        ObjectInitializer, PropertyNameKind, PROTOTYPE,
     },
     property::Attribute,
     property::DataDescriptor,
@@ -63,6 +67,17 @@ impl BuiltIn for Object {
         .static_method(Self::define_properties, "defineProperties", 2)
         .static_method(Self::assign, "assign", 2)
         .static_method(Self::is, "is", 2)
+        .static_method(Self::from_entries, "fromEntries", 1)
+        .static_method(Self::group_by, "groupBy", 2)
+        .static_method(Self::keys, "keys", 1)
+        .static_method(Self::values, "values", 1)
+        .static_method(Self::entries, "entries", 1)
+        .static_method(Self::prevent_extensions, "preventExtensions", 1)
+        .static_method(Self::is_extensible, "isExtensible", 1)
+        .static_method(Self::seal, "seal", 1)
+        .static_method(Self::is_sealed, "isSealed", 1)
+        .static_method(Self::freeze, "freeze", 1)
+        .static_method(Self::is_frozen, "isFrozen", 1)
         .static_method(
             Self::get_own_property_descriptor,
             "getOwnPropertyDescriptor",
@@ -188,21 +203,23 @@ impl Object {
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
+        // 1. Let obj be ? ToObject(O).
         let object = args
             .get(0)
             .unwrap_or(&Value::undefined())
             .to_object(context)?;
-        let descriptors = context.construct_object();
 
-        for key in object.borrow().keys() {
-            let descriptor = {
-                let desc = object
-                    .get_own_property(&key)
-                    .expect("Expected property to be on object.");
-                Self::from_property_descriptor(desc, context)
-            };
+        // 2. Let ownKeys be ? obj.[[OwnPropertyKeys]]().
+        // 3. Let descriptors be ! OrdinaryObjectCreate(%Object.prototype%).
+        let descriptors = context.construct_object();
 
-            if !descriptor.is_undefined() {
+        // 4. For each element key of ownKeys, do
+        for key in object.own_property_keys() {
+            // a. Let desc be ? obj.[[GetOwnProperty]](key).
+            // b. Let descriptor be ! FromPropertyDescriptor(desc).
+            // c. If descriptor is not undefined, perform ! CreateDataPropertyOrThrow(descriptors, key, descriptor).
+            if let Some(desc) = object.get_own_property(&key) {
+                let descriptor = Self::from_property_descriptor(desc, context);
                 descriptors.borrow_mut().insert(
                     key,
                     PropertyDescriptor::from(DataDescriptor::new(descriptor, Attribute::all())),
@@ -210,6 +227,7 @@ impl Object {
             }
         }
 
+        // 5. Return descriptors.
         Ok(Value::Object(descriptors))
     }
 
@@ -221,27 +239,28 @@ impl Object {
     fn from_property_descriptor(desc: PropertyDescriptor, context: &mut Context) -> Value {
         let mut descriptor = ObjectInitializer::new(context);
 
-        if let PropertyDescriptor::Data(data_desc) = &desc {
-            descriptor.property("value", data_desc.value(), Attribute::all());
-        }
-
-        if let PropertyDescriptor::Accessor(accessor_desc) = &desc {
-            if let Some(setter) = accessor_desc.setter() {
-                descriptor.property("set", Value::Object(setter.to_owned()), Attribute::all());
+        // A data descriptor has a value and can be writable, an accessor descriptor has a
+        // getter and a setter, which are undefined when they are missing.
+        match desc {
+            PropertyDescriptor::Data(ref data_desc) => {
+                descriptor
+                    .property("value", data_desc.value(), Attribute::all())
+                    .property(
+                        "writable",
+                        Value::from(data_desc.writable()),
+                        Attribute::all(),
+                    );
             }
-            if let Some(getter) = accessor_desc.getter() {
-                descriptor.property("get", Value::Object(getter.to_owned()), Attribute::all());
+            PropertyDescriptor::Accessor(ref accessor_desc) => {
+                let getter = accessor_desc.getter().cloned().map(Value::from);
+                let setter = accessor_desc.setter().cloned().map(Value::from);
+                descriptor
+                    .property("get", getter.unwrap_or_default(), Attribute::all())
+                    .property("set", setter.unwrap_or_default(), Attribute::all());
             }
         }
 
-        let writable = if let PropertyDescriptor::Data(data_desc) = &desc {
-            data_desc.writable()
-        } else {
-            false
-        };
-
         descriptor
-            .property("writable", Value::from(writable), Attribute::all())
             .property(
                 "enumerable",
                 Value::from(desc.enumerable()),
@@ -256,7 +275,18 @@ impl Object {
         descriptor.build().into()
     }
 
-    /// Uses the SameValue algorithm to check equality of objects
+    /// `Object.is( value1, value2 )`
+    ///
+    /// Determines whether two values are the same value, using the `SameValue` algorithm.
+    ///
+    /// Unlike `===`, it treats `NaN` as the same value as itself and `+0` as different from `-0`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.is
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/is
     pub fn is(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
         let x = args.get(0).cloned().unwrap_or_else(Value::undefined);
         let y = args.get(1).cloned().unwrap_or_else(Value::undefined);
@@ -264,6 +294,141 @@ impl Object {
         Ok(Value::same_value(&x, &y).into())
     }
 
+    /// `Object.preventExtensions( target )`
+    ///
+    /// Prevents new properties from ever being added to an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.preventextensions
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/preventExtensions
+    pub fn prevent_extensions(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let object = args.get(0).cloned().unwrap_or_default();
+
+        // 1. If Type(O) is not Object, return O.
+        // 2. Let status be ? O.[[PreventExtensions]]().
+        // 3. If status is false, throw a TypeError exception.
+        if let Some(mut obj) = object.as_object() {
+            if !obj.prevent_extensions() {
+                return context.throw_type_error("cannot prevent extensions of object");
+            }
+        }
+
+        // 4. Return O.
+        Ok(object)
+    }
+
+    /// `Object.isExtensible( target )`
+    ///
+    /// Determines if new properties can be added to an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.isextensible
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isExtensible
+    pub fn is_extensible(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+        // 1. If Type(O) is not Object, return false.
+        // 2. Return ? IsExtensible(O).
+        Ok(args
+            .get(0)
+            .and_then(Value::as_object)
+            .map_or(false, |obj| obj.is_extensible())
+            .into())
+    }
+
+    /// `Object.seal( target )`
+    ///
+    /// Prevents new properties from being added to an object and makes its existing properties
+    /// non-configurable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.seal
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/seal
+    pub fn seal(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_integrity_level(args, IntegrityLevel::Sealed, context)
+    }
+
+    /// `Object.isSealed( target )`
+    ///
+    /// Determines if an object is sealed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.issealed
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isSealed
+    pub fn is_sealed(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(Self::test_integrity_level(args, IntegrityLevel::Sealed))
+    }
+
+    /// `Object.freeze( target )`
+    ///
+    /// Freezes an object, so that no properties can be added to it and its existing properties
+    /// can't be removed or changed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.freeze
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/freeze
+    pub fn freeze(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::set_integrity_level(args, IntegrityLevel::Frozen, context)
+    }
+
+    /// `Object.isFrozen( target )`
+    ///
+    /// Determines if an object is frozen.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.isfrozen
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/isFrozen
+    pub fn is_frozen(_: &Value, args: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(Self::test_integrity_level(args, IntegrityLevel::Frozen))
+    }
+
+    /// The shared steps of `Object.seal` and `Object.freeze`.
+    fn set_integrity_level(
+        args: &[Value],
+        level: IntegrityLevel,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let object = args.get(0).cloned().unwrap_or_default();
+
+        // 1. If Type(O) is not Object, return O.
+        // 2. Let status be ? SetIntegrityLevel(O, level).
+        // 3. If status is false, throw a TypeError exception.
+        if let Some(mut obj) = object.as_object() {
+            if !obj.set_integrity_level(level, context)? {
+                return context.throw_type_error("cannot change the integrity level of object");
+            }
+        }
+
+        // 4. Return O.
+        Ok(object)
+    }
+
+    /// The shared steps of `Object.isSealed` and `Object.isFrozen`.
+    fn test_integrity_level(args: &[Value], level: IntegrityLevel) -> Value {
+        // 1. If Type(O) is not Object, return true.
+        // 2. Return ? TestIntegrityLevel(O, level).
+        args.get(0)
+            .and_then(Value::as_object)
+            .map_or(true, |obj| obj.test_integrity_level(level))
+            .into()
+    }
+
     /// Get the `prototype` of an object.
     pub fn get_prototype_of(_: &Value, args: &[Value], ctx: &mut Context) -> Result<Value> {
         if args.is_empty() {
@@ -365,10 +530,11 @@ impl Object {
                 .get(1)
                 .unwrap_or(&Value::undefined())
                 .to_property_key(context)?;
-            let desc = args
-                .get(2)
-                .unwrap_or(&Value::undefined())
-                .to_property_descriptor(context)?;
+            let desc = match args.get(2) {
+                Some(Value::Object(desc)) => desc
+                    .to_property_descriptor_for(object.get_own_property(&key).as_ref(), context)?,
+                _ => return context.throw_type_error("Property description must be an object"),
+            };
 
             object.define_property_or_throw(key, desc, context)?;
 
@@ -421,9 +587,9 @@ impl Object {
                 let o = o.borrow();
                 match &o.data {
                     ObjectData::Array => "Array",
-                    // TODO: Arguments Exotic Objects are currently not supported
+                    ObjectData::Arguments(_) => "Arguments",
                     ObjectData::Function(_) => "Function",
-                    ObjectData::Error => "Error",
+                    ObjectData::Error(_) => "Error",
                     ObjectData::Boolean(_) => "Boolean",
                     ObjectData::Number(_) => "Number",
                     ObjectData::String(_) => "String",
@@ -482,12 +648,10 @@ impl Object {
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        let key = match args.get(0) {
-            None => return Ok(Value::from(false)),
-            Some(key) => key,
-        };
-
-        let key = key.to_property_key(context)?;
+        let key = args
+            .get(0)
+            .unwrap_or(&Value::undefined())
+            .to_property_key(context)?;
         let own_property = this.to_object(context)?.get_own_property(&key);
 
         Ok(own_property.map_or(Value::from(false), |own_prop| {
@@ -507,31 +671,215 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-object.assign
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/assign
     pub fn assign(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let to be ? ToObject(target).
         let mut to = args
             .get(0)
             .cloned()
             .unwrap_or_default()
             .to_object(context)?;
 
-        if args.len() == 1 {
-            return Ok(to.into());
-        }
+        // 2. If only one argument was passed, return to.
+        // 3. For each element nextSource of sources, do
+        for source in args.iter().skip(1) {
+            // a. If nextSource is neither undefined nor null, then
+            if source.is_null_or_undefined() {
+                continue;
+            }
 
-        for source in &args[1..] {
-            if !source.is_null_or_undefined() {
-                let from = source.to_object(context).unwrap();
-                let keys = from.own_property_keys();
-                for key in keys {
-                    if let Some(desc) = from.get_own_property(&key) {
-                        if desc.enumerable() {
-                            let property = from.get(&key, from.clone().into(), context)?;
-                            to.set(key, property, to.clone().into(), context)?;
+            // i. Let from be ! ToObject(nextSource).
+            let from = source.to_object(context)?;
+
+            // ii. Let keys be ? from.[[OwnPropertyKeys]]().
+            let keys = from.own_property_keys();
+
+            // iii. For each element nextKey of keys, do
+            for key in keys {
+                // 1. Let desc be ? from.[[GetOwnProperty]](nextKey).
+                // 2. If desc is not undefined and desc.[[Enumerable]] is true, then
+                if let Some(desc) = from.get_own_property(&key) {
+                    if desc.enumerable() {
+                        // a. Let propValue be ? Get(from, nextKey).
+                        let property = from.get(&key, from.clone().into(), context)?;
+
+                        // b. Perform ? Set(to, nextKey, propValue, true).
+                        if !to.set(key.clone(), property, to.clone().into(), context)? {
+                            return context.throw_type_error(format!(
+                                "Cannot assign to property {} of object",
+                                key
+                            ));
                         }
                     }
                 }
             }
         }
 
+        // 4. Return to.
         Ok(to.into())
     }
+
+    /// `Object.keys( target )`
+    ///
+    /// This method returns an array of the enumerable string keyed property names of an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.keys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/keys
+    pub fn keys(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::enumerable_own_property_names(args, PropertyNameKind::Key, context)
+    }
+
+    /// `Object.values( target )`
+    ///
+    /// This method returns an array of the enumerable string keyed property values of an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/values
+    pub fn values(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::enumerable_own_property_names(args, PropertyNameKind::Value, context)
+    }
+
+    /// `Object.entries( target )`
+    ///
+    /// This method returns an array of the `[key, value]` pairs of the enumerable string keyed
+    /// properties of an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/entries
+    pub fn entries(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::enumerable_own_property_names(args, PropertyNameKind::KeyAndValue, context)
+    }
+
+    /// Shared implementation of `Object.keys`, `Object.values` and `Object.entries`.
+    fn enumerable_own_property_names(
+        args: &[Value],
+        kind: PropertyNameKind,
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1. Let obj be ? ToObject(O).
+        let object = args
+            .get(0)
+            .unwrap_or(&Value::undefined())
+            .to_object(context)?;
+        // 2. Let nameList be ? EnumerableOwnPropertyNames(obj, kind).
+        let name_list = object.enumerable_own_property_names(kind, context)?;
+        // 3. Return CreateArrayFromList(nameList).
+        Array::construct_array(&Array::new_array(context), &name_list, context)
+    }
+
+    /// `Object.fromEntries( iterable )`
+    ///
+    /// This method transforms a list of key-value pairs into an object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.fromentries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/fromEntries
+    pub fn from_entries(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Perform ? RequireObjectCoercible(iterable).
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        iterable.require_object_coercible(context)?;
+
+        // 2. Let obj be ! OrdinaryObjectCreate(%Object.prototype%).
+        let object = Value::new_object(context);
+
+        // 3. Assert: obj is an extensible ordinary object with no own properties.
+        // 4. Let closure be a new Abstract Closure with parameters (key, value) that captures obj
+        //    and performs the following steps when called:
+        // 5. Let adder be ! CreateBuiltinFunction(closure, 2, "", « »).
+        // 6. Return ? AddEntriesFromIterable(obj, iterable, adder).
+        let iterator_record = get_iterator(context, iterable)?;
+        let mut next = iterator_record.next(context)?;
+        while !next.is_done() {
+            let entry = next.value();
+
+            // If Type(nextItem) is not Object, then throw a TypeError and close the iterator.
+            if !entry.is_object() {
+                let error = context.construct_type_error("iterator value is not an entry object");
+                return iterator_record.close(Err(error), context);
+            }
+
+            let status = Self::add_entry(&object, &entry, context);
+            if status.is_err() {
+                return iterator_record.close(status, context);
+            }
+
+            next = iterator_record.next(context)?;
+        }
+
+        Ok(object)
+    }
+
+    /// `Object.groupBy( items, callbackFn )`
+    ///
+    /// Groups the values of an iterable into an object with a `null` prototype, keyed by the
+    /// property keys returned by the callback.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-object.groupby
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/groupBy
+    pub fn group_by(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let items = args.get(0).cloned().unwrap_or_default();
+        let callback = args.get(1).cloned().unwrap_or_default();
+
+        // 1. Let groups be ? GroupBy(items, callbackfn, property).
+        let groups = group_by(&items, &callback, GroupKeyCoercion::Property, context)?;
+
+        // 2. Let obj be OrdinaryObjectCreate(null).
+        let mut object = GcObject::new(BuiltinObject::with_prototype(
+            Value::null(),
+            ObjectData::Ordinary,
+        ));
+
+        // 3. For each Record { [[Key]], [[Elements]] } g of groups, do
+        for (key, elements) in groups {
+            // a. Let elements be CreateArrayFromList(g.[[Elements]]).
+            let new = Array::new_array(context);
+            let elements = Array::construct_array(&new, &elements, context)?;
+
+            // b. Perform ! CreateDataPropertyOrThrow(obj, g.[[Key]], elements).
+            let key = key.to_property_key(context)?;
+            let property = DataDescriptor::new(elements, Attribute::all());
+            object.define_own_property(key, property.into(), context)?;
+        }
+
+        // 4. Return obj.
+        Ok(object.into())
+    }
+
+    /// Adds the key-value pair of an entry of `Object.fromEntries` to the object.
+    fn add_entry(object: &Value, entry: &Value, context: &mut Context) -> Result<Value> {
+        // Let k be ? Get(nextItem, "0").
+        let key = entry.get_field(0, context)?;
+        // Let v be ? Get(nextItem, "1").
+        let value = entry.get_field(1, context)?;
+
+        // a. Let propertyKey be ? ToPropertyKey(key).
+        let key = key.to_property_key(context)?;
+
+        // b. Perform ! CreateDataPropertyOrThrow(obj, propertyKey, value).
+        let mut object = object.as_object().expect("fromEntries target is an object");
+        let property = DataDescriptor::new(value, Attribute::all());
+        if !object.define_own_property(key, property.into(), context)? {
+            return context.throw_type_error("cannot define property of object");
+        }
+
+        // c. Return undefined.
+        Ok(Value::undefined())
+    }
 }
@@ -53,6 +53,81 @@ fn object_create_with_number() {
     );
 }
 
+#[test]
+fn object_create_with_null() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const bare = Object.create(null);
+        bare.x = 1;
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(bare) === null"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "bare.hasOwnProperty"), "undefined");
+    assert_eq!(forward(&mut context, "'toString' in bare"), "false");
+    assert_eq!(forward(&mut context, "Object.keys(bare).join()"), "\"x\"");
+}
+
+#[test]
+fn object_create_with_properties() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const proto = { inherited: true };
+        const obj = Object.create(proto, {
+            x: { value: 1, enumerable: true },
+            y: { get() { return this.x + 1; } },
+            [Symbol.iterator]: { value: 2 },
+        });
+        const descriptor = Object.getOwnPropertyDescriptor(obj, "x");
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(obj) === proto"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "obj.inherited"), "true");
+    assert_eq!(forward(&mut context, "obj.x"), "1");
+    assert_eq!(forward(&mut context, "obj.y"), "2");
+    assert_eq!(forward(&mut context, "obj[Symbol.iterator]"), "2");
+    assert_eq!(forward(&mut context, "Object.keys(obj).join()"), "\"x\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "[descriptor.writable, descriptor.enumerable, descriptor.configurable].join()"
+        ),
+        "\"false,true,false\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.create({}, { x: 1 }) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.create({}, null) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.create({}, { x: { value: 1, get() {} } }) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
 #[test]
 #[ignore]
 // TODO: to test on __proto__ somehow. __proto__ getter is not working as expected currently
@@ -93,6 +168,62 @@ fn object_is() {
     assert_eq!(forward(&mut context, "Object.is(undefined)"), "true");
     assert!(context.global_object().is_global());
 }
+
+#[test]
+fn object_is_value_types() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var sym = Symbol();
+        var fn = function() {};
+        "#;
+
+    forward(&mut context, init);
+
+    // Numbers are compared by value, regardless of how they were computed.
+    assert_eq!(forward(&mut context, "Object.is(1, 1.0)"), "true");
+    assert_eq!(forward(&mut context, "Object.is(1, 0.5 * 2)"), "true");
+    assert_eq!(forward(&mut context, "Object.is(0, 0 * -1)"), "false");
+    assert_eq!(forward(&mut context, "Object.is(-0, 0 * -1)"), "true");
+    assert_eq!(forward(&mut context, "Object.is(NaN, NaN)"), "true");
+    assert_eq!(forward(&mut context, "Object.is(NaN, 'NaN')"), "false");
+    assert_eq!(forward(&mut context, "Object.is(Infinity, 1 / 0)"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.is(Infinity, -Infinity)"),
+        "false"
+    );
+
+    assert_eq!(forward(&mut context, "Object.is(1n, 1n)"), "true");
+    assert_eq!(forward(&mut context, "Object.is(1n, 2n)"), "false");
+    assert_eq!(forward(&mut context, "Object.is(1n, 1)"), "false");
+
+    assert_eq!(forward(&mut context, "Object.is(true, true)"), "true");
+    assert_eq!(forward(&mut context, "Object.is(true, 1)"), "false");
+    assert_eq!(forward(&mut context, "Object.is('1', 1)"), "false");
+    assert_eq!(forward(&mut context, "Object.is(undefined, null)"), "false");
+
+    assert_eq!(forward(&mut context, "Object.is(sym, sym)"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.is(Symbol(), Symbol())"),
+        "false"
+    );
+
+    // Objects are compared by reference.
+    assert_eq!(forward(&mut context, "Object.is(fn, fn)"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.is(fn, function() {})"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.is(new String('a'), new String('a'))"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.is(new String('a'), 'a')"),
+        "false"
+    );
+}
+
 #[test]
 fn object_has_own_property() {
     let mut context = Context::new();
@@ -144,6 +275,35 @@ fn object_property_is_enumerable() {
     )
 }
 
+#[test]
+fn symbol_keyed_own_properties() {
+    let mut context = Context::new();
+    let init = r#"
+        let sym = Symbol("key");
+        let hidden = Symbol("hidden");
+        let x = { [sym]: 1, undefined: 2 };
+        Object.defineProperty(x, hidden, { value: 3, enumerable: false });
+    "#;
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(forward(&mut context, "x.hasOwnProperty(sym)"), "true");
+    assert_eq!(forward(&mut context, "x.hasOwnProperty(hidden)"), "true");
+    assert_eq!(
+        forward(&mut context, "x.hasOwnProperty(Symbol('key'))"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "x.propertyIsEnumerable(sym)"), "true");
+    assert_eq!(
+        forward(&mut context, "x.propertyIsEnumerable(hidden)"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "x.propertyIsEnumerable()"), "true");
+    assert_eq!(forward(&mut context, "delete x[sym]"), "true");
+    assert_eq!(forward(&mut context, "x.hasOwnProperty(sym)"), "false");
+    assert_eq!(forward(&mut context, "x[sym]"), "undefined");
+    assert_eq!(forward(&mut context, "delete x[hidden]"), "false");
+    assert_eq!(forward(&mut context, "x[hidden]"), "3");
+}
+
 #[test]
 fn object_to_string() {
     let mut context = Context::new();
@@ -260,6 +420,74 @@ fn get_own_property_descriptors() {
     assert_eq!(forward(&mut context, "result.b.value"), "2");
 }
 
+#[test]
+fn get_own_property_descriptors_clone_with_accessors() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        let proto = { inherited: 1 };
+        let symbol = Symbol("symbol");
+        let src = Object.create(proto);
+        src.x = 21;
+        src[symbol] = "symbol";
+        Object.defineProperty(src, "double", {
+            get() { return this.x * 2; },
+            enumerable: true,
+            configurable: true,
+        });
+        Object.defineProperty(src, "hidden", { value: 5 });
+        let clone = Object.create(proto, Object.getOwnPropertyDescriptors(src));
+        clone.x = 50;
+    "#,
+    );
+
+    // The getter is copied, not the value it returns.
+    assert_eq!(forward(&mut context, "clone.double"), "100");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(clone, 'double').get === Object.getOwnPropertyDescriptor(src, 'double').get"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "clone.hidden"), "5");
+    assert_eq!(
+        forward(&mut context, "clone.propertyIsEnumerable('hidden')"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(clone, 'hidden').writable"
+        ),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "clone[symbol]"), "\"symbol\"");
+    assert_eq!(forward(&mut context, "clone.inherited"), "1");
+    assert_eq!(forward(&mut context, "src.double"), "42");
+}
+
+#[test]
+fn get_own_property_descriptors_exotic_objects() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        "let result = Object.getOwnPropertyDescriptors('ab');",
+    );
+
+    assert_eq!(forward(&mut context, "result[0].value"), "\"a\"");
+    assert_eq!(forward(&mut context, "result[1].writable"), "false");
+    assert_eq!(forward(&mut context, "result.length.value"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.getOwnPropertyDescriptors(null) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
 #[test]
 fn object_define_properties() {
     let mut context = Context::new();
@@ -279,6 +507,155 @@ fn object_define_properties() {
     assert_eq!(forward(&mut context, "obj.p"), "42");
 }
 
+#[test]
+fn define_accessor_property() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const temperature = { celsius: 20 };
+        Object.defineProperty(temperature, "fahrenheit", {
+            get() {
+                return this.celsius * 9 / 5 + 32;
+            },
+            set(value) {
+                this.celsius = (value - 32) * 5 / 9;
+            },
+            enumerable: true,
+        });
+        const child = Object.create(temperature);
+        child.celsius = 100;
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "temperature.fahrenheit"), "68");
+    assert_eq!(forward(&mut context, "child.fahrenheit"), "212");
+    forward(&mut context, "temperature.fahrenheit = 50");
+    assert_eq!(forward(&mut context, "temperature.celsius"), "10");
+    assert_eq!(forward(&mut context, "temperature.fahrenheit"), "50");
+
+    let init = r#"
+        const counter = {};
+        Object.defineProperties(counter, {
+            count: { value: 0, writable: true },
+            next: { get() { return ++this.count; } },
+        });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "counter.next + counter.next"), "3");
+}
+
+#[test]
+fn get_own_property_descriptor_of_accessor() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function getter() { return 1; }
+        const obj = {};
+        Object.defineProperty(obj, "a", { get: getter, configurable: true });
+        const result = Object.getOwnPropertyDescriptor(obj, "a");
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "result.get === getter"), "true");
+    assert_eq!(forward(&mut context, "'set' in result"), "true");
+    assert_eq!(forward(&mut context, "result.set"), "undefined");
+    assert_eq!(forward(&mut context, "'value' in result"), "false");
+    assert_eq!(forward(&mut context, "'writable' in result"), "false");
+    assert_eq!(forward(&mut context, "result.enumerable"), "false");
+    assert_eq!(forward(&mut context, "result.configurable"), "true");
+}
+
+#[test]
+fn redefine_accessor_property() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const obj = { a: 1 };
+        function getter() { return "get"; }
+        function setter(value) { this.b = value; }
+        Object.defineProperty(obj, "a", { get: getter });
+        Object.defineProperty(obj, "a", { set: setter });
+        obj.a = 2;
+    "#;
+    forward(&mut context, init);
+
+    // The fields that are missing from the new descriptor keep their current value.
+    assert_eq!(forward(&mut context, "obj.a"), "\"get\"");
+    assert_eq!(forward(&mut context, "obj.b"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(obj, 'a').enumerable"
+        ),
+        "true"
+    );
+
+    forward(
+        &mut context,
+        "Object.defineProperty(obj, 'a', { value: 3, configurable: false })",
+    );
+    assert_eq!(forward(&mut context, "obj.a"), "3");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(obj, 'a').writable"
+        ),
+        "false"
+    );
+
+    let init = r#"
+        const fixed = {};
+        Object.defineProperty(fixed, "a", { get: getter });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.defineProperty(fixed, 'a', { get: getter, enumerable: false })"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.defineProperty(fixed, 'a', { get: undefined })"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Reflect.defineProperty(fixed, 'a', { value: 1 })"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn define_property_invalid_descriptor() {
+    let mut context = Context::new();
+
+    let check = |context: &mut Context, descriptor: &str| {
+        forward(
+            context,
+            &format!(
+                "try {{ Object.defineProperty({{}}, 'a', {}); false }} catch (e) {{ e instanceof TypeError }}",
+                descriptor
+            ),
+        )
+    };
+
+    assert_eq!(check(&mut context, "{ value: 1, get() {} }"), "true");
+    assert_eq!(check(&mut context, "{ writable: true, set(v) {} }"), "true");
+    assert_eq!(check(&mut context, "{ get: 1 }"), "true");
+    assert_eq!(check(&mut context, "{ set: {} }"), "true");
+    assert_eq!(check(&mut context, "1"), "true");
+    assert_eq!(
+        check(&mut context, "{ get: undefined, set: undefined }"),
+        "false"
+    );
+}
+
 #[test]
 fn object_is_prototype_of() {
     let mut context = Context::new();
@@ -289,3 +666,365 @@ fn object_is_prototype_of() {
 
     assert_eq!(context.eval(init).unwrap(), Value::boolean(true));
 }
+
+#[test]
+fn object_keys_values_entries_order() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const obj = {};
+        obj.b = "b";
+        obj["2"] = "two";
+        obj.a = "a";
+        obj[1] = "one";
+        obj[Symbol("sym")] = "symbol";
+        Object.defineProperty(obj, "hidden", { value: "hidden", enumerable: false });
+        let forIn = [];
+        for (let key in obj) {
+            forIn.push(key);
+        }
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.keys(obj).join()"),
+        "\"1,2,b,a\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.values(obj).join()"),
+        "\"one,two,b,a\""
+    );
+    assert_eq!(
+        forward(&mut context, "Object.entries(obj).join(';')"),
+        "\"1,one;2,two;b,b;a,a\""
+    );
+    assert_eq!(forward(&mut context, "forIn.join()"), "\"1,2,b,a\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"JSON.stringify(obj) === '{"1":"one","2":"two","b":"b","a":"a"}'"#
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Object.keys('ab').join()"), "\"0,1\"");
+    assert_eq!(
+        forward(&mut context, "Object.keys(null)"),
+        "Uncaught \"TypeError\": \"cannot convert 'null' or 'undefined' to object\""
+    );
+}
+
+#[test]
+fn object_from_entries() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const fromArray = Object.fromEntries([["a", 1], ["b", 2]]);
+        const fromMap = Object.fromEntries(new Map([["x", "y"], [1, true]]));
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "fromArray.a"), "1");
+    assert_eq!(forward(&mut context, "fromArray.b"), "2");
+    assert_eq!(
+        forward(&mut context, "fromArray.propertyIsEnumerable('a')"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "fromMap.x"), "\"y\"");
+    assert_eq!(forward(&mut context, "fromMap['1']"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(fromMap) === Object.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Object.fromEntries.length"), "1");
+}
+
+#[test]
+fn object_from_entries_invalid_entry() {
+    let mut context = Context::new();
+
+    let init = r#"
+        let closed = false;
+        const iterator = {
+            next: function() { return { value: 1, done: false }; },
+            return: function() { closed = true; return {}; }
+        };
+        const iterable = {};
+        iterable[Symbol.iterator] = function() { return iterator; };
+        let error;
+        try {
+            Object.fromEntries(iterable);
+        } catch (e) {
+            error = e;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "error instanceof TypeError"), "true");
+    assert_eq!(forward(&mut context, "closed"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.fromEntries([1]) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.fromEntries() } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn object_assign_copies_symbol_keys() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const sym = Symbol("s");
+        const hidden = Symbol("hidden");
+        const source = { a: 1, [sym]: 2 };
+        Object.defineProperty(source, hidden, { value: 3, enumerable: false });
+        const target = Object.assign({ b: 0 }, source, null, undefined);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "target.a"), "1");
+    assert_eq!(forward(&mut context, "target.b"), "0");
+    assert_eq!(forward(&mut context, "target[sym]"), "2");
+    assert_eq!(forward(&mut context, "hidden in target"), "false");
+}
+
+#[test]
+fn object_assign_invokes_getters_and_setters() {
+    let mut context = Context::new();
+
+    let init = r#"
+        let getterCalls = 0;
+        let setValue;
+        const source = {};
+        Object.defineProperty(source, "x", {
+            get() { getterCalls++; return 42; },
+            enumerable: true,
+        });
+        const target = {};
+        Object.defineProperty(target, "x", {
+            set(value) { setValue = value; },
+        });
+        Object.assign(target, source);
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "getterCalls"), "1");
+    assert_eq!(forward(&mut context, "setValue"), "42");
+    assert_eq!(
+        forward(
+            &mut context,
+            "typeof Object.getOwnPropertyDescriptor(target, 'x').set"
+        ),
+        "\"function\""
+    );
+}
+
+#[test]
+fn object_assign_throws_when_set_fails() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const target = {};
+        Object.defineProperty(target, "a", { value: 1, writable: false });
+        const source = {
+            get a() { return 2; },
+            get b() { throw new Error("should not be reached"); },
+        };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.assign(target, source) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "target.a"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.assign({ set c(v) { throw 'setter'; } }, { c: 1 }) } catch (e) { e }"
+        ),
+        "\"setter\""
+    );
+}
+
+#[test]
+fn object_freeze_and_seal() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var frozen = Object.freeze({ a: 1, get b() { return 2; } });
+        var sealed = Object.seal({ a: 1 });
+        var nonExtensible = Object.preventExtensions({ a: 1 });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "Object.isFrozen(frozen)"), "true");
+    assert_eq!(forward(&mut context, "Object.isSealed(frozen)"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.isExtensible(frozen)"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "Object.isFrozen(sealed)"), "false");
+    assert_eq!(forward(&mut context, "Object.isSealed(sealed)"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.isSealed(nonExtensible)"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.isExtensible(nonExtensible)"),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.isFrozen(Object.preventExtensions({}))"
+        ),
+        "true"
+    );
+
+    assert_eq!(forward(&mut context, "sealed.a = 2; sealed.a"), "2");
+    assert_eq!(forward(&mut context, "delete sealed.a"), "false");
+    assert_eq!(
+        forward(&mut context, "nonExtensible.b = 2; nonExtensible.b"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut context, "delete nonExtensible.a"), "true");
+
+    assert_eq!(forward(&mut context, "Object.freeze(1)"), "1");
+    assert_eq!(forward(&mut context, "Object.isFrozen('a')"), "true");
+    assert_eq!(forward(&mut context, "Object.isExtensible(1)"), "false");
+}
+
+#[test]
+fn frozen_object_writes() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var frozen = Object.freeze({ a: 1 });
+        var array = Object.freeze([1, 2]);
+        function strictWrite() {
+            "use strict";
+            try {
+                frozen.a = 2;
+            } catch (e) {
+                return e instanceof TypeError;
+            }
+            return false;
+        }
+        "#;
+    forward(&mut context, init);
+
+    // Writes to frozen objects are ignored in sloppy mode code.
+    assert_eq!(forward(&mut context, "frozen.a = 2; frozen.a"), "1");
+    assert_eq!(forward(&mut context, "frozen.b = 2; frozen.b"), "undefined");
+    assert_eq!(forward(&mut context, "delete frozen.a"), "false");
+    assert_eq!(forward(&mut context, "array[0] = 3; array[0]"), "1");
+    assert_eq!(forward(&mut context, "array.length = 0; array.length"), "2");
+
+    // And they throw in strict mode code.
+    assert_eq!(forward(&mut context, "strictWrite()"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"'use strict'; var o = Object.freeze({ a: 1 }); try { o.a = 2; } catch (e) { e instanceof TypeError }"#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"'use strict'; try { Object.preventExtensions({}).b = 1; } catch (e) { e.name }"#
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"'use strict'; try { delete Object.freeze({ a: 1 }).a; } catch (e) { e.name }"#
+        ),
+        "\"TypeError\""
+    );
+    assert_eq!(forward(&mut context, "frozen.a"), "1");
+}
+
+#[test]
+fn array_index_keys_order() {
+    let mut context = Context::new();
+
+    let init = r#"
+        const obj = { "4294967295": "max", "a": "a", "5": "five" };
+        obj["05"] = "padded";
+        obj["4294967294"] = "last index";
+        obj[1.5] = "fraction";
+        obj[-1] = "negative";
+        obj["+1"] = "sign";
+        obj[0] = "zero";
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.keys(obj).join()"),
+        "\"0,5,4294967294,4294967295,a,05,1.5,-1,+1\""
+    );
+    assert_eq!(forward(&mut context, "obj[5]"), "\"five\"");
+    assert_eq!(forward(&mut context, "obj['4294967295']"), "\"max\"");
+    assert_eq!(forward(&mut context, "obj[1]"), "undefined");
+
+    let array = r#"
+        const array = [];
+        array[4294967295] = "not an index";
+        array["4294967294"] = "index";
+        array.length
+    "#;
+    assert_eq!(forward(&mut context, array), "4294967295");
+    assert_eq!(
+        forward(&mut context, "Object.keys(array).join()"),
+        "\"4294967294,4294967295\""
+    );
+}
+
+#[test]
+fn object_group_by() {
+    let mut context = Context::new();
+    let init = r#"
+        var groups = Object.groupBy([1, 2, 3, 4, 5, 6], (n, i) => n % 2 === 0 ? "even" : "odd");
+        var indices = [];
+        var byIndex = Object.groupBy("abc", (c, i) => { indices.push(i); return i; });
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "groups.even.join()"), "\"2,4,6\"");
+    assert_eq!(forward(&mut context, "groups.odd.join()"), "\"1,3,5\"");
+    assert_eq!(forward(&mut context, "Array.isArray(groups.odd)"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(groups)"),
+        "null"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.keys(groups).join()"),
+        "\"odd,even\""
+    );
+    assert_eq!(forward(&mut context, "indices.join()"), "\"0,1,2\"");
+    assert_eq!(forward(&mut context, "byIndex['1'][0]"), "\"b\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Object.groupBy([1], 1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Object.groupBy.length"), "2");
+}
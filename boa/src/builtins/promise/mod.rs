@@ -0,0 +1,967 @@
+//! This module implements the global `Promise` object.
+//!
+//! A `Promise` represents the eventual completion (or failure) of an asynchronous operation, and
+//! its resulting value.
+//!
+//! The reactions to the settlement of a promise never run synchronously: they are enqueued as
+//! jobs in the [`Context`], which runs them once the script being executed ends.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-promise-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
+
+use crate::{
+    builtins::{
+        iterable::{get_iterator, IteratorRecord},
+        Array, BuiltIn,
+    },
+    context::PromiseRejectionOperation,
+    gc::{empty_trace, Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Gc, GcCell};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a promise.
+#[derive(Debug, Clone, Trace, Finalize)]
+enum PromiseState {
+    Pending,
+    Fulfilled(Value),
+    Rejected(Value),
+}
+
+/// Whether a reaction handles the fulfillment or the rejection of a promise.
+#[derive(Debug, Clone, Copy, PartialEq, Finalize)]
+enum ReactionType {
+    Fulfill,
+    Reject,
+}
+
+unsafe impl Trace for ReactionType {
+    empty_trace!();
+}
+
+/// A promise, and the functions that resolve or reject it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisecapability-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub(crate) struct PromiseCapability {
+    pub(crate) promise: Value,
+    pub(crate) resolve: Value,
+    pub(crate) reject: Value,
+}
+
+/// A function to call when a promise is settled.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisereaction-records
+#[derive(Debug, Clone, Trace, Finalize)]
+struct PromiseReaction {
+    /// The promise that is settled with the result of the handler, if any.
+    ///
+    /// The reactions of `await` don't create a promise.
+    capability: Option<PromiseCapability>,
+    kind: ReactionType,
+    /// The handler, or `None` to pass the value or the reason through.
+    handler: Option<Value>,
+}
+
+/// The internal representation of a `Promise` object.
+#[derive(Debug, Trace, Finalize)]
+pub struct Promise {
+    state: PromiseState,
+    fulfill_reactions: Vec<PromiseReaction>,
+    reject_reactions: Vec<PromiseReaction>,
+    /// Whether a reaction has ever been registered, so that a rejection is reported as unhandled.
+    is_handled: bool,
+}
+
+/// The captures of the `resolve` and `reject` functions of a promise.
+#[derive(Debug, Trace, Finalize)]
+struct ResolvingFunctionCaptures {
+    promise: GcObject,
+    /// Shared by the functions, so that only the first call of either settles the promise.
+    already_resolved: Gc<GcCell<bool>>,
+}
+
+/// The captures of the functions of `Promise.prototype.finally`.
+#[derive(Debug, Trace, Finalize)]
+struct FinallyCaptures {
+    on_finally: Value,
+    constructor: Value,
+}
+
+/// The captures of the function that resolves an element of `Promise.all`.
+#[derive(Debug, Trace, Finalize)]
+struct ResolveElementCaptures {
+    already_called: GcCell<bool>,
+    index: usize,
+    values: Gc<GcCell<Vec<Value>>>,
+    capability: PromiseCapability,
+    remaining_elements: Gc<GcCell<usize>>,
+}
+
+impl BuiltIn for Promise {
+    const NAME: &'static str = "Promise";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let get_species = FunctionBuilder::new(context, Self::get_species)
+            .name("get [Symbol.species]")
+            .constructable(false)
+            .callable(true)
+            .build();
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+
+        let promise_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().promise_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_accessor(
+            WellKnownSymbols::species(),
+            Some(get_species),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .static_method(Self::all, "all", 1)
+        .static_method(Self::race, "race", 1)
+        .static_method(Self::reject, "reject", 1)
+        .static_method(Self::resolve, "resolve", 1)
+        .method(Self::catch, "catch", 1)
+        .method(Self::finally, "finally", 1)
+        .method(Self::then, "then", 2)
+        .property(
+            to_string_tag,
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        (Self::NAME, promise_object.into(), Self::attribute())
+    }
+}
+
+impl Promise {
+    pub(crate) const LENGTH: usize = 1;
+
+    /// `Promise ( executor )`
+    ///
+    /// Creates a new promise, and calls the executor with the functions that resolve and reject
+    /// it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-executor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/Promise
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin Promise constructor without new is forbidden",
+            );
+        }
+
+        let executor = args.get(0).cloned().unwrap_or_default();
+        if !executor.is_function() {
+            return context.throw_type_error("Promise executor is not a function");
+        }
+
+        let promise_prototype = context.standard_objects().promise_object().prototype();
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or(promise_prototype);
+
+        let mut promise = context.construct_object();
+        promise.set_prototype_instance(prototype.into());
+        promise.borrow_mut().data = ObjectData::Promise(Promise {
+            state: PromiseState::Pending,
+            fulfill_reactions: Vec::new(),
+            reject_reactions: Vec::new(),
+            is_handled: false,
+        });
+
+        let (resolve, reject) = Self::create_resolving_functions(&promise, context);
+        if let Err(error) = context.call(&executor, &Value::undefined(), &[resolve, reject.clone()])
+        {
+            context.call(&reject, &Value::undefined(), &[error])?;
+        }
+
+        Ok(promise.into())
+    }
+
+    /// `get Promise [ @@species ]`
+    ///
+    /// The `Promise [ @@species ]` accessor property returns the Promise constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-promise-@@species
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/@@species
+    fn get_species(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        // 1. Return the this value.
+        Ok(this.clone())
+    }
+
+    /// `Promise.all ( iterable )`
+    ///
+    /// Returns a promise that is fulfilled with the array of the values of the promises of the
+    /// iterable once they are all fulfilled, or rejected as soon as one of them is rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.all
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/all
+    pub(crate) fn all(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::combine(this, args, Self::perform_promise_all, context)
+    }
+
+    /// `Promise.race ( iterable )`
+    ///
+    /// Returns a promise that is settled like the first of the promises of the iterable to be
+    /// settled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.race
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/race
+    pub(crate) fn race(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::combine(this, args, Self::perform_promise_race, context)
+    }
+
+    /// `Promise.reject ( r )`
+    ///
+    /// Returns a promise rejected with the given reason.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.reject
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/reject
+    pub(crate) fn reject(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let reason = args.get(0).cloned().unwrap_or_default();
+        let capability = Self::new_promise_capability(this, context)?;
+        context.call(&capability.reject, &Value::undefined(), &[reason])?;
+        Ok(capability.promise.clone())
+    }
+
+    /// `Promise.resolve ( x )`
+    ///
+    /// Returns a promise resolved with the given value. If the value is a promise created by
+    /// the same constructor, it's returned as is.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.resolve
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve
+    pub(crate) fn resolve(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        if !this.is_object() {
+            return context.throw_type_error("Promise.resolve called on a non-object");
+        }
+        let value = args.get(0).cloned().unwrap_or_default();
+        Self::promise_resolve(this, value, context)
+    }
+
+    /// `Promise.prototype.catch ( onRejected )`
+    ///
+    /// Registers a function to call when the promise is rejected.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.catch
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/catch
+    pub(crate) fn catch(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let on_rejected = args.get(0).cloned().unwrap_or_default();
+        Self::invoke_then(this, &[Value::undefined(), on_rejected], context)
+    }
+
+    /// `Promise.prototype.finally ( onFinally )`
+    ///
+    /// Registers a function to call when the promise is settled. The promise returned is
+    /// settled like this one, once the promise returned by the function is fulfilled.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.finally
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/finally
+    pub(crate) fn finally(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let promise = match this.as_object() {
+            Some(promise) => promise,
+            None => {
+                return context.throw_type_error("Promise.prototype.finally called on a non-object")
+            }
+        };
+        let constructor = Self::species_constructor(&promise, context)?;
+
+        let on_finally = args.get(0).cloned().unwrap_or_default();
+        if !on_finally.is_function() {
+            return Self::invoke_then(this, &[on_finally.clone(), on_finally], context);
+        }
+
+        let then_finally = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, captures: &FinallyCaptures, context| {
+                let value = args.get(0).cloned().unwrap_or_default();
+                let result = context.call(&captures.on_finally, &Value::undefined(), &[])?;
+                let promise = Self::promise_resolve(&captures.constructor, result, context)?;
+                let value_thunk = FunctionBuilder::closure_with_captures(
+                    context,
+                    |_, _, value: &Value, _| Ok(value.clone()),
+                    value,
+                )
+                .build();
+                Self::invoke_then(&promise, &[value_thunk.into()], context)
+            },
+            FinallyCaptures {
+                on_finally: on_finally.clone(),
+                constructor: constructor.clone(),
+            },
+        )
+        .length(1)
+        .build();
+
+        let catch_finally = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, captures: &FinallyCaptures, context| {
+                let reason = args.get(0).cloned().unwrap_or_default();
+                let result = context.call(&captures.on_finally, &Value::undefined(), &[])?;
+                let promise = Self::promise_resolve(&captures.constructor, result, context)?;
+                let thrower = FunctionBuilder::closure_with_captures(
+                    context,
+                    |_, _, reason: &Value, _| Err(reason.clone()),
+                    reason,
+                )
+                .build();
+                Self::invoke_then(&promise, &[thrower.into()], context)
+            },
+            FinallyCaptures {
+                on_finally,
+                constructor,
+            },
+        )
+        .length(1)
+        .build();
+
+        Self::invoke_then(this, &[then_finally.into(), catch_finally.into()], context)
+    }
+
+    /// `Promise.prototype.then ( onFulfilled, onRejected )`
+    ///
+    /// Registers functions to call when the promise is fulfilled or rejected, and returns a
+    /// promise resolved with the result of the function called.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.then
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/then
+    pub(crate) fn then(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let promise = match this.as_object() {
+            Some(promise) if promise.borrow().as_promise().is_some() => promise,
+            _ => return context.throw_type_error("'this' is not a Promise"),
+        };
+
+        let constructor = Self::species_constructor(&promise, context)?;
+        let capability = Self::new_promise_capability(&constructor, context)?;
+
+        let on_fulfilled = args.get(0).cloned().unwrap_or_default();
+        let on_rejected = args.get(1).cloned().unwrap_or_default();
+        Ok(Self::perform_promise_then(
+            &promise,
+            on_fulfilled,
+            on_rejected,
+            Some(capability),
+            context,
+        ))
+    }
+
+    /// The shared steps of `Promise.all` and `Promise.race`, which combine the promises of an
+    /// iterable with the given function.
+    fn combine(
+        this: &Value,
+        args: &[Value],
+        perform: fn(
+            &IteratorRecord,
+            &Value,
+            &PromiseCapability,
+            &Value,
+            &mut bool,
+            &mut Context,
+        ) -> Result<Value>,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let capability = Self::new_promise_capability(this, context)?;
+
+        let promise_resolve = this.get_field("resolve", context);
+        let promise_resolve = match promise_resolve {
+            Ok(resolve) if resolve.is_function() => resolve,
+            Ok(_) => {
+                let error = context.construct_type_error("Promise resolve is not a function");
+                return Self::reject_capability(&capability, error, context);
+            }
+            Err(error) => return Self::reject_capability(&capability, error, context),
+        };
+
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        let iterator = match get_iterator(context, iterable) {
+            Ok(iterator) => iterator,
+            Err(error) => return Self::reject_capability(&capability, error, context),
+        };
+
+        let mut done = false;
+        match perform(
+            &iterator,
+            this,
+            &capability,
+            &promise_resolve,
+            &mut done,
+            context,
+        ) {
+            Ok(promise) => Ok(promise),
+            Err(error) => {
+                let error = if done {
+                    error
+                } else {
+                    iterator
+                        .close(Err(error), context)
+                        .expect_err("closing an iterator with an error keeps the error")
+                };
+                Self::reject_capability(&capability, error, context)
+            }
+        }
+    }
+
+    /// Rejects the promise of a capability with an error thrown while combining promises, and
+    /// returns the promise.
+    fn reject_capability(
+        capability: &PromiseCapability,
+        error: Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        context.call(&capability.reject, &Value::undefined(), &[error])?;
+        Ok(capability.promise.clone())
+    }
+
+    /// The abstract operation `PerformPromiseAll`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiseall
+    fn perform_promise_all(
+        iterator: &IteratorRecord,
+        constructor: &Value,
+        capability: &PromiseCapability,
+        promise_resolve: &Value,
+        done: &mut bool,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let values = Gc::new(GcCell::new(Vec::new()));
+        let remaining_elements = Gc::new(GcCell::new(1));
+
+        for index in 0.. {
+            let next = iterator.next(context).map_err(|error| {
+                *done = true;
+                error
+            })?;
+            if next.is_done() {
+                *done = true;
+                *remaining_elements.borrow_mut() -= 1;
+                if *remaining_elements.borrow() == 0 {
+                    let array = Array::new_array(context);
+                    Array::add_to_array_object(&array, &values.borrow(), context)?;
+                    context.call(&capability.resolve, &Value::undefined(), &[array])?;
+                }
+                break;
+            }
+
+            values.borrow_mut().push(Value::undefined());
+            let next_promise = context.call(promise_resolve, constructor, &[next.value()])?;
+
+            let resolve_element = FunctionBuilder::closure_with_captures(
+                context,
+                |_, args, captures: &ResolveElementCaptures, context| {
+                    if *captures.already_called.borrow() {
+                        return Ok(Value::undefined());
+                    }
+                    *captures.already_called.borrow_mut() = true;
+
+                    let value = args.get(0).cloned().unwrap_or_default();
+                    captures.values.borrow_mut()[captures.index] = value;
+
+                    *captures.remaining_elements.borrow_mut() -= 1;
+                    if *captures.remaining_elements.borrow() == 0 {
+                        let array = Array::new_array(context);
+                        Array::add_to_array_object(&array, &captures.values.borrow(), context)?;
+                        return context.call(
+                            &captures.capability.resolve,
+                            &Value::undefined(),
+                            &[array],
+                        );
+                    }
+                    Ok(Value::undefined())
+                },
+                ResolveElementCaptures {
+                    already_called: GcCell::new(false),
+                    index,
+                    values: values.clone(),
+                    capability: capability.clone(),
+                    remaining_elements: remaining_elements.clone(),
+                },
+            )
+            .length(1)
+            .build();
+
+            *remaining_elements.borrow_mut() += 1;
+            Self::invoke_then(
+                &next_promise,
+                &[resolve_element.into(), capability.reject.clone()],
+                context,
+            )?;
+        }
+
+        Ok(capability.promise.clone())
+    }
+
+    /// The abstract operation `PerformPromiseRace`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromiserace
+    fn perform_promise_race(
+        iterator: &IteratorRecord,
+        constructor: &Value,
+        capability: &PromiseCapability,
+        promise_resolve: &Value,
+        done: &mut bool,
+        context: &mut Context,
+    ) -> Result<Value> {
+        loop {
+            let next = iterator.next(context).map_err(|error| {
+                *done = true;
+                error
+            })?;
+            if next.is_done() {
+                *done = true;
+                return Ok(capability.promise.clone());
+            }
+
+            let next_promise = context.call(promise_resolve, constructor, &[next.value()])?;
+            Self::invoke_then(
+                &next_promise,
+                &[capability.resolve.clone(), capability.reject.clone()],
+                context,
+            )?;
+        }
+    }
+
+    /// Calls the `then` method of the given value.
+    fn invoke_then(promise: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let then = promise.get_field("then", context)?;
+        context.call(&then, promise, args)
+    }
+
+    /// The abstract operation `SpeciesConstructor`, with `%Promise%` as the default constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-speciesconstructor
+    fn species_constructor(promise: &GcObject, context: &mut Context) -> Result<Value> {
+        let default: Value = context
+            .standard_objects()
+            .promise_object()
+            .constructor()
+            .into();
+
+        let constructor = promise.get(&"constructor".into(), promise.clone().into(), context)?;
+        if constructor.is_undefined() {
+            return Ok(default);
+        }
+        let constructor = match constructor.as_object() {
+            Some(constructor) => constructor,
+            None => {
+                return context.throw_type_error("the constructor of a Promise is not an object")
+            }
+        };
+
+        let species = constructor.get(
+            &WellKnownSymbols::species().into(),
+            constructor.clone().into(),
+            context,
+        )?;
+        if species.is_null_or_undefined() {
+            return Ok(default);
+        }
+        match species.as_object() {
+            Some(species) if species.is_constructable() => Ok(species.into()),
+            _ => context.throw_type_error("Symbol.species must be a constructor"),
+        }
+    }
+
+    /// The abstract operation `NewPromiseCapability`, which creates a promise with the given
+    /// constructor, along with the functions that resolve and reject it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisecapability
+    pub(crate) fn new_promise_capability(
+        constructor: &Value,
+        context: &mut Context,
+    ) -> Result<PromiseCapability> {
+        let constructor = match constructor.as_object() {
+            Some(constructor) if constructor.is_constructable() => constructor,
+            _ => {
+                return Err(context.construct_type_error("Promise constructor is not a constructor"))
+            }
+        };
+
+        // The resolve and reject functions given to the executor.
+        let functions = Gc::new(GcCell::new((Value::undefined(), Value::undefined())));
+        let executor = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, functions: &Gc<GcCell<(Value, Value)>>, context| {
+                let mut functions = functions.borrow_mut();
+                if !functions.0.is_undefined() || !functions.1.is_undefined() {
+                    return context.throw_type_error("Promise executor has already been called");
+                }
+                functions.0 = args.get(0).cloned().unwrap_or_default();
+                functions.1 = args.get(1).cloned().unwrap_or_default();
+                Ok(Value::undefined())
+            },
+            functions.clone(),
+        )
+        .length(2)
+        .build();
+
+        let promise =
+            constructor.construct(&[executor.into()], &constructor.clone().into(), context)?;
+
+        let (resolve, reject) = functions.borrow().clone();
+        if !resolve.is_function() {
+            return Err(context.construct_type_error("Promise resolve is not a function"));
+        }
+        if !reject.is_function() {
+            return Err(context.construct_type_error("Promise reject is not a function"));
+        }
+
+        Ok(PromiseCapability {
+            promise,
+            resolve,
+            reject,
+        })
+    }
+
+    /// The abstract operation `PromiseResolve`, which converts a value to a promise created by
+    /// the given constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve
+    pub(crate) fn promise_resolve(
+        constructor: &Value,
+        value: Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let is_promise = value
+            .as_object()
+            .map_or(false, |object| object.borrow().as_promise().is_some());
+        if is_promise {
+            let value_constructor = value.get_field("constructor", context)?;
+            if value_constructor.strict_equals(constructor) {
+                return Ok(value);
+            }
+        }
+
+        let capability = Self::new_promise_capability(constructor, context)?;
+        context.call(&capability.resolve, &Value::undefined(), &[value])?;
+        Ok(capability.promise.clone())
+    }
+
+    /// The abstract operation `CreateResolvingFunctions`, which creates the functions that
+    /// resolve and reject the given promise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
+    fn create_resolving_functions(promise: &GcObject, context: &mut Context) -> (Value, Value) {
+        let already_resolved = Gc::new(GcCell::new(false));
+
+        let resolve = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, captures: &ResolvingFunctionCaptures, context| {
+                if *captures.already_resolved.borrow() {
+                    return Ok(Value::undefined());
+                }
+                *captures.already_resolved.borrow_mut() = true;
+
+                let resolution = args.get(0).cloned().unwrap_or_default();
+                let promise = &captures.promise;
+                let resolution_object = match resolution.as_object() {
+                    Some(object) if GcObject::equals(&object, promise) => {
+                        let error =
+                            context.construct_type_error("a Promise can't be resolved with itself");
+                        Self::reject_promise(promise, error, context);
+                        return Ok(Value::undefined());
+                    }
+                    Some(object) => object,
+                    None => {
+                        Self::fulfill_promise(promise, resolution, context);
+                        return Ok(Value::undefined());
+                    }
+                };
+
+                let then = match resolution_object.get(&"then".into(), resolution.clone(), context)
+                {
+                    Ok(then) => then,
+                    Err(error) => {
+                        Self::reject_promise(promise, error, context);
+                        return Ok(Value::undefined());
+                    }
+                };
+                if !then.is_function() {
+                    Self::fulfill_promise(promise, resolution, context);
+                    return Ok(Value::undefined());
+                }
+
+                // NewPromiseResolveThenableJob
+                let promise = promise.clone();
+                context.enqueue_job(move |context| {
+                    let (resolve, reject) = Self::create_resolving_functions(&promise, context);
+                    if let Err(error) = context.call(&then, &resolution, &[resolve, reject.clone()])
+                    {
+                        return context.call(&reject, &Value::undefined(), &[error]);
+                    }
+                    Ok(Value::undefined())
+                });
+                Ok(Value::undefined())
+            },
+            ResolvingFunctionCaptures {
+                promise: promise.clone(),
+                already_resolved: already_resolved.clone(),
+            },
+        )
+        .length(1)
+        .build();
+
+        let reject = FunctionBuilder::closure_with_captures(
+            context,
+            |_, args, captures: &ResolvingFunctionCaptures, context| {
+                if *captures.already_resolved.borrow() {
+                    return Ok(Value::undefined());
+                }
+                *captures.already_resolved.borrow_mut() = true;
+
+                let reason = args.get(0).cloned().unwrap_or_default();
+                Self::reject_promise(&captures.promise, reason, context);
+                Ok(Value::undefined())
+            },
+            ResolvingFunctionCaptures {
+                promise: promise.clone(),
+                already_resolved,
+            },
+        )
+        .length(1)
+        .build();
+
+        (resolve.into(), reject.into())
+    }
+
+    /// The abstract operation `FulfillPromise`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-fulfillpromise
+    fn fulfill_promise(promise: &GcObject, value: Value, context: &mut Context) {
+        Self::settle_promise(
+            promise,
+            PromiseState::Fulfilled(value.clone()),
+            value,
+            context,
+        );
+    }
+
+    /// The abstract operation `RejectPromise`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rejectpromise
+    fn reject_promise(promise: &GcObject, reason: Value, context: &mut Context) {
+        Self::settle_promise(
+            promise,
+            PromiseState::Rejected(reason.clone()),
+            reason.clone(),
+            context,
+        );
+
+        let is_handled = promise
+            .borrow()
+            .as_promise()
+            .expect("the object is a Promise")
+            .is_handled;
+        if !is_handled {
+            context.track_promise_rejection(promise, PromiseRejectionOperation::Reject(reason));
+        }
+    }
+
+    /// Settles a pending promise, and triggers the reactions for the new state.
+    fn settle_promise(
+        promise: &GcObject,
+        state: PromiseState,
+        argument: Value,
+        context: &mut Context,
+    ) {
+        let reactions = {
+            let mut promise = promise.borrow_mut();
+            let promise = promise.as_promise_mut().expect("the object is a Promise");
+            let fulfill_reactions = std::mem::take(&mut promise.fulfill_reactions);
+            let reject_reactions = std::mem::take(&mut promise.reject_reactions);
+            let reactions = match state {
+                PromiseState::Fulfilled(_) => fulfill_reactions,
+                _ => reject_reactions,
+            };
+            promise.state = state;
+            reactions
+        };
+
+        for reaction in reactions {
+            Self::enqueue_reaction_job(reaction, argument.clone(), context);
+        }
+    }
+
+    /// The abstract operation `PerformPromiseThen`, which registers the reactions to the
+    /// settlement of a promise, and returns the promise of the capability, or `undefined` if
+    /// there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromisethen
+    pub(crate) fn perform_promise_then(
+        promise: &GcObject,
+        on_fulfilled: Value,
+        on_rejected: Value,
+        capability: Option<PromiseCapability>,
+        context: &mut Context,
+    ) -> Value {
+        let result = capability
+            .as_ref()
+            .map_or_else(Value::undefined, |capability| capability.promise.clone());
+        let fulfill_reaction = PromiseReaction {
+            capability: capability.clone(),
+            kind: ReactionType::Fulfill,
+            handler: Some(on_fulfilled).filter(Value::is_function),
+        };
+        let reject_reaction = PromiseReaction {
+            capability,
+            kind: ReactionType::Reject,
+            handler: Some(on_rejected).filter(Value::is_function),
+        };
+
+        let (state, is_handled) = {
+            let mut promise = promise.borrow_mut();
+            let promise = promise.as_promise_mut().expect("the object is a Promise");
+            (
+                promise.state.clone(),
+                std::mem::replace(&mut promise.is_handled, true),
+            )
+        };
+        match &state {
+            PromiseState::Pending => {
+                let mut promise = promise.borrow_mut();
+                let promise = promise.as_promise_mut().expect("the object is a Promise");
+                promise.fulfill_reactions.push(fulfill_reaction);
+                promise.reject_reactions.push(reject_reaction);
+            }
+            PromiseState::Fulfilled(value) => {
+                Self::enqueue_reaction_job(fulfill_reaction, value.clone(), context)
+            }
+            PromiseState::Rejected(reason) => {
+                if !is_handled {
+                    context.track_promise_rejection(promise, PromiseRejectionOperation::Handle);
+                }
+                Self::enqueue_reaction_job(reject_reaction, reason.clone(), context)
+            }
+        }
+
+        result
+    }
+
+    /// The abstract operation `NewPromiseReactionJob`, which enqueues the call of the handler
+    /// of a reaction, and the settlement of its promise with the result.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisereactionjob
+    fn enqueue_reaction_job(reaction: PromiseReaction, argument: Value, context: &mut Context) {
+        context.enqueue_job(move |context| {
+            let result = match (&reaction.handler, reaction.kind) {
+                (Some(handler), _) => context.call(handler, &Value::undefined(), &[argument]),
+                (None, ReactionType::Fulfill) => Ok(argument),
+                (None, ReactionType::Reject) => Err(argument),
+            };
+
+            let capability = match &reaction.capability {
+                Some(capability) => capability,
+                None => return result.map(|_| Value::undefined()),
+            };
+            match result {
+                Ok(value) => context.call(&capability.resolve, &Value::undefined(), &[value]),
+                Err(reason) => context.call(&capability.reject, &Value::undefined(), &[reason]),
+            }
+        });
+    }
+}
@@ -0,0 +1,358 @@
+use crate::{forward, Context, Value};
+use std::{cell::RefCell, rc::Rc};
+
+#[test]
+fn then_runs_after_synchronous_code() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        Promise.resolve("then").then(function (value) {
+            log.push(value);
+        });
+        log.push("sync");
+        log.join();
+        "#;
+    assert_eq!(forward(&mut context, init), "\"sync\"");
+    assert_eq!(forward(&mut context, "log.join()"), "\"sync,then\"");
+}
+
+#[test]
+fn executor_runs_synchronously() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        let resolve;
+        let promise = new Promise(function (res) {
+            log.push("executor");
+            resolve = res;
+        });
+        promise.then(function (value) {
+            log.push(value);
+        });
+        log.push("sync");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.join()"), "\"executor,sync\"");
+    forward(&mut context, "resolve('resolved'); resolve('ignored');");
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"executor,sync,resolved\""
+    );
+}
+
+#[test]
+fn then_chains() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        Promise.resolve(1)
+            .then(function (value) {
+                log.push(value);
+                return value + 1;
+            })
+            .then(function (value) {
+                log.push(value);
+                return Promise.resolve(value + 1);
+            })
+            .then(function (value) {
+                log.push(value);
+            });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.join()"), "\"1,2,3\"");
+}
+
+#[test]
+fn reactions_run_in_order() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        let first = Promise.resolve();
+        first.then(function () {
+            log.push("a1");
+        }).then(function () {
+            log.push("a2");
+        });
+        first.then(function () {
+            log.push("b1");
+        }).then(function () {
+            log.push("b2");
+        });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.join()"), "\"a1,b1,a2,b2\"");
+}
+
+#[test]
+fn catch_and_finally() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        Promise.reject(new TypeError("bad"))
+            .then(function () {
+                log.push("skipped");
+            })
+            .catch(function (e) {
+                log.push(e.message);
+                return "recovered";
+            })
+            .finally(function () {
+                log.push("finally");
+                return "ignored";
+            })
+            .then(function (value) {
+                log.push(value);
+            });
+        new Promise(function () {
+            throw "thrown";
+        })
+            .finally(function () {
+                log.push("finally2");
+            })
+            .catch(function (e) {
+                log.push(e);
+            });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"finally2,bad,finally,thrown,recovered\""
+    );
+}
+
+#[test]
+fn resolve_and_reject() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        let promise = Promise.resolve(1);
+        let same = Promise.resolve(promise) === promise;
+        Promise.resolve({
+            then: function (resolve) {
+                resolve("thenable");
+            }
+        }).then(function (value) {
+            log.push(value);
+        });
+        Promise.reject("reason").then(null, function (reason) {
+            log.push(reason);
+        });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "same"), "true");
+    assert_eq!(forward(&mut context, "log.join()"), "\"reason,thenable\"");
+}
+
+#[test]
+fn resolve_with_itself() {
+    let mut context = Context::new();
+    let init = r#"
+        let error;
+        let resolve;
+        let promise = new Promise(function (res) {
+            resolve = res;
+        });
+        resolve(promise);
+        promise.catch(function (e) {
+            error = e;
+        });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error instanceof TypeError"), "true");
+}
+
+#[test]
+fn all() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        let resolveLater;
+        let later = new Promise(function (resolve) {
+            resolveLater = resolve;
+        });
+        Promise.all([1, Promise.resolve(2), later]).then(function (values) {
+            log.push(values.join());
+        });
+        Promise.all([]).then(function (values) {
+            log.push("empty " + values.length);
+        });
+        Promise.all([Promise.resolve(1), Promise.reject("rejected")]).catch(function (e) {
+            log.push(e);
+        });
+        Promise.all(new Set(["a", "b"])).then(function (values) {
+            log.push(values.join());
+        });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join(' ')"),
+        "\"empty 0 rejected a,b\""
+    );
+    forward(&mut context, "resolveLater(3);");
+    assert_eq!(
+        forward(&mut context, "log.join(' ')"),
+        "\"empty 0 rejected a,b 1,2,3\""
+    );
+}
+
+#[test]
+fn all_invalid_iterable() {
+    let mut context = Context::new();
+    let init = r#"
+        let error;
+        Promise.all(1).catch(function (e) {
+            error = e;
+        });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error instanceof TypeError"), "true");
+}
+
+#[test]
+fn race() {
+    let mut context = Context::new();
+    let init = r#"
+        let log = [];
+        let never = new Promise(function () {});
+        Promise.race([never, Promise.resolve("fast")]).then(function (value) {
+            log.push(value);
+        });
+        Promise.race([never, Promise.reject("failed")]).catch(function (e) {
+            log.push(e);
+        });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "log.join()"), "\"fast,failed\"");
+}
+
+#[test]
+fn not_a_function() {
+    let mut context = Context::new();
+    let init = r#"
+        let errors = [];
+        try {
+            Promise(function () {});
+        } catch (e) {
+            errors.push(e instanceof TypeError);
+        }
+        try {
+            new Promise(1);
+        } catch (e) {
+            errors.push(e instanceof TypeError);
+        }
+        try {
+            Promise.prototype.then.call({});
+        } catch (e) {
+            errors.push(e instanceof TypeError);
+        }
+        errors.join();
+        "#;
+    assert_eq!(forward(&mut context, init), "\"true,true,true\"");
+}
+
+#[test]
+fn run_jobs_from_host() {
+    let mut context = Context::new();
+    let promise = context.eval("Promise.resolve(1)").unwrap();
+    let callback = context
+        .eval("(function (value) { globalThis.result = value; })")
+        .unwrap();
+    let then = promise.get_field("then", &mut context).unwrap();
+    context.call(&then, &promise, &[callback]).unwrap();
+
+    let global = Value::from(context.global_object());
+    let result = global.get_field("result", &mut context).unwrap();
+    assert!(result.is_undefined());
+
+    context.run_jobs().unwrap();
+    let result = global.get_field("result", &mut context).unwrap();
+    assert_eq!(result.as_number(), Some(1.0));
+}
+
+/// Evaluates a script with a handler recording the rejections without a handler.
+fn unhandled_rejections(src: &str) -> Vec<String> {
+    let mut context = Context::new();
+    let reasons = Rc::new(RefCell::new(Vec::new()));
+    let handler_reasons = reasons.clone();
+    context.set_unhandled_rejection_handler(move |reason| {
+        handler_reasons
+            .borrow_mut()
+            .push(reason.display().to_string())
+    });
+    forward(&mut context, src);
+    let reasons = reasons.borrow().clone();
+    reasons
+}
+
+#[test]
+fn unhandled_rejection_handler() {
+    assert_eq!(
+        unhandled_rejections("Promise.reject('reason');"),
+        vec!["\"reason\""]
+    );
+    assert_eq!(
+        unhandled_rejections("new Promise(function () { throw 1; });"),
+        vec!["1"]
+    );
+    // The promise returned by `then` is rejected without a handler.
+    assert_eq!(
+        unhandled_rejections("Promise.reject(1).then(function () {});"),
+        vec!["1"]
+    );
+    assert_eq!(
+        unhandled_rejections("Promise.resolve().then(function () { throw 2; });"),
+        vec!["2"]
+    );
+}
+
+#[test]
+fn unhandled_rejection_handler_ignores_handled_rejections() {
+    assert!(unhandled_rejections("Promise.reject(1).catch(function () {});").is_empty());
+    assert!(unhandled_rejections("Promise.reject(1).then(undefined, function () {});").is_empty());
+    assert!(unhandled_rejections(
+        "Promise.reject(1).finally(function () {}).catch(function () {});"
+    )
+    .is_empty());
+    assert!(
+        unhandled_rejections("Promise.all([Promise.reject(1)]).catch(function () {});").is_empty()
+    );
+
+    // A handler added by a job before the job queue is empty handles the rejection.
+    let late_handler = r#"
+        let rejected = Promise.reject(1);
+        Promise.resolve().then(function () {
+            rejected.catch(function () {});
+        });
+        "#;
+    assert!(unhandled_rejections(late_handler).is_empty());
+}
+
+#[test]
+fn unhandled_rejection_handler_from_host() {
+    let mut context = Context::new();
+    let reasons = Rc::new(RefCell::new(Vec::new()));
+    let handler_reasons = reasons.clone();
+    context
+        .set_unhandled_rejection_handler(move |reason| handler_reasons.borrow_mut().push(reason));
+
+    let reject = context
+        .eval("let reject; new Promise(function (_, rej) { reject = rej; }); reject")
+        .unwrap();
+    assert!(reasons.borrow().is_empty());
+
+    context
+        .call(&reject, &Value::undefined(), &["reason".into()])
+        .unwrap();
+    assert!(reasons.borrow().is_empty());
+
+    context.run_jobs().unwrap();
+    assert_eq!(reasons.borrow().len(), 1);
+    assert_eq!(
+        reasons.borrow()[0].as_string().map(|s| s.as_str()),
+        Some("reason")
+    );
+
+    // Each rejection is only reported once.
+    context.run_jobs().unwrap();
+    assert_eq!(reasons.borrow().len(), 1);
+}
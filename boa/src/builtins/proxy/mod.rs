@@ -0,0 +1,438 @@
+//! This module implements the global `Proxy` object.
+//!
+//! The `Proxy` object enables you to create a proxy for another object, which can intercept and
+//! redefine fundamental operations for that object.
+//!
+//! Operations that have no trap in the handler are forwarded to the target object.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-proxy-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy
+
+use crate::{
+    builtins::{Array, BuiltIn},
+    gc::{Finalize, Trace},
+    object::{FunctionBuilder, GcObject, Object, ObjectData},
+    property::{Attribute, PropertyDescriptor, PropertyKey},
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The internal representation of a `Proxy` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Proxy {
+    target: GcObject,
+    handler: GcObject,
+}
+
+impl BuiltIn for Proxy {
+    const NAME: &'static str = "Proxy";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // The `Proxy` constructor does not have a `prototype` property.
+        let proxy = FunctionBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .constructable(true)
+            .build();
+
+        (Self::NAME, proxy.into(), Self::attribute())
+    }
+}
+
+impl Proxy {
+    const LENGTH: usize = 2;
+
+    /// Gets the target object of the proxy.
+    #[inline]
+    pub(crate) fn target(&self) -> &GcObject {
+        &self.target
+    }
+
+    /// `Proxy( target, handler )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-target-handler
+    fn constructor(new_target: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        if new_target.is_undefined() {
+            return context.throw_type_error("Proxy constructor requires 'new'");
+        }
+
+        // 2. Return ? ProxyCreate(target, handler).
+        Self::create(
+            args.get(0).cloned().unwrap_or_default(),
+            args.get(1).cloned().unwrap_or_default(),
+            context,
+        )
+    }
+
+    /// Abstract operation `ProxyCreate ( target, handler )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxycreate
+    fn create(target: Value, handler: Value, context: &mut Context) -> Result<Value> {
+        // 1. If Type(target) is not Object, throw a TypeError exception.
+        let target = target.as_object().ok_or_else(|| {
+            context.construct_type_error("Cannot create proxy with a non-object as target")
+        })?;
+
+        // 2. If Type(handler) is not Object, throw a TypeError exception.
+        let handler = handler.as_object().ok_or_else(|| {
+            context.construct_type_error("Cannot create proxy with a non-object as handler")
+        })?;
+
+        // 3. Let P be ! MakeBasicObject(« [[ProxyHandler]], [[ProxyTarget]] »).
+        // 4-6. Set P's essential internal methods to the definitions of the proxy exotic object.
+        // 7. Set P.[[ProxyTarget]] to target.
+        // 8. Set P.[[ProxyHandler]] to handler.
+        let mut proxy = Object::default();
+        proxy.data = ObjectData::Proxy(Self { target, handler });
+
+        // 9. Return P.
+        Ok(GcObject::new(proxy).into())
+    }
+
+    /// Gets the trap with the given name from the handler, or `None` if it is not defined.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmethod
+    fn trap(&self, name: &str, context: &mut Context) -> Result<Option<GcObject>> {
+        let trap = self
+            .handler
+            .get(&name.into(), self.handler.clone().into(), context)?;
+        match trap {
+            Value::Undefined | Value::Null => Ok(None),
+            Value::Object(ref object) if object.is_callable() => Ok(Some(object.clone())),
+            _ => {
+                Err(context
+                    .construct_type_error(format!("proxy trap '{}' is not a function", name)))
+            }
+        }
+    }
+
+    /// `[[Get]] ( P, Receiver )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-get-p-receiver
+    pub(crate) fn get(
+        &self,
+        key: &PropertyKey,
+        receiver: Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 6. Let trap be ? GetMethod(handler, "get").
+        let trap = if let Some(trap) = self.trap("get", context)? {
+            trap
+        } else {
+            // 7. If trap is undefined, then
+            //     a. Return ? target.[[Get]](P, Receiver).
+            return self.target.get(key, receiver, context);
+        };
+
+        // 8. Let trapResult be ? Call(trap, handler, « target, P, Receiver »).
+        let trap_result = trap.call(
+            &self.handler.clone().into(),
+            &[self.target.clone().into(), key.into(), receiver],
+            context,
+        )?;
+
+        // 9. Let targetDesc be ? target.[[GetOwnProperty]](P).
+        // 10. If targetDesc is not undefined and targetDesc.[[Configurable]] is false, then
+        match self.target.get_own_property(key) {
+            Some(PropertyDescriptor::Data(ref desc)) if !desc.configurable() => {
+                // a. If IsDataDescriptor(targetDesc) is true and targetDesc.[[Writable]] is false, then
+                //     i. If SameValue(trapResult, targetDesc.[[Value]]) is false, throw a TypeError exception.
+                if !desc.writable() && !Value::same_value(&trap_result, &desc.value()) {
+                    return context.throw_type_error(
+                        "proxy get trap returned a different value for a non-writable, non-configurable property",
+                    );
+                }
+            }
+            Some(PropertyDescriptor::Accessor(ref desc)) if !desc.configurable() => {
+                // b. If IsAccessorDescriptor(targetDesc) is true and targetDesc.[[Get]] is undefined, then
+                //     i. If trapResult is not undefined, throw a TypeError exception.
+                if desc.getter().is_none() && !trap_result.is_undefined() {
+                    return context.throw_type_error(
+                        "proxy get trap returned a value for a non-configurable accessor property without a getter",
+                    );
+                }
+            }
+            _ => {}
+        }
+
+        // 11. Return trapResult.
+        Ok(trap_result)
+    }
+
+    /// `[[Set]] ( P, V, Receiver )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-set-p-v-receiver
+    pub(crate) fn set(
+        &self,
+        key: PropertyKey,
+        value: Value,
+        receiver: Value,
+        context: &mut Context,
+    ) -> Result<bool> {
+        // 6. Let trap be ? GetMethod(handler, "set").
+        let trap = if let Some(trap) = self.trap("set", context)? {
+            trap
+        } else {
+            // 7. If trap is undefined, then
+            //     a. Return ? target.[[Set]](P, V, Receiver).
+            return self.target.clone().set(key, value, receiver, context);
+        };
+
+        // 8. Let booleanTrapResult be ! ToBoolean(? Call(trap, handler, « target, P, V, Receiver »)).
+        // 9. If booleanTrapResult is false, return false.
+        if !trap
+            .call(
+                &self.handler.clone().into(),
+                &[
+                    self.target.clone().into(),
+                    (&key).into(),
+                    value.clone(),
+                    receiver,
+                ],
+                context,
+            )?
+            .to_boolean()
+        {
+            return Ok(false);
+        }
+
+        // 10. Let targetDesc be ? target.[[GetOwnProperty]](P).
+        // 11. If targetDesc is not undefined and targetDesc.[[Configurable]] is false, then
+        match self.target.get_own_property(&key) {
+            Some(PropertyDescriptor::Data(ref desc)) if !desc.configurable() => {
+                // a. If IsDataDescriptor(targetDesc) is true and targetDesc.[[Writable]] is false, then
+                //     i. If SameValue(V, targetDesc.[[Value]]) is false, throw a TypeError exception.
+                if !desc.writable() && !Value::same_value(&value, &desc.value()) {
+                    return Err(context.construct_type_error(
+                        "proxy set trap changed the value of a non-writable, non-configurable property",
+                    ));
+                }
+            }
+            Some(PropertyDescriptor::Accessor(ref desc)) if !desc.configurable() => {
+                // b. If IsAccessorDescriptor(targetDesc) is true, then
+                //     i. If targetDesc.[[Set]] is undefined, throw a TypeError exception.
+                if desc.setter().is_none() {
+                    return Err(context.construct_type_error(
+                        "proxy set trap succeeded for a non-configurable accessor property without a setter",
+                    ));
+                }
+            }
+            _ => {}
+        }
+
+        // 12. Return true.
+        Ok(true)
+    }
+
+    /// `[[HasProperty]] ( P )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-hasproperty-p
+    pub(crate) fn has_property(&self, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+        // 6. Let trap be ? GetMethod(handler, "has").
+        let trap = if let Some(trap) = self.trap("has", context)? {
+            trap
+        } else {
+            // 7. If trap is undefined, then
+            //     a. Return ? target.[[HasProperty]](P).
+            return self.target.has_property(key, context);
+        };
+
+        // 8. Let booleanTrapResult be ! ToBoolean(? Call(trap, handler, « target, P »)).
+        let boolean_trap_result = trap
+            .call(
+                &self.handler.clone().into(),
+                &[self.target.clone().into(), key.into()],
+                context,
+            )?
+            .to_boolean();
+
+        // 9. If booleanTrapResult is false, then
+        if !boolean_trap_result {
+            // a. Let targetDesc be ? target.[[GetOwnProperty]](P).
+            // b. If targetDesc is not undefined, then
+            if let Some(desc) = self.target.get_own_property(key) {
+                // i. If targetDesc.[[Configurable]] is false, throw a TypeError exception.
+                if !desc.configurable() {
+                    return Err(context
+                        .construct_type_error("proxy has trap hid a non-configurable property"));
+                }
+                // ii. Let extensibleTarget be ? IsExtensible(target).
+                // iii. If extensibleTarget is false, throw a TypeError exception.
+                if !self.target.is_extensible() {
+                    return Err(context.construct_type_error(
+                        "proxy has trap hid a property of a non-extensible object",
+                    ));
+                }
+            }
+        }
+
+        // 10. Return booleanTrapResult.
+        Ok(boolean_trap_result)
+    }
+
+    /// `[[Delete]] ( P )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-delete-p
+    pub(crate) fn delete(&self, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+        // 6. Let trap be ? GetMethod(handler, "deleteProperty").
+        let trap = if let Some(trap) = self.trap("deleteProperty", context)? {
+            trap
+        } else {
+            // 7. If trap is undefined, then
+            //     a. Return ? target.[[Delete]](P).
+            return self.target.clone().delete(key, context);
+        };
+
+        // 8. Let booleanTrapResult be ! ToBoolean(? Call(trap, handler, « target, P »)).
+        // 9. If booleanTrapResult is false, return false.
+        if !trap
+            .call(
+                &self.handler.clone().into(),
+                &[self.target.clone().into(), key.into()],
+                context,
+            )?
+            .to_boolean()
+        {
+            return Ok(false);
+        }
+
+        // 10. Let targetDesc be ? target.[[GetOwnProperty]](P).
+        // 11. If targetDesc is undefined, return true.
+        if let Some(desc) = self.target.get_own_property(key) {
+            // 12. If targetDesc.[[Configurable]] is false, throw a TypeError exception.
+            if !desc.configurable() {
+                return Err(context.construct_type_error(
+                    "proxy deleteProperty trap deleted a non-configurable property",
+                ));
+            }
+            // 13. Let extensibleTarget be ? IsExtensible(target).
+            // 14. If extensibleTarget is false, throw a TypeError exception.
+            if !self.target.is_extensible() {
+                return Err(context.construct_type_error(
+                    "proxy deleteProperty trap deleted a property of a non-extensible object",
+                ));
+            }
+        }
+
+        // 15. Return true.
+        Ok(true)
+    }
+
+    /// `[[Call]] ( thisArgument, argumentsList )`
+    ///
+    /// A proxy only has a `[[Call]]` internal method if its target is callable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-call-thisargument-argumentslist
+    pub(crate) fn call(
+        &self,
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if !self.target.is_callable() {
+            return context.throw_type_error("not a function");
+        }
+
+        // 5. Let trap be ? GetMethod(handler, "apply").
+        let trap = if let Some(trap) = self.trap("apply", context)? {
+            trap
+        } else {
+            // 6. If trap is undefined, then
+            //     a. Return ? Call(target, thisArgument, argumentsList).
+            return self.target.call(this, args, context);
+        };
+
+        // 7. Let argArray be ! CreateArrayFromList(argumentsList).
+        let arg_array = Array::create_array_from_list(args.iter().cloned(), context);
+
+        // 8. Return ? Call(trap, handler, « target, thisArgument, argArray »).
+        trap.call(
+            &self.handler.clone().into(),
+            &[self.target.clone().into(), this.clone(), arg_array],
+            context,
+        )
+    }
+
+    /// `[[Construct]] ( argumentsList, newTarget )`
+    ///
+    /// A proxy only has a `[[Construct]]` internal method if its target is a constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-construct-argumentslist-newtarget
+    pub(crate) fn construct(
+        &self,
+        args: &[Value],
+        new_target: &Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        if !self.target.is_constructable() {
+            return context.throw_type_error("not a constructor");
+        }
+
+        // 5. Let trap be ? GetMethod(handler, "construct").
+        let trap = if let Some(trap) = self.trap("construct", context)? {
+            trap
+        } else {
+            // 6. If trap is undefined, then
+            //     a. Return ? Construct(target, argumentsList, newTarget).
+            return self.target.construct(args, new_target, context);
+        };
+
+        // 7. Let argArray be ! CreateArrayFromList(argumentsList).
+        let arg_array = Array::create_array_from_list(args.iter().cloned(), context);
+
+        // 8. Let newObj be ? Call(trap, handler, « target, argArray, newTarget »).
+        let new_object = trap.call(
+            &self.handler.clone().into(),
+            &[self.target.clone().into(), arg_array, new_target.clone()],
+            context,
+        )?;
+
+        // 9. If Type(newObj) is not Object, throw a TypeError exception.
+        if !new_object.is_object() {
+            return context.throw_type_error("proxy construct trap returned a non-object");
+        }
+
+        // 10. Return newObj.
+        Ok(new_object)
+    }
+}
@@ -0,0 +1,232 @@
+use crate::{forward, Context};
+
+#[test]
+fn forwards_without_traps() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var target = { a: 1 };
+        var proxy = new Proxy(target, {});
+        proxy.b = 2;
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "proxy.a"), "1");
+    assert_eq!(forward(&mut context, "target.b"), "2");
+    assert_eq!(forward(&mut context, "'a' in proxy"), "true");
+    assert_eq!(forward(&mut context, "delete proxy.a"), "true");
+    assert_eq!(forward(&mut context, "target.a"), "undefined");
+}
+
+#[test]
+fn get_and_set_traps() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var log = [];
+        var target = {};
+        var proxy = new Proxy(target, {
+            get: function(target, key, receiver) {
+                log.push("get " + key);
+                return key in target ? target[key] : 0;
+            },
+            set: function(target, key, value, receiver) {
+                log.push("set " + key);
+                target[key] = value * 2;
+                return true;
+            }
+        });
+        proxy.x = 21;
+        var x = proxy.x;
+        var y = proxy.y;
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "x"), "42");
+    assert_eq!(forward(&mut context, "y"), "0");
+    assert_eq!(forward(&mut context, "log.join()"), "\"set x,get x,get y\"");
+}
+
+#[test]
+fn has_trap() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var proxy = new Proxy({}, {
+            has: function(target, key) {
+                return key.startsWith("_");
+            }
+        });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "'_hidden' in proxy"), "true");
+    assert_eq!(forward(&mut context, "'visible' in proxy"), "false");
+}
+
+#[test]
+fn delete_property_trap() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var target = { a: 1, b: 2 };
+        var proxy = new Proxy(target, {
+            deleteProperty: function(target, key) {
+                if (key === "a") {
+                    return false;
+                }
+                delete target[key];
+                return true;
+            }
+        });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "delete proxy.a"), "false");
+    assert_eq!(forward(&mut context, "delete proxy.b"), "true");
+    assert_eq!(forward(&mut context, "target.a"), "1");
+    assert_eq!(forward(&mut context, "target.b"), "undefined");
+}
+
+#[test]
+fn get_trap_invariant() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var target = {};
+        Object.defineProperty(target, "fixed", {
+            value: 1,
+            writable: false,
+            configurable: false
+        });
+        var proxy = new Proxy(target, {
+            get: function(target, key) {
+                return 2;
+            }
+        });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { proxy.fixed } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn constructor_errors() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Proxy({}, {}) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Proxy(1, {}) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Proxy({}, null) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn function_target() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function add(a, b) {
+            return a + b;
+        }
+        function Point(x) {
+            this.x = x;
+        }
+        var plain = new Proxy(add, {});
+        var scaled = new Proxy(add, {
+            apply: function(target, thisArg, args) {
+                return target.apply(thisArg, args) * 10;
+            }
+        });
+        var point = new Proxy(Point, {});
+        var made = new Proxy(Point, {
+            construct: function(target, args, newTarget) {
+                return { args: args.join(), same: newTarget === made };
+            }
+        });
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "typeof plain"), "\"function\"");
+    assert_eq!(
+        forward(&mut context, "typeof new Proxy({}, {})"),
+        "\"object\""
+    );
+    assert_eq!(forward(&mut context, "plain(1, 2)"), "3");
+    assert_eq!(forward(&mut context, "scaled(1, 2)"), "30");
+    assert_eq!(forward(&mut context, "[1, 2].map(plain).join()"), "\"1,3\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "var p = new point(5); p.x + '|' + (p instanceof Point)"
+        ),
+        "\"5|true\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "var m = new made(1, 2); m.args + '|' + m.same"
+        ),
+        "\"1,2|true\""
+    );
+}
+
+#[test]
+fn function_target_errors() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function check(f) {
+            try {
+                f();
+                return "no error";
+            } catch (e) {
+                return e.constructor.name;
+            }
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "check(() => new Proxy({}, {})())"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(&mut context, "check(() => new (new Proxy(() => 1, {}))())"),
+        "\"TypeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "check(() => new (new Proxy(function() {}, { construct: () => 1 }))())"
+        ),
+        "\"TypeError\""
+    );
+}
@@ -0,0 +1,75 @@
+//! This module implements the global `queueMicrotask` function.
+//!
+//! The callback is enqueued on the job queue of the context, the same queue as the reactions to
+//! settled promises, so it runs once the script being executed ends, after the jobs enqueued
+//! before it. Host code can also run the jobs with
+//! [`Context::run_jobs`](crate::Context::run_jobs).
+//!
+//! More information:
+//!  - [HTML reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#microtask-queuing
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/queueMicrotask
+
+use crate::{
+    builtins::BuiltIn, object::FunctionBuilder, property::Attribute, BoaProfiler, Context, Result,
+    Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The JavaScript `queueMicrotask` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct QueueMicrotask;
+
+impl BuiltIn for QueueMicrotask {
+    const NAME: &'static str = "queueMicrotask";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let queue_microtask = FunctionBuilder::new(context, Self::queue_microtask)
+            .name(Self::NAME)
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        (Self::NAME, queue_microtask.into(), Self::attribute())
+    }
+}
+
+impl QueueMicrotask {
+    /// `queueMicrotask( callback )`
+    ///
+    /// Enqueues a call of the callback, without arguments, on the job queue.
+    ///
+    /// If the callback throws, the error is returned by the evaluation that runs the job, and the
+    /// jobs after it stay in the queue.
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#dom-queuemicrotask
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/queueMicrotask
+    pub(crate) fn queue_microtask(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let callback = args.get(0).cloned().unwrap_or_default();
+        if !callback.is_function() {
+            return context.throw_type_error("queueMicrotask: the callback is not a function");
+        }
+
+        context.enqueue_job(move |context| context.call(&callback, &Value::undefined(), &[]));
+        Ok(Value::undefined())
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{forward, Context, Value};
+
+#[test]
+fn microtasks_run_in_order_after_the_script() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        queueMicrotask(function () { log.push(1); });
+        queueMicrotask(function () {
+            log.push(2);
+            queueMicrotask(function () { log.push(4); });
+        });
+        queueMicrotask(function () { log.push(3); });
+        log.push(0);
+        log.join()
+        "#;
+    assert_eq!(forward(&mut context, init), "\"0\"");
+    assert_eq!(forward(&mut context, "log.join()"), "\"0,1,2,3,4\"");
+}
+
+#[test]
+fn microtasks_share_the_promise_job_queue() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        Promise.resolve().then(function () { log.push("promise 1"); });
+        queueMicrotask(function () { log.push("microtask"); });
+        Promise.resolve().then(function () { log.push("promise 2"); });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"promise 1,microtask,promise 2\""
+    );
+}
+
+#[test]
+fn microtasks_from_host() {
+    let mut context = Context::new();
+    // Evaluating code runs the queued microtasks, so everything is evaluated before queueing
+    // them. `log` is a binding of the global scope, so it's read by evaluating it too.
+    let log = context.eval("var log = []; log").unwrap();
+    let queue_microtask = context.eval("queueMicrotask").unwrap();
+    let callbacks: Vec<_> = (0..3)
+        .map(|i| {
+            context
+                .eval(format!("(function () {{ log.push({}); }})", i))
+                .unwrap()
+        })
+        .collect();
+    for callback in callbacks {
+        context
+            .call(&queue_microtask, &Value::undefined(), &[callback])
+            .unwrap();
+    }
+
+    assert_eq!(
+        log.get_field("length", &mut context).unwrap().as_number(),
+        Some(0.0)
+    );
+
+    context.run_jobs().unwrap();
+    let join = log.get_field("join", &mut context).unwrap();
+    let joined = context.call(&join, &log, &[]).unwrap();
+    assert_eq!(joined.as_string().unwrap().as_str(), "0,1,2");
+}
+
+#[test]
+fn microtask_errors() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { queueMicrotask(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+
+    let error = context
+        .eval("var ran = false; queueMicrotask(function () { throw 'error'; }); queueMicrotask(function () { ran = true; });")
+        .unwrap_err();
+    assert_eq!(error.as_string().unwrap().as_str(), "error");
+    // The job after the failed one stays in the queue, and runs at the end of the next script.
+    assert_eq!(forward(&mut context, "ran"), "false");
+    assert_eq!(forward(&mut context, "ran"), "true");
+}
@@ -54,7 +54,7 @@ impl BuiltIn for Reflect {
             .function(Self::own_keys, "ownKeys", 1)
             .function(Self::prevent_extensions, "preventExtensions", 1)
             .function(Self::set, "set", 3)
-            .function(Self::set_prototype_of, "setPrototypeOf", 3)
+            .function(Self::set_prototype_of, "setPrototypeOf", 2)
             .property(
                 to_string_tag,
                 Self::NAME,
@@ -151,7 +151,7 @@ impl Reflect {
             .get(2)
             .and_then(|v| v.as_object())
             .ok_or_else(|| context.construct_type_error("property descriptor must be an object"))?
-            .to_property_descriptor(context)?;
+            .to_property_descriptor_for(target.get_own_property(&key).as_ref(), context)?;
 
         target
             .define_own_property(key, prop_desc, context)
@@ -178,7 +178,7 @@ impl Reflect {
             .ok_or_else(|| context.construct_type_error("target must be an object"))?;
         let key = args.get(1).unwrap_or(&undefined).to_property_key(context)?;
 
-        Ok(target.delete(&key).into())
+        Ok(target.delete(&key, context)?.into())
     }
 
     /// Gets a property of an object.
@@ -263,7 +263,7 @@ impl Reflect {
             .get(1)
             .unwrap_or(&Value::undefined())
             .to_property_key(context)?;
-        Ok(target.has_property(&key).into())
+        Ok(target.has_property(&key, context)?.into())
     }
 
     /// Returns `true` if the object is extensible, `false` otherwise.
@@ -146,6 +146,37 @@ fn own_keys() {
     assert_eq!(forward(&mut context, "ownKeys"), r#"[ "p" ]"#);
 }
 
+#[test]
+fn own_keys_order() {
+    let mut context = Context::new();
+
+    let init = r#"
+        let first = Symbol("first");
+        let second = Symbol("second");
+        let obj = {};
+        obj[second] = 0;
+        obj.b = 1;
+        obj[10] = 2;
+        obj.a = 3;
+        obj[first] = 4;
+        obj[2] = 5;
+        obj.c = 6;
+        delete obj.b;
+        obj.b = 7;
+        let keys = Reflect.ownKeys(obj);
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "keys.length"), "7");
+    assert_eq!(
+        forward(&mut context, "keys.slice(0, 5).join()"),
+        "\"2,10,a,c,b\""
+    );
+    assert_eq!(forward(&mut context, "keys[5] === second"), "true");
+    assert_eq!(forward(&mut context, "keys[6] === first"), "true");
+}
+
 #[test]
 fn prevent_extensions() {
     let mut context = Context::new();
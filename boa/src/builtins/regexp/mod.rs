@@ -10,18 +10,24 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp
 
 use crate::{
-    builtins::BuiltIn,
+    builtins::{init_lazy, Array, BuiltIn},
     gc::{empty_trace, Finalize, Trace},
-    object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData, PROTOTYPE},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData, PROTOTYPE},
     property::{Attribute, DataDescriptor},
     symbol::WellKnownSymbols,
-    value::{RcString, Value},
+    value::Value,
     BoaProfiler, Context, Result,
 };
-use regress::Regex;
+use named_groups::{desugar_named_groups, GroupNames};
+use regexp_string_iterator::RegExpStringIterator;
+use regress::{Match, Regex};
+use unicode_escapes::desugar_unicode_escapes;
 
+mod named_groups;
+pub mod regexp_string_iterator;
 #[cfg(test)]
 mod tests;
+mod unicode_escapes;
 
 /// The internal representation on a `RegExp` object.
 #[derive(Debug, Clone, Finalize)]
@@ -29,6 +35,9 @@ pub struct RegExp {
     /// Regex matcher.
     matcher: Regex,
 
+    /// The names of the named capture groups, with the index of their capture group.
+    group_names: GroupNames,
+
     /// Update last_index, set if global or sticky flags are set.
     use_last_index: bool,
 
@@ -54,7 +63,7 @@ pub struct RegExp {
     unicode: bool,
 
     pub(crate) original_source: Box<str>,
-    original_flags: Box<str>,
+    pub(crate) original_flags: Box<str>,
 }
 
 // Only safe while regress::Regex doesn't implement Trace itself.
@@ -138,6 +147,21 @@ impl BuiltIn for RegExp {
             (WellKnownSymbols::search(), "[Symbol.search]"),
             1,
         )
+        .method(
+            Self::r#match,
+            (WellKnownSymbols::match_(), "[Symbol.match]"),
+            1,
+        )
+        .method(
+            Self::match_all,
+            (WellKnownSymbols::match_all(), "[Symbol.matchAll]"),
+            1,
+        )
+        .method(
+            Self::split,
+            (WellKnownSymbols::split(), "[Symbol.split]"),
+            2,
+        )
         .accessor("global", Some(get_global), None, flag_attributes)
         .accessor("ignoreCase", Some(get_ignore_case), None, flag_attributes)
         .accessor("multiline", Some(get_multiline), None, flag_attributes)
@@ -166,6 +190,10 @@ impl RegExp {
         args: &[Value],
         ctx: &mut Context,
     ) -> Result<Value> {
+        // Creating a regular expression, like `String.prototype.match` does, doesn't need the
+        // global `RegExp`, so it may not be initialized yet.
+        init_lazy(ctx, Self::NAME);
+
         let prototype = new_target
             .as_object()
             .and_then(|obj| {
@@ -242,7 +270,19 @@ impl RegExp {
             sorted_flags.push('y');
         }
 
-        let matcher = match Regex::with_flags(&regex_body, sorted_flags.as_str()) {
+        let (matcher_source, group_names) =
+            match desugar_named_groups(&regex_body).and_then(|(source, group_names)| {
+                Ok((desugar_unicode_escapes(&source, unicode)?, group_names))
+            }) {
+                Err(error) => {
+                    return Err(
+                        ctx.construct_syntax_error(format!("failed to create matcher: {}", error))
+                    );
+                }
+                Ok(val) => val,
+            };
+
+        let matcher = match Regex::with_flags(&matcher_source, sorted_flags.as_str()) {
             Err(error) => {
                 return Err(
                     ctx.construct_syntax_error(format!("failed to create matcher: {}", error.text))
@@ -253,6 +293,7 @@ impl RegExp {
 
         let regexp = RegExp {
             matcher,
+            group_names,
             use_last_index: global || sticky,
             flags: sorted_flags.into_boxed_str(),
             dot_all,
@@ -270,6 +311,94 @@ impl RegExp {
         Ok(this)
     }
 
+    /// Finds the first match of the regular expression in `text` that starts at the index `start`
+    /// or later, like `RegExpBuiltinExec` does with `start` as the `lastIndex`.
+    ///
+    /// The index is in UTF-16 code units, while the ranges of the match are byte offsets. If the
+    /// regular expression is sticky, only a match starting exactly at `start` is found.
+    fn find_at(&self, text: &str, start: usize) -> Option<Match> {
+        // The matcher can't start in the middle of a surrogate pair, so the index is rounded up to
+        // the end of the character it's in.
+        let mut units = 0;
+        let mut offset = text.len();
+        for (index, ch) in text.char_indices() {
+            if units >= start {
+                offset = index;
+                break;
+            }
+            units += ch.len_utf16();
+        }
+        if units < start || (self.sticky && units != start) {
+            return None;
+        }
+        self.find_at_offset(text, offset)
+    }
+
+    /// Finds the first match of the regular expression in `text` that starts at the byte offset
+    /// `offset` or later, or exactly at `offset` if the regular expression is sticky.
+    fn find_at_offset(&self, text: &str, offset: usize) -> Option<Match> {
+        self.matcher
+            .find_from(text, offset)
+            .next()
+            .filter(|m| !self.sticky || m.start() == offset)
+    }
+
+    /// Finds all the matches of the regular expression in `text`, like calling `exec` until it
+    /// returns `null` does for a global regular expression whose `lastIndex` is 0.
+    fn find_all(&self, text: &str) -> Vec<Match> {
+        let mut matches = Vec::new();
+        let mut offset = 0;
+        while offset <= text.len() {
+            let m = match self.find_at_offset(text, offset) {
+                Some(m) => m,
+                None => break,
+            };
+            // An empty match is followed by a search from the next character, so that it isn't
+            // found again.
+            offset = if m.range().is_empty() {
+                m.end() + next_char_len(text, m.end())
+            } else {
+                m.end()
+            };
+            matches.push(m);
+        }
+        matches
+    }
+
+    /// Finds the matches of the regular expression object `this` in `text` to be replaced by
+    /// `String.prototype.replace`, and updates its `lastIndex` like `RegExpExec` would.
+    ///
+    /// A global regular expression finds all of its matches from the beginning of the string,
+    /// and any other one finds its first match, from its `lastIndex` if it's sticky.
+    pub(crate) fn find_matches(
+        this: &Value,
+        text: &str,
+        context: &mut Context,
+    ) -> Result<(Vec<Match>, GroupNames)> {
+        let regex = match this.as_object() {
+            Some(object) => match object.borrow().as_regexp() {
+                Some(regex) => regex.clone(),
+                None => return Err(context.construct_type_error("value is not a RegExp")),
+            },
+            None => return Err(context.construct_type_error("value is not a RegExp")),
+        };
+
+        if regex.global {
+            // The last call to exec fails and resets lastIndex to 0 as well.
+            this.set_field("lastIndex", 0, true, context)?;
+            return Ok((regex.find_all(text), regex.group_names));
+        }
+
+        let last_index = this.get_field("lastIndex", context)?.to_length(context)?;
+        let start = if regex.use_last_index { last_index } else { 0 };
+        let m = regex.find_at(text, start);
+        if regex.use_last_index {
+            let last_index = m.as_ref().map_or(0, |m| utf16_index(text, m.end()));
+            this.set_field("lastIndex", last_index, true, context)?;
+        }
+        Ok((m.into_iter().collect(), regex.group_names))
+    }
+
     /// `get RegExp[@@species]`
     ///
     /// The RegExp[@@species] accessor property returns the RegExp constructor.
@@ -501,7 +630,7 @@ impl RegExp {
     pub(crate) fn test(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
         // 22.2.5.2.2.4 really says to use "toLength" and not "toIndex"
         let mut last_index = this.get_field("lastIndex", context)?.to_length(context)?;
-        let result = if let Some(object) = this.as_object() {
+        let (result, use_last_index) = if let Some(object) = this.as_object() {
             // 3. Let string be ? ToString(S).
             let arg_str = args
                 .get(0)
@@ -512,21 +641,22 @@ impl RegExp {
             // 4. Let match be ? RegExpExec(R, string).
             let object = object.borrow();
             if let Some(regex) = object.as_regexp() {
-                let result =
-                    if let Some(m) = regex.matcher.find_from(arg_str.as_str(), last_index).next() {
-                        if regex.use_last_index {
-                            last_index = m.end();
-                        }
-                        true
-                    } else {
-                        if regex.use_last_index {
-                            last_index = 0;
-                        }
-                        false
-                    };
+                // Only global and sticky regular expressions start matching at lastIndex.
+                let start = if regex.use_last_index { last_index } else { 0 };
+                let result = if let Some(m) = regex.find_at(arg_str.as_str(), start) {
+                    if regex.use_last_index {
+                        last_index = utf16_index(&arg_str, m.end());
+                    }
+                    true
+                } else {
+                    if regex.use_last_index {
+                        last_index = 0;
+                    }
+                    false
+                };
 
                 // 5. If match is not null, return true; else return false.
-                Ok(Value::boolean(result))
+                (Value::boolean(result), regex.use_last_index)
             } else {
                 return context
                     .throw_type_error("RegExp.prototype.exec method called on incompatible value");
@@ -536,8 +666,11 @@ impl RegExp {
             return context
                 .throw_type_error("RegExp.prototype.exec method called on incompatible value");
         };
-        this.set_field("lastIndex", Value::from(last_index), true, context)?;
-        result
+
+        if use_last_index {
+            this.set_field("lastIndex", Value::from(last_index), true, context)?;
+        }
+        Ok(result)
     }
 
     /// `RegExp.prototype.exec( string )`
@@ -556,7 +689,7 @@ impl RegExp {
         // 4. Return ? RegExpBuiltinExec(R, S).
         // 22.2.5.2.2.4 really says to use "toLength" and not "toIndex"
         let mut last_index = this.get_field("lastIndex", context)?.to_length(context)?;
-        let result = if let Some(object) = this.as_object() {
+        let (result, use_last_index) = if let Some(object) = this.as_object() {
             let object = object.borrow();
             if let Some(regex) = object.as_regexp() {
                 // 3. Let S be ? ToString(string).
@@ -566,17 +699,12 @@ impl RegExp {
                     .unwrap_or_default()
                     .to_string(context)?;
 
+                // Only global and sticky regular expressions start matching at lastIndex.
+                let start = if regex.use_last_index { last_index } else { 0 };
                 let result = {
-                    if last_index > arg_str.len() {
+                    if let Some(m) = regex.find_at(arg_str.as_str(), start) {
                         if regex.use_last_index {
-                            last_index = 0;
-                        }
-                        Value::null()
-                    } else if let Some(m) =
-                        regex.matcher.find_from(arg_str.as_str(), last_index).next()
-                    {
-                        if regex.use_last_index {
-                            last_index = m.end();
+                            last_index = utf16_index(&arg_str, m.end());
                         }
                         let groups = m.captures.len() + 1;
                         let mut result = Vec::with_capacity(groups);
@@ -593,10 +721,33 @@ impl RegExp {
                         let result = Value::from(result);
                         result.set_property(
                             "index",
-                            DataDescriptor::new(m.start(), Attribute::all()),
+                            DataDescriptor::new(utf16_index(&arg_str, m.start()), Attribute::all()),
+                        );
+                        result.set_property(
+                            "input",
+                            DataDescriptor::new(arg_str.clone(), Attribute::all()),
                         );
+
+                        // If R contains any GroupName, then let groups be OrdinaryObjectCreate(null).
+                        // Else, let groups be undefined.
+                        let groups = if regex.group_names.is_empty() {
+                            Value::undefined()
+                        } else {
+                            let groups = Value::object(Object::default());
+                            for (name, group) in regex.group_names.iter() {
+                                let value = m
+                                    .group(*group)
+                                    .and_then(|range| arg_str.get(range))
+                                    .map_or_else(Value::undefined, Value::from);
+                                groups.set_property(
+                                    name.as_ref(),
+                                    DataDescriptor::new(value, Attribute::all()),
+                                );
+                            }
+                            groups
+                        };
                         result
-                            .set_property("input", DataDescriptor::new(arg_str, Attribute::all()));
+                            .set_property("groups", DataDescriptor::new(groups, Attribute::all()));
                         result
                     } else {
                         if regex.use_last_index {
@@ -606,18 +757,23 @@ impl RegExp {
                     }
                 };
 
-                Ok(result)
+                (result, regex.use_last_index)
             } else {
                 // 2. Perform ? RequireInternalSlot(R, [[RegExpMatcher]]).
-                context
-                    .throw_type_error("RegExp.prototype.exec method called on incompatible value")
+                return context
+                    .throw_type_error("RegExp.prototype.exec method called on incompatible value");
             }
         } else {
             return context.throw_type_error("exec method called on incompatible value");
         };
 
-        this.set_field("lastIndex", Value::from(last_index), true, context)?;
-        result
+        // The lastIndex of regular expressions that are neither global nor sticky is left as is.
+        // An empty match leaves lastIndex where the match ends, so loops calling exec have to
+        // advance it themselves, like the iterator of `String.prototype.matchAll` does.
+        if use_last_index {
+            this.set_field("lastIndex", Value::from(last_index), true, context)?;
+        }
+        Ok(result)
     }
 
     /// `RegExp.prototype[ @@match ]( string )`
@@ -630,11 +786,16 @@ impl RegExp {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@match
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@match
-    pub(crate) fn r#match(this: &Value, arg: RcString, context: &mut Context) -> Result<Value> {
-        let (matcher, flags) = if let Some(object) = this.as_object() {
+    pub(crate) fn r#match(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let arg = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+        let regex = if let Some(object) = this.as_object() {
             let object = object.borrow();
             if let Some(regex) = object.as_regexp() {
-                (regex.matcher.clone(), regex.flags.clone())
+                regex.clone()
             } else {
                 return context
                     .throw_type_error("RegExp.prototype.exec method called on incompatible value");
@@ -643,15 +804,19 @@ impl RegExp {
             return context
                 .throw_type_error("RegExp.prototype.match method called on incompatible value");
         };
-        if flags.contains('g') {
-            let mut matches = Vec::new();
-            for mat in matcher.find_iter(&arg) {
-                matches.push(Value::from(&arg[mat.range()]));
-            }
+        if regex.global {
+            // A global match starts at the beginning of the string, and always ends with a failed
+            // match that resets lastIndex.
+            this.set_field("lastIndex", 0, true, context)?;
+            let matches: Vec<Value> = regex
+                .find_all(&arg)
+                .into_iter()
+                .map(|mat| Value::from(&arg[mat.range()]))
+                .collect();
             if matches.is_empty() {
                 return Ok(Value::null());
             }
-            Ok(Value::from(matches))
+            Ok(Array::create_array_from_list(matches, context))
         } else {
             Self::exec(this, &[Value::from(arg)], context)
         }
@@ -697,55 +862,55 @@ impl RegExp {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-regexp-prototype-matchall
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@matchAll
-    // TODO: it's returning an array, it should return an iterator
-    pub(crate) fn match_all(this: &Value, arg_str: String, context: &mut Context) -> Result<Value> {
-        let matches = if let Some(object) = this.as_object() {
-            let object = object.borrow();
-            if let Some(regex) = object.as_regexp() {
-                let mut matches = Vec::new();
-
-                for mat in regex.matcher.find_iter(&arg_str) {
-                    let match_vec: Vec<Value> = mat
-                        .groups()
-                        .map(|group| match group {
-                            Some(range) => Value::from(&arg_str[range]),
-                            None => Value::undefined(),
-                        })
-                        .collect();
-
-                    let match_val = Value::from(match_vec);
-
-                    match_val
-                        .set_property("index", DataDescriptor::new(mat.start(), Attribute::all()));
-                    match_val.set_property(
-                        "input",
-                        DataDescriptor::new(arg_str.clone(), Attribute::all()),
-                    );
-                    matches.push(match_val);
-
-                    if !regex.flags.contains('g') {
-                        break;
-                    }
-                }
-
-                matches
-            } else {
-                return context.throw_type_error(
-                    "RegExp.prototype.match_all method called on incompatible value",
-                );
-            }
-        } else {
+    pub(crate) fn match_all(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let R be the this value.
+        // 2. If Type(R) is not Object, throw a TypeError exception.
+        if !this.is_object() {
             return context.throw_type_error(
-                "RegExp.prototype.match_all method called on incompatible value",
+                "RegExp.prototype[Symbol.matchAll] method called on incompatible value",
             );
-        };
+        }
 
-        let length = matches.len();
-        let result = Value::from(matches);
-        result.set_field("length", Value::from(length), false, context)?;
-        result.set_data(ObjectData::Array);
+        // 3. Let S be ? ToString(string).
+        let arg_str = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
 
-        Ok(result)
+        // 4. Let C be ? SpeciesConstructor(R, %RegExp%).
+        // 5. Let flags be ? ToString(? Get(R, "flags")).
+        let flags = this.get_field("flags", context)?.to_string(context)?;
+
+        // 6. Let matcher be ? Construct(C, « R, flags »).
+        let matcher = Self::constructor(
+            &context
+                .standard_objects()
+                .regexp_object()
+                .constructor()
+                .into(),
+            &[this.clone(), Value::from(flags.clone())],
+            context,
+        )?;
+
+        // 7. Let lastIndex be ? ToLength(? Get(R, "lastIndex")).
+        let last_index = this.get_field("lastIndex", context)?.to_length(context)?;
+
+        // 8. Perform ? Set(matcher, "lastIndex", lastIndex, true).
+        matcher.set_field("lastIndex", last_index, true, context)?;
+
+        // 9. If flags contains "g", let global be true.
+        // 10. Else, let global be false.
+        let global = flags.contains('g');
+
+        // 11. If flags contains "u", let fullUnicode be true.
+        // 12. Else, let fullUnicode be false.
+        let unicode = flags.contains('u');
+
+        // 13. Return ! CreateRegExpStringIterator(matcher, S, global, fullUnicode).
+        Ok(RegExpStringIterator::create_regexp_string_iterator(
+            context, matcher, arg_str, global, unicode,
+        ))
     }
 
     /// `RegExp.prototype[ @@search ]( string )`
@@ -805,4 +970,145 @@ impl RegExp {
                 .map_err(|_| context.construct_type_error("Could not find property `index`"))
         }
     }
+
+    /// `RegExp.prototype[ @@split ]( string, limit )`
+    ///
+    /// This method splits a string into an array of substrings at the matches of this regular
+    /// expression, with the captures of each match spliced in between them.
+    ///
+    /// The regular expression is matched directly, instead of through a new sticky regular
+    /// expression created with the species constructor, so its `lastIndex` is left unchanged.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-regexp.prototype-@@split
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@split
+    pub(crate) fn split(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let rx be the this value.
+        // 2. If Type(rx) is not Object, throw a TypeError exception.
+        let matcher = match this.as_object() {
+            Some(object) => match object.borrow().as_regexp() {
+                Some(regex) => regex.matcher.clone(),
+                None => {
+                    return context.throw_type_error(
+                        "RegExp.prototype[Symbol.split] method called on incompatible value",
+                    )
+                }
+            },
+            None => {
+                return context.throw_type_error(
+                    "RegExp.prototype[Symbol.split] method called on incompatible value",
+                )
+            }
+        };
+
+        // 3. Let S be ? ToString(string).
+        let arg_str = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+
+        // 14. If limit is undefined, let lim be 2^32 - 1; else let lim be ℝ(? ToUint32(limit)).
+        let limit = match args.get(1) {
+            None | Some(Value::Undefined) => u32::MAX,
+            Some(limit) => limit.to_u32(context)?,
+        } as usize;
+
+        let substrings = Self::split_substrings(&matcher, &arg_str, limit);
+        let array = Array::new_array(context);
+        Array::construct_array(&array, &substrings, context)
+    }
+
+    /// Splits `arg_str` at the matches of `matcher`, into at most `limit` substrings and captures.
+    fn split_substrings(matcher: &Regex, arg_str: &str, limit: usize) -> Vec<Value> {
+        // 15. Let p be 0.
+        // 16. If lim is 0, return A.
+        let mut substrings = Vec::new();
+        if limit == 0 {
+            return substrings;
+        }
+
+        // 18. If size is 0, then
+        let size = arg_str.len();
+        if size == 0 {
+            // a. Let z be ? RegExpExec(splitter, S).
+            // b. If z is not null, return A.
+            // d. Perform ! CreateDataPropertyOrThrow(A, "0", S).
+            if matcher.find_from(arg_str, 0).next().is_none() {
+                substrings.push(Value::from(arg_str));
+            }
+            return substrings;
+        }
+
+        // 19. Let q be p.
+        let mut last_end = 0;
+        let mut search_start = 0;
+        // 20. Repeat, while q < size,
+        while search_start < size {
+            // a. Perform ? Set(splitter, "lastIndex", 𝔽(q), true).
+            // b. Let z be ? RegExpExec(splitter, S).
+            // c. If z is null, set q to AdvanceStringIndex(S, q, unicodeMatching).
+            // The first match at or after `q` is found at once, instead of trying every index.
+            let m = match matcher.find_from(arg_str, search_start).next() {
+                Some(m) if m.start() < size => m,
+                _ => break,
+            };
+
+            // d. Else,
+            //    i. Let e be ℝ(? ToLength(? Get(splitter, "lastIndex"))).
+            //    ii. Set e to min(e, size).
+            let end = m.end().min(size);
+
+            //    iii. If e = p, set q to AdvanceStringIndex(S, q, unicodeMatching).
+            if end == last_end {
+                search_start = m.start() + next_char_len(&arg_str, m.start());
+                continue;
+            }
+
+            //    iv. Else,
+            //        1. Let T be the substring of S from p to q.
+            //        2. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
+            //        4. If lengthA = lim, return A.
+            substrings.push(Value::from(&arg_str[last_end..m.start()]));
+            if substrings.len() == limit {
+                return substrings;
+            }
+
+            //        5. Set p to e.
+            //        6-11. Splice the captures of the match into A.
+            last_end = end;
+            for group in 1..=m.captures.len() {
+                let capture = m
+                    .group(group)
+                    .map_or_else(Value::undefined, |range| Value::from(&arg_str[range]));
+                substrings.push(capture);
+                if substrings.len() == limit {
+                    return substrings;
+                }
+            }
+
+            //        12. Set q to p.
+            search_start = last_end;
+        }
+
+        // 21. Let T be the substring of S from p to size.
+        // 22. Perform ! CreateDataPropertyOrThrow(A, ! ToString(𝔽(lengthA)), T).
+        substrings.push(Value::from(&arg_str[last_end..]));
+
+        // 23. Return A.
+        substrings
+    }
+}
+
+/// The length in bytes of the character of `string` at `index`, or 1 at the end of the string.
+fn next_char_len(string: &str, index: usize) -> usize {
+    string[index..].chars().next().map_or(1, char::len_utf8)
+}
+
+/// Converts the byte offset `offset` of `string` into an index in UTF-16 code units.
+pub(crate) fn utf16_index(string: &str, offset: usize) -> usize {
+    string[..offset].encode_utf16().count()
 }
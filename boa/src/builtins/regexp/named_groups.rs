@@ -0,0 +1,131 @@
+//! Support for named capture groups and named backreferences.
+//!
+//! The matcher only knows about numbered capture groups, so before a pattern is compiled every
+//! `(?<name>...)` group is turned into a plain `(...)` group and every `\k<name>` backreference
+//! into the numbered backreference of the group with that name.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-GroupSpecifier
+
+/// The names of the named capture groups of a pattern, with the index of their capture group.
+pub(crate) type GroupNames = Box<[(Box<str>, usize)]>;
+
+/// Rewrites the named groups and named backreferences of `pattern` into numbered ones.
+///
+/// Returns the rewritten pattern, with the names of the groups of the pattern in source order.
+/// A duplicate group name, a malformed group name or a backreference to a group that doesn't
+/// exist is an error.
+pub(crate) fn desugar_named_groups(pattern: &str) -> Result<(String, GroupNames), String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let group_names = collect_group_names(&chars)?;
+
+    // Without named groups `\k` is an identity escape, so there is nothing to rewrite.
+    if group_names.is_empty() {
+        return Ok((pattern.to_owned(), group_names));
+    }
+
+    let mut result = String::with_capacity(pattern.len());
+    let mut in_class = false;
+    let mut index = 0;
+    while index < chars.len() {
+        match chars[index] {
+            '\\' if !in_class && chars.get(index + 1) == Some(&'k') => {
+                if chars.get(index + 2) != Some(&'<') {
+                    return Err("invalid named reference".to_owned());
+                }
+                let (name, end) = parse_group_name(&chars, index + 3)?;
+                let group = group_names
+                    .iter()
+                    .find(|(group_name, _)| **group_name == *name)
+                    .map(|(_, group)| *group)
+                    .ok_or_else(|| format!("invalid named capture referenced: {}", name))?;
+
+                // The group keeps the number from being merged with a following digit.
+                result.push_str(&format!("(?:\\{})", group));
+                index = end;
+                continue;
+            }
+            '\\' => {
+                result.push('\\');
+                if let Some(escaped) = chars.get(index + 1) {
+                    result.push(*escaped);
+                }
+                index += 2;
+                continue;
+            }
+            '[' => in_class = true,
+            ']' => in_class = false,
+            '(' if !in_class && is_named_group(&chars, index) => {
+                let (_, end) = parse_group_name(&chars, index + 3)?;
+                result.push('(');
+                index = end;
+                continue;
+            }
+            _ => {}
+        }
+        result.push(chars[index]);
+        index += 1;
+    }
+
+    Ok((result, group_names))
+}
+
+/// Collects the names of the named capture groups of the pattern.
+fn collect_group_names(chars: &[char]) -> Result<GroupNames, String> {
+    let mut group_names: Vec<(Box<str>, usize)> = Vec::new();
+    let mut group_count = 0;
+    let mut in_class = false;
+    let mut index = 0;
+    while index < chars.len() {
+        match chars[index] {
+            '\\' => index += 1,
+            '[' => in_class = true,
+            ']' => in_class = false,
+            '(' if !in_class => {
+                if is_named_group(chars, index) {
+                    group_count += 1;
+                    let (name, _) = parse_group_name(chars, index + 3)?;
+                    if group_names
+                        .iter()
+                        .any(|(group_name, _)| *group_name == name)
+                    {
+                        return Err(format!("duplicate capture group name: {}", name));
+                    }
+                    group_names.push((name, group_count));
+                } else if chars.get(index + 1) != Some(&'?') {
+                    group_count += 1;
+                }
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    Ok(group_names.into_boxed_slice())
+}
+
+/// Checks if the group opened at `index` is a named group, and not a lookbehind assertion.
+fn is_named_group(chars: &[char], index: usize) -> bool {
+    chars.get(index + 1) == Some(&'?')
+        && chars.get(index + 2) == Some(&'<')
+        && !matches!(chars.get(index + 3), Some('=') | Some('!'))
+}
+
+/// Parses the group name starting at `start`, up to the closing `>`.
+///
+/// Returns the name and the index after the closing `>`.
+fn parse_group_name(chars: &[char], start: usize) -> Result<(Box<str>, usize), String> {
+    let mut name = String::new();
+    for (index, &c) in chars.iter().enumerate().skip(start) {
+        match c {
+            '>' if !name.is_empty() => return Ok((name.into_boxed_str(), index + 1)),
+            '$' | '_' => name.push(c),
+            c if c.is_alphabetic() || (c.is_alphanumeric() && !name.is_empty()) => name.push(c),
+            _ => break,
+        }
+    }
+
+    Err("invalid capture group name".to_owned())
+}
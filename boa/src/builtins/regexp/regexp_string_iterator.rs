@@ -0,0 +1,195 @@
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object, RegExp},
+    gc::{Finalize, Trace},
+    object::{GcObject, ObjectData},
+    property::{Attribute, DataDescriptor},
+    symbol::WellKnownSymbols,
+    value::RcString,
+    BoaProfiler, Context, Result, Value,
+};
+
+/// The RegExp String Iterator object represents an iteration over the matches of a regular
+/// expression in a string. It implements the iterator protocol.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-regexp-string-iterator-objects
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct RegExpStringIterator {
+    matcher: Value,
+    string: RcString,
+    global: bool,
+    unicode: bool,
+    completed: bool,
+}
+
+impl RegExpStringIterator {
+    pub(crate) const NAME: &'static str = "RegExpStringIterator";
+
+    fn new(matcher: Value, string: RcString, global: bool, unicode: bool) -> Self {
+        Self {
+            matcher,
+            string,
+            global,
+            unicode,
+            completed: false,
+        }
+    }
+
+    /// Abstract operation CreateRegExpStringIterator( R, S, global, fullUnicode )
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createregexpstringiterator
+    pub(crate) fn create_regexp_string_iterator(
+        context: &Context,
+        matcher: Value,
+        string: RcString,
+        global: bool,
+        unicode: bool,
+    ) -> Value {
+        let regexp_string_iterator = Value::new_object(context);
+        regexp_string_iterator.set_data(ObjectData::RegExpStringIterator(Self::new(
+            matcher, string, global, unicode,
+        )));
+        regexp_string_iterator
+            .as_object()
+            .expect("regexp string iterator object")
+            .set_prototype_instance(
+                context
+                    .iterator_prototypes()
+                    .regexp_string_iterator()
+                    .into(),
+            );
+        regexp_string_iterator
+    }
+
+    /// %RegExpStringIteratorPrototype%.next( )
+    ///
+    /// Advances the iterator and gets the next match of the regular expression.
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%regexpstringiteratorprototype%.next
+    pub(crate) fn next(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let iterator = if let Value::Object(ref object) = this {
+            if let Some(iterator) = object.borrow().as_regexp_string_iterator() {
+                iterator.clone()
+            } else {
+                return context.throw_type_error("`this` is not a RegExpStringIterator");
+            }
+        } else {
+            return context.throw_type_error("`this` is not a RegExpStringIterator");
+        };
+
+        if iterator.completed {
+            return Ok(create_iter_result_object(context, Value::undefined(), true));
+        }
+
+        // i. Let match be ? RegExpExec(R, S).
+        let m = RegExp::exec(
+            &iterator.matcher,
+            &[Value::from(iterator.string.clone())],
+            context,
+        )?;
+
+        // ii. If match is null, then
+        if m.is_null() {
+            // 1. Return undefined.
+            Self::set_completed(this);
+            return Ok(create_iter_result_object(context, Value::undefined(), true));
+        }
+
+        // iii. Else,
+        // 1. If global is false, then
+        if !iterator.global {
+            // a. Perform ? Yield(match).
+            // b. Return undefined.
+            Self::set_completed(this);
+            return Ok(create_iter_result_object(context, m, false));
+        }
+
+        // 2. Let matchStr be ? ToString(? Get(match, "0")).
+        let match_str = m.get_field("0", context)?.to_string(context)?;
+
+        // 3. If matchStr is the empty String, then
+        if match_str.is_empty() {
+            // a. Let thisIndex be ℝ(? ToLength(? Get(R, "lastIndex"))).
+            let this_index = iterator
+                .matcher
+                .get_field("lastIndex", context)?
+                .to_length(context)?;
+
+            // b. Let nextIndex be ! AdvanceStringIndex(S, thisIndex, fullUnicode).
+            let next_index = advance_string_index(&iterator.string, this_index, iterator.unicode);
+
+            // c. Perform ? Set(R, "lastIndex", 𝔽(nextIndex), true).
+            iterator
+                .matcher
+                .set_field("lastIndex", next_index, true, context)?;
+        }
+
+        // 4. Perform ? Yield(match).
+        Ok(create_iter_result_object(context, m, false))
+    }
+
+    fn set_completed(this: &Value) {
+        if let Value::Object(ref object) = this {
+            if let Some(iterator) = object.borrow_mut().as_regexp_string_iterator_mut() {
+                iterator.completed = true;
+            }
+        }
+    }
+
+    /// Create the %RegExpStringIteratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMA reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%regexpstringiteratorprototype%-object
+    pub(crate) fn create_prototype(context: &mut Context, iterator_prototype: Value) -> GcObject {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        // Create prototype
+        let mut regexp_string_iterator = context.construct_object();
+        make_builtin_fn(Self::next, "next", &regexp_string_iterator, 0, context);
+        regexp_string_iterator.set_prototype_instance(iterator_prototype);
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+        let to_string_tag_property = DataDescriptor::new(
+            "RegExp String Iterator",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+        regexp_string_iterator.insert(to_string_tag, to_string_tag_property);
+        regexp_string_iterator
+    }
+}
+
+/// Abstract operation AdvanceStringIndex( S, index, unicode )
+///
+/// The index is in UTF-16 code units. In `unicode` mode, a surrogate pair is skipped at once.
+///
+/// More information:
+///  - [ECMA reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-advancestringindex
+pub(crate) fn advance_string_index(string: &str, index: usize, unicode: bool) -> usize {
+    // 2. If unicode is false, return index + 1.
+    if !unicode {
+        return index + 1;
+    }
+
+    // 3-6. Let cp be ! CodePointAt(S, index), and return index + cp.[[CodeUnitCount]].
+    let mut code_units = string.encode_utf16().skip(index);
+    match (code_units.next(), code_units.next()) {
+        (Some(lead), Some(trail))
+            if (0xD800..0xDC00).contains(&lead) && (0xDC00..0xE000).contains(&trail) =>
+        {
+            index + 2
+        }
+        _ => index + 1,
+    }
+}
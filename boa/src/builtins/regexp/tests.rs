@@ -56,7 +56,7 @@ fn species() {
         "\"function\""
     );
     assert_eq!(forward(&mut context, "descriptor.enumerable"), "false");
-    assert_eq!(forward(&mut context, "descriptor.writable"), "false");
+    assert_eq!(forward(&mut context, "descriptor.writable"), "undefined");
     assert_eq!(forward(&mut context, "descriptor.configurable"), "true");
 }
 
@@ -125,6 +125,63 @@ fn exec() {
     );
 }
 
+#[test]
+fn exec_last_index() {
+    let mut context = Context::new();
+    let init = r#"
+        var re = /o+/g;
+        var matches = [];
+        var m;
+        while ((m = re.exec("foo boo zoo")) !== null) {
+            matches.push(m[0] + "@" + m.index + ":" + re.lastIndex);
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "matches.join('|')"),
+        "\"oo@1:3|oo@5:7|oo@9:11\""
+    );
+    assert_eq!(forward(&mut context, "re.lastIndex"), "0");
+
+    forward(&mut context, "re.lastIndex = 20;");
+    assert_eq!(forward(&mut context, "re.exec('foo')"), "null");
+    assert_eq!(forward(&mut context, "re.lastIndex"), "0");
+
+    // An empty match doesn't move lastIndex, so the loop has to advance it.
+    let empty = r#"
+        var empty = /a*/g;
+        var found = [];
+        while ((m = empty.exec("baa")) !== null) {
+            found.push(m[0] + "@" + m.index);
+            if (m[0] === "") {
+                empty.lastIndex++;
+            }
+        }
+        found.join("|")
+        "#;
+    assert_eq!(forward(&mut context, empty), "\"@0|aa@1|@3\"");
+
+    // The lastIndex of regular expressions that are neither global nor sticky isn't written.
+    forward(&mut context, "var plain = /o/; plain.lastIndex = -1;");
+    assert_eq!(forward(&mut context, "plain.exec('foo').index"), "1");
+    assert_eq!(forward(&mut context, "plain.lastIndex"), "-1");
+    assert_eq!(forward(&mut context, "plain.test('bar')"), "false");
+    assert_eq!(forward(&mut context, "plain.lastIndex"), "-1");
+    forward(
+        &mut context,
+        "Object.defineProperty(plain, 'lastIndex', { writable: false });",
+    );
+    assert_eq!(forward(&mut context, "plain.test('foo')"), "true");
+
+    // A global match starts at the beginning of the string and resets lastIndex.
+    forward(&mut context, "re.lastIndex = 5;");
+    assert_eq!(
+        forward(&mut context, "'foo boo'.match(re).join()"),
+        "\"oo,oo\""
+    );
+    assert_eq!(forward(&mut context, "re.lastIndex"), "0");
+}
+
 #[test]
 fn to_string() {
     let mut context = Context::new();
@@ -238,3 +295,230 @@ fn search() {
     assert_eq!(forward(&mut context, "/u/[Symbol.search](null)"), "1");
     assert_eq!(forward(&mut context, "/d/[Symbol.search](undefined)"), "2");
 }
+
+#[test]
+fn named_groups() {
+    let mut context = Context::new();
+    let init = r#"
+        var date = "2024-01-02".match(/(?<y>\d{4})-(?<m>\d{2})/);
+        var alternatives = /(?<first>a)|(?<second>b)/.exec("b");
+        "#;
+
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(forward(&mut context, "date.groups.y === \"2024\""), "true");
+    assert_eq!(forward(&mut context, "date.groups.m"), "\"01\"");
+    assert_eq!(forward(&mut context, "date[1]"), "\"2024\"");
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(date.groups)"),
+        "null"
+    );
+    assert_eq!(
+        forward(&mut context, "alternatives.groups.first"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut context, "alternatives.groups.second"), "\"b\"");
+    assert_eq!(
+        forward(&mut context, "/(a)(b)/.exec('ab').groups"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "/(?<=a)b/.exec('ab').groups"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "/(?<x>[(])(\\d)/.exec('(1')[2]"),
+        "\"1\""
+    );
+}
+
+#[test]
+fn named_backreferences() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "/(?<quote>['\"]).*\\k<quote>/.test(`'a'`)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "/(?<quote>['\"]).*\\k<quote>/.test(`'a\"`)"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "/(?<a>x)\\k<a>1/.test('xx1')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "/(?<a>x)\\k<a>1/.test('xx')"),
+        "false"
+    );
+}
+
+#[test]
+fn named_groups_syntax_errors() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new RegExp('(?<a>x)(?<a>y)'); } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new RegExp('(?<a>x)\\\\k<b>'); } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new RegExp('(?<1a>x)'); } catch (e) { e instanceof SyntaxError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn sticky() {
+    let mut context = Context::new();
+    let init = r#"
+        var tokenizer = /[a-z]+|[0-9]+|\s+|=/y;
+        var tokens = [];
+        var token;
+        while ((token = tokenizer.exec("let x1 = 42")) !== null) {
+            tokens.push(token[0] + "@" + token.index);
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "tokens.join('|')"),
+        "\"let@0| @3|x@4|1@5| @6|=@7| @8|42@9\""
+    );
+    assert_eq!(forward(&mut context, "tokenizer.lastIndex"), "0");
+
+    forward(&mut context, "var re = /foo/y; re.lastIndex = 3;");
+    assert_eq!(forward(&mut context, "re.test('barfoo')"), "true");
+    assert_eq!(forward(&mut context, "re.lastIndex"), "6");
+    forward(&mut context, "re.lastIndex = 1;");
+    assert_eq!(forward(&mut context, "re.test('barfoo')"), "false");
+    assert_eq!(forward(&mut context, "re.lastIndex"), "0");
+    assert_eq!(forward(&mut context, "/foo/y.exec('a foo')"), "null");
+
+    // Regular expressions that are neither global nor sticky ignore lastIndex.
+    forward(&mut context, "var plain = /a/; plain.lastIndex = 5;");
+    assert_eq!(forward(&mut context, "plain.exec('bab').index"), "1");
+    assert_eq!(forward(&mut context, "plain.lastIndex"), "5");
+}
+
+#[test]
+fn sticky_match_and_replace() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "'xay'.replace(/a/y, 'Q')"), "\"xay\"");
+    assert_eq!(
+        forward(&mut context, "'aaxa'.replace(/a/gy, '-')"),
+        "\"--xa\""
+    );
+    assert_eq!(
+        forward(&mut context, "'a-b-c'.replaceAll(/-/gy, '+')"),
+        "\"a-b-c\""
+    );
+
+    forward(&mut context, "var re = /a/y; re.lastIndex = 1;");
+    assert_eq!(forward(&mut context, "'xay'.replace(re, 'Q')"), "\"xQy\"");
+    assert_eq!(forward(&mut context, "re.lastIndex"), "2");
+
+    assert_eq!(
+        forward(&mut context, "'aaxa'.match(/a/gy).join()"),
+        "\"a,a\""
+    );
+    assert_eq!(forward(&mut context, "'xa'.match(/a/y)"), "null");
+    forward(&mut context, "re.lastIndex = 1;");
+    assert_eq!(forward(&mut context, "'xa'.match(re).index"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "[...'aaxa'.matchAll(/a/gy)].map(m => m.index).join()"
+        ),
+        "\"0,1\""
+    );
+}
+
+#[test]
+fn last_index_is_in_utf16_code_units() {
+    let mut context = Context::new();
+
+    forward(&mut context, "var re = /é/y; re.lastIndex = 1;");
+    assert_eq!(forward(&mut context, "re.exec('éé').index"), "1");
+    assert_eq!(forward(&mut context, "re.lastIndex"), "2");
+
+    forward(
+        &mut context,
+        "var dot = /./gu; dot.exec('a😀'); dot.exec('a😀');",
+    );
+    assert_eq!(forward(&mut context, "dot.lastIndex"), "3");
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "[...'é😀aé'.matchAll(/a|é/g)].map(m => m.index).join()"
+        ),
+        "\"0,3,4\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[...'😀😀'.matchAll(/(?:)/gu)].map(m => m.index).join()"
+        ),
+        "\"0,2,4\""
+    );
+    assert_eq!(
+        forward(&mut context, "'😀x😀'.replace(/x/, (m, p) => p)"),
+        "\"😀2😀\""
+    );
+}
+
+#[test]
+fn unicode() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "/^.$/u.test('\\u{1F600}')"), "true");
+    assert_eq!(forward(&mut context, "/^\\u{1F600}$/u.test('😀')"), "true");
+    assert_eq!(
+        forward(&mut context, "/^\\uD83D\\uDE00$/u.test('😀')"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "/\\u0041\\u002B/.test('A+')"), "true");
+    assert_eq!(
+        forward(&mut context, "/[\\u0041-\\u005A]+/.exec('abcXYZ')[0]"),
+        "\"XYZ\""
+    );
+    // Without the `u` flag, `\u{2}` is `u` repeated twice.
+    assert_eq!(forward(&mut context, "/^\\u{2}$/.test('uu')"), "true");
+
+    // Identity escapes of letters are only allowed without the `u` flag.
+    assert_eq!(forward(&mut context, "/\\a/.test('a')"), "true");
+    for source in &[
+        "\\\\a",
+        "\\\\u{110000}",
+        "\\\\u00",
+        "\\\\c1",
+        "[\\\\B]",
+        "\\\\-",
+    ] {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!(
+                    "try {{ new RegExp('{}', 'u'); }} catch (e) {{ e instanceof SyntaxError }}",
+                    source
+                )
+            ),
+            "true",
+            "{}",
+            source
+        );
+    }
+}
@@ -0,0 +1,141 @@
+//! Support for Unicode escapes and for the stricter escapes of the `u` flag.
+//!
+//! The matcher works on code points and doesn't know about `\u` escapes, so before a pattern is
+//! compiled every `\uXXXX` escape, surrogate pair of escapes and, with the `u` flag, `\u{X}` escape
+//! is turned into the code point it stands for. With the `u` flag, escapes that are only allowed
+//! for web compatibility, like `\a`, are an error.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#prod-RegExpUnicodeEscapeSequence
+
+/// A pattern that never matches, for escapes of lone surrogates, which the matcher can't match.
+const NEVER_MATCHES: &str = "[^\\s\\S]";
+
+/// Rewrites the Unicode escapes of `pattern` into the code points they stand for.
+///
+/// If `unicode` is `true`, the pattern uses the `u` flag, so `\u{X}` escapes are rewritten too and
+/// malformed or unknown escapes are an error.
+pub(crate) fn desugar_unicode_escapes(pattern: &str, unicode: bool) -> Result<String, String> {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut result = String::with_capacity(pattern.len());
+    let mut in_class = false;
+    let mut index = 0;
+    while index < chars.len() {
+        match chars[index] {
+            '\\' if chars.get(index + 1) == Some(&'u') => {
+                match parse_unicode_escape(&chars, index, unicode) {
+                    Some((code_point, end)) => {
+                        push_code_point(&mut result, code_point, in_class);
+                        index = end;
+                    }
+                    None if unicode => return Err("invalid unicode escape".to_owned()),
+                    // Without the `u` flag a malformed `\u` escape is an identity escape.
+                    None => {
+                        result.push_str("\\u");
+                        index += 2;
+                    }
+                }
+                continue;
+            }
+            '\\' => {
+                if unicode {
+                    check_escape(&chars, index + 1, in_class)?;
+                }
+                result.push('\\');
+                if let Some(escaped) = chars.get(index + 1) {
+                    result.push(*escaped);
+                }
+                index += 2;
+                continue;
+            }
+            '[' => in_class = true,
+            ']' => in_class = false,
+            _ => {}
+        }
+        result.push(chars[index]);
+        index += 1;
+    }
+
+    Ok(result)
+}
+
+/// Parses the `\u` escape starting at `start`, with a trailing surrogate escape if there is one.
+///
+/// Returns the code point, which may be a lone surrogate, and the index after the escape.
+fn parse_unicode_escape(chars: &[char], start: usize, unicode: bool) -> Option<(u32, usize)> {
+    if unicode && chars.get(start + 2) == Some(&'{') {
+        let digits = chars[start + 3..].iter().take_while(|c| **c != '}').count();
+        if digits == 0 || chars.get(start + 3 + digits).is_none() {
+            return None;
+        }
+        let code_point = parse_hex(&chars[start + 3..start + 3 + digits])?;
+        return if code_point <= 0x10FFFF {
+            Some((code_point, start + 4 + digits))
+        } else {
+            None
+        };
+    }
+
+    let unit = parse_hex(chars.get(start + 2..start + 6)?)?;
+    if (0xD800..0xDC00).contains(&unit)
+        && chars.get(start + 6) == Some(&'\\')
+        && chars.get(start + 7) == Some(&'u')
+    {
+        if let Some(trail) = chars.get(start + 8..start + 12).and_then(parse_hex) {
+            if (0xDC00..0xE000).contains(&trail) {
+                let code_point = 0x10000 + ((unit - 0xD800) << 10) + (trail - 0xDC00);
+                return Some((code_point, start + 12));
+            }
+        }
+    }
+    Some((unit, start + 6))
+}
+
+fn parse_hex(digits: &[char]) -> Option<u32> {
+    if digits.len() > 6 {
+        return None;
+    }
+    digits
+        .iter()
+        .try_fold(0, |value, digit| Some(value * 16 + digit.to_digit(16)?))
+}
+
+/// Pushes a code point to the rewritten pattern, escaping it if it has a meaning in patterns.
+fn push_code_point(result: &mut String, code_point: u32, in_class: bool) {
+    match std::char::from_u32(code_point) {
+        Some(c) if c.is_ascii() && !c.is_ascii_alphanumeric() => {
+            result.push_str(&format!("\\x{:02x}", code_point))
+        }
+        Some(c) => result.push(c),
+        None if in_class => {}
+        None => result.push_str(NEVER_MATCHES),
+    }
+}
+
+/// Checks that the escape whose character is at `index` is allowed with the `u` flag.
+fn check_escape(chars: &[char], index: usize, in_class: bool) -> Result<(), String> {
+    let valid = match chars.get(index) {
+        None => false,
+        Some('^') | Some('$') | Some('\\') | Some('.') | Some('*') | Some('+') | Some('?')
+        | Some('(') | Some(')') | Some('[') | Some(']') | Some('{') | Some('}') | Some('|')
+        | Some('/') => true,
+        Some('-') => in_class,
+        Some('d') | Some('D') | Some('w') | Some('W') | Some('s') | Some('S') | Some('b')
+        | Some('f') | Some('n') | Some('r') | Some('t') | Some('v') => true,
+        Some('B') => !in_class,
+        Some('c') => matches!(chars.get(index + 1), Some(c) if c.is_ascii_alphabetic()),
+        Some('x') => chars
+            .get(index + 1..index + 3)
+            .map_or(false, |digits| digits.iter().all(|c| c.is_ascii_hexdigit())),
+        Some('0') => !matches!(chars.get(index + 1), Some(c) if c.is_ascii_digit()),
+        Some(c) => c.is_ascii_digit() && !in_class,
+    };
+
+    if valid {
+        Ok(())
+    } else {
+        Err("invalid escape".to_owned())
+    }
+}
@@ -11,7 +11,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set
 
 use crate::{
-    builtins::{iterable::get_iterator, BuiltIn},
+    builtins::{init_all_lazy, iterable::get_iterator, BuiltIn},
     object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
     property::Attribute,
     symbol::WellKnownSymbols,
@@ -322,6 +322,7 @@ impl Set {
         let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
         // TODO: if condition should also check that we are not in strict mode
         let this_arg = if this_arg.is_undefined() {
+            init_all_lazy(context);
             Value::Object(context.global_object())
         } else {
             this_arg
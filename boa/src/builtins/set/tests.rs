@@ -246,3 +246,33 @@ fn not_a_function() {
         "\"TypeError: calling a builtin Set constructor without new is forbidden\""
     );
 }
+
+#[test]
+fn iteration_order_after_delete() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set(["a", "b", "c"]);
+        set.delete("a");
+        set.add("d");
+        set.add("a");
+        set.add("b");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "[...set].join()"), "\"b,c,d,a\"");
+    assert_eq!(
+        forward(&mut context, "[...set.entries()].join(' ')"),
+        "\"b,b c,c d,d a,a\""
+    );
+}
+
+#[test]
+fn same_value_zero_values() {
+    let mut context = Context::new();
+    let init = r#"
+        let set = new Set([NaN, 0 / 0, 0, -0]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "set.size"), "2");
+    assert_eq!(forward(&mut context, "set.has(NaN)"), "true");
+    assert_eq!(forward(&mut context, "set.has(-0)"), "true");
+}
@@ -17,19 +17,22 @@ use crate::builtins::Symbol;
 use crate::object::PROTOTYPE;
 use crate::property::DataDescriptor;
 use crate::{
-    builtins::{string::string_iterator::StringIterator, Array, BuiltIn, RegExp},
-    object::{ConstructorBuilder, Object, ObjectData},
+    builtins::{
+        init_lazy, intl::Collator, string::string_iterator::StringIterator, Array, BuiltIn, RegExp,
+    },
+    object::{ConstructorBuilder, FunctionBuilder, Object, ObjectData},
     property::Attribute,
     symbol::WellKnownSymbols,
-    value::{RcString, Value},
+    value::{IntegerOrInfinity, RcString, Value},
     BoaProfiler, Context, Result,
 };
-use regress::Regex;
+use regress::Match;
 use std::{
-    char::{decode_utf16, from_u32},
-    cmp::{max, min},
+    char::from_u32,
+    cmp::{max, min, Ordering},
     string::String as StdString,
 };
+use unicode_normalization::UnicodeNormalization;
 
 pub(crate) fn code_point_at(string: RcString, position: i32) -> Option<(u32, u8, bool)> {
     let size = string.encode_utf16().count() as i32;
@@ -97,6 +100,20 @@ impl BuiltIn for String {
 
         let symbol_iterator = WellKnownSymbols::iterator();
 
+        let trim_start = FunctionBuilder::new(context, Self::trim_start)
+            .name("trimStart")
+            .length(0)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        let trim_end = FunctionBuilder::new(context, Self::trim_end)
+            .name("trimEnd")
+            .length(0)
+            .callable(true)
+            .constructable(false)
+            .build();
+
         let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
         let string_object = ConstructorBuilder::with_standard_object(
             context,
@@ -106,6 +123,7 @@ impl BuiltIn for String {
         .name(Self::NAME)
         .length(Self::LENGTH)
         .property("length", 0, attribute)
+        .method(Self::at, "at", 1)
         .method(Self::char_at, "charAt", 1)
         .method(Self::char_code_at, "charCodeAt", 1)
         .method(Self::code_point_at, "codePointAt", 1)
@@ -122,18 +140,41 @@ impl BuiltIn for String {
         .method(Self::pad_end, "padEnd", 1)
         .method(Self::pad_start, "padStart", 1)
         .method(Self::trim, "trim", 0)
-        .method(Self::trim_start, "trimStart", 0)
-        .method(Self::trim_end, "trimEnd", 0)
+        .property(
+            "trimStart",
+            trim_start.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .property(
+            "trimLeft",
+            trim_start,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .property(
+            "trimEnd",
+            trim_end.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .property(
+            "trimRight",
+            trim_end,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
         .method(Self::to_lowercase, "toLowerCase", 0)
         .method(Self::to_uppercase, "toUpperCase", 0)
+        .method(Self::normalize, "normalize", 0)
+        .method(Self::locale_compare, "localeCompare", 1)
         .method(Self::substring, "substring", 2)
         .method(Self::substr, "substr", 2)
         .method(Self::split, "split", 2)
         .method(Self::value_of, "valueOf", 0)
         .method(Self::match_all, "matchAll", 1)
         .method(Self::replace, "replace", 2)
+        .method(Self::replace_all, "replaceAll", 2)
         .method(Self::iterator, (symbol_iterator, "[Symbol.iterator]"), 0)
         .method(Self::search, "search", 1)
+        .static_method(Self::raw, "raw", 1)
+        .static_method(Self::from_code_point, "fromCodePoint", 1)
         .build();
 
         (Self::NAME, string_object.into(), Self::attribute())
@@ -201,6 +242,121 @@ impl String {
         Ok(this)
     }
 
+    /// `String.fromCodePoint( ...codePoints )`
+    ///
+    /// The static `String.fromCodePoint()` method returns a string created from a sequence of code
+    /// points.
+    ///
+    /// It throws a `RangeError` if a code point isn't an integer between `0` and `0x10FFFF`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.fromcodepoint
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint
+    pub(crate) fn from_code_point(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1. Let result be the empty String.
+        let mut result = Vec::with_capacity(args.len());
+
+        // 2. For each element next of codePoints, do
+        for next in args {
+            // a. Let nextCP be ? ToNumber(next).
+            let next_cp = next.to_number(context)?;
+
+            // b. If ! IsIntegralNumber(nextCP) is false, throw a RangeError exception.
+            // c. If ℝ(nextCP) < 0 or ℝ(nextCP) > 0x10FFFF, throw a RangeError exception.
+            if next_cp.fract() != 0.0 || !(0.0..=f64::from(0x10FFFF)).contains(&next_cp) {
+                return context.throw_range_error(format!("invalid code point: {}", next_cp));
+            }
+
+            // d. Set result to the string-concatenation of result and ! UTF16Encoding(ℝ(nextCP)).
+            let next_cp = next_cp as u32;
+            if next_cp < 0x10000 {
+                result.push(next_cp as u16);
+            } else {
+                let next_cp = next_cp - 0x10000;
+                result.push(0xD800 + (next_cp >> 10) as u16);
+                result.push(0xDC00 + (next_cp & 0x3FF) as u16);
+            }
+        }
+
+        // 3. Assert: If codePoints is empty, then result is the empty String.
+        // 4. Return result.
+        Ok(StdString::from_utf16_lossy(&result).into())
+    }
+
+    /// `String.raw( template, ...substitutions )`
+    ///
+    /// The static `String.raw()` method is the tag function of template literals, it returns the raw
+    /// string form of a template, with the substitutions interleaved.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.raw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw
+    pub(crate) fn raw(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let substitutions = args.get(1..).unwrap_or(&[]);
+
+        // 1. Let numberOfSubstitutions be the number of elements in substitutions.
+        // 2. Let cooked be ? ToObject(template).
+        let cooked = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_object(context)?;
+
+        // 3. Let raw be ? ToObject(? Get(cooked, "raw")).
+        let raw = Value::from(cooked).get_field("raw", context)?;
+        let raw = Value::from(raw.to_object(context)?);
+
+        // 4. Let literalSegments be ? LengthOfArrayLike(raw).
+        let literal_segments = raw.get_field("length", context)?.to_length(context)?;
+
+        // 5. If literalSegments ≤ 0, return the empty String.
+        if literal_segments == 0 {
+            return Ok(Value::from(""));
+        }
+
+        // 6. Let stringElements be a new empty List.
+        let mut string_elements = StdString::new();
+
+        // 7. Let nextIndex be 0.
+        // 8. Repeat,
+        for next_index in 0..literal_segments {
+            // a. Let nextKey be ! ToString(𝔽(nextIndex)).
+            // b. Let nextSeg be ? ToString(? Get(raw, nextKey)).
+            let next_seg = raw.get_field(next_index, context)?.to_string(context)?;
+
+            // c. Append the code unit elements of nextSeg to the end of stringElements.
+            string_elements.push_str(&next_seg);
+
+            // d. If nextIndex + 1 = literalSegments, then
+            if next_index + 1 == literal_segments {
+                break;
+            }
+
+            // e. If nextIndex < numberOfSubstitutions, let next be substitutions[nextIndex].
+            // f. Else, let next be the empty String.
+            // g. Let nextSub be ? ToString(next).
+            // h. Append the code unit elements of nextSub to the end of stringElements.
+            if let Some(next) = substitutions.get(next_index) {
+                string_elements.push_str(&next.to_string(context)?);
+            }
+
+            // i. Set nextIndex to nextIndex + 1.
+        }
+
+        // d. i. Return the String value whose code units are the elements of stringElements.
+        Ok(Value::from(string_elements))
+    }
+
     fn this_string_value(this: &Value, context: &mut Context) -> Result<RcString> {
         match this {
             Value::String(ref string) => return Ok(string.clone()),
@@ -224,6 +380,57 @@ impl String {
         Ok(Value::from(Self::this_string_value(this, context)?))
     }
 
+    /// `String.prototype.at( index )`
+    ///
+    /// The `at()` method returns a new string consisting of the single UTF-16 code unit at the
+    /// given index, counting back from the end of the string for negative indices.
+    ///
+    /// It returns `undefined` if the index is out of range.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-relative-indexing-method/#sec-string.prototype.at
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/at
+    pub(crate) fn at(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        // 2. Let S be ? ToString(O).
+        let string = this.require_object_coercible(context)?.to_string(context)?;
+        let code_units: Vec<u16> = string.encode_utf16().collect();
+
+        // 3. Let len be the length of S.
+        let len = code_units.len() as u64;
+
+        // 4. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        let relative_index = args
+            .get(0)
+            .cloned()
+            .unwrap_or_else(Value::undefined)
+            .to_integer_or_infinity(context)?;
+
+        // 5. If relativeIndex ≥ 0, then
+        //     a. Let k be relativeIndex.
+        // 6. Else,
+        //     a. Let k be len + relativeIndex.
+        let k = match relative_index {
+            IntegerOrInfinity::Integer(i) if i >= 0 => i as u64,
+            IntegerOrInfinity::Integer(i) => match len.checked_sub(i.unsigned_abs()) {
+                Some(k) => k,
+                None => return Ok(Value::undefined()),
+            },
+            _ => return Ok(Value::undefined()),
+        };
+
+        // 7. If k < 0 or k ≥ len, return undefined.
+        if k >= len {
+            return Ok(Value::undefined());
+        }
+
+        // 8. Return the String value consisting of only the code unit at position k in S.
+        Ok(StdString::from_utf16_lossy(&code_units[k as usize..=k as usize]).into())
+    }
+
     /// `String.prototype.charAt( index )`
     ///
     /// The `String` object's `charAt()` method returns a new string consisting of the single UTF-16 code unit located at the specified offset into the string.
@@ -285,9 +492,9 @@ impl String {
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the context has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(context)?;
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        // 2. Let S be ? ToString(O).
+        let primitive_val = this.require_object_coercible(context)?.to_string(context)?;
         let pos = args
             .get(0)
             .cloned()
@@ -399,7 +606,8 @@ impl String {
                 return context.throw_range_error("repeat count cannot be infinity");
             }
 
-            if n * (string.len() as f64) > Self::MAX_STRING_LENGTH {
+            // The length of a string is measured in UTF-16 code units.
+            if n * (string.encode_utf16().count() as f64) > Self::MAX_STRING_LENGTH {
                 return context
                     .throw_range_error("repeat count must not overflow maximum string length");
             }
@@ -420,44 +628,51 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.slice
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/slice
     pub(crate) fn slice(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the context has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(context)?;
-
-        // Calling .len() on a string would give the wrong result, as they are bytes not the number of unicode code points
-        // Note that this is an O(N) operation (because UTF-8 is complex) while getting the number of bytes is an O(1) operation.
-        let length = primitive_val.chars().count() as i32;
-
-        let start = args
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        // 2. Let S be ? ToString(O).
+        let string = this.require_object_coercible(context)?.to_string(context)?;
+        let code_units: Vec<u16> = string.encode_utf16().collect();
+
+        // 3. Let len be the length of S.
+        let len = code_units.len() as i64;
+
+        // 4. Let intStart be ? ToIntegerOrInfinity(start).
+        // 5. If intStart is -∞, let from be 0.
+        // 6. Else if intStart < 0, let from be max(len + intStart, 0).
+        // 7. Else, let from be min(intStart, len).
+        let from = match args
             .get(0)
             .cloned()
             .unwrap_or_else(Value::undefined)
-            .to_integer(context)? as i32;
-        let end = args
-            .get(1)
-            .cloned()
-            .unwrap_or_else(|| Value::integer(length))
-            .to_integer(context)? as i32;
-
-        let from = if start < 0 {
-            max(length.wrapping_add(start), 0)
-        } else {
-            min(start, length)
+            .to_integer_or_infinity(context)?
+        {
+            IntegerOrInfinity::NegativeInfinity => 0,
+            IntegerOrInfinity::Integer(i) if i < 0 => max(len + i, 0),
+            IntegerOrInfinity::Integer(i) => min(i, len),
+            IntegerOrInfinity::PositiveInfinity => len,
         };
-        let to = if end < 0 {
-            max(length.wrapping_add(end), 0)
-        } else {
-            min(end, length)
+
+        // 8. If end is undefined, let intEnd be len; else let intEnd be ? ToIntegerOrInfinity(end).
+        // 9. If intEnd is -∞, let to be 0.
+        // 10. Else if intEnd < 0, let to be max(len + intEnd, 0).
+        // 11. Else, let to be min(intEnd, len).
+        let to = match args.get(1) {
+            None | Some(Value::Undefined) => len,
+            Some(end) => match end.to_integer_or_infinity(context)? {
+                IntegerOrInfinity::NegativeInfinity => 0,
+                IntegerOrInfinity::Integer(i) if i < 0 => max(len + i, 0),
+                IntegerOrInfinity::Integer(i) => min(i, len),
+                IntegerOrInfinity::PositiveInfinity => len,
+            },
         };
 
-        let span = max(to.wrapping_sub(from), 0);
+        // 12. If from ≥ to, return the empty String.
+        if from >= to {
+            return Ok("".into());
+        }
 
-        let new_str: StdString = primitive_val
-            .chars()
-            .skip(from as usize)
-            .take(span as usize)
-            .collect();
-        Ok(Value::from(new_str))
+        // 13. Return the substring of S from from to to.
+        Ok(StdString::from_utf16_lossy(&code_units[from as usize..to as usize]).into())
     }
 
     /// `String.prototype.startWith( searchString[, position] )`
@@ -475,42 +690,41 @@ impl String {
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the context has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(context)?;
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible(context)?;
 
-        let arg = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        // 2. Let S be ? ToString(O).
+        let string: Vec<u16> = this.to_string(context)?.encode_utf16().collect();
 
-        if Self::is_regexp_object(&arg) {
-            context.throw_type_error(
+        // 3. Let isRegExp be ? IsRegExp(searchString).
+        // 4. If isRegExp is true, throw a TypeError exception.
+        let search_string = args.get(0).cloned().unwrap_or_default();
+        if Self::is_regexp(&search_string, context)? {
+            return context.throw_type_error(
                 "First argument to String.prototype.startsWith must not be a regular expression",
-            )?;
+            );
         }
 
-        let search_string = arg.to_string(context)?;
-
-        let length = primitive_val.chars().count() as i32;
-        let search_length = search_string.chars().count() as i32;
-
-        // If less than 2 args specified, position is 'undefined', defaults to 0
-        let position = if args.len() < 2 {
-            0
-        } else {
-            args.get(1)
-                .expect("failed to get arg")
-                .to_integer(context)? as i32
-        };
-
-        let start = min(max(position, 0), length);
-        let end = start.wrapping_add(search_length);
+        // 5. Let searchStr be ? ToString(searchString).
+        let search_string: Vec<u16> = search_string.to_string(context)?.encode_utf16().collect();
 
-        if end > length {
-            Ok(Value::from(false))
-        } else {
-            // Only use the part of the string from "start"
-            let this_string: StdString = primitive_val.chars().skip(start as usize).collect();
-            Ok(Value::from(this_string.starts_with(search_string.as_str())))
-        }
+        // 6. Let len be the length of S.
+        // 7. NOTE: If position is undefined, this step produces the value 0.
+        // 8. Let pos be ? ToIntegerOrInfinity(position).
+        // 9. Let start be the result of clamping pos between 0 and len.
+        let start = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_integer(context)?
+            .max(0.0)
+            .min(string.len() as f64) as usize;
+
+        // 10. Let searchLength be the length of searchStr.
+        // 11. If searchLength + start > len, return false.
+        // 12. Let substring be the substring of S from start to start + searchLength.
+        // 13. Return ! SameValueNonNumeric(substring, searchStr).
+        Ok(Value::from(string[start..].starts_with(&search_string)))
     }
 
     /// `String.prototype.endsWith( searchString[, length] )`
@@ -524,43 +738,41 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.endswith
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/endsWith
     pub(crate) fn ends_with(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the context has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(context)?;
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible(context)?;
 
-        let arg = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        // 2. Let S be ? ToString(O).
+        let string: Vec<u16> = this.to_string(context)?.encode_utf16().collect();
 
-        if Self::is_regexp_object(&arg) {
-            context.throw_type_error(
+        // 3. Let isRegExp be ? IsRegExp(searchString).
+        // 4. If isRegExp is true, throw a TypeError exception.
+        let search_string = args.get(0).cloned().unwrap_or_default();
+        if Self::is_regexp(&search_string, context)? {
+            return context.throw_type_error(
                 "First argument to String.prototype.endsWith must not be a regular expression",
-            )?;
+            );
         }
 
-        let search_string = arg.to_string(context)?;
-
-        let length = primitive_val.chars().count() as i32;
-        let search_length = search_string.chars().count() as i32;
-
-        // If less than 2 args specified, end_position is 'undefined', defaults to
-        // length of this
-        let end_position = if args.len() < 2 {
-            length
-        } else {
-            args.get(1)
-                .expect("Could not get argumetn")
-                .to_integer(context)? as i32
+        // 5. Let searchStr be ? ToString(searchString).
+        let search_string: Vec<u16> = search_string.to_string(context)?.encode_utf16().collect();
+
+        // 6. Let len be the length of S.
+        // 7. If endPosition is undefined, let pos be len; else let pos be ? ToIntegerOrInfinity(endPosition).
+        // 8. Let end be the result of clamping pos between 0 and len.
+        let end = match args.get(1) {
+            None | Some(Value::Undefined) => string.len(),
+            Some(end_position) => end_position
+                .to_integer(context)?
+                .max(0.0)
+                .min(string.len() as f64) as usize,
         };
 
-        let end = min(max(end_position, 0), length);
-        let start = end.wrapping_sub(search_length);
-
-        if start < 0 {
-            Ok(Value::from(false))
-        } else {
-            // Only use the part of the string up to "end"
-            let this_string: StdString = primitive_val.chars().take(end as usize).collect();
-            Ok(Value::from(this_string.ends_with(search_string.as_str())))
-        }
+        // 9. Let searchLength be the length of searchStr.
+        // 10. Let start be end - searchLength.
+        // 11. If start < 0, return false.
+        // 12. Let substring be the substring of S from start to end.
+        // 13. Return ! SameValueNonNumeric(substring, searchStr).
+        Ok(Value::from(string[..end].ends_with(&search_string)))
     }
 
     /// `String.prototype.includes( searchString[, position] )`
@@ -574,54 +786,75 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.includes
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/includes
     pub(crate) fn includes(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the context has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(context)?;
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible(context)?;
 
-        let arg = args.get(0).cloned().unwrap_or_else(Value::undefined);
+        // 2. Let S be ? ToString(O).
+        let string: Vec<u16> = this.to_string(context)?.encode_utf16().collect();
 
-        if Self::is_regexp_object(&arg) {
-            context.throw_type_error(
+        // 3. Let isRegExp be ? IsRegExp(searchString).
+        // 4. If isRegExp is true, throw a TypeError exception.
+        let search_string = args.get(0).cloned().unwrap_or_default();
+        if Self::is_regexp(&search_string, context)? {
+            return context.throw_type_error(
                 "First argument to String.prototype.includes must not be a regular expression",
-            )?;
+            );
         }
 
-        let search_string = arg.to_string(context)?;
+        // 5. Let searchStr be ? ToString(searchString).
+        let search_string: Vec<u16> = search_string.to_string(context)?.encode_utf16().collect();
 
-        let length = primitive_val.chars().count() as i32;
+        // 6. Let pos be ? ToIntegerOrInfinity(position).
+        // 7. Assert: If position is undefined, then pos is 0.
+        // 8. Let len be the length of S.
+        // 9. Let start be the result of clamping pos between 0 and len.
+        let start = args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_integer(context)?
+            .max(0.0)
+            .min(string.len() as f64) as usize;
+
+        // 10. Let index be ! StringIndexOf(S, searchStr, start).
+        // 11. If index is not -1, return true.
+        // 12. Return false.
+        Ok(Value::from(
+            search_string.is_empty()
+                || string[start..]
+                    .windows(search_string.len())
+                    .any(|window| window == search_string.as_slice()),
+        ))
+    }
 
-        // If less than 2 args specified, position is 'undefined', defaults to 0
-        let position = if args.len() < 2 {
-            0
-        } else {
-            args.get(1)
-                .expect("Could not get argument")
-                .to_integer(context)? as i32
+    /// `IsRegExp ( argument )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isregexp
+    fn is_regexp(argument: &Value, context: &mut Context) -> Result<bool> {
+        // 1. If Type(argument) is not Object, return false.
+        let object = match argument {
+            Value::Object(ref object) => object,
+            _ => return Ok(false),
         };
 
-        let start = min(max(position, 0), length);
-
-        // Take the string from "this" and use only the part of it after "start"
-        let this_string: StdString = primitive_val.chars().skip(start as usize).collect();
-
-        Ok(Value::from(this_string.contains(search_string.as_str())))
-    }
-
-    /// Return either the string itself or the string of the regex equivalent
-    fn get_regex_string(value: &Value) -> StdString {
-        match value {
-            Value::String(ref body) => body.to_string(),
-            Value::Object(ref obj) => {
-                let obj = obj.borrow();
+        // 2. Let matcher be ? Get(argument, @@match).
+        let matcher = object.get(
+            &WellKnownSymbols::match_().into(),
+            argument.clone(),
+            context,
+        )?;
 
-                if let Some(regexp) = obj.as_regexp() {
-                    // first argument is another `RegExp` object, so copy its pattern and flags
-                    return regexp.original_source.clone().into();
-                }
-                "undefined".to_string()
-            }
-            _ => "undefined".to_string(),
+        // 3. If matcher is not undefined, return ! ToBoolean(matcher).
+        if !matcher.is_undefined() {
+            return Ok(matcher.to_boolean());
         }
+
+        // 4. If argument has a [[RegExpMatcher]] internal slot, return true.
+        // 5. Return false.
+        Ok(object.borrow().is_regexp())
     }
 
     fn is_regexp_object(value: &Value) -> bool {
@@ -647,149 +880,318 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.replace
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replace
     pub(crate) fn replace(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        // TODO: Support Symbol replacer
-        let primitive_val = this.to_string(context)?;
-        if args.is_empty() {
-            return Ok(Value::from(primitive_val));
-        }
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let o = this.require_object_coercible(context)?;
+
+        let search_value = args.get(0).cloned().unwrap_or_default();
+        let replace_value = args.get(1).cloned().unwrap_or_default();
+
+        // 2. If searchValue is neither undefined nor null, then
+        //   a. Let replacer be ? GetMethod(searchValue, @@replace).
+        // TODO: `RegExp.prototype[@@replace]` is not implemented, so the matches are collected here.
+        let is_regexp = Self::is_regexp_object(&search_value);
 
-        let regex_body = Self::get_regex_string(args.get(0).expect("Value needed"));
-        let re = Regex::new(&regex_body).expect("unable to convert regex to regex object");
-        let mat = match re.find(&primitive_val) {
-            Some(mat) => mat,
-            None => return Ok(Value::from(primitive_val)),
+        // 3. Let string be ? ToString(O).
+        let string = o.to_string(context)?;
+
+        let (matches, group_names) = if is_regexp {
+            // A global regular expression replaces all of its matches, starting from the
+            // beginning of the string.
+            let (matches, group_names) = RegExp::find_matches(&search_value, &string, context)?;
+            (matches, group_names.into_vec())
+        } else {
+            // 4. Let searchString be ? ToString(searchValue).
+            let search_string = search_value.to_string(context)?;
+
+            // 7. Let searchLength be the length of searchString.
+            // 8. Let position be ! StringIndexOf(string, searchString, 0).
+            // 9. If position is -1, return string.
+            let matches = string
+                .find(search_string.as_str())
+                .map(|start| Match {
+                    range: start..start + search_string.len(),
+                    captures: Vec::new(),
+                })
+                .into_iter()
+                .collect();
+            (matches, Vec::new())
         };
-        let caps = re
-            .find(&primitive_val)
-            .expect("unable to get capture groups from text")
-            .captures;
-
-        let replace_value = if args.len() > 1 {
-            // replace_object could be a string or function or not exist at all
-            let replace_object: &Value = args.get(1).expect("second argument expected");
-            match replace_object {
-                Value::String(val) => {
-                    // https://tc39.es/ecma262/#table-45
-                    let mut result = StdString::new();
-                    let mut chars = val.chars().peekable();
-
-                    let m = caps.len();
-
-                    while let Some(first) = chars.next() {
-                        if first == '$' {
-                            let second = chars.next();
-                            let second_is_digit = second.map_or(false, |ch| ch.is_digit(10));
-                            // we use peek so that it is still in the iterator if not used
-                            let third = if second_is_digit { chars.peek() } else { None };
-                            let third_is_digit = third.map_or(false, |ch| ch.is_digit(10));
-
-                            match (second, third) {
-                                (Some('$'), _) => {
-                                    // $$
-                                    result.push('$');
-                                }
-                                (Some('&'), _) => {
-                                    // $&
-                                    result.push_str(&primitive_val[mat.range()]);
-                                }
-                                (Some('`'), _) => {
-                                    // $`
-                                    let start_of_match = mat.start();
-                                    result.push_str(&primitive_val[..start_of_match]);
-                                }
-                                (Some('\''), _) => {
-                                    // $'
-                                    let end_of_match = mat.end();
-                                    result.push_str(&primitive_val[end_of_match..]);
-                                }
-                                (Some(second), Some(third))
-                                    if second_is_digit && third_is_digit =>
-                                {
-                                    // $nn
-                                    let tens = second.to_digit(10).unwrap() as usize;
-                                    let units = third.to_digit(10).unwrap() as usize;
-                                    let nn = 10 * tens + units;
-                                    if nn == 0 || nn > m {
-                                        result.push(first);
-                                        result.push(second);
-                                        if let Some(ch) = chars.next() {
-                                            result.push(ch);
-                                        }
-                                    } else {
-                                        let group = match mat.group(nn) {
-                                            Some(range) => &primitive_val[range.clone()],
-                                            _ => "",
-                                        };
-                                        result.push_str(group);
-                                        chars.next(); // consume third
-                                    }
-                                }
-                                (Some(second), _) if second_is_digit => {
-                                    // $n
-                                    let n = second.to_digit(10).unwrap() as usize;
-                                    if n == 0 || n > m {
-                                        result.push(first);
-                                        result.push(second);
-                                    } else {
-                                        let group = match mat.group(n) {
-                                            Some(range) => &primitive_val[range.clone()],
-                                            _ => "",
-                                        };
-                                        result.push_str(group);
-                                    }
-                                }
-                                (Some('<'), _) => {
-                                    // $<
-                                    // TODO: named capture groups
-                                    result.push_str("$<");
-                                }
-                                _ => {
-                                    // $?, ? is none of the above
-                                    // we can consume second because it isn't $
-                                    result.push(first);
-                                    if let Some(second) = second {
-                                        result.push(second);
-                                    }
-                                }
-                            }
-                        } else {
-                            result.push(first);
-                        }
-                    }
 
-                    result
-                }
-                Value::Object(_) => {
-                    // This will return the matched substring first, then captured parenthesized groups later
+        Self::replace_matches(&string, matches, &group_names, &replace_value, context)
+    }
+
+    /// `String.prototype.replaceAll( regexp|substr, newSubstr|function )`
+    ///
+    /// The `replaceAll()` method returns a new string with all matches of a `pattern` replaced by a `replacement`.
+    ///
+    /// The `pattern` can be a string or a global `RegExp`, and the `replacement` can be a string or a function to be called for each match.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.replaceall
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replaceAll
+    pub(crate) fn replace_all(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let o = this.require_object_coercible(context)?;
+
+        let search_value = args.get(0).cloned().unwrap_or_default();
+        let replace_value = args.get(1).cloned().unwrap_or_default();
+
+        // 2. If searchValue is neither undefined nor null, then
+        //   a. Let isRegExp be ? IsRegExp(searchValue).
+        //   b. If isRegExp is true, then
+        let is_regexp = if Self::is_regexp_object(&search_value) {
+            // i. Let flags be ? Get(searchValue, "flags").
+            let flags = search_value.get_field("flags", context)?;
+
+            // ii. Perform ? RequireObjectCoercible(flags).
+            flags.require_object_coercible(context)?;
+
+            // iii. If ? ToString(flags) does not contain "g", throw a TypeError exception.
+            if !flags.to_string(context)?.contains('g') {
+                return context.throw_type_error(
+                    "String.prototype.replaceAll called with a non-global RegExp argument",
+                );
+            }
+
+            // c. Let replacer be ? GetMethod(searchValue, @@replace).
+            // TODO: `RegExp.prototype[@@replace]` is not implemented, so the matches are collected here.
+            true
+        } else {
+            false
+        };
+
+        // 3. Let string be ? ToString(O).
+        let string = o.to_string(context)?;
+
+        let (matches, group_names) = if is_regexp {
+            let (matches, group_names) = RegExp::find_matches(&search_value, &string, context)?;
+            (matches, group_names.into_vec())
+        } else {
+            // 4. Let searchString be ? ToString(searchValue).
+            let search_string = search_value.to_string(context)?;
+
+            // 8. Let searchLength be the length of searchString.
+            // 10. Let matchPositions be a new empty List.
+            // 11. Let position be ! StringIndexOf(string, searchString, 0).
+            let mut matches = Vec::new();
+            let mut position = string.find(search_string.as_str());
+
+            // 12. Repeat, while position is not -1,
+            while let Some(start) = position {
+                // a. Append position to the end of matchPositions.
+                matches.push(Match {
+                    range: start..start + search_string.len(),
+                    captures: Vec::new(),
+                });
+
+                // 9. Let advanceBy be max(1, searchLength).
+                // b. Set position to ! StringIndexOf(string, searchString, position + advanceBy).
+                let next = if search_string.is_empty() {
+                    start + string[start..].chars().next().map_or(1, char::len_utf8)
+                } else {
+                    start + search_string.len()
+                };
+                position = string
+                    .get(next..)
+                    .and_then(|rest| rest.find(search_string.as_str()))
+                    .map(|index| next + index);
+            }
+            (matches, Vec::new())
+        };
+
+        Self::replace_matches(&string, matches, &group_names, &replace_value, context)
+    }
+
+    /// Replaces the `matches` found in `string` by `replace_value`, which is either called with the
+    /// match, its captures, its offset, `string` and its named groups, or converted to a string
+    /// and used with its `$` patterns expanded.
+    ///
+    /// This is shared by `String.prototype.replace` and `String.prototype.replaceAll`.
+    fn replace_matches(
+        string: &str,
+        matches: Vec<Match>,
+        group_names: &[(Box<str>, usize)],
+        replace_value: &Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        // Let functionalReplace be IsCallable(replaceValue).
+        // If functionalReplace is false, then
+        //   a. Set replaceValue to ? ToString(replaceValue).
+        let replace_string = if replace_value.is_function() {
+            None
+        } else {
+            Some(replace_value.to_string(context)?)
+        };
+
+        // Let endOfLastMatch be 0.
+        // Let result be the empty String.
+        let mut end_of_last_match = 0;
+        let mut result = StdString::new();
+
+        // For each element p of matchPositions, do
+        for mat in matches {
+            // a. Let preserved be the substring of string from endOfLastMatch to p.
+            let preserved = &string[end_of_last_match..mat.start()];
+
+            // b. If functionalReplace is true, then
+            let replacement = match &replace_string {
+                None => {
+                    // i. Let replacement be ? ToString(? Call(replaceValue, undefined, « searchString, 𝔽(p), string »)).
                     let mut results: Vec<Value> = mat
                         .groups()
                         .map(|group| match group {
-                            Some(range) => Value::from(&primitive_val[range]),
+                            Some(range) => Value::from(&string[range]),
                             None => Value::undefined(),
                         })
                         .collect();
+                    let position = string[..mat.start()].encode_utf16().count();
+                    results.push(Value::from(position));
+                    results.push(Value::from(string));
+                    if !group_names.is_empty() {
+                        let groups = Value::object(Object::default());
+                        for (name, group) in group_names.iter() {
+                            let value = mat
+                                .group(*group)
+                                .map_or_else(Value::undefined, |range| Value::from(&string[range]));
+                            groups.set_property(
+                                name.as_ref(),
+                                DataDescriptor::new(value, Attribute::all()),
+                            );
+                        }
+                        results.push(groups);
+                    }
+
+                    context
+                        .call(replace_value, &Value::undefined(), &results)?
+                        .to_string(context)?
+                        .to_string()
+                }
+                // c. Else,
+                //   i-iii. Let replacement be ! GetSubstitution(searchString, string, p, captures, undefined, replaceValue).
+                Some(replace_string) => {
+                    Self::get_substitution(string, &mat, group_names, replace_string)
+                }
+            };
 
-                    // Returns the starting byte offset of the match
-                    let start = mat.start();
-                    results.push(Value::from(start));
-                    // Push the whole string being examined
-                    results.push(Value::from(primitive_val.to_string()));
+            // d. Set result to the string-concatenation of result, preserved, and replacement.
+            result.push_str(preserved);
+            result.push_str(&replacement);
 
-                    let result = context.call(&replace_object, this, &results)?;
+            // e. Set endOfLastMatch to p + searchLength.
+            end_of_last_match = mat.end();
+        }
+
+        // If endOfLastMatch < the length of string, then
+        //   a. Set result to the string-concatenation of result and the substring of string from endOfLastMatch.
+        result.push_str(&string[end_of_last_match..]);
+
+        // Return result.
+        Ok(Value::from(result))
+    }
 
-                    result.to_string(context)?.to_string()
+    /// Abstract operation `GetSubstitution ( matched, str, position, captures, namedCaptures, replacement )`
+    ///
+    /// Expands the `$` replacement patterns of `replacement` for the match `mat` in `string`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getsubstitution
+    fn get_substitution(
+        string: &str,
+        mat: &Match,
+        group_names: &[(Box<str>, usize)],
+        replacement: &str,
+    ) -> StdString {
+        let m = mat.captures.len();
+        let group = |n: usize| mat.group(n).map_or("", |range| &string[range]);
+
+        // https://tc39.es/ecma262/#table-45
+        let mut result = StdString::new();
+        let mut rest = replacement;
+        while let Some(index) = rest.find('$') {
+            result.push_str(&rest[..index]);
+            rest = &rest[index + 1..];
+
+            let mut chars = rest.chars();
+            let second = chars.next();
+            let consumed = match second {
+                Some('$') => {
+                    // $$
+                    result.push('$');
+                    1
                 }
-                _ => "undefined".to_string(),
-            }
-        } else {
-            "undefined".to_string()
-        };
+                Some('&') => {
+                    // $&
+                    result.push_str(&string[mat.range()]);
+                    1
+                }
+                Some('`') => {
+                    // $`
+                    result.push_str(&string[..mat.start()]);
+                    1
+                }
+                Some('\'') => {
+                    // $'
+                    result.push_str(&string[mat.end()..]);
+                    1
+                }
+                Some(second) if second.is_ascii_digit() => {
+                    let n = second.to_digit(10).expect("second is a digit") as usize;
+                    let nn = chars
+                        .next()
+                        .and_then(|third| third.to_digit(10))
+                        .map(|third| 10 * n + third as usize);
+                    match nn {
+                        // $nn
+                        Some(nn) if nn >= 1 && nn <= m => {
+                            result.push_str(group(nn));
+                            2
+                        }
+                        // $n
+                        _ if n >= 1 && n <= m => {
+                            result.push_str(group(n));
+                            1
+                        }
+                        _ => {
+                            result.push('$');
+                            0
+                        }
+                    }
+                }
+                Some('<') if !group_names.is_empty() => {
+                    // $<
+                    if let Some(end) = rest.find('>') {
+                        let name = &rest[1..end];
+                        if let Some((_, index)) =
+                            group_names.iter().find(|(group, _)| group.as_ref() == name)
+                        {
+                            result.push_str(group(*index));
+                        }
+                        end + 1
+                    } else {
+                        result.push('$');
+                        0
+                    }
+                }
+                _ => {
+                    // $?, ? is none of the above
+                    result.push('$');
+                    0
+                }
+            };
+            rest = &rest[consumed..];
+        }
+        result.push_str(rest);
 
-        Ok(Value::from(primitive_val.replacen(
-            &primitive_val[mat.range()],
-            &replace_value,
-            1,
-        )))
+        result
     }
 
     /// `String.prototype.indexOf( searchValue[, fromIndex] )`
@@ -894,45 +1296,79 @@ impl String {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/match
     /// [regex]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions
     pub(crate) fn r#match(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let re = RegExp::constructor(
-            &Value::from(Object::default()),
-            &[args.get(0).cloned().unwrap_or_default()],
-            context,
-        )?;
-        RegExp::r#match(&re, this.to_string(context)?, context)
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible(context)?;
+
+        // 2. If regexp is neither undefined nor null, then
+        let regexp = args.get(0).cloned().unwrap_or_default();
+        if !regexp.is_null_or_undefined() {
+            // a. Let matcher be ? GetMethod(regexp, @@match).
+            // b. If matcher is not undefined, then
+            if let Some(matcher) = regexp
+                .to_object(context)?
+                .get_method(context, WellKnownSymbols::match_())?
+            {
+                // i. Return ? Call(matcher, regexp, « O »).
+                return matcher.call(&regexp, &[this.clone()], context);
+            }
+        }
+
+        // 3. Let S be ? ToString(O).
+        let s = this.to_string(context)?;
+
+        // 4. Let rx be ? RegExpCreate(regexp, undefined).
+        let rx = RegExp::constructor(&Value::from(Object::default()), &[regexp], context)?;
+
+        // 5. Return ? Invoke(rx, @@match, « S »).
+        if let Some(matcher) = rx
+            .to_object(context)?
+            .get_method(context, WellKnownSymbols::match_())?
+        {
+            matcher.call(&rx, &[Value::from(s)], context)
+        } else {
+            context.throw_type_error("regexp[Symbol.match] is not a function")
+        }
     }
 
     /// Abstract method `StringPad`.
     ///
-    /// Performs the actual string padding for padStart/End.
-    /// <https://tc39.es/ecma262/#sec-stringpad/>
+    /// Performs the actual string padding for padStart/End. Lengths are counted in UTF-16 code
+    /// units, and the fill string is repeated and truncated to fit the remaining length.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-stringpad
     fn string_pad(
         primitive: RcString,
-        max_length: i32,
+        max_length: usize,
         fill_string: Option<RcString>,
         at_start: bool,
     ) -> Value {
-        let primitive_length = primitive.len() as i32;
+        let primitive_length = primitive.encode_utf16().count();
 
         if max_length <= primitive_length {
             return Value::from(primitive);
         }
 
-        let filter = fill_string.as_deref().unwrap_or(" ");
-
-        let fill_len = max_length.wrapping_sub(primitive_length);
-        let mut fill_str = StdString::new();
-
-        while fill_str.len() < fill_len as usize {
-            fill_str.push_str(filter);
+        let filler: Vec<u16> = fill_string
+            .as_deref()
+            .unwrap_or(" ")
+            .encode_utf16()
+            .collect();
+        if filler.is_empty() {
+            return Value::from(primitive);
         }
-        // Cut to size max_length
-        let concat_fill_str: StdString = fill_str.chars().take(fill_len as usize).collect();
+
+        let fill_len = max_length - primitive_length;
+        let truncated_string_filler: Vec<u16> =
+            filler.iter().copied().cycle().take(fill_len).collect();
+        let truncated_string_filler = StdString::from_utf16_lossy(&truncated_string_filler);
 
         if at_start {
-            Value::from(format!("{}{}", concat_fill_str, &primitive))
+            Value::from(format!("{}{}", truncated_string_filler, &primitive))
         } else {
-            Value::from(format!("{}{}", primitive, &concat_fill_str))
+            Value::from(format!("{}{}", primitive, &truncated_string_filler))
         }
     }
 
@@ -949,16 +1385,21 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.padend
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/padEnd
     pub(crate) fn pad_end(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(context)?;
         let primitive = this.to_string(context)?;
-        if args.is_empty() {
-            return Err(Value::from("padEnd requires maxLength argument"));
-        }
+
         let max_length = args
             .get(0)
-            .expect("failed to get argument for String method")
-            .to_integer(context)? as i32;
+            .cloned()
+            .unwrap_or_default()
+            .to_length(context)?;
 
-        let fill_string = args.get(1).map(|arg| arg.to_string(context)).transpose()?;
+        let fill_string = match args.get(1) {
+            Some(fill_string) if !fill_string.is_undefined() => {
+                Some(fill_string.to_string(context)?)
+            }
+            _ => None,
+        };
 
         Ok(Self::string_pad(primitive, max_length, fill_string, false))
     }
@@ -976,16 +1417,21 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.padstart
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/padStart
     pub(crate) fn pad_start(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(context)?;
         let primitive = this.to_string(context)?;
-        if args.is_empty() {
-            return Err(Value::from("padStart requires maxLength argument"));
-        }
+
         let max_length = args
             .get(0)
-            .expect("failed to get argument for String method")
-            .to_integer(context)? as i32;
+            .cloned()
+            .unwrap_or_default()
+            .to_length(context)?;
 
-        let fill_string = args.get(1).map(|arg| arg.to_string(context)).transpose()?;
+        let fill_string = match args.get(1) {
+            Some(fill_string) if !fill_string.is_undefined() => {
+                Some(fill_string.to_string(context)?)
+            }
+            _ => None,
+        };
 
         Ok(Self::string_pad(primitive, max_length, fill_string, true))
     }
@@ -1021,6 +1467,7 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.trimstart
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trimStart
     pub(crate) fn trim_start(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(context)?;
         let string = this.to_string(context)?;
         Ok(Value::from(
             string.trim_start_matches(is_trimmable_whitespace),
@@ -1089,6 +1536,93 @@ impl String {
         Ok(Value::from(this_str.to_uppercase()))
     }
 
+    /// `String.prototype.normalize( [ form ] )`
+    ///
+    /// The `normalize()` method returns the Unicode Normalization Form of the string, one of
+    /// `"NFC"` (the default), `"NFD"`, `"NFKC"` or `"NFKD"`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.normalize
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/normalize
+    pub(crate) fn normalize(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        // 2. Let S be ? ToString(O).
+        let string = this.require_object_coercible(context)?.to_string(context)?;
+
+        // 3. If form is undefined, let f be "NFC".
+        // 4. Else, let f be ? ToString(form).
+        let form = match args.get(0) {
+            Some(form) if !form.is_undefined() => form.to_string(context)?,
+            _ => RcString::from("NFC"),
+        };
+
+        // 5. If f is not one of "NFC", "NFD", "NFKC", or "NFKD", throw a RangeError exception.
+        // 6. Let ns be the String value that is the result of normalizing S into the normalization
+        //    form named by f as specified in https://unicode.org/reports/tr15/.
+        let normalized: StdString = match form.as_str() {
+            "NFC" => string.nfc().collect(),
+            "NFD" => string.nfd().collect(),
+            "NFKC" => string.nfkc().collect(),
+            "NFKD" => string.nfkd().collect(),
+            _ => {
+                return context.throw_range_error(format!(
+                    "The normalization form should be one of NFC, NFD, NFKC, NFKD, not {}",
+                    form
+                ))
+            }
+        };
+
+        // 7. Return ns.
+        Ok(normalized.into())
+    }
+
+    /// `String.prototype.localeCompare( that [, locales [, options ] ] )`
+    ///
+    /// The `localeCompare()` method returns `-1`, `0` or `1`, depending on whether the string
+    /// comes before, is equivalent to or comes after the given string in the sort order of the
+    /// `en-US` locale. The `sensitivity` option can be `"base"`, `"accent"`, `"case"` or
+    /// `"variant"` (the default).
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma402/#sup-String.prototype.localeCompare
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/localeCompare
+    pub(crate) fn locale_compare(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        // 2. Let S be ? ToString(O).
+        let string = this.require_object_coercible(context)?.to_string(context)?;
+
+        // 3. Let thatValue be ? ToString(that).
+        let that = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+
+        // 4. Let collator be ? Construct(%Collator%, « locales, options »).
+        let collator = Collator::new(
+            args.get(1).cloned().unwrap_or_default(),
+            args.get(2).cloned().unwrap_or_default(),
+            context,
+        )?;
+
+        // 5. Return CompareStrings(collator, S, thatValue).
+        Ok(match collator.compare(&string, &that) {
+            Ordering::Less => Value::from(-1),
+            Ordering::Equal => Value::from(0),
+            Ordering::Greater => Value::from(1),
+        })
+    }
+
     /// `String.prototype.substring( indexStart[, indexEnd] )`
     ///
     /// The `substring()` method returns the part of the `string` between the start and end indexes, or to the end of the string.
@@ -1100,43 +1634,45 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.substring
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/substring
     pub(crate) fn substring(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the context has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(context)?;
-        // If no args are specified, start is 'undefined', defaults to 0
-        let start = if args.is_empty() {
-            0
-        } else {
-            args.get(0)
-                .expect("failed to get argument for String method")
-                .to_integer(context)? as i32
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        // 2. Let S be ? ToString(O).
+        let string = this.require_object_coercible(context)?.to_string(context)?;
+        let code_units: Vec<u16> = string.encode_utf16().collect();
+
+        // 3. Let len be the length of S.
+        let len = code_units.len() as i64;
+
+        // 4. Let intStart be ? ToIntegerOrInfinity(start).
+        // 6. Let finalStart be the result of clamping intStart between 0 and len.
+        let final_start = match args
+            .get(0)
+            .cloned()
+            .unwrap_or_else(Value::undefined)
+            .to_integer_or_infinity(context)?
+        {
+            IntegerOrInfinity::Integer(i) => i.clamp(0, len),
+            IntegerOrInfinity::PositiveInfinity => len,
+            IntegerOrInfinity::NegativeInfinity => 0,
         };
-        let length = primitive_val.encode_utf16().count() as i32;
-        // If less than 2 args specified, end is the length of the this object converted to a String
-        let end = if args.len() < 2 {
-            length
-        } else {
-            args.get(1)
-                .expect("Could not get argument")
-                .to_integer(context)? as i32
+
+        // 5. If end is undefined, let intEnd be len; else let intEnd be ? ToIntegerOrInfinity(end).
+        // 7. Let finalEnd be the result of clamping intEnd between 0 and len.
+        let final_end = match args.get(1) {
+            None | Some(Value::Undefined) => len,
+            Some(end) => match end.to_integer_or_infinity(context)? {
+                IntegerOrInfinity::Integer(i) => i.clamp(0, len),
+                IntegerOrInfinity::PositiveInfinity => len,
+                IntegerOrInfinity::NegativeInfinity => 0,
+            },
         };
-        // Both start and end args replaced by 0 if they were negative
-        // or by the length of the String if they were greater
-        let final_start = min(max(start, 0), length);
-        let final_end = min(max(end, 0), length);
-        // Start and end are swapped if start is greater than end
+
+        // 8. Let from be min(finalStart, finalEnd).
         let from = min(final_start, final_end) as usize;
+        // 9. Let to be max(finalStart, finalEnd).
         let to = max(final_start, final_end) as usize;
-        // Extract the part of the string contained between the start index and the end index
-        // where start is guaranteed to be smaller or equals to end
-        let extracted_string: std::result::Result<StdString, _> = decode_utf16(
-            primitive_val
-                .encode_utf16()
-                .skip(from)
-                .take(to.wrapping_sub(from)),
-        )
-        .collect();
-        Ok(Value::from(extracted_string.expect("Invalid string")))
+
+        // 10. Return the substring of S from from to to.
+        Ok(StdString::from_utf16_lossy(&code_units[from..to]).into())
     }
 
     /// `String.prototype.substr( start[, length] )`
@@ -1151,49 +1687,49 @@ impl String {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/substr
     /// <https://tc39.es/ecma262/#sec-string.prototype.substr>
     pub(crate) fn substr(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        // First we get it the actual string a private field stored on the object only the context has access to.
-        // Then we convert it into a Rust String by wrapping it in from_value
-        let primitive_val = this.to_string(context)?;
-        // If no args are specified, start is 'undefined', defaults to 0
-        let mut start = if args.is_empty() {
-            0
-        } else {
-            args.get(0)
-                .expect("failed to get argument for String method")
-                .to_integer(context)? as i32
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        // 2. Let S be ? ToString(O).
+        let string = this.require_object_coercible(context)?.to_string(context)?;
+        let code_units: Vec<u16> = string.encode_utf16().collect();
+
+        // 3. Let size be the length of S.
+        let size = code_units.len() as i64;
+
+        // 4. Let intStart be ? ToIntegerOrInfinity(start).
+        // 5. If intStart is -∞, set intStart to 0.
+        // 6. Else if intStart < 0, set intStart to max(size + intStart, 0).
+        // 7. Else, set intStart to min(intStart, size).
+        let int_start = match args
+            .get(0)
+            .cloned()
+            .unwrap_or_else(Value::undefined)
+            .to_integer_or_infinity(context)?
+        {
+            IntegerOrInfinity::NegativeInfinity => 0,
+            IntegerOrInfinity::Integer(i) if i < 0 => max(size + i, 0),
+            IntegerOrInfinity::Integer(i) => min(i, size),
+            IntegerOrInfinity::PositiveInfinity => size,
         };
-        let length = primitive_val.chars().count() as i32;
-        // If less than 2 args specified, end is +infinity, the maximum number value.
-        // Using i32::max_value() should be safe because the final length used is at most
-        // the number of code units from start to the end of the string,
-        // which should always be smaller or equals to both +infinity and i32::max_value
-        let end = if args.len() < 2 {
-            i32::MAX
-        } else {
-            args.get(1)
-                .expect("Could not get argument")
-                .to_integer(context)? as i32
+
+        // 8. If length is undefined, let intLength be size; otherwise let intLength be ? ToIntegerOrInfinity(length).
+        // 9. Set intLength to the result of clamping intLength between 0 and size.
+        let int_length = match args.get(1) {
+            None | Some(Value::Undefined) => size,
+            Some(length) => match length.to_integer_or_infinity(context)? {
+                IntegerOrInfinity::Integer(i) => i.clamp(0, size),
+                IntegerOrInfinity::PositiveInfinity => size,
+                IntegerOrInfinity::NegativeInfinity => 0,
+            },
         };
-        // If start is negative it become the number of code units from the end of the string
-        if start < 0 {
-            start = max(length.wrapping_add(start), 0);
-        }
-        // length replaced by 0 if it was negative
-        // or by the number of code units from start to the end of the string if it was greater
-        let result_length = min(max(end, 0), length.wrapping_sub(start));
-        // If length is negative we return an empty string
-        // otherwise we extract the part of the string from start and is length code units long
-        if result_length <= 0 {
-            Ok(Value::from(""))
-        } else {
-            let extracted_string: StdString = primitive_val
-                .chars()
-                .skip(start as usize)
-                .take(result_length as usize)
-                .collect();
 
-            Ok(Value::from(extracted_string))
+        // 10. Let intEnd be min(intStart + intLength, size).
+        let int_end = min(int_start + int_length, size);
+
+        // 11. Return the substring of S from intStart to intEnd.
+        if int_start >= int_end {
+            return Ok("".into());
         }
+        Ok(StdString::from_utf16_lossy(&code_units[int_start as usize..int_end as usize]).into())
     }
 
     /// String.prototype.split()
@@ -1209,45 +1745,41 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.split
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/split
     pub(crate) fn split(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
         let this = this.require_object_coercible(context)?;
-        let string = this.to_string(context)?;
 
+        // 2. If separator is neither undefined nor null, then
         let separator = args.get(0).filter(|value| !value.is_null_or_undefined());
-
-        if let Some(result) = separator
-            .and_then(|separator| separator.as_object())
-            .and_then(|separator| {
-                let key = WellKnownSymbols::split();
-
-                match separator.get_method(context, key) {
-                    Ok(splitter) => splitter.map(|splitter| {
-                        let arguments = &[
-                            Value::from(string.clone()),
-                            args.get(1)
-                                .map(|x| x.to_owned())
-                                .unwrap_or(Value::Undefined),
-                        ];
-                        splitter.call(this, arguments, context)
-                    }),
-                    Err(_) => Some(Err(
-                        context.construct_type_error("separator[Symbol.split] is not a function")
-                    )),
-                }
-            })
-        {
-            return result;
+        let limit = args.get(1).cloned().unwrap_or_default();
+        if let Some(separator) = separator.and_then(Value::as_object) {
+            // a. Let splitter be ? GetMethod(separator, @@split).
+            let splitter = separator
+                .get_method(context, WellKnownSymbols::split())
+                .map_err(|_| {
+                    context.construct_type_error("separator[Symbol.split] is not a function")
+                })?;
+            // b. If splitter is not undefined, then
+            if let Some(splitter) = splitter {
+                // i. Return ? Call(splitter, separator, « O, limit »).
+                return splitter.call(&separator.into(), &[this.clone(), limit], context);
+            }
         }
 
+        // 3. Let S be ? ToString(O).
+        let string = this.to_string(context)?;
+
+        // 5. If limit is undefined, let lim be 2^32 - 1; else let lim be ℝ(? ToUint32(limit)).
+        let limit = if limit.is_undefined() {
+            u32::MAX
+        } else {
+            limit.to_u32(context)?
+        } as usize;
+
+        // 6. Let R be ? ToString(separator).
         let separator = separator
             .map(|separator| separator.to_string(context))
             .transpose()?;
 
-        let limit = args
-            .get(1)
-            .map(|arg| arg.to_integer(context).map(|limit| limit as usize))
-            .transpose()?
-            .unwrap_or(u32::MAX as usize);
-
         let values: Vec<Value> = match separator {
             None if limit == 0 => vec![],
             None => vec![Value::from(string)],
@@ -1295,34 +1827,70 @@ impl String {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/matchAll
     /// [regex]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions
     /// [cg]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions/Groups_and_Ranges
-    // TODO: update this method to return iterator
     pub(crate) fn match_all(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let re: Value = match args.get(0) {
-            Some(arg) => {
-                if arg.is_null() {
-                    RegExp::constructor(
-                        &Value::from(Object::default()),
-                        &[Value::from(arg.to_string(context)?), Value::from("g")],
-                        context,
-                    )
-                } else if arg.is_undefined() {
-                    RegExp::constructor(
-                        &Value::from(Object::default()),
-                        &[Value::undefined(), Value::from("g")],
-                        context,
-                    )
-                } else {
-                    Ok(arg.clone())
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let o = this.require_object_coercible(context)?;
+
+        // 2. If regexp is neither undefined nor null, then
+        let regexp = args.get(0).cloned().unwrap_or_default();
+        if !regexp.is_null_or_undefined() {
+            // a. Let isRegExp be ? IsRegExp(regexp).
+            // b. If isRegExp is true, then
+            if Self::is_regexp_object(&regexp) {
+                // i. Let flags be ? Get(regexp, "flags").
+                let flags = regexp.get_field("flags", context)?;
+
+                // ii. Perform ? RequireObjectCoercible(flags).
+                flags.require_object_coercible(context)?;
+
+                // iii. If ? ToString(flags) does not contain "g", throw a TypeError exception.
+                if !flags.to_string(context)?.contains('g') {
+                    return context.throw_type_error(
+                        "String.prototype.matchAll called with a non-global RegExp argument",
+                    );
                 }
             }
-            None => RegExp::constructor(
-                &Value::from(Object::default()),
-                &[Value::from(""), Value::from("g")],
-                context,
-            ),
-        }?;
-
-        RegExp::match_all(&re, this.to_string(context)?.to_string(), context)
+
+            // c. Let matcher be ? GetMethod(regexp, @@matchAll).
+            // d. If matcher is not undefined, then
+            if let Some(matcher) = regexp
+                .to_object(context)?
+                .get_method(context, WellKnownSymbols::match_all())?
+            {
+                // i. Return ? Call(matcher, regexp, « O »).
+                return matcher.call(&regexp, &[o.clone()], context);
+            }
+        }
+
+        // 3. Let S be ? ToString(O).
+        let s = o.to_string(context)?;
+
+        // 4. Let rx be ? RegExpCreate(regexp, "g").
+        let pattern = if regexp.is_undefined() {
+            RcString::default()
+        } else {
+            regexp.to_string(context)?
+        };
+        init_lazy(context, RegExp::NAME);
+        let rx = RegExp::constructor(
+            &context
+                .standard_objects()
+                .regexp_object()
+                .constructor()
+                .into(),
+            &[Value::from(pattern), Value::from("g")],
+            context,
+        )?;
+
+        // 5. Return ? Invoke(rx, @@matchAll, « S »).
+        if let Some(matcher) = rx
+            .to_object(context)?
+            .get_method(context, WellKnownSymbols::match_all())?
+        {
+            matcher.call(&rx, &[Value::from(s)], context)
+        } else {
+            context.throw_type_error("RegExp.prototype[Symbol.matchAll] is not a function")
+        }
     }
 
     /// `String.prototype.search( regexp )`
@@ -1370,7 +1938,25 @@ impl String {
         }
     }
 
+    /// `String.prototype[ @@iterator ]( )`
+    ///
+    /// Returns an iterator over the code points of the string, which yields a surrogate pair as a
+    /// single string.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype-@@iterator
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/@@iterator
     pub(crate) fn iterator(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
-        StringIterator::create_string_iterator(context, this.clone())
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible(context)?;
+
+        // 2. Let s be ? ToString(O).
+        let string = this.to_string(context)?;
+
+        // 3. Return CreateStringIterator(s).
+        StringIterator::create_string_iterator(context, string.into())
     }
 }
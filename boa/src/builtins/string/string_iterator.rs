@@ -57,10 +57,10 @@ impl StringIterator {
                 )?;
                 Ok(create_iter_result_object(context, result_string, false))
             } else {
-                context.throw_type_error("`this` is not an ArrayIterator")
+                context.throw_type_error("`this` is not a StringIterator")
             }
         } else {
-            context.throw_type_error("`this` is not an ArrayIterator")
+            context.throw_type_error("`this` is not a StringIterator")
         }
     }
 
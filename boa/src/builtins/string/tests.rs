@@ -133,6 +133,14 @@ fn repeat() {
 
     assert_eq!(forward(&mut context, "en.repeat(1)"), "\"english\"");
     assert_eq!(forward(&mut context, "zh.repeat(2)"), "\"中文中文\"");
+
+    assert_eq!(forward(&mut context, "'ab'.repeat(3)"), "\"ababab\"");
+    assert_eq!(forward(&mut context, "'ab'.repeat(2.9)"), "\"abab\"");
+    assert_eq!(forward(&mut context, "'ab'.repeat('2')"), "\"abab\"");
+    assert_eq!(forward(&mut context, "'x'.repeat(0)"), "\"\"");
+    assert_eq!(forward(&mut context, "'x'.repeat()"), "\"\"");
+    assert_eq!(forward(&mut context, "'x'.repeat(NaN)"), "\"\"");
+    assert_eq!(forward(&mut context, "''.repeat(2 ** 40)"), "\"\"");
 }
 
 #[test]
@@ -206,6 +214,50 @@ fn repeat_generic() {
     assert_eq!(forward(&mut context, "(12).repeat(3)"), "\"121212\"");
 }
 
+#[test]
+fn pad_start() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "'5'.padStart(3, '0')"), "\"005\"");
+    assert_eq!(
+        forward(&mut context, "'abc'.padStart(10, '123')"),
+        "\"1231231abc\""
+    );
+    assert_eq!(forward(&mut context, "'abc'.padStart(6)"), "\"   abc\"");
+    assert_eq!(
+        forward(&mut context, "'abc'.padStart(6, undefined)"),
+        "\"   abc\""
+    );
+    assert_eq!(
+        forward(&mut context, "'中文'.padStart(4, '字')"),
+        "\"字字中文\""
+    );
+}
+
+#[test]
+fn pad_end() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "'5'.padEnd(3, '0')"), "\"500\"");
+    assert_eq!(
+        forward(&mut context, "'abc'.padEnd(10, '123')"),
+        "\"abc1231231\""
+    );
+    assert_eq!(forward(&mut context, "'abc'.padEnd(6)"), "\"abc   \"");
+}
+
+#[test]
+fn pad_when_already_long_enough() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "'abc'.padStart(2, '0')"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padEnd(3, '0')"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padStart(-1)"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padEnd()"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padStart(10, '')"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padEnd(10, '')"), "\"abc\"");
+}
+
 #[test]
 fn replace() {
     let mut context = Context::new();
@@ -312,6 +364,173 @@ fn replace_with_function() {
     assert_eq!(forward(&mut context, "length"), "14");
 }
 
+#[test]
+fn replace_with_function_arguments() {
+    let mut context = Context::new();
+    let init = r#"
+        var args;
+        var upper = "née: john smith".replace(/(\w+) (\w+)/, function (match, first, last, offset, string) {
+            args = [match, first, last, offset, string, arguments.length];
+            return last.toUpperCase() + ", " + first.toUpperCase();
+        });
+        var named;
+        var dated = "on 2021-03-04".replace(/(?<year>\d+)-(?<month>\d+)-(?<day>\d+)/, (...rest) => {
+            named = rest;
+            var groups = rest[rest.length - 1];
+            return groups.day + "/" + groups.month + "/" + groups.year;
+        });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "upper"), "\"née: SMITH, JOHN\"");
+    assert_eq!(
+        forward(&mut context, "args.join('|')"),
+        "\"john smith|john|smith|5|née: john smith|5\""
+    );
+    assert_eq!(forward(&mut context, "dated"), "\"on 04/03/2021\"");
+    assert_eq!(forward(&mut context, "named.length"), "7");
+    assert_eq!(forward(&mut context, "named[4]"), "3");
+    assert_eq!(forward(&mut context, "named[5]"), "\"on 2021-03-04\"");
+}
+
+#[test]
+fn replace_string_pattern_is_literal() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, r#""a.b.c".replace(".", "-")"#),
+        "\"a-b.c\""
+    );
+    assert_eq!(
+        forward(&mut context, r#""xyz".replace("(", "-")"#),
+        "\"xyz\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#""aXbX".replace("X", (m, offset) => offset)"#
+        ),
+        "\"a1bX\""
+    );
+}
+
+#[test]
+fn replace_with_global_regexp() {
+    let mut context = Context::new();
+    let init = r#"
+        var offsets = [];
+        var re = /o(\w)/g;
+        re.lastIndex = 5;
+        var result = "foo bar boz".replace(re, (match, next, offset) => {
+            offsets.push(offset);
+            return next.toUpperCase();
+        });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "result"), "\"fO bar bZ\"");
+    assert_eq!(forward(&mut context, "offsets.join()"), "\"1,9\"");
+    assert_eq!(forward(&mut context, "re.lastIndex"), "0");
+    assert_eq!(
+        forward(&mut context, r#""aaa".replace(/a/, "b")"#),
+        "\"baa\""
+    );
+}
+
+#[test]
+fn replace_all() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, r#""a.b.c".replaceAll(".", "-")"#),
+        "\"a-b-c\""
+    );
+    assert_eq!(
+        forward(&mut context, r#""aaaa".replaceAll("aa", "b")"#),
+        "\"bb\""
+    );
+    assert_eq!(
+        forward(&mut context, r#""abc".replaceAll("d", "e")"#),
+        "\"abc\""
+    );
+    assert_eq!(
+        forward(&mut context, r#""a.b".replaceAll(".", "[$&$$]")"#),
+        "\"a[.$]b\""
+    );
+}
+
+#[test]
+fn replace_all_empty_search_string() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, r#""abc".replaceAll("", "_")"#),
+        "\"_a_b_c_\""
+    );
+    assert_eq!(forward(&mut context, r#""".replaceAll("", "_")"#), "\"_\"");
+}
+
+#[test]
+fn replace_all_with_function() {
+    let mut context = Context::new();
+    let init = r#"
+        var calls = [];
+        var a = "xaxax".replaceAll("x", function (match, offset, string) {
+            calls.push(match + offset + string);
+            return offset;
+        });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "a"), "\"0a2a4\"");
+    assert_eq!(
+        forward(&mut context, "calls.join()"),
+        "\"x0xaxax,x2xaxax,x4xaxax\""
+    );
+}
+
+#[test]
+fn replace_all_with_regexp() {
+    let mut context = Context::new();
+    let init = r#"
+        var dates = "2020-01 1999-12";
+        var a = dates.replaceAll(/(?<year>\d+)-(\d+)/g, "$2/$<year>");
+        var b = dates.replaceAll(/(\d+)-(\d+)/g, "[$&] $1");
+        var groups = [];
+        var c = dates.replaceAll(/(?<year>\d+)-(\d+)/g, function (match, year, month, offset, string, named) {
+            groups.push(named.year + "@" + offset);
+            return month;
+        });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "a"), "\"01/2020 12/1999\"");
+    assert_eq!(
+        forward(&mut context, "b"),
+        "\"[2020-01] 2020 [1999-12] 1999\""
+    );
+    assert_eq!(forward(&mut context, "c"), "\"01 12\"");
+    assert_eq!(forward(&mut context, "groups.join()"), "\"2020@0,1999@8\"");
+}
+
+#[test]
+fn replace_all_non_global_regexp() {
+    let mut context = Context::new();
+    let init = r#"
+        try {
+            "aaa".replaceAll(/a/, "b");
+        } catch (e) {
+            e.toString();
+        }
+        "#;
+
+    assert_eq!(
+        forward(&mut context, init),
+        "\"TypeError: String.prototype.replaceAll called with a non-global RegExp argument\""
+    );
+}
+
 #[test]
 fn starts_with() {
     let mut context = Context::new();
@@ -444,38 +663,117 @@ fn includes_with_regex_arg() {
     )
 }
 
+#[test]
+fn starts_ends_with_includes_positions() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "'hello'.startsWith('ll', 2)"), "true");
+    assert_eq!(
+        forward(&mut context, "'hello'.startsWith('ll', 1)"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "'hello'.startsWith('h', -5)"), "true");
+    assert_eq!(forward(&mut context, "'hello'.endsWith('ell', 4)"), "true");
+    assert_eq!(
+        forward(&mut context, "'hello'.endsWith('o', undefined)"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "'hello'.endsWith('h', 1)"), "true");
+    assert_eq!(forward(&mut context, "'hello'.includes('l', 3)"), "true");
+    assert_eq!(forward(&mut context, "'hello'.includes('e', 2)"), "false");
+    assert_eq!(forward(&mut context, "'hello'.includes('', 10)"), "true");
+
+    // The positions are in UTF-16 code units.
+    assert_eq!(
+        forward(&mut context, "'\\u{1F600}a'.startsWith('a', 2)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'\\u{1F600}a'.endsWith('\\u{1F600}', 2)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'\\u{1F600}a'.startsWith('a', 1)"),
+        "false"
+    );
+}
+
+#[test]
+fn starts_ends_with_includes_is_regexp() {
+    let mut context = Context::new();
+
+    let scenario = r#"
+        let regexp = /a/;
+        regexp[Symbol.match] = false;
+        let lookalike = { [Symbol.match]: true, toString() { return "a"; } };
+        let results = ["/a/".startsWith(regexp), "/a/".includes(regexp)];
+        for (let method of ["startsWith", "endsWith", "includes"]) {
+            try {
+                "a"[method](lookalike);
+                results.push("no error");
+            } catch (e) {
+                results.push(e instanceof TypeError);
+            }
+        }
+        results.join()
+    "#;
+    assert_eq!(
+        forward(&mut context, scenario),
+        "\"true,true,true,true,true\""
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { String.prototype.startsWith.call(null, 'a') } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
 #[test]
 fn match_all() {
     let mut context = Context::new();
 
-    assert_eq!(forward(&mut context, "'aa'.matchAll(null).length"), "0");
-    assert_eq!(forward(&mut context, "'aa'.matchAll(/b/).length"), "0");
-    assert_eq!(forward(&mut context, "'aa'.matchAll(/a/).length"), "1");
-    assert_eq!(forward(&mut context, "'aa'.matchAll(/a/g).length"), "2");
+    assert_eq!(
+        forward(&mut context, "[...'aa'.matchAll(null)].length"),
+        "0"
+    );
+    assert_eq!(
+        forward(&mut context, "[...'aa'.matchAll(/b/g)].length"),
+        "0"
+    );
+    assert_eq!(
+        forward(&mut context, "[...'aa'.matchAll(/a/g)].length"),
+        "2"
+    );
+    assert_eq!(forward(&mut context, "[...'aa'.matchAll('a')].length"), "2");
+    assert_eq!(forward(&mut context, "[...'aa'.matchAll()].length"), "3");
 
     forward(
         &mut context,
-        "var groupMatches = 'test1test2'.matchAll(/t(e)(st(\\d?))/g)",
+        "var groupMatches = [...'test1test2'.matchAll(/t(e)(st(\\d?))/g)]",
     );
 
     assert_eq!(forward(&mut context, "groupMatches.length"), "2");
+    assert_eq!(forward(&mut context, "groupMatches[0][0]"), "\"test1\"");
     assert_eq!(forward(&mut context, "groupMatches[0][1]"), "\"e\"");
     assert_eq!(forward(&mut context, "groupMatches[0][2]"), "\"st1\"");
     assert_eq!(forward(&mut context, "groupMatches[0][3]"), "\"1\"");
-    assert_eq!(forward(&mut context, "groupMatches[1][3]"), "\"2\"");
-
+    assert_eq!(forward(&mut context, "groupMatches[0].index"), "0");
     assert_eq!(
-        forward(
-            &mut context,
-            "'test1test2'.matchAll(/t(e)(st(\\d?))/).length"
-        ),
-        "1"
+        forward(&mut context, "groupMatches[0].input"),
+        "\"test1test2\""
     );
+    assert_eq!(forward(&mut context, "groupMatches[0].groups"), "undefined");
+    assert_eq!(forward(&mut context, "groupMatches[1][0]"), "\"test2\"");
+    assert_eq!(forward(&mut context, "groupMatches[1][3]"), "\"2\"");
+    assert_eq!(forward(&mut context, "groupMatches[1].index"), "5");
 
     let init = r#"
         var regexp = RegExp('foo[a-z]*','g');
         var str = 'table football, foosball';
-        var matches = str.matchAll(regexp);
+        var matches = [...str.matchAll(regexp)];
         "#;
 
     forward(&mut context, init);
@@ -484,6 +782,72 @@ fn match_all() {
     assert_eq!(forward(&mut context, "matches[0].index"), "6");
     assert_eq!(forward(&mut context, "matches[1][0]"), "\"foosball\"");
     assert_eq!(forward(&mut context, "matches[1].index"), "16");
+    assert_eq!(forward(&mut context, "regexp.lastIndex"), "0");
+}
+
+#[test]
+fn match_all_iterator() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var regexp = /t(e)(st(\d?))/g;
+        var iterator = 'test1test2'.matchAll(regexp);
+        var first = iterator.next();
+        var second = iterator.next();
+        var third = iterator.next();
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(iterator)"),
+        "\"[object RegExp String Iterator]\""
+    );
+    assert_eq!(
+        forward(&mut context, "iterator[Symbol.iterator]() === iterator"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "first.done"), "false");
+    assert_eq!(forward(&mut context, "first.value[2]"), "\"st1\"");
+    assert_eq!(forward(&mut context, "second.done"), "false");
+    assert_eq!(forward(&mut context, "second.value.index"), "5");
+    assert_eq!(forward(&mut context, "third.done"), "true");
+    assert_eq!(forward(&mut context, "third.value"), "undefined");
+    assert_eq!(forward(&mut context, "iterator.next().done"), "true");
+    assert_eq!(forward(&mut context, "regexp.lastIndex"), "0");
+}
+
+#[test]
+fn match_all_empty_matches() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "[...'abc'.matchAll(/(?:)/g)].map(m => m.index).join()"
+        ),
+        "\"0,1,2,3\""
+    );
+}
+
+#[test]
+fn match_all_non_global_regexp_throws() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { 'aa'.matchAll(/a/); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { 'aa'.matchAll(new RegExp('a', 'i')); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
 }
 
 #[test]
@@ -575,6 +939,135 @@ fn trim_end() {
     );
 }
 
+#[test]
+fn trim_unicode_whitespace() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"'\t\u00A0\uFEFFHello\u3000 '.trimStart() === 'Hello\u3000 '"#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"'\t\u00A0Hello \uFEFF\t'.trimEnd() === '\t\u00A0Hello'"#
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"'\u00A0Hello \u00A0\t World\uFEFF'.trim() === 'Hello \u00A0\t World'"#
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn trim_aliases() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "String.prototype.trimLeft === String.prototype.trimStart"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "String.prototype.trimRight === String.prototype.trimEnd"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "String.prototype.trimLeft.name"),
+        "\"trimStart\""
+    );
+    assert_eq!(
+        forward(&mut context, r#"'\t Hello World '.trimLeft()"#),
+        "\"Hello World \""
+    );
+    assert_eq!(
+        forward(&mut context, r#"' Hello World \t'.trimRight()"#),
+        "\" Hello World\""
+    );
+}
+
+#[test]
+fn normalize() {
+    let mut context = Context::new();
+    let init = r#"
+        var composed = "\u00e9";
+        var decomposed = "e\u0301";
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "composed === decomposed"), "false");
+    assert_eq!(
+        forward(
+            &mut context,
+            "composed.normalize() === decomposed.normalize('NFC')"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "decomposed.normalize().length"), "1");
+    assert_eq!(
+        forward(&mut context, "composed.normalize('NFD') === decomposed"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "composed.normalize('NFD').normalize(undefined) === composed"
+        ),
+        "true"
+    );
+
+    // Compatibility forms replace characters with their compatibility equivalents.
+    assert_eq!(
+        forward(&mut context, "'\\ufb01'.normalize('NFC')"),
+        "\"\u{fb01}\""
+    );
+    assert_eq!(
+        forward(&mut context, "'\\ufb01'.normalize('NFKC')"),
+        "\"fi\""
+    );
+    assert_eq!(
+        forward(&mut context, "'\\u1e9b\\u0323'.normalize('NFKD').length"),
+        "3"
+    );
+
+    // Characters outside of the basic multilingual plane are normalized as code points.
+    assert_eq!(
+        forward(&mut context, "'\\u{1d15e}'.normalize('NFD').length"),
+        "4"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "'\\u{1d15e}'.normalize('NFD').codePointAt(2).toString(16)"
+        ),
+        "\"1d165\""
+    );
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { composed.normalize('nfc') } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { String.prototype.normalize.call(null) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
 #[test]
 fn split() {
     let mut context = Context::new();
@@ -655,6 +1148,72 @@ fn split() {
     );
 }
 
+#[test]
+fn split_with_regexp() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "'a1b2c'.split(/(\\d)/)"),
+        forward(&mut context, "['a', '1', 'b', '2', 'c']")
+    );
+    assert_eq!(
+        forward(&mut context, "'a1b2c'.split(/\\d/)"),
+        forward(&mut context, "['a', 'b', 'c']")
+    );
+    assert_eq!(
+        forward(&mut context, "'aXbX'.split(/X/)"),
+        forward(&mut context, "['a', 'b', '']")
+    );
+    assert_eq!(
+        forward(&mut context, "'test'.split(/(x)?t/)"),
+        forward(&mut context, "['', undefined, 'es', undefined, '']")
+    );
+
+    // Empty matches never split at the end of the previous substring.
+    assert_eq!(
+        forward(&mut context, "'abc'.split(/(?:)/)"),
+        forward(&mut context, "['a', 'b', 'c']")
+    );
+    assert_eq!(
+        forward(&mut context, "'ab'.split(/a*/)"),
+        forward(&mut context, "['', 'b']")
+    );
+    assert_eq!(
+        forward(&mut context, "''.split(/(?:)/)"),
+        forward(&mut context, "[]")
+    );
+    assert_eq!(
+        forward(&mut context, "''.split(/x/)"),
+        forward(&mut context, "['']")
+    );
+
+    // The limit counts the captures too.
+    assert_eq!(
+        forward(&mut context, "'a1b2c'.split(/(\\d)/, 2)"),
+        forward(&mut context, "['a', '1']")
+    );
+    assert_eq!(
+        forward(&mut context, "'a1b2c'.split(/(\\d)/, 0)"),
+        forward(&mut context, "[]")
+    );
+    assert_eq!(
+        forward(&mut context, "'a,b,c'.split(/,/, -1)"),
+        forward(&mut context, "['a', 'b', 'c']")
+    );
+    assert_eq!(
+        forward(&mut context, "'a,b,c'.split(',', -1)"),
+        forward(&mut context, "['a', 'b', 'c']")
+    );
+
+    // The regular expression doesn't use or change its `lastIndex`.
+    assert_eq!(
+        forward(
+            &mut context,
+            "var re = /b/g; re.lastIndex = 2; 'abc'.split(re).length + ' ' + re.lastIndex"
+        ),
+        "\"2 2\""
+    );
+}
+
 #[test]
 fn split_with_symbol_split_method() {
     assert_eq!(
@@ -921,6 +1480,24 @@ fn last_index_non_integer_position_argument() {
     assert_eq!(forward(&mut context, "'abcx'.lastIndexOf('x', null)"), "3");
 }
 
+#[test]
+fn at() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "'abc'.at(0)"), "\"a\"");
+    assert_eq!(forward(&mut context, "'abc'.at(-1)"), "\"c\"");
+    assert_eq!(forward(&mut context, "'abc'.at(-3)"), "\"a\"");
+    assert_eq!(forward(&mut context, "'abc'.at(3)"), "undefined");
+    assert_eq!(forward(&mut context, "'abc'.at(-4)"), "undefined");
+    assert_eq!(forward(&mut context, "'abc'.at(Infinity)"), "undefined");
+    assert_eq!(forward(&mut context, "'abc'.at()"), "\"a\"");
+    assert_eq!(forward(&mut context, "'abc'.at('1')"), "\"b\"");
+    assert_eq!(
+        forward(&mut context, "'\\uD83D\\uDE00'.at(-1)"),
+        "\"\u{FFFD}\""
+    );
+    assert_eq!(forward(&mut context, "String.prototype.at.length"), "1");
+}
+
 #[test]
 fn char_at() {
     let mut context = Context::new();
@@ -985,6 +1562,77 @@ fn code_point_at() {
     );
 }
 
+#[test]
+fn code_point_at_astral() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "'😀'.codePointAt(0)"), "128512");
+    assert_eq!(forward(&mut context, "'😀'.codePointAt(1)"), "56832");
+    assert_eq!(forward(&mut context, "'😀'.codePointAt(2)"), "undefined");
+    assert_eq!(forward(&mut context, "'a😀b'.codePointAt(3)"), "98");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { String.prototype.codePointAt.call(null, 0) } catch (e) { e.name }"
+        ),
+        "\"TypeError\""
+    );
+}
+
+#[test]
+fn from_code_point() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "String.fromCodePoint()"), "\"\"");
+    assert_eq!(
+        forward(&mut context, "String.fromCodePoint(0x1F600)"),
+        "\"😀\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.fromCodePoint(0x1F600).length"),
+        "2"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "String.fromCodePoint(72, 105, '33', 0x10FFFF)"
+        ),
+        "\"Hi!\u{10FFFF}\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.fromCodePoint(0xD83D, 0xDE00)"),
+        "\"😀\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.fromCodePoint(0x1F600).codePointAt(0)"),
+        "128512"
+    );
+    assert_eq!(forward(&mut context, "String.fromCodePoint.length"), "1");
+
+    for code_point in [
+        "0x110000",
+        "-1",
+        "1.5",
+        "NaN",
+        "Infinity",
+        "'a'",
+        "undefined",
+    ]
+    .iter()
+    {
+        assert_eq!(
+            forward(
+                &mut context,
+                &format!(
+                    "try {{ String.fromCodePoint({}) }} catch (e) {{ e.name }}",
+                    code_point
+                )
+            ),
+            "\"RangeError\"",
+            "String.fromCodePoint({})",
+            code_point
+        );
+    }
+}
+
 #[test]
 fn slice() {
     let mut context = Context::new();
@@ -995,6 +1643,88 @@ fn slice() {
     assert_eq!(forward(&mut context, "'abc'.slice(9, 10)"), "\"\"");
 }
 
+#[test]
+fn slice_clamping() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "'hello'.slice(-3)"), "\"llo\"");
+    assert_eq!(forward(&mut context, "'hello'.slice(1, -1)"), "\"ell\"");
+    assert_eq!(forward(&mut context, "'hello'.slice(3, 1)"), "\"\"");
+    assert_eq!(
+        forward(&mut context, "'hello'.slice(-Infinity, Infinity)"),
+        "\"hello\""
+    );
+    assert_eq!(
+        forward(&mut context, "'hello'.slice(2, undefined)"),
+        "\"llo\""
+    );
+    assert_eq!(forward(&mut context, "'a\\u{1F600}b'.slice(3)"), "\"b\"");
+    assert_eq!(
+        forward(&mut context, "'a\\u{1F600}b'.slice(1, 3).length"),
+        "2"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { String.prototype.slice.call(null) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn substring() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "'hello'.substring(3, 1)"), "\"el\"");
+    assert_eq!(forward(&mut context, "'hello'.substring(1)"), "\"ello\"");
+    assert_eq!(forward(&mut context, "'hello'.substring(-5, 2)"), "\"he\"");
+    assert_eq!(forward(&mut context, "'hello'.substring(2, NaN)"), "\"he\"");
+    assert_eq!(
+        forward(&mut context, "'hello'.substring(2, undefined)"),
+        "\"llo\""
+    );
+    assert_eq!(forward(&mut context, "'hello'.substring(Infinity)"), "\"\"");
+    assert_eq!(
+        forward(&mut context, "'\\u{1F600}'.substring(0, 1).length"),
+        "1"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { String.prototype.substring.call(undefined) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn substr() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "'hello'.substr(-2, 1)"), "\"l\"");
+    assert_eq!(forward(&mut context, "'hello'.substr(1)"), "\"ello\"");
+    assert_eq!(
+        forward(&mut context, "'hello'.substr(1, undefined)"),
+        "\"ello\""
+    );
+    assert_eq!(forward(&mut context, "'hello'.substr(-10, 2)"), "\"he\"");
+    assert_eq!(forward(&mut context, "'hello'.substr(1, -1)"), "\"\"");
+    assert_eq!(
+        forward(&mut context, "'hello'.substr(1, Infinity)"),
+        "\"ello\""
+    );
+    assert_eq!(forward(&mut context, "'hello'.substr(9)"), "\"\"");
+    assert_eq!(
+        forward(&mut context, "'a\\u{1F600}b'.substr(3, 1)"),
+        "\"b\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { String.prototype.substr.call(null) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
 #[test]
 fn empty_iter() {
     let mut context = Context::new();
@@ -1114,3 +1844,199 @@ fn search() {
     assert_eq!(forward(&mut context, "'aa'.search(/a/g)"), "0");
     assert_eq!(forward(&mut context, "'ba'.search(/a/)"), "1");
 }
+
+#[test]
+fn raw() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, r"String.raw`a\nb`"), r#""a\nb""#);
+    assert_eq!(forward(&mut context, r"String.raw`a\nb`.length"), "4");
+    assert_eq!(
+        forward(&mut context, r"String.raw`x${1}y${2 + 3}z`"),
+        "\"x1y5z\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.raw({ raw: ['a', 'b', 'c'] }, 1)"),
+        "\"a1bc\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.raw({ raw: 'abc' }, '-', '+', '*')"),
+        "\"a-b+c\""
+    );
+    assert_eq!(forward(&mut context, "String.raw({ raw: [] }, 1)"), "\"\"");
+    assert_eq!(forward(&mut context, "String.raw.length"), "1");
+}
+
+#[test]
+fn raw_invalid_template() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { String.raw(); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { String.raw({}); } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn locale_compare() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "'a'.localeCompare('b')"), "-1");
+    assert_eq!(forward(&mut context, "'b'.localeCompare('a')"), "1");
+    assert_eq!(forward(&mut context, "'a'.localeCompare('a')"), "0");
+    assert_eq!(forward(&mut context, "'a'.localeCompare('A')"), "-1");
+    assert_eq!(forward(&mut context, "'A'.localeCompare('b')"), "-1");
+    assert_eq!(forward(&mut context, "'a'.localeCompare('á')"), "-1");
+    assert_eq!(forward(&mut context, "'A'.localeCompare('á')"), "-1");
+    assert_eq!(forward(&mut context, "'9'.localeCompare('10')"), "1");
+    assert_eq!(forward(&mut context, "' b'.localeCompare('a')"), "-1");
+    assert_eq!(forward(&mut context, "''.localeCompare('a')"), "-1");
+    // Canonically equivalent strings are equal.
+    assert_eq!(
+        forward(&mut context, "'\\u00e9'.localeCompare('e\\u0301')"),
+        "0"
+    );
+    assert_eq!(forward(&mut context, "'a'.localeCompare()"), "-1");
+    assert_eq!(
+        forward(&mut context, "String.prototype.localeCompare.length"),
+        "1"
+    );
+}
+
+#[test]
+fn locale_compare_sensitivity() {
+    let mut context = Context::new();
+
+    let compare = |sensitivity: &str, x: &str, y: &str| {
+        format!(
+            "'{}'.localeCompare('{}', 'en-US', {{ sensitivity: '{}' }})",
+            x, y, sensitivity
+        )
+    };
+    let cases = [
+        ("base", "a", "A", "0"),
+        ("base", "a", "á", "0"),
+        ("base", "a", "b", "-1"),
+        ("accent", "a", "A", "0"),
+        ("accent", "a", "á", "-1"),
+        ("case", "a", "A", "-1"),
+        ("case", "a", "á", "0"),
+        ("variant", "a", "A", "-1"),
+        ("variant", "a", "á", "-1"),
+    ];
+    for (sensitivity, x, y, expected) in cases.iter() {
+        let source = compare(sensitivity, x, y);
+        assert_eq!(&forward(&mut context, &source), expected, "{}", source);
+    }
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { 'a'.localeCompare('b', undefined, { sensitivity: 'none' }) } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { 'a'.localeCompare('b', 'not a locale') } catch (e) { e.name }"
+        ),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn locale_compare_sort() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "['b', 'a', 'C'].sort(function(x, y) { return x.localeCompare(y); }).join()"
+        ),
+        "\"a,b,C\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            ['b', 'a', 'C'].sort(function(x, y) {
+                return x.localeCompare(y, undefined, { sensitivity: 'base' });
+            }).join()
+            "#
+        ),
+        "\"a,b,C\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "['B', 'b', 'á', 'A', 'a'].sort(function(x, y) { return x.localeCompare(y); }).join()"
+        ),
+        "\"a,A,á,b,B\""
+    );
+    // Equal strings keep their order, since the sort is stable.
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"
+            ['B', 'b', 'á', 'A', 'a'].sort(function(x, y) {
+                return x.localeCompare(y, undefined, { sensitivity: 'base' });
+            }).join()
+            "#
+        ),
+        "\"á,A,a,B,b\""
+    );
+}
+
+#[test]
+fn for_of_code_points() {
+    let mut context = Context::new();
+    let init = r#"
+        let chars = [];
+        for (const ch of "a\u{1F600}b") {
+            chars.push(ch);
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "chars.length"), "3");
+    assert_eq!(forward(&mut context, "chars[1] === '\\u{1F600}'"), "true");
+    assert_eq!(forward(&mut context, "chars[1].length"), "2");
+    assert_eq!(
+        forward(&mut context, "[...'\\u{1F600}\\u{1F600}'].length"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut context, "Array.from('x\\u{1F600}').length"),
+        "2"
+    );
+}
+
+#[test]
+fn iterator_converts_this_once() {
+    let mut context = Context::new();
+    let init = r#"
+        let calls = 0;
+        let object = { toString() { calls++; return "ab"; } };
+        let items = [...String.prototype[Symbol.iterator].call(object)];
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "items.join()"), "\"a,b\"");
+    assert_eq!(forward(&mut context, "calls"), "1");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { String.prototype[Symbol.iterator].call(null) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
@@ -0,0 +1,320 @@
+//! This module implements the global `structuredClone` function.
+//!
+//! The structured clone algorithm deep-copies plain data: primitives, ordinary objects, arrays,
+//! `Map`s, `Set`s, `Date`s, regular expressions, errors and the primitive wrapper objects.
+//! Objects that are referenced more than once, including through cycles, are copied once, so the
+//! copy has the same shape as the original. Other objects, like functions, can't be cloned and
+//! make the algorithm throw a `DataCloneError`.
+//!
+//! The same algorithm imports values from another `Context`. The copies are created in the realm
+//! of the importing context, and the properties of the imported objects are read without running
+//! any code of the other context, so only data properties can be imported.
+//!
+//! More information:
+//!  - [HTML reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/structuredClone
+
+use crate::{
+    builtins::{date::Date, Array, BuiltIn, Error},
+    object::{FunctionBuilder, GcObject, ObjectData},
+    property::{Attribute, DataDescriptor, PropertyKey},
+    BoaProfiler, Context, Result, Value,
+};
+use rustc_hash::FxHashMap;
+
+#[cfg(test)]
+mod tests;
+
+/// The JavaScript `structuredClone` function.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StructuredClone;
+
+impl BuiltIn for StructuredClone {
+    const NAME: &'static str = "structuredClone";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let structured_clone = FunctionBuilder::new(context, Self::structured_clone)
+            .name(Self::NAME)
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        (Self::NAME, structured_clone.into(), Self::attribute())
+    }
+}
+
+impl StructuredClone {
+    /// `structuredClone( value )`
+    ///
+    /// Returns a deep copy of the value.
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/structured-data.html#dom-structuredclone
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/structuredClone
+    pub(crate) fn structured_clone(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        Self::clone_value(args.get(0).unwrap_or(&Value::undefined()), context)
+    }
+
+    /// Returns a deep copy of the value, or throws a `DataCloneError` if it can't be cloned.
+    pub(crate) fn clone_value(value: &Value, context: &mut Context) -> Result<Value> {
+        Cloner::default().clone_value(value, context)
+    }
+
+    /// Returns a deep copy, created in `context`, of a value of another context, or throws a
+    /// `DataCloneError` if it can't be imported.
+    pub(crate) fn import_value(value: &Value, context: &mut Context) -> Result<Value> {
+        Cloner {
+            import: true,
+            ..Cloner::default()
+        }
+        .clone_value(value, context)
+    }
+}
+
+/// What needs to be copied from a cloned object, taken out of its data so the object isn't
+/// borrowed while the copy is created.
+#[derive(Debug)]
+enum Kind {
+    Wrapper(Value),
+    Date(Date),
+    RegExp(Box<str>, Box<str>),
+    Map(Vec<(Value, Value)>),
+    Set(Vec<Value>),
+    Array,
+    Error,
+    Ordinary,
+}
+
+/// The state of a structured clone.
+#[derive(Debug, Default)]
+struct Cloner {
+    /// The copies of the objects cloned so far.
+    memory: FxHashMap<Value, Value>,
+    /// Whether the cloned value belongs to another context.
+    import: bool,
+}
+
+impl Cloner {
+    fn clone_value(&mut self, value: &Value, context: &mut Context) -> Result<Value> {
+        let object = match value {
+            Value::Object(object) => object,
+            Value::Symbol(_) => return data_clone_error("a symbol can't be cloned", context),
+            _ => return Ok(value.clone()),
+        };
+
+        if let Some(copy) = self.memory.get(value) {
+            return Ok(copy.clone());
+        }
+
+        let kind = match &object.borrow().data {
+            ObjectData::Boolean(boolean) => Ok(Kind::Wrapper(Value::from(*boolean))),
+            ObjectData::Number(number) => Ok(Kind::Wrapper(Value::from(*number))),
+            ObjectData::String(string) => Ok(Kind::Wrapper(Value::from(string.clone()))),
+            ObjectData::BigInt(bigint) => Ok(Kind::Wrapper(Value::from(bigint.clone()))),
+            ObjectData::Date(date) => Ok(Kind::Date(*date)),
+            ObjectData::RegExp(regexp) => Ok(Kind::RegExp(
+                regexp.original_source.clone(),
+                regexp.original_flags.clone(),
+            )),
+            ObjectData::Map(map) => Ok(Kind::Map(
+                map.iter()
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect(),
+            )),
+            ObjectData::Set(set) => Ok(Kind::Set(set.iter().cloned().collect())),
+            ObjectData::Array => Ok(Kind::Array),
+            ObjectData::Error(_) => Ok(Kind::Error),
+            ObjectData::Ordinary => Ok(Kind::Ordinary),
+            data => Err(format!("{} objects can't be cloned", data)),
+        };
+        let kind = match kind {
+            Ok(kind) => kind,
+            Err(message) => return data_clone_error(message, context),
+        };
+
+        let copy = match kind {
+            Kind::Wrapper(primitive) => primitive.to_object(context)?.into(),
+            Kind::Date(date) => {
+                let prototype = context.standard_objects().date_object().prototype();
+                let copy = Value::new_object(context);
+                copy.set_data(ObjectData::Date(date));
+                copy.as_object()
+                    .expect("the copy is an object")
+                    .set_prototype_instance(prototype.into());
+                copy
+            }
+            Kind::RegExp(source, flags) => {
+                let constructor = context.standard_objects().regexp_object().constructor();
+                let new_target = constructor.clone().into();
+                constructor.construct(&[source.into(), flags.into()], &new_target, context)?
+            }
+            Kind::Map(entries) => {
+                let prototype = context.standard_objects().map_object().prototype();
+                let copy = Value::new_object(context);
+                copy.set_data(ObjectData::Map(Default::default()));
+                let mut copy_object = copy.as_object().expect("the copy is an object");
+                copy_object.set_prototype_instance(prototype.into());
+                self.memory.insert(value.clone(), copy.clone());
+
+                for (key, value) in entries {
+                    let key = self.clone_value(&key, context)?;
+                    let value = self.clone_value(&value, context)?;
+                    copy_object
+                        .borrow_mut()
+                        .as_map_mut()
+                        .expect("the copy is a map")
+                        .insert(key, value);
+                }
+                return Ok(copy);
+            }
+            Kind::Set(values) => {
+                let prototype = context.standard_objects().set_object().prototype();
+                let copy = Value::new_object(context);
+                copy.set_data(ObjectData::Set(Default::default()));
+                let mut copy_object = copy.as_object().expect("the copy is an object");
+                copy_object.set_prototype_instance(prototype.into());
+                self.memory.insert(value.clone(), copy.clone());
+
+                for value in values {
+                    let value = self.clone_value(&value, context)?;
+                    copy_object
+                        .borrow_mut()
+                        .as_set_mut()
+                        .expect("the copy is a set")
+                        .add(value);
+                }
+                return Ok(copy);
+            }
+            Kind::Array => {
+                let copy = Array::new_array(context);
+                let length = self.get(object, &"length".into(), context)?;
+                copy.set_field("length", length, true, context)?;
+                self.memory.insert(value.clone(), copy.clone());
+                self.clone_properties(object, &copy, context)?;
+                return Ok(copy);
+            }
+            Kind::Error => self.clone_error(object, context)?,
+            Kind::Ordinary => {
+                let copy = Value::new_object(context);
+                self.memory.insert(value.clone(), copy.clone());
+                self.clone_properties(object, &copy, context)?;
+                return Ok(copy);
+            }
+        };
+
+        self.memory.insert(value.clone(), copy.clone());
+        Ok(copy)
+    }
+
+    /// Clones the own enumerable string keyed properties of `object` into `copy`.
+    fn clone_properties(
+        &mut self,
+        object: &GcObject,
+        copy: &Value,
+        context: &mut Context,
+    ) -> Result<()> {
+        for key in object.own_property_keys() {
+            if let PropertyKey::Symbol(_) = key {
+                continue;
+            }
+            let enumerable = object
+                .get_own_property(&key)
+                .map_or(false, |property| property.enumerable());
+            if !enumerable {
+                continue;
+            }
+
+            let value = self.get(object, &key, context)?;
+            let value = self.clone_value(&value, context)?;
+            copy.set_property(key, DataDescriptor::new(value, Attribute::all()));
+        }
+        Ok(())
+    }
+
+    /// Clones an error object, which keeps its standard error type and its message.
+    fn clone_error(&mut self, error: &GcObject, context: &mut Context) -> Result<Value> {
+        let name = self
+            .get(error, &"name".into(), context)?
+            .to_string(context)?;
+        let constructor = match name.as_str() {
+            "EvalError" => context.standard_objects().eval_error_object(),
+            "RangeError" => context.standard_objects().range_error_object(),
+            "ReferenceError" => context.standard_objects().reference_error_object(),
+            "SyntaxError" => context.standard_objects().syntax_error_object(),
+            "TypeError" => context.standard_objects().type_error_object(),
+            "URIError" => context.standard_objects().uri_error_object(),
+            _ => context.standard_objects().error_object(),
+        }
+        .constructor();
+
+        let message = error
+            .get_own_property(&"message".into())
+            .and_then(|message| Some(message.as_data_descriptor()?.value()));
+        let message = match message {
+            Some(message) => vec![message.to_string(context)?.into()],
+            None => Vec::new(),
+        };
+
+        let new_target = constructor.clone().into();
+        constructor.construct(&message, &new_target, context)
+    }
+
+    /// Gets the value of the property `key` of `object`.
+    ///
+    /// The getters of an imported object would run code of the other context, so only its data
+    /// properties can be read.
+    fn get(&self, object: &GcObject, key: &PropertyKey, context: &mut Context) -> Result<Value> {
+        if !self.import {
+            return object.get(key, object.clone().into(), context);
+        }
+
+        let mut object = object.clone();
+        loop {
+            match object.get_own_property(key) {
+                Some(property) => {
+                    return match property.as_data_descriptor() {
+                        Some(property) => Ok(property.value()),
+                        None => data_clone_error(
+                            format!("the accessor property {} can't be imported", key),
+                            context,
+                        ),
+                    }
+                }
+                None => match object.get_prototype_of().as_object() {
+                    Some(prototype) => object = prototype,
+                    None => return Ok(Value::undefined()),
+                },
+            }
+        }
+    }
+}
+
+/// Throws a `DataCloneError` with the given message.
+fn data_clone_error<M>(message: M, context: &mut Context) -> Result<Value>
+where
+    M: Into<Box<str>>,
+{
+    let constructor = context.standard_objects().error_object().constructor();
+    let new_target = constructor.clone().into();
+    let error = constructor.construct(&[message.into().into()], &new_target, context)?;
+    error.set_field("name", "DataCloneError", false, context)?;
+    Error::capture_stack(&error, context)?;
+    Err(error)
+}
@@ -0,0 +1,191 @@
+use crate::{forward, forward_val, property::Attribute, Context};
+
+#[test]
+fn primitives() {
+    let mut context = Context::new();
+    let init = r#"
+        let values = [1, "a", true, null, undefined, 10n].map(structuredClone);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "values[0]"), "1");
+    assert_eq!(forward(&mut context, "values[1]"), "\"a\"");
+    assert_eq!(forward(&mut context, "values[2]"), "true");
+    assert_eq!(forward(&mut context, "values[3]"), "null");
+    assert_eq!(forward(&mut context, "values[4]"), "undefined");
+    assert_eq!(forward(&mut context, "values[5]"), "10n");
+}
+
+#[test]
+fn nested_objects_and_arrays() {
+    let mut context = Context::new();
+    let init = r#"
+        let original = { a: 1, list: [1, { b: 2 }, "c"], nested: { d: "e" } };
+        let copy = structuredClone(original);
+        original.list[1].b = 3;
+        original.nested.d = "f";
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "copy === original"), "false");
+    assert_eq!(forward(&mut context, "copy.a"), "1");
+    assert_eq!(forward(&mut context, "Array.isArray(copy.list)"), "true");
+    assert_eq!(forward(&mut context, "copy.list.length"), "3");
+    assert_eq!(forward(&mut context, "copy.list[1].b"), "2");
+    assert_eq!(forward(&mut context, "copy.nested.d"), "\"e\"");
+}
+
+#[test]
+fn cyclic_object() {
+    let mut context = Context::new();
+    let init = r#"
+        let original = { name: "root" };
+        original.self = original;
+        original.child = { parent: original };
+        let copy = structuredClone(original);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "copy === original"), "false");
+    assert_eq!(forward(&mut context, "copy.self === copy"), "true");
+    assert_eq!(forward(&mut context, "copy.child.parent === copy"), "true");
+    assert_eq!(forward(&mut context, "copy.name"), "\"root\"");
+}
+
+#[test]
+fn shared_references() {
+    let mut context = Context::new();
+    let init = r#"
+        let shared = { value: 1 };
+        let copy = structuredClone({ first: shared, second: shared });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "copy.first === copy.second"), "true");
+    assert_eq!(forward(&mut context, "copy.first === shared"), "false");
+}
+
+#[test]
+fn map_and_set() {
+    let mut context = Context::new();
+    let init = r#"
+        let key = { k: 1 };
+        let map = new Map([[key, { v: 2 }], ["a", 3]]);
+        let set = new Set([key, 4]);
+        let copy = structuredClone({ map, set });
+        let copiedKey = copy.map.keys().next().value;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "copy.map instanceof Map"), "true");
+    assert_eq!(forward(&mut context, "copy.map === map"), "false");
+    assert_eq!(forward(&mut context, "copy.map.size"), "2");
+    assert_eq!(forward(&mut context, "copiedKey === key"), "false");
+    assert_eq!(forward(&mut context, "copiedKey.k"), "1");
+    assert_eq!(forward(&mut context, "copy.map.get(copiedKey).v"), "2");
+    assert_eq!(forward(&mut context, "copy.map.get('a')"), "3");
+    assert_eq!(forward(&mut context, "copy.set instanceof Set"), "true");
+    assert_eq!(forward(&mut context, "copy.set.has(copiedKey)"), "true");
+    assert_eq!(forward(&mut context, "copy.set.has(4)"), "true");
+}
+
+#[test]
+fn date_regexp_and_error() {
+    let mut context = Context::new();
+    let init = r#"
+        let date = new Date(1000);
+        let copy = structuredClone({ date, regexp: /a+b/gi, error: new RangeError("bad") });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "copy.date instanceof Date"), "true");
+    assert_eq!(forward(&mut context, "copy.date === date"), "false");
+    assert_eq!(forward(&mut context, "copy.date.getTime()"), "1000");
+    assert_eq!(forward(&mut context, "String(copy.regexp)"), "\"/a+b/gi\"");
+    assert_eq!(
+        forward(&mut context, "copy.error instanceof RangeError"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "copy.error.message"), "\"bad\"");
+}
+
+#[test]
+fn functions_are_rejected() {
+    let mut context = Context::new();
+    let init = r#"
+        let error;
+        try {
+            structuredClone({ f: function () {} });
+        } catch (e) {
+            error = e;
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "error.name"), "\"DataCloneError\"");
+    assert_eq!(forward(&mut context, "error instanceof Error"), "true");
+}
+
+#[test]
+fn context_structured_clone() {
+    let mut context = Context::new();
+    let original = context.eval("let o = { a: [1] }; o.o = o; o").unwrap();
+    let copy = context.structured_clone(&original).unwrap();
+    assert!(!copy.strict_equals(&original));
+
+    let inner = copy.get_field("o", &mut context).unwrap();
+    assert!(inner.strict_equals(&copy));
+
+    let function = context.eval("(function () {})").unwrap();
+    assert!(context.structured_clone(&function).is_err());
+}
+
+#[test]
+fn import_value_between_contexts() {
+    let mut source = Context::new();
+    let mut target = Context::new();
+
+    let original = forward_val(
+        &mut source,
+        r#"
+        var original = { a: 1, nested: { list: [1, { b: 2 }] }, error: new TypeError("bad") };
+        original.self = original;
+        original
+        "#,
+    )
+    .unwrap();
+    let copy = target.import_value(&original).unwrap();
+    target.register_global_property("copy", copy, Attribute::all());
+
+    forward(&mut source, "original.nested.list[1].b = 3");
+    forward(&mut target, "copy.nested.list.push(4)");
+    assert_eq!(forward(&mut source, "original.nested.list.length"), "2");
+    assert_eq!(forward(&mut target, "copy.nested.list[1].b"), "2");
+    assert_eq!(forward(&mut target, "copy.nested.list.length"), "3");
+    assert_eq!(forward(&mut target, "copy.self === copy"), "true");
+
+    // The copies use the standard objects of the target context.
+    assert_eq!(
+        forward(&mut target, "copy.nested.list instanceof Array"),
+        "true"
+    );
+    assert_eq!(forward(&mut target, "copy instanceof Object"), "true");
+    assert_eq!(
+        forward(&mut target, "copy.error instanceof TypeError"),
+        "true"
+    );
+    assert_eq!(forward(&mut target, "copy.error.message"), "\"bad\"");
+}
+
+#[test]
+fn import_value_rejects_functions_and_accessors() {
+    let mut source = Context::new();
+    let mut target = Context::new();
+
+    let function = forward_val(&mut source, "({ f: function () {} })").unwrap();
+    let error = target.import_value(&function).unwrap_err();
+    assert_eq!(
+        error
+            .get_field("name", &mut target)
+            .unwrap()
+            .display()
+            .to_string(),
+        "\"DataCloneError\""
+    );
+
+    let accessor = forward_val(&mut source, "({ get a() { return 1; } })").unwrap();
+    assert!(target.import_value(&accessor).is_err());
+}
@@ -82,6 +82,8 @@ impl BuiltIn for Symbol {
         .static_property("toPrimitive", symbol_to_primitive, attribute)
         .static_property("toStringTag", symbol_to_string_tag.clone(), attribute)
         .static_property("unscopables", symbol_unscopables, attribute)
+        .static_method(Self::r#for, "for", 1)
+        .static_method(Self::key_for, "keyFor", 1)
         .method(Self::to_string, "toString", 0)
         .accessor(
             "description",
@@ -122,7 +124,8 @@ impl Symbol {
         args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        if new_target.is_undefined() {
+        // `Symbol` can be called, but not constructed with `new`.
+        if !new_target.is_undefined() {
             return context.throw_type_error("Symbol is not a constructor");
         }
         let description = match args.get(0) {
@@ -165,6 +168,61 @@ impl Symbol {
         Ok(Value::from(format!("Symbol({})", description)))
     }
 
+    /// `Symbol.for( key )`
+    ///
+    /// This method returns the symbol registered in the global symbol registry with the given
+    /// key, creating and registering a new one if there is none.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.for
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/for
+    pub(crate) fn r#for(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let stringKey be ? ToString(key).
+        let string_key = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+        // 2. For each element e of the GlobalSymbolRegistry List, do
+        //    a. If SameValue(e.[[Key]], stringKey) is true, return e.[[Symbol]].
+        // 3. Assert: GlobalSymbolRegistry does not currently contain an entry for stringKey.
+        // 4. Let newSymbol be a new unique Symbol value whose [[Description]] value is stringKey.
+        // 5. Append the Record { [[Key]]: stringKey, [[Symbol]]: newSymbol } to the
+        //    GlobalSymbolRegistry List.
+        // 6. Return newSymbol.
+        Ok(context.symbol_registry().get_or_insert(string_key).into())
+    }
+
+    /// `Symbol.keyFor( sym )`
+    ///
+    /// This method returns the key of a symbol in the global symbol registry, or `undefined` if
+    /// the symbol isn't registered.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.keyfor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/keyFor
+    pub(crate) fn key_for(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. If Type(sym) is not Symbol, throw a TypeError exception.
+        let symbol = match args.get(0) {
+            Some(Value::Symbol(ref symbol)) => symbol.clone(),
+            _ => return context.throw_type_error("Symbol.keyFor: argument is not a symbol"),
+        };
+        // 2. For each element e of the GlobalSymbolRegistry List (see 20.4.2.2), do
+        //    a. If SameValue(e.[[Symbol]], sym) is true, return e.[[Key]].
+        // 3. Assert: GlobalSymbolRegistry does not currently contain an entry for sym.
+        // 4. Return undefined.
+        Ok(context
+            .symbol_registry()
+            .key_for(&symbol)
+            .map_or_else(Value::undefined, Value::from))
+    }
+
     /// `get Symbol.prototype.description`
     ///
     /// This accessor returns the description of the `Symbol` object.
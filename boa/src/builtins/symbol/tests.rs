@@ -11,6 +11,19 @@ fn call_symbol_and_check_return_type() {
     assert_eq!(sym.is_symbol(), true);
 }
 
+#[test]
+fn symbol_is_not_a_constructor() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new Symbol('x') } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "typeof Symbol('x')"), "\"symbol\"");
+}
+
 #[test]
 fn print_symbol_expect_description() {
     let mut context = Context::new();
@@ -37,3 +50,47 @@ fn symbol_access() {
     assert_eq!(forward(&mut context, "x[sym2]"), "20");
     assert_eq!(forward(&mut context, "x['Symbol(Hello)']"), "undefined");
 }
+
+#[test]
+fn symbol_registry() {
+    let mut context = Context::new();
+    let init = r#"
+        var registered = Symbol.for("x");
+        var plain = Symbol("x");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "Symbol.for('x') === registered"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Symbol.for('y') === registered"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "plain === registered"), "false");
+    assert_eq!(forward(&mut context, "registered.description"), "\"x\"");
+    assert_eq!(forward(&mut context, "Symbol.keyFor(registered)"), "\"x\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Symbol.for(Symbol.keyFor(registered)) === registered"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Symbol.keyFor(plain)"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Symbol.keyFor(Symbol.iterator)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "Symbol.for() === Symbol.for('undefined')"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Symbol.keyFor('x') } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
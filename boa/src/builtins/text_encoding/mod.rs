@@ -0,0 +1,407 @@
+//! This module implements the global `TextEncoder` and `TextDecoder` objects.
+//!
+//! A `TextEncoder` converts strings to the bytes of their UTF-8 encoding, in a `Uint8Array`, and a
+//! `TextDecoder` converts UTF-8 bytes back to a string. UTF-8 is the only supported encoding.
+//!
+//! The decoder replaces each invalid byte sequence with the replacement character U+FFFD, unless
+//! it was created with the `fatal` option, in which case it throws a `TypeError`.
+//!
+//! More information:
+//!  - [Encoding reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://encoding.spec.whatwg.org/#api
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Encoding_API
+
+use crate::{
+    builtins::{BuiltIn, TypedArray},
+    gc::{empty_trace, Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The name of the only supported encoding.
+const UTF_8: &str = "utf-8";
+
+/// The message of the `TypeError` thrown when decoding something that isn't a buffer.
+const INVALID_INPUT: &str = "The input of TextDecoder.prototype.decode must be a buffer";
+
+/// The labels of the UTF-8 encoding.
+///
+/// More information:
+///  - [Encoding reference][spec]
+///
+/// [spec]: https://encoding.spec.whatwg.org/#names-and-labels
+const UTF_8_LABELS: [&str; 6] = [
+    "unicode-1-1-utf-8",
+    "unicode11utf8",
+    "unicode20utf8",
+    "utf-8",
+    "utf8",
+    "x-unicode20utf8",
+];
+
+/// Gets the prototype of a new object, from the `prototype` property of `new_target`, or the
+/// default prototype if it isn't an object.
+fn prototype_from_new_target(
+    new_target: &Value,
+    default: GcObject,
+    context: &mut Context,
+) -> Result<GcObject> {
+    Ok(new_target
+        .as_object()
+        .and_then(|obj| {
+            obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                .map(|o| o.as_object())
+                .transpose()
+        })
+        .transpose()?
+        .unwrap_or(default))
+}
+
+/// The JavaScript `TextEncoder` object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct TextEncoder;
+
+impl BuiltIn for TextEncoder {
+    const NAME: &'static str = "TextEncoder";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let encoding_getter = FunctionBuilder::new(context, Self::encoding_getter)
+            .name("get encoding")
+            .constructable(false)
+            .callable(true)
+            .build();
+
+        let text_encoder_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().text_encoder_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .accessor(
+            "encoding",
+            Some(encoding_getter),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::encode, "encode", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        (Self::NAME, text_encoder_object.into(), Self::attribute())
+    }
+}
+
+impl TextEncoder {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `TextEncoder()`
+    ///
+    /// More information:
+    ///  - [Encoding reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textencoder
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder/TextEncoder
+    pub(crate) fn constructor(
+        new_target: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin TextEncoder constructor without new is forbidden",
+            );
+        }
+        let default = context.standard_objects().text_encoder_object().prototype();
+        let prototype = prototype_from_new_target(new_target, default, context)?;
+
+        let mut object = context.construct_object();
+        object.set_prototype_instance(prototype.into());
+        object.borrow_mut().data = ObjectData::TextEncoder;
+        Ok(object.into())
+    }
+
+    /// Throws a `TypeError` if `this` isn't a `TextEncoder`.
+    fn check_this(this: &Value, context: &mut Context) -> Result<()> {
+        match this.as_object() {
+            Some(object) if object.borrow().is_text_encoder() => Ok(()),
+            _ => Err(context.construct_type_error("'this' is not a TextEncoder")),
+        }
+    }
+
+    /// `get TextEncoder.prototype.encoding`
+    ///
+    /// The encoding of a `TextEncoder` is always `"utf-8"`.
+    ///
+    /// More information:
+    ///  - [Encoding reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textencoder-encoding
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder/encoding
+    pub(crate) fn encoding_getter(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        Self::check_this(this, context)?;
+        Ok(UTF_8.into())
+    }
+
+    /// `TextEncoder.prototype.encode( [ input ] )`
+    ///
+    /// Returns a `Uint8Array` holding the UTF-8 encoding of the input string.
+    ///
+    /// More information:
+    ///  - [Encoding reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textencoder-encode
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextEncoder/encode
+    pub(crate) fn encode(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        Self::check_this(this, context)?;
+        let input = match args.get(0) {
+            None | Some(Value::Undefined) => Default::default(),
+            Some(input) => input.to_string(context)?,
+        };
+        let bytes = input.as_bytes().to_vec();
+        Ok(TypedArray::create_uint8_array(bytes, context)?.into())
+    }
+}
+
+/// The internal representation of a `TextDecoder` object.
+#[derive(Debug, Clone, Copy, Finalize)]
+pub struct TextDecoder {
+    fatal: bool,
+    ignore_bom: bool,
+}
+
+unsafe impl Trace for TextDecoder {
+    empty_trace!();
+}
+
+impl BuiltIn for TextDecoder {
+    const NAME: &'static str = "TextDecoder";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let encoding_getter = FunctionBuilder::new(context, Self::encoding_getter)
+            .name("get encoding")
+            .constructable(false)
+            .callable(true)
+            .build();
+        let fatal_getter = FunctionBuilder::new(context, Self::fatal_getter)
+            .name("get fatal")
+            .constructable(false)
+            .callable(true)
+            .build();
+        let ignore_bom_getter = FunctionBuilder::new(context, Self::ignore_bom_getter)
+            .name("get ignoreBOM")
+            .constructable(false)
+            .callable(true)
+            .build();
+
+        let text_decoder_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().text_decoder_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .accessor(
+            "encoding",
+            Some(encoding_getter),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor("fatal", Some(fatal_getter), None, Attribute::CONFIGURABLE)
+        .accessor(
+            "ignoreBOM",
+            Some(ignore_bom_getter),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::decode, "decode", 0)
+        .property(
+            WellKnownSymbols::to_string_tag(),
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        (Self::NAME, text_decoder_object.into(), Self::attribute())
+    }
+}
+
+impl TextDecoder {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `TextDecoder( [ label [ , options ] ] )`
+    ///
+    /// Throws a `RangeError` if the label isn't a label of the UTF-8 encoding.
+    ///
+    /// More information:
+    ///  - [Encoding reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/TextDecoder
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin TextDecoder constructor without new is forbidden",
+            );
+        }
+        let default = context.standard_objects().text_decoder_object().prototype();
+        let prototype = prototype_from_new_target(new_target, default, context)?;
+
+        // 1. Let encoding be the result of getting an encoding from label.
+        if let Some(label) = args.get(0).filter(|label| !label.is_undefined()) {
+            let label = label.to_string(context)?;
+            // 2. If encoding is failure or replacement, then throw a RangeError.
+            let trimmed = label.trim_matches(|c| matches!(c, '\t' | '\n' | '\x0C' | '\r' | ' '));
+            if !UTF_8_LABELS.contains(&trimmed.to_ascii_lowercase().as_str()) {
+                return context.throw_range_error(format!(
+                    "The encoding label provided ('{}') is invalid",
+                    label
+                ));
+            }
+        }
+
+        let options = args.get(1).cloned().unwrap_or_default();
+        let (fatal, ignore_bom) = if options.is_null_or_undefined() {
+            (false, false)
+        } else {
+            (
+                options.get_field("fatal", context)?.to_boolean(),
+                options.get_field("ignoreBOM", context)?.to_boolean(),
+            )
+        };
+
+        let mut object = context.construct_object();
+        object.set_prototype_instance(prototype.into());
+        object.borrow_mut().data = ObjectData::TextDecoder(TextDecoder { fatal, ignore_bom });
+        Ok(object.into())
+    }
+
+    /// Gets the `TextDecoder` data of `this`.
+    fn this_text_decoder(this: &Value, context: &mut Context) -> Result<TextDecoder> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_text_decoder().copied())
+            .ok_or_else(|| context.construct_type_error("'this' is not a TextDecoder"))
+    }
+
+    /// `get TextDecoder.prototype.encoding`
+    ///
+    /// More information:
+    ///  - [Encoding reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder-encoding
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/encoding
+    pub(crate) fn encoding_getter(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        Self::this_text_decoder(this, context)?;
+        Ok(UTF_8.into())
+    }
+
+    /// `get TextDecoder.prototype.fatal`
+    ///
+    /// More information:
+    ///  - [Encoding reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder-fatal
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/fatal
+    pub(crate) fn fatal_getter(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Ok(Self::this_text_decoder(this, context)?.fatal.into())
+    }
+
+    /// `get TextDecoder.prototype.ignoreBOM`
+    ///
+    /// More information:
+    ///  - [Encoding reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder-ignorebom
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/ignoreBOM
+    pub(crate) fn ignore_bom_getter(
+        this: &Value,
+        _: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        Ok(Self::this_text_decoder(this, context)?.ignore_bom.into())
+    }
+
+    /// `TextDecoder.prototype.decode( [ input ] )`
+    ///
+    /// Decodes the bytes of an `ArrayBuffer` or a typed array as UTF-8.
+    ///
+    /// More information:
+    ///  - [Encoding reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://encoding.spec.whatwg.org/#dom-textdecoder-decode
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/TextDecoder/decode
+    pub(crate) fn decode(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let decoder = Self::this_text_decoder(this, context)?;
+        let bytes = match args.get(0) {
+            None | Some(Value::Undefined) => Vec::new(),
+            Some(Value::Object(object)) => {
+                let object = object.borrow();
+                if let Some(buffer) = object.as_array_buffer() {
+                    buffer.bytes().to_vec()
+                } else if let Some(typed_array) = object.as_typed_array() {
+                    typed_array.bytes()
+                } else {
+                    return context.throw_type_error(INVALID_INPUT);
+                }
+            }
+            Some(_) => return context.throw_type_error(INVALID_INPUT),
+        };
+
+        let mut input = bytes.as_slice();
+        if !decoder.ignore_bom && input.starts_with(b"\xEF\xBB\xBF") {
+            input = &input[3..];
+        }
+
+        if decoder.fatal {
+            match std::str::from_utf8(input) {
+                Ok(string) => Ok(string.into()),
+                Err(_) => context.throw_type_error("The encoded data was not valid utf-8"),
+            }
+        } else {
+            Ok(String::from_utf8_lossy(input).as_ref().into())
+        }
+    }
+}
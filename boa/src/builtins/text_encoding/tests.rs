@@ -0,0 +1,97 @@
+use crate::{forward, Context};
+
+#[test]
+fn encode() {
+    let mut context = Context::new();
+    let init = r#"
+        var encoder = new TextEncoder();
+        var bytes = encoder.encode("aé€😀");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "encoder.encoding"), "\"utf-8\"");
+    assert_eq!(forward(&mut context, "bytes instanceof Uint8Array"), "true");
+    assert_eq!(
+        forward(&mut context, "[...bytes].join()"),
+        "\"97,195,169,226,130,172,240,159,152,128\""
+    );
+    assert_eq!(forward(&mut context, "encoder.encode().length"), "0");
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { TextEncoder() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn round_trip() {
+    let mut context = Context::new();
+    let init = r#"
+        var string = "Grüße, 世界! 😀";
+        var bytes = new TextEncoder().encode(string);
+        var decoder = new TextDecoder();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "decoder.decode(bytes) === string"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "decoder.decode(bytes.buffer) === string"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "decoder.decode(bytes.subarray(9, 15))"),
+        "\"世界\""
+    );
+    assert_eq!(forward(&mut context, "decoder.decode()"), "\"\"");
+}
+
+#[test]
+fn decode_invalid() {
+    let mut context = Context::new();
+    let init = r#"
+        var invalid = new Uint8Array([0x61, 0xff, 0x62, 0xe2, 0x82]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "new TextDecoder().decode(invalid) === 'a\\uFFFDb\\uFFFD'"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new TextDecoder('utf-8', { fatal: true }).decode(invalid) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn decoder_options() {
+    let mut context = Context::new();
+    let init = r#"
+        var bom = new Uint8Array([0xef, 0xbb, 0xbf, 0x61]);
+        var decoder = new TextDecoder(" UTF8 ", { ignoreBOM: true });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "decoder.encoding"), "\"utf-8\"");
+    assert_eq!(forward(&mut context, "decoder.fatal"), "false");
+    assert_eq!(forward(&mut context, "decoder.ignoreBOM"), "true");
+    assert_eq!(forward(&mut context, "decoder.decode(bom).length"), "2");
+    assert_eq!(
+        forward(&mut context, "new TextDecoder().decode(bom)"),
+        "\"a\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new TextDecoder('latin1') } catch (e) { e instanceof RangeError }"
+        ),
+        "true"
+    );
+}
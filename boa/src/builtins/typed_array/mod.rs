@@ -0,0 +1,887 @@
+//! This module implements the typed array objects, like `Uint8Array` or `Float64Array`.
+//!
+//! A typed array is a view of an `ArrayBuffer` that reads and writes its bytes as numbers of a
+//! fixed type. All of the typed array constructors inherit from the `%TypedArray%` intrinsic
+//! object, which isn't a global binding, and whose prototype holds the methods they share.
+//!
+//! Elements are stored in little-endian byte order.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-typedarray-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray
+
+use crate::{
+    builtins::{
+        array::array_iterator::{ArrayIterationKind, ArrayIterator},
+        array_buffer::ArrayBuffer,
+        function::NativeFunction,
+        iterable::get_iterator,
+        number::{f64_to_int32, f64_to_uint32},
+        Array, Number,
+    },
+    context::{StandardConstructor, StandardObjects},
+    gc::{empty_trace, Finalize, Trace},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData, PROTOTYPE},
+    property::{Attribute, DataDescriptor, PropertyKey},
+    symbol::WellKnownSymbols,
+    value::IntegerOrInfinity,
+    BoaProfiler, Context, Result, Value,
+};
+use std::convert::TryInto;
+
+#[cfg(test)]
+mod tests;
+
+/// The element type of a typed array.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+pub enum TypedArrayKind {
+    Int8,
+    Uint8,
+    Uint8Clamped,
+    Int16,
+    Uint16,
+    Int32,
+    Uint32,
+    Float32,
+    Float64,
+}
+
+unsafe impl Trace for TypedArrayKind {
+    empty_trace!();
+}
+
+impl TypedArrayKind {
+    /// All the element types, in the order their constructors are defined.
+    const ALL: [Self; 9] = [
+        Self::Int8,
+        Self::Uint8,
+        Self::Uint8Clamped,
+        Self::Int16,
+        Self::Uint16,
+        Self::Int32,
+        Self::Uint32,
+        Self::Float32,
+        Self::Float64,
+    ];
+
+    /// The name of the constructor of the typed arrays with this element type.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Int8 => "Int8Array",
+            Self::Uint8 => "Uint8Array",
+            Self::Uint8Clamped => "Uint8ClampedArray",
+            Self::Int16 => "Int16Array",
+            Self::Uint16 => "Uint16Array",
+            Self::Int32 => "Int32Array",
+            Self::Uint32 => "Uint32Array",
+            Self::Float32 => "Float32Array",
+            Self::Float64 => "Float64Array",
+        }
+    }
+
+    /// The size of an element, in bytes.
+    pub fn element_size(self) -> usize {
+        match self {
+            Self::Int8 | Self::Uint8 | Self::Uint8Clamped => 1,
+            Self::Int16 | Self::Uint16 => 2,
+            Self::Int32 | Self::Uint32 | Self::Float32 => 4,
+            Self::Float64 => 8,
+        }
+    }
+
+    fn standard_constructor(self, standard_objects: &StandardObjects) -> &StandardConstructor {
+        match self {
+            Self::Int8 => standard_objects.int8_array_object(),
+            Self::Uint8 => standard_objects.uint8_array_object(),
+            Self::Uint8Clamped => standard_objects.uint8_clamped_array_object(),
+            Self::Int16 => standard_objects.int16_array_object(),
+            Self::Uint16 => standard_objects.uint16_array_object(),
+            Self::Int32 => standard_objects.int32_array_object(),
+            Self::Uint32 => standard_objects.uint32_array_object(),
+            Self::Float32 => standard_objects.float32_array_object(),
+            Self::Float64 => standard_objects.float64_array_object(),
+        }
+    }
+
+    /// Reads an element from its bytes.
+    fn get_value(self, bytes: &[u8]) -> Value {
+        match self {
+            Self::Int8 => i32::from(bytes[0] as i8).into(),
+            Self::Uint8 | Self::Uint8Clamped => i32::from(bytes[0]).into(),
+            Self::Int16 => i32::from(i16::from_le_bytes(Self::array(bytes))).into(),
+            Self::Uint16 => i32::from(u16::from_le_bytes(Self::array(bytes))).into(),
+            Self::Int32 => i32::from_le_bytes(Self::array(bytes)).into(),
+            Self::Uint32 => u32::from_le_bytes(Self::array(bytes)).into(),
+            Self::Float32 => f64::from(f32::from_le_bytes(Self::array(bytes))).into(),
+            Self::Float64 => f64::from_le_bytes(Self::array(bytes)).into(),
+        }
+    }
+
+    /// Writes a number to the bytes of an element, converting it to the element type.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-numerictorawbytes
+    fn set_value(self, bytes: &mut [u8], value: f64) {
+        match self {
+            Self::Int8 => bytes.copy_from_slice(&(f64_to_int32(value) as i8).to_le_bytes()),
+            Self::Uint8 => bytes.copy_from_slice(&(f64_to_uint32(value) as u8).to_le_bytes()),
+            Self::Uint8Clamped => bytes[0] = to_uint8_clamp(value),
+            Self::Int16 => bytes.copy_from_slice(&(f64_to_int32(value) as i16).to_le_bytes()),
+            Self::Uint16 => bytes.copy_from_slice(&(f64_to_uint32(value) as u16).to_le_bytes()),
+            Self::Int32 => bytes.copy_from_slice(&f64_to_int32(value).to_le_bytes()),
+            Self::Uint32 => bytes.copy_from_slice(&f64_to_uint32(value).to_le_bytes()),
+            Self::Float32 => bytes.copy_from_slice(&(value as f32).to_le_bytes()),
+            Self::Float64 => bytes.copy_from_slice(&value.to_le_bytes()),
+        }
+    }
+
+    fn array<const N: usize>(bytes: &[u8]) -> [u8; N] {
+        bytes
+            .try_into()
+            .expect("the bytes of an element have the element size")
+    }
+}
+
+/// Converts a number to an 8 bit unsigned integer, clamping it to the range of the type and
+/// rounding it half to even.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-touint8clamp
+#[allow(clippy::float_cmp)]
+fn to_uint8_clamp(number: f64) -> u8 {
+    if number.is_nan() || number <= 0.0 {
+        return 0;
+    }
+    if number >= 255.0 {
+        return 255;
+    }
+    let floor = number.floor();
+    if floor + 0.5 < number || (floor + 0.5 == number && floor % 2.0 != 0.0) {
+        floor as u8 + 1
+    } else {
+        floor as u8
+    }
+}
+
+/// Converts a property key to a number if it is a canonical numeric string, that is, the result
+/// of converting a number to a string, or `"-0"`.
+///
+/// Typed arrays never look up these keys as ordinary properties, even if they aren't valid
+/// indices of their elements.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-canonicalnumericindexstring
+pub(crate) fn canonical_numeric_index(key: &PropertyKey) -> Option<f64> {
+    let string = match key {
+        PropertyKey::Index(index) => return Some(f64::from(*index)),
+        PropertyKey::String(string) => string.as_str(),
+        PropertyKey::Symbol(_) => return None,
+    };
+    let number = match string {
+        "-0" => return Some(-0.0),
+        "Infinity" => f64::INFINITY,
+        "-Infinity" => f64::NEG_INFINITY,
+        "NaN" => f64::NAN,
+        _ => string.parse().ok()?,
+    };
+    if Number::to_native_string(number) == string {
+        Some(number)
+    } else {
+        None
+    }
+}
+
+/// The internal representation of a typed array object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct TypedArray {
+    kind: TypedArrayKind,
+    buffer: GcObject,
+    byte_offset: usize,
+    length: usize,
+}
+
+impl TypedArray {
+    /// The element type of the typed array.
+    #[inline]
+    pub fn kind(&self) -> TypedArrayKind {
+        self.kind
+    }
+
+    /// The `ArrayBuffer` object viewed by the typed array.
+    #[inline]
+    pub fn buffer(&self) -> &GcObject {
+        &self.buffer
+    }
+
+    /// The offset of the first element in the buffer, in bytes.
+    #[inline]
+    pub fn byte_offset(&self) -> usize {
+        self.byte_offset
+    }
+
+    /// The number of elements of the typed array.
+    #[inline]
+    pub fn length(&self) -> usize {
+        self.length
+    }
+
+    /// The length of the typed array in bytes.
+    #[inline]
+    pub fn byte_length(&self) -> usize {
+        self.length * self.kind.element_size()
+    }
+
+    /// Checks if `index` is the index of an element of the typed array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isvalidintegerindex
+    pub(crate) fn is_valid_index(&self, index: f64) -> bool {
+        index.fract() == 0.0
+            && !(index == 0.0 && index.is_sign_negative())
+            && index >= 0.0
+            && index < self.length as f64
+    }
+
+    /// Reads the element at `index`, if there is one.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-integerindexedelementget
+    pub(crate) fn get_element(&self, index: f64) -> Option<Value> {
+        if !self.is_valid_index(index) {
+            return None;
+        }
+
+        let size = self.kind.element_size();
+        let start = self.byte_offset + index as usize * size;
+        let buffer = self.buffer.borrow();
+        let bytes = buffer
+            .as_array_buffer()
+            .expect("typed arrays view array buffers")
+            .bytes();
+        Some(self.kind.get_value(&bytes[start..start + size]))
+    }
+
+    /// Writes `value` to the element at `index`. Nothing is written if there is no such element.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-integerindexedelementset
+    pub(crate) fn set_element(&self, index: f64, value: f64) {
+        if !self.is_valid_index(index) {
+            return;
+        }
+
+        let size = self.kind.element_size();
+        let start = self.byte_offset + index as usize * size;
+        let mut buffer = self.buffer.borrow_mut();
+        let bytes = buffer
+            .as_array_buffer_mut()
+            .expect("typed arrays view array buffers")
+            .bytes_mut();
+        self.kind.set_value(&mut bytes[start..start + size], value);
+    }
+
+    /// Copies the bytes viewed by the typed array.
+    pub(crate) fn bytes(&self) -> Vec<u8> {
+        let buffer = self.buffer.borrow();
+        let bytes = buffer
+            .as_array_buffer()
+            .expect("typed arrays view array buffers")
+            .bytes();
+        bytes[self.byte_offset..self.byte_offset + self.byte_length()].to_vec()
+    }
+
+    /// Reads all the elements of the typed array.
+    fn elements(&self) -> Vec<Value> {
+        (0..self.length)
+            .map(|index| self.get_element(index as f64).unwrap_or_default())
+            .collect()
+    }
+}
+
+macro_rules! typed_array_constructors {
+    ($($kind:ident => $constructor:ident),* $(,)?) => {
+        $(
+            fn $constructor(
+                new_target: &Value,
+                args: &[Value],
+                context: &mut Context,
+            ) -> Result<Value> {
+                TypedArray::constructor(TypedArrayKind::$kind, new_target, args, context)
+            }
+        )*
+
+        impl TypedArrayKind {
+            /// The native function of the constructor of the typed arrays with this element type.
+            fn constructor(self) -> NativeFunction {
+                match self {
+                    $(Self::$kind => $constructor,)*
+                }
+            }
+        }
+    };
+}
+
+typed_array_constructors! {
+    Int8 => int8_array,
+    Uint8 => uint8_array,
+    Uint8Clamped => uint8_clamped_array,
+    Int16 => int16_array,
+    Uint16 => uint16_array,
+    Int32 => int32_array,
+    Uint32 => uint32_array,
+    Float32 => float32_array,
+    Float64 => float64_array,
+}
+
+impl TypedArray {
+    /// The amount of arguments the typed array constructors take.
+    pub(crate) const LENGTH: usize = 3;
+
+    /// Initializes the `%TypedArray%` intrinsic object, and registers the typed array
+    /// constructors on the global object.
+    pub(crate) fn init(context: &mut Context) {
+        let _timer = BoaProfiler::global().start_event("TypedArray", "init");
+
+        let typed_array = Self::init_intrinsic(context);
+        let typed_array_prototype = context.standard_objects().typed_array_object().prototype();
+        for kind in TypedArrayKind::ALL.iter().copied() {
+            let bytes_per_element = kind.element_size();
+            let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
+            let mut constructor = ConstructorBuilder::with_standard_object(
+                context,
+                kind.constructor(),
+                kind.standard_constructor(context.standard_objects())
+                    .clone(),
+            )
+            .name(kind.name())
+            .length(Self::LENGTH)
+            .inherit(typed_array_prototype.clone().into())
+            .static_property("BYTES_PER_ELEMENT", bytes_per_element, attribute)
+            .property("BYTES_PER_ELEMENT", bytes_per_element, attribute)
+            .build();
+            constructor.set_prototype_instance(typed_array.clone().into());
+
+            let property = DataDescriptor::new(
+                constructor,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            );
+            context
+                .global_object()
+                .borrow_mut()
+                .insert(kind.name(), property);
+        }
+    }
+
+    /// Initializes the `%TypedArray%` intrinsic object.
+    fn init_intrinsic(context: &mut Context) -> GcObject {
+        let getter = |context: &mut Context, function: NativeFunction, name: &str| {
+            FunctionBuilder::new(context, function)
+                .name(name)
+                .constructable(false)
+                .callable(true)
+                .build()
+        };
+        let buffer_getter = getter(context, Self::buffer_getter, "get buffer");
+        let byte_length_getter = getter(context, Self::byte_length_getter, "get byteLength");
+        let byte_offset_getter = getter(context, Self::byte_offset_getter, "get byteOffset");
+        let length_getter = getter(context, Self::length_getter, "get length");
+        let get_to_string_tag =
+            getter(context, Self::get_to_string_tag, "get [Symbol.toStringTag]");
+
+        let values_function = FunctionBuilder::new(context, Self::values)
+            .name("values")
+            .length(0)
+            .callable(true)
+            .constructable(false)
+            .build();
+
+        ConstructorBuilder::with_standard_object(
+            context,
+            Self::intrinsic_constructor,
+            context.standard_objects().typed_array_object().clone(),
+        )
+        .name("TypedArray")
+        .length(0)
+        .accessor("buffer", Some(buffer_getter), None, Attribute::CONFIGURABLE)
+        .accessor(
+            "byteLength",
+            Some(byte_length_getter),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor(
+            "byteOffset",
+            Some(byte_offset_getter),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .accessor("length", Some(length_getter), None, Attribute::CONFIGURABLE)
+        .accessor(
+            WellKnownSymbols::to_string_tag(),
+            Some(get_to_string_tag),
+            None,
+            Attribute::CONFIGURABLE,
+        )
+        .method(Self::entries, "entries", 0)
+        .method(Self::keys, "keys", 0)
+        .method(Self::set, "set", 1)
+        .method(Self::subarray, "subarray", 2)
+        .property(
+            "values",
+            values_function.clone(),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .property(
+            WellKnownSymbols::iterator(),
+            values_function,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build()
+    }
+
+    /// The `%TypedArray%` intrinsic object, which can't be called nor constructed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%
+    fn intrinsic_constructor(_: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        context.throw_type_error("Abstract class TypedArray not directly constructable")
+    }
+
+    /// `TypedArray( ...args )`
+    ///
+    /// Creates a typed array of `kind` elements, from a length, another typed array, an
+    /// iterable or array-like object, or a view of an `ArrayBuffer`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-typedarray
+    fn constructor(
+        kind: TypedArrayKind,
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(format!(
+                "calling a builtin {} constructor without new is forbidden",
+                kind.name()
+            ));
+        }
+        let default_prototype = kind
+            .standard_constructor(context.standard_objects())
+            .prototype();
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or(default_prototype);
+
+        let first = args.get(0).cloned().unwrap_or_default();
+        let typed_array = if let Some(object) = first.as_object() {
+            let source = object.borrow().as_typed_array().cloned();
+            let is_buffer = object.borrow().as_array_buffer().is_some();
+            if let Some(source) = source {
+                Self::from_elements(kind, source.elements(), context)?
+            } else if is_buffer {
+                Self::from_array_buffer(kind, object, args.get(1), args.get(2), context)?
+            } else {
+                Self::from_object(kind, &object, context)?
+            }
+        } else {
+            let length = first.to_index(context)?;
+            Self::allocate(kind, length, context)?
+        };
+
+        let mut object = Self::create(typed_array, context);
+        object.set_prototype_instance(prototype.into());
+        Ok(object.into())
+    }
+
+    /// Creates a typed array object with the default prototype of its element type.
+    fn create(typed_array: TypedArray, context: &mut Context) -> GcObject {
+        let prototype = typed_array
+            .kind
+            .standard_constructor(context.standard_objects())
+            .prototype();
+        let mut object = context.construct_object();
+        object.set_prototype_instance(prototype.into());
+        object.borrow_mut().data = ObjectData::TypedArray(typed_array);
+        object
+    }
+
+    /// Creates a `Uint8Array` object holding `bytes`, in a new `ArrayBuffer`.
+    pub(crate) fn create_uint8_array(bytes: Vec<u8>, context: &mut Context) -> Result<GcObject> {
+        let length = bytes.len();
+        let typed_array = TypedArray {
+            kind: TypedArrayKind::Uint8,
+            buffer: ArrayBuffer::from_bytes(bytes, context)?,
+            byte_offset: 0,
+            length,
+        };
+        Ok(Self::create(typed_array, context))
+    }
+
+    /// Creates a typed array of `length` zeroed elements, in a new `ArrayBuffer`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-allocatetypedarraybuffer
+    fn allocate(kind: TypedArrayKind, length: usize, context: &mut Context) -> Result<TypedArray> {
+        let byte_length = length
+            .checked_mul(kind.element_size())
+            .ok_or_else(|| context.construct_range_error("Array buffer allocation failed"))?;
+        Ok(TypedArray {
+            kind,
+            buffer: ArrayBuffer::allocate(byte_length, context)?,
+            byte_offset: 0,
+            length,
+        })
+    }
+
+    /// Creates a typed array holding `elements`, converted to numbers.
+    fn from_elements(
+        kind: TypedArrayKind,
+        elements: Vec<Value>,
+        context: &mut Context,
+    ) -> Result<TypedArray> {
+        let typed_array = Self::allocate(kind, elements.len(), context)?;
+        for (index, element) in elements.into_iter().enumerate() {
+            let number = element.to_number(context)?;
+            typed_array.set_element(index as f64, number);
+        }
+        Ok(typed_array)
+    }
+
+    /// Creates a typed array from the values of an iterable object, or from the elements of an
+    /// array-like object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromlist
+    fn from_object(
+        kind: TypedArrayKind,
+        object: &GcObject,
+        context: &mut Context,
+    ) -> Result<TypedArray> {
+        let mut elements = Vec::new();
+        if object
+            .get_method(context, WellKnownSymbols::iterator())?
+            .is_some()
+        {
+            let iterator = get_iterator(context, object.clone().into())?;
+            loop {
+                let next = iterator.next(context)?;
+                if next.is_done() {
+                    break;
+                }
+                elements.push(next.value());
+            }
+        } else {
+            let array_like = Value::from(object.clone());
+            let length = array_like
+                .get_field("length", context)?
+                .to_length(context)?;
+            for index in 0..length {
+                elements.push(array_like.get_field(index, context)?);
+            }
+        }
+        Self::from_elements(kind, elements, context)
+    }
+
+    /// Creates a typed array viewing the bytes of an `ArrayBuffer`, from `byte_offset` to the
+    /// end of the buffer, or to the end of its `length` elements.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-initializetypedarrayfromarraybuffer
+    fn from_array_buffer(
+        kind: TypedArrayKind,
+        buffer: GcObject,
+        byte_offset: Option<&Value>,
+        length: Option<&Value>,
+        context: &mut Context,
+    ) -> Result<TypedArray> {
+        let size = kind.element_size();
+        let byte_offset = byte_offset.cloned().unwrap_or_default().to_index(context)?;
+        if byte_offset % size != 0 {
+            return Err(context.construct_range_error(format!(
+                "start offset of {} should be a multiple of {}",
+                kind.name(),
+                size
+            )));
+        }
+        let length = match length {
+            Some(length) if !length.is_undefined() => Some(length.to_index(context)?),
+            _ => None,
+        };
+
+        let buffer_byte_length = buffer
+            .borrow()
+            .as_array_buffer()
+            .map_or(0, ArrayBuffer::byte_length);
+        let byte_length = match length {
+            Some(length) => {
+                let byte_length = length.checked_mul(size).filter(|byte_length| {
+                    byte_offset
+                        .checked_add(*byte_length)
+                        .map_or(false, |end| end <= buffer_byte_length)
+                });
+                byte_length.ok_or_else(|| {
+                    context.construct_range_error(format!("invalid typed array length: {}", length))
+                })?
+            }
+            None if buffer_byte_length % size != 0 => {
+                return Err(context.construct_range_error(format!(
+                    "byte length of {} should be a multiple of {}",
+                    kind.name(),
+                    size
+                )))
+            }
+            None if byte_offset > buffer_byte_length => {
+                return Err(context.construct_range_error(format!(
+                    "start offset {} is outside the bounds of the buffer",
+                    byte_offset
+                )))
+            }
+            None => buffer_byte_length - byte_offset,
+        };
+
+        Ok(TypedArray {
+            kind,
+            buffer,
+            byte_offset,
+            length: byte_length / size,
+        })
+    }
+
+    /// Gets the typed array data of `this`, or throws a `TypeError` if it isn't a typed array.
+    fn this_typed_array(this: &Value, context: &mut Context) -> Result<TypedArray> {
+        this.as_object()
+            .and_then(|object| object.borrow().as_typed_array().cloned())
+            .ok_or_else(|| context.construct_type_error("'this' is not a typed array"))
+    }
+
+    /// `get %TypedArray%.prototype.buffer`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.buffer
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/buffer
+    fn buffer_getter(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Ok(Self::this_typed_array(this, context)?.buffer.clone().into())
+    }
+
+    /// `get %TypedArray%.prototype.byteLength`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.bytelength
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/byteLength
+    fn byte_length_getter(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Ok(Self::this_typed_array(this, context)?.byte_length().into())
+    }
+
+    /// `get %TypedArray%.prototype.byteOffset`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.byteoffset
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/byteOffset
+    fn byte_offset_getter(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Ok(Self::this_typed_array(this, context)?.byte_offset.into())
+    }
+
+    /// `get %TypedArray%.prototype.length`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype.length
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/length
+    fn length_getter(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Ok(Self::this_typed_array(this, context)?.length.into())
+    }
+
+    /// `get %TypedArray%.prototype [ @@toStringTag ]`
+    ///
+    /// Returns the name of the constructor of the typed array, or `undefined` if `this` isn't a
+    /// typed array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-%typedarray%.prototype-@@tostringtag
+    fn get_to_string_tag(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this
+            .as_object()
+            .and_then(|object| {
+                object
+                    .borrow()
+                    .as_typed_array()
+                    .map(|typed_array| typed_array.kind.name())
+            })
+            .map_or_else(Value::undefined, Value::from))
+    }
+
+    /// `%TypedArray%.prototype.set( source [ , offset ] )`
+    ///
+    /// Copies the elements of a typed array or array-like object into this typed array, starting
+    /// at the element `offset`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/set
+    fn set(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let target = Self::this_typed_array(this, context)?;
+        let offset = match args
+            .get(1)
+            .cloned()
+            .unwrap_or_default()
+            .to_integer_or_infinity(context)?
+        {
+            IntegerOrInfinity::Integer(offset) if offset >= 0 => offset as usize,
+            IntegerOrInfinity::PositiveInfinity => usize::MAX,
+            _ => return context.throw_range_error("offset is out of bounds"),
+        };
+
+        let source = args.get(0).cloned().unwrap_or_default();
+        let source_typed_array = source
+            .as_object()
+            .and_then(|object| object.borrow().as_typed_array().cloned());
+
+        if let Some(source) = source_typed_array {
+            if source.length.saturating_add(offset) > target.length {
+                return context.throw_range_error("offset is out of bounds");
+            }
+            // The elements are read before any is written, since both typed arrays can view the
+            // same bytes.
+            for (index, element) in source.elements().into_iter().enumerate() {
+                let number = element.as_number().unwrap_or_default();
+                target.set_element((offset + index) as f64, number);
+            }
+        } else {
+            let source = Value::from(source.to_object(context)?);
+            let length = source.get_field("length", context)?.to_length(context)?;
+            if length.saturating_add(offset) > target.length {
+                return context.throw_range_error("offset is out of bounds");
+            }
+            for index in 0..length {
+                let number = source.get_field(index, context)?.to_number(context)?;
+                target.set_element((offset + index) as f64, number);
+            }
+        }
+
+        Ok(Value::undefined())
+    }
+
+    /// `%TypedArray%.prototype.subarray( begin, end )`
+    ///
+    /// Returns a new typed array viewing the elements of this typed array from `begin` up to,
+    /// but not including, `end`, in the same buffer.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.subarray
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/subarray
+    fn subarray(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let typed_array = Self::this_typed_array(this, context)?;
+        let begin = Array::get_relative_start(context, args.get(0), typed_array.length)?;
+        let end = Array::get_relative_end(context, args.get(1), typed_array.length)?;
+
+        let subarray = TypedArray {
+            kind: typed_array.kind,
+            byte_offset: typed_array.byte_offset + begin * typed_array.kind.element_size(),
+            length: end.saturating_sub(begin),
+            buffer: typed_array.buffer.clone(),
+        };
+        Ok(Self::create(subarray, context).into())
+    }
+
+    /// `%TypedArray%.prototype.entries( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.entries
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/entries
+    fn entries(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Self::this_typed_array(this, context)?;
+        Ok(ArrayIterator::create_array_iterator(
+            context,
+            this.clone(),
+            ArrayIterationKind::KeyAndValue,
+        ))
+    }
+
+    /// `%TypedArray%.prototype.keys( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.keys
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/keys
+    fn keys(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Self::this_typed_array(this, context)?;
+        Ok(ArrayIterator::create_array_iterator(
+            context,
+            this.clone(),
+            ArrayIterationKind::Key,
+        ))
+    }
+
+    /// `%TypedArray%.prototype.values( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%typedarray%.prototype.values
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/TypedArray/values
+    fn values(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        Self::this_typed_array(this, context)?;
+        Ok(ArrayIterator::create_array_iterator(
+            context,
+            this.clone(),
+            ArrayIterationKind::Value,
+        ))
+    }
+}
@@ -0,0 +1,305 @@
+use crate::{forward, Context};
+
+#[test]
+fn shared_buffer() {
+    let mut context = Context::new();
+    let init = r#"
+        var buffer = new ArrayBuffer(8);
+        var bytes = new Uint8Array(buffer);
+        var floats = new Float64Array(buffer);
+        bytes[6] = 0xf8;
+        bytes[7] = 0x3f;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "floats[0]"), "1.5");
+
+    forward(&mut context, "floats[0] = -2;");
+    assert_eq!(
+        forward(&mut context, "[...bytes].join()"),
+        "\"0,0,0,0,0,0,0,192\""
+    );
+
+    forward(&mut context, "new Int32Array(buffer, 4)[0] = -1;");
+    assert_eq!(
+        forward(&mut context, "[...bytes].join()"),
+        "\"0,0,0,0,255,255,255,255\""
+    );
+    assert_eq!(forward(&mut context, "floats[0]"), "NaN");
+}
+
+#[test]
+fn out_of_bounds() {
+    let mut context = Context::new();
+    let init = r#"
+        var array = new Int32Array(2);
+        array[2] = 1;
+        array[-1] = 1;
+        array[1.5] = 1;
+        array["-0"] = 1;
+        array.foo = 1;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "array[2]"), "undefined");
+    assert_eq!(forward(&mut context, "array[-1]"), "undefined");
+    assert_eq!(forward(&mut context, "array[1.5]"), "undefined");
+    assert_eq!(forward(&mut context, "array['-0']"), "undefined");
+    assert_eq!(forward(&mut context, "array.foo"), "1");
+    assert_eq!(forward(&mut context, "array.length"), "2");
+    assert_eq!(forward(&mut context, "2 in array"), "false");
+    assert_eq!(forward(&mut context, "1 in array"), "true");
+    assert_eq!(
+        forward(&mut context, "Reflect.ownKeys(array).join()"),
+        "\"0,1,foo\""
+    );
+
+    // Out of bounds indices aren't looked up in the prototype chain.
+    forward(&mut context, "Object.prototype[5] = 'inherited';");
+    assert_eq!(forward(&mut context, "array[5]"), "undefined");
+}
+
+#[test]
+fn element_conversions() {
+    let mut context = Context::new();
+    let init = r#"
+        function convert(Type, values) {
+            let array = new Type(values.length);
+            for (let i = 0; i < values.length; i++) {
+                array[i] = values[i];
+            }
+            return [...array].join();
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "convert(Uint8Array, [261, -1, 1.9, NaN])"),
+        "\"5,255,1,0\""
+    );
+    assert_eq!(
+        forward(&mut context, "convert(Int8Array, [200, -129, '12'])"),
+        "\"-56,127,12\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "convert(Uint8ClampedArray, [300, -5, 1.5, 2.5, 2.6])"
+        ),
+        "\"255,0,2,2,3\""
+    );
+    assert_eq!(
+        forward(&mut context, "convert(Int16Array, [32768, -1])"),
+        "\"-32768,-1\""
+    );
+    assert_eq!(
+        forward(&mut context, "convert(Uint16Array, [65536, -1])"),
+        "\"0,65535\""
+    );
+    assert_eq!(
+        forward(&mut context, "convert(Uint32Array, [-1, 4294967296])"),
+        "\"4294967295,0\""
+    );
+    assert_eq!(
+        forward(&mut context, "convert(Float32Array, [0.1, 1e40])"),
+        "\"0.10000000149011612,Infinity\""
+    );
+}
+
+#[test]
+fn constructors() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "[...new Uint8Array([1, 2, 300])].join()"),
+        "\"1,2,44\""
+    );
+    assert_eq!(
+        forward(&mut context, "[...new Int16Array(new Set([1, -2]))].join()"),
+        "\"1,-2\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[...new Float64Array({ length: 2, 0: 0.5, 1: '2' })].join()"
+        ),
+        "\"0.5,2\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[...new Int8Array(new Float64Array([1.5, 255]))].join()"
+        ),
+        "\"1,-1\""
+    );
+
+    let init = r#"
+        var buffer = new ArrayBuffer(16);
+        var view = new Int32Array(buffer, 4, 2);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "view.length"), "2");
+    assert_eq!(forward(&mut context, "view.byteOffset"), "4");
+    assert_eq!(forward(&mut context, "view.byteLength"), "8");
+    assert_eq!(forward(&mut context, "view.buffer === buffer"), "true");
+    assert_eq!(
+        forward(&mut context, "new Int32Array(buffer, 8).length"),
+        "2"
+    );
+
+    for source in &[
+        "new Int32Array(buffer, 3)",
+        "new Int32Array(buffer, 4, 4)",
+        "new Int32Array(buffer, 20)",
+        "new Float64Array(new ArrayBuffer(12))",
+        "new Uint8Array(-1)",
+    ] {
+        let source = format!(
+            "try {{ {} }} catch (e) {{ e instanceof RangeError }}",
+            source
+        );
+        assert_eq!(forward(&mut context, &source), "true", "{}", source);
+    }
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { Uint8Array(1) } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn typed_array_intrinsic() {
+    let mut context = Context::new();
+    let init = r#"
+        let TypedArray = Object.getPrototypeOf(Uint8Array);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(Float64Array) === TypedArray"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(Int32Array.prototype) === TypedArray.prototype"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "TypedArray.name"), "\"TypedArray\"");
+    assert_eq!(
+        forward(&mut context, "typeof globalThis.TypedArray"),
+        "\"undefined\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new TypedArray() } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Float64Array.BYTES_PER_ELEMENT"), "8");
+    assert_eq!(
+        forward(&mut context, "new Int16Array().BYTES_PER_ELEMENT"),
+        "2"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.prototype.toString.call(new Uint8ClampedArray())"
+        ),
+        "\"[object Uint8ClampedArray]\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            r#"try { TypedArray.prototype.length } catch (e) { e instanceof TypeError }"#
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn set() {
+    let mut context = Context::new();
+    let init = r#"
+        var target = new Uint8Array(6);
+        target.set([1, 2, 3], 1);
+        target.set(new Float64Array([4.5, 256]), 4);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "[...target].join()"),
+        "\"0,1,2,3,4,0\""
+    );
+
+    forward(&mut context, "target.set(target.subarray(0, 4), 2);");
+    assert_eq!(
+        forward(&mut context, "[...target].join()"),
+        "\"0,1,0,1,2,3\"",
+        "overlapping elements are read before they are written"
+    );
+
+    for source in &[
+        "target.set([1, 2], 5)",
+        "target.set(new Int8Array(7))",
+        "target.set([], -1)",
+        "target.set([], Infinity)",
+    ] {
+        let source = format!(
+            "try {{ {} }} catch (e) {{ e instanceof RangeError }}",
+            source
+        );
+        assert_eq!(forward(&mut context, &source), "true", "{}", source);
+    }
+}
+
+#[test]
+fn subarray() {
+    let mut context = Context::new();
+    let init = r#"
+        var array = new Int16Array([1, 2, 3, 4, 5]);
+        var subarray = array.subarray(1, -1);
+        subarray[0] = 9;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "[...subarray].join()"), "\"9,3,4\"");
+    assert_eq!(forward(&mut context, "[...array].join()"), "\"1,9,3,4,5\"");
+    assert_eq!(forward(&mut context, "subarray.byteOffset"), "2");
+    assert_eq!(
+        forward(&mut context, "subarray.buffer === array.buffer"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "subarray instanceof Int16Array"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "array.subarray(3, 1).length"), "0");
+    assert_eq!(forward(&mut context, "array.subarray(-2).length"), "2");
+    assert_eq!(
+        forward(&mut context, "[...array.subarray(2).subarray(1)].join()"),
+        "\"4,5\""
+    );
+}
+
+#[test]
+fn iteration() {
+    let mut context = Context::new();
+    let init = r#"
+        var array = new Uint8Array([7, 8]);
+        var entries = [];
+        for (let entry of array.entries()) {
+            entries.push(entry[0] + ":" + entry[1]);
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "entries.join()"), "\"0:7,1:8\"");
+    assert_eq!(forward(&mut context, "[...array.keys()].join()"), "\"0,1\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "array[Symbol.iterator] === Uint8Array.prototype.values"
+        ),
+        "true"
+    );
+}
@@ -0,0 +1,427 @@
+//! This module implements the global URI handling functions.
+//!
+//! The `encodeURI()`, `decodeURI()`, `encodeURIComponent()` and `decodeURIComponent()`
+//! functions are properties of the global object. They encode or decode a Uniform Resource
+//! Identifier by replacing (or restoring) certain characters with escape sequences
+//! representing the UTF-8 encoding of the character.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! The legacy `escape()` and `unescape()` functions are also defined here.
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-uri-handling-functions
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI
+
+use super::function::make_builtin_fn;
+use crate::{value::RcString, BoaProfiler, Context, Result, Value};
+use percent_encoding::{percent_encode, AsciiSet, CONTROLS};
+use std::char::decode_utf16;
+
+#[cfg(test)]
+mod tests;
+
+/// The characters escaped by `encodeURI`.
+///
+/// Everything except the `uriAlpha`, `DecimalDigit`, `uriMark` and `uriReserved` characters
+/// and `#` gets escaped.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-encodeuri-uri
+const ENCODE_URI: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'`')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+/// The characters escaped by `encodeURIComponent`.
+///
+/// Everything except the `uriAlpha`, `DecimalDigit` and `uriMark` characters gets escaped,
+/// including the `uriReserved` set.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-encodeuricomponent-uricomponent
+const ENCODE_URI_COMPONENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'$')
+    .add(b'%')
+    .add(b'&')
+    .add(b'+')
+    .add(b',')
+    .add(b'/')
+    .add(b':')
+    .add(b';')
+    .add(b'<')
+    .add(b'=')
+    .add(b'>')
+    .add(b'?')
+    .add(b'@')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'`')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+/// The characters whose escape sequences are preserved by `decodeURI`.
+///
+/// These are the `uriReserved` characters and `#`.
+const URI_RESERVED_SET: &str = ";/?:@&=+$,#";
+
+/// The type of the functions used to encode or decode a string.
+type EncodeFuncType = fn(&str, &mut Context) -> Result<RcString>;
+
+/// JavaScript URI handling functions.
+///
+/// Unlike most builtins these are not grouped under a single global binding,
+/// each function is a property of the global object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Uri;
+
+impl Uri {
+    /// Registers the URI handling functions on the global object.
+    pub(crate) fn init(context: &mut Context) {
+        let _timer = BoaProfiler::global().start_event("Uri", "init");
+
+        let global = context.global_object();
+        make_builtin_fn(Self::encode_uri, "encodeURI", &global, 1, context);
+        make_builtin_fn(Self::decode_uri, "decodeURI", &global, 1, context);
+        make_builtin_fn(
+            Self::encode_uri_component,
+            "encodeURIComponent",
+            &global,
+            1,
+            context,
+        );
+        make_builtin_fn(
+            Self::decode_uri_component,
+            "decodeURIComponent",
+            &global,
+            1,
+            context,
+        );
+        make_builtin_fn(Self::escape, "escape", &global, 1, context);
+        make_builtin_fn(Self::unescape, "unescape", &global, 1, context);
+    }
+
+    /// Applies the given encoding or decoding function to the value, after converting it
+    /// with `ToString`.
+    fn handle_uri(value: &Value, context: &mut Context, f: EncodeFuncType) -> Result<RcString> {
+        let string = value.to_string(context)?;
+        if string.is_empty() {
+            return Ok(string);
+        }
+
+        f(&string, context)
+    }
+
+    /// Encodes the value the same way `encodeURI()` does.
+    pub(crate) fn encode_uri_value(value: &Value, context: &mut Context) -> Result<RcString> {
+        Self::handle_uri(value, context, |string, context| {
+            Self::encode(string.encode_utf16(), ENCODE_URI, context)
+        })
+    }
+
+    /// Decodes the value the same way `decodeURI()` does.
+    pub(crate) fn decode_uri_value(value: &Value, context: &mut Context) -> Result<RcString> {
+        Self::handle_uri(value, context, |string, context| {
+            Self::decode(string, URI_RESERVED_SET, context)
+        })
+    }
+
+    /// Encodes the given UTF-16 code units, escaping the characters of the `escaped_set`
+    /// and every non-ASCII character with the escape sequences of their UTF-8 encoding.
+    ///
+    /// Surrogate pairs are combined into a single code point, throws a `URIError` if a
+    /// surrogate is not paired.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-encode
+    fn encode<I>(
+        code_units: I,
+        escaped_set: &'static AsciiSet,
+        context: &mut Context,
+    ) -> Result<RcString>
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        let mut encoded = String::new();
+        for code_point in decode_utf16(code_units) {
+            let code_point = match code_point {
+                Ok(code_point) => code_point,
+                Err(_) => return Err(context.construct_uri_error("URI malformed")),
+            };
+
+            let mut buffer = [0; 4];
+            let octets = code_point.encode_utf8(&mut buffer).as_bytes();
+            encoded.extend(percent_encode(octets, escaped_set));
+        }
+
+        Ok(RcString::from(encoded))
+    }
+
+    /// Decodes the escape sequences of the given string, except the ones that represent
+    /// a character of the `reserved_set`, which are kept as they are.
+    ///
+    /// Throws a `URIError` if a `%` is not followed by two hexadecimal digits, or if the
+    /// escaped octets are not a valid UTF-8 sequence.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-decode
+    fn decode(string: &str, reserved_set: &str, context: &mut Context) -> Result<RcString> {
+        /// Parses the byte of the escape sequence starting at `index`, if it is one.
+        fn decode_byte(bytes: &[u8], index: usize) -> Option<u8> {
+            if bytes.get(index) != Some(&b'%') {
+                return None;
+            }
+            let digits = std::str::from_utf8(bytes.get(index + 1..index + 3)?).ok()?;
+            if !digits.bytes().all(|digit| digit.is_ascii_hexdigit()) {
+                return None;
+            }
+            u8::from_str_radix(digits, 16).ok()
+        }
+
+        let bytes = string.as_bytes();
+        let mut decoded = Vec::with_capacity(bytes.len());
+        let mut index = 0;
+        while index < bytes.len() {
+            if bytes[index] != b'%' {
+                decoded.push(bytes[index]);
+                index += 1;
+                continue;
+            }
+
+            let byte = match decode_byte(bytes, index) {
+                Some(byte) => byte,
+                None => return Err(context.construct_uri_error("URI malformed")),
+            };
+
+            if byte < 0x80 {
+                if reserved_set.as_bytes().contains(&byte) {
+                    decoded.extend_from_slice(&bytes[index..index + 3]);
+                } else {
+                    decoded.push(byte);
+                }
+                index += 3;
+                continue;
+            }
+
+            // The number of leading one bits gives the length of the UTF-8 sequence.
+            let length = byte.leading_ones() as usize;
+            if length == 1 || length > 4 {
+                return Err(context.construct_uri_error("URI malformed"));
+            }
+
+            let mut octets = vec![byte];
+            for n in 1..length {
+                match decode_byte(bytes, index + 3 * n) {
+                    Some(octet) if octet & 0xC0 == 0x80 => octets.push(octet),
+                    _ => return Err(context.construct_uri_error("URI malformed")),
+                }
+            }
+
+            // `from_utf8` rejects overlong encodings and surrogate code points.
+            if std::str::from_utf8(&octets).is_err() {
+                return Err(context.construct_uri_error("URI malformed"));
+            }
+            decoded.extend_from_slice(&octets);
+            index += 3 * length;
+        }
+
+        let decoded = String::from_utf8(decoded).expect("decoded string must be valid UTF-8");
+        Ok(RcString::from(decoded))
+    }
+
+    /// `encodeURI( uri )`
+    ///
+    /// Encodes a URI by replacing each instance of certain characters by escape sequences
+    /// representing the UTF-8 encoding of the character.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-encodeuri-uri
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURI
+    pub(crate) fn encode_uri(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let uri = args.get(0).cloned().unwrap_or_default();
+        Ok(Self::encode_uri_value(&uri, context)?.into())
+    }
+
+    /// `decodeURI( encodedURI )`
+    ///
+    /// Decodes a URI previously created by `encodeURI()` or by a similar routine.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-decodeuri-encodeduri
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURI
+    pub(crate) fn decode_uri(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let encoded_uri = args.get(0).cloned().unwrap_or_default();
+        Ok(Self::decode_uri_value(&encoded_uri, context)?.into())
+    }
+
+    /// `encodeURIComponent( uriComponent )`
+    ///
+    /// Encodes a URI component by replacing each instance of certain characters by escape
+    /// sequences representing the UTF-8 encoding of the character. Unlike `encodeURI()`,
+    /// this also escapes the reserved characters `; / ? : @ & = + $ , #`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-encodeuricomponent-uricomponent
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/encodeURIComponent
+    pub(crate) fn encode_uri_component(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let uri_component = args.get(0).cloned().unwrap_or_default();
+        let encoded = Self::handle_uri(&uri_component, context, |string, context| {
+            Self::encode(string.encode_utf16(), ENCODE_URI_COMPONENT, context)
+        })?;
+        Ok(encoded.into())
+    }
+
+    /// `decodeURIComponent( encodedURIComponent )`
+    ///
+    /// Decodes a URI component previously created by `encodeURIComponent()` or by a
+    /// similar routine.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-decodeuricomponent-encodeduricomponent
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/decodeURIComponent
+    pub(crate) fn decode_uri_component(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let encoded_uri_component = args.get(0).cloned().unwrap_or_default();
+        let decoded = Self::handle_uri(&encoded_uri_component, context, |string, context| {
+            Self::decode(string, "", context)
+        })?;
+        Ok(decoded.into())
+    }
+
+    /// `escape( string )`
+    ///
+    /// Computes a new string in which certain characters have been replaced by a
+    /// hexadecimal escape sequence of the form `%XX` or `%uXXXX`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-escape-string
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/escape
+    pub(crate) fn escape(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let string = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+
+        let mut escaped = String::with_capacity(string.len());
+        for code_unit in string.encode_utf16() {
+            match code_unit {
+                // The code units of `A-Za-z0-9@*_+-./` are left unescaped.
+                0x41..=0x5A
+                | 0x61..=0x7A
+                | 0x30..=0x39
+                | 0x40
+                | 0x2A
+                | 0x5F
+                | 0x2B
+                | 0x2D
+                | 0x2E
+                | 0x2F => escaped.push(code_unit as u8 as char),
+                0..=0xFF => escaped.push_str(&format!("%{:02X}", code_unit)),
+                _ => escaped.push_str(&format!("%u{:04X}", code_unit)),
+            }
+        }
+
+        Ok(escaped.into())
+    }
+
+    /// `unescape( string )`
+    ///
+    /// Computes a new string in which hexadecimal escape sequences of the form `%XX` or
+    /// `%uXXXX` are replaced with the code unit that they represent.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-unescape-string
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/unescape
+    pub(crate) fn unescape(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        /// Parses the given code units as a hexadecimal number.
+        fn parse_hex(code_units: &[u16]) -> Option<u16> {
+            code_units.iter().try_fold(0, |acc, &code_unit| {
+                let digit = char::from_u32(u32::from(code_unit))?.to_digit(16)?;
+                Some(acc * 16 + digit as u16)
+            })
+        }
+
+        let string = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+        let code_units: Vec<u16> = string.encode_utf16().collect();
+
+        let mut unescaped = Vec::with_capacity(code_units.len());
+        let mut index = 0;
+        while index < code_units.len() {
+            let code_unit = code_units[index];
+            if code_unit == u16::from(b'%') {
+                if code_units.get(index + 1) == Some(&u16::from(b'u')) {
+                    if let Some(value) = code_units.get(index + 2..index + 6).and_then(parse_hex) {
+                        unescaped.push(value);
+                        index += 6;
+                        continue;
+                    }
+                } else if let Some(value) = code_units.get(index + 1..index + 3).and_then(parse_hex)
+                {
+                    unescaped.push(value);
+                    index += 3;
+                    continue;
+                }
+            }
+
+            unescaped.push(code_unit);
+            index += 1;
+        }
+
+        Ok(String::from_utf16_lossy(&unescaped).into())
+    }
+}
@@ -0,0 +1,291 @@
+use super::{Uri, ENCODE_URI};
+use crate::{forward, Context};
+
+#[test]
+fn encode_uri_component_escapes_reserved_characters() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "encodeURIComponent(';/?:@&=+$,#')"),
+        "\"%3B%2F%3F%3A%40%26%3D%2B%24%2C%23\""
+    );
+    assert_eq!(
+        forward(&mut context, "encodeURIComponent('a b')"),
+        "\"a%20b\""
+    );
+}
+
+#[test]
+fn encode_uri_component_keeps_unreserved_marks() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "encodeURIComponent(\"-_.!~*'()\")"),
+        "\"-_.!~*'()\""
+    );
+    assert_eq!(
+        forward(&mut context, "encodeURIComponent('AZaz09')"),
+        "\"AZaz09\""
+    );
+}
+
+#[test]
+fn decode_uri_component_round_trip() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "decodeURIComponent(encodeURIComponent('a=b&c=d'))"
+        ),
+        "\"a=b&c=d\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "decodeURIComponent(encodeURIComponent('шеллы ü'))"
+        ),
+        "\"шеллы ü\""
+    );
+}
+
+#[test]
+fn decode_uri_round_trip() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "decodeURI(encodeURI('a b<c>'))"),
+        "\"a b<c>\""
+    );
+    assert_eq!(forward(&mut context, "encodeURI('')"), "\"\"");
+}
+
+#[test]
+fn decode_uri_malformed_throws_uri_error() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "decodeURI('%')"),
+        "Uncaught \"URIError\": \"URI malformed\""
+    );
+    assert_eq!(
+        forward(&mut context, "decodeURI('%E0%A4%A')"),
+        "Uncaught \"URIError\": \"URI malformed\""
+    );
+    assert_eq!(
+        forward(&mut context, "decodeURIComponent('%zz')"),
+        "Uncaught \"URIError\": \"URI malformed\""
+    );
+    assert_eq!(
+        forward(&mut context, "decodeURIComponent('%C3')"),
+        "Uncaught \"URIError\": \"URI malformed\""
+    );
+}
+
+#[test]
+fn decode_uri_malformed_error_is_catchable() {
+    let mut context = Context::new();
+    let scenario = r#"
+        let caught;
+        try {
+            decodeURI("%");
+        } catch (e) {
+            caught = e instanceof URIError;
+        }
+        caught
+    "#;
+    assert_eq!(forward(&mut context, scenario), "true");
+}
+
+#[test]
+fn encode_uri_keeps_reserved_characters() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "encodeURI('http://a.com/?x=1')"),
+        "\"http://a.com/?x=1\""
+    );
+    assert_eq!(
+        forward(&mut context, "encodeURI(';/?:@&=+$,#')"),
+        "\";/?:@&=+$,#\""
+    );
+    assert_eq!(
+        forward(&mut context, "encodeURI(\"-_.!~*'()\")"),
+        "\"-_.!~*'()\""
+    );
+}
+
+#[test]
+fn encode_uri_escapes_other_characters() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "encodeURI('http://a.com/a b')"),
+        "\"http://a.com/a%20b\""
+    );
+    assert_eq!(forward(&mut context, "encodeURI('100%')"), "\"100%25\"");
+    assert_eq!(
+        forward(&mut context, "encodeURI('<[{|}]>^`\"\\\\')"),
+        "\"%3C%5B%7B%7C%7D%5D%3E%5E%60%22%5C\""
+    );
+    assert_eq!(
+        forward(&mut context, "encodeURI('https://mozilla.org/?x=шеллы')"),
+        "\"https://mozilla.org/?x=%D1%88%D0%B5%D0%BB%D0%BB%D1%8B\""
+    );
+    assert_eq!(forward(&mut context, "encodeURI('\\n')"), "\"%0A\"");
+}
+
+#[test]
+fn uri_functions_coerce_argument_to_string() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "encodeURI(123)"), "\"123\"");
+    assert_eq!(forward(&mut context, "encodeURI(true)"), "\"true\"");
+    assert_eq!(forward(&mut context, "encodeURI(null)"), "\"null\"");
+    assert_eq!(
+        forward(&mut context, "encodeURI(undefined)"),
+        "\"undefined\""
+    );
+    assert_eq!(forward(&mut context, "encodeURI()"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "decodeURI(false)"), "\"false\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "encodeURIComponent({ toString() { return 'a b'; } })"
+        ),
+        "\"a%20b\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "decodeURIComponent({ toString() { return '%3D'; } })"
+        ),
+        "\"=\""
+    );
+}
+
+#[test]
+fn escape() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "escape('a b')"), "\"a%20b\"");
+    assert_eq!(forward(&mut context, "escape('€')"), "\"%u20AC\"");
+    assert_eq!(forward(&mut context, "escape('ä')"), "\"%E4\"");
+    assert_eq!(
+        forward(&mut context, "escape('AZaz09@*_+-./')"),
+        "\"AZaz09@*_+-./\""
+    );
+    assert_eq!(forward(&mut context, "escape('=&%')"), "\"%3D%26%25\"");
+    assert_eq!(forward(&mut context, "escape(42)"), "\"42\"");
+}
+
+#[test]
+fn unescape() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "unescape('a%20b')"), "\"a b\"");
+    assert_eq!(forward(&mut context, "unescape('%u20AC')"), "\"€\"");
+    assert_eq!(forward(&mut context, "unescape('%E4')"), "\"ä\"");
+    assert_eq!(forward(&mut context, "unescape('%zz%u12')"), "\"%zz%u12\"");
+    assert_eq!(
+        forward(&mut context, "unescape(escape('a=b €&ä'))"),
+        "\"a=b €&ä\""
+    );
+}
+
+#[test]
+fn escape_is_not_a_constructor() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { new escape('a') } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn uri_functions_have_no_aggregate_global() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "'Uri' in globalThis"), "false");
+    assert_eq!(forward(&mut context, "typeof encodeURI"), "\"function\"");
+    assert_eq!(
+        forward(&mut context, "typeof decodeURIComponent"),
+        "\"function\""
+    );
+}
+
+#[test]
+fn decode_uri_preserves_reserved_escape_sequences() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "decodeURI('%3B')"), "\"%3B\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "decodeURI('%3B%2F%3F%3A%40%26%3D%2B%24%2C%23')"
+        ),
+        "\"%3B%2F%3F%3A%40%26%3D%2B%24%2C%23\""
+    );
+    assert_eq!(forward(&mut context, "decodeURI('%3b%20')"), "\"%3b \"");
+    assert_eq!(forward(&mut context, "decodeURI('%D1%88%41')"), "\"шA\"");
+}
+
+#[test]
+fn decode_uri_component_decodes_reserved_escape_sequences() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "decodeURIComponent('%3B')"), "\";\"");
+    assert_eq!(
+        forward(
+            &mut context,
+            "decodeURIComponent('%3B%2F%3F%3A%40%26%3D%2B%24%2C%23')"
+        ),
+        "\";/?:@&=+$,#\""
+    );
+}
+
+#[test]
+fn decode_uri_rejects_invalid_utf8_sequences() {
+    let mut context = Context::new();
+    // Unexpected continuation byte.
+    assert_eq!(
+        forward(&mut context, "decodeURI('%80')"),
+        "Uncaught \"URIError\": \"URI malformed\""
+    );
+    // Overlong encoding.
+    assert_eq!(
+        forward(&mut context, "decodeURI('%C0%AF')"),
+        "Uncaught \"URIError\": \"URI malformed\""
+    );
+    // Encoded surrogate.
+    assert_eq!(
+        forward(&mut context, "decodeURI('%ED%A0%80')"),
+        "Uncaught \"URIError\": \"URI malformed\""
+    );
+    // Truncated sequence.
+    assert_eq!(
+        forward(&mut context, "decodeURI('%E2%82')"),
+        "Uncaught \"URIError\": \"URI malformed\""
+    );
+}
+
+#[test]
+fn encode_uri_astral_code_points() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "encodeURI('😀')"), "\"%F0%9F%98%80\"");
+    assert_eq!(
+        forward(&mut context, "encodeURIComponent('\\uD83D\\uDE00')"),
+        "\"%F0%9F%98%80\""
+    );
+    assert_eq!(
+        forward(&mut context, "decodeURI(encodeURI('a😀b'))"),
+        "\"a😀b\""
+    );
+}
+
+#[test]
+fn encode_uri_unpaired_surrogate_throws_uri_error() {
+    let mut context = Context::new();
+    for code_units in &[&[0xD800][..], &[0xDC00], &[0x61, 0xD83D, 0x62]] {
+        let error = Uri::encode(code_units.iter().copied(), ENCODE_URI, &mut context)
+            .expect_err("an unpaired surrogate must throw");
+        assert_eq!(
+            error.display().to_string(),
+            "\"URIError\": \"URI malformed\""
+        );
+    }
+
+    let encoded = Uri::encode([0xD83D, 0xDE00].iter().copied(), ENCODE_URI, &mut context)
+        .expect("a surrogate pair must be encoded");
+    assert_eq!(encoded, "%F0%9F%98%80");
+}
@@ -0,0 +1,369 @@
+//! This module implements the global `WeakMap` object.
+//!
+//! A `WeakMap` is a collection of key/value pairs in which the keys are objects, and whose
+//! entries don't keep their keys alive.
+//!
+//! The garbage collector doesn't support weak references, so each object that is used as a key
+//! owns a reference counted anchor, and the entries of a map are keyed by weak references to the
+//! anchors of their keys. The anchor is dropped with its object when the object is collected,
+//! which kills the weak references to it. The entries whose key was collected are removed when
+//! the map grows, so its table stays proportional to the number of live keys.
+//!
+//! Without ephemerons, the values of a map are kept alive by the map, so a value that refers to
+//! its own key keeps the key alive as long as the map is. Symbols are reference counted outside
+//! of the garbage collector, so entries keyed by a symbol are held by the map itself.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakmap-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap
+
+use crate::{
+    builtins::{iterable::get_iterator, BuiltIn},
+    gc::{empty_trace, Finalize, Trace},
+    object::{ConstructorBuilder, GcObject, ObjectData, PROTOTYPE},
+    property::Attribute,
+    symbol::{RcSymbol, WellKnownSymbols},
+    BoaProfiler, Context, Result, Value,
+};
+use rustc_hash::FxHashMap;
+use std::rc::{Rc, Weak};
+
+#[cfg(test)]
+mod tests;
+
+/// The minimum size of the table of a `WeakMap` before the entries of collected keys are removed.
+const MIN_SWEEP_LEN: usize = 16;
+
+/// The anchor of the weak references to an object, created when the object is first used as the
+/// key of a `WeakMap`, and dropped with the object.
+#[derive(Debug, Default, Finalize)]
+pub(crate) struct WeakTarget(Option<Rc<()>>);
+
+unsafe impl Trace for WeakTarget {
+    empty_trace!();
+}
+
+impl WeakTarget {
+    /// Gets the identity of the object, if it was ever used as a key.
+    fn key(&self) -> Option<usize> {
+        self.0.as_ref().map(|anchor| Rc::as_ptr(anchor) as usize)
+    }
+
+    /// Gets the identity of the object and a weak reference to it, creating its anchor if needed.
+    fn key_or_insert(&mut self) -> (usize, Weak<()>) {
+        let anchor = self.0.get_or_insert_with(|| Rc::new(()));
+        (Rc::as_ptr(anchor) as usize, Rc::downgrade(anchor))
+    }
+}
+
+/// The internal representation of a `WeakMap` object.
+#[derive(Debug, Trace, Finalize)]
+pub struct WeakMap {
+    /// The entries keyed by objects, indexed by the identity of the anchor of their key.
+    ///
+    /// An anchor isn't deallocated while there are weak references to it, so an identity isn't
+    /// reused while an entry refers to it.
+    entries: FxHashMap<usize, WeakMapEntry>,
+    /// The size the table can grow to before the entries of collected keys are removed.
+    sweep_len: usize,
+    symbol_entries: FxHashMap<RcSymbol, Value>,
+}
+
+#[derive(Debug, Trace, Finalize)]
+struct WeakMapEntry {
+    #[unsafe_ignore_trace]
+    key: Weak<()>,
+    value: Value,
+    #[cfg(test)]
+    #[unsafe_ignore_trace]
+    _live: tests::LiveEntry,
+}
+
+impl WeakMap {
+    /// Creates an empty `WeakMap`.
+    fn new() -> Self {
+        Self {
+            entries: FxHashMap::default(),
+            sweep_len: MIN_SWEEP_LEN,
+            symbol_entries: FxHashMap::default(),
+        }
+    }
+
+    /// Associates the value with the key of the given identity.
+    fn insert(&mut self, id: usize, key: Weak<()>, value: Value) {
+        if let Some(entry) = self.entries.get_mut(&id) {
+            entry.value = value;
+            return;
+        }
+
+        // Removing the entries of collected keys when the table has doubled since the last sweep
+        // keeps the insertions amortized constant time.
+        if self.entries.len() >= self.sweep_len {
+            self.entries.retain(|_, entry| entry.key.strong_count() > 0);
+            self.sweep_len = (self.entries.len() * 2).max(MIN_SWEEP_LEN);
+        }
+        self.entries.insert(
+            id,
+            WeakMapEntry {
+                key,
+                value,
+                #[cfg(test)]
+                _live: tests::LiveEntry::new(),
+            },
+        );
+    }
+}
+
+impl BuiltIn for WeakMap {
+    const NAME: &'static str = "WeakMap";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+
+        let weak_map_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_map_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::delete, "delete", 1)
+        .method(Self::get, "get", 1)
+        .method(Self::has, "has", 1)
+        .method(Self::set, "set", 2)
+        .property(
+            to_string_tag,
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        (Self::NAME, weak_map_object.into(), Self::attribute())
+    }
+}
+
+impl WeakMap {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// Create a new weak map
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakMap constructor without new is forbidden",
+            );
+        }
+        let weak_map_prototype = context.standard_objects().weak_map_object().prototype();
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or(weak_map_prototype);
+
+        let mut obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+        let map = Value::from(obj);
+        map.set_data(ObjectData::WeakMap(WeakMap::new()));
+
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        if iterable.is_null_or_undefined() {
+            return Ok(map);
+        }
+
+        let adder = map.get_field("set", context)?;
+        if !adder.is_function() {
+            return context.throw_type_error("'set' of 'newTarget' is not a function");
+        }
+
+        // AddEntriesFromIterable
+        let iterator_record = get_iterator(context, iterable)?;
+        loop {
+            let next = iterator_record.next(context)?;
+            if next.is_done() {
+                return Ok(map);
+            }
+
+            let next_item = next.value();
+            if !next_item.is_object() {
+                let error = context.construct_type_error("iterator value is not an entry object");
+                return iterator_record.close(Err(error), context);
+            }
+
+            let entry = next_item
+                .get_field(0, context)
+                .and_then(|key| Ok((key, next_item.get_field(1, context)?)));
+            let (key, value) = match entry {
+                Ok(entry) => entry,
+                Err(error) => return iterator_record.close(Err(error), context),
+            };
+
+            if let Err(error) = context.call(&adder, &map, &[key, value]) {
+                return iterator_record.close(Err(error), context);
+            }
+        }
+    }
+
+    /// Helper function to get the `WeakMap` object in `this`.
+    fn this_map(this: &Value, context: &mut Context) -> Result<GcObject> {
+        if let Some(object) = this.as_object() {
+            if object.borrow().as_weak_map().is_some() {
+                return Ok(object);
+            }
+        }
+
+        Err(context.construct_type_error("'this' is not a WeakMap"))
+    }
+
+    /// `WeakMap.prototype.delete( key )`
+    ///
+    /// This method removes the element associated with the key, if it exists. Returns true if
+    /// there was an element, false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let map = Self::this_map(this, context)?;
+
+        let deleted = match args.get(0) {
+            Some(Value::Object(key)) => {
+                // The key is borrowed before the map, since a map can be its own key.
+                let id = key.borrow().weak_target().key();
+                let mut map = map.borrow_mut();
+                let map = map.as_weak_map_mut().expect("'this' is a WeakMap");
+                id.and_then(|id| map.entries.remove(&id)).is_some()
+            }
+            Some(Value::Symbol(key)) => map
+                .borrow_mut()
+                .as_weak_map_mut()
+                .expect("'this' is a WeakMap")
+                .symbol_entries
+                .remove(key)
+                .is_some(),
+            _ => false,
+        };
+
+        Ok(deleted.into())
+    }
+
+    /// `WeakMap.prototype.get( key )`
+    ///
+    /// This method returns the value associated with the key, or undefined if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get
+    pub(crate) fn get(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let map = Self::this_map(this, context)?;
+
+        let value = match args.get(0) {
+            Some(Value::Object(key)) => {
+                let id = key.borrow().weak_target().key();
+                let map = map.borrow();
+                let map = map.as_weak_map().expect("'this' is a WeakMap");
+                id.and_then(|id| map.entries.get(&id))
+                    .map(|entry| entry.value.clone())
+            }
+            Some(Value::Symbol(key)) => map
+                .borrow()
+                .as_weak_map()
+                .expect("'this' is a WeakMap")
+                .symbol_entries
+                .get(key)
+                .cloned(),
+            _ => None,
+        };
+
+        Ok(value.unwrap_or_default())
+    }
+
+    /// `WeakMap.prototype.has( key )`
+    ///
+    /// This method checks if the weak map contains an entry with the given key.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has
+    pub(crate) fn has(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let map = Self::this_map(this, context)?;
+
+        let has = match args.get(0) {
+            Some(Value::Object(key)) => {
+                let id = key.borrow().weak_target().key();
+                let map = map.borrow();
+                let map = map.as_weak_map().expect("'this' is a WeakMap");
+                id.map_or(false, |id| map.entries.contains_key(&id))
+            }
+            Some(Value::Symbol(key)) => map
+                .borrow()
+                .as_weak_map()
+                .expect("'this' is a WeakMap")
+                .symbol_entries
+                .contains_key(key),
+            _ => false,
+        };
+
+        Ok(has.into())
+    }
+
+    /// `WeakMap.prototype.set( key, value )`
+    ///
+    /// This method associates the value with the key, which must be an object or a symbol.
+    /// Returns the weak map object.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set
+    pub(crate) fn set(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let map = Self::this_map(this, context)?;
+        let value = args.get(1).cloned().unwrap_or_default();
+
+        match args.get(0) {
+            Some(Value::Object(key)) => {
+                let (id, key) = key.borrow_mut().weak_target_mut().key_or_insert();
+                map.borrow_mut()
+                    .as_weak_map_mut()
+                    .expect("'this' is a WeakMap")
+                    .insert(id, key, value);
+            }
+            Some(Value::Symbol(key)) => {
+                map.borrow_mut()
+                    .as_weak_map_mut()
+                    .expect("'this' is a WeakMap")
+                    .symbol_entries
+                    .insert(key.clone(), value);
+            }
+            _ => return context.throw_type_error("invalid value used as weak map key"),
+        }
+
+        Ok(this.clone())
+    }
+}
@@ -0,0 +1,154 @@
+use crate::{forward, gc::force_collect, Context};
+use std::cell::Cell;
+
+thread_local! {
+    static LIVE_ENTRIES: Cell<usize> = Cell::new(0);
+}
+
+/// Counts the `WeakMap` entries alive in the current thread.
+#[derive(Debug)]
+pub(super) struct LiveEntry;
+
+impl LiveEntry {
+    pub(super) fn new() -> Self {
+        LIVE_ENTRIES.with(|count| count.set(count.get() + 1));
+        Self
+    }
+}
+
+impl Drop for LiveEntry {
+    fn drop(&mut self) {
+        LIVE_ENTRIES.with(|count| count.set(count.get() - 1));
+    }
+}
+
+fn live_entries() -> usize {
+    LIVE_ENTRIES.with(Cell::get)
+}
+
+#[test]
+fn get_set_has_delete() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new WeakMap();
+        let key = {};
+        let other = {};
+        let symbol = Symbol("key");
+        map.set(key, "value").set(symbol, 42);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "map.get(key)"), "\"value\"");
+    assert_eq!(forward(&mut context, "map.get(symbol)"), "42");
+    assert_eq!(forward(&mut context, "map.get(other)"), "undefined");
+    assert_eq!(forward(&mut context, "map.has(key)"), "true");
+    assert_eq!(forward(&mut context, "map.has(1)"), "false");
+    assert_eq!(forward(&mut context, "map.delete(key)"), "true");
+    assert_eq!(forward(&mut context, "map.delete(key)"), "false");
+    assert_eq!(forward(&mut context, "map.has(key)"), "false");
+    assert_eq!(forward(&mut context, "map.delete(symbol)"), "true");
+}
+
+#[test]
+fn maps_are_independent() {
+    let mut context = Context::new();
+    let init = r#"
+        let key = {};
+        let first = new WeakMap([[key, 1]]);
+        let second = new WeakMap([[key, 2]]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "first.get(key)"), "1");
+    assert_eq!(forward(&mut context, "second.get(key)"), "2");
+    forward(&mut context, "first.delete(key)");
+    assert_eq!(forward(&mut context, "second.get(key)"), "2");
+}
+
+#[test]
+fn primitive_keys() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new WeakMap();
+        let errors = [1, "a", null, undefined, true].map(function (key) {
+            try {
+                map.set(key, 1);
+            } catch (e) {
+                return e instanceof TypeError;
+            }
+        });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "errors.join()"),
+        "\"true,true,true,true,true\""
+    );
+}
+
+#[test]
+fn not_a_function() {
+    let mut context = Context::new();
+    let init = r"
+        try {
+            let map = WeakMap()
+        } catch(e) {
+            e.toString()
+        }
+    ";
+    assert_eq!(
+        forward(&mut context, init),
+        "\"TypeError: calling a builtin WeakMap constructor without new is forbidden\""
+    );
+}
+
+#[test]
+fn map_as_its_own_key() {
+    let mut context = Context::new();
+    forward(&mut context, "let map = new WeakMap(); map.set(map, 1);");
+    assert_eq!(forward(&mut context, "map.get(map)"), "1");
+    assert_eq!(forward(&mut context, "map.delete(map)"), "true");
+}
+
+#[test]
+fn entries_of_collected_keys_are_removed() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new WeakMap();
+        let keys = [];
+        for (let i = 0; i < 100; i++) {
+            keys.push({});
+            map.set(keys[i], i);
+        }
+        "#;
+    forward(&mut context, init);
+    force_collect();
+    assert_eq!(live_entries(), 100);
+
+    // The entries of the collected keys are removed once the table has grown enough.
+    forward(&mut context, "keys = [];");
+    force_collect();
+    let grow = r#"
+        for (let i = 0; i < 100; i++) {
+            keys.push({});
+            map.set(keys[i], i);
+        }
+        "#;
+    forward(&mut context, grow);
+    assert_eq!(live_entries(), 100);
+    assert_eq!(forward(&mut context, "map.get(keys[99])"), "99");
+}
+
+#[test]
+fn entries_are_collected_with_their_map() {
+    let mut context = Context::new();
+    let init = r#"
+        let map = new WeakMap();
+        let key = {};
+        map.set(key, { value: 1 });
+        "#;
+    forward(&mut context, init);
+    force_collect();
+    assert_eq!(live_entries(), 1);
+
+    forward(&mut context, "map = null;");
+    force_collect();
+    assert_eq!(live_entries(), 0);
+}
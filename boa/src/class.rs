@@ -62,8 +62,9 @@
 
 use crate::{
     builtins::function::NativeFunction,
-    object::{ConstructorBuilder, GcObject, NativeObject, ObjectData},
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, NativeObject, ObjectData, PROTOTYPE},
     property::{Attribute, PropertyDescriptor, PropertyKey},
+    symbol::WellKnownSymbols,
     Context, Result, Value,
 };
 
@@ -94,13 +95,28 @@ pub trait ClassConstructor: Class {
 }
 
 impl<T: Class> ClassConstructor for T {
-    fn raw_constructor(this: &Value, args: &[Value], context: &mut Context) -> Result<Value>
+    fn raw_constructor(new_target: &Value, args: &[Value], context: &mut Context) -> Result<Value>
     where
         Self: Sized,
     {
-        let object_instance = Self::constructor(this, args, context)?;
+        // Native constructors get the `new_target`, so the instance is created here, with the
+        // prototype of the class.
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or_else(|| context.standard_objects().object_object().prototype());
+
+        let mut object = context.construct_object();
+        object.set_prototype_instance(prototype.into());
+        let this = Value::from(object);
+        let object_instance = Self::constructor(&this, args, context)?;
         this.set_data(ObjectData::NativeObject(Box::new(object_instance)));
-        Ok(this.clone())
+        Ok(this)
     }
 }
 
@@ -156,6 +172,78 @@ impl<'context> ClassBuilder<'context> {
         self
     }
 
+    /// Add a `[Symbol.toPrimitive]` method to the class, which converts its instances to
+    /// primitive values, like in `String(instance)`, template literals or arithmetic.
+    ///
+    /// The function is called with the instance as `this`, and the preferred type of the
+    /// conversion (`"string"`, `"number"` or `"default"`) as its only argument. It must return a
+    /// primitive value.
+    ///
+    /// It is added to `prototype`.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{
+    ///#    class::{Class, ClassBuilder},
+    ///#    gc::{Finalize, Trace},
+    ///#    Context, Result, Value,
+    ///# };
+    /// #[derive(Debug, Trace, Finalize)]
+    /// struct Point {
+    ///     x: f64,
+    ///     y: f64,
+    /// }
+    ///
+    /// impl Class for Point {
+    ///     const NAME: &'static str = "Point";
+    ///     const LENGTH: usize = 2;
+    ///
+    ///     fn constructor(_this: &Value, args: &[Value], context: &mut Context) -> Result<Self> {
+    ///         let x = args.get(0).cloned().unwrap_or_default().to_number(context)?;
+    ///         let y = args.get(1).cloned().unwrap_or_default().to_number(context)?;
+    ///         Ok(Self { x, y })
+    ///     }
+    ///
+    ///     fn init(class: &mut ClassBuilder) -> Result<()> {
+    ///         class.to_primitive(|this, args, context| {
+    ///             let hint = args.get(0).cloned().unwrap_or_default().to_string(context)?;
+    ///             let object = this.as_object().expect("`this` is a Point");
+    ///             let point = object.downcast_ref::<Point>().expect("`this` is a Point");
+    ///             if hint.as_str() == "number" {
+    ///                 Ok(Value::from(point.x.hypot(point.y)))
+    ///             } else {
+    ///                 Ok(Value::from(format!("({}, {})", point.x, point.y)))
+    ///             }
+    ///         });
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut context = Context::new();
+    /// context.register_global_class::<Point>().unwrap();
+    ///
+    /// let value = context.eval("var p = new Point(3, 4); `point: ${p}`").unwrap();
+    /// assert_eq!(value.as_string().unwrap().as_str(), "point: (3, 4)");
+    ///
+    /// let value = context.eval("+p").unwrap();
+    /// assert_eq!(value.as_number(), Some(5.0));
+    /// ```
+    #[inline]
+    pub fn to_primitive(&mut self, function: NativeFunction) -> &mut Self {
+        let function = FunctionBuilder::new(self.builder.context(), function)
+            .name("[Symbol.toPrimitive]")
+            .length(1)
+            .callable(true)
+            .constructable(false)
+            .build();
+        self.builder.property(
+            WellKnownSymbols::to_primitive(),
+            function,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+        self
+    }
+
     /// Add a data property to the class, with the specified attribute.
     ///
     /// It is added to `prototype`.
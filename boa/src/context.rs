@@ -3,31 +3,38 @@
 use crate::{
     builtins::{
         self,
+        date::LocalTimeZone,
         function::{Function, FunctionFlags, NativeFunction},
         iterable::IteratorPrototypes,
+        Json, LazyBuiltIn, StructuredClone, Uri,
     },
     class::{Class, ClassBuilder},
+    environment::declarative_environment_record::DeclarativeEnvironmentRecordBinding,
     exec::Interpreter,
-    object::{FunctionBuilder, GcObject, Object, PROTOTYPE},
-    property::{Attribute, DataDescriptor, PropertyKey},
+    gc::force_collect,
+    module::{ModuleLoader, Modules},
+    object::{
+        FunctionBuilder, GcObject, Object, ObjectCounter, ObjectData, PropertyCache, PROTOTYPE,
+    },
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
     realm::Realm,
-    symbol::{RcSymbol, Symbol},
+    symbol::{GlobalSymbolRegistry, RcSymbol, Symbol},
     syntax::{
         ast::{
-            node::{
-                statement_list::RcStatementList, Call, FormalParameter, Identifier, New,
-                StatementList,
-            },
-            Const, Node,
+            node::{statement_list::RcStatementList, FormalParameter, StatementList},
+            Node, Position,
         },
         Parser,
     },
     value::{RcString, Value},
     BoaProfiler, Executable, Result,
 };
+use rand::{rngs::StdRng, SeedableRng};
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::{collections::VecDeque, fmt, io::Read, rc::Rc};
 
 #[cfg(feature = "console")]
-use crate::builtins::console::Console;
+use crate::builtins::console::{Console, Logger};
 
 #[cfg(feature = "vm")]
 use crate::vm::{
@@ -75,6 +82,14 @@ impl StandardConstructor {
     pub fn prototype(&self) -> GcObject {
         self.prototype.clone()
     }
+
+    /// Copies the constructor and the prototype with [`copy_intrinsic`].
+    fn copy(&self, copies: &mut FxHashMap<usize, GcObject>) -> Self {
+        Self {
+            constructor: copy_intrinsic(&self.constructor, copies),
+            prototype: copy_intrinsic(&self.prototype, copies),
+        }
+    }
 }
 
 /// Cached core standard objects.
@@ -88,6 +103,7 @@ pub struct StandardObjects {
     boolean: StandardConstructor,
     string: StandardConstructor,
     regexp: StandardConstructor,
+    number_format: StandardConstructor,
     symbol: StandardConstructor,
     error: StandardConstructor,
     type_error: StandardConstructor,
@@ -96,8 +112,24 @@ pub struct StandardObjects {
     syntax_error: StandardConstructor,
     eval_error: StandardConstructor,
     uri_error: StandardConstructor,
+    date: StandardConstructor,
     map: StandardConstructor,
+    promise: StandardConstructor,
     set: StandardConstructor,
+    weak_map: StandardConstructor,
+    array_buffer: StandardConstructor,
+    text_encoder: StandardConstructor,
+    text_decoder: StandardConstructor,
+    typed_array: StandardConstructor,
+    int8_array: StandardConstructor,
+    uint8_array: StandardConstructor,
+    uint8_clamped_array: StandardConstructor,
+    int16_array: StandardConstructor,
+    uint16_array: StandardConstructor,
+    int32_array: StandardConstructor,
+    uint32_array: StandardConstructor,
+    float32_array: StandardConstructor,
+    float64_array: StandardConstructor,
 }
 
 impl Default for StandardObjects {
@@ -111,6 +143,7 @@ impl Default for StandardObjects {
             boolean: StandardConstructor::with_prototype(Object::boolean(false)),
             string: StandardConstructor::with_prototype(Object::string("")),
             regexp: StandardConstructor::default(),
+            number_format: StandardConstructor::default(),
             symbol: StandardConstructor::default(),
             error: StandardConstructor::default(),
             type_error: StandardConstructor::default(),
@@ -119,13 +152,70 @@ impl Default for StandardObjects {
             syntax_error: StandardConstructor::default(),
             eval_error: StandardConstructor::default(),
             uri_error: StandardConstructor::default(),
+            date: StandardConstructor::default(),
             map: StandardConstructor::default(),
+            promise: StandardConstructor::default(),
             set: StandardConstructor::default(),
+            weak_map: StandardConstructor::default(),
+            array_buffer: StandardConstructor::default(),
+            text_encoder: StandardConstructor::default(),
+            text_decoder: StandardConstructor::default(),
+            typed_array: StandardConstructor::default(),
+            int8_array: StandardConstructor::default(),
+            uint8_array: StandardConstructor::default(),
+            uint8_clamped_array: StandardConstructor::default(),
+            int16_array: StandardConstructor::default(),
+            uint16_array: StandardConstructor::default(),
+            int32_array: StandardConstructor::default(),
+            uint32_array: StandardConstructor::default(),
+            float32_array: StandardConstructor::default(),
+            float64_array: StandardConstructor::default(),
         }
     }
 }
 
 impl StandardObjects {
+    /// Copies the standard objects with [`copy_intrinsic`].
+    fn copy(&self, copies: &mut FxHashMap<usize, GcObject>) -> Self {
+        Self {
+            object: self.object.copy(copies),
+            function: self.function.copy(copies),
+            array: self.array.copy(copies),
+            bigint: self.bigint.copy(copies),
+            number: self.number.copy(copies),
+            boolean: self.boolean.copy(copies),
+            string: self.string.copy(copies),
+            regexp: self.regexp.copy(copies),
+            number_format: self.number_format.copy(copies),
+            symbol: self.symbol.copy(copies),
+            error: self.error.copy(copies),
+            type_error: self.type_error.copy(copies),
+            referece_error: self.referece_error.copy(copies),
+            range_error: self.range_error.copy(copies),
+            syntax_error: self.syntax_error.copy(copies),
+            eval_error: self.eval_error.copy(copies),
+            uri_error: self.uri_error.copy(copies),
+            date: self.date.copy(copies),
+            map: self.map.copy(copies),
+            promise: self.promise.copy(copies),
+            set: self.set.copy(copies),
+            weak_map: self.weak_map.copy(copies),
+            array_buffer: self.array_buffer.copy(copies),
+            text_encoder: self.text_encoder.copy(copies),
+            text_decoder: self.text_decoder.copy(copies),
+            typed_array: self.typed_array.copy(copies),
+            int8_array: self.int8_array.copy(copies),
+            uint8_array: self.uint8_array.copy(copies),
+            uint8_clamped_array: self.uint8_clamped_array.copy(copies),
+            int16_array: self.int16_array.copy(copies),
+            uint16_array: self.uint16_array.copy(copies),
+            int32_array: self.int32_array.copy(copies),
+            uint32_array: self.uint32_array.copy(copies),
+            float32_array: self.float32_array.copy(copies),
+            float64_array: self.float64_array.copy(copies),
+        }
+    }
+
     #[inline]
     pub fn object_object(&self) -> &StandardConstructor {
         &self.object
@@ -166,6 +256,11 @@ impl StandardObjects {
         &self.regexp
     }
 
+    #[inline]
+    pub fn number_format_object(&self) -> &StandardConstructor {
+        &self.number_format
+    }
+
     #[inline]
     pub fn symbol_object(&self) -> &StandardConstructor {
         &self.symbol
@@ -206,15 +301,352 @@ impl StandardObjects {
         &self.uri_error
     }
 
+    #[inline]
+    pub fn date_object(&self) -> &StandardConstructor {
+        &self.date
+    }
+
     #[inline]
     pub fn map_object(&self) -> &StandardConstructor {
         &self.map
     }
 
+    #[inline]
+    pub fn promise_object(&self) -> &StandardConstructor {
+        &self.promise
+    }
+
     #[inline]
     pub fn set_object(&self) -> &StandardConstructor {
         &self.set
     }
+
+    #[inline]
+    pub fn weak_map_object(&self) -> &StandardConstructor {
+        &self.weak_map
+    }
+
+    #[inline]
+    pub fn array_buffer_object(&self) -> &StandardConstructor {
+        &self.array_buffer
+    }
+
+    #[inline]
+    pub fn text_encoder_object(&self) -> &StandardConstructor {
+        &self.text_encoder
+    }
+
+    #[inline]
+    pub fn text_decoder_object(&self) -> &StandardConstructor {
+        &self.text_decoder
+    }
+
+    #[inline]
+    pub fn typed_array_object(&self) -> &StandardConstructor {
+        &self.typed_array
+    }
+
+    #[inline]
+    pub fn int8_array_object(&self) -> &StandardConstructor {
+        &self.int8_array
+    }
+
+    #[inline]
+    pub fn uint8_array_object(&self) -> &StandardConstructor {
+        &self.uint8_array
+    }
+
+    #[inline]
+    pub fn uint8_clamped_array_object(&self) -> &StandardConstructor {
+        &self.uint8_clamped_array
+    }
+
+    #[inline]
+    pub fn int16_array_object(&self) -> &StandardConstructor {
+        &self.int16_array
+    }
+
+    #[inline]
+    pub fn uint16_array_object(&self) -> &StandardConstructor {
+        &self.uint16_array
+    }
+
+    #[inline]
+    pub fn int32_array_object(&self) -> &StandardConstructor {
+        &self.int32_array
+    }
+
+    #[inline]
+    pub fn uint32_array_object(&self) -> &StandardConstructor {
+        &self.uint32_array
+    }
+
+    #[inline]
+    pub fn float32_array_object(&self) -> &StandardConstructor {
+        &self.float32_array
+    }
+
+    #[inline]
+    pub fn float64_array_object(&self) -> &StandardConstructor {
+        &self.float64_array
+    }
+}
+
+/// A script that was parsed once by [`Context::compile`], to be run many times by
+/// [`Context::execute`].
+///
+/// The compiled script doesn't depend on the context that compiled it, so it can be cloned
+/// cheaply and executed in any context of the same thread.
+#[derive(Debug, Clone)]
+pub struct CompiledScript {
+    statement_list: RcStatementList,
+}
+
+/// The own properties of the global object and the top-level lexical bindings, saved by
+/// [`Context::snapshot_globals`] to be restored by [`Context::restore_globals`].
+///
+/// The plain objects and arrays reachable from the properties and bindings are copied, so
+/// mutating them after the snapshot is taken doesn't change the snapshot. Functions and the
+/// other objects are shared with the context.
+#[derive(Debug, Clone)]
+pub struct GlobalsSnapshot {
+    properties: Vec<(PropertyKey, PropertyDescriptor)>,
+    bindings: Vec<(Box<str>, DeclarativeEnvironmentRecordBinding)>,
+    var_names: FxHashSet<Box<str>>,
+}
+
+/// Which objects [`deep_copy`] copies, instead of sharing them with the original.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CopyDepth {
+    /// Only the plain objects and arrays are copied, with the plain objects and arrays that their
+    /// data properties reference. Prototypes, accessors and any other object are shared.
+    PlainObjects,
+    /// Every object is copied, with its prototype and the functions of its accessors, so the copy
+    /// shares no object with the original.
+    All,
+}
+
+/// Copies an object with its own properties, and the objects they reference, as far as `depth`
+/// allows. The objects that aren't copied are returned as is.
+///
+/// The objects that were already copied are found in `copies`, by address, so objects that are
+/// referenced more than once, or cyclically, are copied only once.
+pub(crate) fn deep_copy(
+    object: &GcObject,
+    depth: CopyDepth,
+    copies: &mut FxHashMap<usize, GcObject>,
+) -> GcObject {
+    let address = object.as_ref() as *const _ as usize;
+    if let Some(copy) = copies.get(&address) {
+        return copy.clone();
+    }
+
+    let data = match object.borrow().data {
+        ObjectData::Ordinary => ObjectData::Ordinary,
+        ObjectData::Array => ObjectData::Array,
+        _ if depth == CopyDepth::PlainObjects => return object.clone(),
+        ObjectData::Global => ObjectData::Global,
+        ObjectData::Boolean(boolean) => ObjectData::Boolean(boolean),
+        ObjectData::Number(number) => ObjectData::Number(number),
+        ObjectData::String(ref string) => ObjectData::String(string.clone()),
+        ObjectData::Error(kind) => ObjectData::Error(kind),
+        // The native functions of the builtins don't capture any object.
+        ObjectData::Function(ref function @ Function::BuiltIn(..))
+        | ObjectData::Function(ref function @ Function::Closure(..)) => {
+            ObjectData::Function(function.clone())
+        }
+        // The internal state of the other objects can't be copied, so only their prototype and
+        // properties are.
+        _ => ObjectData::Ordinary,
+    };
+
+    // The copy is registered before copying the objects it references, as they may reference it
+    // back, like `Object.prototype.constructor.prototype`.
+    let mut copy = GcObject::new(Object::with_prototype(Value::null(), data));
+    copies.insert(address, copy.clone());
+
+    let (prototype, properties) = {
+        let object = object.borrow();
+        let properties: Vec<_> = object
+            .iter()
+            .map(|(key, property)| (key, property.clone()))
+            .collect();
+        (object.prototype_instance().clone(), properties)
+    };
+    let prototype = match depth {
+        CopyDepth::PlainObjects => prototype,
+        CopyDepth::All => deep_copy_value(&prototype, depth, copies),
+    };
+    copy.borrow_mut().set_prototype_instance(prototype);
+    for (key, property) in properties {
+        copy.insert(key, deep_copy_property(&property, depth, copies));
+    }
+    if !object.is_extensible() {
+        copy.prevent_extensions();
+    }
+    copy
+}
+
+/// Copies a value with [`deep_copy`] if it's an object.
+pub(crate) fn deep_copy_value(
+    value: &Value,
+    depth: CopyDepth,
+    copies: &mut FxHashMap<usize, GcObject>,
+) -> Value {
+    match value {
+        Value::Object(ref object) => deep_copy(object, depth, copies).into(),
+        _ => value.clone(),
+    }
+}
+
+/// Copies a binding of a declarative environment record, copying its value with [`deep_copy`]
+/// in the [`CopyDepth::PlainObjects`] mode.
+fn deep_copy_binding(
+    binding: &DeclarativeEnvironmentRecordBinding,
+    copies: &mut FxHashMap<usize, GcObject>,
+) -> DeclarativeEnvironmentRecordBinding {
+    DeclarativeEnvironmentRecordBinding {
+        value: binding
+            .value
+            .as_ref()
+            .map(|value| deep_copy_value(value, CopyDepth::PlainObjects, copies)),
+        can_delete: binding.can_delete,
+        mutable: binding.mutable,
+        strict: binding.strict,
+    }
+}
+
+/// Copies the objects referenced by a property with [`deep_copy`].
+fn deep_copy_property(
+    property: &PropertyDescriptor,
+    depth: CopyDepth,
+    copies: &mut FxHashMap<usize, GcObject>,
+) -> PropertyDescriptor {
+    match property {
+        PropertyDescriptor::Data(data) => DataDescriptor::new(
+            deep_copy_value(&data.value(), depth, copies),
+            data.attributes(),
+        )
+        .into(),
+        PropertyDescriptor::Accessor(_) if depth == CopyDepth::PlainObjects => property.clone(),
+        PropertyDescriptor::Accessor(accessor) => AccessorDescriptor::new(
+            accessor
+                .getter()
+                .map(|getter| deep_copy(getter, depth, copies)),
+            accessor
+                .setter()
+                .map(|setter| deep_copy(setter, depth, copies)),
+            accessor.attributes(),
+        )
+        .into(),
+    }
+}
+
+/// The standard builtins of a new `Context`, created once to be copied into many contexts by
+/// [`Context::from_intrinsics`].
+///
+/// Copying the intrinsics skips running the initialization code of every builtin, which makes
+/// creating many short-lived contexts, like one context per request, cheaper. Each context gets
+/// its own copy of the builtins and of the global object, so changing them in a context doesn't
+/// change the other contexts, nor the intrinsics.
+///
+/// Like the objects they hold, the intrinsics can only be used in the thread that created them.
+#[derive(Debug)]
+pub struct Intrinsics {
+    /// The context holding the original builtins, which never runs any code.
+    context: Context,
+}
+
+impl Intrinsics {
+    /// Creates the standard builtins.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            context: Context::default(),
+        }
+    }
+}
+
+impl Default for Intrinsics {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Copies an object of the intrinsics, with its prototype, its own properties and every object
+/// they reference, so the copy shares no object with the original.
+pub(crate) fn copy_intrinsic(
+    object: &GcObject,
+    copies: &mut FxHashMap<usize, GcObject>,
+) -> GcObject {
+    deep_copy(object, CopyDepth::All, copies)
+}
+
+/// Copies a value of the intrinsics, with [`copy_intrinsic`] if it's an object.
+pub(crate) fn copy_intrinsic_value(
+    value: &Value,
+    copies: &mut FxHashMap<usize, GcObject>,
+) -> Value {
+    deep_copy_value(value, CopyDepth::All, copies)
+}
+
+/// Counts the line terminators of the given source code, with `\r\n` counting as one.
+fn count_lines(source: &[u8]) -> u32 {
+    let mut lines = 0;
+    let mut index = 0;
+    while index < source.len() {
+        match source[index..] {
+            [b'\r', b'\n', ..] => index += 1,
+            [b'\r', ..] | [b'\n', ..] => lines += 1,
+            // The UTF-8 encodings of U+2028 and U+2029.
+            [0xE2, 0x80, 0xA8, ..] | [0xE2, 0x80, 0xA9, ..] => {
+                lines += 1;
+                index += 2;
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+    lines
+}
+
+/// A job that runs once the script being executed ends, like the reaction to the settlement of a
+/// promise.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-jobs
+pub(crate) struct Job(Box<dyn FnOnce(&mut Context) -> Result<Value>>);
+
+impl fmt::Debug for Job {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Job")
+    }
+}
+
+/// The operations reported to the host promise rejection tracker.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+#[derive(Debug, Clone)]
+pub(crate) enum PromiseRejectionOperation {
+    /// A promise was rejected without any handler, with the given reason.
+    Reject(Value),
+    /// A handler was added to a promise that was already rejected without any handler.
+    Handle,
+}
+
+/// The function called with the reason of the promises rejected without a handler.
+struct RejectionHandler(Box<dyn FnMut(Value)>);
+
+impl fmt::Debug for RejectionHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RejectionHandler")
+    }
 }
 
 /// Javascript context. It is the primary way to interact with the runtime.
@@ -240,23 +672,87 @@ pub struct Context {
     /// Cached standard objects and their prototypes.
     standard_objects: StandardObjects,
 
+    /// Source code buffered by `eval_incremental` that doesn't form a complete script yet.
+    pending_source: Vec<u8>,
+
+    /// The offset and the line of `pending_source` where each of the buffered chunks starts.
+    pending_chunks: Vec<(usize, u32)>,
+
+    /// Whether the scripts are parsed as strict mode code, even without a `"use strict"`
+    /// directive.
+    strict: bool,
+
+    /// The maximum number of instructions a single evaluation can execute, if any.
+    instruction_limit: Option<u64>,
+
+    /// The number of instructions executed by the current evaluation.
+    instruction_count: u64,
+
+    /// The maximum number of live objects, if any.
+    max_objects: Option<usize>,
+
+    /// The number of objects allocated while this context was running that are still alive.
+    object_counter: ObjectCounter,
+
+    /// The number of live objects above which the garbage collector runs before the object limit
+    /// is checked.
+    next_object_collection: usize,
+
+    /// The maximum number of nested function calls.
+    max_call_stack_size: usize,
+
+    /// The jobs waiting to run once the current script ends, like promise reactions.
+    job_queue: VecDeque<Job>,
+
+    /// The promises rejected without a handler, with their reason, reported once the job queue
+    /// is empty unless a handler is added in the meantime.
+    rejected_promises: Vec<(GcObject, Value)>,
+
+    /// The function called with the reason of the promises rejected without a handler.
+    unhandled_rejection_handler: Option<RejectionHandler>,
+
+    /// The module loader and the modules loaded by this context.
+    pub(crate) modules: Modules,
+
+    /// The random number generator backing `Math.random`.
+    rng: StdRng,
+
+    /// The time zone of the local time methods of `Date`.
+    time_zone: LocalTimeZone,
+
+    /// The builtins that are initialized once their global property is first accessed.
+    pub(crate) lazy_builtins: Vec<LazyBuiltIn>,
+
+    /// The inline cache of the constant property accesses.
+    property_cache: PropertyCache,
+
+    /// The strings of the string literals and of the literal property names evaluated so far,
+    /// shared by all the literals with the same content.
+    interned_strings: FxHashSet<RcString>,
+
+    /// The number of interned strings above which the strings that are only referenced by the
+    /// table are removed.
+    interned_sweep_len: usize,
+
+    /// The global symbol registry of `Symbol.for` and `Symbol.keyFor`.
+    symbol_registry: GlobalSymbolRegistry,
+
     /// Whether or not to show trace of instructions being ran
     pub trace: bool,
 }
 
 impl Default for Context {
     fn default() -> Self {
-        let realm = Realm::create();
-        let executor = Interpreter::new();
-        let mut context = Self {
-            realm,
-            executor,
-            #[cfg(feature = "console")]
-            console: Console::default(),
-            iterator_prototypes: IteratorPrototypes::default(),
-            standard_objects: Default::default(),
-            trace: false,
-        };
+        // The builtins count towards the object limit of the context.
+        let object_counter = ObjectCounter::default();
+        object_counter.enter();
+
+        let mut context = Self::with_realm(
+            Realm::create(),
+            StandardObjects::default(),
+            IteratorPrototypes::default(),
+            object_counter,
+        );
 
         // Add new builtIns to Context Realm
         // At a later date this can be removed from here and called explicitly,
@@ -274,11 +770,156 @@ impl Context {
         Default::default()
     }
 
+    /// Creates a new `Context` with its own copy of the given [`Intrinsics`], instead of creating
+    /// the builtins again.
+    ///
+    /// # Example
+    /// ```
+    /// use boa::{Context, Intrinsics, Value};
+    ///
+    /// let intrinsics = Intrinsics::new();
+    /// let mut first = Context::from_intrinsics(&intrinsics);
+    /// let mut second = Context::from_intrinsics(&intrinsics);
+    ///
+    /// first.eval("Array.prototype.answer = 42").unwrap();
+    /// assert_eq!(first.eval("[].answer").unwrap().as_number(), Some(42.0));
+    /// assert_eq!(second.eval("[].answer").unwrap(), Value::undefined());
+    /// ```
+    pub fn from_intrinsics(intrinsics: &Intrinsics) -> Self {
+        let _timer = BoaProfiler::global().start_event("Context::from_intrinsics", "context");
+        let original = &intrinsics.context;
+
+        let object_counter = ObjectCounter::default();
+        object_counter.enter();
+
+        let mut copies = FxHashMap::default();
+        let global_object = copy_intrinsic(&original.realm.global_object, &mut copies);
+        let mut context = Self::with_realm(
+            Realm::with_global_object(global_object),
+            original.standard_objects.copy(&mut copies),
+            original.iterator_prototypes.copy(&mut copies),
+            object_counter,
+        );
+        context.lazy_builtins = original
+            .lazy_builtins
+            .iter()
+            .map(|builtin| builtin.copy(&mut copies))
+            .collect();
+        context
+    }
+
+    /// Creates a `Context` with the given realm and standard objects, and the default settings.
+    fn with_realm(
+        realm: Realm,
+        standard_objects: StandardObjects,
+        iterator_prototypes: IteratorPrototypes,
+        object_counter: ObjectCounter,
+    ) -> Self {
+        Self {
+            realm,
+            executor: Interpreter::new(),
+            #[cfg(feature = "console")]
+            console: Console::default(),
+            iterator_prototypes,
+            standard_objects,
+            pending_source: Vec::new(),
+            pending_chunks: Vec::new(),
+            strict: false,
+            instruction_limit: None,
+            instruction_count: 0,
+            max_objects: None,
+            object_counter,
+            next_object_collection: 0,
+            max_call_stack_size: Self::DEFAULT_MAX_CALL_STACK_SIZE,
+            job_queue: VecDeque::new(),
+            rejected_promises: Vec::new(),
+            unhandled_rejection_handler: None,
+            modules: Modules::default(),
+            rng: StdRng::from_entropy(),
+            time_zone: LocalTimeZone::default(),
+            lazy_builtins: Vec::new(),
+            property_cache: PropertyCache::default(),
+            interned_strings: FxHashSet::default(),
+            interned_sweep_len: Self::MIN_INTERNED_SWEEP_LEN,
+            symbol_registry: GlobalSymbolRegistry::default(),
+            trace: false,
+        }
+    }
+
+    /// Create a [`ContextBuilder`], to choose the global bindings of the new `Context`.
+    #[inline]
+    pub fn builder() -> ContextBuilder {
+        ContextBuilder::default()
+    }
+
     #[inline]
     pub fn executor(&mut self) -> &mut Interpreter {
         &mut self.executor
     }
 
+    /// Gets the inline cache of the constant property accesses.
+    #[inline]
+    pub(crate) fn property_cache(&mut self) -> &mut PropertyCache {
+        &mut self.property_cache
+    }
+
+    /// Gets the number of constant property accesses, like `obj.x` or `obj.x = 1`, that were
+    /// resolved by the inline cache, without looking up the property.
+    ///
+    /// # Example
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    /// context
+    ///     .eval("let obj = { x: 1 }; for (let i = 0; i < 10; i++) { obj.x; }")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(context.property_cache_hits(), 9);
+    /// ```
+    #[inline]
+    pub fn property_cache_hits(&self) -> u64 {
+        self.property_cache.hits()
+    }
+
+    /// Gets the string shared by all the literals equal to `string`, so that evaluating the same
+    /// literal many times, or equal literals, doesn't allocate a new string each time.
+    ///
+    /// The strings that aren't used anymore are removed when the table has doubled since they were
+    /// last removed, so it stays proportional to the number of live strings.
+    pub(crate) fn intern_string(&mut self, string: &str) -> RcString {
+        if let Some(interned) = self.interned_strings.get(string) {
+            return interned.clone();
+        }
+        if self.interned_strings.len() >= self.interned_sweep_len {
+            self.interned_strings
+                .retain(|interned| !interned.is_unique());
+            self.interned_sweep_len =
+                (self.interned_strings.len() * 2).max(Self::MIN_INTERNED_SWEEP_LEN);
+        }
+        let interned = RcString::from(string);
+        self.interned_strings.insert(interned.clone());
+        interned
+    }
+
+    /// Gets the number of distinct strings interned for the string literals and the literal
+    /// property names evaluated by this context, including the unused strings that weren't
+    /// removed yet.
+    ///
+    /// # Example
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    /// context
+    ///     .eval("let a = { key: 'value' }; let b = { key: 'value', other: 'value' };")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(context.interned_string_count(), 3);
+    /// ```
+    #[inline]
+    pub fn interned_string_count(&self) -> usize {
+        self.interned_strings.len()
+    }
+
     /// A helper function for getting an immutable reference to the `console` object.
     #[cfg(feature = "console")]
     pub(crate) fn console(&self) -> &Console {
@@ -292,6 +933,32 @@ impl Context {
         &mut self.console
     }
 
+    /// Sets the [`Logger`] that receives the messages of the `console` object, instead of
+    /// printing them to the standard output.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{builtins::console::{LogMessage, Logger}, Context};
+    /// #[derive(Debug)]
+    /// struct Discard;
+    ///
+    /// impl Logger for Discard {
+    ///     fn log(&mut self, _: LogMessage) {}
+    /// }
+    ///
+    /// let mut context = Context::new();
+    /// context.set_logger(Discard);
+    /// context.eval("console.log('nobody sees this')").unwrap();
+    /// ```
+    #[cfg(feature = "console")]
+    #[inline]
+    pub fn set_logger<L>(&mut self, logger: L)
+    where
+        L: Logger + 'static,
+    {
+        self.console.set_logger(Box::new(logger));
+    }
+
     /// Sets up the default global objects within Global
     #[inline]
     fn create_intrinsics(&mut self) {
@@ -306,6 +973,12 @@ impl Context {
         RcSymbol::from(Symbol::new(description))
     }
 
+    /// Gets the global symbol registry, shared by `Symbol.for` and `Symbol.keyFor`.
+    #[inline]
+    pub(crate) fn symbol_registry(&mut self) -> &mut GlobalSymbolRegistry {
+        &mut self.symbol_registry
+    }
+
     /// Construct an empty object.
     #[inline]
     pub fn construct_object(&self) -> GcObject {
@@ -316,18 +989,47 @@ impl Context {
     /// <https://tc39.es/ecma262/#sec-call>
     #[inline]
     pub(crate) fn call(&mut self, f: &Value, this: &Value, args: &[Value]) -> Result<Value> {
-        match *f {
-            Value::Object(ref object) => object.call(this, args, self),
-            _ => self.throw_type_error("not a function"),
-        }
+        f.call(this, args, self)
     }
 
     /// Return the global object.
+    ///
+    /// Some builtins are only initialized once a script first reads them, and their global
+    /// properties hold a placeholder until then. Call [`init_lazy_builtins`](Self::init_lazy_builtins)
+    /// before reading these properties, or handing the global object to a script.
     #[inline]
     pub fn global_object(&self) -> GcObject {
         self.realm.global_object.clone()
     }
 
+    /// Initializes the builtins that are otherwise initialized when a script first reads them.
+    ///
+    /// # Example
+    /// ```
+    /// use boa::{Context, Value};
+    ///
+    /// let mut context = Context::new();
+    /// context.init_lazy_builtins();
+    /// let global_object = Value::from(context.global_object());
+    /// let math = global_object.get_field("Math", &mut context).unwrap();
+    /// assert!(math.get_field("PI", &mut context).unwrap().is_number());
+    /// ```
+    #[inline]
+    pub fn init_lazy_builtins(&mut self) {
+        builtins::init_all_lazy(self)
+    }
+
+    /// Constructs an error with the standard error `constructor` and the specified message.
+    ///
+    /// The standard constructor is used instead of the global binding, so the error can be
+    /// created even if the global was removed or overwritten.
+    fn construct_error(&mut self, constructor: GcObject, message: Box<str>) -> Value {
+        let new_target = constructor.clone().into();
+        constructor
+            .construct(&[message.into()], &new_target, self)
+            .expect("Into<String> used as message")
+    }
+
     /// Constructs a `RangeError` with the specified message.
     #[inline]
     pub fn construct_range_error<M>(&mut self, message: M) -> Value
@@ -335,12 +1037,8 @@ impl Context {
         M: Into<Box<str>>,
     {
         // Runs a `new RangeError(message)`.
-        New::from(Call::new(
-            Identifier::from("RangeError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        let constructor = self.standard_objects().range_error_object().constructor();
+        self.construct_error(constructor, message.into())
     }
 
     /// Throws a `RangeError` with the specified message.
@@ -359,12 +1057,8 @@ impl Context {
         M: Into<Box<str>>,
     {
         // Runs a `new TypeError(message)`.
-        New::from(Call::new(
-            Identifier::from("TypeError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        let constructor = self.standard_objects().type_error_object().constructor();
+        self.construct_error(constructor, message.into())
     }
 
     /// Throws a `TypeError` with the specified message.
@@ -382,12 +1076,12 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("ReferenceError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        // Runs a `new ReferenceError(message)`.
+        let constructor = self
+            .standard_objects()
+            .reference_error_object()
+            .constructor();
+        self.construct_error(constructor, message.into())
     }
 
     /// Throws a `ReferenceError` with the specified message.
@@ -405,12 +1099,9 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("SyntaxError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        // Runs a `new SyntaxError(message)`.
+        let constructor = self.standard_objects().syntax_error_object().constructor();
+        self.construct_error(constructor, message.into())
     }
 
     /// Throws a `SyntaxError` with the specified message.
@@ -427,12 +1118,9 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("EvalError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        // Runs a `new EvalError(message)`.
+        let constructor = self.standard_objects().eval_error_object().constructor();
+        self.construct_error(constructor, message.into())
     }
 
     /// Constructs a `URIError` with the specified message.
@@ -440,12 +1128,9 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("URIError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        // Runs a `new URIError(message)`.
+        let constructor = self.standard_objects().uri_error_object().constructor();
+        self.construct_error(constructor, message.into())
     }
 
     /// Throws a `EvalError` with the specified message.
@@ -478,9 +1163,6 @@ impl Context {
         let function_prototype: Value =
             self.standard_objects().function_object().prototype().into();
 
-        // Every new function has a prototype property pre-made
-        let proto = Value::new_object(self);
-
         let params = params.into();
         let params_len = params.len();
         let func = Function::Ordinary {
@@ -488,16 +1170,34 @@ impl Context {
             body: RcStatementList::from(body.into()),
             params,
             environment: self.get_current_environment().clone(),
+            home_object: None,
         };
 
         let new_func = Object::function(func, function_prototype);
 
         let val = Value::from(new_func);
 
-        // Set constructor field to the newly created Value (function object)
-        proto.set_field("constructor", val.clone(), false, self)?;
-
-        val.set_field(PROTOTYPE, proto, false, self)?;
+        // Constructors and generator functions have a prototype property pre-made, arrow functions
+        // and async functions don't have one.
+        if flags.is_generator() {
+            // The prototype of the generator objects has no constructor field
+            let generator_prototype = if flags.is_async() {
+                self.iterator_prototypes().async_generator()
+            } else {
+                self.iterator_prototypes().generator()
+            };
+            let proto = Value::new_object(self);
+            proto
+                .as_object()
+                .expect("prototype object")
+                .set_prototype_instance(generator_prototype.into());
+            val.set_field(PROTOTYPE, proto, false, self)?;
+        } else if flags.is_constructable() {
+            // Set constructor field to the newly created Value (function object)
+            let proto = Value::new_object(self);
+            proto.set_field("constructor", val.clone(), false, self)?;
+            val.set_field(PROTOTYPE, proto, false, self)?;
+        }
         val.set_field("length", Value::from(params_len), false, self)?;
 
         Ok(val)
@@ -539,11 +1239,11 @@ impl Context {
 
     /// <https://tc39.es/ecma262/#sec-hasproperty>
     #[inline]
-    pub(crate) fn has_property(&self, obj: &Value, key: &PropertyKey) -> bool {
+    pub(crate) fn has_property(&mut self, obj: &Value, key: &PropertyKey) -> Result<bool> {
         if let Some(obj) = obj.as_object() {
-            obj.has_property(key)
+            obj.has_property(key, self)
         } else {
-            false
+            Ok(false)
         }
     }
 
@@ -554,17 +1254,31 @@ impl Context {
                 self.set_mutable_binding(name.as_ref(), value.clone(), true)?;
                 Ok(value)
             }
-            Node::GetConstField(ref get_const_field_node) => Ok(get_const_field_node
-                .obj()
-                .run(self)?
-                .set_field(get_const_field_node.field(), value, false, self)?),
+            Node::GetConstField(ref get_const_field_node) => {
+                let strict = self.executor().strict();
+                let site = get_const_field_node.slot();
+                let field = get_const_field_node.field();
+                let obj = get_const_field_node.obj().run(self)?;
+                if let Value::Object(ref object) = obj {
+                    if self.property_cache.set(site, object, field, &value) {
+                        return Ok(value);
+                    }
+                }
+
+                let value = obj.set_field(field, value, strict, self)?;
+                if let Value::Object(ref object) = obj {
+                    self.property_cache.record(site, object, field);
+                }
+                Ok(value)
+            }
             Node::GetField(ref get_field) => {
                 let field = get_field.field().run(self)?;
                 let key = field.to_property_key(self)?;
+                let strict = self.executor().strict();
                 Ok(get_field
                     .obj()
                     .run(self)?
-                    .set_field(key, value, false, self)?)
+                    .set_field(key, value, strict, self)?)
             }
             _ => self.throw_type_error(format!("invalid assignment to {}", node)),
         }
@@ -623,42 +1337,270 @@ impl Context {
         self.global_object().insert(key, property);
     }
 
-    /// Evaluates the given code.
+    /// Register a global constant, a property that can't be assigned, deleted or redefined.
     ///
-    /// # Examples
+    /// Assigning to the constant does nothing, or throws a `TypeError` in strict mode. The
+    /// constant isn't enumerable.
+    ///
+    /// # Example
     /// ```
-    ///# use boa::Context;
+    /// use boa::Context;
+    ///
     /// let mut context = Context::new();
     ///
-    /// let value = context.eval("1 + 3").unwrap();
+    /// context.register_global_constant("VERSION", "1.0.0");
     ///
-    /// assert!(value.is_number());
-    /// assert_eq!(value.as_number().unwrap(), 4.0);
+    /// assert!(context.eval("'use strict'; VERSION = '2.0.0';").is_err());
+    /// assert!(context.eval("VERSION = '2.0.0';").is_ok());
+    /// assert_eq!(
+    ///     context.eval("VERSION").unwrap().as_string().unwrap().as_str(),
+    ///     "1.0.0"
+    /// );
     /// ```
-    #[cfg(not(feature = "vm"))]
-    #[allow(clippy::unit_arg, clippy::drop_copy)]
     #[inline]
-    pub fn eval<T: AsRef<[u8]>>(&mut self, src: T) -> Result<Value> {
-        let main_timer = BoaProfiler::global().start_event("Main", "Main");
-        let src_bytes: &[u8] = src.as_ref();
-
-        let parsing_result = Parser::new(src_bytes, false)
-            .parse_all()
-            .map_err(|e| e.to_string());
-
-        let execution_result = match parsing_result {
-            Ok(statement_list) => statement_list.run(self),
-            Err(e) => self.throw_syntax_error(e),
-        };
-
-        // The main_timer needs to be dropped before the BoaProfiler is.
-        drop(main_timer);
-        BoaProfiler::global().drop();
+    pub fn register_global_constant<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        self.register_global_property(
+            key,
+            value,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+    }
+
+    /// Register a global variable, a property that can be assigned, deleted and redefined, like
+    /// the standard builtins.
+    ///
+    /// The variable isn't enumerable.
+    ///
+    /// # Example
+    /// ```
+    /// use boa::Context;
+    ///
+    /// let mut context = Context::new();
+    ///
+    /// context.register_global_mutable("counter", 0);
+    ///
+    /// assert_eq!(context.eval("counter += 1; counter").unwrap().as_number(), Some(1.0));
+    /// ```
+    #[inline]
+    pub fn register_global_mutable<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        self.register_global_property(
+            key,
+            value,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+    }
+
+    /// Register a global accessor property, whose getter runs the `get` closure on each access.
+    ///
+    /// The getter is called with the global object as `this` and no arguments. The property
+    /// doesn't have a setter, so assigning to it does nothing, or throws in strict mode. Use
+    /// [`Context::register_global_accessor`] to also handle the assignments.
+    ///
+    /// Like [`FunctionBuilder::closure`], the closure must not capture garbage collected values.
+    ///
+    /// # Example
+    /// ```
+    /// use boa::{Context, property::Attribute, Value};
+    ///
+    /// let mut context = Context::new();
+    ///
+    /// context.register_global_getter(
+    ///     "random",
+    ///     |_, _, _| Ok(Value::from(rand::random::<f64>())),
+    ///     Attribute::CONFIGURABLE,
+    /// );
+    ///
+    /// let first = context.eval("random").unwrap();
+    /// let second = context.eval("random").unwrap();
+    /// assert!(first.is_number() && second.is_number());
+    /// assert_ne!(first.as_number(), second.as_number());
+    /// ```
+    #[inline]
+    pub fn register_global_getter<K, G>(&mut self, key: K, get: G, attribute: Attribute)
+    where
+        K: Into<PropertyKey>,
+        G: Fn(&Value, &[Value], &mut Context) -> Result<Value> + 'static,
+    {
+        let key = key.into();
+        let get = FunctionBuilder::closure(self, get)
+            .name(format!("get {}", key))
+            .build();
+        let property = AccessorDescriptor::new(Some(get), None, attribute);
+        self.global_object().insert(key, property);
+    }
+
+    /// Register a global accessor property, whose getter and setter run the `get` and `set`
+    /// closures on each access.
+    ///
+    /// The getter is called with the global object as `this` and no arguments, and the setter is
+    /// called with the global object as `this` and the assigned value as its only argument.
+    ///
+    /// Like [`FunctionBuilder::closure`], the closures must not capture garbage collected values.
+    ///
+    /// # Example
+    /// ```
+    /// use boa::{Context, property::Attribute, Value};
+    /// use std::{cell::Cell, rc::Rc};
+    ///
+    /// let mut context = Context::new();
+    /// let level = Rc::new(Cell::new(1.0));
+    ///
+    /// let (get_level, set_level) = (level.clone(), level.clone());
+    /// context.register_global_accessor(
+    ///     "level",
+    ///     move |_, _, _| Ok(Value::from(get_level.get())),
+    ///     move |_, args, context| {
+    ///         let value = args.get(0).cloned().unwrap_or_default().to_number(context)?;
+    ///         set_level.set(value);
+    ///         Ok(Value::undefined())
+    ///     },
+    ///     Attribute::CONFIGURABLE,
+    /// );
+    ///
+    /// let result = context.eval("level = level * 4; level").unwrap();
+    /// assert_eq!(result.as_number(), Some(4.0));
+    /// assert_eq!(level.get(), 4.0);
+    /// ```
+    #[inline]
+    pub fn register_global_accessor<K, G, S>(
+        &mut self,
+        key: K,
+        get: G,
+        set: S,
+        attribute: Attribute,
+    ) where
+        K: Into<PropertyKey>,
+        G: Fn(&Value, &[Value], &mut Context) -> Result<Value> + 'static,
+        S: Fn(&Value, &[Value], &mut Context) -> Result<Value> + 'static,
+    {
+        let key = key.into();
+        let get = FunctionBuilder::closure(self, get)
+            .name(format!("get {}", key))
+            .build();
+        let set = FunctionBuilder::closure(self, set)
+            .name(format!("set {}", key))
+            .length(1)
+            .build();
+        let property = AccessorDescriptor::new(Some(get), Some(set), attribute);
+        self.global_object().insert(key, property);
+    }
 
-        execution_result
+    /// Saves the own properties of the global object and the top-level lexical bindings, to
+    /// restore them later with [`Context::restore_globals`].
+    ///
+    /// This covers the built-in objects, the global `var` and function declarations, the
+    /// properties registered by the embedder, and the top-level `let`, `const` and `class`
+    /// declarations.
+    ///
+    /// # Example
+    /// ```
+    /// use boa::Context;
+    ///
+    /// let mut context = Context::new();
+    /// context.eval("var config = { debug: false }; let count = 0;").unwrap();
+    /// let snapshot = context.snapshot_globals();
+    ///
+    /// context.eval("config.debug = true; count++; var extra = 1;").unwrap();
+    /// context.restore_globals(&snapshot);
+    ///
+    /// assert_eq!(context.eval("config.debug").unwrap().as_boolean(), Some(false));
+    /// assert_eq!(context.eval("count").unwrap().as_number(), Some(0.0));
+    /// assert_eq!(
+    ///     context.eval("typeof extra").unwrap().as_string().unwrap().as_str(),
+    ///     "undefined"
+    /// );
+    /// ```
+    pub fn snapshot_globals(&self) -> GlobalsSnapshot {
+        let properties: Vec<_> = self
+            .global_object()
+            .borrow()
+            .iter()
+            .map(|(key, property)| (key, property.clone()))
+            .collect();
+
+        let environment = self.get_global_environment();
+        let global_environment = environment
+            .as_global_environment()
+            .expect("the global environment");
+        let bindings: Vec<_> = global_environment
+            .declarative_record
+            .env_rec
+            .borrow()
+            .iter()
+            .map(|(name, binding)| (name.clone(), binding.clone()))
+            .collect();
+        let var_names = global_environment.var_names.borrow().clone();
+
+        let mut copies = FxHashMap::default();
+        GlobalsSnapshot {
+            properties: properties
+                .into_iter()
+                .map(|(key, property)| {
+                    let property = if builtins::is_lazy_placeholder(self, &property) {
+                        property
+                    } else {
+                        deep_copy_property(&property, CopyDepth::PlainObjects, &mut copies)
+                    };
+                    (key, property)
+                })
+                .collect(),
+            bindings: bindings
+                .into_iter()
+                .map(|(name, binding)| {
+                    let binding = deep_copy_binding(&binding, &mut copies);
+                    (name, binding)
+                })
+                .collect(),
+            var_names,
+        }
     }
 
-    /// Evaluates the given code by compiling down to bytecode, then interpreting the bytecode into a value
+    /// Restores the own properties of the global object and the top-level lexical bindings
+    /// saved by [`Context::snapshot_globals`].
+    ///
+    /// The global properties and bindings added after the snapshot was taken are removed, and
+    /// the others get back their value and attributes, even if they aren't configurable. The
+    /// snapshot is left unchanged, so it can be restored again.
+    pub fn restore_globals(&mut self, snapshot: &GlobalsSnapshot) {
+        let mut global = self.global_object();
+        let keys: Vec<_> = global.borrow().keys().collect();
+        for key in keys {
+            global.remove(&key);
+        }
+
+        let mut copies = FxHashMap::default();
+        for (key, property) in &snapshot.properties {
+            let property = if builtins::is_lazy_placeholder(self, property) {
+                property.clone()
+            } else {
+                deep_copy_property(property, CopyDepth::PlainObjects, &mut copies)
+            };
+            global.insert(key.clone(), property);
+        }
+        builtins::restore_lazy(self);
+
+        let environment = self.get_global_environment();
+        let global_environment = environment
+            .as_global_environment()
+            .expect("the global environment");
+        let bindings = snapshot
+            .bindings
+            .iter()
+            .map(|(name, binding)| (name.clone(), deep_copy_binding(binding, &mut copies)))
+            .collect();
+        *global_environment.declarative_record.env_rec.borrow_mut() = bindings;
+        *global_environment.var_names.borrow_mut() = snapshot.var_names.clone();
+    }
+
+    /// Evaluates the given code.
     ///
     /// # Examples
     /// ```
@@ -670,33 +1612,660 @@ impl Context {
     /// assert!(value.is_number());
     /// assert_eq!(value.as_number().unwrap(), 4.0);
     /// ```
-    #[cfg(feature = "vm")]
+    ///
+    /// If the code throws an exception that isn't caught, the thrown value is returned as the
+    /// error, and the context can keep evaluating code afterwards:
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// let error = context.eval("throw { code: 42 }").unwrap_err();
+    /// let code = error.get_field("code", &mut context).unwrap();
+    /// assert_eq!(code.as_number().unwrap(), 42.0);
+    ///
+    /// let error = context.eval("throw 'oops'").unwrap_err();
+    /// assert_eq!(error.as_string().unwrap().as_str(), "oops");
+    ///
+    /// assert!(context.eval("1 + 1").is_ok());
+    /// ```
     #[allow(clippy::unit_arg, clippy::drop_copy)]
+    #[inline]
     pub fn eval<T: AsRef<[u8]>>(&mut self, src: T) -> Result<Value> {
         let main_timer = BoaProfiler::global().start_event("Main", "Main");
-        let src_bytes: &[u8] = src.as_ref();
 
-        let parsing_result = Parser::new(src_bytes, false)
-            .parse_all()
-            .map_err(|e| e.to_string());
+        let result = self.compile(src).and_then(|script| self.execute(&script));
 
-        let statement_list = match parsing_result {
-            Ok(statement_list) => statement_list,
-            Err(e) => return self.throw_syntax_error(e),
-        };
+        // The main_timer needs to be dropped before the BoaProfiler is.
+        drop(main_timer);
+        BoaProfiler::global().drop();
 
+        result
+    }
+
+    /// Parses the given code once, so that it can be run many times with
+    /// [`Context::execute`](Context::execute).
+    ///
+    /// Throws a `SyntaxError` if the code can't be parsed.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// let script = context
+    ///     .compile("var count = typeof count === 'number' ? count + 1 : 1; count")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(context.execute(&script).unwrap().as_number(), Some(1.0));
+    /// assert_eq!(context.execute(&script).unwrap().as_number(), Some(2.0));
+    ///
+    /// // The script can run in other contexts too.
+    /// assert_eq!(Context::new().execute(&script).unwrap().as_number(), Some(1.0));
+    /// ```
+    pub fn compile<T: AsRef<[u8]>>(&mut self, src: T) -> Result<CompiledScript> {
+        match Parser::new(src.as_ref(), self.strict).parse_all() {
+            Ok(statement_list) => Ok(CompiledScript {
+                statement_list: statement_list.into(),
+            }),
+            Err(e) => Err(self.construct_syntax_error(e.to_string())),
+        }
+    }
+
+    /// Runs a script compiled by [`Context::compile`](Context::compile), like
+    /// [`Context::eval`](Context::eval) runs code, but without parsing it again.
+    #[cfg(not(feature = "vm"))]
+    pub fn execute(&mut self, script: &CompiledScript) -> Result<Value> {
+        self.instruction_count = 0;
+        let result = script.statement_list.run_body(self);
+        self.finish_script(result)
+    }
+
+    /// Runs a script compiled by [`Context::compile`](Context::compile), by compiling it down to
+    /// bytecode, then interpreting the bytecode into a value.
+    #[cfg(feature = "vm")]
+    pub fn execute(&mut self, script: &CompiledScript) -> Result<Value> {
         let mut compiler = Compiler::default();
-        statement_list.compile(&mut compiler);
+        script.statement_list.compile(&mut compiler);
 
+        self.instruction_count = 0;
         let mut vm = VM::new(compiler, self);
         // Generate Bytecode and place it into instruction_stack
         // Interpret the Bytecode
         let result = vm.run();
-        // The main_timer needs to be dropped before the BoaProfiler is.
-        drop(main_timer);
-        BoaProfiler::global().drop();
+        self.finish_script(result)
+    }
 
-        result
+    /// Evaluates a chunk of code, buffering it until a complete script can be parsed.
+    ///
+    /// Returns `Ok(None)` if more input is needed to complete the script, for example when
+    /// the chunk ends with an open bracket, an unterminated template literal or an operator,
+    /// and `Ok(Some(value))` once the buffered code has been evaluated. The buffer is cleared
+    /// after a complete script has been evaluated, or a syntax error has been thrown.
+    ///
+    /// The chunks are joined with line terminators, like the lines of a REPL, and the position of
+    /// a syntax error is given relative to the chunk it's in.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// assert!(context.eval_incremental("1 +").unwrap().is_none());
+    ///
+    /// let value = context.eval_incremental("2").unwrap().unwrap();
+    /// assert_eq!(value.as_number().unwrap(), 3.0);
+    /// ```
+    pub fn eval_incremental<T: AsRef<[u8]>>(&mut self, chunk: T) -> Result<Option<Value>> {
+        let line = match self.pending_chunks.last() {
+            Some(&(offset, line)) => {
+                let lines = count_lines(&self.pending_source[offset..]);
+                self.pending_source.push(b'\n');
+                line + lines + 1
+            }
+            None => 1,
+        };
+        self.pending_chunks.push((self.pending_source.len(), line));
+        self.pending_source.extend_from_slice(chunk.as_ref());
+
+        let result = Parser::new(self.pending_source.as_slice(), self.strict).parse_all();
+        if matches!(result, Err(ref e) if e.is_incomplete()) {
+            return Ok(None);
+        }
+
+        let chunks = std::mem::take(&mut self.pending_chunks);
+        self.pending_source.clear();
+        match result {
+            Ok(statement_list) => {
+                self.instruction_count = 0;
+                let result = statement_list.run_body(self);
+                self.finish_script(result).map(Some)
+            }
+            Err(e) => {
+                // The position is given relative to the chunk that contains it.
+                let index = e.position().map(|position| {
+                    chunks
+                        .iter()
+                        .rposition(|&(_, line)| line <= position.line_number())
+                        .unwrap_or(0)
+                });
+                let message = match index {
+                    Some(index) if chunks.len() > 1 => {
+                        let first_line = chunks[index].1;
+                        let e = e.map_position(|position| {
+                            Position::new(
+                                position.line_number() - first_line + 1,
+                                position.column_number(),
+                            )
+                        });
+                        format!("{} of chunk {} of the pending input", e, index + 1)
+                    }
+                    _ => e.to_string(),
+                };
+                Err(self.construct_syntax_error(message))
+            }
+        }
+    }
+
+    /// Adds a job to the end of the job queue.
+    ///
+    /// The job runs once the script being executed ends, after the jobs enqueued before it.
+    #[inline]
+    pub(crate) fn enqueue_job<F>(&mut self, job: F)
+    where
+        F: FnOnce(&mut Context) -> Result<Value> + 'static,
+    {
+        self.job_queue.push_back(Job(Box::new(job)));
+    }
+
+    /// Runs the jobs of the job queue, like the reactions to settled promises, until it's empty.
+    ///
+    /// The jobs enqueued by a job run in the same call. The jobs of a script are run at the end
+    /// of [`Context::eval`](Context::eval), so this is only needed by the jobs enqueued by host
+    /// code. If a job throws, the jobs after it stay in the queue.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// context
+    ///     .eval("var result; Promise.resolve(1).then(function (v) { result = v; })")
+    ///     .unwrap();
+    ///
+    /// // The reaction already ran at the end of the evaluation.
+    /// assert_eq!(context.eval("result").unwrap().as_number(), Some(1.0));
+    /// context.run_jobs().unwrap();
+    /// ```
+    pub fn run_jobs(&mut self) -> Result<()> {
+        while let Some(Job(job)) = self.job_queue.pop_front() {
+            job(self)?;
+        }
+
+        let rejected_promises = std::mem::take(&mut self.rejected_promises);
+        if let Some(RejectionHandler(handler)) = self.unhandled_rejection_handler.as_mut() {
+            for (_, reason) in rejected_promises {
+                handler(reason);
+            }
+        }
+        Ok(())
+    }
+
+    /// Sets the function called with the reason of each promise rejected without a handler.
+    ///
+    /// The function is called once the job queue is empty, so a handler added by a job that runs
+    /// after the rejection, like a later `.catch()`, keeps the rejection from being reported.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    ///# use std::{cell::RefCell, rc::Rc};
+    /// let mut context = Context::new();
+    /// let reasons = Rc::new(RefCell::new(Vec::new()));
+    ///
+    /// let handler_reasons = reasons.clone();
+    /// context.set_unhandled_rejection_handler(move |reason| {
+    ///     handler_reasons.borrow_mut().push(reason);
+    /// });
+    /// context
+    ///     .eval("Promise.reject(1); Promise.reject(2).catch(function () {})")
+    ///     .unwrap();
+    ///
+    /// let reasons = reasons.borrow();
+    /// assert_eq!(reasons.len(), 1);
+    /// assert_eq!(reasons[0].as_number(), Some(1.0));
+    /// ```
+    #[inline]
+    pub fn set_unhandled_rejection_handler<F>(&mut self, handler: F)
+    where
+        F: FnMut(Value) + 'static,
+    {
+        self.unhandled_rejection_handler = Some(RejectionHandler(Box::new(handler)));
+    }
+
+    /// The host promise rejection tracker, which keeps track of the promises rejected without a
+    /// handler.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-host-promise-rejection-tracker
+    pub(crate) fn track_promise_rejection(
+        &mut self,
+        promise: &GcObject,
+        operation: PromiseRejectionOperation,
+    ) {
+        match operation {
+            PromiseRejectionOperation::Reject(reason) => {
+                self.rejected_promises.push((promise.clone(), reason))
+            }
+            PromiseRejectionOperation::Handle => self
+                .rejected_promises
+                .retain(|(rejected, _)| !GcObject::equals(rejected, promise)),
+        }
+    }
+
+    /// Runs the jobs enqueued by a script once it ends, and returns the result of the script.
+    ///
+    /// The jobs run even if the script throws, in which case the error of the script is
+    /// returned.
+    pub(crate) fn finish_script(&mut self, result: Result<Value>) -> Result<Value> {
+        let jobs = self.run_jobs();
+        let value = result?;
+        jobs.map(|_| value)
+    }
+
+    /// Discards the code buffered by [`Context::eval_incremental`](Context::eval_incremental).
+    #[inline]
+    pub fn clear_incremental(&mut self) {
+        self.pending_source.clear();
+        self.pending_chunks.clear();
+    }
+
+    /// Sets the loader that resolves the modules imported by `import` declarations.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{module::ModuleSource, Context, Result};
+    /// let mut context = Context::new();
+    ///
+    /// context.set_module_loader(
+    ///     |specifier: &str, _referrer: Option<&str>, _: &mut Context| -> Result<ModuleSource> {
+    ///         Ok(ModuleSource::new(
+    ///             specifier,
+    ///             "export function double(x) { return x * 2; }",
+    ///         ))
+    ///     },
+    /// );
+    ///
+    /// let namespace = context
+    ///     .eval_module("import { double } from 'math'; export const result = double(21);")
+    ///     .unwrap();
+    /// let result = namespace.get_field("result", &mut context).unwrap();
+    /// assert_eq!(result.as_number(), Some(42.0));
+    /// ```
+    #[inline]
+    pub fn set_module_loader<L>(&mut self, loader: L)
+    where
+        L: ModuleLoader + 'static,
+    {
+        self.modules.set_loader(Rc::new(loader));
+    }
+
+    /// Evaluates the given code as a module, and returns its namespace object.
+    ///
+    /// The modules it imports are resolved by the loader set with
+    /// [`Context::set_module_loader`](Context::set_module_loader), and evaluated before it.
+    pub fn eval_module<T: AsRef<[u8]>>(&mut self, src: T) -> Result<Value> {
+        self.instruction_count = 0;
+        let result = self.run_module_source(src.as_ref());
+        self.finish_script(result)
+    }
+
+    /// Imports the module `specifier` with the module loader, and returns its namespace object.
+    ///
+    /// The module is only evaluated the first time it's imported.
+    pub fn import_module(&mut self, specifier: &str) -> Result<Value> {
+        self.instruction_count = 0;
+        let result = self.run_module_specifier(specifier);
+        self.finish_script(result)
+    }
+
+    /// Returns a deep copy of the value, like the global `structuredClone` function.
+    ///
+    /// Objects referenced more than once, including through cycles, are copied only once.
+    /// Values that can't be cloned, like functions and symbols, make it throw a `DataCloneError`.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// let original = context.eval("({ list: [1, 2] })").unwrap();
+    /// let copy = context.structured_clone(&original).unwrap();
+    ///
+    /// assert!(!original.strict_equals(&copy));
+    /// ```
+    #[inline]
+    pub fn structured_clone(&mut self, value: &Value) -> Result<Value> {
+        StructuredClone::clone_value(value, self)
+    }
+
+    /// Returns a deep copy, created in this context, of a value of another context.
+    ///
+    /// The value is copied like with [`Context::structured_clone`], but the copies use the
+    /// standard objects of this context, so they don't keep any reference to the other context.
+    /// The properties of the imported objects are read without running code of the other
+    /// context. Accessor properties make it throw a `DataCloneError`, like the values that can't
+    /// be cloned, such as functions.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{property::Attribute, Context};
+    /// let mut source = Context::new();
+    /// let mut target = Context::new();
+    ///
+    /// let original = source.eval("({ list: [1, 2] })").unwrap();
+    /// let copy = target.import_value(&original).unwrap();
+    /// target.register_global_property("copy", copy, Attribute::all());
+    ///
+    /// let result = target.eval("copy.list instanceof Array").unwrap();
+    /// assert_eq!(result.as_boolean(), Some(true));
+    /// ```
+    #[inline]
+    pub fn import_value(&mut self, value: &Value) -> Result<Value> {
+        StructuredClone::import_value(value, self)
+    }
+
+    /// Parses the JSON text read from `reader` into a `Value`, like `JSON.parse` without a
+    /// reviver.
+    ///
+    /// The text is parsed while it is read, so large inputs, like files or network payloads,
+    /// don't have to be loaded into a string first. The reader is read in many small reads, so
+    /// readers without a buffer should be wrapped in a [`BufReader`][std::io::BufReader].
+    ///
+    /// A `SyntaxError` is thrown if the text is not valid JSON, or if reading it fails.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    ///
+    /// let value = context
+    ///     .json_parse_reader(r#"{ "list": [1, 2, 3] }"#.as_bytes())
+    ///     .unwrap();
+    /// let list = value.get_field("list", &mut context).unwrap();
+    /// let length = list.get_field("length", &mut context).unwrap();
+    /// assert_eq!(length.as_number(), Some(3.0));
+    ///
+    /// assert!(context.json_parse_reader("[1, 2".as_bytes()).is_err());
+    /// ```
+    #[inline]
+    pub fn json_parse_reader<R>(&mut self, reader: R) -> Result<Value>
+    where
+        R: Read,
+    {
+        Json::parse_reader(reader, self)
+    }
+
+    /// Sets the maximum number of instructions that a single evaluation can execute.
+    ///
+    /// Every evaluated node of the syntax tree (or bytecode instruction, with the `vm` feature)
+    /// counts as one instruction, so the limit doesn't depend on the speed of the machine.
+    /// When the limit is exceeded the evaluation fails with a `RangeError`, which keeps being
+    /// thrown for every further instruction, so the script can't recover from it. `None`
+    /// removes the limit.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    /// context.set_instruction_limit(Some(10_000));
+    ///
+    /// assert!(context.eval("while (true) {}").is_err());
+    /// assert!(context.eval("1 + 2").is_ok());
+    /// ```
+    #[inline]
+    pub fn set_instruction_limit(&mut self, limit: Option<u64>) {
+        self.instruction_limit = limit;
+    }
+
+    /// Gets the maximum number of instructions that a single evaluation can execute.
+    #[inline]
+    pub fn instruction_limit(&self) -> Option<u64> {
+        self.instruction_limit
+    }
+
+    /// Counts an executed instruction against the instruction limit.
+    #[inline]
+    pub(crate) fn count_instruction(&mut self) -> Result<()> {
+        if let Some(limit) = self.instruction_limit {
+            if self.instruction_count >= limit {
+                // Constructing the error executes instructions as well.
+                self.instruction_limit = None;
+                let error = self.construct_range_error("instruction limit exceeded");
+                self.instruction_limit = Some(limit);
+                return Err(error);
+            }
+            self.instruction_count += 1;
+        }
+        self.object_counter.enter();
+        self.check_object_limit()
+    }
+
+    /// Sets the maximum number of objects that can be alive at the same time.
+    ///
+    /// The objects allocated while the context is running, including its builtins, count towards
+    /// the limit. Once the count crosses a threshold, the garbage collector runs, and a
+    /// `RangeError` is thrown if more objects than allowed are still alive. The threshold is then
+    /// set to half the limit above the count, so the count can exceed the limit by that much
+    /// between two collections. The error can be caught, and a script can handle it by dropping
+    /// its references to the objects. `None` removes the limit.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    /// context.set_max_objects(Some(50_000));
+    ///
+    /// let result = context.eval(
+    ///     r#"
+    ///     let list = [];
+    ///     try {
+    ///         while (true) list.push({});
+    ///     } catch (e) {
+    ///         list = null;
+    ///         e instanceof RangeError
+    ///     }
+    ///     "#,
+    /// );
+    /// assert_eq!(result.unwrap().as_boolean(), Some(true));
+    /// ```
+    #[inline]
+    pub fn set_max_objects(&mut self, max: Option<usize>) {
+        self.max_objects = max;
+        self.next_object_collection = 0;
+    }
+
+    /// Gets the maximum number of objects that can be alive at the same time.
+    #[inline]
+    pub fn max_objects(&self) -> Option<usize> {
+        self.max_objects
+    }
+
+    /// Throws a `RangeError` if more objects are alive than allowed by the object limit.
+    fn check_object_limit(&mut self) -> Result<()> {
+        let max = match self.max_objects {
+            Some(max) => max,
+            None => return Ok(()),
+        };
+
+        if self.object_counter.get() <= max.max(self.next_object_collection) {
+            return Ok(());
+        }
+
+        force_collect();
+        let live = self.object_counter.get();
+        // Half the limit has to be allocated before the next collection, so that the cost of
+        // the collections stays proportional to the number of allocations.
+        self.next_object_collection = live + max / 2;
+        if live <= max {
+            return Ok(());
+        }
+
+        // Constructing the error allocates objects as well.
+        self.max_objects = None;
+        let error = self.construct_range_error("object limit exceeded");
+        self.max_objects = Some(max);
+        Err(error)
+    }
+
+    /// The minimum number of interned strings above which the unused strings are removed.
+    const MIN_INTERNED_SWEEP_LEN: usize = 256;
+
+    /// The default maximum number of nested function calls.
+    ///
+    /// The calls that don't fit on the stack of the thread are executed on stack segments
+    /// allocated on demand, so this doesn't depend on the size of the native stack.
+    pub const DEFAULT_MAX_CALL_STACK_SIZE: usize = 10_000;
+
+    /// Sets the maximum number of nested function calls.
+    ///
+    /// Calling a function when the limit is reached throws a `RangeError`, which can be caught
+    /// by the script. The native stack is grown as needed by the nested calls, so raising the
+    /// limit only raises the memory a deep recursion may use.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    /// context.set_max_call_stack_size(50);
+    ///
+    /// let result = context.eval(
+    ///     r#"
+    ///     function recurse() { return recurse(); }
+    ///     try { recurse(); } catch (e) { e instanceof RangeError }
+    ///     "#,
+    /// );
+    /// assert_eq!(result.unwrap().as_boolean(), Some(true));
+    /// ```
+    #[inline]
+    pub fn set_max_call_stack_size(&mut self, size: usize) {
+        self.max_call_stack_size = size;
+    }
+
+    /// Gets the maximum number of nested function calls.
+    #[inline]
+    pub fn max_call_stack_size(&self) -> usize {
+        self.max_call_stack_size
+    }
+
+    /// Seeds the random number generator backing `Math.random`.
+    ///
+    /// By default the generator is seeded from the operating system. After seeding it with a
+    /// given value, `Math.random` returns the same sequence of numbers in every context, which
+    /// makes scripts using it reproducible in tests.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut first = Context::new();
+    /// first.seed_rng(42);
+    /// let mut second = Context::new();
+    /// second.seed_rng(42);
+    ///
+    /// let a = first.eval("Math.random()").unwrap();
+    /// let b = second.eval("Math.random()").unwrap();
+    /// assert_eq!(a.as_number(), b.as_number());
+    /// ```
+    #[inline]
+    pub fn seed_rng(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    /// Gets the random number generator backing `Math.random`.
+    #[inline]
+    pub(crate) fn rng(&mut self) -> &mut StdRng {
+        &mut self.rng
+    }
+
+    /// Sets the time zone used by the local time methods of `Date`, like `getHours` or `toString`.
+    ///
+    /// By default it is the time zone of the host, which makes the results of these methods
+    /// depend on the machine running the scripts. The `UTC` methods are not affected.
+    ///
+    /// The time zone can be a [`LocalTimeZone`], a `chrono::FixedOffset`, or parsed from a string
+    /// like `"UTC"` or `"+05:30"`.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{builtins::date::LocalTimeZone, Context};
+    /// let mut context = Context::new();
+    /// context.set_time_zone("+05:30".parse::<LocalTimeZone>().unwrap());
+    ///
+    /// let offset = context.eval("new Date(0).getTimezoneOffset()").unwrap();
+    /// assert_eq!(offset.as_number(), Some(-330.0));
+    /// let hours = context.eval("new Date(0).getHours()").unwrap();
+    /// assert_eq!(hours.as_number(), Some(5.0));
+    /// ```
+    #[inline]
+    pub fn set_time_zone<Z>(&mut self, time_zone: Z)
+    where
+        Z: Into<LocalTimeZone>,
+    {
+        self.time_zone = time_zone.into();
+    }
+
+    /// Gets the time zone used by the local time methods of `Date`.
+    #[inline]
+    pub fn time_zone(&self) -> LocalTimeZone {
+        self.time_zone
+    }
+
+    /// Throws a `RangeError` if a call would exceed the maximum number of nested function calls.
+    #[inline]
+    pub(crate) fn check_call_stack_size(&mut self) -> Result<()> {
+        if self.executor.call_depth() >= self.max_call_stack_size {
+            return Err(self.construct_range_error("Maximum call stack size exceeded"));
+        }
+        Ok(())
+    }
+
+    /// Encodes the value as a URI, the same way the JavaScript `encodeURI()` function does.
+    ///
+    /// The value is converted with `ToString` first, a `URIError` is thrown if the
+    /// resulting string contains an unpaired surrogate.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{Context, Value};
+    /// let mut context = Context::new();
+    ///
+    /// let encoded = context.encode_uri(&Value::string("a b")).unwrap();
+    ///
+    /// assert_eq!(encoded, "a%20b");
+    /// ```
+    #[inline]
+    pub fn encode_uri(&mut self, value: &Value) -> Result<RcString> {
+        Uri::encode_uri_value(value, self)
+    }
+
+    /// Decodes the value as a URI, the same way the JavaScript `decodeURI()` function does.
+    ///
+    /// The value is converted with `ToString` first, a `URIError` is thrown if it contains
+    /// a malformed escape sequence.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{Context, Value};
+    /// let mut context = Context::new();
+    ///
+    /// let decoded = context.decode_uri(&Value::string("a%20b%3F")).unwrap();
+    ///
+    /// assert_eq!(decoded, "a b%3F");
+    /// ```
+    #[inline]
+    pub fn decode_uri(&mut self, value: &Value) -> Result<RcString> {
+        Uri::decode_uri_value(value, self)
     }
 
     /// Return the cached iterator prototypes.
@@ -716,3 +2285,181 @@ impl Context {
         self.trace = trace;
     }
 }
+
+/// A global binding registered by the embedder with a [`ContextBuilder`].
+enum HostGlobal {
+    Property(PropertyKey, Value, Attribute),
+    Function(Box<str>, usize, NativeFunction),
+}
+
+impl fmt::Debug for HostGlobal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Property(key, value, attribute) => f
+                .debug_tuple("Property")
+                .field(key)
+                .field(value)
+                .field(attribute)
+                .finish(),
+            Self::Function(name, length, _) => {
+                f.debug_tuple("Function").field(name).field(length).finish()
+            }
+        }
+    }
+}
+
+/// A builder for a [`Context`], letting embedders choose the global bindings that are available
+/// to scripts.
+///
+/// The standard builtins to bind to the global object can be restricted, and host globals can be
+/// registered before any script runs. By default, every standard builtin is installed, like with
+/// [`Context::new`].
+///
+/// The standard objects are always created, since the engine relies on them internally (for
+/// example to throw a `TypeError`), so this doesn't prevent scripts from reaching an object
+/// through another one. For example `(function () {}).constructor` is still `Function`.
+///
+/// # Example
+/// ```
+/// use boa::{Context, property::Attribute};
+///
+/// let mut context = Context::builder()
+///     .only_builtins(&["Math", "JSON"])
+///     .register_global_property("answer", 42, Attribute::all())
+///     .build();
+///
+/// assert_eq!(context.eval("Math.max(answer, 1)").unwrap().as_number(), Some(42.0));
+/// assert_eq!(context.eval("typeof Array").unwrap().as_string().unwrap().as_str(), "undefined");
+/// ```
+#[derive(Debug, Default)]
+pub struct ContextBuilder {
+    /// The names of the only builtins to install, if not all of them.
+    only: Option<FxHashSet<Box<str>>>,
+
+    /// The names of the builtins that are not installed.
+    excluded: FxHashSet<Box<str>>,
+
+    host_globals: Vec<HostGlobal>,
+
+    /// Whether the scripts are strict mode code by default.
+    strict: bool,
+}
+
+impl ContextBuilder {
+    /// Installs only the standard builtins with the given global names, like `"Math"` or
+    /// `"parseInt"`.
+    #[inline]
+    pub fn only_builtins<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.only = Some(names.into_iter().map(|name| name.as_ref().into()).collect());
+        self
+    }
+
+    /// Doesn't install the standard builtins with the given global names.
+    #[inline]
+    pub fn exclude_builtins<I, S>(mut self, names: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.excluded
+            .extend(names.into_iter().map(|name| name.as_ref().into()));
+        self
+    }
+
+    /// Registers a global property, like [`Context::register_global_property`].
+    #[inline]
+    pub fn register_global_property<K, V>(mut self, key: K, value: V, attribute: Attribute) -> Self
+    where
+        K: Into<PropertyKey>,
+        V: Into<Value>,
+    {
+        self.host_globals
+            .push(HostGlobal::Property(key.into(), value.into(), attribute));
+        self
+    }
+
+    /// Registers a global function, like [`Context::register_global_function`].
+    #[inline]
+    pub fn register_global_function(
+        mut self,
+        name: &str,
+        length: usize,
+        body: NativeFunction,
+    ) -> Self {
+        self.host_globals
+            .push(HostGlobal::Function(name.into(), length, body));
+        self
+    }
+
+    /// Parses the scripts evaluated by the context as strict mode code, as if they started with a
+    /// `"use strict"` directive.
+    ///
+    /// Scripts are sloppy mode code by default.
+    ///
+    /// # Example
+    /// ```
+    /// use boa::Context;
+    ///
+    /// let mut context = Context::builder().strict(true).build();
+    ///
+    /// assert!(context.eval("undeclared = 1").is_err());
+    /// assert_eq!(
+    ///     context.eval("(function () { return this; })()").unwrap(),
+    ///     boa::Value::undefined()
+    /// );
+    /// ```
+    #[inline]
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Checks if the builtin bound to the global `name` must be installed.
+    fn is_installed(&self, name: &str) -> bool {
+        self.only.as_ref().map_or(true, |only| only.contains(name)) && !self.excluded.contains(name)
+    }
+
+    /// Builds the `Context`.
+    pub fn build(self) -> Context {
+        self.finish(Context::default())
+    }
+
+    /// Builds the `Context` from a copy of the given [`Intrinsics`], like
+    /// [`Context::from_intrinsics`].
+    pub fn build_from(self, intrinsics: &Intrinsics) -> Context {
+        self.finish(Context::from_intrinsics(intrinsics))
+    }
+
+    /// Applies the settings of the builder to a new `Context`.
+    fn finish(self, mut context: Context) -> Context {
+        context.strict = self.strict;
+
+        let mut global_object = context.global_object();
+        let removed: Vec<PropertyKey> = global_object
+            .borrow()
+            .string_property_keys()
+            .filter(|name| !self.is_installed(name))
+            .map(|name| name.clone().into())
+            .collect();
+        for name in &removed {
+            global_object.remove(name);
+        }
+
+        for global in self.host_globals {
+            match global {
+                HostGlobal::Property(key, value, attribute) => {
+                    context.register_global_property(key, value, attribute)
+                }
+                HostGlobal::Function(name, length, body) => context
+                    .register_global_function(&name, length, body)
+                    .expect("registering a global function cannot fail"),
+            }
+        }
+
+        context
+    }
+}
@@ -96,7 +96,7 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
             name.into_boxed_str(),
             DeclarativeEnvironmentRecordBinding {
                 value: None,
-                can_delete: true,
+                can_delete: false,
                 mutable: false,
                 strict,
             },
@@ -171,18 +171,19 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
         }
     }
 
-    fn delete_binding(&self, name: &str) -> bool {
-        match self.env_rec.borrow().get(name) {
-            Some(binding) => {
-                if binding.can_delete {
-                    self.env_rec.borrow_mut().remove(name);
-                    true
-                } else {
-                    false
-                }
-            }
+    fn get_initialized_binding_value(&self, name: &str) -> Option<Value> {
+        self.env_rec.borrow().get(name)?.value.clone()
+    }
+
+    fn delete_binding(&self, name: &str, _context: &mut Context) -> Result<bool> {
+        let can_delete = match self.env_rec.borrow().get(name) {
+            Some(binding) => binding.can_delete,
             None => panic!("env_rec has no binding for {}", name),
+        };
+        if can_delete {
+            self.env_rec.borrow_mut().remove(name);
         }
+        Ok(can_delete)
     }
 
     fn has_this_binding(&self) -> bool {
@@ -11,7 +11,11 @@
 
 use crate::{environment::lexical_environment::VariableScope, object::GcObject};
 use crate::{
-    environment::lexical_environment::{Environment, EnvironmentType},
+    environment::{
+        global_environment_record::GlobalEnvironmentRecord,
+        lexical_environment::{Environment, EnvironmentType},
+        module_environment_record::ImportMeta,
+    },
     gc::{Finalize, Trace},
     Context, Result, Value,
 };
@@ -75,11 +79,20 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// otherwise require strict mode reference semantics.
     fn get_binding_value(&self, name: &str, strict: bool, context: &mut Context) -> Result<Value>;
 
+    /// Returns the value of a binding of this Environment Record, without throwing or running any
+    /// code. Returns `None` if the binding doesn't exist or is not initialized, or if the record
+    /// can't get it without running code.
+    ///
+    /// This is used by mapped arguments objects, which read the parameters of a function.
+    fn get_initialized_binding_value(&self, _name: &str) -> Option<Value> {
+        None
+    }
+
     /// Delete a binding from an Environment Record.
     /// The String value name is the text of the bound name.
     /// If a binding for name exists, remove the binding and return true.
     /// If the binding exists but cannot be removed return false. If the binding does not exist return true.
-    fn delete_binding(&self, name: &str) -> bool;
+    fn delete_binding(&self, name: &str, context: &mut Context) -> Result<bool>;
 
     /// Determine if an Environment Record establishes a this binding.
     /// Return true if it does and false if it does not.
@@ -88,6 +101,24 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// Return the `this` binding from the environment
     fn get_this_binding(&self, context: &mut Context) -> Result<Value>;
 
+    /// Returns the `[[NewTarget]]` of the function invocation that created this Environment
+    /// Record. Only function Environment Records have one, other records return undefined.
+    fn get_new_target(&self) -> Value {
+        Value::undefined()
+    }
+
+    /// Returns the `[[HomeObject]]` of the method whose invocation created this Environment
+    /// Record. Only function Environment Records have one, other records return undefined.
+    fn get_home_object(&self) -> Value {
+        Value::undefined()
+    }
+
+    /// Returns the `import.meta` of the module that created this Environment Record. Only module
+    /// Environment Records have one.
+    fn get_import_meta(&self) -> Option<ImportMeta> {
+        None
+    }
+
     /// Determine if an Environment Record establishes a super method binding.
     /// Return true if it does and false if it does not.
     fn has_super_binding(&self) -> bool;
@@ -108,6 +139,11 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
     /// Get the type of environment this is
     fn get_environment_type(&self) -> EnvironmentType;
 
+    /// Get the global Environment Record, if this is the global environment.
+    fn as_global_environment(&self) -> Option<&GlobalEnvironmentRecord> {
+        None
+    }
+
     /// Return the `this` binding from the environment or try to get it from outer environments
     fn recursive_get_this_binding(&self, context: &mut Context) -> Result<Value> {
         if self.has_this_binding() {
@@ -120,6 +156,43 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
         }
     }
 
+    /// Returns the `new.target` value of the nearest environment that has a `this` binding.
+    ///
+    /// <https://tc39.es/ecma262/#sec-getnewtarget>
+    fn recursive_get_new_target(&self) -> Value {
+        if self.has_this_binding() {
+            self.get_new_target()
+        } else {
+            match self.get_outer_environment_ref() {
+                Some(outer) => outer.recursive_get_new_target(),
+                None => Value::undefined(),
+            }
+        }
+    }
+
+    /// Returns the home object of the nearest environment that has a `this` binding, which is
+    /// undefined outside of methods.
+    ///
+    /// <https://tc39.es/ecma262/#sec-getsuperbase>
+    fn recursive_get_home_object(&self) -> Value {
+        if self.has_this_binding() {
+            self.get_home_object()
+        } else {
+            match self.get_outer_environment_ref() {
+                Some(outer) => outer.recursive_get_home_object(),
+                None => Value::undefined(),
+            }
+        }
+    }
+
+    /// Returns the `import.meta` of the nearest module Environment Record.
+    fn recursive_get_import_meta(&self) -> Option<ImportMeta> {
+        self.get_import_meta().or_else(|| {
+            self.get_outer_environment_ref()
+                .and_then(|outer| outer.recursive_get_import_meta())
+        })
+    }
+
     /// Create mutable binding while handling outer environments
     fn recursive_create_mutable_binding(
         &self,
@@ -196,6 +269,31 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
             }
     }
 
+    /// Delete a binding from the nearest environment that has it
+    ///
+    /// Returns true if no environment has the binding, as the reference is unresolvable.
+    fn recursive_delete_binding(&self, name: &str, context: &mut Context) -> Result<bool> {
+        if self.has_binding(name) {
+            self.delete_binding(name, context)
+        } else {
+            match self.get_outer_environment_ref() {
+                Some(outer) => outer.recursive_delete_binding(name, context),
+                None => Ok(true),
+            }
+        }
+    }
+
+    /// Returns the `with` object of the nearest environment that has a binding for `name`, if
+    /// that environment is associated with a `with` statement.
+    fn recursive_with_base_object(&self, name: &str) -> Option<GcObject> {
+        if self.has_binding(name) {
+            self.with_base_object()
+        } else {
+            self.get_outer_environment_ref()
+                .and_then(|outer| outer.recursive_with_base_object(name))
+        }
+    }
+
     /// Retrieve binding from current or any outer environment
     fn recursive_get_binding_value(&self, name: &str, context: &mut Context) -> Result<Value> {
         if self.has_binding(name) {
@@ -159,8 +159,12 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
             .get_binding_value(name, strict, context)
     }
 
-    fn delete_binding(&self, name: &str) -> bool {
-        self.declarative_record.delete_binding(name)
+    fn get_initialized_binding_value(&self, name: &str) -> Option<Value> {
+        self.declarative_record.get_initialized_binding_value(name)
+    }
+
+    fn delete_binding(&self, name: &str, context: &mut Context) -> Result<bool> {
+        self.declarative_record.delete_binding(name, context)
     }
 
     fn has_this_binding(&self) -> bool {
@@ -179,6 +183,14 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
         }
     }
 
+    fn get_new_target(&self) -> Value {
+        self.new_target.clone()
+    }
+
+    fn get_home_object(&self) -> Value {
+        self.home_object.clone()
+    }
+
     fn has_super_binding(&self) -> bool {
         if let BindingStatus::Lexical = self.this_binding_status {
             false
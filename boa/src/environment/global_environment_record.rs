@@ -8,6 +8,7 @@
 //! More info:  <https://tc39.es/ecma262/#sec-global-environment-records>
 
 use crate::{
+    builtins,
     environment::{
         declarative_environment_record::DeclarativeEnvironmentRecord,
         environment_record_trait::EnvironmentRecordTrait,
@@ -100,12 +101,12 @@ impl GlobalEnvironmentRecord {
     }
 
     pub fn create_global_var_binding(
-        &mut self,
+        &self,
         name: String,
         deletion: bool,
         context: &mut Context,
     ) -> Result<()> {
-        let obj_rec = &mut self.object_record;
+        let obj_rec = &self.object_record;
         let global_object = &obj_rec.bindings;
         let has_property = global_object.has_field(name.as_str());
         let extensible = global_object.is_extensible();
@@ -220,33 +221,42 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
                 .declarative_record
                 .get_binding_value(name, strict, context);
         }
-        self.object_record.get_binding_value(name, strict, context)
+        let value = self
+            .object_record
+            .get_binding_value(name, strict, context)?;
+        let is_global_this = value.as_object().map_or(false, |object| {
+            GcObject::equals(&object, &self.global_this_binding)
+        });
+        if is_global_this {
+            // The script can inspect the global properties of the lazy builtins from now on.
+            builtins::init_all_lazy(context);
+            return Ok(value);
+        }
+        Ok(builtins::resolve_lazy(context, name, value))
     }
 
-    fn delete_binding(&self, name: &str) -> bool {
+    fn delete_binding(&self, name: &str, context: &mut Context) -> Result<bool> {
         if self.declarative_record.has_binding(&name) {
-            return self.declarative_record.delete_binding(name);
+            return self.declarative_record.delete_binding(name, context);
         }
 
         let global: &Value = &self.object_record.bindings;
         if global.has_field(name) {
-            let status = self.object_record.delete_binding(name);
+            let status = self.object_record.delete_binding(name, context)?;
             if status {
-                let mut var_names = self.var_names.borrow_mut();
-                if var_names.contains(name) {
-                    var_names.remove(name);
-                    return status;
-                }
+                self.var_names.borrow_mut().remove(name);
             }
+            return Ok(status);
         }
-        true
+        Ok(true)
     }
 
     fn has_this_binding(&self) -> bool {
         true
     }
 
-    fn get_this_binding(&self, _context: &mut Context) -> Result<Value> {
+    fn get_this_binding(&self, context: &mut Context) -> Result<Value> {
+        builtins::init_all_lazy(context);
         Ok(self.global_this_binding.clone().into())
     }
 
@@ -275,14 +285,23 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
         EnvironmentType::Global
     }
 
+    fn as_global_environment(&self) -> Option<&GlobalEnvironmentRecord> {
+        Some(self)
+    }
+
     fn recursive_create_mutable_binding(
         &self,
         name: String,
         deletion: bool,
-        _scope: VariableScope,
+        scope: VariableScope,
         context: &mut Context,
     ) -> Result<()> {
-        self.create_mutable_binding(name, deletion, false, context)
+        // `var` and function declarations of scripts, as well as the implicit globals of sloppy
+        // mode assignments, are properties of the global object.
+        match scope {
+            VariableScope::Function => self.create_global_var_binding(name, deletion, context),
+            VariableScope::Block => self.create_mutable_binding(name, deletion, false, context),
+        }
     }
 
     fn recursive_create_immutable_binding(
@@ -23,6 +23,7 @@ pub enum EnvironmentType {
     Declarative,
     Function,
     Global,
+    Module,
     Object,
 }
 
@@ -93,6 +94,14 @@ impl Context {
             .recursive_get_this_binding(self)
     }
 
+    pub(crate) fn get_new_target(&mut self) -> Value {
+        self.get_current_environment().recursive_get_new_target()
+    }
+
+    pub(crate) fn get_home_object(&mut self) -> Value {
+        self.get_current_environment().recursive_get_home_object()
+    }
+
     pub(crate) fn create_mutable_binding(
         &mut self,
         name: String,
@@ -139,10 +148,32 @@ impl Context {
             .clone()
     }
 
+    /// Gets the environment at the bottom of the stack, that holds the global bindings.
+    pub(crate) fn get_global_environment(&self) -> Environment {
+        self.realm
+            .environment
+            .environment_stack
+            .front()
+            .expect("Could not get the global environment")
+            .clone()
+    }
+
+    pub(crate) fn delete_binding(&mut self, name: &str) -> Result<bool> {
+        self.get_current_environment()
+            .recursive_delete_binding(name, self)
+    }
+
     pub(crate) fn has_binding(&mut self, name: &str) -> bool {
         self.get_current_environment().recursive_has_binding(name)
     }
 
+    /// Gets the `with` object that is the `this` value of a call to the function bound to `name`,
+    /// if the binding is resolved in the environment of a `with` statement.
+    pub(crate) fn get_with_base_object(&mut self, name: &str) -> Option<GcObject> {
+        self.get_current_environment()
+            .recursive_with_base_object(name)
+    }
+
     pub(crate) fn get_binding_value(&mut self, name: &str) -> Result<Value> {
         self.get_current_environment()
             .recursive_get_binding_value(name, self)
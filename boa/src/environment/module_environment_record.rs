@@ -0,0 +1,240 @@
+//! # Module Environment Records
+//!
+//! A module Environment Record is a declarative Environment Record that is used to represent
+//! the outer scope of an ECMAScript Module. In addition to normal mutable and immutable bindings,
+//! module Environment Records also provide immutable import bindings which are bindings that
+//! provide indirect access to a target binding that exists in another Environment Record.
+//! More info: <https://tc39.es/ecma262/#sec-module-environment-records>
+
+use crate::{
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        environment_record_trait::EnvironmentRecordTrait,
+        lexical_environment::{Environment, EnvironmentType, VariableScope},
+    },
+    gc::{Finalize, Trace},
+    object::GcObject,
+    Context, Result, Value,
+};
+use gc::{Gc, GcCell};
+use rustc_hash::FxHashMap;
+
+/// An indirect binding to the binding `name` of the module environment `environment`.
+///
+/// Reading an import binding always reads the current value of the target binding, which is what
+/// makes the bindings of imported modules "live".
+#[derive(Trace, Finalize, Debug, Clone)]
+pub struct ImportBinding {
+    pub environment: Environment,
+    pub name: Box<str>,
+}
+
+/// The import bindings of a module environment.
+///
+/// They are shared with the module record, since they are only created when the module is linked,
+/// after the environment has been created.
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct ImportBindings(Gc<GcCell<FxHashMap<Box<str>, ImportBinding>>>);
+
+impl ImportBindings {
+    /// Creates a new initialized immutable indirect binding for the name `name`, that reads the
+    /// binding `binding_name` of the module environment `environment`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createimportbinding
+    pub fn create_import_binding(
+        &self,
+        name: String,
+        environment: Environment,
+        binding_name: &str,
+    ) {
+        assert!(
+            !self.contains(&name),
+            "Identifier {} has already been declared",
+            name
+        );
+
+        self.0.borrow_mut().insert(
+            name.into_boxed_str(),
+            ImportBinding {
+                environment,
+                name: binding_name.into(),
+            },
+        );
+    }
+
+    fn contains(&self, name: &str) -> bool {
+        self.0.borrow().contains_key(name)
+    }
+
+    fn get(&self, name: &str) -> Option<ImportBinding> {
+        self.0.borrow().get(name).cloned()
+    }
+}
+
+/// The `import.meta` object of a module, which is created the first time the module evaluates
+/// `import.meta`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-meta-properties-runtime-semantics-evaluation
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct ImportMeta {
+    /// The name of the module, or `None` for an anonymous module.
+    pub module: Option<Box<str>>,
+    pub object: Gc<GcCell<Option<GcObject>>>,
+}
+
+/// <https://tc39.es/ecma262/#sec-module-environment-records>
+#[derive(Debug, Trace, Finalize, Clone)]
+pub struct ModuleEnvironmentRecord {
+    pub declarative_record: DeclarativeEnvironmentRecord,
+    pub import_bindings: ImportBindings,
+    pub import_meta: ImportMeta,
+}
+
+impl ModuleEnvironmentRecord {
+    pub fn new(outer: Option<Environment>, module: Option<Box<str>>) -> ModuleEnvironmentRecord {
+        ModuleEnvironmentRecord {
+            declarative_record: DeclarativeEnvironmentRecord::new(outer),
+            import_bindings: ImportBindings(Gc::new(GcCell::new(FxHashMap::default()))),
+            import_meta: ImportMeta {
+                module,
+                object: Gc::new(GcCell::new(None)),
+            },
+        }
+    }
+}
+
+impl EnvironmentRecordTrait for ModuleEnvironmentRecord {
+    fn has_binding(&self, name: &str) -> bool {
+        self.import_bindings.contains(name) || self.declarative_record.has_binding(name)
+    }
+
+    fn create_mutable_binding(
+        &self,
+        name: String,
+        deletion: bool,
+        allow_name_reuse: bool,
+        context: &mut Context,
+    ) -> Result<()> {
+        self.declarative_record
+            .create_mutable_binding(name, deletion, allow_name_reuse, context)
+    }
+
+    fn create_immutable_binding(
+        &self,
+        name: String,
+        strict: bool,
+        context: &mut Context,
+    ) -> Result<()> {
+        self.declarative_record
+            .create_immutable_binding(name, strict, context)
+    }
+
+    fn initialize_binding(&self, name: &str, value: Value, context: &mut Context) -> Result<()> {
+        self.declarative_record
+            .initialize_binding(name, value, context)
+    }
+
+    fn set_mutable_binding(
+        &self,
+        name: &str,
+        value: Value,
+        strict: bool,
+        context: &mut Context,
+    ) -> Result<()> {
+        if self.import_bindings.contains(name) {
+            return Err(
+                context.construct_type_error(format!("Cannot assign to import binding {}", name))
+            );
+        }
+        self.declarative_record
+            .set_mutable_binding(name, value, strict, context)
+    }
+
+    fn get_binding_value(&self, name: &str, strict: bool, context: &mut Context) -> Result<Value> {
+        let import = self.import_bindings.get(name);
+        match import {
+            // The target binding doesn't exist yet while the module that declares it hasn't been
+            // evaluated, which only happens with circular imports.
+            Some(import) if !import.environment.has_binding(&import.name) => {
+                context.throw_reference_error(format!("{} is an uninitialized binding", name))
+            }
+            Some(import) => import
+                .environment
+                .get_binding_value(&import.name, true, context),
+            None => self
+                .declarative_record
+                .get_binding_value(name, strict, context),
+        }
+    }
+
+    fn delete_binding(&self, name: &str, context: &mut Context) -> Result<bool> {
+        if self.import_bindings.contains(name) {
+            return Ok(false);
+        }
+        self.declarative_record.delete_binding(name, context)
+    }
+
+    fn has_this_binding(&self) -> bool {
+        true
+    }
+
+    fn get_this_binding(&self, _context: &mut Context) -> Result<Value> {
+        Ok(Value::undefined())
+    }
+
+    fn get_import_meta(&self) -> Option<ImportMeta> {
+        Some(self.import_meta.clone())
+    }
+
+    fn has_super_binding(&self) -> bool {
+        false
+    }
+
+    fn with_base_object(&self) -> Option<GcObject> {
+        None
+    }
+
+    fn get_outer_environment_ref(&self) -> Option<&Environment> {
+        self.declarative_record.get_outer_environment_ref()
+    }
+
+    fn set_outer_environment(&mut self, env: Environment) {
+        self.declarative_record.set_outer_environment(env)
+    }
+
+    fn get_environment_type(&self) -> EnvironmentType {
+        EnvironmentType::Module
+    }
+
+    fn recursive_create_mutable_binding(
+        &self,
+        name: String,
+        deletion: bool,
+        _scope: VariableScope,
+        context: &mut Context,
+    ) -> Result<()> {
+        self.create_mutable_binding(name, deletion, false, context)
+    }
+
+    fn recursive_create_immutable_binding(
+        &self,
+        name: String,
+        deletion: bool,
+        _scope: VariableScope,
+        context: &mut Context,
+    ) -> Result<()> {
+        self.create_immutable_binding(name, deletion, context)
+    }
+}
+
+impl From<ModuleEnvironmentRecord> for Environment {
+    fn from(env: ModuleEnvironmentRecord) -> Environment {
+        Gc::new(Box::new(env))
+    }
+}
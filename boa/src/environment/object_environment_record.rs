@@ -15,8 +15,8 @@ use crate::{
     },
     gc::{Finalize, Trace},
     object::GcObject,
-    property::PropertyDescriptor,
     property::{Attribute, DataDescriptor},
+    symbol::WellKnownSymbols,
     Context, Result, Value,
 };
 
@@ -45,14 +45,37 @@ impl ObjectEnvironmentRecord {
 
 impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
     fn has_binding(&self, name: &str) -> bool {
-        if self.bindings.has_field(name) {
-            if self.with_environment {
-                // TODO: implement unscopables
-            }
-            true
-        } else {
-            false
+        // 1. Let bindingObject be envRec.[[BindingObject]].
+        // 2. Let foundBinding be ? HasProperty(bindingObject, N).
+        // 3. If foundBinding is false, return false.
+        if !self.bindings.has_field(name) {
+            return false;
+        }
+
+        // 4. If envRec.[[IsWithEnvironment]] is false, return true.
+        if !self.with_environment {
+            return true;
         }
+
+        // 5. Let unscopables be ? Get(bindingObject, @@unscopables).
+        // 6. If Type(unscopables) is Object, then
+        //     a. Let blocked be ! ToBoolean(? Get(unscopables, N)).
+        //     b. If blocked is true, return false.
+        // 7. Return true.
+        //
+        // The bindings are resolved without a context, so only data properties are looked up.
+        let unscopables = self
+            .bindings
+            .get_property(WellKnownSymbols::unscopables())
+            .and_then(|property| property.as_data_descriptor().map(DataDescriptor::value));
+        let blocked = match unscopables {
+            Some(unscopables @ Value::Object(_)) => unscopables
+                .get_property(name)
+                .and_then(|property| property.as_data_descriptor().map(DataDescriptor::value))
+                .map_or(false, |blocked| blocked.to_boolean()),
+            _ => false,
+        };
+        !blocked
     }
 
     fn create_mutable_binding(
@@ -97,24 +120,19 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         name: &str,
         value: Value,
         strict: bool,
-        _context: &mut Context,
+        context: &mut Context,
     ) -> Result<()> {
-        debug_assert!(value.is_object() || value.is_function());
-        let mut property = DataDescriptor::new(value, Attribute::ENUMERABLE);
-        property.set_configurable(strict);
-        self.bindings
-            .as_object()
-            .expect("binding object")
-            .insert(name, property);
+        // The callers don't know the strictness of the code, so the assignment only throws if the
+        // code being run is strict mode code.
+        // This keeps the attributes of the property, and calls its setter if it's an accessor.
+        let strict = strict && context.executor().strict();
+        self.bindings.set_field(name, value, strict, context)?;
         Ok(())
     }
 
     fn get_binding_value(&self, name: &str, strict: bool, context: &mut Context) -> Result<Value> {
         if self.bindings.has_field(name) {
-            match self.bindings.get_property(name) {
-                Some(PropertyDescriptor::Data(ref d)) => Ok(d.value()),
-                _ => Ok(Value::undefined()),
-            }
+            self.bindings.get_field(name, context)
         } else if strict {
             context.throw_reference_error(format!("{} has no binding", name))
         } else {
@@ -122,9 +140,13 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         }
     }
 
-    fn delete_binding(&self, name: &str) -> bool {
-        self.bindings.remove_property(name);
-        true
+    fn delete_binding(&self, name: &str, context: &mut Context) -> Result<bool> {
+        // 1. Let bindingObject be envRec.[[BindingObject]].
+        // 2. Return ? bindingObject.[[Delete]](N).
+        match self.bindings.as_object() {
+            Some(mut binding_object) => binding_object.delete(&name.into(), context),
+            None => Ok(true),
+        }
     }
 
     fn has_this_binding(&self) -> bool {
@@ -158,7 +180,7 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
     }
 
     fn get_environment_type(&self) -> EnvironmentType {
-        EnvironmentType::Function
+        EnvironmentType::Object
     }
 }
 
@@ -3,14 +3,20 @@
 #[cfg(test)]
 mod tests;
 
-use crate::{Context, Result, Value};
+use crate::{
+    builtins::generator::{GeneratorFrame, ResumeState},
+    gc::{empty_trace, Finalize, Trace},
+    syntax::ast::Position,
+    Context, Result, Value,
+};
+use std::fmt;
 
 pub trait Executable {
     /// Runs this executable in the given context.
     fn run(&self, context: &mut Context) -> Result<Value>;
 }
 
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, PartialEq, Finalize)]
 pub(crate) enum InterpreterState {
     Executing,
     Return,
@@ -20,11 +26,59 @@ pub(crate) enum InterpreterState {
     Continue(Option<Box<str>>),
 }
 
+unsafe impl Trace for InterpreterState {
+    empty_trace!();
+}
+
+/// A frame of the call stack.
+#[derive(Debug)]
+pub(crate) struct StackFrame {
+    /// The name of the function being executed.
+    function_name: Box<str>,
+    /// The position of the statement being executed, if it is known.
+    position: Option<Position>,
+    /// The state of the generator, if the body of a generator is being executed.
+    generator: Option<GeneratorFrame>,
+}
+
+impl StackFrame {
+    /// Creates a new `StackFrame` for the function with the given name.
+    fn new<N>(function_name: N) -> Self
+    where
+        N: Into<Box<str>>,
+    {
+        Self {
+            function_name: function_name.into(),
+            position: None,
+            generator: None,
+        }
+    }
+
+    /// Gets the position of the statement being executed, if it is known.
+    pub(crate) fn position(&self) -> Option<Position> {
+        self.position
+    }
+}
+
+impl fmt::Display for StackFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "at {}", self.function_name)?;
+        if let Some(position) = self.position {
+            write!(f, " ({})", position)?;
+        }
+        Ok(())
+    }
+}
+
 /// A Javascript intepreter
 #[derive(Debug)]
 pub struct Interpreter {
     /// the current state of the interpreter.
     state: InterpreterState,
+    /// The call stack, with the global code at the bottom.
+    call_stack: Vec<StackFrame>,
+    /// Whether the code being run is strict mode code.
+    strict: bool,
 }
 
 impl Default for Interpreter {
@@ -38,9 +92,23 @@ impl Interpreter {
     pub fn new() -> Self {
         Self {
             state: InterpreterState::Executing,
+            call_stack: vec![StackFrame::new("<global>")],
+            strict: false,
         }
     }
 
+    /// Whether the code being run is strict mode code.
+    #[inline]
+    pub(crate) fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether the code being run is strict mode code, returning the previous value.
+    #[inline]
+    pub(crate) fn set_strict(&mut self, strict: bool) -> bool {
+        std::mem::replace(&mut self.strict, strict)
+    }
+
     #[inline]
     pub(crate) fn set_current_state(&mut self, new_state: InterpreterState) {
         self.state = new_state
@@ -50,4 +118,114 @@ impl Interpreter {
     pub(crate) fn get_current_state(&self) -> &InterpreterState {
         &self.state
     }
+
+    /// Pushes a new frame on the call stack, for a call to the function with the given name.
+    #[inline]
+    pub(crate) fn push_frame<N>(&mut self, function_name: N)
+    where
+        N: Into<Box<str>>,
+    {
+        self.call_stack.push(StackFrame::new(function_name));
+    }
+
+    /// Gets the number of function calls on the call stack, not counting the global frame.
+    #[inline]
+    pub(crate) fn call_depth(&self) -> usize {
+        self.call_stack.len() - 1
+    }
+
+    /// Pops the frame of the innermost function call from the call stack.
+    #[inline]
+    pub(crate) fn pop_frame(&mut self) {
+        debug_assert!(self.call_stack.len() > 1, "cannot pop the global frame");
+        self.call_stack.pop();
+    }
+
+    /// Pushes a new frame on the call stack, for the execution of the body of a generator.
+    #[inline]
+    pub(crate) fn push_generator_frame<N>(&mut self, function_name: N, generator: GeneratorFrame)
+    where
+        N: Into<Box<str>>,
+    {
+        let mut frame = StackFrame::new(function_name);
+        frame.generator = Some(generator);
+        self.call_stack.push(frame);
+    }
+
+    /// Pops the frame of the innermost generator from the call stack, returning its state.
+    #[inline]
+    pub(crate) fn pop_generator_frame(&mut self) -> GeneratorFrame {
+        debug_assert!(self.call_stack.len() > 1, "cannot pop the global frame");
+        self.call_stack
+            .pop()
+            .and_then(|frame| frame.generator)
+            .expect("the innermost frame is not a generator")
+    }
+
+    /// Gets the state of the generator whose body is being executed, if the innermost frame is
+    /// the body of a generator.
+    #[inline]
+    pub(crate) fn generator_frame(&mut self) -> Option<&mut GeneratorFrame> {
+        self.call_stack.last_mut()?.generator.as_mut()
+    }
+
+    /// Takes the state the given node saved when the generator being executed was suspended.
+    #[inline]
+    pub(crate) fn take_resume_state<T>(&mut self, node: &T) -> Option<ResumeState> {
+        self.generator_frame()?.take_resume_state(node)
+    }
+
+    /// Saves the state of the given node, if the generator being executed is suspended.
+    #[inline]
+    pub(crate) fn save_resume_state<T>(&mut self, node: &T, state: ResumeState) {
+        if let Some(generator) = self.generator_frame() {
+            generator.save_resume_state(node, state);
+        }
+    }
+
+    /// Returns `true` if the body of the generator being executed is unwound, in which case
+    /// errors must not be caught.
+    #[inline]
+    pub(crate) fn is_unwinding_generator(&mut self) -> bool {
+        self.generator_frame()
+            .map_or(false, |generator| generator.is_unwinding())
+    }
+
+    /// Returns `true` if the body of the generator being executed is unwound because the
+    /// generator is suspended, in which case the nodes must save the state they need to resume.
+    #[inline]
+    pub(crate) fn is_suspending_generator(&mut self) -> bool {
+        self.generator_frame()
+            .map_or(false, |generator| generator.is_suspending())
+    }
+
+    /// Takes the value of the early return of the generator being executed, if it returns early.
+    ///
+    /// The body can then be executed normally, until the return is resumed.
+    #[inline]
+    pub(crate) fn take_generator_return(&mut self) -> Option<Value> {
+        self.generator_frame()?.take_return()
+    }
+
+    /// Resumes the early return of the generator being executed.
+    #[inline]
+    pub(crate) fn resume_generator_return(&mut self, value: Value) {
+        if let Some(generator) = self.generator_frame() {
+            generator.start_return(value);
+        }
+    }
+
+    /// Sets the position of the statement being executed in the innermost frame.
+    #[inline]
+    pub(crate) fn set_current_position(&mut self, position: Position) {
+        if let Some(frame) = self.call_stack.last_mut() {
+            frame.position = Some(position);
+        }
+    }
+
+    /// Gets the frames of the call stack, from the innermost to the outermost one.
+    #[inline]
+    pub(crate) fn stack_frames(&self) -> impl Iterator<Item = &StackFrame> {
+        self.call_stack.iter().rev()
+    }
 }
@@ -1,4 +1,4 @@
-use crate::{builtins::Number, exec, forward, forward_val, Context, Value};
+use crate::{builtins::Number, exec, forward, forward_val, property::Attribute, Context, Value};
 
 #[test]
 fn function_declaration_returns_undefined() {
@@ -83,6 +83,51 @@ fn semicolon_expression_stop() {
     assert_eq!(&exec(scenario), "1");
 }
 
+#[test]
+fn semicolon_insertion_after_return() {
+    let scenario = r#"
+        function f() {
+            return
+            5;
+        }
+        f()
+        "#;
+
+    assert_eq!(&exec(scenario), "undefined");
+}
+
+#[test]
+fn semicolon_insertion_before_postfix_update() {
+    let scenario = r#"
+        let a = 1;
+        let b = 1;
+        a
+        ++b;
+        a + "," + b
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,2\"");
+}
+
+#[test]
+fn multi_line_expressions() {
+    let scenario = r#"
+        let x = 1
+            + 2
+            * 3
+        let s = "a"
+            .concat("b")
+        let f = function () {
+            return (
+                x
+            );
+        }
+        s + f()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"ab7\"");
+}
+
 #[test]
 fn empty_var_decl_undefined() {
     let scenario = r#"
@@ -551,6 +596,42 @@ fn typeof_function() {
     assert_eq!(&exec(typeof_function), "\"function\"");
 }
 
+#[test]
+fn function_without_return_returns_undefined() {
+    let scenario = r#"
+        function withoutReturn() {
+            5;
+        }
+        function emptyReturn() {
+            return;
+        }
+        [withoutReturn() === undefined, emptyReturn() === undefined].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"true,true\"");
+}
+
+#[test]
+fn typeof_undeclared() {
+    let typeof_undeclared = r#"
+        typeof notDeclared;
+    "#;
+    assert_eq!(&exec(typeof_undeclared), "\"undefined\"");
+
+    let typeof_undeclared_comparison = r#"
+        typeof notDeclared === "undefined";
+    "#;
+    assert_eq!(&exec(typeof_undeclared_comparison), "true");
+
+    let typeof_undeclared_property = r#"
+        try {
+            typeof notDeclared.property;
+        } catch (e) {
+            e.name;
+        }
+    "#;
+    assert_eq!(&exec(typeof_undeclared_property), "\"ReferenceError\"");
+}
+
 #[test]
 fn unary_post() {
     let unary_inc = r#"
@@ -663,6 +744,73 @@ fn unary_delete() {
     assert_eq!(&exec(delete_recursive), "true");
 }
 
+#[test]
+fn unary_delete_non_configurable() {
+    let delete_non_configurable = r#"
+        const a = {};
+        Object.defineProperty(a, 'b', { value: 5, configurable: false });
+        const c = delete a.b + '';
+        a.b + c
+    "#;
+    assert_eq!(&exec(delete_non_configurable), "\"5false\"");
+
+    let delete_builtin = r#"
+        [delete Math.PI, delete [].length, delete 'abc'.length, delete 'abc'[0]].join()
+    "#;
+    assert_eq!(&exec(delete_builtin), "\"false,false,false,false\"");
+}
+
+#[test]
+fn unary_delete_array_element() {
+    let scenario = r#"
+        const a = [1, 2, 3];
+        [delete a[0], a.length, 0 in a, a[0] === undefined, a.hasOwnProperty(1)].join()
+    "#;
+    assert_eq!(&exec(scenario), "\"true,3,false,true,true\"");
+}
+
+#[test]
+fn unary_delete_bindings() {
+    let delete_undeclared = r#"
+        delete notDeclared
+    "#;
+    assert_eq!(&exec(delete_undeclared), "true");
+
+    let delete_var = r#"
+        var a = 5;
+        function b() {}
+        [delete a, delete b, typeof a, typeof b].join()
+    "#;
+    assert_eq!(&exec(delete_var), "\"false,false,number,function\"");
+
+    let delete_implicit_global = r#"
+        a = 5;
+        [delete a, typeof a].join()
+    "#;
+    assert_eq!(&exec(delete_implicit_global), "\"true,undefined\"");
+
+    let delete_local = r#"
+        function f() {
+            var a = 5;
+            return [delete a, a].join();
+        }
+        f()
+    "#;
+    assert_eq!(&exec(delete_local), "\"false,5\"");
+}
+
+#[test]
+fn unary_delete_evaluates_non_references() {
+    let scenario = r#"
+        let calls = 0;
+        function f() {
+            calls++;
+        }
+        [delete f(), delete (1 + 2), delete this, calls].join()
+    "#;
+    assert_eq!(&exec(scenario), "\"true,true,true,1\"");
+}
+
 #[cfg(test)]
 mod in_operator {
     use super::*;
@@ -809,7 +957,6 @@ fn var_decl_hoisting_with_initialization() {
 }
 
 #[test]
-#[ignore]
 fn var_decl_hoisting_2_variables_hoisting() {
     let scenario = r#"
         x = y;
@@ -823,7 +970,6 @@ fn var_decl_hoisting_2_variables_hoisting() {
 }
 
 #[test]
-#[ignore]
 fn var_decl_hoisting_2_variables_hoisting_2() {
     let scenario = r#"
         var x = y;
@@ -835,7 +981,6 @@ fn var_decl_hoisting_2_variables_hoisting_2() {
 }
 
 #[test]
-#[ignore]
 fn var_decl_hoisting_2_variables_hoisting_3() {
     let scenario = r#"
         let y = x;
@@ -895,6 +1040,81 @@ fn function_decl_hoisting() {
     assert_eq!(&exec(scenario), "5");
 }
 
+#[test]
+fn var_decl_hoisting_before_declaration() {
+    let scenario = r#"
+        let before = typeof hoisted;
+        var hoisted = 1;
+        before;
+    "#;
+    assert_eq!(&exec(scenario), "\"undefined\"");
+
+    let scenario = r#"
+        let before = hoisted;
+        var hoisted = 1;
+        before;
+    "#;
+    assert_eq!(&exec(scenario), "undefined");
+
+    let scenario = r#"
+        var x = 10;
+        var x;
+        x;
+    "#;
+    assert_eq!(&exec(scenario), "10");
+}
+
+#[test]
+fn var_decl_hoisting_in_function() {
+    let scenario = r#"
+        var x = "outer";
+        function f() {
+            let before = x;
+            if (true) {
+                for (var i = 0; i < 2; i++) {
+                    var x = "inner";
+                }
+            }
+            return [before, x, i].join();
+        }
+        f() + "," + x;
+    "#;
+    assert_eq!(&exec(scenario), "\",inner,2,outer\"");
+
+    let scenario = r#"
+        function f(a) {
+            var a;
+            return a;
+        }
+        f(5);
+    "#;
+    assert_eq!(&exec(scenario), "5");
+
+    let scenario = r#"
+        function f() {
+            function g() { var y = 1; }
+            g();
+            return typeof y;
+        }
+        f();
+    "#;
+    assert_eq!(&exec(scenario), "\"undefined\"");
+}
+
+#[test]
+fn function_decl_hoisting_mutual_recursion() {
+    let scenario = r#"
+        function check(n) {
+            return [isEven(n), isOdd(n)].join();
+
+            function isEven(n) { return n === 0 ? true : isOdd(n - 1); }
+            function isOdd(n) { return n === 0 ? false : isEven(n - 1); }
+        }
+        check(7);
+    "#;
+    assert_eq!(&exec(scenario), "\"false,true\"");
+}
+
 #[test]
 fn to_bigint() {
     let mut context = Context::new();
@@ -1240,7 +1460,7 @@ fn not_a_function() {
     "#;
     assert_eq!(
         forward(&mut context, scenario),
-        "\"TypeError: not a function\""
+        "\"TypeError: a is not a function\""
     );
     let scenario = r#"
         try {
@@ -1251,7 +1471,7 @@ fn not_a_function() {
     "#;
     assert_eq!(
         forward(&mut context, scenario),
-        "\"TypeError: not a function\""
+        "\"TypeError: a.a is not a function\""
     );
     let scenario = r#"
         try {
@@ -1262,7 +1482,50 @@ fn not_a_function() {
     "#;
     assert_eq!(
         forward(&mut context, scenario),
-        "\"TypeError: not a function\""
+        "\"TypeError: b is not a function\""
+    );
+}
+
+#[test]
+fn not_a_function_describes_the_callee() {
+    let mut context = Context::new();
+    let catch = |src: &str| {
+        format!(
+            "try {{ {} }} catch (e) {{ [e instanceof TypeError, e.message].join() }}",
+            src
+        )
+    };
+
+    assert_eq!(
+        forward(&mut context, &catch("(5)();")),
+        "\"true,5 is not a function\""
+    );
+    assert_eq!(
+        forward(&mut context, &catch("({}).foo();")),
+        "\"true,(intermediate value).foo is not a function\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            &catch("let obj = { a: { b: 1 } }; obj.a['b']();")
+        ),
+        "\"true,obj.a[\"b\"] is not a function\""
+    );
+    assert_eq!(
+        forward(&mut context, &catch("let tag = null; tag`x`;")),
+        "\"true,tag is not a function\""
+    );
+    assert_eq!(
+        forward(&mut context, &catch("let opt = { a: 1 }; opt?.a();")),
+        "\"true,opt.a is not a function\""
+    );
+    assert_eq!(
+        forward(&mut context, &catch("undefined.foo();")),
+        "\"true,cannot read property 'foo' of undefined\""
+    );
+    assert_eq!(
+        forward(&mut context, &catch("null['b' + 'ar']();")),
+        "\"true,cannot read property 'bar' of null\""
     );
 }
 
@@ -1283,6 +1546,33 @@ fn comma_operator() {
         a
     "#;
     assert_eq!(&exec(scenario), "2");
+
+    assert_eq!(&exec("(1, 2, 3) === 3"), "true");
+
+    let scenario = r#"
+        var a;
+        var b = (a = 1, 2);
+        a + b
+    "#;
+    assert_eq!(&exec(scenario), "3");
+
+    let scenario = r#"
+        function f(x, y) { return arguments.length + ":" + x + ":" + y; }
+        [f((1, 2), 3), [(4, 5), 6].length].join(" ")
+    "#;
+    assert_eq!(&exec(scenario), "\"2:2:3 2\"");
+}
+
+#[test]
+fn for_loop_with_comma_operator() {
+    let scenario = r#"
+        var steps = [];
+        for (var i = 0, j = 10; i < j; i++, j -= 2) {
+            steps.push(i + ":" + j);
+        }
+        steps.join(" ")
+    "#;
+    assert_eq!(&exec(scenario), "\"0:10 1:8 2:6 3:4\"");
 }
 
 #[test]
@@ -1455,6 +1745,23 @@ fn test_strict_mode_delete() {
     assert!(string.starts_with("Uncaught \"SyntaxError\": "));
 }
 
+#[test]
+fn test_strict_mode_read_only_global() {
+    // Checks that assigning to a non-writable property of the global object throws a TypeError
+    // in strict mode code, and is ignored otherwise.
+
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "undefined = 1; undefined"),
+        "undefined"
+    );
+
+    let string = dbg!(forward(&mut context, "'use strict'; undefined = 1;"));
+
+    assert!(string.starts_with("Uncaught \"TypeError\": "));
+}
+
 #[test]
 fn test_strict_mode_reserved_name() {
     // Checks that usage of a reserved keyword for an identifier name is
@@ -1520,6 +1827,72 @@ fn test_strict_mode_dup_func_parameters() {
     assert!(string.starts_with("Uncaught \"SyntaxError\": "));
 }
 
+#[test]
+fn test_sloppy_mode_dup_func_parameters() {
+    let scenario = r#"
+    function f(a, b, b) { return b; }
+    f(1, 2, 3)
+    "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn test_strict_mode_undeclared_assignment() {
+    let scenario = r#"
+    'use strict';
+    try {
+        undeclared = 1;
+    } catch (e) {
+        e.name + ": " + e.message
+    }
+    "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"ReferenceError: undeclared is not defined\""
+    );
+}
+
+#[test]
+fn test_this_in_function_call() {
+    let scenario = r#"
+    function sloppy() { return this; }
+    function strict() { 'use strict'; return this; }
+    [sloppy() === globalThis, strict() === undefined, typeof sloppy.call(1)].join()
+    "#;
+
+    assert_eq!(&exec(scenario), "\"true,true,object\"");
+}
+
+#[test]
+fn context_strict_by_default() {
+    let mut sloppy = Context::new();
+    assert_eq!(forward(&mut sloppy, "undeclared = 1; undeclared"), "1");
+
+    // `forward` always parses sloppy mode code, so the code is run with `Context::eval`, which
+    // parses it in the mode of the context.
+    let mut context = Context::builder().strict(true).build();
+    let mut error_name = |src: &str| {
+        let error = context.eval(src).unwrap_err();
+        error
+            .get_field("name", &mut context)
+            .unwrap()
+            .display()
+            .to_string()
+    };
+    assert_eq!(error_name("undeclared = 1"), "\"ReferenceError\"");
+    assert_eq!(error_name("function f(a, a) {}"), "\"SyntaxError\"");
+    assert_eq!(error_name("with ({}) {}"), "\"SyntaxError\"");
+    assert_eq!(
+        context
+            .eval("(function () { return this; })() === undefined")
+            .unwrap()
+            .as_boolean(),
+        Some(true)
+    );
+}
+
 #[test]
 fn test_empty_statement() {
     let src = r#"
@@ -1529,3 +1902,730 @@ fn test_empty_statement() {
     "#;
     assert_eq!(&exec(src), "10");
 }
+
+#[test]
+fn string_literals_are_interned() {
+    let mut context = Context::new();
+    let src = r#"
+        var records = [];
+        for (var i = 0; i < 100; i++) {
+            records.push({ id: i, name: "name", kind: "record", tags: ["a", "b", "a"] });
+        }
+        var other = { name: "other", kind: "record", id: -1 };
+        records.length + other.kind
+    "#;
+
+    assert_eq!(forward(&mut context, src), "\"100record\"");
+    // "id", "name", "kind", "tags", "record", "a", "b" and "other".
+    assert_eq!(context.interned_string_count(), 8);
+
+    forward(&mut context, "var again = { id: 0, name: 'record' };");
+    assert_eq!(context.interned_string_count(), 8);
+}
+
+#[test]
+fn unused_interned_strings_are_removed() {
+    let mut context = Context::new();
+    forward(&mut context, "var kept = { key: 'kept' };");
+
+    // Each literal is only referenced by the table once its statement is evaluated.
+    let src: String = (0..1000).map(|i| format!("'unused {}';", i)).collect();
+    forward(&mut context, &src);
+    assert!(context.interned_string_count() <= 512);
+
+    let src: String = (0..1000).map(|i| format!("'other {}';", i)).collect();
+    forward(&mut context, &src);
+    assert!(context.interned_string_count() <= 512);
+    assert_eq!(forward(&mut context, "kept.key"), "\"kept\"");
+}
+
+#[test]
+fn compiled_script_is_parsed_once() {
+    let mut context = Context::new();
+    let src =
+        "var total = (typeof total === 'number' ? total : 0) + 10; [total, typeof total].join()";
+
+    let script = context.compile(src).unwrap();
+    let parses = crate::syntax::parser::parse_count();
+    let first = context.execute(&script).unwrap();
+    let second = context.execute(&script).unwrap();
+    assert_eq!(crate::syntax::parser::parse_count(), parses);
+
+    assert_eq!(first.display().to_string(), "\"10,number\"");
+    assert_eq!(second.display().to_string(), "\"20,number\"");
+
+    // The compiled script gives the same results as evaluating the code, in any context.
+    assert_eq!(
+        Context::new().execute(&script).unwrap(),
+        Context::new().eval(src).unwrap()
+    );
+}
+
+#[test]
+fn compile_throws_syntax_errors() {
+    let mut context = Context::new();
+
+    let error = context.compile("1 +").unwrap_err();
+    context.register_global_property("error", error, Attribute::all());
+    assert_eq!(
+        forward(&mut context, "error instanceof SyntaxError"),
+        "true"
+    );
+}
+
+#[test]
+fn eval_incremental_waits_for_complete_script() {
+    let mut context = Context::new();
+
+    assert_eq!(context.eval_incremental("1 +").unwrap(), None);
+    assert_eq!(context.eval_incremental("2").unwrap(), Some(Value::from(3)));
+}
+
+#[test]
+fn eval_incremental_open_constructs() {
+    let mut context = Context::new();
+
+    assert_eq!(context.eval_incremental("let a = [1,").unwrap(), None);
+    assert_eq!(
+        context.eval_incremental(" 2];").unwrap(),
+        Some(Value::undefined())
+    );
+    assert_eq!(
+        context.eval_incremental("a.length").unwrap(),
+        Some(Value::from(2))
+    );
+
+    assert_eq!(context.eval_incremental("function f() {\n").unwrap(), None);
+    assert_eq!(context.eval_incremental("return 4;\n").unwrap(), None);
+    assert_eq!(
+        context.eval_incremental("}\nf()").unwrap(),
+        Some(Value::from(4))
+    );
+
+    assert_eq!(context.eval_incremental("`a${1 +").unwrap(), None);
+    assert_eq!(context.eval_incremental(" 1}").unwrap(), None);
+    assert_eq!(
+        context.eval_incremental("b`").unwrap(),
+        Some(Value::from("a2\nb"))
+    );
+
+    assert_eq!(context.eval_incremental("5 /* a").unwrap(), None);
+    assert_eq!(
+        context.eval_incremental(" comment */").unwrap(),
+        Some(Value::from(5))
+    );
+    assert_eq!(
+        context.eval_incremental("6 // a comment").unwrap(),
+        Some(Value::from(6))
+    );
+}
+
+#[test]
+fn eval_incremental_syntax_error_clears_buffer() {
+    let mut context = Context::new();
+
+    assert!(context.eval_incremental("1 +").unwrap().is_none());
+    assert!(context.eval_incremental(")").is_err());
+    assert_eq!(context.eval_incremental("7").unwrap(), Some(Value::from(7)));
+
+    assert!(context.eval_incremental("'abc\n").is_err());
+    assert_eq!(context.eval_incremental("8").unwrap(), Some(Value::from(8)));
+}
+
+#[test]
+fn eval_incremental_joins_chunks_with_line_terminators() {
+    let mut context = Context::new();
+
+    // Without a line terminator between the chunks, the comment would end with the input.
+    assert!(context.eval_incremental("[1, // one").unwrap().is_none());
+    assert_eq!(
+        context.eval_incremental("2].length").unwrap(),
+        Some(Value::from(2))
+    );
+}
+
+#[test]
+fn eval_incremental_error_position_in_chunk() {
+    let mut context = Context::new();
+    let message = |error: Value, context: &mut Context| {
+        error
+            .get_field("message", context)
+            .unwrap()
+            .to_string(context)
+            .unwrap()
+            .to_string()
+    };
+
+    assert!(context.eval_incremental("let a = (1 +").unwrap().is_none());
+    assert!(context.eval_incremental("\n2 +").unwrap().is_none());
+    let error = context.eval_incremental("  3 ]").unwrap_err();
+    assert_eq!(
+        message(error, &mut context),
+        "expected token ')', got ']' in primary expression at line 1, col 5 of chunk 3 of the pending input"
+    );
+
+    let error = context.eval_incremental("let b = ]").unwrap_err();
+    assert!(message(error, &mut context).ends_with("at line 1, col 9"));
+}
+
+#[test]
+fn clear_incremental_discards_pending_source() {
+    let mut context = Context::new();
+
+    assert!(context.eval_incremental("(1 +").unwrap().is_none());
+    context.clear_incremental();
+    assert_eq!(context.eval_incremental("9").unwrap(), Some(Value::from(9)));
+}
+
+#[test]
+fn instruction_limit_stops_infinite_loop() {
+    let mut context = Context::new();
+    context.set_instruction_limit(Some(10_000));
+
+    let error = context
+        .eval("let i = 0; while (true) { i++; }")
+        .expect_err("the loop must be stopped by the instruction limit");
+    assert_eq!(
+        error
+            .get_field("message", &mut context)
+            .unwrap()
+            .display()
+            .to_string(),
+        "\"instruction limit exceeded\""
+    );
+    assert_eq!(
+        error
+            .get_field("name", &mut context)
+            .unwrap()
+            .display()
+            .to_string(),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn instruction_limit_allows_short_scripts() {
+    let mut context = Context::new();
+    context.set_instruction_limit(Some(10_000));
+
+    assert_eq!(
+        context
+            .eval("let sum = 0; for (let i = 0; i < 10; i++) { sum += i; } sum")
+            .unwrap(),
+        Value::from(45)
+    );
+
+    // The budget is per evaluation, so it is not used up by the previous scripts.
+    for _ in 0..10 {
+        assert!(context.eval("for (let i = 0; i < 10; i++) {}").is_ok());
+    }
+
+    context.set_instruction_limit(None);
+    assert_eq!(context.instruction_limit(), None);
+    assert!(context.eval("for (let i = 0; i < 100000; i++) {}").is_ok());
+}
+
+#[test]
+fn instruction_limit_cannot_be_caught() {
+    let mut context = Context::new();
+    context.set_instruction_limit(Some(10_000));
+
+    let result = context.eval(
+        r#"
+        var caught = false;
+        try {
+            while (true) {}
+        } catch (e) {
+            caught = true;
+        }
+        "#,
+    );
+    assert!(result.is_err());
+
+    context.set_instruction_limit(None);
+    assert_eq!(context.eval("caught").unwrap(), Value::from(false));
+}
+
+#[test]
+fn object_limit_stops_unbounded_allocation() {
+    let mut context = Context::new();
+    context.set_max_objects(Some(20_000));
+    assert_eq!(context.max_objects(), Some(20_000));
+
+    let error = context
+        .eval("let list = []; while (true) { list.push({}); }")
+        .expect_err("the loop must be stopped by the object limit");
+    assert_eq!(
+        error
+            .get_field("message", &mut context)
+            .unwrap()
+            .display()
+            .to_string(),
+        "\"object limit exceeded\""
+    );
+    assert_eq!(
+        error
+            .get_field("name", &mut context)
+            .unwrap()
+            .display()
+            .to_string(),
+        "\"RangeError\""
+    );
+
+    // Once the objects can be collected, the script can allocate again.
+    context.set_max_objects(None);
+    assert!(context.eval("list = null;").is_ok());
+    context.set_max_objects(Some(20_000));
+    assert!(context
+        .eval("let small = []; for (let i = 0; i < 100; i++) { small.push({}); }")
+        .is_ok());
+}
+
+#[test]
+fn object_limit_only_counts_the_objects_of_the_context() {
+    let mut limited = Context::new();
+    limited.set_max_objects(Some(5_000));
+
+    // The objects kept alive by another context of the thread don't count towards the limit.
+    let mut other = Context::new();
+    other
+        .eval("var list = []; for (let i = 0; i < 10000; i++) { list.push({}); }")
+        .unwrap();
+
+    assert!(limited
+        .eval("let small = []; for (let i = 0; i < 100; i++) { small.push({}); }")
+        .is_ok());
+    assert_eq!(other.eval("list.length").unwrap(), Value::from(10_000));
+}
+
+#[test]
+fn object_limit_error_can_be_caught() {
+    let mut context = Context::new();
+    context.set_max_objects(Some(20_000));
+
+    let result = context.eval(
+        r#"
+        let list = [];
+        let caught;
+        try {
+            while (true) {
+                list.push([1, 2, 3]);
+            }
+        } catch (e) {
+            list = null;
+            caught = e instanceof RangeError;
+        }
+        let after = [];
+        for (let i = 0; i < 100; i++) {
+            after.push({ i });
+        }
+        caught && after.length === 100
+        "#,
+    );
+    assert_eq!(result.unwrap(), Value::from(true));
+}
+
+#[test]
+fn max_call_stack_size_throws_range_error() {
+    let mut context = Context::new();
+    context.set_max_call_stack_size(50);
+
+    let scenario = r#"
+        let depth = 0;
+        function recurse() {
+            depth++;
+            recurse();
+        }
+        let error;
+        try {
+            recurse();
+        } catch (e) {
+            error = e;
+        }
+    "#;
+    context.eval(scenario).unwrap();
+    assert_eq!(forward(&mut context, "error instanceof RangeError"), "true");
+    assert_eq!(
+        forward(&mut context, "error.message"),
+        "\"Maximum call stack size exceeded\""
+    );
+    assert_eq!(forward(&mut context, "depth"), "50");
+
+    // The call stack is unwound, so the limit applies to new calls again.
+    let scenario = r#"
+        function sum(n) {
+            return n === 0 ? 0 : n + sum(n - 1);
+        }
+        sum(40)
+    "#;
+    assert_eq!(forward(&mut context, scenario), "820");
+    assert_eq!(
+        forward(&mut context, "try { sum(60) } catch (e) { e.name }"),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn deep_recursion_does_not_overflow_the_native_stack() {
+    // The test threads have a small stack, which the nested calls outgrow.
+    let mut context = Context::new();
+    let scenario = r#"
+        function count(n) {
+            return n === 0 ? 0 : 1 + count(n - 1);
+        }
+        count(5000)
+    "#;
+    assert_eq!(forward(&mut context, scenario), "5000");
+    assert_eq!(
+        forward(&mut context, "try { count(20000) } catch (e) { e.name }"),
+        "\"RangeError\""
+    );
+}
+
+#[test]
+fn max_call_stack_size_can_be_raised() {
+    let mut context = Context::new();
+    assert_eq!(
+        context.max_call_stack_size(),
+        Context::DEFAULT_MAX_CALL_STACK_SIZE
+    );
+    let scenario = r#"
+        function count(n) {
+            return n === 0 ? 0 : 1 + count(n - 1);
+        }
+    "#;
+    context.eval(scenario).unwrap();
+    assert_eq!(
+        forward(&mut context, "try { count(15000) } catch (e) { e.name }"),
+        "\"RangeError\""
+    );
+
+    context.set_max_call_stack_size(20_000);
+    assert_eq!(forward(&mut context, "count(15000)"), "15000");
+}
+
+#[test]
+fn context_builder_excludes_builtins() {
+    let mut context = Context::builder()
+        .exclude_builtins(&["Function", "eval"])
+        .build();
+
+    assert_eq!(forward(&mut context, "typeof eval"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "typeof Function"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "Math.max(1, 3, 2)"), "3");
+    assert_eq!(forward(&mut context, "typeof Array"), "\"function\"");
+}
+
+#[test]
+fn context_builder_only_builtins() {
+    let mut context = Context::builder().only_builtins(&["Math", "JSON"]).build();
+
+    assert_eq!(forward(&mut context, "Math.max(1, 3, 2)"), "3");
+    assert_eq!(forward(&mut context, "JSON.stringify([1])"), "\"[1]\"");
+    assert_eq!(forward(&mut context, "typeof Array"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "typeof parseInt"), "\"undefined\"");
+
+    // The engine still throws its standard errors without the global bindings.
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { null.x } catch (e) { e.name + ': ' + typeof e.message }"
+        ),
+        "\"TypeError: string\""
+    );
+}
+
+#[test]
+fn context_builder_host_globals() {
+    fn double(_: &Value, args: &[Value], context: &mut Context) -> crate::Result<Value> {
+        let value = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_number(context)?;
+        Ok(Value::from(value * 2.0))
+    }
+
+    let mut context = Context::builder()
+        .only_builtins(&["Math"])
+        .register_global_property("answer", 21, Attribute::all())
+        .register_global_function("double", 1, double)
+        .build();
+
+    assert_eq!(forward(&mut context, "double(answer)"), "42");
+    assert_eq!(forward(&mut context, "Math.abs(-answer)"), "21");
+}
+
+#[test]
+fn contexts_from_intrinsics_are_isolated() {
+    let intrinsics = crate::Intrinsics::new();
+    let mut first = Context::from_intrinsics(&intrinsics);
+    let mut second = Context::from_intrinsics(&intrinsics);
+
+    forward(
+        &mut first,
+        r#"
+        var shared = 1;
+        Array.prototype.extra = "first";
+        Math.max = function () { return "patched"; };
+        delete globalThis.JSON;
+        "#,
+    );
+    assert_eq!(forward(&mut first, "[].extra"), "\"first\"");
+    assert_eq!(forward(&mut first, "Math.max(1, 2)"), "\"patched\"");
+
+    assert_eq!(forward(&mut second, "typeof shared"), "\"undefined\"");
+    assert_eq!(forward(&mut second, "typeof [].extra"), "\"undefined\"");
+    assert_eq!(forward(&mut second, "Math.max(1, 2)"), "2");
+    assert_eq!(
+        forward(&mut second, "JSON.stringify({ a: [1] })"),
+        "\"{\"a\":[1]}\""
+    );
+
+    // The copied builtins are wired to each other like the original ones.
+    assert_eq!(
+        forward(
+            &mut second,
+            "Object.getPrototypeOf(Array.prototype) === Object.prototype && [].constructor === Array"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut second,
+            "try { null.x } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut second, "[...new Set([1, 2])].join()"),
+        "\"1,2\""
+    );
+
+    // The intrinsics themselves are left untouched.
+    let mut third = Context::builder().build_from(&intrinsics);
+    assert_eq!(forward(&mut third, "typeof [].extra"), "\"undefined\"");
+    assert_eq!(forward(&mut third, "typeof JSON"), "\"object\"");
+}
+
+#[test]
+fn numeric_literals() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "1_000 === 1000"), "true");
+    assert_eq!(forward(&mut context, "1_000.000_1"), "1000.0001");
+    assert_eq!(forward(&mut context, "0b101 === 5"), "true");
+    assert_eq!(forward(&mut context, "0O1_7 === 15"), "true");
+    assert_eq!(forward(&mut context, "0xF_F === 255"), "true");
+    assert_eq!(forward(&mut context, "1e3 === 1000"), "true");
+    assert_eq!(forward(&mut context, "2.5E-1_0 === 0.00000000025"), "true");
+    assert_eq!(forward(&mut context, "1_0n === 10n"), "true");
+
+    for literal in ["1_000_", "1__000", "0b_101", "1_e3", "0_7"].iter() {
+        let string = forward(&mut context, literal);
+        assert!(
+            string.starts_with("Uncaught \"SyntaxError\": "),
+            "{} is not a syntax error",
+            literal
+        );
+    }
+}
+
+#[test]
+fn lazy_builtins() {
+    let mut context = Context::new();
+    let initialized = |context: &Context| {
+        context
+            .lazy_builtins
+            .iter()
+            .filter(|builtin| builtin.is_initialized())
+            .count()
+    };
+
+    // A builtin is only initialized by the first read of its global binding.
+    forward(&mut context, "var x = [1, 2].length;");
+    assert_eq!(initialized(&context), 0);
+    forward(&mut context, "var first = Date;");
+    assert_eq!(initialized(&context), 1);
+
+    // Every builtin is initialized once the global object is read, so their global properties
+    // are the data properties holding them.
+    let descriptor = "Object.getOwnPropertyDescriptor(globalThis, 'Date')";
+    assert_eq!(
+        forward(&mut context, &format!("{}.value === first", descriptor)),
+        "true"
+    );
+    assert_eq!(initialized(&context), 4);
+    assert_eq!(
+        forward(&mut context, &format!("{}.writable", descriptor)),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, &format!("{}.enumerable", descriptor)),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "typeof Object.getOwnPropertyDescriptor(globalThis, 'Math').value.max"
+        ),
+        "\"function\""
+    );
+    assert_eq!(forward(&mut context, "Date === first"), "true");
+    assert_eq!(forward(&mut context, "new first(0).getTime()"), "0");
+    assert_eq!(
+        forward(&mut context, "first.prototype.constructor === Date"),
+        "true"
+    );
+
+    assert_eq!(forward(&mut context, "Math.max(1, 2)"), "2");
+    assert_eq!(forward(&mut context, "Math === globalThis.Math"), "true");
+    assert_eq!(
+        forward(&mut context, "new Intl.NumberFormat().format(1)"),
+        "\"1\""
+    );
+}
+
+#[test]
+fn lazy_builtins_through_this() {
+    // The global object is also reached as the `this` value of sloppy functions.
+    let mut context = Context::new();
+    let init = r#"
+        (function () {
+            return Object.getOwnPropertyDescriptor(this, 'RegExp').value;
+        })() === RegExp
+        "#;
+    assert_eq!(forward(&mut context, init), "true");
+
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "Object.keys(this.Math).length"), "0");
+    assert_eq!(forward(&mut context, "typeof this.Math.PI"), "\"number\"");
+}
+
+#[test]
+fn lazy_builtins_used_internally() {
+    let mut context = Context::new();
+
+    // Regular expressions created by other builtins have the prototype of `RegExp`.
+    assert_eq!(forward(&mut context, "'abc'.search('c')"), "2");
+    assert_eq!(forward(&mut context, "'abc'.match('b').index"), "1");
+    assert_eq!(
+        forward(&mut context, "[...'a1b2'.matchAll('[0-9]')].length"),
+        "2"
+    );
+    assert_eq!(forward(&mut context, "/a/ instanceof RegExp"), "true");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getPrototypeOf(/a/) === RegExp.prototype"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn lazy_builtins_redefined() {
+    let mut context = Context::new();
+
+    forward(&mut context, "Math = 1; globalThis.Intl = 2;");
+    assert_eq!(forward(&mut context, "Math"), "1");
+    assert_eq!(forward(&mut context, "Intl"), "2");
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.getOwnPropertyDescriptor(globalThis, 'Intl').enumerable"
+        ),
+        "false"
+    );
+
+    // A deleted builtin stays deleted, even once other builtins initialize it.
+    assert_eq!(forward(&mut context, "delete globalThis.RegExp"), "true");
+    assert_eq!(forward(&mut context, "'abc'.search('c')"), "2");
+    assert_eq!(forward(&mut context, "typeof RegExp"), "\"undefined\"");
+
+    let mut context = Context::builder().exclude_builtins(&["Date"]).build();
+    assert_eq!(forward(&mut context, "typeof Date"), "\"undefined\"");
+}
+
+#[test]
+fn snapshot_and_restore_globals() {
+    let mut context = Context::new();
+    let init = r#"
+        var counter = 0;
+        let lexical = { count: 0 };
+        var config = { nested: { list: [1, 2] } };
+        config.self = config;
+        function increment() { return ++counter; }
+    "#;
+    forward(&mut context, init);
+    let snapshot = context.snapshot_globals();
+
+    let mutations = r#"
+        increment();
+        config.nested.list.push(3);
+        config.added = true;
+        var extra = "extra";
+        Math = null;
+        delete config.self;
+        lexical.count++;
+        let added = 1;
+    "#;
+    forward(&mut context, mutations);
+    assert_eq!(forward(&mut context, "counter"), "1");
+
+    context.restore_globals(&snapshot);
+
+    assert_eq!(forward(&mut context, "counter"), "0");
+    assert_eq!(
+        forward(&mut context, "config.nested.list.join()"),
+        "\"1,2\""
+    );
+    assert_eq!(forward(&mut context, "config.added"), "undefined");
+    assert_eq!(forward(&mut context, "config.self === config"), "true");
+    assert_eq!(forward(&mut context, "typeof extra"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "Math.max(1, 2)"), "2");
+    assert_eq!(forward(&mut context, "lexical.count"), "0");
+    assert_eq!(forward(&mut context, "typeof added"), "\"undefined\"");
+
+    // Functions are shared with the snapshot, and the snapshot can be restored again.
+    assert_eq!(forward(&mut context, "increment()"), "1");
+    context.restore_globals(&snapshot);
+    assert_eq!(forward(&mut context, "counter"), "0");
+    assert_eq!(
+        forward(&mut context, "Array.isArray(config.nested.list)"),
+        "true"
+    );
+}
+
+#[test]
+fn uncaught_exception_carries_thrown_value() {
+    let mut context = Context::new();
+
+    let error = context.eval("throw { code: 42 }").unwrap_err();
+    assert!(error.is_object());
+    assert_eq!(
+        error.get_field("code", &mut context).unwrap(),
+        Value::from(42)
+    );
+
+    let error = context
+        .eval("function fail() { throw 'oops'; } fail()")
+        .unwrap_err();
+    assert_eq!(error.as_string().unwrap().as_str(), "oops");
+
+    let error = context.eval("throw 1.5").unwrap_err();
+    assert_eq!(error.as_number(), Some(1.5));
+
+    let error = context.eval("null.x").unwrap_err();
+    assert_eq!(
+        error
+            .get_field("name", &mut context)
+            .unwrap()
+            .as_string()
+            .unwrap()
+            .as_str(),
+        "TypeError"
+    );
+
+    assert_eq!(context.eval("fail.name").unwrap(), Value::from("fail"));
+}
@@ -49,6 +49,7 @@ pub mod class;
 pub mod environment;
 pub mod exec;
 pub mod gc;
+pub mod module;
 pub mod object;
 pub mod profiler;
 pub mod property;
@@ -69,7 +70,10 @@ pub(crate) use crate::{exec::Executable, profiler::BoaProfiler};
 
 // Export things to root level
 #[doc(inline)]
-pub use crate::{context::Context, value::Value};
+pub use crate::{
+    context::{CompiledScript, Context, ContextBuilder, GlobalsSnapshot, Intrinsics},
+    value::Value,
+};
 
 use crate::syntax::{
     ast::node::StatementList,
@@ -77,6 +81,9 @@ use crate::syntax::{
 };
 
 /// The result of a Javascript expression is represented like this so it can succeed (`Ok`) or fail (`Err`)
+///
+/// The `Err` variant holds the value that was thrown, exactly as the script threw it. It is an
+/// `Error` object for errors raised by the engine, but a script can throw any value.
 #[must_use]
 pub type Result<T> = StdResult<T, Value>;
 
@@ -109,7 +116,8 @@ pub(crate) fn forward<T: AsRef<[u8]>>(context: &mut Context, src: T) -> String {
             );
         }
     };
-    expr.run(context).map_or_else(
+    let result = expr.run_body(context);
+    context.finish_script(result).map_or_else(
         |e| format!("Uncaught {}", e.display()),
         |v| v.display().to_string(),
     )
@@ -132,7 +140,10 @@ pub(crate) fn forward_val<T: AsRef<[u8]>>(context: &mut Context, src: T) -> Resu
                 .throw_syntax_error(e.to_string())
                 .expect_err("interpreter.throw_syntax_error() did not return an error")
         })
-        .and_then(|expr| expr.run(context));
+        .and_then(|expr| {
+            let result = expr.run_body(context);
+            context.finish_script(result)
+        });
 
     // The main_timer needs to be dropped before the BoaProfiler is.
     drop(main_timer);
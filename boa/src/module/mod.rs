@@ -0,0 +1,753 @@
+//! ECMAScript modules.
+//!
+//! Modules are loaded through the [`ModuleLoader`] set on the [`Context`], which resolves the
+//! specifier of every `import` declaration into the source of the imported module. Each module is
+//! evaluated once per context, after the modules it imports, and its exports are live bindings
+//! to the variables of its module environment.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Modules
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    environment::{
+        lexical_environment::Environment,
+        module_environment_record::{ImportBinding, ImportBindings, ModuleEnvironmentRecord},
+    },
+    exec::InterpreterState,
+    object::{FunctionBuilder, GcObject, Object},
+    property::{AccessorDescriptor, Attribute, DataDescriptor},
+    symbol::WellKnownSymbols,
+    syntax::{
+        ast::node::{module::DEFAULT_EXPORT_BINDING, ExportDecl, ImportName, Node, StatementList},
+        Parser,
+    },
+    BoaProfiler, Context, Result, Value,
+};
+use rustc_hash::FxHashMap;
+use std::{
+    cell::{Cell, RefCell},
+    fmt,
+    rc::Rc,
+};
+
+/// A host hook that resolves the modules imported by `import` declarations.
+///
+/// Resolution is synchronous: the loader has to return the source of the module before the
+/// importing module can be linked. Modules are cached by the name of the returned
+/// [`ModuleSource`], so resolving different specifiers to the same name imports the same module.
+///
+/// Closures with the same signature as [`resolve`](Self::resolve) implement this trait.
+pub trait ModuleLoader {
+    /// Resolves the module `specifier` imported by the module named `referrer`.
+    ///
+    /// The `referrer` is `None` for the modules imported by the module passed to
+    /// [`Context::eval_module`], or for the modules imported by [`Context::import_module`].
+    fn resolve(
+        &self,
+        specifier: &str,
+        referrer: Option<&str>,
+        context: &mut Context,
+    ) -> Result<ModuleSource>;
+
+    /// Sets the properties of the `import.meta` object of the module named `module`, like its
+    /// `url`.
+    ///
+    /// It's called the first time the module evaluates `import.meta`, with an empty object. The
+    /// `module` is `None` for the module passed to [`Context::eval_module`]. By default, the object
+    /// is left empty.
+    fn import_meta(
+        &self,
+        _module: Option<&str>,
+        _meta: &GcObject,
+        _context: &mut Context,
+    ) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<F> ModuleLoader for F
+where
+    F: Fn(&str, Option<&str>, &mut Context) -> Result<ModuleSource>,
+{
+    fn resolve(
+        &self,
+        specifier: &str,
+        referrer: Option<&str>,
+        context: &mut Context,
+    ) -> Result<ModuleSource> {
+        self(specifier, referrer, context)
+    }
+}
+
+/// The source code of a module, along with the name that identifies it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModuleSource {
+    name: Box<str>,
+    source: Box<str>,
+}
+
+impl ModuleSource {
+    /// Creates a new `ModuleSource`.
+    pub fn new<N, S>(name: N, source: S) -> Self
+    where
+        N: Into<Box<str>>,
+        S: Into<Box<str>>,
+    {
+        Self {
+            name: name.into(),
+            source: source.into(),
+        }
+    }
+
+    /// Gets the name of the module, which is also the `referrer` of the modules it imports.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the source code of the module.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+/// The module loader and the modules loaded by a context.
+#[derive(Default)]
+pub(crate) struct Modules {
+    loader: Option<Rc<dyn ModuleLoader>>,
+    records: FxHashMap<Box<str>, Module>,
+}
+
+impl Modules {
+    /// Sets the loader used to resolve the imported modules.
+    pub(crate) fn set_loader(&mut self, loader: Rc<dyn ModuleLoader>) {
+        self.loader = Some(loader);
+    }
+}
+
+impl fmt::Debug for Modules {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Modules")
+            .field("loader", &self.loader.as_ref().map(|_| "ModuleLoader"))
+            .field("records", &self.records)
+            .finish()
+    }
+}
+
+/// The progress of a module through linking and evaluation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Unlinked,
+    Linking,
+    Linked,
+    Evaluating,
+    Evaluated,
+}
+
+/// The static information of a module, and its state.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-source-text-module-records
+#[derive(Debug)]
+struct ModuleRecord {
+    name: Option<Box<str>>,
+    body: StatementList,
+    environment: Environment,
+    import_bindings: ImportBindings,
+    status: Cell<Status>,
+    /// The error thrown while evaluating the module, which is thrown again by later imports.
+    error: RefCell<Option<Value>>,
+    namespace: RefCell<Option<GcObject>>,
+    /// The names of the requested modules, by specifier, in the order they are first imported.
+    requested_modules: RefCell<Vec<(Box<str>, Box<str>)>>,
+    /// The `(module specifier, import name, local name)` of each imported binding.
+    import_entries: Vec<(Box<str>, ImportName, Box<str>)>,
+    /// The local binding of each export name.
+    local_exports: FxHashMap<Box<str>, Box<str>>,
+    /// The `(module specifier, import name)` of each re-exported binding.
+    indirect_exports: FxHashMap<Box<str>, (Box<str>, ImportName)>,
+    /// The specifiers of the modules whose exports are all re-exported.
+    star_exports: Vec<Box<str>>,
+    /// Whether the default export is an expression, stored in a hidden binding.
+    default_expression: bool,
+}
+
+/// A shared reference to a module record.
+#[derive(Debug, Clone)]
+pub(crate) struct Module(Rc<ModuleRecord>);
+
+impl Module {
+    fn ptr_eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+
+    /// Gets the specifiers of the modules imported by `body`, without duplicates.
+    fn module_requests(body: &StatementList) -> Vec<Box<str>> {
+        let mut requests: Vec<Box<str>> = Vec::new();
+        for item in body.items() {
+            let module = match item {
+                Node::ImportDecl(decl) => Some(decl.module()),
+                Node::ExportDecl(decl) => decl.module(),
+                _ => None,
+            };
+            if let Some(module) = module {
+                if !requests.iter().any(|request| request.as_ref() == module) {
+                    requests.push(module.into());
+                }
+            }
+        }
+        requests
+    }
+
+    /// Creates the record of the module with the given body, collecting its import and export
+    /// entries.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-parsemodule
+    fn new(
+        name: Option<Box<str>>,
+        body: StatementList,
+        environment: ModuleEnvironmentRecord,
+    ) -> Self {
+        let mut import_entries: Vec<(Box<str>, ImportName, Box<str>)> = Vec::new();
+        let mut local_exports = FxHashMap::default();
+        let mut indirect_exports = FxHashMap::default();
+        let mut star_exports = Vec::new();
+        let mut default_expression = false;
+
+        for item in body.items() {
+            if let Node::ImportDecl(decl) = item {
+                for specifier in decl.specifiers() {
+                    import_entries.push((
+                        decl.module().into(),
+                        specifier.import_name().clone(),
+                        specifier.local_name().into(),
+                    ));
+                }
+            }
+        }
+
+        for item in body.items() {
+            let decl = match item {
+                Node::ExportDecl(decl) => decl,
+                _ => continue,
+            };
+            match decl {
+                ExportDecl::Declaration(decl) => {
+                    for name in declared_names(decl) {
+                        local_exports.insert(name.into(), name.into());
+                    }
+                }
+                ExportDecl::Default(decl) => {
+                    let name = declared_names(decl).pop().unwrap_or_else(|| {
+                        default_expression = true;
+                        DEFAULT_EXPORT_BINDING
+                    });
+                    local_exports.insert("default".into(), name.into());
+                }
+                ExportDecl::Named {
+                    specifiers,
+                    module: None,
+                } => {
+                    for specifier in specifiers.iter() {
+                        let local_name = specifier.local_name();
+                        let export_name = specifier.export_name().into();
+                        // Exporting an imported binding re-exports the binding of the imported
+                        // module, except for namespace objects, which are local bindings.
+                        match import_entries
+                            .iter()
+                            .find(|(_, _, local)| local.as_ref() == local_name)
+                        {
+                            Some((module, import_name @ ImportName::Name(_), _)) => {
+                                indirect_exports
+                                    .insert(export_name, (module.clone(), import_name.clone()));
+                            }
+                            _ => {
+                                local_exports.insert(export_name, local_name.into());
+                            }
+                        }
+                    }
+                }
+                ExportDecl::Named {
+                    specifiers,
+                    module: Some(module),
+                } => {
+                    for specifier in specifiers.iter() {
+                        indirect_exports.insert(
+                            specifier.export_name().into(),
+                            (
+                                module.clone(),
+                                ImportName::Name(specifier.local_name().into()),
+                            ),
+                        );
+                    }
+                }
+                ExportDecl::All {
+                    alias: Some(alias),
+                    module,
+                } => {
+                    indirect_exports.insert(alias.clone(), (module.clone(), ImportName::Namespace));
+                }
+                ExportDecl::All {
+                    alias: None,
+                    module,
+                } => star_exports.push(module.clone()),
+            }
+        }
+
+        Self(Rc::new(ModuleRecord {
+            name,
+            body,
+            import_bindings: environment.import_bindings.clone(),
+            environment: environment.into(),
+            status: Cell::new(Status::Unlinked),
+            error: RefCell::new(None),
+            namespace: RefCell::new(None),
+            requested_modules: RefCell::new(Vec::new()),
+            import_entries,
+            local_exports,
+            indirect_exports,
+            star_exports,
+            default_expression,
+        }))
+    }
+
+    /// Gets the name under which the requested module `specifier` was loaded.
+    fn requested_module(&self, specifier: &str) -> Box<str> {
+        self.0
+            .requested_modules
+            .borrow()
+            .iter()
+            .find(|(request, _)| request.as_ref() == specifier)
+            .map(|(_, name)| name.clone())
+            .expect("requested modules are loaded before linking")
+    }
+}
+
+/// The names of the bindings created by a declaration.
+fn declared_names(node: &Node) -> Vec<&str> {
+    match node {
+        Node::VarDeclList(list) | Node::LetDeclList(list) | Node::ConstDeclList(list) => {
+            list.as_ref().iter().map(|decl| decl.name()).collect()
+        }
+        Node::FunctionDecl(decl) => vec![decl.name()],
+        Node::ClassDecl(decl) => vec![decl.name()],
+        Node::GeneratorDecl(decl) => vec![decl.name()],
+        Node::AsyncFunctionDecl(decl) => decl.name().into_iter().collect(),
+        Node::AsyncGeneratorDecl(decl) => vec![decl.name()],
+        _ => Vec::new(),
+    }
+}
+
+/// The binding an export name resolves to.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#resolvedbinding-record
+#[derive(Debug, Clone)]
+enum ResolvedBinding {
+    Binding(Module, Box<str>),
+    Namespace(Module),
+    Ambiguous,
+}
+
+impl ResolvedBinding {
+    fn same_binding(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Binding(a, a_name), Self::Binding(b, b_name)) => a.ptr_eq(b) && a_name == b_name,
+            (Self::Namespace(a), Self::Namespace(b)) => a.ptr_eq(b),
+            _ => false,
+        }
+    }
+}
+
+impl Context {
+    /// Gets a loaded module by name.
+    fn loaded_module(&self, name: &str) -> Module {
+        self.modules
+            .records
+            .get(name)
+            .cloned()
+            .expect("requested modules are loaded before linking")
+    }
+
+    /// Resolves the module `specifier` with the module loader, and loads it along with the
+    /// modules it imports, unless it was already loaded.
+    fn load_module(&mut self, specifier: &str, referrer: Option<&str>) -> Result<Module> {
+        let loader = match self.modules.loader.clone() {
+            Some(loader) => loader,
+            None => {
+                return Err(self.construct_type_error(format!(
+                    "Cannot import module \"{}\" without a module loader",
+                    specifier
+                )))
+            }
+        };
+        let source = loader.resolve(specifier, referrer, self)?;
+        if let Some(module) = self.modules.records.get(source.name()) {
+            return Ok(module.clone());
+        }
+        self.create_module(Some(source.name), source.source.as_bytes())
+    }
+
+    /// Parses a module and loads the modules it imports.
+    ///
+    /// Named modules are cached before their imports are loaded, so that circular imports
+    /// resolve to the module that is already being loaded.
+    fn create_module(&mut self, name: Option<Box<str>>, src: &[u8]) -> Result<Module> {
+        let _timer = BoaProfiler::global().start_event("Module::create", "module");
+        let body = match Parser::new(src, true).parse_module() {
+            Ok(body) => body,
+            Err(e) => return Err(self.construct_syntax_error(e.to_string())),
+        };
+        let requests = Module::module_requests(&body);
+        let environment =
+            ModuleEnvironmentRecord::new(Some(self.get_global_environment()), name.clone());
+        let module = Module::new(name, body, environment);
+        if let Some(ref name) = module.0.name {
+            self.modules.records.insert(name.clone(), module.clone());
+        }
+
+        for request in requests {
+            let requested = self.load_module(&request, module.0.name.as_deref())?;
+            let requested_name = requested
+                .0
+                .name
+                .clone()
+                .expect("loaded modules have a name");
+            module
+                .0
+                .requested_modules
+                .borrow_mut()
+                .push((request, requested_name));
+        }
+        Ok(module)
+    }
+
+    /// Links the module and the modules it imports, creating the bindings of their imports.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduledeclarationlinking
+    fn link_module(&mut self, module: &Module) -> Result<()> {
+        if module.0.status.get() != Status::Unlinked {
+            return Ok(());
+        }
+        module.0.status.set(Status::Linking);
+
+        let requested: Vec<_> = module.0.requested_modules.borrow().clone();
+        for (_, name) in requested {
+            let requested = self.loaded_module(&name);
+            self.link_module(&requested)?;
+        }
+
+        let environment = module.0.environment.clone();
+        for (specifier, import_name, local_name) in module.0.import_entries.iter() {
+            let imported = self.loaded_module(&module.requested_module(specifier));
+            let resolution = match import_name {
+                ImportName::Namespace => ResolvedBinding::Namespace(imported),
+                ImportName::Name(name) => {
+                    match Self::resolve_export(&imported, name, &mut Vec::new(), self) {
+                        Some(ResolvedBinding::Ambiguous) => {
+                            return Err(self.construct_syntax_error(format!(
+                                "The requested module \"{}\" contains conflicting star exports for name \"{}\"",
+                                specifier, name
+                            )))
+                        }
+                        Some(resolution) => resolution,
+                        None => {
+                            return Err(self.construct_syntax_error(format!(
+                                "The requested module \"{}\" does not provide an export named \"{}\"",
+                                specifier, name
+                            )))
+                        }
+                    }
+                }
+            };
+            match resolution {
+                ResolvedBinding::Binding(target, binding_name) => {
+                    module.0.import_bindings.create_import_binding(
+                        local_name.to_string(),
+                        target.0.environment.clone(),
+                        &binding_name,
+                    );
+                }
+                ResolvedBinding::Namespace(target) => {
+                    let namespace = self.module_namespace(&target)?;
+                    environment.create_immutable_binding(local_name.to_string(), true, self)?;
+                    environment.initialize_binding(local_name, namespace.into(), self)?;
+                }
+                ResolvedBinding::Ambiguous => unreachable!("ambiguous resolutions are rejected"),
+            }
+        }
+
+        if module.0.default_expression {
+            environment.create_mutable_binding(
+                DEFAULT_EXPORT_BINDING.to_owned(),
+                false,
+                false,
+                self,
+            )?;
+        }
+
+        module.0.status.set(Status::Linked);
+        Ok(())
+    }
+
+    /// Evaluates the module after the modules it imports, unless it was already evaluated.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-moduleevaluation
+    fn evaluate_module(&mut self, module: &Module) -> Result<()> {
+        match module.0.status.get() {
+            Status::Evaluated => {
+                return match *module.0.error.borrow() {
+                    Some(ref error) => Err(error.clone()),
+                    None => Ok(()),
+                }
+            }
+            // The module is being evaluated, and imports itself through a cycle.
+            Status::Evaluating => return Ok(()),
+            _ => {}
+        }
+        module.0.status.set(Status::Evaluating);
+
+        let requested: Vec<_> = module.0.requested_modules.borrow().clone();
+        let mut result = Ok(Value::undefined());
+        for (_, name) in requested {
+            let requested = self.loaded_module(&name);
+            if let Err(error) = self.evaluate_module(&requested) {
+                result = Err(error);
+                break;
+            }
+        }
+
+        if result.is_ok() {
+            self.push_environment(module.0.environment.clone());
+            result = module.0.body.run_body(self);
+            self.pop_environment();
+            self.executor()
+                .set_current_state(InterpreterState::Executing);
+        }
+
+        module.0.status.set(Status::Evaluated);
+        result.map(|_| ()).map_err(|error| {
+            *module.0.error.borrow_mut() = Some(error.clone());
+            error
+        })
+    }
+
+    /// Resolves an export name of the module to the binding that holds its value.
+    ///
+    /// Returns `None` if the module doesn't export the name, or if the name is exported through
+    /// a circular re-export.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-resolveexport
+    fn resolve_export(
+        module: &Module,
+        export_name: &str,
+        resolve_set: &mut Vec<(Module, Box<str>)>,
+        context: &Context,
+    ) -> Option<ResolvedBinding> {
+        if resolve_set
+            .iter()
+            .any(|(resolved, name)| resolved.ptr_eq(module) && name.as_ref() == export_name)
+        {
+            return None;
+        }
+        resolve_set.push((module.clone(), export_name.into()));
+
+        if let Some(local_name) = module.0.local_exports.get(export_name) {
+            return Some(ResolvedBinding::Binding(module.clone(), local_name.clone()));
+        }
+
+        if let Some((specifier, import_name)) = module.0.indirect_exports.get(export_name) {
+            let imported = context.loaded_module(&module.requested_module(specifier));
+            return match import_name {
+                ImportName::Namespace => Some(ResolvedBinding::Namespace(imported)),
+                ImportName::Name(name) => {
+                    Self::resolve_export(&imported, name, resolve_set, context)
+                }
+            };
+        }
+
+        // A default export is never re-exported by `export *`.
+        if export_name == "default" {
+            return None;
+        }
+
+        let mut star_resolution: Option<ResolvedBinding> = None;
+        for specifier in module.0.star_exports.iter() {
+            let imported = context.loaded_module(&module.requested_module(specifier));
+            match Self::resolve_export(&imported, export_name, resolve_set, context) {
+                Some(ResolvedBinding::Ambiguous) => return Some(ResolvedBinding::Ambiguous),
+                Some(resolution) => match star_resolution {
+                    Some(ref star) if !star.same_binding(&resolution) => {
+                        return Some(ResolvedBinding::Ambiguous)
+                    }
+                    Some(_) => {}
+                    None => star_resolution = Some(resolution),
+                },
+                None => {}
+            }
+        }
+        star_resolution
+    }
+
+    /// Gets the names exported by the module, including the names re-exported with `export *`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getexportednames
+    fn exported_names(&self, module: &Module, export_star_set: &mut Vec<Module>) -> Vec<Box<str>> {
+        if export_star_set.iter().any(|visited| visited.ptr_eq(module)) {
+            return Vec::new();
+        }
+        export_star_set.push(module.clone());
+
+        let mut names: Vec<Box<str>> = module
+            .0
+            .local_exports
+            .keys()
+            .chain(module.0.indirect_exports.keys())
+            .cloned()
+            .collect();
+        for specifier in module.0.star_exports.iter() {
+            let imported = self.loaded_module(&module.requested_module(specifier));
+            for name in self.exported_names(&imported, export_star_set) {
+                if name.as_ref() != "default" && !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        names
+    }
+
+    /// Gets the namespace object of the module, creating it the first time.
+    ///
+    /// Every unambiguous export is an enumerable accessor property that reads the current value
+    /// of the exported binding.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getmodulenamespace
+    fn module_namespace(&mut self, module: &Module) -> Result<GcObject> {
+        if let Some(ref namespace) = *module.0.namespace.borrow() {
+            return Ok(namespace.clone());
+        }
+
+        let namespace = GcObject::new(Object::create(Value::null()));
+        *module.0.namespace.borrow_mut() = Some(namespace.clone());
+
+        let mut names = self.exported_names(module, &mut Vec::new());
+        names.sort();
+        for name in names {
+            match Self::resolve_export(module, &name, &mut Vec::new(), self) {
+                Some(ResolvedBinding::Binding(target, binding_name)) => {
+                    let getter = FunctionBuilder::closure_with_captures(
+                        self,
+                        |_, _, binding: &ImportBinding, context| {
+                            if binding.environment.has_binding(&binding.name) {
+                                binding
+                                    .environment
+                                    .get_binding_value(&binding.name, true, context)
+                            } else {
+                                context.throw_reference_error(format!(
+                                    "{} is an uninitialized binding",
+                                    binding.name
+                                ))
+                            }
+                        },
+                        ImportBinding {
+                            environment: target.0.environment.clone(),
+                            name: binding_name,
+                        },
+                    )
+                    .build();
+                    namespace.borrow_mut().insert(
+                        name,
+                        AccessorDescriptor::new(Some(getter), None, Attribute::ENUMERABLE),
+                    );
+                }
+                Some(ResolvedBinding::Namespace(target)) => {
+                    let value = self.module_namespace(&target)?;
+                    namespace.borrow_mut().insert(
+                        name,
+                        DataDescriptor::new(value, Attribute::WRITABLE | Attribute::ENUMERABLE),
+                    );
+                }
+                Some(ResolvedBinding::Ambiguous) | None => {}
+            }
+        }
+        namespace.borrow_mut().insert(
+            WellKnownSymbols::to_string_tag(),
+            DataDescriptor::new("Module", Attribute::empty()),
+        );
+        namespace.clone().prevent_extensions();
+
+        Ok(namespace)
+    }
+
+    /// Gets the `import.meta` object of the module being evaluated, creating it the first time.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-meta-properties-runtime-semantics-evaluation
+    pub(crate) fn import_meta(&mut self) -> Result<Value> {
+        let import_meta = match self.get_current_environment().recursive_get_import_meta() {
+            Some(import_meta) => import_meta,
+            None => {
+                return Err(self.construct_syntax_error("Cannot use 'import.meta' outside a module"))
+            }
+        };
+        if let Some(ref object) = *import_meta.object.borrow() {
+            return Ok(object.clone().into());
+        }
+
+        let object = GcObject::new(Object::create(Value::null()));
+        *import_meta.object.borrow_mut() = Some(object.clone());
+        if let Some(loader) = self.modules.loader.clone() {
+            loader.import_meta(import_meta.module.as_deref(), &object, self)?;
+        }
+        Ok(object.into())
+    }
+
+    /// Links and evaluates a loaded module, and returns its namespace object.
+    fn run_module(&mut self, module: &Module) -> Result<Value> {
+        self.link_module(module)?;
+        self.evaluate_module(module)?;
+        self.module_namespace(module).map(Value::from)
+    }
+
+    /// Evaluates the source code of an anonymous module, and returns its namespace object.
+    pub(crate) fn run_module_source(&mut self, src: &[u8]) -> Result<Value> {
+        let module = self.create_module(None, src)?;
+        self.run_module(&module)
+    }
+
+    /// Imports the module `specifier` with the module loader, and returns its namespace object.
+    pub(crate) fn run_module_specifier(&mut self, specifier: &str) -> Result<Value> {
+        let module = self.load_module(specifier, None)?;
+        self.run_module(&module)
+    }
+}
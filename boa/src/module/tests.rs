@@ -0,0 +1,380 @@
+use super::{ModuleLoader, ModuleSource};
+use crate::{forward, object::GcObject, Context, Result, Value};
+use std::{cell::RefCell, rc::Rc};
+
+/// A loader that serves modules from memory, and records the imports it resolves.
+#[derive(Debug, Default)]
+struct MemoryLoader {
+    modules: Vec<(&'static str, &'static str)>,
+    resolved: Rc<RefCell<Vec<String>>>,
+}
+
+impl MemoryLoader {
+    fn new(modules: &[(&'static str, &'static str)]) -> Self {
+        Self {
+            modules: modules.to_vec(),
+            resolved: Rc::default(),
+        }
+    }
+}
+
+impl ModuleLoader for MemoryLoader {
+    fn resolve(
+        &self,
+        specifier: &str,
+        referrer: Option<&str>,
+        context: &mut Context,
+    ) -> Result<ModuleSource> {
+        self.resolved.borrow_mut().push(format!(
+            "{} from {}",
+            specifier,
+            referrer.unwrap_or("<main>")
+        ));
+        match self.modules.iter().find(|(name, _)| *name == specifier) {
+            Some((name, source)) => Ok(ModuleSource::new(*name, *source)),
+            None => Err(context.construct_type_error(format!("Cannot find module {}", specifier))),
+        }
+    }
+
+    fn import_meta(
+        &self,
+        module: Option<&str>,
+        meta: &GcObject,
+        context: &mut Context,
+    ) -> Result<()> {
+        let url = format!("memory:{}", module.unwrap_or("<main>"));
+        Value::from(meta.clone()).set_field("url", url, false, context)?;
+        Ok(())
+    }
+}
+
+fn eval_module(context: &mut Context, src: &str) -> String {
+    context.eval_module(src).map_or_else(
+        |e| format!("Uncaught {}", e.display()),
+        |v| v.display().to_string(),
+    )
+}
+
+fn get(context: &mut Context, namespace: &Value, name: &str) -> String {
+    namespace
+        .get_field(name, context)
+        .unwrap()
+        .display()
+        .to_string()
+}
+
+#[test]
+fn import_host_function() {
+    let mut context = Context::new();
+    context.set_module_loader(MemoryLoader::new(&[(
+        "math",
+        "export function add(a, b) { return a + b; }",
+    )]));
+
+    let namespace = context
+        .eval_module("import { add } from 'math'; export const sum = add(1, 2);")
+        .unwrap();
+    assert_eq!(get(&mut context, &namespace, "sum"), "3");
+}
+
+#[test]
+fn live_bindings() {
+    let mut context = Context::new();
+    context.set_module_loader(MemoryLoader::new(&[(
+        "counter",
+        r#"
+        export let count = 0;
+        export function increment() {
+            count += 1;
+        }
+        "#,
+    )]));
+
+    let init = r#"
+        import { count, increment } from "counter";
+        import * as counter from "counter";
+        export let log = [count];
+        increment();
+        log.push(count, counter.count);
+        "#;
+    let namespace = context.eval_module(init).unwrap();
+    assert_eq!(
+        forward_log(&mut context, &namespace),
+        "\"0,1,1\"",
+        "imports read the current value of the exported binding"
+    );
+
+    let counter = context.import_module("counter").unwrap();
+    let increment = counter.get_field("increment", &mut context).unwrap();
+    context.call(&increment, &Value::undefined(), &[]).unwrap();
+    assert_eq!(get(&mut context, &counter, "count"), "2");
+}
+
+fn forward_log(context: &mut Context, namespace: &Value) -> String {
+    let log = namespace.get_field("log", context).unwrap();
+    let join = log.get_field("join", context).unwrap();
+    context
+        .call(&join, &log, &[])
+        .unwrap()
+        .display()
+        .to_string()
+}
+
+#[test]
+fn default_and_renamed_imports() {
+    let mut context = Context::new();
+    context.set_module_loader(MemoryLoader::new(&[
+        (
+            "greeter",
+            r#"
+            export default function greet(name) { return "hello " + name; }
+            const punctuation = "!";
+            export { punctuation as mark };
+            "#,
+        ),
+        ("answer", "export default 6 * 7;"),
+    ]));
+
+    let init = r#"
+        import greet, { mark as exclamation } from "greeter";
+        import answer from "answer";
+        export const greeting = greet("world") + exclamation;
+        export { answer };
+        "#;
+    let namespace = context.eval_module(init).unwrap();
+    assert_eq!(
+        get(&mut context, &namespace, "greeting"),
+        "\"hello world!\""
+    );
+    assert_eq!(get(&mut context, &namespace, "answer"), "42");
+}
+
+#[test]
+fn default_export_names() {
+    let mut context = Context::new();
+    context.set_module_loader(MemoryLoader::new(&[
+        ("arrow", "export default () => {};"),
+        ("function", "export default function () {}"),
+        ("class", "export default class {}"),
+        ("named", "export default function greet() {}"),
+    ]));
+
+    let init = r#"
+        import arrow from "arrow";
+        import func from "function";
+        import cls from "class";
+        import named from "named";
+        export const names = [arrow.name, func.name, cls.name, named.name].join();
+        "#;
+    let namespace = context.eval_module(init).unwrap();
+    assert_eq!(
+        get(&mut context, &namespace, "names"),
+        "\"default,default,default,greet\""
+    );
+}
+
+#[test]
+fn re_exports() {
+    let mut context = Context::new();
+    context.set_module_loader(MemoryLoader::new(&[
+        ("a", "export const a = 'a'; export default 'default a';"),
+        ("b", "export var b = 'b';"),
+        (
+            "index",
+            r#"
+            export * from "a";
+            export { b as renamed } from "b";
+            export * as ns from "b";
+            "#,
+        ),
+    ]));
+
+    let init = r#"
+        import { a, renamed, ns } from "index";
+        import * as index from "index";
+        export const values = [a, renamed, ns.b, index.default === undefined];
+        "#;
+    let namespace = context.eval_module(init).unwrap();
+    let values = namespace.get_field("values", &mut context).unwrap();
+    let join = values.get_field("join", &mut context).unwrap();
+    let result = context.call(&join, &values, &[]).unwrap();
+    assert_eq!(result.display().to_string(), "\"a,b,b,true\"");
+}
+
+#[test]
+fn namespace_object() {
+    let mut context = Context::new();
+    context.set_module_loader(MemoryLoader::new(&[(
+        "lib",
+        "export let z = 1; export const a = 2; export default 3;",
+    )]));
+
+    let init = r#"
+        import * as lib from "lib";
+        let keys = [];
+        for (let key in lib) {
+            keys.push(key);
+        }
+        export const result = [
+            keys.join(),
+            Object.prototype.toString.call(lib),
+            Object.getPrototypeOf(lib) === null,
+            Reflect.isExtensible(lib),
+        ].join(" ");
+        "#;
+    let namespace = context.eval_module(init).unwrap();
+    assert_eq!(
+        get(&mut context, &namespace, "result"),
+        "\"a,default,z [object Module] true false\""
+    );
+}
+
+#[test]
+fn modules_are_evaluated_once() {
+    let mut context = Context::new();
+    let loader = MemoryLoader::new(&[
+        ("log", "export const log = [];"),
+        ("a", "import { log } from 'log'; log.push('a');"),
+        ("b", "import { log } from 'log'; import 'a'; log.push('b');"),
+    ]);
+    let resolved = loader.resolved.clone();
+    context.set_module_loader(loader);
+
+    let init = r#"
+        import "b";
+        import "a";
+        import { log } from "log";
+        export const order = log.join();
+        "#;
+    let namespace = context.eval_module(init).unwrap();
+    assert_eq!(get(&mut context, &namespace, "order"), "\"a,b\"");
+    assert_eq!(
+        resolved.borrow().join(", "),
+        "b from <main>, log from b, a from b, log from a, a from <main>, log from <main>"
+    );
+}
+
+#[test]
+fn circular_imports() {
+    let mut context = Context::new();
+    context.set_module_loader(MemoryLoader::new(&[
+        (
+            "even",
+            r#"
+            import { isOdd } from "odd";
+            export function isEven(n) { return n === 0 ? true : isOdd(n - 1); }
+            "#,
+        ),
+        (
+            "odd",
+            r#"
+            import { isEven } from "even";
+            export function isOdd(n) { return n === 0 ? false : isEven(n - 1); }
+            "#,
+        ),
+    ]));
+
+    let namespace = context
+        .eval_module("import { isEven } from 'even'; export const result = isEven(10);")
+        .unwrap();
+    assert_eq!(get(&mut context, &namespace, "result"), "true");
+}
+
+#[test]
+fn module_scope() {
+    let mut context = Context::new();
+    context.set_module_loader(MemoryLoader::new(&[(
+        "private",
+        "var hidden = 1; export const visible = hidden + 1;",
+    )]));
+
+    let init = r#"
+        import { visible } from "private";
+        export const result = [typeof hidden, visible, this === undefined].join();
+        "#;
+    let namespace = context.eval_module(init).unwrap();
+    assert_eq!(
+        get(&mut context, &namespace, "result"),
+        "\"undefined,2,true\""
+    );
+}
+
+#[test]
+fn import_errors() {
+    let mut context = Context::new();
+    assert_eq!(
+        eval_module(&mut context, "import { a } from 'missing';"),
+        "Uncaught \"TypeError\": \"Cannot import module \"missing\" without a module loader\""
+    );
+
+    context.set_module_loader(MemoryLoader::new(&[
+        ("lib", "export const a = 1;"),
+        ("throws", "throw new Error('failed');"),
+    ]));
+    assert_eq!(
+        eval_module(&mut context, "import { b } from 'lib';"),
+        "Uncaught \"SyntaxError\": \"The requested module \"lib\" does not provide an export named \"b\"\""
+    );
+    assert_eq!(
+        eval_module(&mut context, "import { a } from 'missing';"),
+        "Uncaught \"TypeError\": \"Cannot find module missing\""
+    );
+    assert_eq!(
+        eval_module(&mut context, "import { a } from 'lib'; a = 2;"),
+        "Uncaught \"TypeError\": \"Cannot assign to import binding a\""
+    );
+    assert_eq!(
+        eval_module(&mut context, "import 'throws';"),
+        "Uncaught \"Error\": \"failed\""
+    );
+    assert_eq!(
+        eval_module(&mut context, "import 'throws';"),
+        "Uncaught \"Error\": \"failed\"",
+        "the error of a module is thrown again by later imports"
+    );
+}
+
+#[test]
+fn import_meta() {
+    let mut context = Context::new();
+    context.set_module_loader(MemoryLoader::new(&[(
+        "lib",
+        r#"
+        export const url = import.meta.url;
+        export function meta() {
+            return import.meta;
+        }
+        "#,
+    )]));
+
+    let namespace = context
+        .eval_module(
+            r#"
+            import { url, meta } from 'lib';
+            export const libUrl = url;
+            export const sameObject = meta() === meta() && meta() !== import.meta;
+            export const mainUrl = import.meta.url;
+            "#,
+        )
+        .unwrap();
+    assert_eq!(get(&mut context, &namespace, "libUrl"), "\"memory:lib\"");
+    assert_eq!(get(&mut context, &namespace, "sameObject"), "true");
+    assert_eq!(
+        get(&mut context, &namespace, "mainUrl"),
+        "\"memory:<main>\""
+    );
+}
+
+#[test]
+fn import_meta_outside_module() {
+    let mut context = Context::new();
+    assert!(forward(&mut context, "import.meta").starts_with("Uncaught \"SyntaxError\": "));
+    assert!(
+        forward(&mut context, "function f() { return import.meta; }")
+            .starts_with("Uncaught \"SyntaxError\": ")
+    );
+    let namespace = context
+        .eval_module("export const meta = typeof import.meta;")
+        .unwrap();
+    assert_eq!(get(&mut context, &namespace, "meta"), "\"object\"");
+}
@@ -4,29 +4,47 @@
 
 use super::{NativeObject, Object, PROTOTYPE};
 use crate::{
-    builtins::function::{
-        create_unmapped_arguments_object, BuiltInFunction, Function, NativeFunction,
+    builtins::{
+        async_function::AsyncFunction,
+        async_generator::AsyncGenerator,
+        function::{
+            create_mapped_arguments_object, create_unmapped_arguments_object, BuiltInFunction,
+            ClosureFunction, Function, NativeFunction,
+        },
+        init_all_lazy, Array, Generator,
     },
     environment::{
-        environment_record_trait::EnvironmentRecordTrait,
         function_environment_record::{BindingStatus, FunctionEnvironmentRecord},
         lexical_environment::Environment,
     },
+    exec::InterpreterState,
     property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
-    syntax::ast::node::RcStatementList,
+    syntax::ast::node::{FormalParameter, RcStatementList},
     value::PreferredType,
-    Context, Executable, Result, Value,
+    Context, Result, Value,
 };
 use gc::{Finalize, Gc, GcCell, GcCellRef, GcCellRefMut, Trace};
 use serde_json::{map::Map, Value as JSONValue};
 use std::{
-    cell::RefCell,
+    cell::{Cell, RefCell},
     collections::HashMap,
     error::Error,
     fmt::{self, Debug, Display},
+    rc::Rc,
     result::Result as StdResult,
 };
 
+/// The amount of native stack that must be left when a function is called, below which the call
+/// is executed on a new stack segment.
+///
+/// It must hold the evaluation of a function body up to its next call, which uses a few tens of
+/// kilobytes with an unoptimized build.
+const CALL_STACK_RED_ZONE: usize = 256 * 1024;
+
+/// The size of the stack segments allocated for the nested function calls that don't fit on the
+/// stack of the thread.
+const CALL_STACK_SEGMENT_SIZE: usize = 4 * 1024 * 1024;
+
 /// A wrapper type for an immutably borrowed type T.
 pub type Ref<'a, T> = GcCellRef<'a, T>;
 
@@ -34,9 +52,44 @@ pub type Ref<'a, T> = GcCellRef<'a, T>;
 pub type RefMut<'a, T, U> = GcCellRefMut<'a, T, U>;
 
 /// Garbage collected `Object`.
-#[derive(Trace, Finalize, Clone, Default)]
+#[derive(Trace, Finalize, Clone)]
 pub struct GcObject(Gc<GcCell<Object>>);
 
+thread_local! {
+    /// The counter of the context running on this thread, which the new objects count towards.
+    static CURRENT_COUNTER: RefCell<Option<ObjectCounter>> = RefCell::new(None);
+}
+
+/// The number of objects allocated while a context is running that haven't been collected yet.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct ObjectCounter(Rc<Cell<usize>>);
+
+impl ObjectCounter {
+    /// Makes the objects allocated on the current thread count towards this counter.
+    #[inline]
+    pub(crate) fn enter(&self) {
+        CURRENT_COUNTER.with(|current| {
+            let mut current = current.borrow_mut();
+            match *current {
+                Some(ref counter) if Rc::ptr_eq(&counter.0, &self.0) => {}
+                _ => *current = Some(self.clone()),
+            }
+        });
+    }
+
+    /// Gets the number of objects counted that haven't been collected yet.
+    #[inline]
+    pub(crate) fn get(&self) -> usize {
+        self.0.get()
+    }
+
+    /// Records that an object counted towards this counter has been collected.
+    #[inline]
+    pub(super) fn record_collected(&self) {
+        self.0.set(self.0.get().saturating_sub(1));
+    }
+}
+
 /// The body of a JavaScript function.
 ///
 /// This is needed for the call method since we cannot mutate the function itself since we
@@ -44,13 +97,31 @@ pub struct GcObject(Gc<GcCell<Object>>);
 enum FunctionBody {
     BuiltInFunction(NativeFunction),
     BuiltInConstructor(NativeFunction),
+    Closure {
+        function: ClosureFunction,
+        constructor: bool,
+    },
     Ordinary(RcStatementList),
+    Generator(RcStatementList),
+    AsyncFunction(RcStatementList),
+    AsyncGenerator(RcStatementList),
+}
+
+impl Default for GcObject {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Object::default())
+    }
 }
 
 impl GcObject {
     /// Create a new `GcObject` from a `Object`.
     #[inline]
-    pub fn new(object: Object) -> Self {
+    pub fn new(mut object: Object) -> Self {
+        object.counter = CURRENT_COUNTER.with(|current| current.borrow().clone());
+        if let Some(ref counter) = object.counter {
+            counter.0.set(counter.0.get() + 1);
+        }
         Self(Gc::new(GcCell::new(object)))
     }
 
@@ -119,6 +190,9 @@ impl GcObject {
     /// <https://tc39.es/ecma262/#sec-ordinarycallbindthis>
     /// <https://tc39.es/ecma262/#sec-runtime-semantics-evaluatebody>
     /// <https://tc39.es/ecma262/#sec-ordinarycallevaluatebody>
+    ///
+    /// The call is executed on a new stack segment when the native stack is nearly exhausted, so
+    /// the depth of the nested calls is only bounded by the maximum call stack size of the context.
     #[track_caller]
     fn call_construct(
         &self,
@@ -127,6 +201,38 @@ impl GcObject {
         context: &mut Context,
         construct: bool,
     ) -> Result<Value> {
+        stacker::maybe_grow(CALL_STACK_RED_ZONE, CALL_STACK_SEGMENT_SIZE, || {
+            self.call_construct_on_stack(this_target, args, context, construct)
+        })
+    }
+
+    /// Executes a call or a construction on the current native stack.
+    #[track_caller]
+    fn call_construct_on_stack(
+        &self,
+        this_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+        construct: bool,
+    ) -> Result<Value> {
+        let bound_function = self.borrow().as_bound_function().cloned();
+        if let Some(bound_function) = bound_function {
+            return if construct {
+                bound_function.construct(self, args, this_target, context)
+            } else {
+                bound_function.call(args, context)
+            };
+        }
+
+        let proxy = self.borrow().as_proxy().cloned();
+        if let Some(proxy) = proxy {
+            return if construct {
+                proxy.construct(args, this_target, context)
+            } else {
+                proxy.call(this_target, args, context)
+            };
+        }
+
         let this_function_object = self.clone();
         let mut has_parameter_expressions = false;
 
@@ -139,6 +245,14 @@ impl GcObject {
                 return context.throw_type_error(format!("{} is not a constructor", name));
             } else if !construct && !function.is_callable() {
                 return context.throw_type_error("function object is not callable");
+            } else if !construct && function.is_class_constructor() {
+                let name = self
+                    .get(&"name".into(), self.clone().into(), context)?
+                    .to_string(context)?;
+                return context.throw_type_error(format!(
+                    "Class constructor {} cannot be invoked without 'new'",
+                    name
+                ));
             } else {
                 match function {
                     Function::BuiltIn(BuiltInFunction(function), flags) => {
@@ -148,12 +262,19 @@ impl GcObject {
                             FunctionBody::BuiltInFunction(*function)
                         }
                     }
+                    Function::Closure(function, flags) => FunctionBody::Closure {
+                        function: function.clone(),
+                        constructor: flags.is_constructable(),
+                    },
                     Function::Ordinary {
                         body,
                         params,
                         environment,
                         flags,
+                        home_object,
                     } => {
+                        context.check_call_stack_size()?;
+
                         let this = if construct {
                             // If the prototype of the constructor is not an object, then use the default object
                             // prototype as prototype for the new object
@@ -174,13 +295,27 @@ impl GcObject {
                                     .into()
                             };
                             Value::from(Object::create(proto))
+                        } else if body.strict() || flags.is_lexical_this_mode() {
+                            this_target.clone()
+                        } else if this_target.is_null_or_undefined() {
+                            // Sloppy mode functions use the global object when called without a
+                            // `this` value, and box primitive `this` values.
+                            // <https://tc39.es/ecma262/#sec-ordinarycallbindthis>
+                            init_all_lazy(context);
+                            context.global_object().into()
                         } else {
+                            this_target.to_object(context)?.into()
+                        };
+
+                        let new_target = if construct {
                             this_target.clone()
+                        } else {
+                            Value::undefined()
                         };
 
                         // Create a new Function environment whose parent is set to the scope of the function declaration (self.environment)
                         // <https://tc39.es/ecma262/#sec-prepareforordinarycall>
-                        let local_env = FunctionEnvironmentRecord::new(
+                        let mut local_env = FunctionEnvironmentRecord::new(
                             this_function_object.clone(),
                             if construct || !flags.is_lexical_this_mode() {
                                 Some(this.clone())
@@ -194,16 +329,19 @@ impl GcObject {
                             } else {
                                 BindingStatus::Uninitialized
                             },
-                            Value::undefined(),
+                            new_target.clone(),
                         );
+                        local_env.home_object = home_object
+                            .clone()
+                            .map_or_else(Value::undefined, Value::from);
 
                         let mut arguments_in_parameter_names = false;
 
                         for param in params.iter() {
                             has_parameter_expressions =
-                                has_parameter_expressions || param.init().is_some();
-                            arguments_in_parameter_names =
-                                arguments_in_parameter_names || param.name() == "arguments";
+                                has_parameter_expressions || param.contains_expression();
+                            arguments_in_parameter_names = arguments_in_parameter_names
+                                || param.names().contains(&"arguments");
                         }
 
                         // An arguments object is added when all of the following conditions are met
@@ -212,14 +350,31 @@ impl GcObject {
                         // - If there are default parameters or if lexical names and function names do not contain `arguments` (10.2.11.18)
                         //
                         // https://tc39.es/ecma262/#sec-functiondeclarationinstantiation
-                        if !flags.is_lexical_this_mode()
+                        let arguments_object_needed = !flags.is_lexical_this_mode()
                             && !arguments_in_parameter_names
                             && (has_parameter_expressions
                                 || (!body.lexically_declared_names().contains("arguments")
-                                    && !body.function_declared_names().contains("arguments")))
-                        {
-                            // Add arguments object
-                            let arguments_obj = create_unmapped_arguments_object(args);
+                                    && !body.function_declared_names().contains("arguments")));
+
+                        // Turn local_env into Environment so it can be cloned
+                        let local_env: Environment = local_env.into();
+
+                        if arguments_object_needed {
+                            // The arguments of sloppy mode functions with a simple parameter
+                            // list are aliased to the parameters.
+                            let arguments_obj = if body.strict()
+                                || !params.iter().all(FormalParameter::is_simple)
+                            {
+                                create_unmapped_arguments_object(args, context)
+                            } else {
+                                create_mapped_arguments_object(
+                                    &this_function_object,
+                                    params,
+                                    args,
+                                    &local_env,
+                                    context,
+                                )
+                            };
                             local_env.create_mutable_binding(
                                 "arguments".to_string(),
                                 false,
@@ -229,31 +384,38 @@ impl GcObject {
                             local_env.initialize_binding("arguments", arguments_obj, context)?;
                         }
 
-                        // Turn local_env into Environment so it can be cloned
-                        let local_env: Environment = local_env.into();
-
                         // Push the environment first so that it will be used by default parameters
                         context.push_environment(local_env.clone());
 
+                        // Create the bindings of all the parameters before initializing them,
+                        // so a default value can't read the parameters that come after it.
+                        for name in params.iter().flat_map(|param| param.names()) {
+                            local_env.create_mutable_binding(
+                                name.to_owned(),
+                                false,
+                                true,
+                                context,
+                            )?;
+                        }
+
                         // Add argument bindings to the function environment
                         for (i, param) in params.iter().enumerate() {
                             // Rest Parameters
                             if param.is_rest_param() {
-                                function.add_rest_param(param, i, args, context, &local_env);
+                                function.add_rest_param(param, i, args, context, &local_env)?;
                                 break;
                             }
 
                             let value = match args.get(i).cloned() {
-                                None | Some(Value::Undefined) => param
-                                    .init()
-                                    .map(|init| init.run(context).ok())
-                                    .flatten()
-                                    .unwrap_or_default(),
+                                None | Some(Value::Undefined) => match param.init() {
+                                    Some(init) => param.binding().run_default(init, context)?,
+                                    None => Value::undefined(),
+                                },
                                 Some(value) => value,
                             };
 
                             function
-                                .add_arguments_to_environment(param, value, &local_env, context);
+                                .add_arguments_to_environment(param, value, &local_env, context)?;
                         }
 
                         if has_parameter_expressions {
@@ -275,12 +437,17 @@ impl GcObject {
                                 } else {
                                     BindingStatus::Uninitialized
                                 },
-                                Value::undefined(),
+                                new_target,
                             );
                             context.push_environment(second_env);
                         }
 
-                        FunctionBody::Ordinary(body.clone())
+                        match (flags.is_async(), flags.is_generator()) {
+                            (false, false) => FunctionBody::Ordinary(body.clone()),
+                            (false, true) => FunctionBody::Generator(body.clone()),
+                            (true, false) => FunctionBody::AsyncFunction(body.clone()),
+                            (true, true) => FunctionBody::AsyncGenerator(body.clone()),
+                        }
                     }
                 }
             }
@@ -296,8 +463,25 @@ impl GcObject {
                 function(&Value::undefined(), args, context)
             }
             FunctionBody::BuiltInFunction(function) => function(this_target, args, context),
+            FunctionBody::Closure {
+                function,
+                constructor,
+            } if constructor && !construct => function.call(&Value::undefined(), args, context),
+            FunctionBody::Closure { function, .. } => function.call(this_target, args, context),
             FunctionBody::Ordinary(body) => {
-                let result = body.run(context);
+                context.executor().push_frame(self.frame_name());
+                let result = body.run_body(context);
+                context.executor().pop_frame();
+                // A function returns undefined, unless its body ended with a `return`.
+                let returned = matches!(
+                    context.executor().get_current_state(),
+                    InterpreterState::Return
+                );
+                let result = result.map(|value| if returned { value } else { Value::undefined() });
+                // A `return` in the body must not end statement lists outside of the function.
+                context
+                    .executor()
+                    .set_current_state(InterpreterState::Executing);
                 let this = context.get_this_binding();
 
                 if has_parameter_expressions {
@@ -306,11 +490,56 @@ impl GcObject {
                 context.pop_environment();
 
                 if construct {
-                    this
+                    // A constructor returns the object it created, unless its body returns
+                    // another object.
+                    // <https://tc39.es/ecma262/#sec-ecmascript-function-objects-construct-argumentslist>
+                    match result? {
+                        result @ Value::Object(_) => Ok(result),
+                        _ => this,
+                    }
                 } else {
                     result
                 }
             }
+            FunctionBody::Generator(body) => {
+                // The body is executed by the generator object, in the environments of this call.
+                let environments = Self::take_call_environments(has_parameter_expressions, context);
+                Generator::create(self, self.frame_name(), body, environments, context)
+            }
+            FunctionBody::AsyncFunction(body) => {
+                let environments = Self::take_call_environments(has_parameter_expressions, context);
+                AsyncFunction::start(self.frame_name(), body, environments, context)
+            }
+            FunctionBody::AsyncGenerator(body) => {
+                let environments = Self::take_call_environments(has_parameter_expressions, context);
+                AsyncGenerator::create(self, self.frame_name(), body, environments, context)
+            }
+        }
+    }
+
+    /// Pops the environments pushed for a call, from the outermost to the innermost, for a body
+    /// that is executed later.
+    fn take_call_environments(
+        has_parameter_expressions: bool,
+        context: &mut Context,
+    ) -> Vec<Environment> {
+        let mut environments = Vec::new();
+        if has_parameter_expressions {
+            environments.extend(context.pop_environment());
+        }
+        environments.extend(context.pop_environment());
+        environments.reverse();
+        environments
+    }
+
+    /// Gets the name of the function used in the frames of the call stack.
+    fn frame_name(&self) -> Box<str> {
+        let name = self
+            .get_own_property(&"name".into())
+            .and_then(|property| Some(property.as_data_descriptor()?.value()));
+        match name {
+            Some(Value::String(ref name)) if !name.is_empty() => name.as_str().into(),
+            _ => "<anonymous>".into(),
         }
     }
 
@@ -456,84 +685,97 @@ impl GcObject {
     ///
     /// Panics if the object is currently mutably borrowed.
     pub fn to_property_descriptor(&self, context: &mut Context) -> Result<PropertyDescriptor> {
-        let mut attribute = Attribute::empty();
-
-        let enumerable_key = PropertyKey::from("enumerable");
-        if self.has_property(&enumerable_key)
-            && self
-                .get(&enumerable_key, self.clone().into(), context)?
-                .to_boolean()
-        {
-            attribute |= Attribute::ENUMERABLE;
-        }
-
-        let configurable_key = PropertyKey::from("configurable");
-        if self.has_property(&configurable_key)
-            && self
-                .get(&configurable_key, self.clone().into(), context)?
-                .to_boolean()
-        {
-            attribute |= Attribute::CONFIGURABLE;
-        }
-
-        let mut value = None;
-        let value_key = PropertyKey::from("value");
-        if self.has_property(&value_key) {
-            value = Some(self.get(&value_key, self.clone().into(), context)?);
-        }
+        self.to_property_descriptor_for(None, context)
+    }
 
-        let mut has_writable = false;
-        let writable_key = PropertyKey::from("writable");
-        if self.has_property(&writable_key) {
-            has_writable = true;
-            if self
-                .get(&writable_key, self.clone().into(), context)?
-                .to_boolean()
-            {
-                attribute |= Attribute::WRITABLE;
+    /// Convert the object to a `PropertyDescriptor` that updates the `current` property.
+    ///
+    /// The fields missing from the object keep their value in `current`, or get their default
+    /// value if the property doesn't exist yet or changes between a data and an accessor
+    /// property.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    ///
+    /// <https://tc39.es/ecma262/#sec-topropertydescriptor>
+    /// <https://tc39.es/ecma262/#sec-validateandapplypropertydescriptor>
+    pub fn to_property_descriptor_for(
+        &self,
+        current: Option<&PropertyDescriptor>,
+        context: &mut Context,
+    ) -> Result<PropertyDescriptor> {
+        let enumerable = self
+            .get_descriptor_field("enumerable", context)?
+            .map(|enumerable| enumerable.to_boolean());
+        let configurable = self
+            .get_descriptor_field("configurable", context)?
+            .map(|configurable| configurable.to_boolean());
+        let value = self.get_descriptor_field("value", context)?;
+        let writable = self
+            .get_descriptor_field("writable", context)?
+            .map(|writable| writable.to_boolean());
+
+        let get = match self.get_descriptor_field("get", context)? {
+            Some(Value::Object(ref object)) if object.is_callable() => Some(Some(object.clone())),
+            Some(Value::Undefined) => Some(None),
+            Some(_) => {
+                return Err(
+                    context.construct_type_error("Property descriptor getter must be callable")
+                )
             }
-        }
-
-        let mut get = None;
-        let get_key = PropertyKey::from("get");
-        if self.has_property(&get_key) {
-            let getter = self.get(&get_key, self.clone().into(), context)?;
-            match getter {
-                Value::Object(ref object) if object.is_callable() => {
-                    get = Some(object.clone());
-                }
-                _ => {
-                    return Err(
-                        context.construct_type_error("Property descriptor getter must be callable")
-                    );
-                }
+            None => None,
+        };
+        let set = match self.get_descriptor_field("set", context)? {
+            Some(Value::Object(ref object)) if object.is_callable() => Some(Some(object.clone())),
+            Some(Value::Undefined) => Some(None),
+            Some(_) => {
+                return Err(
+                    context.construct_type_error("Property descriptor setter must be callable")
+                )
             }
-        }
-
-        let mut set = None;
-        let set_key = PropertyKey::from("set");
-        if self.has_property(&set_key) {
-            let setter = self.get(&set_key, self.clone().into(), context)?;
-            match setter {
-                Value::Object(ref object) if object.is_callable() => {
-                    set = Some(object.clone());
-                }
-                _ => {
-                    return Err(
-                        context.construct_type_error("Property descriptor setter must be callable")
-                    );
-                }
-            };
-        }
+            None => None,
+        };
 
-        if get.is_some() || set.is_some() {
-            if value.is_some() || has_writable {
+        let mut attribute = Attribute::empty();
+        attribute.set_enumerable(
+            enumerable.unwrap_or_else(|| current.map_or(false, PropertyDescriptor::enumerable)),
+        );
+        attribute.set_configurable(
+            configurable.unwrap_or_else(|| current.map_or(false, PropertyDescriptor::configurable)),
+        );
+
+        let current_accessor = current.and_then(PropertyDescriptor::as_accessor_descriptor);
+        let is_accessor = get.is_some()
+            || set.is_some()
+            || (value.is_none() && writable.is_none() && current_accessor.is_some());
+        if is_accessor {
+            if value.is_some() || writable.is_some() {
                 return Err(context.construct_type_error("Invalid property descriptor. Cannot both specify accessors and a value or writable attribute"));
             }
 
+            let get = get.unwrap_or_else(|| current_accessor.and_then(|a| a.getter().cloned()));
+            let set = set.unwrap_or_else(|| current_accessor.and_then(|a| a.setter().cloned()));
             Ok(AccessorDescriptor::new(get, set, attribute).into())
         } else {
-            Ok(DataDescriptor::new(value.unwrap_or_else(Value::undefined), attribute).into())
+            let current_data = current.and_then(PropertyDescriptor::as_data_descriptor);
+            attribute.set_writable(
+                writable.unwrap_or_else(|| current_data.map_or(false, DataDescriptor::writable)),
+            );
+            let value = value
+                .or_else(|| current_data.map(DataDescriptor::value))
+                .unwrap_or_default();
+            Ok(DataDescriptor::new(value, attribute).into())
+        }
+    }
+
+    /// Gets a field of a property descriptor object, if the object has it.
+    fn get_descriptor_field(&self, name: &str, context: &mut Context) -> Result<Option<Value>> {
+        let key = PropertyKey::from(name);
+        if self.has_property(&key, context)? {
+            self.get(&key, self.clone().into(), context).map(Some)
+        } else {
+            Ok(None)
         }
     }
 
@@ -625,6 +867,30 @@ impl GcObject {
         self.borrow().is_array()
     }
 
+    /// Checks if it is an `Array` object, or a `Proxy` whose target is one.
+    ///
+    /// Unlike [`is_array`](Self::is_array), this sees through proxies, like `Array.isArray`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isarray
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object, or the target of a proxy, is currently mutably borrowed.
+    #[track_caller]
+    pub fn is_array_abstract(&self) -> bool {
+        let mut object = self.clone();
+        loop {
+            let target = match object.borrow().as_proxy() {
+                Some(proxy) => proxy.target().clone(),
+                None => return object.is_array(),
+            };
+            object = target;
+        }
+    }
+
     /// Checks if it is an `ArrayIterator` object.
     ///
     /// # Panics
@@ -798,9 +1064,15 @@ impl GcObject {
             return Ok(false);
         }
 
-        // TODO: If C has a [[BoundTargetFunction]] internal slot, then
-        //           Let BC be C.[[BoundTargetFunction]].
-        //           Return ? InstanceofOperator(O, BC).
+        // If C has a [[BoundTargetFunction]] internal slot, then
+        //     Let BC be C.[[BoundTargetFunction]].
+        //     Return ? InstanceofOperator(O, BC).
+        let bound_function = self.borrow().as_bound_function().cloned();
+        if let Some(bound_function) = bound_function {
+            return bound_function
+                .target_function()
+                .ordinary_has_instance(context, value);
+        }
 
         if let Some(object) = value.as_object() {
             if let Some(prototype) = self
@@ -834,6 +1106,68 @@ impl GcObject {
         self.get_own_property(&key).is_some()
     }
 
+    /// Returns the keys, values or entries of the enumerable string keyed own properties of the
+    /// object, in the order of `[[OwnPropertyKeys]]`: the integer indices in ascending order,
+    /// followed by the other string keys in property creation order.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-enumerableownpropertynames
+    pub(crate) fn enumerable_own_property_names(
+        &self,
+        kind: PropertyNameKind,
+        context: &mut Context,
+    ) -> Result<Vec<Value>> {
+        // 1. Let ownKeys be ? O.[[OwnPropertyKeys]]().
+        let own_keys = self.own_property_keys();
+        // 2. Let properties be a new empty List.
+        let mut properties = Vec::with_capacity(own_keys.len());
+
+        // 3. For each element key of ownKeys, do
+        for key in own_keys {
+            // a. If Type(key) is String, then
+            if let PropertyKey::Symbol(_) = key {
+                continue;
+            }
+            // i. Let desc be ? O.[[GetOwnProperty]](key).
+            // ii. If desc is not undefined and desc.[[Enumerable]] is true, then
+            let enumerable = self
+                .get_own_property(&key)
+                .map_or(false, |desc| desc.enumerable());
+            if !enumerable {
+                continue;
+            }
+
+            let key_value = Value::from(key.to_string());
+            match kind {
+                // 1. If kind is key, append key to properties.
+                PropertyNameKind::Key => properties.push(key_value),
+                // 2. Else,
+                //    a. Let value be ? Get(O, key).
+                //    b. If kind is value, append value to properties.
+                //    c. Else,
+                //       i. Let entry be ! CreateArrayFromList(« key, value »).
+                //       ii. Append entry to properties.
+                PropertyNameKind::Value => {
+                    properties.push(self.get(&key, self.clone().into(), context)?)
+                }
+                PropertyNameKind::KeyAndValue => {
+                    let value = self.get(&key, self.clone().into(), context)?;
+                    let entry = Array::construct_array(
+                        &Array::new_array(context),
+                        &[key_value, value],
+                        context,
+                    )?;
+                    properties.push(entry);
+                }
+            }
+        }
+
+        // 4. Return properties.
+        Ok(properties)
+    }
+
     /// Defines the property or throws a `TypeError` if the operation fails.
     ///
     /// More information:
@@ -861,6 +1195,38 @@ impl GcObject {
             Ok(())
         }
     }
+
+    /// Deletes the property or throws a `TypeError` if the operation fails.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-deletepropertyorthrow
+    #[inline]
+    pub(crate) fn delete_property_or_throw<K>(
+        &mut self,
+        key: K,
+        context: &mut Context,
+    ) -> Result<()>
+    where
+        K: Into<PropertyKey>,
+    {
+        let key = key.into();
+
+        if !self.delete(&key, context)? {
+            Err(context.construct_type_error(format!("Cannot delete property: {}", key)))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The kind of the property names returned by `EnumerableOwnPropertyNames`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyNameKind {
+    Key,
+    Value,
+    KeyAndValue,
 }
 
 impl AsRef<GcCell<Object>> for GcObject {
@@ -6,12 +6,22 @@
 //! [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots
 
 use crate::{
+    builtins::typed_array::{canonical_numeric_index, TypedArray},
     object::{GcObject, Object, ObjectData},
     property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
     value::{Type, Value},
     BoaProfiler, Context, Result,
 };
 
+/// The integrity levels of an object, set by `Object.seal` and `Object.freeze`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrityLevel {
+    /// No properties can be added or removed.
+    Sealed,
+    /// No properties can be added, removed or changed.
+    Frozen,
+}
+
 impl GcObject {
     /// Check if object has property.
     ///
@@ -20,12 +30,37 @@ impl GcObject {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-hasproperty-p
     #[inline]
-    pub fn has_property(&self, key: &PropertyKey) -> bool {
+    pub fn has_property(&self, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+        let proxy = self.borrow().as_proxy().cloned();
+        if let Some(proxy) = proxy {
+            return proxy.has_property(key, context);
+        }
+        if let Some((typed_array, index)) = self.typed_array_index(key) {
+            return Ok(typed_array.is_valid_index(index));
+        }
+
+        if self.get_own_property(key).is_some() {
+            return Ok(true);
+        }
+        if let Value::Object(ref object) = self.get_prototype_of() {
+            object.has_property(key, context)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Check if object has property, without calling the `has` trap of proxies.
+    #[inline]
+    pub(crate) fn ordinary_has_property(&self, key: &PropertyKey) -> bool {
+        if let Some((typed_array, index)) = self.typed_array_index(key) {
+            return typed_array.is_valid_index(index);
+        }
+
         let prop = self.get_own_property(key);
         if prop.is_none() {
             let parent = self.get_prototype_of();
             return if let Value::Object(ref object) = parent {
-                object.has_property(key)
+                object.ordinary_has_property(key)
             } else {
                 false
             };
@@ -41,6 +76,11 @@ impl GcObject {
     /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-isextensible
     #[inline]
     pub fn is_extensible(&self) -> bool {
+        let proxy = self.borrow().as_proxy().cloned();
+        if let Some(proxy) = proxy {
+            return proxy.target().is_extensible();
+        }
+
         self.borrow().extensible
     }
 
@@ -52,13 +92,44 @@ impl GcObject {
     /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-preventextensions
     #[inline]
     pub fn prevent_extensions(&mut self) -> bool {
+        let proxy = self.borrow().as_proxy().cloned();
+        if let Some(proxy) = proxy {
+            return proxy.target().clone().prevent_extensions();
+        }
+
         self.borrow_mut().extensible = false;
         true
     }
 
     /// Delete property.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-delete-p
     #[inline]
-    pub fn delete(&mut self, key: &PropertyKey) -> bool {
+    pub fn delete(&mut self, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+        let proxy = self.borrow().as_proxy().cloned();
+        if let Some(proxy) = proxy {
+            return proxy.delete(key, context);
+        }
+        if let Some((typed_array, index)) = self.typed_array_index(key) {
+            return Ok(!typed_array.is_valid_index(index));
+        }
+
+        let deleted = self.ordinary_delete(key);
+        // A deleted index of a mapped arguments object stops being aliased to its parameter.
+        if deleted {
+            if let Some(map) = self.borrow_mut().as_mapped_arguments_mut() {
+                map.unmap(key);
+            }
+        }
+        Ok(deleted)
+    }
+
+    /// Delete property, without calling the `deleteProperty` trap of proxies.
+    #[inline]
+    pub(crate) fn ordinary_delete(&mut self, key: &PropertyKey) -> bool {
         match self.get_own_property(key) {
             Some(desc) if desc.configurable() => {
                 self.remove(&key);
@@ -72,6 +143,14 @@ impl GcObject {
     /// `[[Get]]`
     /// <https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-get-p-receiver>
     pub fn get(&self, key: &PropertyKey, receiver: Value, context: &mut Context) -> Result<Value> {
+        let proxy = self.borrow().as_proxy().cloned();
+        if let Some(proxy) = proxy {
+            return proxy.get(key, receiver, context);
+        }
+        if let Some((typed_array, index)) = self.typed_array_index(key) {
+            return Ok(typed_array.get_element(index).unwrap_or_default());
+        }
+
         match self.get_own_property(key) {
             None => {
                 // parent will either be null or an Object
@@ -102,6 +181,15 @@ impl GcObject {
     ) -> Result<bool> {
         let _timer = BoaProfiler::global().start_event("Object::set", "object");
 
+        let proxy = self.borrow().as_proxy().cloned();
+        if let Some(proxy) = proxy {
+            return proxy.set(key, val, receiver, context);
+        }
+        if let Some((typed_array, index)) = self.typed_array_index(&key) {
+            typed_array.set_element(index, val.to_number(context)?);
+            return Ok(true);
+        }
+
         // Fetch property key
         let own_desc = if let Some(desc) = self.get_own_property(&key) {
             desc
@@ -166,8 +254,30 @@ impl GcObject {
     where
         K: Into<PropertyKey>,
     {
+        let proxy = self.borrow().as_proxy().cloned();
+        if let Some(proxy) = proxy {
+            return proxy
+                .target()
+                .clone()
+                .define_own_property(key, desc, context);
+        }
+        let key = key.into();
+        if let Some((typed_array, index)) = self.typed_array_index(&key) {
+            // Descriptors don't record which of their fields are present, so only accessor
+            // descriptors can be rejected.
+            return match desc {
+                PropertyDescriptor::Data(ref desc) if typed_array.is_valid_index(index) => {
+                    typed_array.set_element(index, desc.value().to_number(context)?);
+                    Ok(true)
+                }
+                _ => Ok(false),
+            };
+        }
+
         if self.is_array() {
             self.array_define_own_property(key, desc, context)
+        } else if self.borrow().as_mapped_arguments().is_some() {
+            self.arguments_define_own_property(key, desc, context)
         } else {
             Ok(self.ordinary_define_own_property(key, desc))
         }
@@ -211,32 +321,17 @@ impl GcObject {
         }
 
         match (&current, &desc) {
-            (
-                PropertyDescriptor::Data(current),
-                PropertyDescriptor::Accessor(AccessorDescriptor { get, set, .. }),
-            ) => {
+            (PropertyDescriptor::Data(current), PropertyDescriptor::Accessor(_)) => {
                 // 6. b
                 if !current.configurable() {
                     return false;
                 }
-
-                let current =
-                    AccessorDescriptor::new(get.clone(), set.clone(), current.attributes());
-                self.insert(key, current);
-                return true;
             }
-            (
-                PropertyDescriptor::Accessor(current),
-                PropertyDescriptor::Data(DataDescriptor { value, .. }),
-            ) => {
+            (PropertyDescriptor::Accessor(current), PropertyDescriptor::Data(_)) => {
                 // 6. c
                 if !current.configurable() {
                     return false;
                 }
-
-                let current = DataDescriptor::new(value, current.attributes());
-                self.insert(key, current);
-                return true;
             }
             (PropertyDescriptor::Data(current), PropertyDescriptor::Data(desc)) => {
                 // 7.
@@ -252,18 +347,16 @@ impl GcObject {
             }
             (PropertyDescriptor::Accessor(current), PropertyDescriptor::Accessor(desc)) => {
                 // 8.
-                if !current.configurable() {
-                    if let (Some(current_get), Some(desc_get)) = (current.getter(), desc.getter()) {
-                        if !GcObject::equals(&current_get, &desc_get) {
-                            return false;
-                        }
-                    }
-
-                    if let (Some(current_set), Some(desc_set)) = (current.setter(), desc.setter()) {
-                        if !GcObject::equals(&current_set, &desc_set) {
-                            return false;
-                        }
-                    }
+                let same_function =
+                    |current: Option<&GcObject>, desc: Option<&GcObject>| match (current, desc) {
+                        (Some(current), Some(desc)) => GcObject::equals(current, desc),
+                        (current, desc) => current.is_none() && desc.is_none(),
+                    };
+                if !current.configurable()
+                    && (!same_function(current.getter(), desc.getter())
+                        || !same_function(current.setter(), desc.setter()))
+                {
+                    return false;
                 }
             }
         }
@@ -340,7 +433,7 @@ impl GcObject {
                             keys
                         };
                         for key in keys_to_delete.into_iter().rev() {
-                            if !self.delete(&key.into()) {
+                            if !self.ordinary_delete(&key.into()) {
                                 let mut new_len_desc_attribute = new_len_desc.attributes();
                                 if !new_writable {
                                     new_len_desc_attribute.set_writable(false);
@@ -390,6 +483,59 @@ impl GcObject {
         }
     }
 
+    /// Define an own property of a mapped arguments object, which also changes the parameter the
+    /// property is mapped to.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-arguments-exotic-objects-defineownproperty-p-desc
+    fn arguments_define_own_property(
+        &mut self,
+        key: PropertyKey,
+        desc: PropertyDescriptor,
+        context: &mut Context,
+    ) -> Result<bool> {
+        // 1. Let map be args.[[ParameterMap]].
+        // 2. Let isMapped be HasOwnProperty(map, P).
+        let mapped = self.borrow().as_mapped_arguments().and_then(|map| {
+            map.mapped_name(&key)
+                .map(|name| (map.environment().clone(), name.to_owned()))
+        });
+
+        // 5. Let allowed be ? OrdinaryDefineOwnProperty(args, P, newArgDesc).
+        // 6. If allowed is false, return false.
+        if !self.ordinary_define_own_property(key.clone(), desc.clone()) {
+            return Ok(false);
+        }
+
+        // 7. If isMapped is true, then
+        if let Some((environment, name)) = mapped {
+            let unmap = match desc {
+                // a. If IsAccessorDescriptor(Desc) is true, then
+                //    i. Call map.[[Delete]](P).
+                PropertyDescriptor::Accessor(_) => true,
+                // b. Else,
+                //    i. If Desc.[[Value]] is present, then
+                //       1. Let setStatus be Set(map, P, Desc.[[Value]], false).
+                //    ii. If Desc.[[Writable]] is present and its value is false, then
+                //       1. Call map.[[Delete]](P).
+                PropertyDescriptor::Data(ref data) => {
+                    environment.set_mutable_binding(&name, data.value(), false, context)?;
+                    !data.writable()
+                }
+            };
+            if unmap {
+                if let Some(map) = self.borrow_mut().as_mapped_arguments_mut() {
+                    map.unmap(&key);
+                }
+            }
+        }
+
+        // 8. Return true.
+        Ok(true)
+    }
+
     /// Gets own property of 'Object'
     ///
     #[inline]
@@ -398,11 +544,37 @@ impl GcObject {
 
         let object = self.borrow();
         match object.data {
+            ObjectData::Proxy(ref proxy) => proxy.target().get_own_property(key),
             ObjectData::String(_) => self.string_exotic_get_own_property(key),
+            ObjectData::Arguments(Some(ref map)) => {
+                // The value of a mapped index is the value of its parameter.
+                let property = self.ordinary_get_own_property(key)?;
+                match map.mapped_name(key).and_then(|name| map.get(name)) {
+                    Some(value) => Some(DataDescriptor::new(value, property.attributes()).into()),
+                    None => Some(property),
+                }
+            }
+            ObjectData::TypedArray(ref typed_array) => match canonical_numeric_index(key) {
+                Some(index) => typed_array
+                    .get_element(index)
+                    .map(|element| DataDescriptor::new(element, Attribute::all()).into()),
+                None => self.ordinary_get_own_property(key),
+            },
             _ => self.ordinary_get_own_property(key),
         }
     }
 
+    /// Gets the data of this object if it is a typed array, and the key as a number if it is a
+    /// canonical numeric string.
+    ///
+    /// Typed arrays handle these keys as the indices of their elements, instead of looking up
+    /// ordinary properties.
+    #[inline]
+    fn typed_array_index(&self, key: &PropertyKey) -> Option<(TypedArray, f64)> {
+        let typed_array = self.borrow().as_typed_array()?.clone();
+        Some((typed_array, canonical_numeric_index(key)?))
+    }
+
     /// StringGetOwnProperty abstract operation
     ///
     /// More information:
@@ -477,14 +649,48 @@ impl GcObject {
 
     /// Essential internal method OwnPropertyKeys
     ///
+    /// The keys are the integer indices in ascending numeric order, followed by the strings and
+    /// then the symbols, both in property creation order.
+    ///
     /// More information:
     ///  - [ECMAScript reference][spec]
     ///
-    /// [spec]: https://tc39.es/ecma262/#table-essential-internal-methods
+    /// [spec]: https://tc39.es/ecma262/#sec-ordinaryownpropertykeys
     #[inline]
     #[track_caller]
     pub fn own_property_keys(&self) -> Vec<PropertyKey> {
-        self.borrow().keys().collect()
+        let object = self.borrow();
+        if let Some(proxy) = object.as_proxy() {
+            return proxy.target().own_property_keys();
+        }
+
+        let mut indices: Vec<u32> = object.index_property_keys().copied().collect();
+        // The characters of a `String` object, and the elements of a typed array, come before
+        // the other index properties.
+        let exotic_length = match object.data {
+            ObjectData::String(ref string) => string.encode_utf16().count() as u32,
+            ObjectData::TypedArray(ref typed_array) => typed_array.length() as u32,
+            _ => 0,
+        };
+        indices.retain(|index| *index >= exotic_length);
+        indices.sort_unstable();
+
+        (0..exotic_length)
+            .chain(indices)
+            .map(PropertyKey::from)
+            .chain(
+                object
+                    .string_property_keys()
+                    .cloned()
+                    .map(PropertyKey::from),
+            )
+            .chain(
+                object
+                    .symbol_property_keys()
+                    .cloned()
+                    .map(PropertyKey::from),
+            )
+            .collect()
     }
 
     /// The abstract operation ObjectDefineProperties
@@ -503,7 +709,13 @@ impl GcObject {
             if let Some(prop_desc) = props.get_own_property(&next_key) {
                 if prop_desc.enumerable() {
                     let desc_obj = props.get(&next_key, props.clone().into(), context)?;
-                    let desc = desc_obj.to_property_descriptor(context)?;
+                    let current = self.get_own_property(&next_key);
+                    let desc = match desc_obj {
+                        Value::Object(ref desc_obj) => {
+                            desc_obj.to_property_descriptor_for(current.as_ref(), context)?
+                        }
+                        _ => desc_obj.to_property_descriptor(context)?,
+                    };
                     descriptors.push((next_key, desc));
                 }
             }
@@ -516,6 +728,80 @@ impl GcObject {
         Ok(())
     }
 
+    /// Prevents extensions of the object and makes its own properties non-configurable, and also
+    /// non-writable if the level is `Frozen`.
+    ///
+    /// Returns `false` if the object or one of its properties couldn't be changed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-setintegritylevel
+    pub fn set_integrity_level(
+        &mut self,
+        level: IntegrityLevel,
+        context: &mut Context,
+    ) -> Result<bool> {
+        if !self.prevent_extensions() {
+            return Ok(false);
+        }
+
+        for key in self.own_property_keys() {
+            let desc = match self.get_own_property(&key) {
+                Some(PropertyDescriptor::Data(ref data)) => {
+                    let mut attributes = data.attributes();
+                    attributes.set_configurable(false);
+                    if level == IntegrityLevel::Frozen {
+                        attributes.set_writable(false);
+                    }
+                    DataDescriptor::new(data.value(), attributes).into()
+                }
+                Some(PropertyDescriptor::Accessor(ref accessor)) => {
+                    let mut attributes = accessor.attributes();
+                    attributes.set_configurable(false);
+                    AccessorDescriptor::new(
+                        accessor.getter().cloned(),
+                        accessor.setter().cloned(),
+                        attributes,
+                    )
+                    .into()
+                }
+                None => continue,
+            };
+            if !self.define_own_property(key, desc, context)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Checks if the object is not extensible and all its own properties are non-configurable,
+    /// and also non-writable if the level is `Frozen`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-testintegritylevel
+    pub fn test_integrity_level(&self, level: IntegrityLevel) -> bool {
+        if self.is_extensible() {
+            return false;
+        }
+
+        self.own_property_keys()
+            .iter()
+            .filter_map(|key| self.get_own_property(key))
+            .all(|desc| {
+                !desc.configurable()
+                    && match desc {
+                        PropertyDescriptor::Data(ref data) if level == IntegrityLevel::Frozen => {
+                            !data.writable()
+                        }
+                        _ => true,
+                    }
+            })
+    }
+
     /// `Object.setPropertyOf(obj, prototype)`
     ///
     /// This method sets the prototype (i.e., the internal `[[Prototype]]` property)
@@ -530,6 +816,11 @@ impl GcObject {
     #[inline]
     pub fn set_prototype_of(&mut self, val: Value) -> bool {
         debug_assert!(val.is_object() || val.is_null());
+        let proxy = self.borrow().as_proxy().cloned();
+        if let Some(proxy) = proxy {
+            return proxy.target().clone().set_prototype_of(val);
+        }
+
         let current = self.get_prototype_of();
         if Value::same_value(&current, &val) {
             return true;
@@ -567,7 +858,12 @@ impl GcObject {
     #[inline]
     #[track_caller]
     pub fn get_prototype_of(&self) -> Value {
-        self.borrow().prototype.clone()
+        let object = self.borrow();
+        if let Some(proxy) = object.as_proxy() {
+            return proxy.target().get_prototype_of();
+        }
+
+        object.prototype.clone()
     }
 
     /// Helper function for property insertion.
@@ -723,4 +1019,48 @@ impl Object {
     {
         self.insert(key.into(), DataDescriptor::new(value, attribute))
     }
+
+    /// Gets the slot of the string keyed property `key` in the property map.
+    #[inline]
+    pub(crate) fn string_property_slot(&self, key: &str) -> Option<usize> {
+        self.string_properties.get_index_of(key)
+    }
+
+    /// Gets the value of the own data property in `slot` of the property map, if its key is
+    /// `key`.
+    ///
+    /// Returns `None` for proxies and typed arrays, whose string keyed properties aren't all in the
+    /// property map.
+    pub(crate) fn slot_value(&self, slot: usize, key: &str) -> Option<Value> {
+        if matches!(self.data, ObjectData::Proxy(_) | ObjectData::TypedArray(_)) {
+            return None;
+        }
+        match self.string_properties.get_index(slot)? {
+            (name, PropertyDescriptor::Data(data)) if name.as_str() == key => Some(data.value()),
+            _ => None,
+        }
+    }
+
+    /// Sets the value of the own writable data property in `slot` of the property map, if its key
+    /// is `key`, which is what `[[Set]]` does for an own writable data property.
+    ///
+    /// Returns `false` if the property isn't in the slot, or for proxies, typed arrays and arrays,
+    /// where setting a property can have other effects.
+    pub(crate) fn set_slot_value(&mut self, slot: usize, key: &str, value: &Value) -> bool {
+        if matches!(
+            self.data,
+            ObjectData::Proxy(_) | ObjectData::TypedArray(_) | ObjectData::Array
+        ) {
+            return false;
+        }
+        match self.string_properties.get_index_mut(slot) {
+            Some((name, PropertyDescriptor::Data(data)))
+                if name.as_str() == key && data.writable() =>
+            {
+                data.value = value.clone();
+                true
+            }
+            _ => false,
+        }
+    }
 }
@@ -3,12 +3,27 @@
 use crate::{
     builtins::{
         array::array_iterator::ArrayIterator,
-        function::{BuiltInFunction, Function, FunctionFlags, NativeFunction},
+        array_buffer::ArrayBuffer,
+        async_generator::AsyncGenerator,
+        error::ErrorKind,
+        function::{
+            arguments::ParameterMap, BoundFunction, BuiltInFunction, ClosureFunction, Function,
+            FunctionFlags, NativeFunction,
+        },
+        generator::Generator,
+        intl::NumberFormat,
+        iterable::async_from_sync_iterator::AsyncFromSyncIterator,
         map::map_iterator::MapIterator,
         map::ordered_map::OrderedMap,
+        promise::Promise,
+        proxy::Proxy,
+        regexp::regexp_string_iterator::RegExpStringIterator,
         set::ordered_set::OrderedSet,
         set::set_iterator::SetIterator,
         string::string_iterator::StringIterator,
+        text_encoding::TextDecoder,
+        typed_array::TypedArray,
+        weak_map::{WeakMap, WeakTarget},
         BigInt, Date, RegExp,
     },
     context::StandardConstructor,
@@ -31,10 +46,16 @@ mod tests;
 mod gcobject;
 mod internal_methods;
 mod iter;
+mod property_cache;
+mod property_map;
 
 use crate::builtins::object::for_in_iterator::ForInIterator;
-pub use gcobject::{GcObject, RecursionLimiter, Ref, RefMut};
+pub(crate) use gcobject::ObjectCounter;
+pub use gcobject::{GcObject, PropertyNameKind, RecursionLimiter, Ref, RefMut};
+pub use internal_methods::IntegrityLevel;
 pub use iter::*;
+pub(crate) use property_cache::{CachedSlot, PropertyCache};
+use property_map::PropertyMap;
 
 /// Static `prototype`, usually set on constructors as a key to point to their respective prototype object.
 pub static PROTOTYPE: &str = "prototype";
@@ -63,44 +84,63 @@ impl<T: Any + Debug + Trace> NativeObject for T {
 }
 
 /// The internal representation of an JavaScript object.
-#[derive(Debug, Trace, Finalize)]
+#[derive(Debug, Trace)]
 pub struct Object {
     /// The type of the object.
     pub data: ObjectData,
     indexed_properties: FxHashMap<u32, PropertyDescriptor>,
     /// Properties
-    string_properties: FxHashMap<RcString, PropertyDescriptor>,
+    string_properties: PropertyMap<RcString>,
     /// Symbol Properties
-    symbol_properties: FxHashMap<RcSymbol, PropertyDescriptor>,
+    symbol_properties: PropertyMap<RcSymbol>,
     /// Instance prototype `__proto__`.
     prototype: Value,
     /// Whether it can have new properties added to it.
     extensible: bool,
+    /// The target of the weak references to this object, held by the `WeakMap`s it's a key of.
+    weak_target: WeakTarget,
+    /// The counter of the context this object was allocated in, if any.
+    #[unsafe_ignore_trace]
+    counter: Option<ObjectCounter>,
 }
 
 /// Defines the different types of objects.
 #[derive(Debug, Trace, Finalize)]
 pub enum ObjectData {
+    Arguments(Option<ParameterMap>),
     Array,
+    ArrayBuffer(ArrayBuffer),
     ArrayIterator(ArrayIterator),
+    AsyncFromSyncIterator(AsyncFromSyncIterator),
+    AsyncGenerator(AsyncGenerator),
     Map(OrderedMap<Value, Value>),
     MapIterator(MapIterator),
     RegExp(Box<RegExp>),
+    RegExpStringIterator(RegExpStringIterator),
     BigInt(RcBigInt),
     Boolean(bool),
+    BoundFunction(BoundFunction),
     ForInIterator(ForInIterator),
     Function(Function),
+    Generator(Generator),
     Set(OrderedSet<Value>),
     SetIterator(SetIterator),
     String(RcString),
     StringIterator(StringIterator),
     Number(f64),
+    NumberFormat(Box<NumberFormat>),
     Symbol(RcSymbol),
-    Error,
+    TextDecoder(TextDecoder),
+    TextEncoder,
+    Error(ErrorKind),
     Ordinary,
     Date(Date),
     Global,
     NativeObject(Box<dyn NativeObject>),
+    Promise(Promise),
+    Proxy(Proxy),
+    TypedArray(TypedArray),
+    WeakMap(WeakMap),
 }
 
 impl Display for ObjectData {
@@ -109,11 +149,18 @@ impl Display for ObjectData {
             f,
             "{}",
             match self {
+                Self::Arguments(_) => "Arguments",
                 Self::Array => "Array",
+                Self::ArrayBuffer(_) => "ArrayBuffer",
                 Self::ArrayIterator(_) => "ArrayIterator",
+                Self::AsyncFromSyncIterator(_) => "AsyncFromSyncIterator",
+                Self::AsyncGenerator(_) => "AsyncGenerator",
                 Self::ForInIterator(_) => "ForInIterator",
                 Self::Function(_) => "Function",
+                Self::BoundFunction(_) => "BoundFunction",
+                Self::Generator(_) => "Generator",
                 Self::RegExp(_) => "RegExp",
+                Self::RegExpStringIterator(_) => "RegExpStringIterator",
                 Self::Map(_) => "Map",
                 Self::MapIterator(_) => "MapIterator",
                 Self::Set(_) => "Set",
@@ -121,19 +168,35 @@ impl Display for ObjectData {
                 Self::String(_) => "String",
                 Self::StringIterator(_) => "StringIterator",
                 Self::Symbol(_) => "Symbol",
-                Self::Error => "Error",
+                Self::TextDecoder(_) => "TextDecoder",
+                Self::TextEncoder => "TextEncoder",
+                Self::Error(_) => "Error",
                 Self::Ordinary => "Ordinary",
                 Self::Boolean(_) => "Boolean",
                 Self::Number(_) => "Number",
+                Self::NumberFormat(_) => "NumberFormat",
                 Self::BigInt(_) => "BigInt",
                 Self::Date(_) => "Date",
                 Self::Global => "Global",
                 Self::NativeObject(_) => "NativeObject",
+                Self::Promise(_) => "Promise",
+                Self::Proxy(_) => "Proxy",
+                Self::TypedArray(_) => "TypedArray",
+                Self::WeakMap(_) => "WeakMap",
             }
         )
     }
 }
 
+impl Finalize for Object {
+    /// Objects are only finalized by the garbage collector, right before they are dropped.
+    fn finalize(&self) {
+        if let Some(ref counter) = self.counter {
+            counter.record_collected();
+        }
+    }
+}
+
 impl Default for Object {
     /// Return a new ObjectData struct, with `kind` set to Ordinary
     #[inline]
@@ -141,10 +204,12 @@ impl Default for Object {
         Self {
             data: ObjectData::Ordinary,
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_target: WeakTarget::default(),
+            counter: None,
         }
     }
 }
@@ -163,10 +228,12 @@ impl Object {
         Self {
             data: ObjectData::Function(function),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype,
             extensible: true,
+            weak_target: WeakTarget::default(),
+            counter: None,
         }
     }
 
@@ -190,10 +257,12 @@ impl Object {
         Self {
             data: ObjectData::Boolean(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_target: WeakTarget::default(),
+            counter: None,
         }
     }
 
@@ -203,10 +272,12 @@ impl Object {
         Self {
             data: ObjectData::Number(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_target: WeakTarget::default(),
+            counter: None,
         }
     }
 
@@ -219,10 +290,12 @@ impl Object {
         Self {
             data: ObjectData::String(value.into()),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_target: WeakTarget::default(),
+            counter: None,
         }
     }
 
@@ -232,10 +305,12 @@ impl Object {
         Self {
             data: ObjectData::BigInt(value),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_target: WeakTarget::default(),
+            counter: None,
         }
     }
 
@@ -248,10 +323,12 @@ impl Object {
         Self {
             data: ObjectData::NativeObject(Box::new(value)),
             indexed_properties: FxHashMap::default(),
-            string_properties: FxHashMap::default(),
-            symbol_properties: FxHashMap::default(),
+            string_properties: PropertyMap::default(),
+            symbol_properties: PropertyMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_target: WeakTarget::default(),
+            counter: None,
         }
     }
 
@@ -263,7 +340,12 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-iscallable
     #[inline]
     pub fn is_callable(&self) -> bool {
-        matches!(self.data, ObjectData::Function(ref f) if f.is_callable())
+        match self.data {
+            ObjectData::Function(ref function) => function.is_callable(),
+            ObjectData::BoundFunction(ref bound) => bound.target_function().is_callable(),
+            ObjectData::Proxy(ref proxy) => proxy.target().is_callable(),
+            _ => false,
+        }
     }
 
     /// It determines if Object is a function object with a `[[Construct]]` internal method.
@@ -274,7 +356,12 @@ impl Object {
     /// [spec]: https://tc39.es/ecma262/#sec-isconstructor
     #[inline]
     pub fn is_constructable(&self) -> bool {
-        matches!(self.data, ObjectData::Function(ref f) if f.is_constructable())
+        match self.data {
+            ObjectData::Function(ref function) => function.is_constructable(),
+            ObjectData::BoundFunction(ref bound) => bound.target_function().is_constructable(),
+            ObjectData::Proxy(ref proxy) => proxy.target().is_constructable(),
+            _ => false,
+        }
     }
 
     /// Checks if it an `Array` object.
@@ -367,6 +454,171 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_regexp_string_iterator(&self) -> Option<&RegExpStringIterator> {
+        match &self.data {
+            ObjectData::RegExpStringIterator(iter) => Some(iter),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_regexp_string_iterator_mut(&mut self) -> Option<&mut RegExpStringIterator> {
+        match &mut self.data {
+            ObjectData::RegExpStringIterator(iter) => Some(iter),
+            _ => None,
+        }
+    }
+
+    /// Gets the proxy data if the object is a `Proxy`.
+    #[inline]
+    pub fn as_proxy(&self) -> Option<&Proxy> {
+        match &self.data {
+            ObjectData::Proxy(proxy) => Some(proxy),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_generator(&self) -> Option<&Generator> {
+        match &self.data {
+            ObjectData::Generator(generator) => Some(generator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_generator_mut(&mut self) -> Option<&mut Generator> {
+        match &mut self.data {
+            ObjectData::Generator(generator) => Some(generator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_async_generator(&self) -> Option<&AsyncGenerator> {
+        match &self.data {
+            ObjectData::AsyncGenerator(generator) => Some(generator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_async_generator_mut(&mut self) -> Option<&mut AsyncGenerator> {
+        match &mut self.data {
+            ObjectData::AsyncGenerator(generator) => Some(generator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_async_from_sync_iterator(&self) -> Option<&AsyncFromSyncIterator> {
+        match &self.data {
+            ObjectData::AsyncFromSyncIterator(iterator) => Some(iterator),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_promise(&self) -> Option<&Promise> {
+        match &self.data {
+            ObjectData::Promise(promise) => Some(promise),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_promise_mut(&mut self) -> Option<&mut Promise> {
+        match &mut self.data {
+            ObjectData::Promise(promise) => Some(promise),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_array_buffer(&self) -> Option<&ArrayBuffer> {
+        match &self.data {
+            ObjectData::ArrayBuffer(buffer) => Some(buffer),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_array_buffer_mut(&mut self) -> Option<&mut ArrayBuffer> {
+        match &mut self.data {
+            ObjectData::ArrayBuffer(buffer) => Some(buffer),
+            _ => None,
+        }
+    }
+
+    /// Gets the parameter map of this object, if it is a mapped arguments object.
+    #[inline]
+    pub fn as_mapped_arguments(&self) -> Option<&ParameterMap> {
+        match &self.data {
+            ObjectData::Arguments(Some(map)) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Gets the parameter map of this object mutably, if it is a mapped arguments object.
+    #[inline]
+    pub fn as_mapped_arguments_mut(&mut self) -> Option<&mut ParameterMap> {
+        match &mut self.data {
+            ObjectData::Arguments(Some(map)) => Some(map),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_typed_array(&self) -> Option<&TypedArray> {
+        match &self.data {
+            ObjectData::TypedArray(typed_array) => Some(typed_array),
+            _ => None,
+        }
+    }
+
+    /// Checks if it is a `TextEncoder` object.
+    #[inline]
+    pub fn is_text_encoder(&self) -> bool {
+        matches!(self.data, ObjectData::TextEncoder)
+    }
+
+    #[inline]
+    pub fn as_text_decoder(&self) -> Option<&TextDecoder> {
+        match &self.data {
+            ObjectData::TextDecoder(decoder) => Some(decoder),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_map(&self) -> Option<&WeakMap> {
+        match &self.data {
+            ObjectData::WeakMap(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_map_mut(&mut self) -> Option<&mut WeakMap> {
+        match &mut self.data {
+            ObjectData::WeakMap(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Gets the target of the weak references to this object.
+    #[inline]
+    pub(crate) fn weak_target(&self) -> &WeakTarget {
+        &self.weak_target
+    }
+
+    /// Gets the target of the weak references to this object mutably.
+    #[inline]
+    pub(crate) fn weak_target_mut(&mut self) -> &mut WeakTarget {
+        &mut self.weak_target
+    }
+
     #[inline]
     pub fn is_set(&self) -> bool {
         matches!(self.data, ObjectData::Set(_))
@@ -413,7 +665,20 @@ impl Object {
     /// Checks if it a `Function` object.
     #[inline]
     pub fn is_function(&self) -> bool {
-        matches!(self.data, ObjectData::Function(_))
+        match self.data {
+            ObjectData::Function(_) | ObjectData::BoundFunction(_) => true,
+            // A proxy is a function if its target is.
+            ObjectData::Proxy(ref proxy) => proxy.target().is_function(),
+            _ => false,
+        }
+    }
+
+    #[inline]
+    pub fn as_bound_function(&self) -> Option<&BoundFunction> {
+        match self.data {
+            ObjectData::BoundFunction(ref bound) => Some(bound),
+            _ => None,
+        }
     }
 
     #[inline]
@@ -441,13 +706,14 @@ impl Object {
     /// Checks if it an Error object.
     #[inline]
     pub fn is_error(&self) -> bool {
-        matches!(self.data, ObjectData::Error)
+        matches!(self.data, ObjectData::Error(_))
     }
 
+    /// Gets the standard type of the error, if it is an Error object.
     #[inline]
-    pub fn as_error(&self) -> Option<()> {
+    pub fn as_error(&self) -> Option<ErrorKind> {
         match self.data {
-            ObjectData::Error => Some(()),
+            ObjectData::Error(kind) => Some(kind),
             _ => None,
         }
     }
@@ -480,6 +746,22 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_number_format(&self) -> Option<&NumberFormat> {
+        match self.data {
+            ObjectData::NumberFormat(ref number_format) => Some(number_format),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_number_format_mut(&mut self) -> Option<&mut NumberFormat> {
+        match self.data {
+            ObjectData::NumberFormat(ref mut number_format) => Some(number_format),
+            _ => None,
+        }
+    }
+
     /// Checks if it a `BigInt` object.
     #[inline]
     pub fn is_bigint(&self) -> bool {
@@ -669,11 +951,18 @@ where
     }
 }
 
+/// The Rust implementation of a function built by a `FunctionBuilder`.
+#[derive(Debug, Clone)]
+enum FunctionBuilderBody {
+    Native(BuiltInFunction),
+    Closure(ClosureFunction),
+}
+
 /// Builder for creating native function objects
 #[derive(Debug)]
 pub struct FunctionBuilder<'context> {
     context: &'context mut Context,
-    function: BuiltInFunction,
+    function: FunctionBuilderBody,
     name: Option<String>,
     length: usize,
     callable: bool,
@@ -686,7 +975,100 @@ impl<'context> FunctionBuilder<'context> {
     pub fn new(context: &'context mut Context, function: NativeFunction) -> Self {
         Self {
             context,
-            function: function.into(),
+            function: FunctionBuilderBody::Native(function.into()),
+            name: None,
+            length: 0,
+            callable: true,
+            constructable: false,
+        }
+    }
+
+    /// Create a new `FunctionBuilder` from a Rust closure.
+    ///
+    /// Unlike [`FunctionBuilder::new`], the function can capture its environment. The closure is
+    /// shared by every call of the function, so the captured state has to be `'static`, and any
+    /// state that changes between calls has to be behind a `Cell` or a `RefCell`.
+    ///
+    /// The garbage collector doesn't trace the captured environment, so the closure must not
+    /// capture garbage collected values, like `Value`s or `GcObject`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa::{object::FunctionBuilder, property::Attribute, Context, Value};
+    /// # use std::{cell::Cell, rc::Rc};
+    /// let mut context = Context::new();
+    /// let counter = Rc::new(Cell::new(0));
+    ///
+    /// let calls = counter.clone();
+    /// let function = FunctionBuilder::closure(&mut context, move |_, _, _| {
+    ///     calls.set(calls.get() + 1);
+    ///     Ok(Value::from(calls.get()))
+    /// })
+    /// .name("count")
+    /// .build();
+    /// context.register_global_property("count", function, Attribute::all());
+    ///
+    /// let result = context.eval("count(); count(); count()").unwrap();
+    /// assert_eq!(result.as_number(), Some(3.0));
+    /// assert_eq!(counter.get(), 3);
+    /// ```
+    #[inline]
+    pub fn closure<F>(context: &'context mut Context, function: F) -> Self
+    where
+        F: Fn(&Value, &[Value], &mut Context) -> crate::Result<Value> + 'static,
+    {
+        Self {
+            context,
+            function: FunctionBuilderBody::Closure(ClosureFunction::new(function)),
+            name: None,
+            length: 0,
+            callable: true,
+            constructable: false,
+        }
+    }
+
+    /// Create a new `FunctionBuilder` from a Rust closure and the state it captures.
+    ///
+    /// Unlike the environment of the closure, the captures are traced by the garbage collector,
+    /// so they can hold garbage collected values. They are shared by every call of the function,
+    /// so any state that changes between calls has to be behind a `GcCell`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa::{gc::force_collect, object::FunctionBuilder, property::Attribute, Context, Value};
+    /// let mut context = Context::new();
+    /// let object = context.eval("({ name: 'captured' })").unwrap();
+    ///
+    /// let function = FunctionBuilder::closure_with_captures(
+    ///     &mut context,
+    ///     |_, _, object: &Value, _| Ok(object.clone()),
+    ///     object,
+    /// )
+    /// .name("getObject")
+    /// .build();
+    /// context.register_global_property("getObject", function, Attribute::all());
+    ///
+    /// force_collect();
+    /// let result = context.eval("getObject().name").unwrap();
+    /// assert_eq!(result.as_string().unwrap().as_str(), "captured");
+    /// ```
+    #[inline]
+    pub fn closure_with_captures<F, C>(
+        context: &'context mut Context,
+        function: F,
+        captures: C,
+    ) -> Self
+    where
+        F: Fn(&Value, &[Value], &C, &mut Context) -> crate::Result<Value> + 'static,
+        C: NativeObject,
+    {
+        Self {
+            context,
+            function: FunctionBuilderBody::Closure(ClosureFunction::with_captures(
+                function, captures,
+            )),
             name: None,
             length: 0,
             callable: true,
@@ -694,6 +1076,15 @@ impl<'context> FunctionBuilder<'context> {
         }
     }
 
+    /// Creates the `Function` with the flags of the builder.
+    fn function(&self) -> Function {
+        let flags = FunctionFlags::from_parameters(self.callable, self.constructable);
+        match self.function.clone() {
+            FunctionBuilderBody::Native(function) => Function::BuiltIn(function, flags),
+            FunctionBuilderBody::Closure(function) => Function::Closure(function, flags),
+        }
+    }
+
     /// Specify the name property of object function object.
     ///
     /// The default is `""` (empty string).
@@ -739,10 +1130,7 @@ impl<'context> FunctionBuilder<'context> {
     #[inline]
     pub fn build(&mut self) -> GcObject {
         let mut function = Object::function(
-            Function::BuiltIn(
-                self.function,
-                FunctionFlags::from_parameters(self.callable, self.constructable),
-            ),
+            self.function(),
             self.context
                 .standard_objects()
                 .function_object()
@@ -763,10 +1151,7 @@ impl<'context> FunctionBuilder<'context> {
     /// Initializes the `Function.prototype` function object.
     pub(crate) fn build_function_prototype(&mut self, object: &GcObject) {
         let mut object = object.borrow_mut();
-        object.data = ObjectData::Function(Function::BuiltIn(
-            self.function,
-            FunctionFlags::from_parameters(self.callable, self.constructable),
-        ));
+        object.data = ObjectData::Function(self.function());
         object.set_prototype_instance(
             self.context
                 .standard_objects()
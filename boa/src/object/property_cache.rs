@@ -0,0 +1,72 @@
+//! This module implements the inline cache of the constant property accesses, like `obj.x`.
+//!
+//! Each access site remembers the slot of the property map where it last found its property, and
+//! the next access reads or writes that slot directly, instead of looking up the key. The string
+//! keyed properties of an object are stored in creation order, so this hits for the objects whose
+//! properties were created in the same order, like the objects created by the same literal or
+//! constructor.
+//!
+//! There are no shapes, so the slot is checked on each access: it's only used if it still holds
+//! an own data property with the accessed key, and the access does the full lookup otherwise.
+//!
+//! The slot is stored in the node of the access site, so it's dropped with the script or
+//! function containing the site.
+
+use super::GcObject;
+use crate::Value;
+use std::cell::Cell;
+
+/// The slot where an access site last found its property.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct CachedSlot(Cell<Option<usize>>);
+
+/// The statistics of the inline cache of the constant property accesses of a `Context`.
+#[derive(Debug, Default)]
+pub(crate) struct PropertyCache {
+    /// The number of accesses resolved by the cache.
+    hits: u64,
+}
+
+impl PropertyCache {
+    /// Gets the value of the property `key` of `object`, if it is in the slot cached for `site`.
+    pub(crate) fn get(&mut self, site: &CachedSlot, object: &GcObject, key: &str) -> Option<Value> {
+        let slot = site.0.get()?;
+        let value = object.borrow().slot_value(slot, key)?;
+        self.hits += 1;
+        Some(value)
+    }
+
+    /// Sets the value of the property `key` of `object`, if it is in the slot cached for `site`.
+    ///
+    /// Returns `false` if the property isn't in the slot, or if it can't be set without the full
+    /// `[[Set]]`.
+    pub(crate) fn set(
+        &mut self,
+        site: &CachedSlot,
+        object: &GcObject,
+        key: &str,
+        value: &Value,
+    ) -> bool {
+        let slot = match site.0.get() {
+            Some(slot) => slot,
+            None => return false,
+        };
+        let set = object.borrow_mut().set_slot_value(slot, key, value);
+        if set {
+            self.hits += 1;
+        }
+        set
+    }
+
+    /// Records the slot of the property `key` of `object` for `site`, after a full lookup.
+    pub(crate) fn record(&mut self, site: &CachedSlot, object: &GcObject, key: &str) {
+        if let Some(slot) = object.borrow().string_property_slot(key) {
+            site.0.set(Some(slot));
+        }
+    }
+
+    /// Gets the number of accesses resolved by the cache.
+    pub(crate) fn hits(&self) -> u64 {
+        self.hits
+    }
+}
@@ -0,0 +1,95 @@
+use super::PropertyDescriptor;
+use crate::gc::{custom_trace, Finalize, Trace};
+use indexmap::{map, IndexMap};
+use rustc_hash::FxHasher;
+use std::{
+    borrow::Borrow,
+    hash::{BuildHasherDefault, Hash},
+};
+
+/// A map of the properties of an object, that keeps the properties in insertion order.
+///
+/// The order is observable through operations like `Reflect.ownKeys`, where the string and symbol
+/// keys must be in property creation order.
+#[derive(Debug, Clone)]
+pub(crate) struct PropertyMap<K>(IndexMap<K, PropertyDescriptor, BuildHasherDefault<FxHasher>>)
+where
+    K: Hash + Eq;
+
+impl<K: Hash + Eq + Trace> Finalize for PropertyMap<K> {}
+unsafe impl<K: Hash + Eq + Trace> Trace for PropertyMap<K> {
+    custom_trace!(this, {
+        for (k, v) in this.0.iter() {
+            mark(k);
+            mark(v);
+        }
+    });
+}
+
+impl<K: Hash + Eq> Default for PropertyMap<K> {
+    #[inline]
+    fn default() -> Self {
+        Self(IndexMap::default())
+    }
+}
+
+impl<K: Hash + Eq> PropertyMap<K> {
+    #[inline]
+    pub(crate) fn get(&self, key: &K) -> Option<&PropertyDescriptor> {
+        self.0.get(key)
+    }
+
+    #[inline]
+    pub(crate) fn insert(
+        &mut self,
+        key: K,
+        value: PropertyDescriptor,
+    ) -> Option<PropertyDescriptor> {
+        self.0.insert(key, value)
+    }
+
+    /// Removes the property, preserving the order of the remaining properties.
+    #[inline]
+    pub(crate) fn remove(&mut self, key: &K) -> Option<PropertyDescriptor> {
+        self.0.shift_remove(key)
+    }
+
+    /// Gets the position of the property in the creation order.
+    #[inline]
+    pub(crate) fn get_index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.0.get_index_of(key)
+    }
+
+    /// Gets the property at the position `index` of the creation order.
+    #[inline]
+    pub(crate) fn get_index(&self, index: usize) -> Option<(&K, &PropertyDescriptor)> {
+        self.0.get_index(index)
+    }
+
+    /// Gets the property at the position `index` of the creation order mutably.
+    #[inline]
+    pub(crate) fn get_index_mut(&mut self, index: usize) -> Option<(&K, &mut PropertyDescriptor)> {
+        self.0
+            .get_index_mut(index)
+            .map(|(key, property)| (&*key, property))
+    }
+
+    #[inline]
+    pub(crate) fn iter(&self) -> map::Iter<'_, K, PropertyDescriptor> {
+        self.0.iter()
+    }
+
+    #[inline]
+    pub(crate) fn keys(&self) -> map::Keys<'_, K, PropertyDescriptor> {
+        self.0.keys()
+    }
+
+    #[inline]
+    pub(crate) fn values(&self) -> map::Values<'_, K, PropertyDescriptor> {
+        self.0.values()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::exec;
+use crate::{exec, forward, Context};
 
 #[test]
 fn ordinary_has_instance_nonobject_prototype() {
@@ -17,3 +17,64 @@ fn ordinary_has_instance_nonobject_prototype() {
         "\"TypeError: function has non-object prototype in instanceof check\""
     );
 }
+
+#[test]
+fn property_cache_hits_same_shape() {
+    let mut context = Context::new();
+
+    let init = r#"
+        let obj = { x: 1, y: 2 };
+        let sum = 0;
+        for (let i = 0; i < 10000; i++) {
+            sum += obj.x;
+        }
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "sum"), "10000");
+    assert_eq!(context.property_cache_hits(), 9_999);
+
+    // Objects whose properties were created in the same order share the cached slot.
+    let init = r#"
+        function getX(o) {
+            return o.x;
+        }
+        let total = 0;
+        for (let i = 0; i < 100; i++) {
+            total += getX({ x: i, y: 0 });
+        }
+    "#;
+    let hits = context.property_cache_hits();
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "total"), "4950");
+    assert_eq!(context.property_cache_hits() - hits, 99);
+}
+
+#[test]
+fn property_cache_misses_other_shapes() {
+    let mut context = Context::new();
+
+    let init = r#"
+        function getX(o) {
+            return o.x;
+        }
+        let objects = [{ x: 1 }, { y: 2, x: 3 }, { get x() { return 5; } }, { x: 7 }];
+        let values = [];
+        for (let i = 0; i < objects.length; i++) {
+            values.push(getX(objects[i]));
+        }
+        let frozen = Object.freeze({ x: 1 });
+        function setX(o, value) {
+            o.x = value;
+        }
+        setX({ x: 0 }, 0);
+        setX(frozen, 2);
+        let array = [1, 2, 3];
+        array.length = 1;
+        array.length = 0;
+    "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "values.join()"), "\"1,3,5,7\"");
+    assert_eq!(forward(&mut context, "frozen.x"), "1");
+    assert_eq!(forward(&mut context, "array.length"), "0");
+    assert_eq!(forward(&mut context, "array[0]"), "undefined");
+}
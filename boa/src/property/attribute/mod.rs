@@ -60,7 +60,7 @@ impl Attribute {
         if value {
             *self |= Self::WRITABLE;
         } else {
-            *self |= *self & !Self::WRITABLE;
+            self.remove(Self::WRITABLE);
         }
     }
 
@@ -76,7 +76,7 @@ impl Attribute {
         if value {
             *self |= Self::ENUMERABLE;
         } else {
-            *self |= *self & !Self::ENUMERABLE;
+            self.remove(Self::ENUMERABLE);
         }
     }
 
@@ -92,7 +92,7 @@ impl Attribute {
         if value {
             *self |= Self::CONFIGURABLE;
         } else {
-            *self |= *self & !Self::CONFIGURABLE;
+            self.remove(Self::CONFIGURABLE);
         }
     }
 
@@ -135,3 +135,21 @@ fn set_configurable_to_false() {
     assert!(!attribute.enumerable());
     assert!(!attribute.configurable());
 }
+
+#[test]
+fn set_attributes_to_false_clears_them() {
+    let mut attribute = Attribute::all();
+
+    attribute.set_writable(false);
+    assert!(!attribute.writable());
+    assert!(attribute.enumerable());
+    assert!(attribute.configurable());
+
+    attribute.set_enumerable(false);
+    assert!(!attribute.enumerable());
+    assert!(attribute.configurable());
+
+    attribute.set_configurable(false);
+    assert!(!attribute.configurable());
+    assert_eq!(attribute, Attribute::empty());
+}
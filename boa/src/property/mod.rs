@@ -311,10 +311,29 @@ pub enum PropertyKey {
     Index(u32),
 }
 
+/// Parses a string that is the canonical form of an array index, an integer from 0 to 2^32 - 2.
+///
+/// Other numeric strings, like `"05"`, `"+5"` or `"4294967295"`, are ordinary string keys.
+///
+/// More information:
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#array-index
+#[inline]
+fn parse_array_index(string: &str) -> Option<u32> {
+    let canonical = !string.is_empty()
+        && string.bytes().all(|byte| byte.is_ascii_digit())
+        && (string == "0" || !string.starts_with('0'));
+    if !canonical {
+        return None;
+    }
+    string.parse().ok().filter(|index| *index != u32::MAX)
+}
+
 impl From<RcString> for PropertyKey {
     #[inline]
     fn from(string: RcString) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_array_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string)
@@ -325,7 +344,7 @@ impl From<RcString> for PropertyKey {
 impl From<&str> for PropertyKey {
     #[inline]
     fn from(string: &str) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_array_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string.into())
@@ -336,7 +355,7 @@ impl From<&str> for PropertyKey {
 impl From<String> for PropertyKey {
     #[inline]
     fn from(string: String) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_array_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string.into())
@@ -347,7 +366,7 @@ impl From<String> for PropertyKey {
 impl From<Box<str>> for PropertyKey {
     #[inline]
     fn from(string: Box<str>) -> PropertyKey {
-        if let Ok(index) = string.parse() {
+        if let Some(index) = parse_array_index(&string) {
             PropertyKey::Index(index)
         } else {
             PropertyKey::String(string.into())
@@ -421,14 +440,18 @@ impl From<u16> for PropertyKey {
 
 impl From<u32> for PropertyKey {
     fn from(value: u32) -> Self {
-        PropertyKey::Index(value)
+        if value != u32::MAX {
+            PropertyKey::Index(value)
+        } else {
+            PropertyKey::String(RcString::from(value.to_string()))
+        }
     }
 }
 
 impl From<usize> for PropertyKey {
     fn from(value: usize) -> Self {
         if let Ok(index) = u32::try_from(value) {
-            PropertyKey::Index(index)
+            PropertyKey::from(index)
         } else {
             PropertyKey::String(RcString::from(value.to_string()))
         }
@@ -438,7 +461,7 @@ impl From<usize> for PropertyKey {
 impl From<isize> for PropertyKey {
     fn from(value: isize) -> Self {
         if let Ok(index) = u32::try_from(value) {
-            PropertyKey::Index(index)
+            PropertyKey::from(index)
         } else {
             PropertyKey::String(RcString::from(value.to_string()))
         }
@@ -448,7 +471,7 @@ impl From<isize> for PropertyKey {
 impl From<i32> for PropertyKey {
     fn from(value: i32) -> Self {
         if let Ok(index) = u32::try_from(value) {
-            PropertyKey::Index(index)
+            PropertyKey::from(index)
         } else {
             PropertyKey::String(RcString::from(value.to_string()))
         }
@@ -457,9 +480,8 @@ impl From<i32> for PropertyKey {
 
 impl From<f64> for PropertyKey {
     fn from(value: f64) -> Self {
-        use num_traits::cast::FromPrimitive;
-        if let Some(index) = u32::from_f64(value) {
-            return PropertyKey::Index(index);
+        if value >= 0.0 && value < f64::from(u32::MAX) && value.fract() == 0.0 {
+            return PropertyKey::Index(value as u32);
         }
 
         PropertyKey::String(ryu_js::Buffer::new().format(value).into())
@@ -12,6 +12,7 @@ use crate::{
     BoaProfiler,
 };
 use gc::Gc;
+use rustc_hash::FxHashMap;
 
 /// Representation of a Realm.
 ///
@@ -21,6 +22,10 @@ pub struct Realm {
     pub global_object: GcObject,
     pub global_env: Gc<GlobalEnvironmentRecord>,
     pub environment: LexicalEnvironment,
+    /// The template objects of the tagged templates evaluated in this realm, by call site.
+    pub(crate) template_map: FxHashMap<usize, GcObject>,
+    /// The `%ThrowTypeError%` function of this realm, once it has been created.
+    pub(crate) throw_type_error: Option<GcObject>,
 }
 
 impl Realm {
@@ -34,8 +39,11 @@ impl Realm {
         // Allow identification of the global object easily
         global.data = ObjectData::Global;
 
-        let gc_global = GcObject::new(global);
+        Self::with_global_object(GcObject::new(global))
+    }
 
+    /// Creates a realm whose global environment is bound to the given global object.
+    pub(crate) fn with_global_object(gc_global: GcObject) -> Self {
         // We need to clone the global here because its referenced from separate places (only pointer is cloned)
         let global_env = GlobalEnvironmentRecord::new(gc_global.clone(), gc_global.clone());
 
@@ -43,6 +51,8 @@ impl Realm {
             global_object: gc_global.clone(),
             global_env: Gc::new(global_env),
             environment: LexicalEnvironment::new(gc_global),
+            template_map: FxHashMap::default(),
+            throw_type_error: None,
         }
     }
 }
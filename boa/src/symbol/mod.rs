@@ -21,6 +21,7 @@ use crate::{
     gc::{Finalize, Trace},
     value::RcString,
 };
+use rustc_hash::FxHashMap;
 use std::{
     cell::Cell,
     hash::{Hash, Hasher},
@@ -295,3 +296,35 @@ impl Hash for Symbol {
         self.hash.hash(state);
     }
 }
+
+/// The global symbol registry, which maps the keys of `Symbol.for` to the symbols shared by all
+/// the code of a `Context`.
+///
+/// More information:
+/// - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-symbol.for
+#[derive(Debug, Default)]
+pub(crate) struct GlobalSymbolRegistry {
+    symbols: FxHashMap<RcString, RcSymbol>,
+    keys: FxHashMap<RcSymbol, RcString>,
+}
+
+impl GlobalSymbolRegistry {
+    /// Gets the symbol registered with `key`, creating and registering it if there is none.
+    pub(crate) fn get_or_insert(&mut self, key: RcString) -> RcSymbol {
+        if let Some(symbol) = self.symbols.get(&key) {
+            return symbol.clone();
+        }
+
+        let symbol = RcSymbol::from(Symbol::new(Some(key.clone())));
+        self.symbols.insert(key.clone(), symbol.clone());
+        self.keys.insert(symbol.clone(), key);
+        symbol
+    }
+
+    /// Gets the key of `symbol`, if it is in the registry.
+    pub(crate) fn key_for(&self, symbol: &RcSymbol) -> Option<RcString> {
+        self.keys.get(symbol).cloned()
+    }
+}
@@ -6,6 +6,7 @@ pub mod node;
 pub mod op;
 pub mod position;
 pub mod punctuator;
+pub mod visit;
 
 pub use self::{
     constant::Const,
@@ -13,4 +14,5 @@ pub use self::{
     node::Node,
     position::{Position, Span},
     punctuator::Punctuator,
+    visit::Visitor,
 };
@@ -1,10 +1,11 @@
 //! Array declaration node.
 
-use super::{join_nodes, Node};
+use super::Node;
 use crate::{
     builtins::{iterable, Array},
     exec::Executable,
     gc::{Finalize, Trace},
+    property::{Attribute, DataDescriptor},
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -42,29 +43,40 @@ impl Executable for ArrayDecl {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("ArrayDecl", "exec");
         let array = Array::new_array(context);
-        let mut elements = Vec::new();
+        // Elisions leave holes, that only add to the length of the array.
+        // <https://tc39.es/ecma262/#sec-runtime-semantics-arrayaccumulation>
+        let mut next_index = 0;
         for elem in self.as_ref() {
-            if let Node::Spread(ref x) = elem {
-                let val = x.run(context)?;
-                let iterator_record = iterable::get_iterator(context, val)?;
-                // TODO after proper internal Array representation as per https://github.com/boa-dev/boa/pull/811#discussion_r502460858
-                // next_index variable should be utilized here as per https://tc39.es/ecma262/#sec-runtime-semantics-arrayaccumulation
-                // let mut next_index = 0;
-                loop {
-                    let next = iterator_record.next(context)?;
-                    if next.is_done() {
-                        break;
+            match elem {
+                Node::Spread(ref x) => {
+                    let val = x.run(context)?;
+                    let iterator_record = iterable::get_iterator(context, val)?;
+                    loop {
+                        let next = iterator_record.next(context)?;
+                        if next.is_done() {
+                            break;
+                        }
+                        array.set_property(
+                            next_index,
+                            DataDescriptor::new(next.value(), Attribute::all()),
+                        );
+                        next_index += 1;
                     }
-                    let next_value = next.value();
-                    //next_index += 1;
-                    elements.push(next_value.clone());
                 }
-            } else {
-                elements.push(elem.run(context)?);
+                Node::Empty => next_index += 1,
+                _ => {
+                    let value = elem.run(context)?;
+                    array.set_property(next_index, DataDescriptor::new(value, Attribute::all()));
+                    next_index += 1;
+                }
             }
         }
 
-        Array::add_to_array_object(&array, &elements, context)?;
+        let length = DataDescriptor::new(
+            next_index,
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+        array.set_property("length", length);
         Ok(array)
     }
 }
@@ -87,7 +99,21 @@ where
 impl fmt::Display for ArrayDecl {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.write_str("[")?;
-        join_nodes(f, &self.arr)?;
+        let mut first = true;
+        for elem in self.arr.iter() {
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+            // Holes are written as nothing between the commas.
+            if !matches!(elem, Node::Empty) {
+                fmt::Display::fmt(elem, f)?;
+            }
+        }
+        // A trailing hole needs a trailing comma, that would be ignored otherwise.
+        if let Some(Node::Empty) = self.arr.last() {
+            f.write_str(",")?;
+        }
         f.write_str("]")
     }
 }
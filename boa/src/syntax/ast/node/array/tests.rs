@@ -4,6 +4,7 @@ fn fmt() {
         r#"
         let a = [1, 2, 3, "words", "more words"];
         let b = [];
+        let c = [, 1, , 2, ,];
         "#,
     );
 }
@@ -1,8 +1,12 @@
 //! Await expression node.
 
 use super::Node;
-use crate::{exec::Executable, BoaProfiler, Context, Result, Value};
-use gc::{Finalize, Trace};
+use crate::{
+    builtins::{async_function::await_value, generator::Resumption},
+    exec::Executable,
+    gc::{Finalize, Trace},
+    BoaProfiler, Context, Result, Value,
+};
 use std::fmt;
 
 #[cfg(feature = "deser")]
@@ -14,6 +18,9 @@ mod tests;
 /// An await expression is used within an async function to pause execution and wait for a
 /// promise to resolve.
 ///
+/// The async function is suspended until the awaited value is settled, and the expression
+/// evaluates to the value of the promise, or throws its reason.
+///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///  - [MDN documentation][mdn]
@@ -26,11 +33,29 @@ pub struct AwaitExpr {
     expr: Box<Node>,
 }
 
+impl AwaitExpr {
+    /// Gets the awaited expression.
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+}
+
 impl Executable for AwaitExpr {
-    fn run(&self, _: &mut Context) -> Result<Value> {
+    fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("AwaitExpression", "exec");
-        // TODO: Implement AwaitExpr
-        Ok(Value::Undefined)
+        let resumption = match context.executor().generator_frame() {
+            Some(frame) if frame.is_async() => frame.take_resumption(self),
+            _ => return context.throw_syntax_error("await is only valid in async functions"),
+        };
+        match resumption {
+            Some(Resumption::Next(value)) => Ok(value),
+            Some(Resumption::Throw(reason)) => Err(reason),
+            Some(Resumption::Return(_)) => unreachable!("an await expression is resumed by return"),
+            None => {
+                let value = self.expr.run(context)?;
+                await_value(self, value, context)
+            }
+        }
     }
 }
 
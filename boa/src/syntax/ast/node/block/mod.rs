@@ -2,6 +2,7 @@
 
 use super::{Node, StatementList};
 use crate::{
+    builtins::generator::ResumeState,
     environment::declarative_environment_record::DeclarativeEnvironmentRecord,
     exec::Executable,
     exec::InterpreterState,
@@ -32,21 +33,33 @@ mod tests;
 /// [spec]: https://tc39.es/ecma262/#prod-BlockStatement
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/block
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "deser", serde(transparent))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Block {
-    #[cfg_attr(feature = "deser", serde(flatten))]
     statements: StatementList,
+    label: Option<Box<str>>,
 }
 
 impl Block {
     /// Gets the list of statements and declarations in this block.
-    pub(crate) fn items(&self) -> &[Node] {
+    pub fn items(&self) -> &[Node] {
         self.statements.items()
     }
 
+    /// Gets the label of the block, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_ref().map(Box::as_ref)
+    }
+
+    /// Sets the label of the block, making it the target of `break` statements with the label.
+    pub fn set_label(&mut self, label: Box<str>) {
+        self.label = Some(label);
+    }
+
     /// Implements the display formatting with indentation.
     pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        if let Some(ref label) = self.label {
+            write!(f, "{}: ", label)?;
+        }
         writeln!(f, "{{")?;
         self.statements.display(f, indentation + 1)?;
         write!(f, "{}}}", "    ".repeat(indentation))
@@ -56,7 +69,10 @@ impl Block {
 impl Executable for Block {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Block", "exec");
-        {
+        if let Some(ResumeState::Environment(env)) = context.executor().take_resume_state(self) {
+            // The block of a resumed generator is executed in the environment it was suspended in.
+            context.push_environment(env);
+        } else {
             let env = context.get_current_environment();
             context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
         }
@@ -64,11 +80,18 @@ impl Executable for Block {
         // https://tc39.es/ecma262/#sec-block-runtime-semantics-evaluation
         // The return value is uninitialized, which means it defaults to Value::Undefined
         let mut obj = Value::default();
-        for statement in self.items() {
+        for (i, statement) in self.items().iter().enumerate() {
+            if let Some(position) = self.statements.position(i) {
+                context.executor().set_current_position(position);
+            }
             obj = statement.run(context).map_err(|e| {
                 // No matter how control leaves the Block the LexicalEnvironment is always
                 // restored to its former state.
-                context.pop_environment();
+                if let Some(env) = context.pop_environment() {
+                    context
+                        .executor()
+                        .save_resume_state(self, ResumeState::Environment(env));
+                }
                 e
             })?;
 
@@ -77,14 +100,17 @@ impl Executable for Block {
                     // Early return.
                     break;
                 }
-                InterpreterState::Break(_label) => {
-                    // TODO, break to a label.
-
-                    // Early break.
+                InterpreterState::Break(label) => {
+                    // Early break, ending the block if the break targets its label.
+                    if label.is_some() && label.as_deref() == self.label() {
+                        context
+                            .executor()
+                            .set_current_state(InterpreterState::Executing);
+                    }
                     break;
                 }
                 InterpreterState::Continue(_label) => {
-                    // TODO, continue to a label
+                    // A continue always targets an enclosing loop.
                     break;
                 }
                 InterpreterState::Executing => {
@@ -109,6 +135,7 @@ where
     fn from(list: T) -> Self {
         Self {
             statements: list.into(),
+            label: None,
         }
     }
 }
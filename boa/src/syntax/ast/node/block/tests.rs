@@ -9,14 +9,13 @@ fn fmt() {
         another_statement();
         "#,
     );
-    // TODO: Once block labels are implemtned, this should be tested:
-    // super::super::test_formatting(
-    //     r#"
-    //     block_name: {
-    //         let a = function_call();
-    //         console.log("hello");
-    //     }
-    //     another_statement();
-    //     "#,
-    // );
+    super::super::test_formatting(
+        r#"
+        block_name: {
+            let a = function_call();
+            console.log("hello");
+        }
+        another_statement();
+        "#,
+    );
 }
@@ -3,7 +3,7 @@ use crate::{
     exec::Executable,
     exec::InterpreterState,
     gc::{Finalize, Trace},
-    syntax::ast::node::{join_nodes, Node},
+    syntax::ast::node::{field::get_super_field, join_nodes, Node},
     value::{Type, Value},
     BoaProfiler, Context, Result,
 };
@@ -64,8 +64,27 @@ impl Executable for Call {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Call", "exec");
         let (this, func) = match self.expr() {
+            // A method found on `super` is called with the `this` value of the current method.
+            Node::GetConstField(ref get_const_field) if get_const_field.obj() == &Node::Super => {
+                let this = context.get_this_binding()?;
+                let func = get_super_field(&this, &get_const_field.field().into(), context)?;
+                (this, func)
+            }
+            Node::GetField(ref get_field) if get_field.obj() == &Node::Super => {
+                let this = context.get_this_binding()?;
+                let key = get_field.field().run(context)?.to_property_key(context)?;
+                let func = get_super_field(&this, &key, context)?;
+                (this, func)
+            }
             Node::GetConstField(ref get_const_field) => {
                 let mut obj = get_const_field.obj().run(context)?;
+                if obj.is_null_or_undefined() {
+                    return context.throw_type_error(format!(
+                        "cannot read property '{}' of {}",
+                        get_const_field.field(),
+                        obj.display()
+                    ));
+                }
                 if obj.get_type() != Type::Object {
                     obj = Value::Object(obj.to_object(context)?);
                 }
@@ -76,38 +95,39 @@ impl Executable for Call {
             }
             Node::GetField(ref get_field) => {
                 let mut obj = get_field.obj().run(context)?;
+                let field = get_field.field().run(context)?;
+                let key = field.to_property_key(context)?;
+                if obj.is_null_or_undefined() {
+                    return context.throw_type_error(format!(
+                        "cannot read property '{}' of {}",
+                        key,
+                        obj.display()
+                    ));
+                }
                 if obj.get_type() != Type::Object {
                     obj = Value::Object(obj.to_object(context)?);
                 }
-                let field = get_field.field().run(context)?;
-                (
-                    obj.clone(),
-                    obj.get_field(field.to_property_key(context)?, context)?,
-                )
+                (obj.clone(), obj.get_field(key, context)?)
             }
-            _ => (
-                // 'this' binding should come from the function's self-contained environment
-                context.global_object().into(),
-                self.expr().run(context)?,
-            ),
-        };
-        let mut v_args = Vec::with_capacity(self.args().len());
-        for arg in self.args() {
-            if let Node::Spread(ref x) = arg {
-                let val = x.run(context)?;
-                let iterator_record = iterable::get_iterator(context, val)?;
-                loop {
-                    let next = iterator_record.next(context)?;
-                    if next.is_done() {
-                        break;
-                    }
-                    let next_value = next.value();
-                    v_args.push(next_value.clone());
-                }
-                break; // after spread we don't accept any new arguments
-            } else {
-                v_args.push(arg.run(context)?);
+            // A parenthesized optional chain keeps the object of its last property access as the
+            // `this` value, like `(a?.b)()`.
+            Node::Optional(ref optional) => optional.run_callee(context)?,
+            // A function found in the object of a `with` statement is called with the object as
+            // its `this` value.
+            Node::Identifier(ref name) => {
+                let this = context
+                    .get_with_base_object(name.as_ref())
+                    .map_or_else(Value::undefined, Value::from);
+                (this, self.expr().run(context)?)
             }
+            _ => (Value::undefined(), self.expr().run(context)?),
+        };
+        let v_args = evaluate_args(self.args(), context)?;
+        if !func.is_callable() {
+            return context.throw_type_error(format!(
+                "{} is not a function",
+                describe_callee(self.expr())
+            ));
         }
 
         // execute the function call itself
@@ -122,6 +142,62 @@ impl Executable for Call {
     }
 }
 
+/// Evaluates the arguments of a call, expanding spread arguments.
+///
+/// A spread argument runs its iterator until it is done before the next argument is evaluated,
+/// so an abrupt completion never leaves an iterator in progress, and iterators are never closed
+/// with `return()`, even if `next()` throws.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-argumentlistevaluation
+pub(crate) fn evaluate_args(args: &[Node], context: &mut Context) -> Result<Vec<Value>> {
+    let mut v_args = Vec::with_capacity(args.len());
+    for arg in args {
+        if let Node::Spread(ref x) = arg {
+            let val = x.run(context)?;
+            let iterator_record = iterable::get_iterator(context, val)?;
+            loop {
+                let next = iterator_record.next(context)?;
+                if next.is_done() {
+                    break;
+                }
+                let next_value = next.value();
+                v_args.push(next_value.clone());
+            }
+        } else {
+            v_args.push(arg.run(context)?);
+        }
+    }
+    Ok(v_args)
+}
+
+/// Describes the callee of a call in the `TypeError` thrown when it is not a function, like
+/// `obj.method` in `obj.method is not a function`.
+///
+/// The expressions that can't be described in a single line, like object literals or function
+/// calls, are described as `(intermediate value)`.
+pub(crate) fn describe_callee(callee: &Node) -> String {
+    match callee {
+        Node::Identifier(ref ident) => ident.to_string(),
+        Node::Const(ref constant) => constant.to_string(),
+        Node::This => "this".to_owned(),
+        Node::Super => "super".to_owned(),
+        Node::GetConstField(ref get_const_field) => format!(
+            "{}.{}",
+            describe_callee(get_const_field.obj()),
+            get_const_field.field()
+        ),
+        Node::GetField(ref get_field) => format!(
+            "{}[{}]",
+            describe_callee(get_field.obj()),
+            describe_callee(get_field.field())
+        ),
+        _ => "(intermediate value)".to_owned(),
+    }
+}
+
 impl fmt::Display for Call {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}(", self.expr)?;
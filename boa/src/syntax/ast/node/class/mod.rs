@@ -0,0 +1,402 @@
+//! Class declaration and expression nodes.
+
+use crate::{
+    builtins::function::{make_method, set_function_name, FunctionFlags},
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        lexical_environment::VariableScope,
+    },
+    exec::Executable,
+    gc::{Finalize, Trace},
+    object::{GcObject, PROTOTYPE},
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor},
+    syntax::ast::node::{join_nodes, FunctionExpr, MethodDefinitionKind, Node, PropertyName},
+    BoaProfiler, Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// A class defines a constructor function, with methods on its prototype and static methods on
+/// the constructor itself.
+///
+/// The body of a class contains method definitions, getters and setters, any of which can be
+/// `static`, with literal or computed names. The `constructor` method, if any, is the body of the
+/// constructor function.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-class-definitions
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Class {
+    name: Option<Box<str>>,
+    constructor: Option<FunctionExpr>,
+    elements: Box<[ClassElement]>,
+}
+
+impl Class {
+    /// Creates a new class.
+    pub(in crate::syntax) fn new<N, C, E>(name: N, constructor: C, elements: E) -> Self
+    where
+        N: Into<Option<Box<str>>>,
+        C: Into<Option<FunctionExpr>>,
+        E: Into<Box<[ClassElement]>>,
+    {
+        Self {
+            name: name.into(),
+            constructor: constructor.into(),
+            elements: elements.into(),
+        }
+    }
+
+    /// Gets the name of the class.
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Gets the `constructor` method of the class, if it has one.
+    pub fn constructor(&self) -> Option<&FunctionExpr> {
+        self.constructor.as_ref()
+    }
+
+    /// Gets the methods of the class, other than the constructor.
+    pub fn elements(&self) -> &[ClassElement] {
+        &self.elements
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        f.write_str("class")?;
+        if let Some(ref name) = self.name {
+            write!(f, " {}", name)?;
+        }
+        if self.constructor.is_none() && self.elements.is_empty() {
+            return f.write_str(" {}");
+        }
+        f.write_str(" {\n")?;
+        let indent = "    ".repeat(indentation + 1);
+        if let Some(ref constructor) = self.constructor {
+            write!(f, "{}constructor(", indent)?;
+            join_nodes(f, constructor.parameters())?;
+            f.write_str(") ")?;
+            constructor.display_block(f, indentation + 1)?;
+            writeln!(f)?;
+        }
+        for element in self.elements.iter() {
+            f.write_str(&indent)?;
+            if element.is_static() {
+                f.write_str("static ")?;
+            }
+            match element.kind() {
+                MethodDefinitionKind::Get => f.write_str("get ")?,
+                MethodDefinitionKind::Set => f.write_str("set ")?,
+                MethodDefinitionKind::Ordinary => {}
+            }
+            write!(f, "{}(", element.name())?;
+            join_nodes(f, element.function().parameters())?;
+            f.write_str(") ")?;
+            element.function().display_block(f, indentation + 1)?;
+            writeln!(f)?;
+        }
+        write!(f, "{}}}", "    ".repeat(indentation))
+    }
+
+    /// Creates the constructor function of the class, with its prototype and methods.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classdefinitionevaluation
+    fn evaluate(&self, context: &mut Context) -> Result<Value> {
+        // The methods of the class are evaluated in a scope where the name of the class refers to
+        // the class being defined.
+        let env = context.get_current_environment();
+        context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+        let result = self.define_class(context);
+        let _ = context.pop_environment();
+        result
+    }
+
+    fn define_class(&self, context: &mut Context) -> Result<Value> {
+        if let Some(name) = self.name() {
+            context.create_immutable_binding(name.to_owned(), true, VariableScope::Block)?;
+        }
+
+        let prototype = Value::new_object(context);
+        let constructor = match self.constructor {
+            Some(ref constructor) => context.create_function(
+                constructor.parameters().to_vec(),
+                constructor.body().to_vec(),
+                FunctionFlags::CALLABLE
+                    | FunctionFlags::CONSTRUCTABLE
+                    | FunctionFlags::CLASS_CONSTRUCTOR,
+            )?,
+            None => context.create_function(
+                Vec::new(),
+                Vec::new(),
+                FunctionFlags::CALLABLE
+                    | FunctionFlags::CONSTRUCTABLE
+                    | FunctionFlags::CLASS_CONSTRUCTOR,
+            )?,
+        };
+        let mut constructor = constructor
+            .as_object()
+            .expect("the constructor of a class is an object");
+        make_method(
+            &constructor,
+            prototype
+                .as_object()
+                .expect("the prototype of a class is an object"),
+        );
+        constructor.insert_property(
+            PROTOTYPE,
+            prototype.clone(),
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+        prototype
+            .as_object()
+            .expect("the prototype of a class is an object")
+            .insert_property(
+                "constructor",
+                constructor.clone(),
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            );
+        if let Some(name) = self.name() {
+            constructor.insert_property("name", name, Attribute::CONFIGURABLE);
+        }
+
+        for element in self.elements.iter() {
+            let target = if element.is_static() {
+                constructor.clone()
+            } else {
+                prototype
+                    .as_object()
+                    .expect("the prototype of a class is an object")
+            };
+            element.define(target, context)?;
+        }
+
+        if let Some(name) = self.name() {
+            context.initialize_binding(name, constructor.clone().into())?;
+        }
+        Ok(constructor.into())
+    }
+}
+
+/// A method, a getter or a setter defined in the body of a class.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassElement
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassElement {
+    is_static: bool,
+    #[unsafe_ignore_trace]
+    kind: MethodDefinitionKind,
+    name: PropertyName,
+    function: FunctionExpr,
+}
+
+impl ClassElement {
+    /// Creates a new class element.
+    pub(in crate::syntax) fn new<N>(
+        is_static: bool,
+        kind: MethodDefinitionKind,
+        name: N,
+        function: FunctionExpr,
+    ) -> Self
+    where
+        N: Into<PropertyName>,
+    {
+        Self {
+            is_static,
+            kind,
+            name: name.into(),
+            function,
+        }
+    }
+
+    /// Returns `true` if the method is defined on the constructor instead of the prototype.
+    pub fn is_static(&self) -> bool {
+        self.is_static
+    }
+
+    /// Gets the kind of the method.
+    pub fn kind(&self) -> MethodDefinitionKind {
+        self.kind
+    }
+
+    /// Gets the name of the method.
+    pub fn name(&self) -> &PropertyName {
+        &self.name
+    }
+
+    /// Gets the function of the method.
+    pub fn function(&self) -> &FunctionExpr {
+        &self.function
+    }
+
+    /// Defines the method on the prototype or the constructor of the class.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-classelementevaluation
+    fn define(&self, mut target: GcObject, context: &mut Context) -> Result<()> {
+        let key = self.name.to_property_key(context)?;
+        let function = self.function.run(context)?;
+        if let Some(mut function) = function.as_object() {
+            set_function_name(&mut function, &key, self.kind.prefix());
+            make_method(&function, target.clone());
+        }
+
+        let descriptor: PropertyDescriptor = match self.kind {
+            MethodDefinitionKind::Ordinary => DataDescriptor::new(
+                function,
+                Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
+            .into(),
+            kind => {
+                // A getter and a setter with the same name define a single accessor property.
+                let existing = target
+                    .get_own_property(&key)
+                    .as_ref()
+                    .and_then(PropertyDescriptor::as_accessor_descriptor)
+                    .cloned();
+                let (mut get, mut set) = existing
+                    .map(|accessor| (accessor.getter().cloned(), accessor.setter().cloned()))
+                    .unwrap_or_default();
+                if kind == MethodDefinitionKind::Get {
+                    get = function.as_object();
+                } else {
+                    set = function.as_object();
+                }
+                AccessorDescriptor::new(
+                    get,
+                    set,
+                    Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+                )
+                .into()
+            }
+        };
+        target.define_property_or_throw(key, descriptor, context)
+    }
+}
+
+/// A class declaration, which binds the name of the class in the current scope like `let`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "deser", serde(transparent))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassDecl {
+    class: Class,
+}
+
+impl ClassDecl {
+    /// Creates a new class declaration.
+    pub(in crate::syntax) fn new(class: Class) -> Self {
+        Self { class }
+    }
+
+    /// Gets the name of the declared class.
+    pub fn name(&self) -> &str {
+        self.class
+            .name()
+            .expect("a class declaration always has a name")
+    }
+
+    /// Gets the declared class.
+    pub fn class(&self) -> &Class {
+        &self.class
+    }
+}
+
+impl Executable for ClassDecl {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ClassDecl", "exec");
+        let class = self.class.evaluate(context)?;
+        context.create_mutable_binding(self.name().to_owned(), false, VariableScope::Block)?;
+        context.initialize_binding(self.name(), class)?;
+        Ok(Value::undefined())
+    }
+}
+
+impl fmt::Display for ClassDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.class.display(f, 0)
+    }
+}
+
+impl From<ClassDecl> for Node {
+    fn from(decl: ClassDecl) -> Self {
+        Self::ClassDecl(decl)
+    }
+}
+
+/// A class expression, which evaluates to the constructor of the class.
+///
+/// The name of a class expression, if any, is only bound in the body of the class.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/class
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "deser", serde(transparent))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ClassExpr {
+    class: Class,
+}
+
+impl ClassExpr {
+    /// Creates a new class expression.
+    pub(in crate::syntax) fn new(class: Class) -> Self {
+        Self { class }
+    }
+
+    /// Gets the class defined by the expression.
+    pub fn class(&self) -> &Class {
+        &self.class
+    }
+}
+
+impl Executable for ClassExpr {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ClassExpr", "exec");
+        self.class.evaluate(context)
+    }
+}
+
+impl fmt::Display for ClassExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.class.display(f, 0)
+    }
+}
+
+impl From<ClassExpr> for Node {
+    fn from(expr: ClassExpr) -> Self {
+        Self::ClassExpr(expr)
+    }
+}
@@ -0,0 +1,252 @@
+use crate::{exec, forward, Context};
+
+#[test]
+fn class_constructor_and_methods() {
+    let scenario = r#"
+        class Point {
+            constructor(x, y) {
+                this.x = x;
+                this.y = y;
+            }
+            add(other) {
+                return new Point(this.x + other.x, this.y + other.y);
+            }
+        }
+
+        let p = new Point(1, 2).add(new Point(3, 4));
+        [p.x, p.y, p instanceof Point, Point.prototype.constructor === Point].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"4,6,true,true\"");
+}
+
+#[test]
+fn class_accessors_live_on_the_prototype() {
+    let mut context = Context::new();
+    let init = r#"
+        let name = "area";
+        class Rect {
+            constructor(width, height) {
+                this.width = width;
+                this.height = height;
+            }
+            get [name]() {
+                return this.width * this.height;
+            }
+            set [name](value) {
+                this.width = value / this.height;
+            }
+        }
+        let rect = new Rect(2, 3);
+        let descriptor = Object.getOwnPropertyDescriptor(Rect.prototype, "area");
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "rect.area"), "6");
+    assert_eq!(forward(&mut context, "rect.area = 12; rect.width"), "4");
+    assert_eq!(
+        forward(&mut context, "rect.hasOwnProperty('area')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "typeof descriptor.get"),
+        "\"function\""
+    );
+    assert_eq!(
+        forward(&mut context, "typeof descriptor.set"),
+        "\"function\""
+    );
+    assert_eq!(forward(&mut context, "descriptor.enumerable"), "false");
+    assert_eq!(forward(&mut context, "descriptor.configurable"), "true");
+    assert_eq!(forward(&mut context, "descriptor.get.name"), "\"get area\"");
+}
+
+#[test]
+fn class_static_members() {
+    let mut context = Context::new();
+    let init = r#"
+        class Temperature {
+            constructor(celsius) {
+                this.celsius = celsius;
+            }
+            static fromFahrenheit(fahrenheit) {
+                return new Temperature((fahrenheit - 32) * 5 / 9);
+            }
+            static get unit() {
+                return "C";
+            }
+            static ["zero" + "Point"]() {
+                return new Temperature(0);
+            }
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "Temperature.fromFahrenheit(212).celsius"),
+        "100"
+    );
+    assert_eq!(forward(&mut context, "Temperature.unit"), "\"C\"");
+    assert_eq!(
+        forward(&mut context, "Temperature.zeroPoint().celsius"),
+        "0"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temperature.prototype.hasOwnProperty('fromFahrenheit')"
+        ),
+        "false"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Temperature.propertyIsEnumerable('fromFahrenheit')"
+        ),
+        "false"
+    );
+}
+
+#[test]
+fn class_constructor_requires_new() {
+    let scenario = r#"
+        class A {}
+        try {
+            A();
+        } catch (e) {
+            e.message;
+        }
+    "#;
+    assert_eq!(
+        &exec(scenario),
+        "\"Class constructor A cannot be invoked without 'new'\""
+    );
+}
+
+#[test]
+fn class_expression() {
+    let mut context = Context::new();
+    let init = r#"
+        let Anonymous = class {
+            value() {
+                return 1;
+            }
+        };
+        let Named = class Inner {
+            self() {
+                return Inner;
+            }
+        };
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "new Anonymous().value()"), "1");
+    assert_eq!(forward(&mut context, "Named.name"), "\"Inner\"");
+    assert_eq!(
+        forward(&mut context, "new Named().self() === Named"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "typeof Inner"), "\"undefined\"");
+}
+
+#[test]
+fn class_declaration_is_block_scoped() {
+    let scenario = r#"
+        {
+            class Hidden {}
+        }
+        typeof Hidden;
+    "#;
+    assert_eq!(&exec(scenario), "\"undefined\"");
+}
+
+#[test]
+fn super_property_access_in_methods() {
+    let mut context = Context::new();
+    let init = r#"
+        class Animal {
+            constructor(name) {
+                this.name = name;
+            }
+            speak() {
+                return this.name + " makes a sound";
+            }
+            get constant() {
+                return "animal " + this.name;
+            }
+        }
+        class Dog {
+            constructor(name) {
+                this.name = name;
+            }
+            speak() {
+                return super.speak() + " and barks";
+            }
+            get constant() {
+                return super.constant;
+            }
+            later() {
+                return () => super["speak"]();
+            }
+        }
+        Object.setPrototypeOf(Dog.prototype, Animal.prototype);
+        let dog = new Dog("Rex");
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "dog.speak()"),
+        "\"Rex makes a sound and barks\""
+    );
+    assert_eq!(forward(&mut context, "dog.constant"), "\"animal Rex\"");
+    assert_eq!(
+        forward(&mut context, "dog.later()()"),
+        "\"Rex makes a sound\""
+    );
+}
+
+#[test]
+fn super_property_access_in_object_literals() {
+    let scenario = r#"
+        let base = {
+            greet() {
+                return "hello " + this.name;
+            },
+        };
+        let obj = {
+            name: "world",
+            greet() {
+                return super.greet() + "!";
+            },
+            set value(v) {
+                super.stored = v;
+            },
+        };
+        Object.setPrototypeOf(obj, base);
+        obj.value = 3;
+        [obj.greet(), obj.stored, base.stored].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"hello world!,3,\"");
+}
+
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        class Counter {
+            constructor(start) {
+                this.count = start;
+            }
+            increment() {
+                this.count += 1;
+            }
+            get [Symbol.toStringTag]() {
+                return "Counter";
+            }
+            static create() {
+                return new Counter(0);
+            }
+        };
+        let Empty = class {};
+        "#,
+    );
+}
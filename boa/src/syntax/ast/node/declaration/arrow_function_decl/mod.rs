@@ -44,12 +44,12 @@ impl ArrowFunctionDecl {
     }
 
     /// Gets the list of parameters of the arrow function.
-    pub(crate) fn params(&self) -> &[FormalParameter] {
+    pub fn params(&self) -> &[FormalParameter] {
         &self.params
     }
 
     /// Gets the body of the arrow function.
-    pub(crate) fn body(&self) -> &[Node] {
+    pub fn body(&self) -> &[Node] {
         &self.body.items()
     }
 
@@ -75,10 +75,8 @@ impl Executable for ArrowFunctionDecl {
     fn run(&self, context: &mut Context) -> Result<Value> {
         context.create_function(
             self.params().to_vec(),
-            self.body().to_vec(),
-            FunctionFlags::CALLABLE
-                | FunctionFlags::CONSTRUCTABLE
-                | FunctionFlags::LEXICAL_THIS_MODE,
+            self.body.clone(),
+            FunctionFlags::CALLABLE | FunctionFlags::LEXICAL_THIS_MODE,
         )
     }
 }
@@ -1,6 +1,8 @@
 //! Async Function Declaration.
 
 use crate::{
+    builtins::function::FunctionFlags,
+    environment::lexical_environment::VariableScope,
     exec::Executable,
     syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
     BoaProfiler, Context, Result, Value,
@@ -13,6 +15,9 @@ use serde::{Deserialize, Serialize};
 
 /// An async function is used to specify an action (or series of actions) to perform asynchronously.
 ///
+/// Calling an async function returns a promise, which is settled when its body completes. The body
+/// is suspended by its `await` expressions until the promise they await is settled.
+///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///  - [MDN documentation][mdn]
@@ -79,9 +84,26 @@ impl AsyncFunctionDecl {
 }
 
 impl Executable for AsyncFunctionDecl {
-    fn run(&self, _: &mut Context) -> Result<Value> {
+    fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("AsyncFunctionDecl", "exec");
-        // TODO: Implement AsyncFunctionDecl
+        let val = context.create_function(
+            self.parameters().to_vec(),
+            self.body.clone(),
+            FunctionFlags::CALLABLE | FunctionFlags::ASYNC,
+        )?;
+
+        // Set the name and assign it in the current environment
+        if let Some(name) = self.name() {
+            val.set_field("name", name, false, context)?;
+
+            if context.has_binding(name) {
+                context.set_mutable_binding(name, val, true)?;
+            } else {
+                context.create_mutable_binding(name.to_owned(), false, VariableScope::Function)?;
+
+                context.initialize_binding(name, val)?;
+            }
+        }
         Ok(Value::undefined())
     }
 }
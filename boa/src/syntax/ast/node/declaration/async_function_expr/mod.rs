@@ -1,6 +1,7 @@
 //! Async Function Expression.
 
 use crate::{
+    builtins::function::FunctionFlags,
     exec::Executable,
     syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
     Context, Result, Value,
@@ -81,9 +82,18 @@ impl AsyncFunctionExpr {
 }
 
 impl Executable for AsyncFunctionExpr {
-    fn run(&self, _: &mut Context) -> Result<Value> {
-        // TODO: Implement AsyncFunctionExpr
-        Ok(Value::Undefined)
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let val = context.create_function(
+            self.parameters().to_vec(),
+            self.body.clone(),
+            FunctionFlags::CALLABLE | FunctionFlags::ASYNC,
+        )?;
+
+        if let Some(name) = self.name() {
+            val.set_field("name", Value::from(name), false, context)?;
+        }
+
+        Ok(val)
     }
 }
 
@@ -0,0 +1,123 @@
+use crate::{
+    builtins::function::FunctionFlags,
+    environment::lexical_environment::VariableScope,
+    exec::Executable,
+    gc::{Finalize, Trace},
+    syntax::ast::node::{join_nodes, FormalParameter, Node, StatementList},
+    BoaProfiler, Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// The `async function*` declaration defines an async generator function with the specified
+/// parameters.
+///
+/// Calling an async generator function returns an async generator object, whose `next()` method
+/// returns a promise for the value of the next `yield` expression of the body.
+///
+/// An async generator function can also be created using an expression (see [async generator
+/// expression][gen_expr]).
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AsyncGeneratorDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/async_function*
+/// [gen_expr]: ../enum.Node.html#variant.AsyncGeneratorExpr
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct AsyncGeneratorDecl {
+    name: Box<str>,
+    parameters: Box<[FormalParameter]>,
+    body: StatementList,
+}
+
+impl AsyncGeneratorDecl {
+    /// Creates a new async generator declaration.
+    pub(in crate::syntax) fn new<N, P, B>(name: N, parameters: P, body: B) -> Self
+    where
+        N: Into<Box<str>>,
+        P: Into<Box<[FormalParameter]>>,
+        B: Into<StatementList>,
+    {
+        Self {
+            name: name.into(),
+            parameters: parameters.into(),
+            body: body.into(),
+        }
+    }
+
+    /// Gets the name of the async generator declaration.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the list of parameters of the async generator declaration.
+    pub fn parameters(&self) -> &[FormalParameter] {
+        &self.parameters
+    }
+
+    /// Gets the body of the async generator declaration.
+    pub fn body(&self) -> &[Node] {
+        self.body.items()
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        write!(f, "async function* {}(", self.name)?;
+        join_nodes(f, &self.parameters)?;
+        if self.body().is_empty() {
+            f.write_str(") {}")
+        } else {
+            f.write_str(") {\n")?;
+            self.body.display(f, indentation + 1)?;
+            write!(f, "{}}}", "    ".repeat(indentation))
+        }
+    }
+}
+
+impl Executable for AsyncGeneratorDecl {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("AsyncGeneratorDecl", "exec");
+        let val = context.create_function(
+            self.parameters().to_vec(),
+            self.body.clone(),
+            FunctionFlags::CALLABLE | FunctionFlags::GENERATOR | FunctionFlags::ASYNC,
+        )?;
+
+        // Set the name and assign it in the current environment
+        val.set_field("name", self.name(), false, context)?;
+
+        if context.has_binding(self.name()) {
+            context.set_mutable_binding(self.name(), val, true)?;
+        } else {
+            context.create_mutable_binding(
+                self.name().to_owned(),
+                false,
+                VariableScope::Function,
+            )?;
+
+            context.initialize_binding(self.name(), val)?;
+        }
+        Ok(Value::undefined())
+    }
+}
+
+impl From<AsyncGeneratorDecl> for Node {
+    fn from(decl: AsyncGeneratorDecl) -> Self {
+        Self::AsyncGeneratorDecl(decl)
+    }
+}
+
+impl fmt::Display for AsyncGeneratorDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
@@ -90,7 +90,7 @@ impl Executable for FunctionDecl {
         let _timer = BoaProfiler::global().start_event("FunctionDecl", "exec");
         let val = context.create_function(
             self.parameters().to_vec(),
-            self.body().to_vec(),
+            self.body.clone(),
             FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
         )?;
 
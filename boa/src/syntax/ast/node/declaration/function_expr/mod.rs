@@ -101,7 +101,7 @@ impl Executable for FunctionExpr {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let val = context.create_function(
             self.parameters().to_vec(),
-            self.body().to_vec(),
+            self.body.clone(),
             FunctionFlags::CALLABLE | FunctionFlags::CONSTRUCTABLE,
         )?;
 
@@ -1,5 +1,6 @@
 //! Declaration nodes
 use crate::{
+    builtins::generator::ResumeState,
     environment::lexical_environment::VariableScope,
     exec::Executable,
     gc::{Finalize, Trace},
@@ -14,13 +15,18 @@ use serde::{Deserialize, Serialize};
 pub mod arrow_function_decl;
 pub mod async_function_decl;
 pub mod async_function_expr;
+pub mod async_generator_decl;
+pub mod async_generator_expr;
 pub mod function_decl;
 pub mod function_expr;
+pub mod generator_decl;
+pub mod generator_expr;
 
 pub use self::{
     arrow_function_decl::ArrowFunctionDecl, async_function_decl::AsyncFunctionDecl,
-    async_function_expr::AsyncFunctionExpr, function_decl::FunctionDecl,
-    function_expr::FunctionExpr,
+    async_function_expr::AsyncFunctionExpr, async_generator_decl::AsyncGeneratorDecl,
+    async_generator_expr::AsyncGeneratorExpr, function_decl::FunctionDecl,
+    function_expr::FunctionExpr, generator_decl::GeneratorDecl, generator_expr::GeneratorExpr,
 };
 
 #[cfg(test)]
@@ -91,13 +97,23 @@ pub enum DeclarationList {
 
 impl Executable for DeclarationList {
     fn run(&self, context: &mut Context) -> Result<Value> {
-        for decl in self.as_ref() {
+        // The declarations evaluated before a generator was suspended are not evaluated again.
+        let start = match context.executor().take_resume_state(self) {
+            Some(ResumeState::Declaration(index)) => index,
+            _ => 0,
+        };
+        for (index, decl) in self.as_ref().iter().enumerate().skip(start) {
             use DeclarationList::*;
             let val = match decl.init() {
                 None if self.is_const() => {
                     return context.throw_syntax_error("missing = in const declaration")
                 }
-                Some(init) => init.run(context)?,
+                Some(init) => init.run_named(decl.name(), context).map_err(|e| {
+                    context
+                        .executor()
+                        .save_resume_state(self, ResumeState::Declaration(index));
+                    e
+                })?,
                 None => Value::undefined(),
             };
 
@@ -11,6 +11,19 @@ fn duplicate_function_name() {
     assert_eq!(&exec(scenario), "12");
 }
 
+#[test]
+fn arrow_function_pattern_parameters() {
+    let scenario = r#"
+    const object = ({a}) => a;
+    const array = ([a, , b]) => a + b;
+    const defaults = (a = 1, [b] = [2], {c} = {c: 3}) => a + b + c;
+    const rest = ({a}, ...r) => a + r.length;
+    [object({a: 1}), array([1, 0, 2]), defaults(), rest({a: 1}, 0, 0)]
+    "#;
+
+    assert_eq!(&exec(scenario), "[ 1, 3, 6, 3 ]");
+}
+
 #[test]
 fn fmt() {
     super::super::test_formatting(
@@ -19,13 +32,14 @@ fn fmt() {
             console.log(a);
         };
         function func_2(a, b) {};
+        async function async_func(a, b) {
+            console.log(a);
+        };
+        async function async_func_2(a, b) {};
         let arrow_func = (a, b) => {
             console.log("in multi statement arrow");
             console.log(b);
         };
-        async function async_func(a, b) {
-            console.log(a);
-        };
         pass_async_func(async function(a, b) {
             console.log("in async callback", a);
         });
@@ -33,7 +47,6 @@ fn fmt() {
             console.log("in callback", a);
         });
         let arrow_func_2 = (a, b) => {};
-        async function async_func_2(a, b) {};
         pass_async_func(async function(a, b) {});
         pass_func(function(a, b) {});
         "#,
@@ -1,7 +1,8 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    object::CachedSlot,
+    syntax::ast::node::{field::get_super_field, Node},
     value::{Type, Value},
     Context, Result,
 };
@@ -32,10 +33,14 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#sec-property-accessors
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Property_accessors#Dot_notation
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct GetConstField {
     obj: Box<Node>,
     field: Box<str>,
+    /// The slot of the inline cache of this access site.
+    #[cfg_attr(feature = "deser", serde(skip))]
+    #[unsafe_ignore_trace]
+    slot: CachedSlot,
 }
 
 impl GetConstField {
@@ -48,6 +53,7 @@ impl GetConstField {
         Self {
             obj: Box::new(value.into()),
             field: label.into(),
+            slot: CachedSlot::default(),
         }
     }
 
@@ -60,16 +66,48 @@ impl GetConstField {
     pub fn field(&self) -> &str {
         &self.field
     }
+
+    /// Gets the slot of the inline cache of this access site.
+    pub(crate) fn slot(&self) -> &CachedSlot {
+        &self.slot
+    }
+}
+
+impl PartialEq for GetConstField {
+    fn eq(&self, other: &Self) -> bool {
+        // The cached slot is not part of the syntax.
+        self.obj == other.obj && self.field == other.field
+    }
 }
 
 impl Executable for GetConstField {
     fn run(&self, context: &mut Context) -> Result<Value> {
+        if let Node::Super = self.obj() {
+            let this = context.get_this_binding()?;
+            return get_super_field(&this, &self.field().into(), context);
+        }
+
         let mut obj = self.obj().run(context)?;
         if obj.get_type() != Type::Object {
             obj = Value::Object(obj.to_object(context)?);
         }
 
-        obj.get_field(self.field(), context)
+        if let Value::Object(ref object) = obj {
+            if let Some(value) = context
+                .property_cache()
+                .get(&self.slot, object, self.field())
+            {
+                return Ok(value);
+            }
+        }
+
+        let value = obj.get_field(self.field(), context)?;
+        if let Value::Object(ref object) = obj {
+            context
+                .property_cache()
+                .record(&self.slot, object, self.field());
+        }
+        Ok(value)
     }
 }
 
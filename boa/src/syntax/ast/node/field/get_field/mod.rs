@@ -1,7 +1,7 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{field::get_super_field, Node},
     value::{Type, Value},
     Context, Result,
 };
@@ -63,6 +63,12 @@ impl GetField {
 
 impl Executable for GetField {
     fn run(&self, context: &mut Context) -> Result<Value> {
+        if let Node::Super = self.obj() {
+            let this = context.get_this_binding()?;
+            let key = self.field().run(context)?.to_property_key(context)?;
+            return get_super_field(&this, &key, context);
+        }
+
         let mut obj = self.obj().run(context)?;
         if obj.get_type() != Type::Object {
             obj = Value::Object(obj.to_object(context)?);
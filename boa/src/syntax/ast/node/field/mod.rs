@@ -5,5 +5,61 @@ pub mod get_field;
 
 pub use self::{get_const_field::GetConstField, get_field::GetField};
 
+use crate::{object::GcObject, property::PropertyKey, Context, Result, Value};
+
 #[cfg(test)]
 mod tests;
+
+/// Gets the object that `super` refers to in the current method, which is the prototype of the
+/// home object of the method.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-getsuperbase
+fn get_super_base(context: &mut Context) -> Result<GcObject> {
+    // The parser only allows `super` in methods, and in the arrow functions nested in methods.
+    let home_object = context
+        .get_home_object()
+        .as_object()
+        .expect("super is used outside of a method");
+    match home_object.get_prototype_of().as_object() {
+        Some(base) => Ok(base),
+        None => Err(context
+            .construct_type_error("cannot access a property of super, its prototype is null")),
+    }
+}
+
+/// Gets the property `key` of `super`, for `super.key` and `super[key]`.
+///
+/// The property is looked up from the prototype of the home object, but getters are called with
+/// the `this` value of the method, like the methods found on `super`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-super-keyword-runtime-semantics-evaluation
+pub(crate) fn get_super_field(
+    this: &Value,
+    key: &PropertyKey,
+    context: &mut Context,
+) -> Result<Value> {
+    get_super_base(context)?.get(key, this.clone(), context)
+}
+
+/// Sets the property `key` of `super`, for `super.key = value` and `super[key] = value`.
+///
+/// The property is looked up from the prototype of the home object, so setters are called with
+/// the `this` value of the method, and data properties are created on `this`.
+pub(crate) fn set_super_field(
+    this: &Value,
+    key: PropertyKey,
+    value: Value,
+    context: &mut Context,
+) -> Result<()> {
+    let success = get_super_base(context)?.set(key, value, this.clone(), context)?;
+    if !success && context.executor().strict() {
+        return Err(context.construct_type_error("Cannot assign value to property"));
+    }
+    Ok(())
+}
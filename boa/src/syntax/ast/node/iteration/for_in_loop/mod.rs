@@ -1,5 +1,5 @@
 use crate::{
-    builtins::{iterable::IteratorRecord, ForInIterator},
+    builtins::{generator::ResumeState, iterable::IteratorRecord, ForInIterator},
     environment::{
         declarative_environment_record::DeclarativeEnvironmentRecord,
         lexical_environment::VariableScope,
@@ -82,119 +82,150 @@ impl From<ForInLoop> for Node {
 impl Executable for ForInLoop {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("ForIn", "exec");
-        let object = self.expr().run(context)?;
         let mut result = Value::undefined();
-
-        if object.is_null_or_undefined() {
-            return Ok(result);
-        }
-        let object = object.to_object(context)?;
-        let for_in_iterator = ForInIterator::create_for_in_iterator(context, Value::from(object));
-        let next_function = for_in_iterator
-            .get_property("next")
-            .map(|p| p.as_data_descriptor().unwrap().value())
-            .ok_or_else(|| context.construct_type_error("Could not find property `next`"))?;
-        let iterator = IteratorRecord::new(for_in_iterator, next_function);
+        let (iterator, mut resumed_environment) = if let Some(ResumeState::Iteration {
+            iterator,
+            environment,
+        }) = context.executor().take_resume_state(self)
+        {
+            (iterator, Some(environment))
+        } else {
+            let object = self.expr().run(context)?;
+            if object.is_null_or_undefined() {
+                return Ok(result);
+            }
+            let object = object.to_object(context)?;
+            let for_in_iterator =
+                ForInIterator::create_for_in_iterator(context, Value::from(object));
+            let next_function = for_in_iterator
+                .get_property("next")
+                .map(|p| p.as_data_descriptor().unwrap().value())
+                .ok_or_else(|| context.construct_type_error("Could not find property `next`"))?;
+            (IteratorRecord::new(for_in_iterator, next_function), None)
+        };
 
         loop {
-            {
+            if let Some(env) = resumed_environment.take() {
+                // The iteration of a resumed generator continues in the environment it was
+                // suspended in.
+                context.push_environment(env);
+            } else {
                 let env = context.get_current_environment();
                 context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
-            }
-            let iterator_result = iterator.next(context)?;
-            if iterator_result.is_done() {
-                context.pop_environment();
-                break;
-            }
-            let next_result = iterator_result.value();
-
-            match self.variable() {
-                Node::Identifier(ref name) => {
-                    if context.has_binding(name.as_ref()) {
-                        // Binding already exists
-                        context.set_mutable_binding(name.as_ref(), next_result.clone(), true)?;
-                    } else {
-                        context.create_mutable_binding(
-                            name.as_ref().to_owned(),
-                            true,
-                            VariableScope::Function,
-                        )?;
-                        context.initialize_binding(name.as_ref(), next_result.clone())?;
-                    }
+                let iterator_result = iterator.next(context)?;
+                if iterator_result.is_done() {
+                    context.pop_environment();
+                    break;
                 }
-                Node::VarDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
-                        }
-
-                        if context.has_binding(var.name()) {
-                            context.set_mutable_binding(var.name(), next_result, true)?;
+                let next_result = iterator_result.value();
+
+                match self.variable() {
+                    Node::Identifier(ref name) => {
+                        if context.has_binding(name.as_ref()) {
+                            // Binding already exists
+                            context.set_mutable_binding(
+                                name.as_ref(),
+                                next_result.clone(),
+                                true,
+                            )?;
                         } else {
                             context.create_mutable_binding(
-                                var.name().to_owned(),
-                                false,
+                                name.as_ref().to_owned(),
+                                true,
                                 VariableScope::Function,
                             )?;
-                            context.initialize_binding(var.name(), next_result)?;
+                            context.initialize_binding(name.as_ref(), next_result.clone())?;
                         }
                     }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-in loop",
-                        )
-                    }
-                },
-                Node::LetDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+                    Node::VarDeclList(ref list) => {
+                        match list.as_ref() {
+                            [var] => {
+                                if var.init().is_some() {
+                                    return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+                                }
+
+                                if context.has_binding(var.name()) {
+                                    context.set_mutable_binding(var.name(), next_result, true)?;
+                                } else {
+                                    context.create_mutable_binding(
+                                        var.name().to_owned(),
+                                        false,
+                                        VariableScope::Function,
+                                    )?;
+                                    context.initialize_binding(var.name(), next_result)?;
+                                }
+                            }
+                            _ => return context.throw_syntax_error(
+                                "only one variable can be declared in the head of a for-in loop",
+                            ),
                         }
-
-                        context.create_mutable_binding(
-                            var.name().to_owned(),
-                            false,
-                            VariableScope::Block,
-                        )?;
-                        context.initialize_binding(var.name(), next_result)?;
                     }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-in loop",
-                        )
+                    Node::LetDeclList(ref list) => {
+                        match list.as_ref() {
+                            [var] => {
+                                if var.init().is_some() {
+                                    return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+                                }
+
+                                context.create_mutable_binding(
+                                    var.name().to_owned(),
+                                    false,
+                                    VariableScope::Block,
+                                )?;
+                                context.initialize_binding(var.name(), next_result)?;
+                            }
+                            _ => return context.throw_syntax_error(
+                                "only one variable can be declared in the head of a for-in loop",
+                            ),
+                        }
                     }
-                },
-                Node::ConstDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+                    Node::ConstDeclList(ref list) => {
+                        match list.as_ref() {
+                            [var] => {
+                                if var.init().is_some() {
+                                    return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+                                }
+
+                                context.create_immutable_binding(
+                                    var.name().to_owned(),
+                                    false,
+                                    VariableScope::Block,
+                                )?;
+                                context.initialize_binding(var.name(), next_result)?;
+                            }
+                            _ => return context.throw_syntax_error(
+                                "only one variable can be declared in the head of a for-in loop",
+                            ),
                         }
-
-                        context.create_immutable_binding(
-                            var.name().to_owned(),
-                            false,
-                            VariableScope::Block,
-                        )?;
-                        context.initialize_binding(var.name(), next_result)?;
                     }
-                    _ => {
+                    Node::Assign(_) => {
                         return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-in loop",
-                        )
+                            "a declaration in the head of a for-in loop can't have an initializer",
+                        );
+                    }
+                    _ => {
+                        return context
+                            .throw_syntax_error("unknown left hand side in head of for-in loop")
                     }
-                },
-                Node::Assign(_) => {
-                    return context.throw_syntax_error(
-                        "a declaration in the head of a for-in loop can't have an initializer",
-                    );
-                }
-                _ => {
-                    return context
-                        .throw_syntax_error("unknown left hand side in head of for-in loop")
                 }
             }
 
-            result = self.body().run(context)?;
+            result = match self.body().run(context) {
+                Ok(result) => result,
+                Err(e) => {
+                    if let Some(environment) = context.pop_environment() {
+                        context.executor().save_resume_state(
+                            self,
+                            ResumeState::Iteration {
+                                iterator,
+                                environment,
+                            },
+                        );
+                    }
+                    return Err(e);
+                }
+            };
+            let _ = context.pop_environment();
             match context.executor().get_current_state() {
                 InterpreterState::Break(label) => {
                     handle_state_with_labels!(self, label, context, break);
@@ -210,7 +241,6 @@ impl Executable for ForInLoop {
                 #[cfg(feature = "vm")]
                 InterpreterState::Error => {}
             }
-            let _ = context.pop_environment();
         }
         Ok(result)
     }
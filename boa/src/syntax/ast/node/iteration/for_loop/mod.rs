@@ -1,8 +1,12 @@
 use crate::{
-    environment::declarative_environment_record::DeclarativeEnvironmentRecord,
+    builtins::generator::ResumeState,
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        environment_record_trait::EnvironmentRecordTrait,
+    },
     exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Declaration, Node},
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -101,15 +105,41 @@ impl Executable for ForLoop {
     fn run(&self, context: &mut Context) -> Result<Value> {
         // Create the block environment.
         let _timer = BoaProfiler::global().start_event("ForLoop", "exec");
-        {
+        if let Some(ResumeState::Environment(env)) = context.executor().take_resume_state(self) {
+            // The loop of a resumed generator is executed in the environment it was suspended in.
+            context.push_environment(env);
+        } else {
             let env = context.get_current_environment();
             context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
         }
 
+        let result = self.run_loop(context);
+
+        // pop the block env
+        let env = context.pop_environment();
+        if let (Err(_), Some(env)) = (&result, env) {
+            context
+                .executor()
+                .save_resume_state(self, ResumeState::Environment(env));
+        }
+
+        result
+    }
+}
+
+impl ForLoop {
+    /// Executes the loop, in its block environment.
+    fn run_loop(&self, context: &mut Context) -> Result<Value> {
         if let Some(init) = self.init() {
             init.run(context)?;
         }
 
+        let per_iteration_lets: Vec<&str> = match self.init() {
+            Some(Node::LetDeclList(list)) => list.as_ref().iter().map(Declaration::name).collect(),
+            _ => Vec::new(),
+        };
+        create_per_iteration_environment(&per_iteration_lets, context)?;
+
         while self
             .condition()
             .map(|cond| cond.run(context).map(|v| v.to_boolean()))
@@ -137,18 +167,54 @@ impl Executable for ForLoop {
                 InterpreterState::Error => {}
             }
 
+            create_per_iteration_environment(&per_iteration_lets, context)?;
+
             if let Some(final_expr) = self.final_expr() {
                 final_expr.run(context)?;
             }
         }
 
-        // pop the block env
-        let _ = context.pop_environment();
-
         Ok(Value::undefined())
     }
 }
 
+/// Replaces the loop environment with a new one holding copies of the `let` bindings declared in
+/// the loop head, so that closures created in one iteration don't see the bindings change in the
+/// next one.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-createperiterationenvironment
+fn create_per_iteration_environment(names: &[&str], context: &mut Context) -> Result<()> {
+    // 1. If perIterationBindings has any elements, then
+    if names.is_empty() {
+        return Ok(());
+    }
+
+    // a. Let lastIterationEnv be the running execution context's LexicalEnvironment.
+    // b. Let outer be lastIterationEnv.[[OuterEnv]].
+    // d. Let thisIterationEnv be NewDeclarativeEnvironment(outer).
+    let last_iteration_env = context.get_current_environment();
+    let this_iteration_env =
+        DeclarativeEnvironmentRecord::new(last_iteration_env.get_outer_environment());
+
+    // e. For each element bn of perIterationBindings, do
+    for &name in names {
+        // i. Perform ! thisIterationEnv.CreateMutableBinding(bn, false).
+        // ii. Let lastValue be ? lastIterationEnv.GetBindingValue(bn, true).
+        // iii. Perform thisIterationEnv.InitializeBinding(bn, lastValue).
+        this_iteration_env.create_mutable_binding(name.to_owned(), false, false, context)?;
+        let last_value = last_iteration_env.get_binding_value(name, true, context)?;
+        this_iteration_env.initialize_binding(name, last_value, context)?;
+    }
+
+    // f. Set the running execution context's LexicalEnvironment to thisIterationEnv.
+    context.pop_environment();
+    context.push_environment(this_iteration_env);
+    Ok(())
+}
+
 impl fmt::Display for ForLoop {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
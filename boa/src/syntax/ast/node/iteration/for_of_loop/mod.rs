@@ -1,5 +1,9 @@
 use crate::{
-    builtins::iterable::get_iterator,
+    builtins::{
+        async_function::await_value,
+        generator::{AsyncIterationStep, ResumeState, Resumption},
+        iterable::{get_async_iterator, get_iterator, IteratorRecord},
+    },
     environment::{
         declarative_environment_record::DeclarativeEnvironmentRecord,
         lexical_environment::VariableScope,
@@ -21,6 +25,7 @@ pub struct ForOfLoop {
     iterable: Box<Node>,
     body: Box<Node>,
     label: Option<Box<str>>,
+    r#await: bool,
 }
 
 impl ForOfLoop {
@@ -35,9 +40,23 @@ impl ForOfLoop {
             iterable: Box::new(iterable.into()),
             body: Box::new(body.into()),
             label: None,
+            r#await: false,
         }
     }
 
+    /// Creates a `for await...of` loop, which iterates over an async iterable in an async
+    /// function.
+    pub fn new_await<V, I, B>(variable: V, iterable: I, body: B) -> Self
+    where
+        V: Into<Node>,
+        I: Into<Node>,
+        B: Into<Node>,
+    {
+        let mut for_of = Self::new(variable, iterable, body);
+        for_of.r#await = true;
+        for_of
+    }
+
     pub fn variable(&self) -> &Node {
         &self.variable
     }
@@ -58,11 +77,17 @@ impl ForOfLoop {
         self.label = Some(label);
     }
 
+    /// Returns `true` if this is a `for await...of` loop.
+    pub fn is_await(&self) -> bool {
+        self.r#await
+    }
+
     pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         if let Some(ref label) = self.label {
             write!(f, "{}: ", label)?;
         }
-        write!(f, "for ({} of {}) ", self.variable, self.iterable)?;
+        f.write_str(if self.r#await { "for await (" } else { "for (" })?;
+        write!(f, "{} of {}) ", self.variable, self.iterable)?;
         self.body().display(f, indentation)
     }
 }
@@ -79,113 +104,399 @@ impl From<ForOfLoop> for Node {
     }
 }
 
-impl Executable for ForOfLoop {
-    fn run(&self, context: &mut Context) -> Result<Value> {
-        let _timer = BoaProfiler::global().start_event("ForOf", "exec");
-        let iterable = self.iterable().run(context)?;
-        let iterator = get_iterator(context, iterable)?;
-        let mut result = Value::undefined();
-
-        loop {
-            {
-                let env = context.get_current_environment();
-                context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
-            }
-            let iterator_result = iterator.next(context)?;
-            if iterator_result.is_done() {
-                context.pop_environment();
-                break;
+impl ForOfLoop {
+    /// Binds the variable of the loop to the value of the current iteration, in the environment
+    /// of the iteration.
+    fn bind_variable(&self, value: Value, context: &mut Context) -> Result<()> {
+        match self.variable() {
+            Node::Identifier(ref name) => {
+                if context.has_binding(name.as_ref()) {
+                    // Binding already exists
+                    context.set_mutable_binding(name.as_ref(), value, true)?;
+                } else {
+                    context.create_mutable_binding(
+                        name.as_ref().to_owned(),
+                        true,
+                        VariableScope::Function,
+                    )?;
+                    context.initialize_binding(name.as_ref(), value)?;
+                }
             }
-            let next_result = iterator_result.value();
+            Node::VarDeclList(ref list) => match list.as_ref() {
+                [var] => {
+                    if var.init().is_some() {
+                        return Err(context.construct_syntax_error(
+                            "a declaration in the head of a for-of loop can't have an initializer",
+                        ));
+                    }
 
-            match self.variable() {
-                Node::Identifier(ref name) => {
-                    if context.has_binding(name.as_ref()) {
-                        // Binding already exists
-                        context.set_mutable_binding(name.as_ref(), next_result.clone(), true)?;
+                    if context.has_binding(var.name()) {
+                        context.set_mutable_binding(var.name(), value, true)?;
                     } else {
                         context.create_mutable_binding(
-                            name.as_ref().to_owned(),
-                            true,
+                            var.name().to_owned(),
+                            false,
                             VariableScope::Function,
                         )?;
-                        context.initialize_binding(name.as_ref(), next_result.clone())?;
+                        context.initialize_binding(var.name(), value)?;
                     }
                 }
-                Node::VarDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
-
-                        if context.has_binding(var.name()) {
-                            context.set_mutable_binding(var.name(), next_result, true)?;
-                        } else {
-                            context.create_mutable_binding(
-                                var.name().to_owned(),
-                                false,
-                                VariableScope::Function,
-                            )?;
-                            context.initialize_binding(var.name(), next_result)?;
-                        }
-                    }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
+                _ => {
+                    return Err(context.construct_syntax_error(
+                        "only one variable can be declared in the head of a for-of loop",
+                    ))
+                }
+            },
+            Node::LetDeclList(ref list) => match list.as_ref() {
+                [var] => {
+                    if var.init().is_some() {
+                        return Err(context.construct_syntax_error(
+                            "a declaration in the head of a for-of loop can't have an initializer",
+                        ));
                     }
-                },
-                Node::LetDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
 
-                        context.create_mutable_binding(
-                            var.name().to_owned(),
-                            false,
-                            VariableScope::Block,
-                        )?;
+                    context.create_mutable_binding(
+                        var.name().to_owned(),
+                        false,
+                        VariableScope::Block,
+                    )?;
 
-                        context.initialize_binding(var.name(), next_result)?;
-                    }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
+                    context.initialize_binding(var.name(), value)?;
+                }
+                _ => {
+                    return Err(context.construct_syntax_error(
+                        "only one variable can be declared in the head of a for-of loop",
+                    ))
+                }
+            },
+            Node::ConstDeclList(ref list) => match list.as_ref() {
+                [var] => {
+                    if var.init().is_some() {
+                        return Err(context.construct_syntax_error(
+                            "a declaration in the head of a for-of loop can't have an initializer",
+                        ));
                     }
-                },
-                Node::ConstDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-of loop can't have an initializer");
-                        }
-
-                        context.create_immutable_binding(
-                            var.name().to_owned(),
-                            false,
-                            VariableScope::Block,
-                        )?;
-                        context.initialize_binding(var.name(), next_result)?;
+
+                    context.create_immutable_binding(
+                        var.name().to_owned(),
+                        false,
+                        VariableScope::Block,
+                    )?;
+                    context.initialize_binding(var.name(), value)?;
+                }
+                _ => {
+                    return Err(context.construct_syntax_error(
+                        "only one variable can be declared in the head of a for-of loop",
+                    ))
+                }
+            },
+            Node::Assign(_) => {
+                return Err(context.construct_syntax_error(
+                    "a declaration in the head of a for-of loop can't have an initializer",
+                ));
+            }
+            _ => {
+                return Err(
+                    context.construct_syntax_error("unknown left hand side in head of for-of loop")
+                )
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if a `break` or a `continue` with the given label targets this loop.
+    fn is_target(&self, label: &Option<Box<str>>) -> bool {
+        match label {
+            Some(label) => self.label() == Some(label.as_ref()),
+            None => true,
+        }
+    }
+
+    /// Evaluates a `for await...of` loop, resuming it at the step its async function was
+    /// suspended at.
+    ///
+    /// Each iteration awaits the result of the `next` method of the iterator, so the loop is
+    /// always suspended before the next iteration, and continues when it's replayed.
+    fn run_async(&self, context: &mut Context) -> Result<Value> {
+        let (iterator, step) = if let Some(ResumeState::AsyncIteration { iterator, step }) =
+            context.executor().take_resume_state(self)
+        {
+            (iterator, step)
+        } else {
+            let iterable = self.iterable().run(context)?;
+            let iterator = get_async_iterator(context, iterable)?;
+            return self.await_next(iterator, context);
+        };
+
+        match step {
+            AsyncIterationStep::Next => {
+                let result = match self.take_resumption(context) {
+                    Resumption::Next(result) => result,
+                    Resumption::Throw(reason) => return Err(reason),
+                    Resumption::Return(_) => {
+                        unreachable!("an await expression is resumed by return")
                     }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-of loop",
-                        )
+                };
+                if !result.is_object() {
+                    return context.throw_type_error("iterator result is not an object");
+                }
+                if result.get_field("done", context)?.to_boolean() {
+                    return Ok(Value::undefined());
+                }
+                let value = result.get_field("value", context)?;
+
+                let env = context.get_current_environment();
+                context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+                if let Err(error) = self.bind_variable(value, context) {
+                    context.pop_environment();
+                    return self.close_async(iterator, Err(error), context);
+                }
+                self.run_async_body(iterator, context)
+            }
+            AsyncIterationStep::Body(environment) => {
+                // The iteration continues in the environment it was suspended in.
+                context.push_environment(environment);
+                self.run_async_body(iterator, context)
+            }
+            AsyncIterationStep::Close {
+                completion,
+                state,
+                returning,
+            } => {
+                let inner_result = match self.take_resumption(context) {
+                    Resumption::Next(result) => Ok(Some(result)),
+                    Resumption::Throw(reason) => Err(reason),
+                    Resumption::Return(_) => {
+                        unreachable!("an await expression is resumed by return")
                     }
-                },
-                Node::Assign(_) => {
-                    return context.throw_syntax_error(
-                        "a declaration in the head of a for-of loop can't have an initializer",
+                };
+                Self::finish_close(completion, inner_result, state, returning, context)
+            }
+        }
+    }
+
+    /// Takes how the loop is resumed, once the promise it awaited is settled.
+    fn take_resumption(&self, context: &mut Context) -> Resumption {
+        context
+            .executor()
+            .generator_frame()
+            .and_then(|generator| generator.take_resumption(self))
+            .expect("a suspended for await...of loop is resumed")
+    }
+
+    /// Runs the body of the loop for the current iteration, whose environment was pushed, then
+    /// starts the next iteration or closes the iterator.
+    fn run_async_body(&self, iterator: IteratorRecord, context: &mut Context) -> Result<Value> {
+        let result = match self.body().run(context) {
+            Ok(result) => result,
+            Err(e) => {
+                let environment = context.pop_environment();
+                if !context.executor().is_suspending_generator() {
+                    return self.close_async(iterator, Err(e), context);
+                }
+                if let Some(environment) = environment {
+                    context.executor().save_resume_state(
+                        self,
+                        ResumeState::AsyncIteration {
+                            iterator,
+                            step: AsyncIterationStep::Body(environment),
+                        },
                     );
                 }
-                _ => {
-                    return context
-                        .throw_syntax_error("unknown left hand side in head of for-of loop")
+                return Err(e);
+            }
+        };
+        let _ = context.pop_environment();
+
+        match context.executor().get_current_state().clone() {
+            InterpreterState::Break(label) => {
+                if self.is_target(&label) {
+                    context
+                        .executor()
+                        .set_current_state(InterpreterState::Executing);
                 }
+                self.close_async(iterator, Ok(result), context)
             }
+            InterpreterState::Continue(label) if !self.is_target(&label) => {
+                self.close_async(iterator, Ok(result), context)
+            }
+            InterpreterState::Return => self.close_async(iterator, Ok(result), context),
+            _ => {
+                context
+                    .executor()
+                    .set_current_state(InterpreterState::Executing);
+                self.await_next(iterator, context)
+            }
+        }
+    }
+
+    /// Calls the `next` method of the iterator, and suspends the async function until its
+    /// result is settled.
+    fn await_next(&self, iterator: IteratorRecord, context: &mut Context) -> Result<Value> {
+        let result = context.call(iterator.next_function(), iterator.iterator_object(), &[])?;
+        let suspended = await_value(self, result, context);
+        context.executor().save_resume_state(
+            self,
+            ResumeState::AsyncIteration {
+                iterator,
+                step: AsyncIterationStep::Next,
+            },
+        );
+        suspended
+    }
+
+    /// AsyncIteratorClose( iteratorRecord, completion )
+    ///
+    /// Closes the iterator of a loop that is exited early, with the given completion, awaiting
+    /// the result of its `return` method.
+    ///
+    /// Like `close_iterator`, the `return` method is called as if the interpreter was executing
+    /// normally, and an early return of the generator being executed is resumed once the iterator
+    /// is closed.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-asynciteratorclose
+    fn close_async(
+        &self,
+        iterator: IteratorRecord,
+        completion: Result<Value>,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let returning = context.executor().take_generator_return();
+        let state = context.executor().get_current_state().clone();
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        let inner_result = iterator
+            .iterator_object()
+            .get_field("return", context)
+            .and_then(|r#return| {
+                if r#return.is_null_or_undefined() {
+                    Ok(None)
+                } else {
+                    context
+                        .call(&r#return, iterator.iterator_object(), &[])
+                        .map(Some)
+                }
+            });
+        let inner_result = match inner_result {
+            Ok(Some(result)) => {
+                let suspended = await_value(self, result, context);
+                if context.executor().is_suspending_generator() {
+                    context.executor().save_resume_state(
+                        self,
+                        ResumeState::AsyncIteration {
+                            iterator,
+                            step: AsyncIterationStep::Close {
+                                completion,
+                                state,
+                                returning,
+                            },
+                        },
+                    );
+                    return suspended;
+                }
+                suspended.map(Some)
+            }
+            inner_result => inner_result,
+        };
+        Self::finish_close(completion, inner_result, state, returning, context)
+    }
 
-            result = self.body().run(context)?;
+    /// Completes the loop once its iterator is closed, with the settled result of the `return`
+    /// method of the iterator, if it has one.
+    fn finish_close(
+        completion: Result<Value>,
+        inner_result: Result<Option<Value>>,
+        state: InterpreterState,
+        returning: Option<Value>,
+        context: &mut Context,
+    ) -> Result<Value> {
+        if completion.is_err() && returning.is_none() {
+            return completion;
+        }
+        match inner_result? {
+            Some(result) if !result.is_object() => {
+                context.throw_type_error("`return` method of iterator didn't return an Object")
+            }
+            _ => {
+                context.executor().set_current_state(state);
+                if let Some(value) = returning {
+                    context.executor().resume_generator_return(value);
+                }
+                completion
+            }
+        }
+    }
+}
+
+impl Executable for ForOfLoop {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ForOf", "exec");
+        if self.r#await {
+            let is_async = context
+                .executor()
+                .generator_frame()
+                .map_or(false, |generator| generator.is_async());
+            if !is_async {
+                return context.throw_syntax_error("for await is only valid in async functions");
+            }
+            return self.run_async(context);
+        }
+
+        let (iterator, mut resumed_environment) = if let Some(ResumeState::Iteration {
+            iterator,
+            environment,
+        }) = context.executor().take_resume_state(self)
+        {
+            (iterator, Some(environment))
+        } else {
+            let iterable = self.iterable().run(context)?;
+            (get_iterator(context, iterable)?, None)
+        };
+        let mut result = Value::undefined();
+        let mut exhausted = false;
+
+        loop {
+            if let Some(env) = resumed_environment.take() {
+                // The iteration of a resumed generator continues in the environment it was
+                // suspended in.
+                context.push_environment(env);
+            } else {
+                let env = context.get_current_environment();
+                context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+                let iterator_result = iterator.next(context)?;
+                if iterator_result.is_done() {
+                    context.pop_environment();
+                    exhausted = true;
+                    break;
+                }
+                self.bind_variable(iterator_result.value(), context)?;
+            }
+
+            result = match self.body().run(context) {
+                Ok(result) => result,
+                Err(e) => {
+                    let environment = context.pop_environment();
+                    if !context.executor().is_suspending_generator() {
+                        return close_iterator(&iterator, Err(e), context);
+                    }
+                    if let Some(environment) = environment {
+                        context.executor().save_resume_state(
+                            self,
+                            ResumeState::Iteration {
+                                iterator,
+                                environment,
+                            },
+                        );
+                    }
+                    return Err(e);
+                }
+            };
+            let _ = context.pop_environment();
             match context.executor().get_current_state() {
                 InterpreterState::Break(label) => {
                     handle_state_with_labels!(self, label, context, break);
@@ -194,15 +505,39 @@ impl Executable for ForOfLoop {
                 InterpreterState::Continue(label) => {
                     handle_state_with_labels!(self, label, context, continue);
                 }
-                InterpreterState::Return => return Ok(result),
+                InterpreterState::Return => return close_iterator(&iterator, Ok(result), context),
                 InterpreterState::Executing => {
                     // Continue execution.
                 }
                 #[cfg(feature = "vm")]
                 InterpreterState::Error => {}
             }
-            let _ = context.pop_environment();
         }
-        Ok(result)
+
+        // A loop exited before the end of the iteration, by a `break` or by a `continue` of an
+        // outer loop, closes its iterator.
+        if exhausted {
+            Ok(result)
+        } else {
+            close_iterator(&iterator, Ok(result), context)
+        }
     }
 }
+
+/// Closes the iterator of a loop that is exited early, with the given completion.
+///
+/// The `return` method of the iterator is called as if the interpreter was executing normally,
+/// and the state of the interpreter is restored unless it throws.
+fn close_iterator(
+    iterator: &IteratorRecord,
+    completion: Result<Value>,
+    context: &mut Context,
+) -> Result<Value> {
+    let state = context.executor().get_current_state().clone();
+    context
+        .executor()
+        .set_current_state(InterpreterState::Executing);
+    let result = iterator.close(completion, context)?;
+    context.executor().set_current_state(state);
+    Ok(result)
+}
@@ -192,6 +192,66 @@ fn do_while_loop_continue() {
     assert_eq!(&exec(scenario), "[ 1, 2 ]");
 }
 
+#[test]
+fn do_while_loop_runs_once() {
+    let scenario = r#"
+        var a = 0;
+        do {
+            a++;
+        } while (false)
+        a;
+    "#;
+    assert_eq!(&exec(scenario), "1");
+
+    let scenario = r#"
+        var a = 0;
+        do a++; while (a > 10);
+        a;
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn do_while_loop_continue_checks_condition() {
+    let scenario = r#"
+        var i = 0;
+        do {
+            i++;
+            continue;
+        } while (false)
+        i;
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn do_while_loop_labels() {
+    let scenario = r#"
+        var outer = 0, inner = 0;
+        loop1: do {
+            outer++;
+            do {
+                inner++;
+                continue loop1;
+            } while (true)
+        } while (outer < 3)
+        [outer, inner]
+    "#;
+    assert_eq!(&exec(scenario), "[ 3, 3 ]");
+
+    let scenario = r#"
+        var count = 0;
+        loop1: do {
+            do {
+                count++;
+                break loop1;
+            } while (true)
+        } while (true)
+        count;
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
+
 #[test]
 fn for_of_loop_declaration() {
     let mut context = Context::new();
@@ -319,6 +379,137 @@ fn for_of_loop_return() {
     assert_eq!(&forward(&mut context, "foo()"), "2");
 }
 
+#[test]
+fn for_of_custom_iterable() {
+    let scenario = r#"
+        let iterable = {
+            [Symbol.iterator]() {
+                let i = 0;
+                return {
+                    next() {
+                        i++;
+                        return { value: i * 10, done: i > 3 };
+                    }
+                };
+            }
+        };
+        let values = [];
+        for (let value of iterable) {
+            values.push(value);
+        }
+        values.join();
+    "#;
+    assert_eq!(&exec(scenario), r#""10,20,30""#);
+}
+
+#[test]
+fn for_of_builtin_iterables() {
+    let scenario = r#"
+        let values = [];
+        for (let c of "a😀") {
+            values.push(c);
+        }
+        for (let entry of new Map([["k", 1]])) {
+            values.push(entry.join(":"));
+        }
+        for (let value of new Set([2, 3])) {
+            values.push(value);
+        }
+        values.join();
+    "#;
+    assert_eq!(&exec(scenario), r#""a,😀,k:1,2,3""#);
+}
+
+#[test]
+fn for_of_closes_iterator_on_early_exit() {
+    let mut context = Context::new();
+    let scenario = r#"
+        let log = [];
+        let iterable = {
+            [Symbol.iterator]() {
+                let i = 0;
+                return {
+                    next() {
+                        i++;
+                        return { value: i, done: i > 3 };
+                    },
+                    return() {
+                        log.push("return");
+                        return {};
+                    }
+                };
+            }
+        };
+    "#;
+    forward(&mut context, scenario);
+
+    forward(
+        &mut context,
+        "for (let x of iterable) { log.push(x); if (x === 2) break; }",
+    );
+    assert_eq!(&forward(&mut context, "log.join()"), r#""1,2,return""#);
+
+    forward(&mut context, "log = [];");
+    forward(
+        &mut context,
+        "try { for (let x of iterable) { throw 1; } } catch (e) { log.push(e); }",
+    );
+    assert_eq!(&forward(&mut context, "log.join()"), r#""return,1""#);
+
+    forward(&mut context, "log = [];");
+    forward(
+        &mut context,
+        "function f() { for (let x of iterable) { return x; } } log.push(f());",
+    );
+    assert_eq!(&forward(&mut context, "log.join()"), r#""return,1""#);
+
+    forward(&mut context, "log = [];");
+    forward(
+        &mut context,
+        "outer: for (let y of [1]) { for (let x of iterable) { continue outer; } }",
+    );
+    assert_eq!(&forward(&mut context, "log.join()"), r#""return""#);
+
+    forward(&mut context, "log = [];");
+    forward(&mut context, "for (let x of iterable) { continue; }");
+    assert_eq!(&forward(&mut context, "log.join()"), r#""""#);
+}
+
+#[test]
+fn for_of_close_errors() {
+    let scenario = r#"
+        let iterable = {
+            [Symbol.iterator]() {
+                return {
+                    next() {
+                        return { value: 1, done: false };
+                    },
+                    return() {
+                        throw "return";
+                    }
+                };
+            }
+        };
+        let errors = [];
+        try {
+            for (let x of iterable) {
+                throw "body";
+            }
+        } catch (e) {
+            errors.push(e);
+        }
+        try {
+            for (let x of iterable) {
+                break;
+            }
+        } catch (e) {
+            errors.push(e);
+        }
+        errors.join();
+    "#;
+    assert_eq!(&exec(scenario), r#""body,return""#);
+}
+
 #[test]
 fn for_loop_break_label() {
     let scenario = r#"
@@ -354,6 +545,127 @@ fn for_loop_continue_label() {
     assert_eq!(&exec(scenario), "10");
 }
 
+#[test]
+fn nested_loops_continue_label() {
+    let scenario = r#"
+        var str = "";
+        outer: for (let i = 0; i < 3; i++) {
+            middle: for (let j = 0; j < 3; j++) {
+                for (let k = 0; k < 3; k++) {
+                    if (k === 1) continue middle;
+                    if (j === 1) continue outer;
+                    str = str + i + j + k + ",";
+                }
+            }
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"000,100,200,\"");
+}
+
+#[test]
+fn continue_directly_nested_labels() {
+    let scenario = r#"
+        var str = "";
+        outer: inner: for (let i = 0; i < 3; i++) {
+            for (let j = 0; j < 3; j++) {
+                if (j === 1) continue outer;
+                if (i === 1) continue inner;
+                str = str + i + j + ",";
+            }
+        }
+        a: b: c: do {
+            str = str + "x";
+            if (str.length > 11) break a;
+            continue b;
+        } while (true);
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"00,20,xxxxxx\"");
+}
+
+#[test]
+fn while_loop_break_nested_label() {
+    let scenario = r#"
+        var count = 0;
+        outer: while (true) {
+            inner: do {
+                count++;
+                if (count === 3) break outer;
+                continue outer;
+            } while (true);
+        }
+        count
+    "#;
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn for_of_break_label_from_switch() {
+    let scenario = r#"
+        var str = "";
+        outer: for (let x of ["a", "b", "c"]) {
+            switch (x) {
+                case "b":
+                    break outer;
+                default:
+                    str = str + x;
+                    break;
+            }
+            str = str + "-";
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"a-\"");
+}
+
+#[test]
+fn break_label_on_block() {
+    let scenario = r#"
+        var str = "";
+        outer: {
+            inner: {
+                str = str + "a";
+                break inner;
+                str = str + "b";
+            }
+            str = str + "c";
+            for (;;) {
+                break outer;
+            }
+            str = str + "d";
+        }
+        str = str + "e";
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"ace\"");
+}
+
+#[test]
+fn break_label_on_statement() {
+    let scenario = r#"
+        var a = 0;
+        label: if (true) {
+            a = 1;
+            break label;
+        }
+        a
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn undefined_label_is_syntax_error() {
+    assert_eq!(
+        &exec("for (;;) { break missing; }"),
+        "\"SyntaxError\": \"undefined break target at line 1, col 18\""
+    );
+    assert_eq!(
+        &exec("label: { for (;;) { continue label; } }"),
+        "\"SyntaxError\": \"undefined continue target at line 1, col 30\""
+    );
+}
+
 #[test]
 fn for_in_declaration() {
     let mut context = Context::new();
@@ -503,6 +815,117 @@ fn for_in_continue_label() {
     assert_eq!(&exec(scenario), "\"00\"")
 }
 
+#[test]
+fn for_in_prototype_chain() {
+    let mut context = Context::new();
+
+    let init = r#"
+        let proto = { inherited: 1 };
+        let obj = Object.create(proto);
+        obj.own = 2;
+        let result = [];
+        for (let key in obj) {
+            result.push(key);
+        }
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "result.join()"), "\"own,inherited\"");
+}
+
+#[test]
+fn for_in_shadowed_property() {
+    let mut context = Context::new();
+
+    let init = r#"
+        let proto = { a: 1, b: 2 };
+        let obj = Object.create(proto);
+        obj.a = 3;
+        Object.defineProperty(obj, "b", { value: 4, enumerable: false });
+        let result = [];
+        for (let key in obj) {
+            result.push(key);
+        }
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "result.join()"), "\"a\"");
+}
+
+#[test]
+fn for_in_skips_symbols() {
+    let mut context = Context::new();
+
+    let init = r#"
+        let sym = Symbol("s");
+        let proto = { [Symbol("inherited")]: 1 };
+        let obj = Object.create(proto);
+        obj[sym] = 2;
+        obj.key = 3;
+        let result = [];
+        for (let key in obj) {
+            result.push(key);
+        }
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "result.join()"), "\"key\"");
+}
+
+#[test]
+fn for_loop_let_closures_capture_each_iteration() {
+    let mut context = Context::new();
+    let init = r#"
+        var letFns = [];
+        for (let i = 0; i < 3; i++) {
+            letFns.push(() => i);
+        }
+        var varFns = [];
+        for (var j = 0; j < 3; j++) {
+            varFns.push(() => j);
+        }
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "letFns.map(f => f()).join()"),
+        "\"0,1,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "varFns.map(f => f()).join()"),
+        "\"3,3,3\""
+    );
+}
+
+#[test]
+fn for_loop_let_per_iteration_copy() {
+    let mut context = Context::new();
+    let init = r#"
+        var fns = [];
+        var counts = [];
+        for (let i = 0, inc = () => i++; i < 6; i++) {
+            fns.push(() => i);
+            counts.push(inc());
+            if (i % 2 === 0) {
+                i++;
+                continue;
+            }
+        }
+    "#;
+    forward(&mut context, init);
+
+    // The closure in the loop head captures the bindings the head was evaluated in, so it
+    // doesn't change the copies made for each iteration, and increments made in the body are
+    // visible to the copy made for the next iteration.
+    assert_eq!(
+        forward(&mut context, "fns.map(f => f()).join()"),
+        "\"1,3,5\""
+    );
+    assert_eq!(forward(&mut context, "counts.join()"), "\"0,1,2\"");
+    assert_eq!(forward(&mut context, "typeof i"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "typeof inc"), "\"undefined\"");
+}
+
 #[test]
 fn fmt() {
     // Labeled and unlabeled for in loops
@@ -575,3 +998,92 @@ fn fmt() {
         "#,
     );
 }
+
+#[test]
+fn for_await_of_async_generator() {
+    let mut context = Context::new();
+    let scenario = r#"
+        let values = [];
+        let gen = async function*() {
+            yield 1;
+            yield await Promise.resolve(2);
+            yield 3;
+        };
+        let f = async function() {
+            for await (const value of gen()) {
+                values.push(value);
+            }
+            return "done";
+        };
+        f().then(function (result) {
+            values.push(result);
+        });
+    "#;
+    forward(&mut context, scenario);
+    assert_eq!(&forward(&mut context, "values.join()"), r#""1,2,3,done""#);
+}
+
+#[test]
+fn for_await_of_sync_iterable() {
+    let mut context = Context::new();
+    let scenario = r#"
+        let values = [];
+        let f = async function() {
+            for await (let value of [Promise.resolve("a"), "b", Promise.resolve("c")]) {
+                values.push(value);
+            }
+        };
+        f();
+        values.push("sync");
+    "#;
+    forward(&mut context, scenario);
+    assert_eq!(&forward(&mut context, "values.join()"), r#""sync,a,b,c""#);
+}
+
+#[test]
+fn for_await_of_closes_iterator_on_early_exit() {
+    let mut context = Context::new();
+    let scenario = r#"
+        let log = [];
+        let iterable = {
+            [Symbol.asyncIterator]() {
+                let i = 0;
+                return {
+                    next() {
+                        i++;
+                        return Promise.resolve({ value: i, done: i > 3 });
+                    },
+                    return() {
+                        log.push("return");
+                        return Promise.resolve({});
+                    }
+                };
+            }
+        };
+        let f = async function() {
+            for await (const x of iterable) {
+                log.push(x);
+                if (x === 2) break;
+            }
+            try {
+                for await (const x of iterable) {
+                    throw "thrown";
+                }
+            } catch (e) {
+                log.push(e);
+            }
+            let g = async function() {
+                for await (const x of iterable) {
+                    return x;
+                }
+            };
+            log.push(await g());
+        };
+        f();
+    "#;
+    forward(&mut context, scenario);
+    assert_eq!(
+        &forward(&mut context, "log.join()"),
+        r#""1,2,return,return,thrown,return,1""#
+    );
+}
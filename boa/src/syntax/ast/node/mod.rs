@@ -5,14 +5,18 @@ pub mod await_expr;
 pub mod block;
 pub mod break_node;
 pub mod call;
+pub mod class;
 pub mod conditional;
 pub mod declaration;
 pub mod field;
 pub mod identifier;
 pub mod iteration;
+pub mod module;
 pub mod new;
 pub mod object;
 pub mod operator;
+pub mod optional;
+pub mod pattern;
 pub mod return_smt;
 pub mod spread;
 pub mod statement_list;
@@ -20,6 +24,8 @@ pub mod switch;
 pub mod template;
 pub mod throw;
 pub mod try_node;
+pub mod with;
+pub mod yield_expr;
 
 pub use self::{
     array::ArrayDecl,
@@ -27,29 +33,42 @@ pub use self::{
     block::Block,
     break_node::Break,
     call::Call,
+    class::{Class, ClassDecl, ClassElement, ClassExpr},
     conditional::{ConditionalOp, If},
     declaration::{
-        ArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, Declaration, DeclarationList,
-        FunctionDecl, FunctionExpr,
+        ArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, AsyncGeneratorDecl,
+        AsyncGeneratorExpr, Declaration, DeclarationList, FunctionDecl, FunctionExpr,
+        GeneratorDecl, GeneratorExpr,
     },
     field::{GetConstField, GetField},
     identifier::Identifier,
     iteration::{Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, WhileLoop},
+    module::{ExportDecl, ExportSpecifier, ImportDecl, ImportName, ImportSpecifier},
     new::New,
     object::Object,
-    operator::{Assign, BinOp, UnaryOp},
+    operator::{Assign, AssignmentTarget, BinOp, UnaryOp},
+    optional::{Optional, OptionalOperation, OptionalOperationKind},
+    pattern::{
+        ArrayAssignmentPattern, ArrayBindingPattern, AssignmentElement, AssignmentProperty,
+        Binding, BindingElement, BindingPattern, BindingProperty, ObjectAssignmentPattern,
+        ObjectBindingPattern,
+    },
     return_smt::Return,
     spread::Spread,
     statement_list::{RcStatementList, StatementList},
     switch::{Case, Switch},
-    template::{TaggedTemplate, TemplateLit},
+    template::{TaggedTemplate, TemplateElement, TemplateLit},
     throw::Throw,
     try_node::{Catch, Finally, Try},
+    with::With,
+    yield_expr::Yield,
 };
 use super::Const;
 use crate::{
+    builtins::function::set_function_name,
     exec::Executable,
     gc::{empty_trace, Finalize, Trace},
+    property::PropertyKey,
     BoaProfiler, Context, Result, Value,
 };
 use std::{
@@ -78,6 +97,12 @@ pub enum Node {
     /// An async function expression node. [More information](./declaration/struct.AsyncFunctionExpr.html).
     AsyncFunctionExpr(AsyncFunctionExpr),
 
+    /// An async generator declaration node. [More information](./declaration/struct.AsyncGeneratorDecl.html).
+    AsyncGeneratorDecl(AsyncGeneratorDecl),
+
+    /// An async generator expression node. [More information](./declaration/struct.AsyncGeneratorExpr.html).
+    AsyncGeneratorExpr(AsyncGeneratorExpr),
+
     /// An await expression node. [More information](./await_expr/struct.AwaitExpression.html).
     AwaitExpr(AwaitExpr),
 
@@ -93,6 +118,12 @@ pub enum Node {
     /// A function call. [More information](./expression/struct.Call.html).
     Call(Call),
 
+    /// A class declaration. [More information](./class/struct.ClassDecl.html).
+    ClassDecl(ClassDecl),
+
+    /// A class expression. [More information](./class/struct.ClassExpr.html).
+    ClassExpr(ClassExpr),
+
     /// A javascript conditional operand ( x ? y : z ). [More information](./conditional/struct.ConditionalOp.html).
     ConditionalOp(ConditionalOp),
 
@@ -117,12 +148,21 @@ pub enum Node {
     /// A do ... while statement. [More information](./iteration/struct.DoWhileLoop.html).
     DoWhileLoop(DoWhileLoop),
 
+    /// An export declaration. [More information](./module/enum.ExportDecl.html).
+    ExportDecl(ExportDecl),
+
     /// A function declaration node. [More information](./declaration/struct.FunctionDecl.html).
     FunctionDecl(FunctionDecl),
 
     /// A function expression node. [More information](./declaration/struct.FunctionExpr.html).
     FunctionExpr(FunctionExpr),
 
+    /// A generator declaration node. [More information](./declaration/struct.GeneratorDecl.html).
+    GeneratorDecl(GeneratorDecl),
+
+    /// A generator expression node. [More information](./declaration/struct.GeneratorExpr.html).
+    GeneratorExpr(GeneratorExpr),
+
     /// Provides access to an object types' constant properties. [More information](./declaration/struct.GetConstField.html).
     GetConstField(GetConstField),
 
@@ -141,6 +181,9 @@ pub enum Node {
     /// An 'if' statement. [More information](./conditional/struct.If.html).
     If(If),
 
+    /// An import declaration. [More information](./module/struct.ImportDecl.html).
+    ImportDecl(ImportDecl),
+
     /// A `let` declaration list. [More information](./declaration/enum.DeclarationList.html#variant.Let).
     LetDeclList(DeclarationList),
 
@@ -150,9 +193,51 @@ pub enum Node {
     /// A `new` expression. [More information](./expression/struct.New.html).
     New(New),
 
+    /// The `new.target` meta property.
+    ///
+    /// It evaluates to the constructor a function was called with by `new`, or to `undefined` if
+    /// the function was called without `new`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-meta-properties
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/new.target
+    NewTarget,
+
+    /// The `import.meta` meta property.
+    ///
+    /// It evaluates to an object holding the metadata of the module, set by the module loader.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-meta-properties
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import.meta
+    ImportMeta,
+
+    /// The `super` keyword, as the object of a property access like `super.method` or
+    /// `super[key]`.
+    ///
+    /// It refers to the prototype of the object the enclosing method is defined on, while the
+    /// `this` value stays the one of the method.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-super-keyword
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/super
+    Super,
+
     /// An object. [More information](./object/struct.Object.html).
     Object(Object),
 
+    /// An optional chain. [More information](./optional/struct.Optional.html).
+    Optional(Optional),
+
     /// A return statement. [More information](./object/struct.Return.html).
     Return(Return),
 
@@ -197,6 +282,12 @@ pub enum Node {
     /// A 'while {...}' node. [More information](./iteration/struct.WhileLoop.html).
     WhileLoop(WhileLoop),
 
+    /// A `with` statement. [More information](./with/struct.With.html).
+    With(With),
+
+    /// A `yield` or `yield*` expression. [More information](./yield_expr/struct.Yield.html).
+    Yield(Yield),
+
     /// A empty node.
     ///
     /// Empty statement do nothing, just return undefined.
@@ -225,13 +316,19 @@ impl From<Const> for Node {
 impl Node {
     /// Returns a node ordering based on the hoistability of each node.
     pub(crate) fn hoistable_order(a: &Node, b: &Node) -> Ordering {
-        match (a, b) {
-            (Node::FunctionDecl(_), Node::FunctionDecl(_)) => Ordering::Equal,
-            (_, Node::FunctionDecl(_)) => Ordering::Greater,
-            (Node::FunctionDecl(_), _) => Ordering::Less,
-
-            (_, _) => Ordering::Equal,
+        fn is_hoistable(node: &Node) -> bool {
+            match node {
+                Node::FunctionDecl(_)
+                | Node::GeneratorDecl(_)
+                | Node::AsyncFunctionDecl(_)
+                | Node::AsyncGeneratorDecl(_) => true,
+                Node::ExportDecl(ExportDecl::Declaration(decl))
+                | Node::ExportDecl(ExportDecl::Default(decl)) => is_hoistable(decl),
+                _ => false,
+            }
         }
+
+        is_hoistable(b).cmp(&is_hoistable(a))
     }
 
     /// Creates a `This` AST node.
@@ -239,6 +336,25 @@ impl Node {
         Self::This
     }
 
+    /// Checks if the node is a function or class definition without a name, which is named after
+    /// the binding or property it is assigned to.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-isanonymousfunctiondefinition
+    pub(crate) fn is_anonymous_function_definition(&self) -> bool {
+        match self {
+            Self::ArrowFunctionDecl(_) => true,
+            Self::FunctionExpr(expr) => expr.name().is_none(),
+            Self::GeneratorExpr(expr) => expr.name().is_none(),
+            Self::AsyncFunctionExpr(expr) => expr.name().is_none(),
+            Self::AsyncGeneratorExpr(expr) => expr.name().is_none(),
+            Self::ClassExpr(expr) => expr.class().name().is_none(),
+            _ => false,
+        }
+    }
+
     /// Displays the value of the node with the given indentation. For example, an indent
     /// level of 2 would produce this:
     ///
@@ -263,12 +379,17 @@ impl Node {
     fn display_no_indent(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         match *self {
             Self::Call(ref expr) => Display::fmt(expr, f),
+            Self::ClassDecl(ref decl) => decl.class().display(f, indentation),
+            Self::ClassExpr(ref expr) => expr.class().display(f, indentation),
             Self::Const(ref c) => write!(f, "{}", c),
             Self::ConditionalOp(ref cond_op) => Display::fmt(cond_op, f),
             Self::ForLoop(ref for_loop) => for_loop.display(f, indentation),
             Self::ForOfLoop(ref for_of) => for_of.display(f, indentation),
             Self::ForInLoop(ref for_in) => for_in.display(f, indentation),
             Self::This => write!(f, "this"),
+            Self::NewTarget => write!(f, "new.target"),
+            Self::ImportMeta => write!(f, "import.meta"),
+            Self::Super => write!(f, "super"),
             Self::Try(ref try_catch) => try_catch.display(f, indentation),
             Self::Break(ref break_smt) => Display::fmt(break_smt, f),
             Self::Continue(ref cont) => Display::fmt(cont, f),
@@ -280,13 +401,17 @@ impl Node {
             Self::GetField(ref get_field) => Display::fmt(get_field, f),
             Self::WhileLoop(ref while_loop) => while_loop.display(f, indentation),
             Self::DoWhileLoop(ref do_while) => do_while.display(f, indentation),
+            Self::With(ref with) => with.display(f, indentation),
             Self::If(ref if_smt) => if_smt.display(f, indentation),
             Self::Switch(ref switch) => switch.display(f, indentation),
             Self::Object(ref obj) => obj.display(f, indentation),
+            Self::Optional(ref optional) => Display::fmt(optional, f),
             Self::ArrayDecl(ref arr) => Display::fmt(arr, f),
             Self::VarDeclList(ref list) => Display::fmt(list, f),
             Self::FunctionDecl(ref decl) => decl.display(f, indentation),
             Self::FunctionExpr(ref expr) => expr.display(f, indentation),
+            Self::GeneratorDecl(ref decl) => decl.display(f, indentation),
+            Self::GeneratorExpr(ref expr) => expr.display(f, indentation),
             Self::ArrowFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::BinOp(ref op) => Display::fmt(op, f),
             Self::UnaryOp(ref op) => Display::fmt(op, f),
@@ -299,7 +424,12 @@ impl Node {
             Self::ConstDeclList(ref decl) => Display::fmt(decl, f),
             Self::AsyncFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::AsyncFunctionExpr(ref expr) => expr.display(f, indentation),
+            Self::AsyncGeneratorDecl(ref decl) => decl.display(f, indentation),
+            Self::AsyncGeneratorExpr(ref expr) => expr.display(f, indentation),
             Self::AwaitExpr(ref expr) => Display::fmt(expr, f),
+            Self::Yield(ref expr) => Display::fmt(expr, f),
+            Self::ImportDecl(ref decl) => Display::fmt(decl, f),
+            Self::ExportDecl(ref decl) => decl.display(f, indentation),
             Self::Empty => write!(f, ";"),
         }
     }
@@ -308,20 +438,73 @@ impl Node {
 impl Executable for Node {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Executable", "exec");
+        context.count_instruction()?;
+
+        // In the body of a generator, the nodes that completed before it was suspended are not
+        // evaluated again when it's resumed.
+        if let Some(generator) = context.executor().generator_frame() {
+            if let Some((value, state)) = generator.replayed_completion(self) {
+                context.executor().set_current_state(state);
+                return Ok(value);
+            }
+            generator.start_node(self);
+            let result = self.evaluate(context);
+            if let Ok(ref value) = result {
+                let state = context.executor().get_current_state().clone();
+                if let Some(generator) = context.executor().generator_frame() {
+                    generator.complete_node(self, value.clone(), state);
+                }
+            }
+            return result;
+        }
+
+        self.evaluate(context)
+    }
+}
+
+impl Node {
+    /// Evaluates the node, and names it after `name` if it's an anonymous function or class
+    /// definition.
+    ///
+    /// A class that defines a static `name` method keeps it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-namedevaluation
+    pub(crate) fn run_named<K>(&self, name: K, context: &mut Context) -> Result<Value>
+    where
+        K: Into<PropertyKey>,
+    {
+        let value = self.run(context)?;
+        if self.is_anonymous_function_definition() {
+            if let Some(mut function) = value.as_object() {
+                if function.get_own_property(&"name".into()).is_none() {
+                    set_function_name(&mut function, &name.into(), None);
+                }
+            }
+        }
+        Ok(value)
+    }
+
+    /// Evaluates the node.
+    fn evaluate(&self, context: &mut Context) -> Result<Value> {
         match *self {
             Node::AsyncFunctionDecl(ref decl) => decl.run(context),
             Node::AsyncFunctionExpr(ref function_expr) => function_expr.run(context),
+            Node::AsyncGeneratorDecl(ref decl) => decl.run(context),
+            Node::AsyncGeneratorExpr(ref expr) => expr.run(context),
             Node::AwaitExpr(ref expr) => expr.run(context),
             Node::Call(ref call) => call.run(context),
+            Node::ClassDecl(ref decl) => decl.run(context),
+            Node::ClassExpr(ref expr) => expr.run(context),
             Node::Const(Const::Null) => Ok(Value::null()),
             Node::Const(Const::Num(num)) => Ok(Value::rational(num)),
             Node::Const(Const::Int(num)) => Ok(Value::integer(num)),
             Node::Const(Const::BigInt(ref num)) => Ok(Value::from(num.clone())),
             Node::Const(Const::Undefined) => Ok(Value::Undefined),
-            // we can't move String from Const into value, because const is a garbage collected value
-            // Which means Drop() get's called on Const, but str will be gone at that point.
-            // Do Const values need to be garbage collected? We no longer need them once we've generated Values
-            Node::Const(Const::String(ref value)) => Ok(Value::string(value.to_string())),
+            // Equal string literals share the string interned by the context.
+            Node::Const(Const::String(ref value)) => Ok(context.intern_string(value).into()),
             Node::Const(Const::Bool(value)) => Ok(Value::boolean(value)),
             Node::Block(ref block) => block.run(context),
             Node::Identifier(ref identifier) => identifier.run(context),
@@ -329,6 +512,7 @@ impl Executable for Node {
             Node::GetField(ref get_field) => get_field.run(context),
             Node::WhileLoop(ref while_loop) => while_loop.run(context),
             Node::DoWhileLoop(ref do_while) => do_while.run(context),
+            Node::With(ref with) => with.run(context),
             Node::ForLoop(ref for_loop) => for_loop.run(context),
             Node::ForOfLoop(ref for_of_loop) => for_of_loop.run(context),
             Node::ForInLoop(ref for_in_loop) => for_in_loop.run(context),
@@ -336,12 +520,16 @@ impl Executable for Node {
             Node::ConditionalOp(ref op) => op.run(context),
             Node::Switch(ref switch) => switch.run(context),
             Node::Object(ref obj) => obj.run(context),
+            Node::Optional(ref optional) => optional.run(context),
             Node::ArrayDecl(ref arr) => arr.run(context),
             // <https://tc39.es/ecma262/#sec-createdynamicfunction>
             Node::FunctionDecl(ref decl) => decl.run(context),
             // <https://tc39.es/ecma262/#sec-createdynamicfunction>
             Node::FunctionExpr(ref function_expr) => function_expr.run(context),
             Node::ArrowFunctionDecl(ref decl) => decl.run(context),
+            Node::GeneratorDecl(ref decl) => decl.run(context),
+            Node::GeneratorExpr(ref expr) => expr.run(context),
+            Node::Yield(ref expr) => expr.run(context),
             Node::BinOp(ref op) => op.run(context),
             Node::UnaryOp(ref op) => op.run(context),
             Node::New(ref call) => call.run(context),
@@ -358,9 +546,15 @@ impl Executable for Node {
                 // Will either return `this` binding or undefined
                 context.get_this_binding()
             }
+            Node::NewTarget => Ok(context.get_new_target()),
+            Node::ImportMeta => context.import_meta(),
+            // `super` is only parsed as the object of a property access, which evaluates it.
+            Node::Super => context.throw_syntax_error("'super' keyword unexpected here"),
             Node::Try(ref try_node) => try_node.run(context),
             Node::Break(ref break_node) => break_node.run(context),
             Node::Continue(ref continue_node) => continue_node.run(context),
+            Node::ImportDecl(ref decl) => decl.run(context),
+            Node::ExportDecl(ref decl) => decl.run(context),
             Node::Empty => Ok(Value::Undefined),
         }
     }
@@ -384,10 +578,10 @@ where
 
 /// "Formal parameter" is a fancy way of saying "function parameter".
 ///
-/// In the declaration of a function, the parameters must be identifiers,
-/// not any value like numbers, strings, or objects.
+/// In the declaration of a function, the parameters must be identifiers or destructuring
+/// patterns, not any value like numbers, strings, or objects.
 ///```text
-///function foo(formalParameter1, formalParameter2) {
+///function foo(formalParameter1, { formalParameter2 }) {
 ///}
 ///```
 ///
@@ -400,27 +594,32 @@ where
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq, Trace, Finalize)]
 pub struct FormalParameter {
-    name: Box<str>,
+    binding: Binding,
     init: Option<Node>,
     is_rest_param: bool,
 }
 
 impl FormalParameter {
     /// Creates a new formal parameter.
-    pub(in crate::syntax) fn new<N>(name: N, init: Option<Node>, is_rest_param: bool) -> Self
+    pub(in crate::syntax) fn new<B>(binding: B, init: Option<Node>, is_rest_param: bool) -> Self
     where
-        N: Into<Box<str>>,
+        B: Into<Binding>,
     {
         Self {
-            name: name.into(),
+            binding: binding.into(),
             init,
             is_rest_param,
         }
     }
 
-    /// Gets the name of the formal parameter.
-    pub fn name(&self) -> &str {
-        &self.name
+    /// Gets the binding of the formal parameter.
+    pub fn binding(&self) -> &Binding {
+        &self.binding
+    }
+
+    /// Gets the names of the identifiers bound by the formal parameter.
+    pub fn names(&self) -> Vec<&str> {
+        self.binding.names()
     }
 
     /// Gets the initialization node of the formal parameter, if any.
@@ -432,6 +631,21 @@ impl FormalParameter {
     pub fn is_rest_param(&self) -> bool {
         self.is_rest_param
     }
+
+    /// Checks if the parameter is a plain identifier, without a default value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-issimpleparameterlist
+    pub fn is_simple(&self) -> bool {
+        !self.is_rest_param && self.init.is_none() && matches!(self.binding, Binding::Identifier(_))
+    }
+
+    /// Checks if the parameter has an expression that is evaluated when it is bound.
+    pub fn contains_expression(&self) -> bool {
+        self.init.is_some() || self.binding.contains_expression()
+    }
 }
 
 impl Display for FormalParameter {
@@ -439,7 +653,7 @@ impl Display for FormalParameter {
         if self.is_rest_param {
             write!(f, "...")?;
         }
-        write!(f, "{}", self.name)?;
+        write!(f, "{}", self.binding)?;
         if let Some(n) = self.init.as_ref() {
             write!(f, " = {}", n)?;
         }
@@ -481,7 +695,7 @@ pub enum PropertyDefinition {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-PropertyDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Property_definitions
-    Property(Box<str>, Node),
+    Property(PropertyName, Node),
 
     /// A property of an object can also refer to a function or a getter or setter method.
     ///
@@ -491,7 +705,7 @@ pub enum PropertyDefinition {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Method_definitions
-    MethodDefinition(MethodDefinitionKind, Box<str>, FunctionExpr),
+    MethodDefinition(MethodDefinitionKind, PropertyName, FunctionExpr),
 
     /// The Rest/Spread Properties for ECMAScript proposal (stage 4) adds spread properties to object literals.
     /// It copies own enumerable properties from a provided object onto a new object.
@@ -519,7 +733,7 @@ impl PropertyDefinition {
     /// Creates a `Property` definition.
     pub fn property<N, V>(name: N, value: V) -> Self
     where
-        N: Into<Box<str>>,
+        N: Into<PropertyName>,
         V: Into<Node>,
     {
         Self::Property(name.into(), value.into())
@@ -528,7 +742,7 @@ impl PropertyDefinition {
     /// Creates a `MethodDefinition`.
     pub fn method_definition<N>(kind: MethodDefinitionKind, name: N, body: FunctionExpr) -> Self
     where
-        N: Into<Box<str>>,
+        N: Into<PropertyName>,
     {
         Self::MethodDefinition(kind, name.into(), body)
     }
@@ -542,6 +756,77 @@ impl PropertyDefinition {
     }
 }
 
+/// The name of a property in an object literal.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-PropertyName
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Computed_property_names
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub enum PropertyName {
+    /// A property name given by an identifier, a string or a numeric literal.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-LiteralPropertyName
+    Literal(Box<str>),
+
+    /// A property name given by an expression, evaluated when the object is created.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-ComputedPropertyName
+    Computed(Box<Node>),
+}
+
+impl PropertyName {
+    /// Evaluates the property name to a property key.
+    pub(crate) fn to_property_key(&self, context: &mut Context) -> Result<PropertyKey> {
+        match self {
+            Self::Literal(name) => Ok(context.intern_string(name).into()),
+            Self::Computed(node) => node.run(context)?.to_property_key(context),
+        }
+    }
+}
+
+impl Display for PropertyName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(name) => write!(f, "{}", name),
+            Self::Computed(node) => write!(f, "[{}]", node),
+        }
+    }
+}
+
+impl From<&str> for PropertyName {
+    fn from(name: &str) -> Self {
+        Self::Literal(name.into())
+    }
+}
+
+impl From<String> for PropertyName {
+    fn from(name: String) -> Self {
+        Self::Literal(name.into())
+    }
+}
+
+impl From<Box<str>> for PropertyName {
+    fn from(name: Box<str>) -> Self {
+        Self::Literal(name)
+    }
+}
+
+impl From<Node> for PropertyName {
+    fn from(node: Node) -> Self {
+        Self::Computed(Box::new(node))
+    }
+}
+
 /// Method definition kinds.
 ///
 /// Starting with ECMAScript 2015, a shorter syntax for method definitions on objects initializers is introduced.
@@ -603,6 +888,17 @@ unsafe impl Trace for MethodDefinitionKind {
     empty_trace!();
 }
 
+impl MethodDefinitionKind {
+    /// Gets the prefix of the name of methods of this kind, like `"get"` for getters.
+    pub(crate) fn prefix(self) -> Option<&'static str> {
+        match self {
+            Self::Get => Some("get"),
+            Self::Set => Some("set"),
+            Self::Ordinary => None,
+        }
+    }
+}
+
 /// This parses the given source code, and then makes sure that
 /// the resulting StatementList is formatted in the same manner
 /// as the source code. This is expected to have a preceding
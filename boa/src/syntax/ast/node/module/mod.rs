@@ -0,0 +1,337 @@
+//! Module import and export declaration nodes.
+
+use crate::{
+    exec::Executable,
+    gc::{Finalize, Trace},
+    syntax::ast::node::Node,
+    Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The name of the local binding that holds the value of an `export default` expression.
+pub const DEFAULT_EXPORT_BINDING: &str = "*default*";
+
+/// The `import` declaration imports bindings exported by another module.
+///
+/// Syntax: `import defaultName, { name, other as alias } from "module-name";`
+///
+/// Imported bindings are live: reading them always gives the current value of the exported
+/// binding in the module that declares it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportDecl {
+    specifiers: Box<[ImportSpecifier]>,
+    module: Box<str>,
+}
+
+impl ImportDecl {
+    /// Creates a new `ImportDecl` AST node.
+    pub fn new<S, M>(specifiers: S, module: M) -> Self
+    where
+        S: Into<Box<[ImportSpecifier]>>,
+        M: Into<Box<str>>,
+    {
+        Self {
+            specifiers: specifiers.into(),
+            module: module.into(),
+        }
+    }
+
+    /// Gets the list of imported bindings.
+    pub fn specifiers(&self) -> &[ImportSpecifier] {
+        &self.specifiers
+    }
+
+    /// Gets the specifier of the imported module.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+}
+
+impl Executable for ImportDecl {
+    fn run(&self, _context: &mut Context) -> Result<Value> {
+        // The import bindings are created when the module is linked, before it runs.
+        Ok(Value::undefined())
+    }
+}
+
+impl fmt::Display for ImportDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("import ")?;
+        if self.specifiers.is_empty() {
+            return write!(f, "\"{}\"", self.module);
+        }
+
+        // Only a leading default import is written as a default binding, since the parser puts
+        // it before the other specifiers.
+        let mut first = true;
+        let mut named = Vec::new();
+        for (i, specifier) in self.specifiers.iter().enumerate() {
+            let prefix = match specifier.import_name() {
+                ImportName::Name(name) if i == 0 && name.as_ref() == "default" => "",
+                ImportName::Name(_) => {
+                    named.push(specifier);
+                    continue;
+                }
+                ImportName::Namespace => "* as ",
+            };
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+            write!(f, "{}{}", prefix, specifier.local_name())?;
+        }
+        if !named.is_empty() {
+            if !first {
+                f.write_str(", ")?;
+            }
+            f.write_str("{ ")?;
+            for (i, specifier) in named.into_iter().enumerate() {
+                if i != 0 {
+                    f.write_str(", ")?;
+                }
+                fmt::Display::fmt(specifier, f)?;
+            }
+            f.write_str(" }")?;
+        }
+        write!(f, " from \"{}\"", self.module)
+    }
+}
+
+impl From<ImportDecl> for Node {
+    fn from(decl: ImportDecl) -> Self {
+        Self::ImportDecl(decl)
+    }
+}
+
+/// The name under which a binding is exported by the imported module.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ImportName {
+    /// A single exported binding. The default export is imported with the name `default`.
+    Name(Box<str>),
+
+    /// The namespace object of the module, imported with `* as name`.
+    Namespace,
+}
+
+/// A single binding of an `import` declaration.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportSpecifier
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportSpecifier {
+    import_name: ImportName,
+    local_name: Box<str>,
+}
+
+impl ImportSpecifier {
+    /// Creates a new `ImportSpecifier`.
+    pub fn new<L>(import_name: ImportName, local_name: L) -> Self
+    where
+        L: Into<Box<str>>,
+    {
+        Self {
+            import_name,
+            local_name: local_name.into(),
+        }
+    }
+
+    /// Gets the name of the binding in the imported module.
+    pub fn import_name(&self) -> &ImportName {
+        &self.import_name
+    }
+
+    /// Gets the name of the binding created in the importing module.
+    pub fn local_name(&self) -> &str {
+        &self.local_name
+    }
+}
+
+impl fmt::Display for ImportSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.import_name {
+            ImportName::Name(ref name) if *name == self.local_name => f.write_str(name),
+            ImportName::Name(ref name) => write!(f, "{} as {}", name, self.local_name),
+            ImportName::Namespace => write!(f, "* as {}", self.local_name),
+        }
+    }
+}
+
+/// The `export` declaration exports bindings of a module, so that other modules can import them.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ExportDecl {
+    /// Exports the bindings created by a declaration, like `export let a = 1;` or
+    /// `export function f() {}`.
+    Declaration(Box<Node>),
+
+    /// Exports a function declaration or the value of an expression as the default export, like
+    /// `export default function f() {}` or `export default 1 + 2;`.
+    Default(Box<Node>),
+
+    /// Exports a list of local bindings, like `export { a, b as c };`, or re-exports bindings of
+    /// another module, like `export { a } from "module-name";`.
+    Named {
+        specifiers: Box<[ExportSpecifier]>,
+        module: Option<Box<str>>,
+    },
+
+    /// Re-exports all the bindings of another module, like `export * from "module-name";`, or
+    /// its namespace object, like `export * as name from "module-name";`.
+    All {
+        alias: Option<Box<str>>,
+        module: Box<str>,
+    },
+}
+
+impl ExportDecl {
+    /// Gets the specifier of the re-exported module, if any.
+    pub fn module(&self) -> Option<&str> {
+        match self {
+            Self::Named { module, .. } => module.as_deref(),
+            Self::All { module, .. } => Some(module),
+            Self::Declaration(_) | Self::Default(_) => None,
+        }
+    }
+
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        match self {
+            Self::Declaration(decl) => {
+                f.write_str("export ")?;
+                decl.display_no_indent(f, indentation)
+            }
+            Self::Default(decl) => {
+                f.write_str("export default ")?;
+                decl.display_no_indent(f, indentation)
+            }
+            Self::Named { specifiers, module } => {
+                f.write_str("export {")?;
+                for (i, specifier) in specifiers.iter().enumerate() {
+                    f.write_str(if i == 0 { " " } else { ", " })?;
+                    fmt::Display::fmt(specifier, f)?;
+                }
+                f.write_str(if specifiers.is_empty() { "}" } else { " }" })?;
+                if let Some(module) = module {
+                    write!(f, " from \"{}\"", module)?;
+                }
+                Ok(())
+            }
+            Self::All { alias, module } => {
+                f.write_str("export *")?;
+                if let Some(alias) = alias {
+                    write!(f, " as {}", alias)?;
+                }
+                write!(f, " from \"{}\"", module)
+            }
+        }
+    }
+}
+
+impl Executable for ExportDecl {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        match self {
+            Self::Declaration(decl) => decl.run(context),
+            Self::Default(decl) => match **decl {
+                Node::FunctionDecl(_)
+                | Node::GeneratorDecl(_)
+                | Node::AsyncFunctionDecl(_)
+                | Node::AsyncGeneratorDecl(_) => decl.run(context),
+                _ => {
+                    let value = decl.run_named("default", context)?;
+                    context.initialize_binding(DEFAULT_EXPORT_BINDING, value)?;
+                    Ok(Value::undefined())
+                }
+            },
+            // The exported bindings are resolved when the module is linked, before it runs.
+            Self::Named { .. } | Self::All { .. } => Ok(Value::undefined()),
+        }
+    }
+}
+
+impl fmt::Display for ExportDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ExportDecl> for Node {
+    fn from(decl: ExportDecl) -> Self {
+        Self::ExportDecl(decl)
+    }
+}
+
+/// A single binding of an `export { ... }` declaration.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportSpecifier
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ExportSpecifier {
+    local_name: Box<str>,
+    export_name: Box<str>,
+}
+
+impl ExportSpecifier {
+    /// Creates a new `ExportSpecifier`.
+    pub fn new<L, E>(local_name: L, export_name: E) -> Self
+    where
+        L: Into<Box<str>>,
+        E: Into<Box<str>>,
+    {
+        Self {
+            local_name: local_name.into(),
+            export_name: export_name.into(),
+        }
+    }
+
+    /// Gets the name of the exported binding in this module, or in the re-exported module.
+    pub fn local_name(&self) -> &str {
+        &self.local_name
+    }
+
+    /// Gets the name under which the binding is exported.
+    pub fn export_name(&self) -> &str {
+        &self.export_name
+    }
+}
+
+impl fmt::Display for ExportSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.local_name == self.export_name {
+            f.write_str(&self.local_name)
+        } else {
+            write!(f, "{} as {}", self.local_name, self.export_name)
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use crate::syntax::parser::Parser;
+
+#[test]
+fn fmt() {
+    let source = r#"import "polyfill";
+import defaultName, * as namespace from "a";
+import { a, b as c, default as d } from "b";
+export let e = 1;
+export const f = 2, g = 3;
+export { e, f as i };
+export { a as j } from "a";
+export * from "c";
+export * as k from "d";
+export default e + 1;
+export function h() {};
+"#;
+    let module = Parser::new(source.as_bytes(), false)
+        .parse_module()
+        .unwrap();
+
+    // Function declarations are hoisted to the top of the module.
+    let (rest, function) = source.split_at(source.find("export function").unwrap());
+    assert_eq!(module.to_string(), format!("{}{}", function, rest));
+}
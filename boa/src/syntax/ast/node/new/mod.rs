@@ -1,8 +1,7 @@
 use crate::{
-    builtins::iterable,
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::{Call, Node},
+    syntax::ast::node::{call::evaluate_args, Call, Node},
     value::Value,
     BoaProfiler, Context, Result,
 };
@@ -52,24 +51,7 @@ impl Executable for New {
         let _timer = BoaProfiler::global().start_event("New", "exec");
 
         let func_object = self.expr().run(context)?;
-        let mut v_args = Vec::with_capacity(self.args().len());
-        for arg in self.args() {
-            if let Node::Spread(ref x) = arg {
-                let val = x.run(context)?;
-                let iterator_record = iterable::get_iterator(context, val)?;
-                loop {
-                    let next = iterator_record.next(context)?;
-                    if next.is_done() {
-                        break;
-                    }
-                    let next_value = next.value();
-                    v_args.push(next_value.clone());
-                }
-                break; // after spread we don't accept any new arguments
-            } else {
-                v_args.push(arg.run(context)?);
-            }
-        }
+        let v_args = evaluate_args(self.args(), context)?;
 
         match func_object {
             Value::Object(ref object) => object.construct(&v_args, &object.clone().into(), context),
@@ -1,6 +1,7 @@
 //! Object node.
 
 use crate::{
+    builtins::function::{make_method, set_function_name},
     exec::Executable,
     gc::{Finalize, Trace},
     property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor},
@@ -108,64 +109,101 @@ impl Executable for Object {
         let _timer = BoaProfiler::global().start_event("object", "exec");
         let obj = Value::new_object(context);
 
-        // TODO: Implement the rest of the property types.
         for property in self.properties().iter() {
             match property {
+                PropertyDefinition::IdentifierReference(name) => {
+                    let value = context.get_binding_value(name)?;
+                    obj.set_property(
+                        context.intern_string(name),
+                        PropertyDescriptor::Data(DataDescriptor::new(value, Attribute::all())),
+                    );
+                }
                 PropertyDefinition::Property(key, value) => {
+                    let key = key.to_property_key(context)?;
+                    let value = value.run_named(key.clone(), context)?;
                     obj.set_property(
-                        key.clone(),
-                        PropertyDescriptor::Data(DataDescriptor::new(
-                            value.run(context)?,
-                            Attribute::all(),
-                        )),
+                        key,
+                        PropertyDescriptor::Data(DataDescriptor::new(value, Attribute::all())),
                     );
                 }
-                PropertyDefinition::MethodDefinition(kind, name, func) => match kind {
-                    MethodDefinitionKind::Ordinary => {
-                        obj.set_property(
-                            name.clone(),
-                            PropertyDescriptor::Data(DataDescriptor::new(
-                                func.run(context)?,
-                                Attribute::all(),
-                            )),
-                        );
+                PropertyDefinition::MethodDefinition(kind, name, func) => {
+                    let name = name.to_property_key(context)?;
+                    let function = func.run(context)?;
+                    if let Some(mut function) = function.as_object() {
+                        set_function_name(&mut function, &name, kind.prefix());
+                        make_method(&function, obj.as_object().expect("obj was not an object"));
                     }
-                    MethodDefinitionKind::Get => {
-                        let set = obj
-                            .get_property(name.clone())
-                            .as_ref()
-                            .and_then(|p| p.as_accessor_descriptor())
-                            .and_then(|a| a.setter().cloned());
-                        obj.set_property(
-                            name.clone(),
-                            PropertyDescriptor::Accessor(AccessorDescriptor {
-                                get: func.run(context)?.as_object(),
-                                set,
-                                attributes: Attribute::WRITABLE
-                                    | Attribute::ENUMERABLE
-                                    | Attribute::CONFIGURABLE,
-                            }),
-                        )
+                    match kind {
+                        MethodDefinitionKind::Ordinary => {
+                            obj.set_property(
+                                name,
+                                PropertyDescriptor::Data(DataDescriptor::new(
+                                    function,
+                                    Attribute::all(),
+                                )),
+                            );
+                        }
+                        MethodDefinitionKind::Get => {
+                            let set = obj
+                                .get_property(name.clone())
+                                .as_ref()
+                                .and_then(|p| p.as_accessor_descriptor())
+                                .and_then(|a| a.setter().cloned());
+                            obj.set_property(
+                                name,
+                                PropertyDescriptor::Accessor(AccessorDescriptor {
+                                    get: function.as_object(),
+                                    set,
+                                    attributes: Attribute::WRITABLE
+                                        | Attribute::ENUMERABLE
+                                        | Attribute::CONFIGURABLE,
+                                }),
+                            )
+                        }
+                        MethodDefinitionKind::Set => {
+                            let get = obj
+                                .get_property(name.clone())
+                                .as_ref()
+                                .and_then(|p| p.as_accessor_descriptor())
+                                .and_then(|a| a.getter().cloned());
+                            obj.set_property(
+                                name,
+                                PropertyDescriptor::Accessor(AccessorDescriptor {
+                                    get,
+                                    set: function.as_object(),
+                                    attributes: Attribute::WRITABLE
+                                        | Attribute::ENUMERABLE
+                                        | Attribute::CONFIGURABLE,
+                                }),
+                            )
+                        }
                     }
-                    MethodDefinitionKind::Set => {
-                        let get = obj
-                            .get_property(name.clone())
-                            .as_ref()
-                            .and_then(|p| p.as_accessor_descriptor())
-                            .and_then(|a| a.getter().cloned());
-                        obj.set_property(
-                            name.clone(),
-                            PropertyDescriptor::Accessor(AccessorDescriptor {
-                                get,
-                                set: func.run(context)?.as_object(),
-                                attributes: Attribute::WRITABLE
-                                    | Attribute::ENUMERABLE
-                                    | Attribute::CONFIGURABLE,
-                            }),
-                        )
+                }
+                PropertyDefinition::SpreadObject(source) => {
+                    let source = source.run(context)?;
+                    if source.is_null_or_undefined() {
+                        continue;
                     }
-                },
-                _ => {} //unimplemented!("{:?} type of property", i),
+
+                    // CopyDataProperties: the own enumerable properties of the source become
+                    // data properties of the new object.
+                    let source = source.to_object(context)?;
+                    for key in source.own_property_keys() {
+                        let enumerable = source
+                            .get_own_property(&key)
+                            .map_or(false, |property| property.enumerable());
+                        if enumerable {
+                            let value = source.get(&key, source.clone().into(), context)?;
+                            obj.set_property(
+                                key,
+                                PropertyDescriptor::Data(DataDescriptor::new(
+                                    value,
+                                    Attribute::all(),
+                                )),
+                            );
+                        }
+                    }
+                }
             }
         }
 
@@ -1,3 +1,101 @@
+use crate::exec;
+
+#[test]
+fn computed_symbol_keys() {
+    let scenario = r#"
+        let symbol = Symbol("key");
+        let obj = { [symbol]: 1, [symbol.toString()]: 2 };
+        [obj[symbol], obj["Symbol(key)"]].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,2""#);
+}
+
+#[test]
+fn duplicate_computed_keys() {
+    let scenario = r#"
+        let order = [];
+        function key(name) {
+            order.push(name);
+            return name;
+        }
+        let obj = { [key("a")]: key(1), b: key(2), [key("a")]: key(3) };
+        [obj.a, obj.b, order.join(" ")].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""3,2,a 1 2 a 3""#);
+}
+
+#[test]
+fn shorthand_properties() {
+    let scenario = r#"
+        let x = 1, get = 2;
+        let obj = { x, get };
+        [obj.x, obj.get].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,2""#);
+}
+
+#[test]
+fn shorthand_method_this() {
+    let scenario = r#"
+        let obj = {
+            value: 5,
+            method() {
+                return this.value;
+            },
+            ["computed" + "Method"]() {
+                return this === obj;
+            },
+        };
+        let other = { value: 7, method: obj.method };
+        [obj.method(), other.method(), obj.computedMethod()].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""5,7,true""#);
+}
+
+#[test]
+fn computed_accessors() {
+    let scenario = r#"
+        let name = "value";
+        let obj = {
+            stored: 1,
+            get [name]() {
+                return this.stored * 10;
+            },
+            set [name](v) {
+                this.stored = v;
+            },
+        };
+        obj.value = 4;
+        [obj.stored, obj.value].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""4,40""#);
+}
+
+#[test]
+fn enumerable_getters_are_read_by_json_and_spread() {
+    let scenario = r#"
+        let calls = 0;
+        let obj = {
+            a: 1,
+            get total() {
+                calls++;
+                return this.a + 1;
+            },
+        };
+        let json = JSON.stringify(obj);
+        let copy = { ...obj };
+        let desc = Object.getOwnPropertyDescriptor(copy, "total");
+        [json, copy.total, desc.value, desc.get === undefined, calls].join(" ");
+    "#;
+
+    assert_eq!(&exec(scenario), r#""{"a":1,"total":2} 2 2 true 2""#);
+}
+
 #[test]
 fn fmt() {
     super::super::test_formatting(
@@ -13,6 +111,8 @@ fn fmt() {
                 b: 6,
             },
             ...other,
+            other,
+            [other.c]: 1,
             say_hi: function() {
                 console.log("hello!");
             },
@@ -25,6 +125,9 @@ fn fmt() {
             say_hello(msg) {
                 console.log("hello " + msg);
             },
+            get ["computed" + "getter"]() {
+                return 1;
+            },
         };
         inst.a = 20;
         inst.a;
@@ -2,7 +2,10 @@ use crate::{
     environment::lexical_environment::VariableScope,
     exec::Executable,
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{
+        field::set_super_field, ArrayAssignmentPattern, GetConstField, GetField, Identifier, Node,
+        ObjectAssignmentPattern,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -24,7 +27,7 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Assign {
-    lhs: Box<Node>,
+    lhs: Box<AssignmentTarget>,
     rhs: Box<Node>,
 }
 
@@ -32,7 +35,7 @@ impl Assign {
     /// Creates an `Assign` AST node.
     pub(in crate::syntax) fn new<L, R>(lhs: L, rhs: R) -> Self
     where
-        L: Into<Node>,
+        L: Into<AssignmentTarget>,
         R: Into<Node>,
     {
         Self {
@@ -42,7 +45,7 @@ impl Assign {
     }
 
     /// Gets the left hand side of the assignment operation.
-    pub fn lhs(&self) -> &Node {
+    pub fn lhs(&self) -> &AssignmentTarget {
         &self.lhs
     }
 
@@ -55,45 +58,177 @@ impl Assign {
 impl Executable for Assign {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Assign", "exec");
-        let val = self.rhs().run(context)?;
         match self.lhs() {
-            Node::Identifier(ref name) => {
+            AssignmentTarget::Expression(Node::Identifier(name)) => {
+                let val = self.rhs().run_named(name.as_ref(), context)?;
+                self.lhs().assign(val.clone(), context)?;
+                Ok(val)
+            }
+            // The object and the key of a property target are evaluated before the value.
+            AssignmentTarget::Expression(Node::GetConstField(ref get_const_field))
+                if get_const_field.obj() == &Node::Super =>
+            {
+                let this = context.get_this_binding()?;
+                let val = self.rhs().run(context)?;
+                set_super_field(&this, get_const_field.field().into(), val.clone(), context)?;
+                Ok(val)
+            }
+            AssignmentTarget::Expression(Node::GetField(ref get_field))
+                if get_field.obj() == &Node::Super =>
+            {
+                let this = context.get_this_binding()?;
+                let key = get_field.field().run(context)?.to_property_key(context)?;
+                let val = self.rhs().run(context)?;
+                set_super_field(&this, key, val.clone(), context)?;
+                Ok(val)
+            }
+            AssignmentTarget::Expression(Node::GetConstField(ref get_const_field)) => {
+                let object = get_const_field.obj().run(context)?;
+                let val = self.rhs().run(context)?;
+                let strict = context.executor().strict();
+                object.set_field(get_const_field.field(), val.clone(), strict, context)?;
+                Ok(val)
+            }
+            AssignmentTarget::Expression(Node::GetField(ref get_field)) => {
+                let object = get_field.obj().run(context)?;
+                let field = get_field.field().run(context)?;
+                let key = field.to_property_key(context)?;
+                let val = self.rhs().run(context)?;
+                let strict = context.executor().strict();
+                object.set_field(key, val.clone(), strict, context)?;
+                Ok(val)
+            }
+            _ => {
+                let val = self.rhs().run(context)?;
+                self.lhs().assign(val.clone(), context)?;
+                Ok(val)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Assign {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.lhs, self.rhs)
+    }
+}
+
+impl From<Assign> for Node {
+    fn from(op: Assign) -> Self {
+        Self::Assign(op)
+    }
+}
+
+/// The left hand side of an assignment, which is either an expression or a destructuring
+/// pattern.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-DestructuringAssignmentTarget
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum AssignmentTarget {
+    /// Assigns the value to the reference the expression evaluates to, like `a` or `a.b`.
+    Expression(Node),
+
+    /// Destructures the value with an array assignment pattern, like `[a, b]`.
+    Array(ArrayAssignmentPattern),
+
+    /// Destructures the value with an object assignment pattern, like `{ a, b: c }`.
+    Object(ObjectAssignmentPattern),
+}
+
+impl AssignmentTarget {
+    /// Assigns `value` to the target.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-destructuringassignmentevaluation
+    pub(crate) fn assign(&self, value: Value, context: &mut Context) -> Result<()> {
+        match self {
+            Self::Expression(Node::Identifier(ref name)) => {
                 if context.has_binding(name.as_ref()) {
                     // Binding already exists
-                    context.set_mutable_binding(name.as_ref(), val.clone(), true)?;
+                    context.set_mutable_binding(name.as_ref(), value, true)?;
+                } else if context.executor().strict() {
+                    // Assigning to an undeclared variable throws in strict mode code.
+                    // <https://tc39.es/ecma262/#sec-putvalue>
+                    return Err(
+                        context.construct_reference_error(format!("{} is not defined", name))
+                    );
                 } else {
                     context.create_mutable_binding(
                         name.as_ref().to_owned(),
                         true,
                         VariableScope::Function,
                     )?;
-                    context.initialize_binding(name.as_ref(), val.clone())?;
+                    context.initialize_binding(name.as_ref(), value)?;
                 }
             }
-            Node::GetConstField(ref get_const_field) => {
+            Self::Expression(Node::GetConstField(ref get_const_field)) => {
                 let val_obj = get_const_field.obj().run(context)?;
-                val_obj.set_field(get_const_field.field(), val.clone(), false, context)?;
+                let strict = context.executor().strict();
+                val_obj.set_field(get_const_field.field(), value, strict, context)?;
             }
-            Node::GetField(ref get_field) => {
+            Self::Expression(Node::GetField(ref get_field)) => {
                 let object = get_field.obj().run(context)?;
                 let field = get_field.field().run(context)?;
                 let key = field.to_property_key(context)?;
-                object.set_field(key, val.clone(), false, context)?;
+                let strict = context.executor().strict();
+                object.set_field(key, value, strict, context)?;
             }
-            _ => (),
+            Self::Expression(_) => (),
+            Self::Array(ref pattern) => pattern.assign(value, context)?,
+            Self::Object(ref pattern) => pattern.assign(value, context)?,
         }
-        Ok(val)
+        Ok(())
     }
 }
 
-impl fmt::Display for Assign {
+impl fmt::Display for AssignmentTarget {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} = {}", self.lhs, self.rhs)
+        match self {
+            Self::Expression(node) => fmt::Display::fmt(node, f),
+            Self::Array(pattern) => fmt::Display::fmt(pattern, f),
+            Self::Object(pattern) => fmt::Display::fmt(pattern, f),
+        }
     }
 }
 
-impl From<Assign> for Node {
-    fn from(op: Assign) -> Self {
-        Self::Assign(op)
+impl From<Node> for AssignmentTarget {
+    fn from(node: Node) -> Self {
+        Self::Expression(node)
+    }
+}
+
+impl From<Identifier> for AssignmentTarget {
+    fn from(identifier: Identifier) -> Self {
+        Self::Expression(identifier.into())
+    }
+}
+
+impl From<GetConstField> for AssignmentTarget {
+    fn from(get_const_field: GetConstField) -> Self {
+        Self::Expression(get_const_field.into())
+    }
+}
+
+impl From<GetField> for AssignmentTarget {
+    fn from(get_field: GetField) -> Self {
+        Self::Expression(get_field.into())
+    }
+}
+
+impl From<ArrayAssignmentPattern> for AssignmentTarget {
+    fn from(pattern: ArrayAssignmentPattern) -> Self {
+        Self::Array(pattern)
+    }
+}
+
+impl From<ObjectAssignmentPattern> for AssignmentTarget {
+    fn from(pattern: ObjectAssignmentPattern) -> Self {
+        Self::Object(pattern)
     }
 }
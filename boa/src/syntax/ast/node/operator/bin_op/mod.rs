@@ -1,7 +1,6 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    symbol::WellKnownSymbols,
     syntax::ast::{
         node::Node,
         op::{self, AssignOp, BitOp, CompOp, LogOp, NumOp},
@@ -150,32 +149,9 @@ impl Executable for BinOp {
                             ));
                         }
                         let key = x.to_property_key(context)?;
-                        context.has_property(&y, &key)
-                    }
-                    CompOp::InstanceOf => {
-                        if let Some(object) = y.as_object() {
-                            let key = WellKnownSymbols::has_instance();
-
-                            match object.get_method(context, key)? {
-                                Some(instance_of_handler) => {
-                                    instance_of_handler.call(&y, &[x], context)?.to_boolean()
-                                }
-                                None if object.is_callable() => {
-                                    object.ordinary_has_instance(context, &x)?
-                                }
-                                None => {
-                                    return context.throw_type_error(
-                                        "right-hand side of 'instanceof' is not callable",
-                                    );
-                                }
-                            }
-                        } else {
-                            return context.throw_type_error(format!(
-                                "right-hand side of 'instanceof' should be an object, got {}",
-                                y.get_type().as_str()
-                            ));
-                        }
+                        context.has_property(&y, &key)?
                     }
+                    CompOp::InstanceOf => x.instance_of(&y, context)?,
                 }))
             }
             op::BinOp::Log(op) => Ok(match op {
@@ -216,7 +192,18 @@ impl Executable for BinOp {
                     let v_r_a = get_const_field.obj().run(context)?;
                     let v_a = v_r_a.get_field(get_const_field.field(), context)?;
                     let value = Self::run_assign(op, v_a, self.rhs(), context)?;
-                    v_r_a.set_field(get_const_field.field(), value.clone(), false, context)?;
+                    let strict = context.executor().strict();
+                    v_r_a.set_field(get_const_field.field(), value.clone(), strict, context)?;
+                    Ok(value)
+                }
+                Node::GetField(ref get_field) => {
+                    // The key is evaluated once, and used both to get and to set the property.
+                    let v_r_a = get_field.obj().run(context)?;
+                    let key = get_field.field().run(context)?.to_property_key(context)?;
+                    let v_a = v_r_a.get_field(key.clone(), context)?;
+                    let value = Self::run_assign(op, v_a, self.rhs(), context)?;
+                    let strict = context.executor().strict();
+                    v_r_a.set_field(key, value.clone(), strict, context)?;
                     Ok(value)
                 }
                 _ => Ok(Value::undefined()),
@@ -274,6 +261,12 @@ impl CodeGen for BinOp {
                     CompOp::InstanceOf => compiler.add_instruction(Instruction::InstanceOf),
                 }
             }
+            op::BinOp::Comma => {
+                // Only the value of the right-hand side is kept.
+                self.lhs().compile(compiler);
+                compiler.add_instruction(Instruction::Pop);
+                self.rhs().compile(compiler);
+            }
             _ => unimplemented!(),
         }
     }
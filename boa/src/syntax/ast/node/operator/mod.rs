@@ -4,7 +4,11 @@ pub mod assign;
 pub mod bin_op;
 pub mod unary_op;
 
-pub use self::{assign::Assign, bin_op::BinOp, unary_op::UnaryOp};
+pub use self::{
+    assign::{Assign, AssignmentTarget},
+    bin_op::BinOp,
+    unary_op::UnaryOp,
+};
 
 #[cfg(test)]
 mod tests;
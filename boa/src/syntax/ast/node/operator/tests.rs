@@ -61,6 +61,93 @@ fn instanceofoperator_rhs_not_callable() {
     );
 }
 
+#[test]
+fn instanceofoperator_custom_has_instance() {
+    let scenario = r#"
+        const Even = {
+          [Symbol.hasInstance](value) {
+            return value % 2 === 0;
+          }
+        };
+        [2 instanceof Even, 3 instanceof Even]
+        "#;
+
+    assert_eq!(&exec(scenario), "[ true, false ]");
+
+    // The result of the method is converted to a boolean.
+    let scenario = r#"
+        const Truthy = { [Symbol.hasInstance]: () => "yes" };
+        1 instanceof Truthy
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn instanceofoperator_has_instance_overrides_prototype_chain() {
+    let scenario = r#"
+        function Never() {}
+        Object.defineProperty(Never, Symbol.hasInstance, { value: () => false });
+        new Never() instanceof Never
+        "#;
+
+    assert_eq!(&exec(scenario), "false");
+}
+
+#[test]
+fn instanceofoperator_prototype_chain() {
+    let scenario = r#"
+        function Base() {}
+        function Derived() {}
+        Derived.prototype = Object.create(Base.prototype);
+        const value = new Derived();
+        [value instanceof Derived, value instanceof Base, value instanceof Object, {} instanceof Base]
+        "#;
+
+    assert_eq!(&exec(scenario), "[ true, true, true, false ]");
+}
+
+#[test]
+fn inoperator_checks_prototype_chain_and_symbols() {
+    let scenario = r#"
+        const symbol = Symbol("own");
+        const inherited = Symbol("inherited");
+        const prototype = { property: 1, [inherited]: 2 };
+        const object = Object.create(prototype);
+        object[symbol] = 3;
+        [
+          "property" in object,
+          "toString" in object,
+          "missing" in object,
+          symbol in object,
+          inherited in object,
+          0 in [1],
+          1 in [1],
+        ]
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "[ true, true, false, true, true, true, false ]"
+    );
+}
+
+#[test]
+fn inoperator_rhs_not_object() {
+    let scenario = r#"
+        try {
+          "length" in "string"
+        } catch (err) {
+          err.toString()
+        }
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        "\"TypeError: right-hand side of 'in' should be an object, got string\""
+    );
+}
+
 #[test]
 fn logical_nullish_assignment() {
     let scenario = r#"
@@ -114,6 +201,70 @@ fn logical_assignment() {
     assert_eq!(&exec(scenario), "20");
 }
 
+#[test]
+fn computed_member_assignment_order() {
+    let scenario = r#"
+        let order = [];
+        let obj = {};
+        function target() { order.push("object"); return obj; }
+        function key() { order.push("key"); return "x"; }
+        function value() { order.push("value"); return 1; }
+        target()[key()] = value();
+        order.join() + " " + obj.x;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"object,key,value 1\"");
+
+    let scenario = r#"
+        let order = [];
+        let obj = {};
+        function target() { order.push("object"); return obj; }
+        function value() { order.push("value"); return 1; }
+        target().x = value();
+        order.join() + " " + obj.x;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"object,value 1\"");
+}
+
+#[test]
+fn computed_member_compound_assignment() {
+    let scenario = r#"
+        let calls = 0;
+        let obj = { x: 1 };
+        function key() { calls++; return "x"; }
+        obj[key()] += 1;
+        obj[key()] *= 10;
+        calls + " " + obj.x;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"2 20\"");
+
+    let scenario = r#"
+        let calls = 0;
+        let obj = { x: 0 };
+        let key = { toString() { calls++; return "x"; } };
+        obj[key] ||= 5;
+        calls + " " + obj.x;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1 5\"");
+}
+
+#[test]
+fn update_expression_evaluates_reference_once() {
+    let scenario = r#"
+        let keys = 0, objects = 0;
+        let obj = { x: 1, p: 1 };
+        function k() { keys++; return "x"; }
+        function f() { objects++; return obj; }
+        let results = [obj[k()]++, ++obj[k()], obj[k()]--, --obj[k()], f().p++, ++f().p, f().p--, --f().p];
+        [results.join(), keys, objects, obj.x, obj.p].join(" ");
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,3,3,1,1,3,3,1 4 4 1 1\"");
+}
+
 #[test]
 fn fmt() {
     super::super::test_formatting(
@@ -138,3 +289,127 @@ fn fmt() {
         "#,
     );
 }
+
+#[test]
+fn integer_arithmetic_overflows_to_float() {
+    let scenario = r#"
+        let max = 2147483647;
+        let min = -2147483648;
+        [
+            max + 1,
+            min - 1,
+            max * 2,
+            min * -1,
+            min % -1,
+            1 / (0 * -5),
+            1 / (-4 % 2),
+            7 % 0,
+        ].join()
+    "#;
+    assert_eq!(
+        &exec(scenario),
+        "\"2147483648,-2147483649,4294967294,2147483648,0,-Infinity,-Infinity,NaN\""
+    );
+
+    let scenario = r#"
+        let i = 2147483646;
+        i++;
+        let post = i;
+        ++i;
+        let j = -2147483648;
+        j--;
+        [post, i, j, i - 1 === 2147483647].join()
+    "#;
+    assert_eq!(
+        &exec(scenario),
+        "\"2147483647,2147483648,-2147483649,true\""
+    );
+}
+
+#[test]
+fn integer_sum_loop() {
+    let scenario = r#"
+        let sum = 0;
+        for (let i = 1; i <= 70000; i++) {
+            sum = sum + i;
+        }
+        sum
+    "#;
+    assert_eq!(&exec(scenario), "2450035000");
+}
+
+#[test]
+fn operator_precedence_and_associativity() {
+    let cases = [
+        ("1 + 2 * 3", "7"),
+        ("(1 + 2) * 3", "9"),
+        ("10 - 4 - 3", "3"),
+        ("100 / 10 / 5", "2"),
+        ("17 % 5 * 2", "4"),
+        ("2 ** 3 ** 2", "512"),
+        ("(2 ** 3) ** 2", "64"),
+        ("2 * 3 ** 2", "18"),
+        ("2 + 3 * 4 ** 2 / 8 - 1", "7"),
+        ("2 ** -1", "0.5"),
+        ("(-2) ** 2", "4"),
+        ("-(2 ** 2)", "-4"),
+        ("1 - -1", "2"),
+        ("-2 * -3", "6"),
+        ("!0 + 1", "2"),
+        ("typeof 1 + 2", "\"number2\""),
+        ("typeof typeof 1", "\"string\""),
+        ("\"3\" + 4 + 5", "\"345\""),
+        ("3 + 4 + \"5\"", "\"75\""),
+        ("1 + 2 << 1", "6"),
+        ("1 << 2 + 1", "8"),
+        ("-16 >> 2 >>> 28", "15"),
+        ("1 | 2 ^ 3 & 4", "3"),
+        ("5 & 3 === 3", "1"),
+        ("1 < 2 === 2 > 1", "true"),
+        ("\"b\" in { b: 1 } === false", "false"),
+        ("1 + 2 > 2 && 3 - 4 < 0 ? \"yes\" : \"no\"", "\"yes\""),
+        ("true || false && false", "true"),
+        ("(true || false) && false", "false"),
+        ("false && true || true", "true"),
+        ("null ?? 1 + 1", "2"),
+        ("0 ?? 1 ? \"a\" : \"b\"", "\"b\""),
+        ("true ? 1 : false ? 2 : 3", "1"),
+        ("false ? 1 : false ? 2 : 3", "3"),
+        ("false ? 1 : true ? 2 : 3", "2"),
+        ("true ? false ? 1 : 2 : 3", "2"),
+        ("1, 2, 3", "3"),
+        ("void 0 === undefined", "true"),
+    ];
+    for (source, expected) in cases.iter() {
+        assert_eq!(&exec(source), expected, "evaluating `{}`", source);
+    }
+}
+
+#[test]
+fn assignment_is_right_associative() {
+    let scenario = r#"
+        var a, b, c;
+        a = b = c = 4;
+        var x = 2;
+        x += x *= 3;
+        var y = 1;
+        y = 2, 3;
+        var z;
+        var w = true ? z = 5 : z = 6;
+        [a, b, c, x, y, z, w].join()
+    "#;
+    assert_eq!(&exec(scenario), "\"4,4,4,8,2,5,5\"");
+}
+
+#[test]
+fn in_operator_inside_conditional_in_for_head() {
+    let scenario = r#"
+        var result;
+        for (var x = true ? "a" in { a: 1 } : false; ; ) {
+            result = x;
+            break;
+        }
+        result
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
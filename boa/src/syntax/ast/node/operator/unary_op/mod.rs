@@ -51,6 +51,62 @@ impl UnaryOp {
     pub fn target(&self) -> &Node {
         self.target.as_ref()
     }
+
+    /// Gets the result of a `delete` of a property, which throws in strict mode code if the
+    /// property couldn't be deleted.
+    fn check_deleted(deleted: bool, context: &mut Context) -> Result<Value> {
+        if !deleted && context.executor().strict() {
+            return context.throw_type_error("Cannot delete property");
+        }
+        Ok(Value::boolean(deleted))
+    }
+
+    /// Adds `delta` to the number value of `value`, for the increment and decrement operators.
+    ///
+    /// Small integers stay unboxed, unless the result overflows.
+    fn step(value: &Value, delta: i32, context: &mut Context) -> Result<Value> {
+        if let Value::Integer(x) = value {
+            if let Some(result) = x.checked_add(delta) {
+                return Ok(Value::integer(result));
+            }
+        }
+        Ok(Value::rational(
+            value.to_number(context)? + f64::from(delta),
+        ))
+    }
+
+    /// Adds `delta` to the value of the target reference, for the increment and decrement
+    /// operators, and returns the old and the new values.
+    ///
+    /// The object and the key of a property reference are evaluated once, and used both to get
+    /// and to set the property.
+    fn run_update(&self, delta: i32, context: &mut Context) -> Result<(Value, Value)> {
+        match *self.target() {
+            Node::GetConstField(ref get_const_field) => {
+                let obj = get_const_field.obj().run(context)?;
+                let old = obj.get_field(get_const_field.field(), context)?;
+                let new = Self::step(&old, delta, context)?;
+                let strict = context.executor().strict();
+                obj.set_field(get_const_field.field(), new.clone(), strict, context)?;
+                Ok((old, new))
+            }
+            Node::GetField(ref get_field) => {
+                let obj = get_field.obj().run(context)?;
+                let key = get_field.field().run(context)?.to_property_key(context)?;
+                let old = obj.get_field(key.clone(), context)?;
+                let new = Self::step(&old, delta, context)?;
+                let strict = context.executor().strict();
+                obj.set_field(key, new.clone(), strict, context)?;
+                Ok((old, new))
+            }
+            _ => {
+                let old = self.target().run(context)?;
+                let new = Self::step(&old, delta, context)?;
+                let new = context.set_value(self.target(), new)?;
+                Ok((old, new))
+            }
+        }
+    }
 }
 
 impl Executable for UnaryOp {
@@ -58,28 +114,10 @@ impl Executable for UnaryOp {
         Ok(match self.op() {
             op::UnaryOp::Minus => self.target().run(context)?.neg(context)?,
             op::UnaryOp::Plus => Value::from(self.target().run(context)?.to_number(context)?),
-            op::UnaryOp::IncrementPost => {
-                let x = self.target().run(context)?;
-                let ret = x.clone();
-                let result = x.to_number(context)? + 1.0;
-                context.set_value(self.target(), result.into())?;
-                ret
-            }
-            op::UnaryOp::IncrementPre => {
-                let result = self.target().run(context)?.to_number(context)? + 1.0;
-                context.set_value(self.target(), result.into())?
-            }
-            op::UnaryOp::DecrementPost => {
-                let x = self.target().run(context)?;
-                let ret = x.clone();
-                let result = x.to_number(context)? - 1.0;
-                context.set_value(self.target(), result.into())?;
-                ret
-            }
-            op::UnaryOp::DecrementPre => {
-                let result = self.target().run(context)?.to_number(context)? - 1.0;
-                context.set_value(self.target(), result.into())?
-            }
+            op::UnaryOp::IncrementPost => self.run_update(1, context)?.0,
+            op::UnaryOp::IncrementPre => self.run_update(1, context)?.1,
+            op::UnaryOp::DecrementPost => self.run_update(-1, context)?.0,
+            op::UnaryOp::DecrementPre => self.run_update(-1, context)?.1,
             op::UnaryOp::Not => self.target().run(context)?.not(context)?.into(),
             op::UnaryOp::Tilde => {
                 let num_v_a = self.target().run(context)?.to_number(context)?;
@@ -95,33 +133,43 @@ impl Executable for UnaryOp {
                 Value::undefined()
             }
             op::UnaryOp::Delete => match *self.target() {
-                Node::GetConstField(ref get_const_field) => Value::boolean(
-                    get_const_field
+                Node::GetConstField(ref get_const_field) => {
+                    let deleted = get_const_field
                         .obj()
                         .run(context)?
                         .to_object(context)?
-                        .delete(&get_const_field.field().into()),
-                ),
+                        .delete(&get_const_field.field().into(), context)?;
+                    Self::check_deleted(deleted, context)?
+                }
                 Node::GetField(ref get_field) => {
                     let obj = get_field.obj().run(context)?;
                     let field = &get_field.field().run(context)?;
-                    let res = obj
+                    let deleted = obj
                         .to_object(context)?
-                        .delete(&field.to_property_key(context)?);
-                    return Ok(Value::boolean(res));
+                        .delete(&field.to_property_key(context)?, context)?;
+                    Self::check_deleted(deleted, context)?
+                }
+                Node::Optional(ref optional) => {
+                    let deleted = optional.delete(context)?;
+                    Self::check_deleted(deleted, context)?
+                }
+                // Deleting an unresolvable reference succeeds, instead of throwing a ReferenceError.
+                Node::Identifier(ref ident) => {
+                    Value::boolean(context.delete_binding(ident.as_ref())?)
+                }
+                // Deleting a value that isn't a reference always succeeds, once it is evaluated.
+                ref target => {
+                    target.run(context)?;
+                    Value::boolean(true)
+                }
+            },
+            op::UnaryOp::TypeOf => match self.target() {
+                // The type of an unresolvable reference is "undefined", instead of a ReferenceError.
+                Node::Identifier(ident) if !context.has_binding(ident.as_ref()) => {
+                    Value::from("undefined")
                 }
-                Node::Identifier(_) => Value::boolean(false),
-                Node::ArrayDecl(_)
-                | Node::Block(_)
-                | Node::Const(_)
-                | Node::FunctionDecl(_)
-                | Node::FunctionExpr(_)
-                | Node::New(_)
-                | Node::Object(_)
-                | Node::UnaryOp(_) => Value::boolean(true),
-                _ => return context.throw_syntax_error(format!("wrong delete argument {}", self)),
+                target => Value::from(target.run(context)?.get_type().as_str()),
             },
-            op::UnaryOp::TypeOf => Value::from(self.target().run(context)?.get_type().as_str()),
         })
     }
 }
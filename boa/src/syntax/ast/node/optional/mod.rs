@@ -0,0 +1,283 @@
+use crate::{
+    exec::{Executable, InterpreterState},
+    gc::{Finalize, Trace},
+    syntax::ast::node::{
+        call::{describe_callee, evaluate_args},
+        join_nodes, Node,
+    },
+    value::{Type, Value},
+    BoaProfiler, Context, Result,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The optional chaining operator (`?.`) permits reading the value of a property located deep
+/// within a chain of connected objects without having to validate each reference in the chain.
+///
+/// If the value before a `?.` is nullish (`null` or `undefined`), the rest of the chain is not
+/// evaluated and the whole expression evaluates to `undefined`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Optional {
+    target: Box<Node>,
+    chain: Box<[OptionalOperation]>,
+}
+
+impl Optional {
+    /// Creates a new `Optional` AST node.
+    pub fn new<T, C>(target: T, chain: C) -> Self
+    where
+        T: Into<Node>,
+        C: Into<Box<[OptionalOperation]>>,
+    {
+        Self {
+            target: Box::new(target.into()),
+            chain: chain.into(),
+        }
+    }
+
+    /// Gets the expression the chain is applied to.
+    pub fn target(&self) -> &Node {
+        &self.target
+    }
+
+    /// Gets the operations of the chain.
+    pub fn chain(&self) -> &[OptionalOperation] {
+        &self.chain
+    }
+
+    /// Describes the callee of the call at `index` in the chain, for the error thrown when it is
+    /// not a function.
+    fn describe_callee(&self, index: usize) -> String {
+        let mut description = describe_callee(self.target());
+        for operation in &self.chain[..index] {
+            description = match operation.kind() {
+                OptionalOperationKind::SimplePropertyAccess(field) => {
+                    format!("{}.{}", description, field)
+                }
+                OptionalOperationKind::PropertyAccess(field) => {
+                    format!("{}[{}]", description, describe_callee(field))
+                }
+                OptionalOperationKind::Call(_) => "(intermediate value)".to_owned(),
+            };
+        }
+        description
+    }
+}
+
+impl Optional {
+    /// Evaluates the chain up to, but not including, the operation at `end`.
+    ///
+    /// Returns the value of the chain, with the `this` value for a call of that value, which is
+    /// the object of the last property access. Returns `None` if the chain short-circuits.
+    fn run_until(&self, end: usize, context: &mut Context) -> Result<Option<(Value, Value)>> {
+        // The `this` value of a call in the chain is the object of the preceding property access.
+        let (mut this, mut value) = match self.target() {
+            Node::GetConstField(ref get_const_field) => {
+                let mut obj = get_const_field.obj().run(context)?;
+                if obj.get_type() != Type::Object {
+                    obj = Value::Object(obj.to_object(context)?);
+                }
+                let value = obj.get_field(get_const_field.field(), context)?;
+                (obj, value)
+            }
+            Node::GetField(ref get_field) => {
+                let mut obj = get_field.obj().run(context)?;
+                if obj.get_type() != Type::Object {
+                    obj = Value::Object(obj.to_object(context)?);
+                }
+                let field = get_field.field().run(context)?;
+                let value = obj.get_field(field.to_property_key(context)?, context)?;
+                (obj, value)
+            }
+            target => (Value::undefined(), target.run(context)?),
+        };
+
+        for (index, operation) in self.chain()[..end].iter().enumerate() {
+            if operation.shorted() && value.is_null_or_undefined() {
+                return Ok(None);
+            }
+
+            match operation.kind() {
+                OptionalOperationKind::SimplePropertyAccess(field) => {
+                    if value.get_type() != Type::Object {
+                        value = Value::Object(value.to_object(context)?);
+                    }
+                    let field_value = value.get_field(field.as_ref(), context)?;
+                    this = std::mem::replace(&mut value, field_value);
+                }
+                OptionalOperationKind::PropertyAccess(field) => {
+                    if value.get_type() != Type::Object {
+                        value = Value::Object(value.to_object(context)?);
+                    }
+                    let key = field.run(context)?.to_property_key(context)?;
+                    let field_value = value.get_field(key, context)?;
+                    this = std::mem::replace(&mut value, field_value);
+                }
+                OptionalOperationKind::Call(args) => {
+                    let args = evaluate_args(args, context)?;
+                    if !value.is_callable() {
+                        return Err(context.construct_type_error(format!(
+                            "{} is not a function",
+                            self.describe_callee(index)
+                        )));
+                    }
+                    value = context.call(&value, &this, &args)?;
+
+                    // unset the early return flag
+                    context
+                        .executor()
+                        .set_current_state(InterpreterState::Executing);
+
+                    this = Value::undefined();
+                }
+            }
+        }
+
+        Ok(Some((this, value)))
+    }
+
+    /// Evaluates the chain as the callee of a call, like `(a?.b)()`, returning the `this` value
+    /// of the call with the function.
+    ///
+    /// The `this` value is the object of the last property access, like for `a.b()`.
+    pub(crate) fn run_callee(&self, context: &mut Context) -> Result<(Value, Value)> {
+        Ok(self
+            .run_until(self.chain.len(), context)?
+            .unwrap_or_else(|| (Value::undefined(), Value::undefined())))
+    }
+
+    /// Deletes the property the chain refers to, for the `delete` operator.
+    ///
+    /// Returns `true` if the chain short-circuits, or if it doesn't end with a property access.
+    pub(crate) fn delete(&self, context: &mut Context) -> Result<bool> {
+        let last = match self.chain.last() {
+            Some(last) if !matches!(last.kind(), OptionalOperationKind::Call(_)) => last,
+            // Deleting a value that isn't a reference always succeeds, once it is evaluated.
+            _ => {
+                self.run(context)?;
+                return Ok(true);
+            }
+        };
+
+        let mut object = match self.run_until(self.chain.len() - 1, context)? {
+            Some((_, object)) if !(last.shorted() && object.is_null_or_undefined()) => {
+                object.to_object(context)?
+            }
+            _ => return Ok(true),
+        };
+        let key = match last.kind() {
+            OptionalOperationKind::SimplePropertyAccess(field) => field.as_ref().into(),
+            OptionalOperationKind::PropertyAccess(field) => {
+                field.run(context)?.to_property_key(context)?
+            }
+            OptionalOperationKind::Call(_) => unreachable!("the chain doesn't end with a call"),
+        };
+        object.delete(&key, context)
+    }
+}
+
+impl Executable for Optional {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Optional", "exec");
+
+        Ok(self
+            .run_until(self.chain.len(), context)?
+            .map_or(Value::undefined(), |(_, value)| value))
+    }
+}
+
+impl fmt::Display for Optional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.target)?;
+        for operation in self.chain().iter() {
+            write!(f, "{}", operation)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Optional> for Node {
+    fn from(optional: Optional) -> Self {
+        Self::Optional(optional)
+    }
+}
+
+/// A single operation of an optional chain.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalChain
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct OptionalOperation {
+    kind: OptionalOperationKind,
+    shorted: bool,
+}
+
+impl OptionalOperation {
+    /// Creates a new `OptionalOperation`.
+    ///
+    /// `shorted` is `true` if the operation is preceded by `?.`, in which case the chain
+    /// evaluates to `undefined` if the value it applies to is nullish.
+    pub fn new(kind: OptionalOperationKind, shorted: bool) -> Self {
+        Self { kind, shorted }
+    }
+
+    /// Gets the kind of the operation.
+    pub fn kind(&self) -> &OptionalOperationKind {
+        &self.kind
+    }
+
+    /// Returns `true` if the operation short-circuits the chain on nullish values.
+    pub fn shorted(&self) -> bool {
+        self.shorted
+    }
+}
+
+impl fmt::Display for OptionalOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.shorted {
+            f.write_str("?.")?;
+        }
+        match &self.kind {
+            OptionalOperationKind::SimplePropertyAccess(field) if self.shorted => {
+                f.write_str(field)
+            }
+            OptionalOperationKind::SimplePropertyAccess(field) => write!(f, ".{}", field),
+            OptionalOperationKind::PropertyAccess(field) => write!(f, "[{}]", field),
+            OptionalOperationKind::Call(args) => {
+                f.write_str("(")?;
+                join_nodes(f, args)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// The kind of an optional chain operation.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum OptionalOperationKind {
+    /// A property access with a constant name, like `a?.b` or `a?.b.c`.
+    SimplePropertyAccess(Box<str>),
+
+    /// A computed property access, like `a?.[b]`.
+    PropertyAccess(Node),
+
+    /// A function call, like `a?.()` or `a?.b()`.
+    Call(Box<[Node]>),
+}
@@ -0,0 +1,118 @@
+use crate::exec;
+
+#[test]
+fn property_access() {
+    let scenario = r#"
+        let a = { b: { c: 1 } };
+        let n = null;
+        [a?.b.c, a?.["b"]?.c, undefined?.x === undefined, n?.["x"] === undefined].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,1,true,true""#);
+}
+
+#[test]
+fn short_circuits_whole_chain() {
+    let scenario = r#"
+        let calls = 0;
+        let a = null;
+        [a?.b.c.d === undefined, a?.b() === undefined, a?.[calls++]().x === undefined, calls].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""true,true,true,0""#);
+}
+
+#[test]
+fn method_call() {
+    let scenario = r#"
+        let o = {
+            v: 2,
+            f: function () {
+                return this.v;
+            }
+        };
+        [o?.f(), o.f?.(), o?.["f"](), o.g?.() === undefined].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""2,2,2,true""#);
+}
+
+#[test]
+fn non_nullish_values_are_not_shorted() {
+    let scenario = r#"
+        let a = { b: 0 };
+        try {
+            a?.b.c.d;
+        } catch (e) {
+            e instanceof TypeError;
+        }
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn call_of_non_function() {
+    let scenario = r#"
+        let a = { b: 1 };
+        try {
+            a.b?.();
+        } catch (e) {
+            e instanceof TypeError;
+        }
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn coalesce() {
+    let scenario = r#"
+        let a = null;
+        [0 ?? 5, a ?? 5, a?.b ?? "default"].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""0,5,default""#);
+}
+
+#[test]
+fn delete() {
+    let scenario = r#"
+        let calls = 0;
+        let o = { a: 1, b: { c: 2 } };
+        let n = null;
+        [
+            delete o?.a, "a" in o,
+            delete o?.b.c, "c" in o.b,
+            delete o?.["b"], "b" in o,
+            delete n?.a, delete n?.[calls++], calls,
+        ].join();
+    "#;
+
+    assert_eq!(
+        &exec(scenario),
+        r#""true,false,true,false,true,false,true,true,0""#
+    );
+}
+
+#[test]
+fn parenthesized_method_call() {
+    let scenario = r#"
+        let o = { v: 2, f() { return this.v; } };
+        [(o?.f)(), (o?.["f"])(), (o.f)()].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""2,2,2""#);
+}
+
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        a?.b.c;
+        a?.[b][c];
+        a?.(b, c).d?.();
+        a.b?.c(d);
+        "#,
+    );
+}
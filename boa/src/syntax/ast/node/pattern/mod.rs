@@ -0,0 +1,882 @@
+//! Destructuring binding and assignment pattern nodes.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-destructuring-binding-patterns
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+
+use crate::{
+    builtins::{iterable::get_iterator, Array},
+    exec::Executable,
+    gc::{Finalize, Trace},
+    object::GcObject,
+    property::{Attribute, DataDescriptor, PropertyKey},
+    syntax::ast::node::{AssignmentTarget, Node},
+    Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// A binding target, which is either a single identifier or a destructuring pattern.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ForBinding
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub enum Binding {
+    /// Binds the value to a single identifier.
+    Identifier(Box<str>),
+
+    /// Destructures the value with a binding pattern.
+    Pattern(BindingPattern),
+}
+
+impl Binding {
+    /// Gets the names of all the identifiers bound by this binding, in source order.
+    pub fn names(&self) -> Vec<&str> {
+        let mut names = Vec::new();
+        self.collect_names(&mut names);
+        names
+    }
+
+    /// Evaluates the default value of the binding, naming an anonymous function after the
+    /// identifier if the binding is a single identifier.
+    pub(crate) fn run_default(&self, init: &Node, context: &mut Context) -> Result<Value> {
+        match self {
+            Self::Identifier(name) => init.run_named(name.as_ref(), context),
+            Self::Pattern(_) => init.run(context),
+        }
+    }
+
+    fn collect_names<'a>(&'a self, names: &mut Vec<&'a str>) {
+        match self {
+            Self::Identifier(name) => names.push(name),
+            Self::Pattern(BindingPattern::Object(pattern)) => {
+                for property in pattern.properties() {
+                    property.element().binding().collect_names(names);
+                }
+                if let Some(rest) = pattern.rest() {
+                    names.push(rest);
+                }
+            }
+            Self::Pattern(BindingPattern::Array(pattern)) => {
+                for element in pattern.elements().iter().flatten() {
+                    element.binding().collect_names(names);
+                }
+                if let Some(rest) = pattern.rest() {
+                    rest.collect_names(names);
+                }
+            }
+        }
+    }
+
+    /// Checks if the binding has default value initializers, which have to be evaluated when
+    /// the value is bound.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-static-semantics-containsexpression
+    pub fn contains_expression(&self) -> bool {
+        match self {
+            Self::Identifier(_) => false,
+            Self::Pattern(BindingPattern::Object(pattern)) => pattern
+                .properties()
+                .iter()
+                .any(|property| property.element().contains_expression()),
+            Self::Pattern(BindingPattern::Array(pattern)) => {
+                pattern
+                    .elements()
+                    .iter()
+                    .flatten()
+                    .any(BindingElement::contains_expression)
+                    || pattern.rest().map_or(false, Binding::contains_expression)
+            }
+        }
+    }
+
+    /// Binds `value` to the identifiers of this binding.
+    ///
+    /// Every bound identifier is passed to `bind` with its value, in source order, so default
+    /// value initializers can refer to the identifiers bound before them.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-bindinginitialization
+    pub(crate) fn initialize<F>(
+        &self,
+        value: Value,
+        context: &mut Context,
+        bind: &mut F,
+    ) -> Result<()>
+    where
+        F: FnMut(&str, Value, &mut Context) -> Result<()>,
+    {
+        match self {
+            Self::Identifier(name) => bind(name, value, context),
+            Self::Pattern(BindingPattern::Object(pattern)) => {
+                pattern.initialize(value, context, bind)
+            }
+            Self::Pattern(BindingPattern::Array(pattern)) => {
+                pattern.initialize(value, context, bind)
+            }
+        }
+    }
+}
+
+impl From<&str> for Binding {
+    fn from(name: &str) -> Self {
+        Self::Identifier(name.into())
+    }
+}
+
+impl From<Box<str>> for Binding {
+    fn from(name: Box<str>) -> Self {
+        Self::Identifier(name)
+    }
+}
+
+impl From<String> for Binding {
+    fn from(name: String) -> Self {
+        Self::Identifier(name.into_boxed_str())
+    }
+}
+
+impl From<BindingPattern> for Binding {
+    fn from(pattern: BindingPattern) -> Self {
+        Self::Pattern(pattern)
+    }
+}
+
+impl From<ObjectBindingPattern> for Binding {
+    fn from(pattern: ObjectBindingPattern) -> Self {
+        Self::Pattern(pattern.into())
+    }
+}
+
+impl From<ArrayBindingPattern> for Binding {
+    fn from(pattern: ArrayBindingPattern) -> Self {
+        Self::Pattern(pattern.into())
+    }
+}
+
+impl fmt::Display for Binding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Identifier(name) => f.write_str(name),
+            Self::Pattern(pattern) => fmt::Display::fmt(pattern, f),
+        }
+    }
+}
+
+/// A destructuring binding pattern.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingPattern
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub enum BindingPattern {
+    /// An object binding pattern, like `{ a, b: c = 1, ...rest }`.
+    Object(ObjectBindingPattern),
+
+    /// An array binding pattern, like `[a, , b = 1, ...rest]`.
+    Array(ArrayBindingPattern),
+}
+
+impl From<ObjectBindingPattern> for BindingPattern {
+    fn from(pattern: ObjectBindingPattern) -> Self {
+        Self::Object(pattern)
+    }
+}
+
+impl From<ArrayBindingPattern> for BindingPattern {
+    fn from(pattern: ArrayBindingPattern) -> Self {
+        Self::Array(pattern)
+    }
+}
+
+impl fmt::Display for BindingPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Object(pattern) => fmt::Display::fmt(pattern, f),
+            Self::Array(pattern) => fmt::Display::fmt(pattern, f),
+        }
+    }
+}
+
+/// An object binding pattern binds the properties of an object to identifiers.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub struct ObjectBindingPattern {
+    properties: Box<[BindingProperty]>,
+    rest: Option<Box<str>>,
+}
+
+impl ObjectBindingPattern {
+    /// Creates a new object binding pattern.
+    pub fn new<P>(properties: P, rest: Option<Box<str>>) -> Self
+    where
+        P: Into<Box<[BindingProperty]>>,
+    {
+        Self {
+            properties: properties.into(),
+            rest,
+        }
+    }
+
+    /// Gets the properties of the pattern.
+    pub fn properties(&self) -> &[BindingProperty] {
+        &self.properties
+    }
+
+    /// Gets the identifier that collects the remaining properties, if any.
+    pub fn rest(&self) -> Option<&str> {
+        self.rest.as_deref()
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-destructuring-binding-patterns-runtime-semantics-bindinginitialization
+    fn initialize<F>(&self, value: Value, context: &mut Context, bind: &mut F) -> Result<()>
+    where
+        F: FnMut(&str, Value, &mut Context) -> Result<()>,
+    {
+        // 1. Perform ? RequireObjectCoercible(value).
+        if value.is_null_or_undefined() {
+            return Err(
+                context.construct_type_error(format!("cannot destructure '{}'", value.display()))
+            );
+        }
+        let object = value.to_object(context)?;
+
+        for property in self.properties() {
+            let key = PropertyKey::from(property.name());
+            let property_value = object.get(&key, value.clone(), context)?;
+            property
+                .element()
+                .initialize(property_value, context, bind)?;
+        }
+
+        if let Some(rest) = self.rest() {
+            let excluded = self.properties().iter().map(BindingProperty::name);
+            let rest_object = copy_rest_properties(&object, &value, excluded, context)?;
+            bind(rest, rest_object, context)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Copies the own enumerable properties of `object` that are not `excluded` into a new object,
+/// for the rest property of an object pattern.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-copydataproperties
+fn copy_rest_properties<'a, I>(
+    object: &GcObject,
+    value: &Value,
+    excluded: I,
+    context: &mut Context,
+) -> Result<Value>
+where
+    I: Iterator<Item = &'a str> + Clone,
+{
+    let rest_object = Value::new_object(context);
+    for key in object.own_property_keys() {
+        let is_excluded = match key {
+            PropertyKey::String(ref name) => {
+                excluded.clone().any(|excluded| excluded == name.as_str())
+            }
+            PropertyKey::Index(index) => excluded
+                .clone()
+                .any(|excluded| excluded == index.to_string()),
+            PropertyKey::Symbol(_) => false,
+        };
+        if is_excluded {
+            continue;
+        }
+        if let Some(desc) = object.get_own_property(&key) {
+            if desc.enumerable() {
+                let property_value = object.get(&key, value.clone(), context)?;
+                rest_object
+                    .set_property(key, DataDescriptor::new(property_value, Attribute::all()));
+            }
+        }
+    }
+    Ok(rest_object)
+}
+
+impl fmt::Display for ObjectBindingPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        let mut first = true;
+        for property in self.properties() {
+            f.write_str(if first { " " } else { ", " })?;
+            first = false;
+            fmt::Display::fmt(property, f)?;
+        }
+        if let Some(rest) = self.rest() {
+            f.write_str(if first { " " } else { ", " })?;
+            first = false;
+            write!(f, "...{}", rest)?;
+        }
+        f.write_str(if first { "}" } else { " }" })
+    }
+}
+
+/// A property of an object binding pattern, like `a`, `a = 1` or `b: c = 1`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingProperty
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub struct BindingProperty {
+    name: Box<str>,
+    element: BindingElement,
+}
+
+impl BindingProperty {
+    /// Creates a new binding property, that binds the property `name` to `element`.
+    pub fn new<N>(name: N, element: BindingElement) -> Self
+    where
+        N: Into<Box<str>>,
+    {
+        Self {
+            name: name.into(),
+            element,
+        }
+    }
+
+    /// Gets the name of the destructured property.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the binding element the property value is bound to.
+    pub fn element(&self) -> &BindingElement {
+        &self.element
+    }
+}
+
+impl fmt::Display for BindingProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.element.binding() {
+            Binding::Identifier(name) if *name == self.name => {}
+            _ => write!(f, "{}: ", self.name)?,
+        }
+        fmt::Display::fmt(&self.element, f)
+    }
+}
+
+/// An array binding pattern binds the values produced by an iterator to identifiers.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub struct ArrayBindingPattern {
+    elements: Box<[Option<BindingElement>]>,
+    rest: Option<Box<Binding>>,
+}
+
+impl ArrayBindingPattern {
+    /// Creates a new array binding pattern.
+    ///
+    /// A `None` element is an elision, which skips a value of the iterator.
+    pub fn new<E>(elements: E, rest: Option<Binding>) -> Self
+    where
+        E: Into<Box<[Option<BindingElement>]>>,
+    {
+        Self {
+            elements: elements.into(),
+            rest: rest.map(Box::new),
+        }
+    }
+
+    /// Gets the elements of the pattern.
+    pub fn elements(&self) -> &[Option<BindingElement>] {
+        &self.elements
+    }
+
+    /// Gets the binding that collects the remaining values, if any.
+    pub fn rest(&self) -> Option<&Binding> {
+        self.rest.as_deref()
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-iteratorbindinginitialization
+    fn initialize<F>(&self, value: Value, context: &mut Context, bind: &mut F) -> Result<()>
+    where
+        F: FnMut(&str, Value, &mut Context) -> Result<()>,
+    {
+        let iterator = get_iterator(context, value)?;
+        let mut done = false;
+
+        for element in self.elements() {
+            // Errors of the iterator itself finish it, so it must not be closed.
+            let next = if done {
+                Value::undefined()
+            } else {
+                let next = iterator.next(context)?;
+                done = next.is_done();
+                if done {
+                    Value::undefined()
+                } else {
+                    next.value()
+                }
+            };
+
+            if let Some(element) = element {
+                if let Err(error) = element.initialize(next, context, bind) {
+                    if done {
+                        return Err(error);
+                    }
+                    return iterator.close(Err(error), context).map(|_| ());
+                }
+            }
+        }
+
+        if let Some(rest) = self.rest() {
+            let array = Array::new_array(context);
+            let mut values = Vec::new();
+            while !done {
+                let next = iterator.next(context)?;
+                done = next.is_done();
+                if !done {
+                    values.push(next.value());
+                }
+            }
+            Array::add_to_array_object(&array, &values, context)?;
+            return rest.initialize(array, context, bind);
+        }
+
+        if !done {
+            iterator.close(Ok(Value::undefined()), context)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ArrayBindingPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        let mut first = true;
+        for element in self.elements() {
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+            if let Some(element) = element {
+                fmt::Display::fmt(element, f)?;
+            }
+        }
+        if let Some(rest) = self.rest() {
+            if !first {
+                f.write_str(", ")?;
+            }
+            write!(f, "...{}", rest)?;
+        } else if matches!(self.elements().last(), Some(None)) {
+            // A trailing elision needs a trailing comma.
+            f.write_str(",")?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// A binding element, which is a binding with an optional default value initializer.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingElement
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub struct BindingElement {
+    binding: Binding,
+    init: Option<Node>,
+}
+
+impl BindingElement {
+    /// Creates a new binding element.
+    pub fn new<B>(binding: B, init: Option<Node>) -> Self
+    where
+        B: Into<Binding>,
+    {
+        Self {
+            binding: binding.into(),
+            init,
+        }
+    }
+
+    /// Gets the binding of the element.
+    pub fn binding(&self) -> &Binding {
+        &self.binding
+    }
+
+    /// Gets the default value initializer of the element, if any.
+    pub fn init(&self) -> Option<&Node> {
+        self.init.as_ref()
+    }
+
+    fn contains_expression(&self) -> bool {
+        self.init.is_some() || self.binding.contains_expression()
+    }
+
+    /// Binds `value`, or the default value if `value` is `undefined`.
+    fn initialize<F>(&self, value: Value, context: &mut Context, bind: &mut F) -> Result<()>
+    where
+        F: FnMut(&str, Value, &mut Context) -> Result<()>,
+    {
+        let value = match self.init() {
+            Some(init) if value.is_undefined() => self.binding.run_default(init, context)?,
+            _ => value,
+        };
+        self.binding.initialize(value, context, bind)
+    }
+}
+
+impl fmt::Display for BindingElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.binding, f)?;
+        if let Some(init) = self.init() {
+            write!(f, " = {}", init)?;
+        }
+        Ok(())
+    }
+}
+
+/// An array assignment pattern assigns the values produced by an iterator to assignment targets,
+/// like `[a, , b.c = 1, ...rest] = values`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayAssignmentPattern
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub struct ArrayAssignmentPattern {
+    elements: Box<[Option<AssignmentElement>]>,
+    rest: Option<Box<AssignmentTarget>>,
+}
+
+impl ArrayAssignmentPattern {
+    /// Creates a new array assignment pattern.
+    ///
+    /// A `None` element is an elision, which skips a value of the iterator.
+    pub fn new<E>(elements: E, rest: Option<AssignmentTarget>) -> Self
+    where
+        E: Into<Box<[Option<AssignmentElement>]>>,
+    {
+        Self {
+            elements: elements.into(),
+            rest: rest.map(Box::new),
+        }
+    }
+
+    /// Gets the elements of the pattern.
+    pub fn elements(&self) -> &[Option<AssignmentElement>] {
+        &self.elements
+    }
+
+    /// Gets the target that collects the remaining values, if any.
+    pub fn rest(&self) -> Option<&AssignmentTarget> {
+        self.rest.as_deref()
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-iteratordestructuringassignmentevaluation
+    pub(crate) fn assign(&self, value: Value, context: &mut Context) -> Result<()> {
+        let iterator = get_iterator(context, value)?;
+        let mut done = false;
+
+        for element in self.elements() {
+            // Errors of the iterator itself finish it, so it must not be closed.
+            let next = if done {
+                Value::undefined()
+            } else {
+                let next = iterator.next(context)?;
+                done = next.is_done();
+                if done {
+                    Value::undefined()
+                } else {
+                    next.value()
+                }
+            };
+
+            if let Some(element) = element {
+                if let Err(error) = element.assign(next, context) {
+                    if done {
+                        return Err(error);
+                    }
+                    return iterator.close(Err(error), context).map(|_| ());
+                }
+            }
+        }
+
+        if let Some(rest) = self.rest() {
+            let array = Array::new_array(context);
+            let mut values = Vec::new();
+            while !done {
+                let next = iterator.next(context)?;
+                done = next.is_done();
+                if !done {
+                    values.push(next.value());
+                }
+            }
+            Array::add_to_array_object(&array, &values, context)?;
+            return rest.assign(array, context);
+        }
+
+        if !done {
+            iterator.close(Ok(Value::undefined()), context)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ArrayAssignmentPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        let mut first = true;
+        for element in self.elements() {
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+            if let Some(element) = element {
+                fmt::Display::fmt(element, f)?;
+            }
+        }
+        if let Some(rest) = self.rest() {
+            if !first {
+                f.write_str(", ")?;
+            }
+            write!(f, "...{}", rest)?;
+        } else if matches!(self.elements().last(), Some(None)) {
+            // A trailing elision needs a trailing comma.
+            f.write_str(",")?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// An object assignment pattern assigns the properties of an object to assignment targets, like
+/// `{ a, b: c.d = 1, ...rest } = value`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectAssignmentPattern
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub struct ObjectAssignmentPattern {
+    properties: Box<[AssignmentProperty]>,
+    rest: Option<Box<AssignmentTarget>>,
+}
+
+impl ObjectAssignmentPattern {
+    /// Creates a new object assignment pattern.
+    pub fn new<P>(properties: P, rest: Option<AssignmentTarget>) -> Self
+    where
+        P: Into<Box<[AssignmentProperty]>>,
+    {
+        Self {
+            properties: properties.into(),
+            rest: rest.map(Box::new),
+        }
+    }
+
+    /// Gets the properties of the pattern.
+    pub fn properties(&self) -> &[AssignmentProperty] {
+        &self.properties
+    }
+
+    /// Gets the target that collects the remaining properties, if any.
+    pub fn rest(&self) -> Option<&AssignmentTarget> {
+        self.rest.as_deref()
+    }
+
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-destructuringassignmentevaluation
+    pub(crate) fn assign(&self, value: Value, context: &mut Context) -> Result<()> {
+        // 1. Perform ? RequireObjectCoercible(value).
+        if value.is_null_or_undefined() {
+            return Err(
+                context.construct_type_error(format!("cannot destructure '{}'", value.display()))
+            );
+        }
+        let object = value.to_object(context)?;
+
+        for property in self.properties() {
+            let key = PropertyKey::from(property.name());
+            let property_value = object.get(&key, value.clone(), context)?;
+            property.element().assign(property_value, context)?;
+        }
+
+        if let Some(rest) = self.rest() {
+            let excluded = self.properties().iter().map(AssignmentProperty::name);
+            let rest_object = copy_rest_properties(&object, &value, excluded, context)?;
+            rest.assign(rest_object, context)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for ObjectAssignmentPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        let mut first = true;
+        for property in self.properties() {
+            f.write_str(if first { " " } else { ", " })?;
+            first = false;
+            fmt::Display::fmt(property, f)?;
+        }
+        if let Some(rest) = self.rest() {
+            f.write_str(if first { " " } else { ", " })?;
+            first = false;
+            write!(f, "...{}", rest)?;
+        }
+        f.write_str(if first { "}" } else { " }" })
+    }
+}
+
+/// A property of an object assignment pattern, like `a`, `a = 1` or `b: c.d = 1`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AssignmentProperty
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub struct AssignmentProperty {
+    name: Box<str>,
+    element: AssignmentElement,
+}
+
+impl AssignmentProperty {
+    /// Creates a new assignment property, that assigns the property `name` to `element`.
+    pub fn new<N>(name: N, element: AssignmentElement) -> Self
+    where
+        N: Into<Box<str>>,
+    {
+        Self {
+            name: name.into(),
+            element,
+        }
+    }
+
+    /// Gets the name of the destructured property.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the assignment element the property value is assigned to.
+    pub fn element(&self) -> &AssignmentElement {
+        &self.element
+    }
+}
+
+impl fmt::Display for AssignmentProperty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.element.target() {
+            AssignmentTarget::Expression(Node::Identifier(name))
+                if name.as_ref() == self.name() => {}
+            _ => write!(f, "{}: ", self.name)?,
+        }
+        fmt::Display::fmt(&self.element, f)
+    }
+}
+
+/// An element of an array assignment pattern, which is an assignment target with an optional
+/// default value initializer.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-AssignmentElement
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub struct AssignmentElement {
+    target: AssignmentTarget,
+    init: Option<Node>,
+}
+
+impl AssignmentElement {
+    /// Creates a new assignment element.
+    pub fn new<T>(target: T, init: Option<Node>) -> Self
+    where
+        T: Into<AssignmentTarget>,
+    {
+        Self {
+            target: target.into(),
+            init,
+        }
+    }
+
+    /// Gets the target of the element.
+    pub fn target(&self) -> &AssignmentTarget {
+        &self.target
+    }
+
+    /// Gets the default value initializer of the element, if any.
+    pub fn init(&self) -> Option<&Node> {
+        self.init.as_ref()
+    }
+
+    /// Assigns `value`, or the default value if `value` is `undefined`.
+    fn assign(&self, value: Value, context: &mut Context) -> Result<()> {
+        let value = match (self.init(), &self.target) {
+            (Some(init), AssignmentTarget::Expression(Node::Identifier(name)))
+                if value.is_undefined() =>
+            {
+                init.run_named(name.as_ref(), context)?
+            }
+            (Some(init), _) if value.is_undefined() => init.run(context)?,
+            _ => value,
+        };
+        self.target.assign(value, context)
+    }
+}
+
+impl fmt::Display for AssignmentElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.target, f)?;
+        if let Some(init) = self.init() {
+            write!(f, " = {}", init)?;
+        }
+        Ok(())
+    }
+}
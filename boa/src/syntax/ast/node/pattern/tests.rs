@@ -0,0 +1,372 @@
+use crate::exec;
+
+#[test]
+fn default_parameters() {
+    let scenario = r#"
+        function f(a, b = a + 1, c = b * 2) {
+            return [a, b, c].join();
+        }
+        [f(1), f(1, 5), f(1, undefined, 3)].join(" ");
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,2,4 1,5,10 1,2,3""#);
+}
+
+#[test]
+fn default_parameter_is_evaluated_lazily() {
+    let scenario = r#"
+        let calls = 0;
+        function f(a = ++calls) {
+            return a;
+        }
+        f(10);
+        f();
+        f();
+        calls;
+    "#;
+
+    assert_eq!(&exec(scenario), "2");
+}
+
+#[test]
+fn default_parameter_tdz() {
+    let scenario = r#"
+        function f(a = b, b) {
+            return a;
+        }
+        try {
+            f();
+        } catch (e) {
+            e instanceof ReferenceError;
+        }
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn default_parameter_error_is_thrown() {
+    let scenario = r#"
+        function f(a = (function () { throw "init"; })()) {}
+        try {
+            f();
+        } catch (e) {
+            e;
+        }
+    "#;
+
+    assert_eq!(&exec(scenario), r#""init""#);
+}
+
+#[test]
+fn rest_parameter() {
+    let scenario = r#"
+        function f(a, ...rest) {
+            return [a, rest.length, rest.join("-"), Array.isArray(rest)].join();
+        }
+        [f(1), f(1, 2, 3)].join(" ");
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,0,,true 1,2,2-3,true""#);
+}
+
+#[test]
+fn object_pattern_parameter() {
+    let scenario = r#"
+        function f({ a, b: c, d = 4, e: { f } = { f: 6 } }) {
+            return [a, c, d, f].join();
+        }
+        [f({ a: 1, b: 2 }), f({ a: 1, b: 2, d: 3, e: { f: 5 } })].join(" ");
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,2,4,6 1,2,3,5""#);
+}
+
+#[test]
+fn object_pattern_rest_property() {
+    let scenario = r#"
+        function f({ a, ...others }) {
+            return [a, "a" in others, others.b, others.c].join();
+        }
+        f({ a: 1, b: 2, c: 3 });
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,false,2,3""#);
+}
+
+#[test]
+fn object_pattern_of_primitive() {
+    let scenario = r#"
+        function f({ length }) {
+            return length;
+        }
+        f("abc");
+    "#;
+
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn object_pattern_of_undefined() {
+    let scenario = r#"
+        function f({ a }) {
+            return a;
+        }
+        try {
+            f();
+        } catch (e) {
+            e instanceof TypeError;
+        }
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn array_pattern_parameter() {
+    let scenario = r#"
+        function f([a, , b = 5, ...rest]) {
+            return [a, b, rest.length].join();
+        }
+        [f([1, 2]), f([1, 2, 3, 4, 5])].join(" ");
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,5,0 1,3,2""#);
+}
+
+#[test]
+fn array_pattern_closes_iterator() {
+    let scenario = r#"
+        let closed = false;
+        let iterable = {};
+        iterable[Symbol.iterator] = function () {
+            return {
+                next: function () {
+                    return { value: 1, done: false };
+                },
+                return: function () {
+                    closed = true;
+                    return {};
+                }
+            };
+        };
+        function f([a, b]) {
+            return a + b;
+        }
+        f(iterable) + " " + closed;
+    "#;
+
+    assert_eq!(&exec(scenario), r#""2 true""#);
+}
+
+#[test]
+fn rest_parameter_pattern() {
+    let scenario = r#"
+        function f(a, ...[b, c]) {
+            return [a, b, c].join();
+        }
+        f(1, 2, 3, 4);
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,2,3""#);
+}
+
+#[test]
+fn pattern_default_references_earlier_binding() {
+    let scenario = r#"
+        function f({ a, b = a * 2 }, [c = b + 1]) {
+            return [a, b, c].join();
+        }
+        f({ a: 1 }, []);
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,2,3""#);
+}
+
+#[test]
+fn pattern_default_tdz() {
+    let scenario = r#"
+        function f({ a = b }, b) {
+            return a;
+        }
+        try {
+            f({}, 1);
+        } catch (e) {
+            e instanceof ReferenceError;
+        }
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn pattern_parameters_arguments() {
+    let scenario = r#"
+        function f({ a }, [b], ...rest) {
+            return [arguments.length, arguments[0].a, arguments[1][0]].join();
+        }
+        f({ a: 1 }, [2], 3);
+    "#;
+
+    assert_eq!(&exec(scenario), r#""3,1,2""#);
+}
+
+#[test]
+fn pattern_binding_arguments() {
+    let scenario = r#"
+        function f({ arguments }) {
+            return arguments;
+        }
+        f({ arguments: 42 });
+    "#;
+
+    assert_eq!(&exec(scenario), "42");
+}
+
+#[test]
+fn arrow_function_pattern_parameters() {
+    let scenario = r#"
+        let f = (a, { b }, [c], ...[d]) => a + b + c + d;
+        f(1, { b: 2 }, [3], 4);
+    "#;
+
+    assert_eq!(&exec(scenario), "10");
+}
+
+#[test]
+fn array_assignment_swap() {
+    let scenario = r#"
+        let a = 1, b = 2;
+        [a, b] = [b, a];
+        [a, b].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""2,1""#);
+}
+
+#[test]
+fn array_assignment_defaults() {
+    let scenario = r#"
+        let calls = 0;
+        let a, b, c, d;
+        [a = ++calls, b = ++calls, c = ++calls, d = ++calls] = [undefined, null, 0];
+        [a, b === null, c, d, calls].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,true,0,2,2""#);
+}
+
+#[test]
+fn array_assignment_holes_and_rest() {
+    let scenario = r#"
+        let a, b, rest;
+        [a, , b, ...rest] = [1, 2, 3, 4, 5];
+        let empty;
+        [, , ...empty] = [1];
+        [a, b, rest.join("-"), empty.length].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,3,4-5,0""#);
+}
+
+#[test]
+fn array_assignment_nested_targets() {
+    let scenario = r#"
+        let object = {}, a, b, c;
+        [object.first, [a, [b] = [2]], ...[c, object["last"]]] = ["x", [1], "y", "z"];
+        [object.first, a, b, c, object.last].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""x,1,2,y,z""#);
+}
+
+#[test]
+fn array_assignment_uses_iterator() {
+    let scenario = r#"
+        let closed = 0;
+        let iterable = {
+            [Symbol.iterator]() {
+                let i = 0;
+                return {
+                    next() { i++; return { value: i, done: i > 3 }; },
+                    return() { closed++; return {}; },
+                };
+            },
+        };
+        let a, b, rest;
+        [a, b] = iterable;
+        [...rest] = iterable;
+        let result = ([a] = iterable);
+        [a, b, rest.join("-"), closed, result === iterable].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,2,1-2-3,2,true""#);
+}
+
+#[test]
+fn array_assignment_of_non_iterable() {
+    let scenario = r#"
+        let a;
+        try {
+            [a] = 1;
+        } catch (e) {
+            e instanceof TypeError;
+        }
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn object_assignment() {
+    let scenario = r#"
+        let a, b, c, rest;
+        let result = ({ a, b: c, x: b = 2, ...rest } = { a: 1, b: 3, y: 4, z: 5 });
+        [a, b, c, Object.keys(rest).join("-"), result.y].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,2,3,y-z,4""#);
+}
+
+#[test]
+fn object_assignment_nested_in_array() {
+    let scenario = r#"
+        let object = {}, x, y, z, w;
+        [{ c: y }] = [{ c: 1 }];
+        [{ a: [x, { b: object.b = 2 }] }, [{ d: z }, ...[{ e: w }]]] = [{ a: [3, {}] }, [{ d: 4 }, { e: 5 }]];
+        ({ f: [{ g: object["g"] }] } = { f: [{ g: 6 }] });
+        [y, x, object.b, z, w, object.g].join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1,3,2,4,5,6""#);
+}
+
+#[test]
+fn object_assignment_of_nullish() {
+    let scenario = r#"
+        let a;
+        let errors = [];
+        try { [{ a }] = [null]; } catch (e) { errors.push(e instanceof TypeError); }
+        try { ({ a } = undefined); } catch (e) { errors.push(e instanceof TypeError); }
+        errors.join();
+    "#;
+
+    assert_eq!(&exec(scenario), r#""true,true""#);
+}
+
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        function f(a, { b, c: d = 1, ...e }, [, f, [g] = [], ...h], ...{ length }) {
+            return a;
+        };
+        function g([a], [b, ,], {}) {
+            return a;
+        };
+        [a, , b.c = 1, [d] = [], ...e] = f;
+        [, ,] = g;
+        [{ a, b: c.d = 1, ...e }, [{}]] = f;
+        "#,
+    );
+}
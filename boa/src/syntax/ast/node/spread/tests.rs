@@ -30,6 +30,133 @@ fn spread_with_call() {
     assert_eq!(&exec(scenario), r#""message""#);
 }
 
+#[test]
+fn spread_in_array_literal() {
+    let scenario = r#"
+    let a = [1, 2];
+    let b = [...a, 3, ...new Set([4, 5]), ...[]];
+    b.join();
+    "#;
+    assert_eq!(&exec(scenario), r#""1,2,3,4,5""#);
+}
+
+#[test]
+fn spread_string_splits_code_points() {
+    let scenario = r#"
+    let chars = [..."a😀b"];
+    chars.length + " " + (chars[1] === "😀");
+    "#;
+    assert_eq!(&exec(scenario), r#""3 true""#);
+}
+
+#[test]
+fn spread_arguments_after_spread() {
+    let scenario = r#"
+    function f() {
+        return Array.prototype.join.call(arguments);
+    }
+    function F(a, b, c) {
+        this.sum = a + b + c;
+    }
+    f(...[1, 2], 3, ...[4]) + " " + new F(...[1], 2, ...[3]).sum;
+    "#;
+    assert_eq!(&exec(scenario), r#""1,2,3,4 6""#);
+}
+
+#[test]
+fn spread_arguments_exhaust_iterators_without_closing() {
+    let scenario = r#"
+    let log = [];
+    function iterable(values, throwAt) {
+        return {
+            [Symbol.iterator]() {
+                let index = 0;
+                return {
+                    next() {
+                        if (index === throwAt) {
+                            throw "next";
+                        }
+                        log.push("next");
+                        return index < values.length
+                            ? { value: values[index++], done: false }
+                            : { value: undefined, done: true };
+                    },
+                    return() {
+                        log.push("return");
+                        return {};
+                    },
+                };
+            },
+        };
+    }
+    function f() {}
+    function thrower() {
+        throw "argument";
+    }
+    let errors = [];
+    try {
+        f(...iterable([1, 2]), thrower());
+    } catch (e) {
+        errors.push(e);
+    }
+    try {
+        f(...iterable([1, 2], 1));
+    } catch (e) {
+        errors.push(e);
+    }
+    errors.join() + " " + log.join();
+    "#;
+    assert_eq!(&exec(scenario), r#""argument,next next,next,next,next""#);
+}
+
+#[test]
+fn spread_non_iterable_throws() {
+    let scenario = r#"
+    function f() {}
+    let errors = [null, undefined, 1, {}].map(function (value) {
+        let results = [];
+        try {
+            [...value];
+        } catch (e) {
+            results.push(e instanceof TypeError);
+        }
+        try {
+            f(...value);
+        } catch (e) {
+            results.push(e instanceof TypeError);
+        }
+        return results.join();
+    });
+    errors.join(" ");
+    "#;
+    assert_eq!(
+        &exec(scenario),
+        r#""true,true true,true true,true true,true""#
+    );
+}
+
+#[test]
+fn spread_in_object_literal() {
+    let scenario = r#"
+    let source = { a: 1, b: 2 };
+    Object.defineProperty(source, "hidden", { value: 3, enumerable: false });
+    let symbol = Symbol();
+    source[symbol] = 4;
+    let copy = { b: 0, ...source, c: 5 };
+    [copy.a, copy.b, copy.c, "hidden" in copy, copy[symbol], copy === source].join();
+    "#;
+    assert_eq!(&exec(scenario), r#""1,2,5,false,4,false""#);
+}
+
+#[test]
+fn spread_in_object_literal_primitives() {
+    let scenario = r#"
+    let copy = { ...null, ...undefined, ...1, ..."hi" };
+    JSON.stringify(copy);
+    "#;
+    assert_eq!(&exec(scenario), r#""{"0":"h","1":"i"}""#);
+}
+
 #[test]
 fn fmt() {
     super::super::test_formatting(
@@ -42,6 +169,10 @@ fn fmt() {
         };
         let a = g("message");
         a;
+        let b = [...a, 1];
+        let c = {
+            ...b,
+        };
         "#,
     );
 }
@@ -1,9 +1,18 @@
 //! Statement list node.
 
 use crate::{
+    environment::lexical_environment::VariableScope,
     exec::{Executable, InterpreterState},
     gc::{empty_trace, Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::{
+        node::{
+            ArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, AsyncGeneratorDecl,
+            AsyncGeneratorExpr, Declaration, DeclarationList, FunctionDecl, FunctionExpr,
+            GeneratorDecl, GeneratorExpr, Node,
+        },
+        visit::{walk_declaration_list, Visitor},
+        Position,
+    },
     BoaProfiler, Context, Result, Value,
 };
 use std::{collections::HashSet, fmt, ops::Deref, rc::Rc};
@@ -23,18 +32,104 @@ use crate::vm::{compilation::CodeGen, Compiler};
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-StatementList
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct StatementList {
     #[cfg_attr(feature = "deser", serde(flatten))]
     items: Box<[Node]>,
+    /// The positions where the items start in the source code, if they are known.
+    #[cfg_attr(feature = "deser", serde(skip))]
+    #[unsafe_ignore_trace]
+    positions: Box<[Position]>,
+    /// Whether this is the body of a script or function that is strict mode code.
+    #[cfg_attr(feature = "deser", serde(skip))]
+    strict: bool,
 }
 
 impl StatementList {
+    /// Creates a new `StatementList` from its items and the positions where they start in the
+    /// source code.
+    pub fn with_positions<T, P>(items: T, positions: P) -> Self
+    where
+        T: Into<Box<[Node]>>,
+        P: Into<Box<[Position]>>,
+    {
+        let items = items.into();
+        let positions = positions.into();
+        debug_assert_eq!(items.len(), positions.len());
+        Self {
+            items,
+            positions,
+            strict: false,
+        }
+    }
+
+    /// Gets the position where the item at `index` starts in the source code, if it is known.
+    pub fn position(&self, index: usize) -> Option<Position> {
+        self.positions.get(index).copied()
+    }
+
     /// Gets the list of items.
     pub fn items(&self) -> &[Node] {
         &self.items
     }
 
+    /// Whether this is the body of a script or function that is strict mode code.
+    pub fn strict(&self) -> bool {
+        self.strict
+    }
+
+    /// Sets whether this is the body of strict mode code.
+    pub(in crate::syntax) fn set_strict(&mut self, strict: bool) {
+        self.strict = strict;
+    }
+
+    /// Returns this list, marked as the body of strict mode code if `strict` is `true`.
+    pub(in crate::syntax) fn with_strict(mut self, strict: bool) -> Self {
+        self.set_strict(strict);
+        self
+    }
+
+    /// Runs this list as the body of a script or function, in strict mode if it is strict mode
+    /// code.
+    pub(crate) fn run_body(&self, context: &mut Context) -> Result<Value> {
+        self.hoist_var_declarations(context)?;
+        self.resume_body(context)
+    }
+
+    /// Runs this list as the body of a generator that is resumed, without hoisting its `var`
+    /// declarations again.
+    pub(crate) fn resume_body(&self, context: &mut Context) -> Result<Value> {
+        let outer_strict = context.executor().set_strict(self.strict);
+        let result = self.run(context);
+        context.executor().set_strict(outer_strict);
+        result
+    }
+
+    /// Creates a binding initialized to `undefined` in the current environment for each name
+    /// declared by a `var` anywhere in this list, outside of nested functions.
+    ///
+    /// Names that are already bound in the current environment, like parameters or the
+    /// properties of the global object, keep their value.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-functiondeclarationinstantiation
+    fn hoist_var_declarations(&self, context: &mut Context) -> Result<()> {
+        let mut names = VarScopedNames::default();
+        names.visit_statement_list(self);
+
+        let environment = context.get_current_environment();
+        for name in names.0 {
+            if environment.has_binding(&name) {
+                continue;
+            }
+            context.create_mutable_binding(name.clone(), false, VariableScope::Function)?;
+            context.initialize_binding(&name, Value::undefined())?;
+        }
+        Ok(())
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -47,7 +142,11 @@ impl StatementList {
             node.display(f, indentation)?;
 
             match node {
-                Node::Block(_) | Node::If(_) | Node::Switch(_) | Node::WhileLoop(_) => {}
+                Node::Block(_)
+                | Node::If(_)
+                | Node::Switch(_)
+                | Node::WhileLoop(_)
+                | Node::With(_) => {}
                 _ => write!(f, ";")?,
             }
             writeln!(f)?;
@@ -58,13 +157,18 @@ impl StatementList {
     pub fn lexically_declared_names(&self) -> HashSet<&str> {
         let mut set = HashSet::new();
         for stmt in self.items() {
-            if let Node::LetDeclList(decl_list) | Node::ConstDeclList(decl_list) = stmt {
-                for decl in decl_list.as_ref() {
-                    if !set.insert(decl.name()) {
-                        // It is a Syntax Error if the LexicallyDeclaredNames of StatementList contains any duplicate entries.
-                        // https://tc39.es/ecma262/#sec-block-static-semantics-early-errors
-                        unreachable!("Redeclaration of {}", decl.name());
-                    }
+            let names: Vec<&str> = match stmt {
+                Node::LetDeclList(decl_list) | Node::ConstDeclList(decl_list) => {
+                    decl_list.as_ref().iter().map(|decl| decl.name()).collect()
+                }
+                Node::ClassDecl(decl) => vec![decl.name()],
+                _ => continue,
+            };
+            for name in names {
+                if !set.insert(name) {
+                    // It is a Syntax Error if the LexicallyDeclaredNames of StatementList contains any duplicate entries.
+                    // https://tc39.es/ecma262/#sec-block-static-semantics-early-errors
+                    unreachable!("Redeclaration of {}", name);
                 }
             }
         }
@@ -74,8 +178,17 @@ impl StatementList {
     pub fn function_declared_names(&self) -> HashSet<&str> {
         let mut set = HashSet::new();
         for stmt in self.items() {
-            if let Node::FunctionDecl(decl) = stmt {
-                set.insert(decl.name());
+            match stmt {
+                Node::FunctionDecl(decl) => {
+                    set.insert(decl.name());
+                }
+                Node::GeneratorDecl(decl) => {
+                    set.insert(decl.name());
+                }
+                Node::AsyncGeneratorDecl(decl) => {
+                    set.insert(decl.name());
+                }
+                _ => {}
             }
         }
         set
@@ -94,6 +207,34 @@ impl StatementList {
     }
 }
 
+/// Collects the names declared by `var` in a statement list, including the ones in nested blocks
+/// and loops, but not the ones in nested functions, which have their own scope.
+#[derive(Default)]
+struct VarScopedNames(Vec<String>);
+
+impl Visitor for VarScopedNames {
+    fn visit_declaration_list(&mut self, node: &DeclarationList) {
+        if let DeclarationList::Var(declarations) = node {
+            for name in declarations.iter().map(Declaration::name) {
+                if !self.0.iter().any(|n| n == name) {
+                    self.0.push(name.to_owned());
+                }
+            }
+        }
+        walk_declaration_list(self, node)
+    }
+
+    fn visit_arrow_function_decl(&mut self, _node: &ArrowFunctionDecl) {}
+    fn visit_async_function_decl(&mut self, _node: &AsyncFunctionDecl) {}
+    fn visit_async_function_expr(&mut self, _node: &AsyncFunctionExpr) {}
+    fn visit_async_generator_decl(&mut self, _node: &AsyncGeneratorDecl) {}
+    fn visit_async_generator_expr(&mut self, _node: &AsyncGeneratorExpr) {}
+    fn visit_function_decl(&mut self, _node: &FunctionDecl) {}
+    fn visit_function_expr(&mut self, _node: &FunctionExpr) {}
+    fn visit_generator_decl(&mut self, _node: &GeneratorDecl) {}
+    fn visit_generator_expr(&mut self, _node: &GeneratorExpr) {}
+}
+
 impl Executable for StatementList {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("StatementList", "exec");
@@ -105,6 +246,9 @@ impl Executable for StatementList {
             .executor()
             .set_current_state(InterpreterState::Executing);
         for (i, item) in self.items().iter().enumerate() {
+            if let Some(position) = self.position(i) {
+                context.executor().set_current_position(position);
+            }
             let val = item.run(context)?;
             match context.executor().get_current_state() {
                 InterpreterState::Return => {
@@ -150,7 +294,18 @@ where
     T: Into<Box<[Node]>>,
 {
     fn from(stm: T) -> Self {
-        Self { items: stm.into() }
+        Self {
+            items: stm.into(),
+            positions: Box::default(),
+            strict: false,
+        }
+    }
+}
+
+// The positions of the items and the strictness are not part of the syntax tree.
+impl PartialEq for StatementList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
     }
 }
 
@@ -180,7 +335,8 @@ impl From<StatementList> for RcStatementList {
     }
 }
 
-// SAFETY: This is safe for types not containing any `Trace` types.
+// SAFETY: This is safe since the objects held by the syntax tree, like the template objects of
+// tagged templates, are kept rooted instead of being traced.
 unsafe impl Trace for RcStatementList {
     empty_trace!();
 }
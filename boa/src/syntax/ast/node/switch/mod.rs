@@ -1,10 +1,12 @@
 //! Switch node.
 //!
 use crate::{
+    builtins::generator::ResumeState,
+    environment::declarative_environment_record::DeclarativeEnvironmentRecord,
     exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
     syntax::ast::node::Node,
-    Context, Result, Value,
+    BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
 
@@ -55,7 +57,8 @@ impl Case {
 /// clause whose expression evaluates to the same value as the result of the input expression
 /// (using the strict comparison, `===`) and transfers control to that clause, executing the
 /// associated statements. (If multiple cases match the provided value, the first case that
-/// matches is selected, even if the cases are not equal to each other.)
+/// matches is selected, even if the cases are not equal to each other.) If no case clause
+/// matches, the `default` clause is executed, followed by the case clauses that come after it.
 ///
 /// More information:
 ///  - [ECMAScript reference][spec]
@@ -69,20 +72,45 @@ pub struct Switch {
     val: Box<Node>,
     cases: Box<[Case]>,
     default: Option<StatementList>,
+    default_position: usize,
 }
 
 impl Switch {
-    /// Creates a `Switch` AST node.
+    /// Creates a `Switch` AST node, with the default clause, if any, after all the cases.
     pub fn new<V, C, D>(val: V, cases: C, default: Option<D>) -> Self
     where
         V: Into<Node>,
         C: Into<Box<[Case]>>,
         D: Into<StatementList>,
     {
+        let cases = cases.into();
+        let default_position = cases.len();
+        Self::with_default_position(val, cases, default, default_position)
+    }
+
+    /// Creates a `Switch` AST node whose default clause, if any, comes after the first
+    /// `default_position` cases.
+    pub fn with_default_position<V, C, D>(
+        val: V,
+        cases: C,
+        default: Option<D>,
+        default_position: usize,
+    ) -> Self
+    where
+        V: Into<Node>,
+        C: Into<Box<[Case]>>,
+        D: Into<StatementList>,
+    {
+        let cases = cases.into();
+        assert!(
+            default_position <= cases.len(),
+            "the default clause must be among the cases"
+        );
         Self {
             val: Box::new(val.into()),
-            cases: cases.into(),
+            cases,
             default: default.map(D::into),
+            default_position,
         }
     }
 
@@ -101,6 +129,11 @@ impl Switch {
         self.default.as_ref().map(StatementList::items)
     }
 
+    /// Gets the number of cases that come before the default clause.
+    pub fn default_position(&self) -> usize {
+        self.default_position
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
@@ -109,96 +142,146 @@ impl Switch {
     ) -> fmt::Result {
         let indent = "    ".repeat(indentation);
         writeln!(f, "switch ({}) {{", self.val())?;
-        for e in self.cases().iter() {
+        for (i, e) in self.cases().iter().enumerate() {
+            if i == self.default_position {
+                self.display_default(f, indentation)?;
+            }
             writeln!(f, "{}    case {}:", indent, e.condition())?;
             e.body().display(f, indentation + 2)?;
         }
+        if self.default_position == self.cases.len() {
+            self.display_default(f, indentation)?;
+        }
+        write!(f, "{}}}", indent)
+    }
 
+    /// Displays the default clause, if any.
+    fn display_default(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         if let Some(ref default) = self.default {
-            writeln!(f, "{}    default:", indent)?;
+            writeln!(f, "{}    default:", "    ".repeat(indentation))?;
             default.display(f, indentation + 2)?;
         }
-        write!(f, "{}}}", indent)
+        Ok(())
     }
-}
 
-impl Executable for Switch {
-    fn run(&self, context: &mut Context) -> Result<Value> {
-        let val = self.val().run(context)?;
-        let mut result = Value::null();
-        let mut matched = false;
-        context
-            .executor()
-            .set_current_state(InterpreterState::Executing);
+    /// Evaluates the case block in the current environment.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-caseblockevaluation
+    fn run_case_block(&self, input: &Value, context: &mut Context) -> Result<Value> {
+        let (before_default, after_default) = self.cases.split_at(self.default_position);
+        let mut completion = Value::undefined();
+
+        // A case clause matching the input starts the execution, which falls through the
+        // following clauses until it is interrupted by an abrupt completion.
+        let mut found = false;
+        for case in before_default {
+            if !found {
+                found = input.strict_equals(&case.condition().run(context)?);
+            }
+            if found && Self::run_clause(case.body(), &mut completion, context)? {
+                return Ok(completion);
+            }
+        }
 
-        // If a case block does not end with a break statement then subsequent cases will be run without
-        // checking their conditions until a break is encountered.
-        let mut fall_through: bool = false;
-
-        for case in self.cases().iter() {
-            let cond = case.condition();
-            let block = case.body();
-            if fall_through || val.strict_equals(&cond.run(context)?) {
-                matched = true;
-                let result = block.run(context)?;
-                match context.executor().get_current_state() {
-                    InterpreterState::Return => {
-                        // Early return.
-                        return Ok(result);
-                    }
-                    InterpreterState::Break(_label) => {
-                        // TODO, break to a label.
-                        // Break statement encountered so therefore end switch statement.
-                        context
-                            .executor()
-                            .set_current_state(InterpreterState::Executing);
-                        break;
-                    }
-                    InterpreterState::Continue(_label) => {
-                        // TODO, continue to a label.
-                        break;
-                    }
-                    InterpreterState::Executing => {
-                        // Continuing execution / falling through to next case statement(s).
-                        fall_through = true;
-                    }
-                    #[cfg(feature = "vm")]
-                    InterpreterState::Error => {}
+        let mut found_after_default = false;
+        if !found {
+            for case in after_default {
+                if !found_after_default {
+                    found_after_default = input.strict_equals(&case.condition().run(context)?);
+                }
+                if found_after_default && Self::run_clause(case.body(), &mut completion, context)? {
+                    return Ok(completion);
                 }
             }
         }
+        if found_after_default {
+            return Ok(completion);
+        }
+
+        if let Some(ref default) = self.default {
+            if Self::run_clause(default, &mut completion, context)? {
+                return Ok(completion);
+            }
+        }
+        // Another iteration of the cases after the default clause, without checking them.
+        for case in after_default {
+            if Self::run_clause(case.body(), &mut completion, context)? {
+                return Ok(completion);
+            }
+        }
 
-        if !matched {
-            if let Some(default) = self.default() {
+        Ok(completion)
+    }
+
+    /// Executes the body of a clause, updating the completion value of the switch statement.
+    ///
+    /// Returns `true` if the execution of the case block ends here.
+    fn run_clause(
+        body: &StatementList,
+        completion: &mut Value,
+        context: &mut Context,
+    ) -> Result<bool> {
+        let value = body.run(context)?;
+        match context.executor().get_current_state() {
+            InterpreterState::Break(None) => {
+                // A break without a label ends the switch statement.
                 context
                     .executor()
                     .set_current_state(InterpreterState::Executing);
-                for (i, item) in default.iter().enumerate() {
-                    let val = item.run(context)?;
-                    match context.executor().get_current_state() {
-                        InterpreterState::Return => {
-                            // Early return.
-                            result = val;
-                            break;
-                        }
-                        InterpreterState::Break(_label) => {
-                            // TODO, break to a label.
-
-                            // Early break.
-                            break;
-                        }
-                        _ => {
-                            // Continue execution
-                        }
-                    }
-                    if i == default.len() - 1 {
-                        result = val;
-                    }
+                Ok(true)
+            }
+            InterpreterState::Break(Some(_)) | InterpreterState::Continue(_) => {
+                // The break or continue targets an enclosing statement.
+                Ok(true)
+            }
+            InterpreterState::Return => {
+                *completion = value;
+                Ok(true)
+            }
+            InterpreterState::Executing => {
+                // Empty clauses keep the completion value of the previous ones.
+                if !body.items().is_empty() {
+                    *completion = value;
                 }
+                Ok(false)
             }
+            #[cfg(feature = "vm")]
+            InterpreterState::Error => Ok(false),
+        }
+    }
+}
+
+impl Executable for Switch {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Switch", "exec");
+        let input = self.val().run(context)?;
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        // All the clauses of the case block share a block scope.
+        if let Some(ResumeState::Environment(env)) = context.executor().take_resume_state(self) {
+            // The case block of a resumed generator is executed in the environment it was
+            // suspended in.
+            context.push_environment(env);
+        } else {
+            let env = context.get_current_environment();
+            context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+        }
+
+        let result = self.run_case_block(&input, context);
+
+        let env = context.pop_environment();
+        if let (Err(_), Some(env)) = (&result, env) {
+            context
+                .executor()
+                .save_resume_state(self, ResumeState::Environment(env));
         }
 
-        Ok(result)
+        result
     }
 }
 
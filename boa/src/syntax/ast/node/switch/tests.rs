@@ -207,6 +207,131 @@ fn bigger_switch_example() {
     }
 }
 
+#[test]
+fn fall_through_until_break() {
+    let scenario = r#"
+        let log = [];
+        switch (2) {
+            case 1:
+                log.push("one");
+            case 2:
+                log.push("two");
+            case 3:
+                log.push("three");
+                break;
+            case 4:
+                log.push("four");
+        }
+
+        log.join();
+    "#;
+    assert_eq!(&exec(scenario), "\"two,three\"");
+}
+
+#[test]
+fn discriminant_evaluated_once() {
+    let scenario = r#"
+        let calls = 0;
+        function value() {
+            calls++;
+            return "1";
+        }
+        let matched = "none";
+        switch (value()) {
+            case 1:
+                matched = "number";
+                break;
+            case "1":
+                matched = "string";
+                break;
+        }
+
+        [calls, matched].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"1,string\"");
+}
+
+#[test]
+fn default_in_middle_switch() {
+    let scenario = r#"
+        function run(value) {
+            let log = [];
+            switch (value) {
+                case 1:
+                    log.push("one");
+                default:
+                    log.push("default");
+                case 2:
+                    log.push("two");
+                    break;
+                case 3:
+                    log.push("three");
+            }
+            return log.join();
+        }
+
+        [run(1), run(2), run(3), run(4)].join(" ");
+    "#;
+    assert_eq!(&exec(scenario), "\"one,default,two two three default,two\"");
+}
+
+#[test]
+fn default_in_middle_checks_later_cases_first() {
+    let scenario = r#"
+        let log = [];
+        switch ("b") {
+            default:
+                log.push("default");
+            case "a":
+                log.push("a");
+                break;
+            case "b":
+                log.push("b");
+        }
+
+        log.join();
+    "#;
+    assert_eq!(&exec(scenario), "\"b\"");
+}
+
+#[test]
+fn switch_completion_value() {
+    assert_eq!(
+        &exec("switch (1) { case 1: 'one'; case 2: break; }"),
+        "\"one\""
+    );
+    assert_eq!(&exec("switch (1) { case 2: 'two'; }"), "undefined");
+    assert_eq!(
+        &exec("switch (3) { case 2: 'two'; default: 'default'; }"),
+        "\"default\""
+    );
+}
+
+#[test]
+fn switch_lexical_scope() {
+    let scenario = r#"
+        let x = "outer";
+        switch (1) {
+            case 1:
+                let x = "inner";
+        }
+
+        x;
+    "#;
+    assert_eq!(&exec(scenario), "\"outer\"");
+
+    let scenario = r#"
+        switch (1) {
+            case 1:
+                let y = 1;
+                break;
+        }
+
+        typeof y;
+    "#;
+    assert_eq!(&exec(scenario), "\"undefined\"");
+}
+
 #[test]
 fn fmt() {
     super::super::test_formatting(
@@ -242,3 +367,20 @@ fn fmt() {
         "#,
     );
 }
+
+#[test]
+fn fmt_default_in_middle() {
+    super::super::test_formatting(
+        r#"
+        switch (a) {
+            case 0:
+                b = 1;
+            default:
+                b = 2;
+            case 1:
+                b = 3;
+                break;
+        }
+        "#,
+    );
+}
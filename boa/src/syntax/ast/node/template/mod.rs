@@ -1,12 +1,22 @@
 //! Template literal node.
 
-use super::Node;
-use crate::{builtins::Array, exec::Executable, value::Type, BoaProfiler, Context, Result, Value};
+use super::{call::describe_callee, Node};
+use crate::{
+    builtins::Array,
+    exec::{Executable, InterpreterState},
+    object::GcObject,
+    property::Attribute,
+    value::Type,
+    BoaProfiler, Context, Result, Value,
+};
 use gc::{Finalize, Trace};
 
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{
+    fmt,
+    sync::atomic::{AtomicUsize, Ordering},
+};
 
 #[cfg(test)]
 mod tests;
@@ -29,6 +39,11 @@ impl TemplateLit {
     pub fn new(elements: Vec<TemplateElement>) -> Self {
         TemplateLit { elements }
     }
+
+    /// Gets the strings and the substituted expressions of the template, in source order.
+    pub fn elements(&self) -> &[TemplateElement] {
+        &self.elements
+    }
 }
 
 impl Executable for TemplateLit {
@@ -64,15 +79,31 @@ impl fmt::Display for TemplateLit {
         write!(f, "`")
     }
 }
+
+/// A tagged template is a function call with a template literal as argument.
+///
+/// Every evaluation of the same tagged template receives the same frozen template object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Template_literals#tagged_templates
+/// [spec]: https://tc39.es/ecma262/#sec-tagged-templates
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Clone, Debug, Trace, Finalize)]
 pub struct TaggedTemplate {
     tag: Box<Node>,
     raws: Vec<Box<str>>,
     cookeds: Vec<Option<Box<str>>>,
     exprs: Vec<Node>,
+    /// The identifier of this call site, under which the realm keeps its template object.
+    site: usize,
 }
 
+/// The identifier of the next tagged template call site to be parsed.
+static NEXT_SITE: AtomicUsize = AtomicUsize::new(0);
+
 impl TaggedTemplate {
     pub fn new(
         tag: Node,
@@ -85,29 +116,102 @@ impl TaggedTemplate {
             raws,
             cookeds,
             exprs,
+            site: NEXT_SITE.fetch_add(1, Ordering::Relaxed),
         }
     }
-}
 
-impl Executable for TaggedTemplate {
-    fn run(&self, context: &mut Context) -> Result<Value> {
-        let _timer = BoaProfiler::global().start_event("TaggedTemplate", "exec");
+    /// Gets the tag function expression.
+    pub fn tag(&self) -> &Node {
+        &self.tag
+    }
 
-        let template_object = Array::new_array(context);
-        let raw_array = Array::new_array(context);
+    /// Gets the substituted expressions of the template.
+    pub fn exprs(&self) -> &[Node] {
+        &self.exprs
+    }
 
-        for (i, raw) in self.raws.iter().enumerate() {
-            raw_array.set_field(i, Value::from(raw), false, context)?;
+    /// Gets the template object of this call site, creating it on the first evaluation.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-gettemplateobject
+    fn get_template_object(&self, context: &mut Context) -> GcObject {
+        if let Some(template_object) = context.realm.template_map.get(&self.site) {
+            return template_object.clone();
         }
 
-        for (i, cooked) in self.cookeds.iter().enumerate() {
-            if let Some(cooked) = cooked {
-                template_object.set_field(i, Value::from(cooked), false, context)?;
-            } else {
-                template_object.set_field(i, Value::undefined(), false, context)?;
-            }
+        let mut raw_object = Self::create_frozen_array(
+            self.raws.iter().map(|raw| Value::from(raw.as_ref())),
+            context,
+        );
+        raw_object.prevent_extensions();
+
+        let mut template_object = Self::create_frozen_array(
+            self.cookeds
+                .iter()
+                .map(|cooked| cooked.as_deref().map_or(Value::undefined(), Value::from)),
+            context,
+        );
+        template_object.insert_property(
+            "raw",
+            raw_object,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+        template_object.prevent_extensions();
+
+        context
+            .realm
+            .template_map
+            .insert(self.site, template_object.clone());
+        template_object
+    }
+
+    /// Creates an array with the given elements, whose properties can't be changed.
+    ///
+    /// The array is left extensible, so that the caller can add more properties to it.
+    fn create_frozen_array<I>(elements: I, context: &mut Context) -> GcObject
+    where
+        I: IntoIterator<Item = Value>,
+    {
+        let mut array = Array::new_array(context)
+            .as_object()
+            .expect("new_array must create an object");
+
+        let mut length = 0;
+        for (index, element) in elements.into_iter().enumerate() {
+            array.insert_property(
+                index,
+                element,
+                Attribute::READONLY | Attribute::ENUMERABLE | Attribute::PERMANENT,
+            );
+            length = index + 1;
         }
-        template_object.set_field("raw", raw_array, false, context)?;
+        array.insert_property(
+            "length",
+            length,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
+        );
+
+        array
+    }
+}
+
+impl PartialEq for TaggedTemplate {
+    fn eq(&self, other: &Self) -> bool {
+        // The call site identifier is not part of the syntax.
+        self.tag == other.tag
+            && self.raws == other.raws
+            && self.cookeds == other.cookeds
+            && self.exprs == other.exprs
+    }
+}
+
+impl Executable for TaggedTemplate {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("TaggedTemplate", "exec");
+
+        let template_object = self.get_template_object(context);
 
         let (this, func) = match *self.tag {
             Node::GetConstField(ref get_const_field) => {
@@ -128,15 +232,27 @@ impl Executable for TaggedTemplate {
                     obj.get_field(field.to_property_key(context)?, context)?,
                 )
             }
-            _ => (context.global_object().into(), self.tag.run(context)?),
+            _ => (Value::undefined(), self.tag.run(context)?),
         };
 
-        let mut args = vec![template_object];
+        let mut args = vec![template_object.into()];
         for expr in self.exprs.iter() {
             args.push(expr.run(context)?);
         }
 
-        context.call(&func, &this, &args)
+        if !func.is_callable() {
+            return context
+                .throw_type_error(format!("{} is not a function", describe_callee(&self.tag)));
+        }
+
+        let result = context.call(&func, &this, &args);
+
+        // unset the early return flag
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+
+        result
     }
 }
 
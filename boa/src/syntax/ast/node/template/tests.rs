@@ -1,4 +1,4 @@
-use crate::exec;
+use crate::{exec, forward, Context};
 
 #[test]
 fn template_literal() {
@@ -46,3 +46,127 @@ fn fmt() {
         "#,
     );
 }
+
+#[test]
+fn tagged_template_object_is_cached_per_call_site() {
+    let scenario = r#"
+        function tag(t) { return t; }
+        function get() { return tag`a${1}b`; }
+        let first = get();
+        let second = get();
+        let other = tag`a${1}b`;
+        [first === second, first === other, first.raw === second.raw];
+        "#;
+
+    assert_eq!(&exec(scenario), "[ true, false, true ]");
+}
+
+#[test]
+fn tagged_template_object_is_owned_by_the_script() {
+    // Each evaluated script has its own call sites, even when the source code is the same.
+    let mut context = Context::new();
+    forward(&mut context, "function tag(t) { return t; }");
+    forward(&mut context, "var first = tag`a`;");
+    forward(&mut context, "var second = tag`a`;");
+    assert_eq!(forward(&mut context, "first === second"), "false");
+    assert_eq!(
+        forward(&mut context, "first.raw[0] === second.raw[0]"),
+        "true"
+    );
+}
+
+#[test]
+fn tagged_template_object_is_cached_per_realm() {
+    let mut context = Context::new();
+    let script = context
+        .compile("var objects = (typeof objects === 'undefined' ? [] : objects).concat([((t) => t)`a`]); objects")
+        .unwrap();
+    let mut other = Context::new();
+
+    context.execute(&script).unwrap();
+    other.execute(&script).unwrap();
+    context.execute(&script).unwrap();
+    other.execute(&script).unwrap();
+
+    assert_eq!(forward(&mut context, "objects[0] === objects[1]"), "true");
+    assert_eq!(forward(&mut other, "objects[0] === objects[1]"), "true");
+    assert_eq!(
+        forward(&mut context, "objects[0].raw === objects[1].raw"),
+        "true"
+    );
+}
+
+#[test]
+fn tagged_template_object_is_frozen() {
+    let scenario = r#"
+        function tag(t) { return t; }
+        let t = tag`a${1}b`;
+        t[0] = "changed";
+        t.raw[0] = "changed";
+        t.extra = 1;
+        t.raw.extra = 1;
+        t.length = 0;
+        [t[0], t.raw[0], t.extra, t.raw.extra, t.length, t.propertyIsEnumerable("raw")];
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        r#"[ "a", "a", undefined, undefined, 2, false ]"#
+    );
+}
+
+#[test]
+fn template_literal_expression() {
+    assert_eq!(&exec("`${1+1}`"), "\"2\"");
+    assert_eq!(
+        &exec("`${null} ${undefined} ${true} ${[1, 2]} ${-0}`"),
+        "\"null undefined true 1,2 0\""
+    );
+}
+
+#[test]
+fn nested_template_literal() {
+    let scenario = r#"
+        let items = ["a", "b"];
+        `list: ${items.map((item, i) => `${i}=${`<${item}>`}`).join(", ")}!`;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"list: 0=<a>, 1=<b>!\"");
+}
+
+#[test]
+fn template_literal_object_coercion() {
+    let scenario = r#"
+        let calls = [];
+        let obj = {
+            toString() { calls.push("toString"); return "str"; },
+            valueOf() { calls.push("valueOf"); return 42; },
+        };
+        let result = `${obj}|${obj + ""}`;
+        result + " " + calls.join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"str|42 toString,valueOf\"");
+}
+
+#[test]
+fn template_literal_evaluation_order() {
+    let scenario = r#"
+        let order = [];
+        function step(n) { order.push(n); return n; }
+        `${step(1)}${step(2)}${step(3)}`;
+        order.join();
+        "#;
+
+    assert_eq!(&exec(scenario), "\"1,2,3\"");
+
+    let scenario = r#"
+        try {
+            `${Symbol("s")}`;
+        } catch (e) {
+            e instanceof TypeError
+        }
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
@@ -1,11 +1,12 @@
 use crate::{
+    builtins::generator::ResumeState,
     environment::{
         declarative_environment_record::DeclarativeEnvironmentRecord,
         lexical_environment::VariableScope,
     },
-    exec::Executable,
+    exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
-    syntax::ast::node::{Block, Identifier, Node},
+    syntax::ast::node::{Binding, Block, Node},
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -97,41 +98,126 @@ impl Try {
 impl Executable for Try {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Try", "exec");
-        let res = self.block().run(context).map_or_else(
-            |err| {
-                if let Some(catch) = self.catch() {
-                    {
-                        let env = context.get_current_environment();
-                        context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
-
-                        if let Some(param) = catch.parameter() {
-                            context.create_mutable_binding(
-                                param.to_owned(),
-                                false,
-                                VariableScope::Block,
-                            )?;
-                            context.initialize_binding(param, err)?;
+        let res = match context.executor().take_resume_state(self) {
+            Some(ResumeState::FinallyBlock {
+                completion,
+                returning,
+            }) => return self.run_finally(completion, returning, context),
+            Some(ResumeState::CatchBlock(env)) => {
+                context.push_environment(env);
+                self.run_catch_block(context)
+            }
+            _ => match (self.block().run(context), self.catch()) {
+                // The body of a generator being unwound can't be caught.
+                (Err(err), Some(catch)) if !context.executor().is_unwinding_generator() => {
+                    let env = context.get_current_environment();
+                    context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+
+                    // The parameter is bound in the environment of the catch block, so a
+                    // destructured parameter isn't visible after the block either.
+                    let bound = match catch.parameter() {
+                        Some(param) => {
+                            param.initialize(err, context, &mut |name, value, context| {
+                                context.create_mutable_binding(
+                                    name.to_owned(),
+                                    false,
+                                    VariableScope::Block,
+                                )?;
+                                context.initialize_binding(name, value)
+                            })
+                        }
+                        None => Ok(()),
+                    };
+
+                    match bound {
+                        Ok(()) => self.run_catch_block(context),
+                        Err(err) => {
+                            context.pop_environment();
+                            Err(err)
                         }
                     }
+                }
+                (res, _) => res,
+            },
+        };
+
+        // A suspended generator executes the `finally` block once it's resumed and completes the
+        // statement.
+        if self.finally().is_none() || context.executor().is_suspending_generator() {
+            return res;
+        }
+        let returning = context.executor().take_generator_return();
+        self.run_finally(res, returning, context)
+    }
+}
+
+impl Try {
+    /// Executes the `catch` block, in the catch environment pushed by the caller.
+    fn run_catch_block(&self, context: &mut Context) -> Result<Value> {
+        let catch = self.catch().expect("try statement without a catch block");
+        let res = catch.block().run(context);
+
+        // pop the block env
+        let env = context.pop_environment();
+        if let (Err(_), Some(env)) = (&res, env) {
+            context
+                .executor()
+                .save_resume_state(self, ResumeState::CatchBlock(env));
+        }
 
-                    let res = catch.block().run(context);
+        res
+    }
 
-                    // pop the block env
-                    let _ = context.pop_environment();
+    /// Executes the `finally` block, after the rest of the statement completed with
+    /// `completion`.
+    ///
+    /// `returning` is the value of the early return of the generator being executed, which
+    /// resumes after the `finally` block.
+    fn run_finally(
+        &self,
+        completion: Result<Value>,
+        returning: Option<Value>,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let finally = self
+            .finally()
+            .expect("try statement without a finally block");
 
-                    res
-                } else {
-                    Err(err)
+        // The `finally` block runs normally even if the rest of the statement completed with a
+        // `return`, a `break` or a `continue`, which is resumed after the block.
+        let state = context.executor().get_current_state().clone();
+        context
+            .executor()
+            .set_current_state(InterpreterState::Executing);
+        let value = match finally.run(context) {
+            Ok(value) => value,
+            Err(err) => {
+                if context.executor().is_suspending_generator() {
+                    context.executor().save_resume_state(
+                        self,
+                        ResumeState::FinallyBlock {
+                            completion,
+                            returning,
+                        },
+                    );
                 }
-            },
-            Ok,
-        );
+                return Err(err);
+            }
+        };
 
-        if let Some(finally) = self.finally() {
-            finally.run(context)?;
+        match context.executor().get_current_state() {
+            InterpreterState::Executing => {
+                context.executor().set_current_state(state);
+            }
+            // An abrupt completion of the `finally` block overrides the completion of the rest
+            // of the statement.
+            _ => return Ok(value),
         }
 
-        res
+        if let Some(value) = returning {
+            context.executor().resume_generator_return(value);
+        }
+        completion
     }
 }
 
@@ -151,7 +237,7 @@ impl From<Try> for Node {
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Catch {
-    parameter: Option<Identifier>,
+    parameter: Option<Binding>,
     block: Block,
 }
 
@@ -160,7 +246,7 @@ impl Catch {
     pub(in crate::syntax) fn new<OI, I, B>(parameter: OI, block: B) -> Self
     where
         OI: Into<Option<I>>,
-        I: Into<Identifier>,
+        I: Into<Binding>,
         B: Into<Block>,
     {
         Self {
@@ -170,8 +256,8 @@ impl Catch {
     }
 
     /// Gets the parameter of the catch block.
-    pub fn parameter(&self) -> Option<&str> {
-        self.parameter.as_ref().map(Identifier::as_ref)
+    pub fn parameter(&self) -> Option<&Binding> {
+        self.parameter.as_ref()
     }
 
     /// Retrieves the catch execution block.
@@ -94,6 +94,118 @@ fn catch_binding_finally() {
     assert_eq!(&exec(scenario), "30");
 }
 
+#[test]
+fn catch_without_binding() {
+    let scenario = r#"
+        let a = 10;
+        try {
+            throw 20;
+        } catch {
+            a = 30;
+        }
+
+        a;
+    "#;
+    assert_eq!(&exec(scenario), "30");
+}
+
+#[test]
+fn finally_return_overrides_throw() {
+    let scenario = r#"
+        function f() {
+            try {
+                throw new Error("try");
+            } finally {
+                return "finally";
+            }
+        }
+
+        f();
+    "#;
+    assert_eq!(&exec(scenario), "\"finally\"");
+}
+
+#[test]
+fn finally_return_overrides_return() {
+    let scenario = r#"
+        function f() {
+            try {
+                return "try";
+            } catch (e) {
+                return "catch";
+            } finally {
+                return "finally";
+            }
+        }
+
+        f();
+    "#;
+    assert_eq!(&exec(scenario), "\"finally\"");
+}
+
+#[test]
+fn return_in_try_runs_finally() {
+    let scenario = r#"
+        let log = [];
+        function f() {
+            try {
+                return "try";
+            } finally {
+                log.push("first");
+                log.push("second");
+            }
+        }
+
+        [f(), log.join()].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"try,first,second\"");
+}
+
+#[test]
+fn break_in_finally_overrides_throw() {
+    let scenario = r#"
+        let a = 0;
+        while (true) {
+            try {
+                throw 1;
+            } finally {
+                a = 2;
+                break;
+            }
+        }
+
+        a;
+    "#;
+    assert_eq!(&exec(scenario), "2");
+}
+
+#[test]
+fn continue_in_try_runs_finally() {
+    let scenario = r#"
+        let log = [];
+        for (let i = 0; i < 3; i++) {
+            try {
+                continue;
+            } finally {
+                log.push(i);
+            }
+            log.push("unreachable");
+        }
+
+        log.join();
+    "#;
+    assert_eq!(&exec(scenario), "\"0,1,2\"");
+}
+
+#[test]
+fn try_finally_completion_value() {
+    assert_eq!(&exec("try { 1 } finally { 2 }"), "1");
+    assert_eq!(
+        &exec("try { throw 1 } catch (e) { e + 1 } finally { 3 }"),
+        "2"
+    );
+}
+
 #[test]
 fn fmt() {
     super::super::test_formatting(
@@ -113,3 +225,61 @@ fn fmt() {
         "#,
     );
 }
+
+#[test]
+fn catch_binding_pattern() {
+    let scenario = r#"
+        let result;
+        try {
+            throw { message: "x", details: [1, 2] };
+        } catch ({ message, details: [first, second = 5, third = 3] }) {
+            result = message + first + second + third;
+        }
+        result
+    "#;
+    assert_eq!(&exec(scenario), "\"x123\"");
+}
+
+#[test]
+fn catch_binding_pattern_is_block_scoped() {
+    let scenario = r#"
+        let message = "outer";
+        try {
+            throw { message: "inner" };
+        } catch ({ message }) {
+            message = "changed";
+        }
+        let visible;
+        try {
+            throw { other: 1 };
+        } catch ({ other }) {}
+        try {
+            other;
+            visible = true;
+        } catch (e) {
+            visible = e instanceof ReferenceError ? "not visible" : e;
+        }
+        message + " " + visible
+    "#;
+    assert_eq!(&exec(scenario), "\"outer not visible\"");
+}
+
+#[test]
+fn catch_binding_pattern_error_runs_finally() {
+    let scenario = r#"
+        let log = [];
+        try {
+            try {
+                throw null;
+            } catch ({ message }) {
+                log.push("catch");
+            } finally {
+                log.push("finally");
+            }
+        } catch (e) {
+            log.push(e instanceof TypeError);
+        }
+        log.join()
+    "#;
+    assert_eq!(&exec(scenario), "\"finally,true\"");
+}
@@ -0,0 +1,117 @@
+use crate::{
+    builtins::generator::ResumeState,
+    environment::object_environment_record::ObjectEnvironmentRecord,
+    exec::Executable,
+    gc::{Finalize, Trace},
+    syntax::ast::node::Node,
+    BoaProfiler, Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The `with` statement extends the scope chain for a statement.
+///
+/// The identifiers in the body of the statement are first looked up in the properties of the
+/// object, except the ones that are listed by its `Symbol.unscopables` property, and then in
+/// the enclosing scopes. The statement is not allowed in strict mode code.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-WithStatement
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/with
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct With {
+    expr: Box<Node>,
+    body: Box<Node>,
+}
+
+impl With {
+    /// Gets the expression of the object that extends the scope chain.
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+
+    /// Gets the body of the statement.
+    pub fn body(&self) -> &Node {
+        &self.body
+    }
+
+    /// Creates a `With` AST node.
+    pub fn new<E, B>(expr: E, body: B) -> Self
+    where
+        E: Into<Node>,
+        B: Into<Node>,
+    {
+        Self {
+            expr: Box::new(expr.into()),
+            body: Box::new(body.into()),
+        }
+    }
+
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        write!(f, "with ({}) ", self.expr())?;
+        self.body().display(f, indentation)
+    }
+}
+
+impl Executable for With {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("With", "exec");
+        if let Some(ResumeState::Environment(env)) = context.executor().take_resume_state(self) {
+            // The statement of a resumed generator is executed in the environment it was
+            // suspended in.
+            context.push_environment(env);
+        } else {
+            // 1. Let val be the result of evaluating Expression.
+            // 2. Let obj be ? ToObject(? GetValue(val)).
+            let object = self.expr().run(context)?.to_object(context)?;
+
+            // 3. Let oldEnv be the running execution context's LexicalEnvironment.
+            // 4. Let newEnv be NewObjectEnvironment(obj, true, oldEnv).
+            // 5. Set the running execution context's LexicalEnvironment to newEnv.
+            let env = context.get_current_environment();
+            let mut record = ObjectEnvironmentRecord::new(object.into(), Some(env));
+            record.with_environment = true;
+            context.push_environment(record);
+        }
+
+        // 6. Let C be the result of evaluating Statement.
+        // 7. Set the running execution context's LexicalEnvironment to oldEnv.
+        let result = self.body().run(context).map_err(|e| {
+            if let Some(env) = context.pop_environment() {
+                context
+                    .executor()
+                    .save_resume_state(self, ResumeState::Environment(env));
+            }
+            e
+        })?;
+        let _ = context.pop_environment();
+
+        // 8. Return Completion(UpdateEmpty(C, undefined)).
+        Ok(result)
+    }
+}
+
+impl fmt::Display for With {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<With> for Node {
+    fn from(with: With) -> Self {
+        Self::With(with)
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{exec, forward, Context};
+
+#[test]
+fn property_lookup() {
+    let scenario = r#"
+        let obj = { a: 1, b: 2 };
+        let result;
+        with (obj) {
+            result = a + b;
+        }
+        result;
+    "#;
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn outer_variable_fallback() {
+    let scenario = r#"
+        let outer = "outer";
+        let obj = { inner: "inner" };
+        let result;
+        with (obj) {
+            result = inner + "," + outer;
+        }
+        result;
+    "#;
+    assert_eq!(&exec(scenario), "\"inner,outer\"");
+}
+
+#[test]
+fn assignment_to_property() {
+    let scenario = r#"
+        var x = 1;
+        let obj = { x: 2 };
+        with (obj) {
+            x = 3;
+            var y = 4;
+        }
+        [x, obj.x, y, "y" in obj].join();
+    "#;
+    assert_eq!(&exec(scenario), "\"1,3,4,false\"");
+}
+
+#[test]
+fn method_call_this() {
+    let scenario = r#"
+        let obj = { name: "obj", getName() { return this.name; } };
+        let result;
+        with (obj) {
+            result = getName();
+        }
+        result;
+    "#;
+    assert_eq!(&exec(scenario), "\"obj\"");
+}
+
+#[test]
+fn unscopables() {
+    let scenario = r#"
+        let value = "outer";
+        let obj = { value: "inner", [Symbol.unscopables]: { value: true } };
+        let result;
+        with (obj) {
+            result = value;
+        }
+        result;
+    "#;
+    assert_eq!(&exec(scenario), "\"outer\"");
+}
+
+#[test]
+fn primitive_object() {
+    let scenario = r#"
+        let result;
+        with ("abc") {
+            result = length;
+        }
+        result;
+    "#;
+    assert_eq!(&exec(scenario), "3");
+
+    let scenario = r#"
+        try {
+            with (null) {}
+        } catch (e) {
+            e instanceof TypeError
+        }
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn strict_mode_syntax_error() {
+    let mut context = Context::new();
+    assert!(forward(&mut context, "'use strict'; with ({}) {}")
+        .starts_with("Uncaught \"SyntaxError\": "));
+    assert!(
+        forward(&mut context, "function f() { 'use strict'; with ({}) {} }")
+            .starts_with("Uncaught \"SyntaxError\": ")
+    );
+}
+
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        with (obj) {
+            a = b;
+        }
+        "#,
+    );
+}
@@ -0,0 +1,340 @@
+//! Yield expression node.
+
+use super::Node;
+use crate::{
+    builtins::{
+        async_function::await_value,
+        generator::{AsyncDelegateStep, ResumeState, Resumption},
+        iterable::{get_async_iterator, get_iterator, IteratorRecord},
+    },
+    exec::Executable,
+    gc::{Finalize, Trace},
+    BoaProfiler, Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The `yield` expression is used to suspend a generator function, producing a value to the
+/// caller of its `next()` method.
+///
+/// The `yield*` expression delegates to another iterable, producing each of its values in turn.
+/// In an async generator, it delegates to an async iterable, whose results are awaited.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Yield {
+    expr: Option<Box<Node>>,
+    delegate: bool,
+}
+
+impl Yield {
+    /// Creates a new `yield` expression, or a `yield*` expression if `delegate` is `true`.
+    pub fn new<E>(expr: Option<E>, delegate: bool) -> Self
+    where
+        E: Into<Node>,
+    {
+        Self {
+            expr: expr.map(|expr| Box::new(expr.into())),
+            delegate,
+        }
+    }
+
+    /// Gets the expression whose value is produced, if any.
+    pub fn expr(&self) -> Option<&Node> {
+        self.expr.as_deref()
+    }
+
+    /// Returns `true` if the expression delegates to another iterable.
+    pub fn delegate(&self) -> bool {
+        self.delegate
+    }
+
+    /// Suspends the generator being executed, producing the given value.
+    ///
+    /// This always returns an error, which unwinds the body of the generator.
+    fn suspend(&self, value: Value, delegated: bool, context: &mut Context) -> Result<Value> {
+        context
+            .executor()
+            .generator_frame()
+            .expect("yield expression outside of a generator")
+            .suspend(self, value, delegated);
+        Err(Value::undefined())
+    }
+
+    /// Makes the generator being executed return early with the given value.
+    ///
+    /// This always returns an error, which unwinds the body of the generator.
+    fn start_return(value: Value, context: &mut Context) -> Result<Value> {
+        context
+            .executor()
+            .generator_frame()
+            .expect("yield expression outside of a generator")
+            .start_return(value);
+        Err(Value::undefined())
+    }
+
+    /// Evaluates a `yield*` expression, resuming the iterator it delegates to.
+    fn run_delegate(&self, context: &mut Context) -> Result<Value> {
+        let (iterator, resumption) = if let Some(ResumeState::Delegate(iterator)) =
+            context.executor().take_resume_state(self)
+        {
+            let resumption = context
+                .executor()
+                .generator_frame()
+                .and_then(|generator| generator.take_resumption(self))
+                .expect("a suspended yield* expression is resumed");
+            (iterator, resumption)
+        } else {
+            let iterable = match self.expr() {
+                Some(expr) => expr.run(context)?,
+                None => Value::undefined(),
+            };
+            (
+                get_iterator(context, iterable)?,
+                Resumption::Next(Value::undefined()),
+            )
+        };
+
+        let result = match resumption {
+            Resumption::Next(value) => context.call(
+                iterator.next_function(),
+                iterator.iterator_object(),
+                &[value],
+            )?,
+            Resumption::Throw(exception) => {
+                let throw = iterator.iterator_object().get_field("throw", context)?;
+                if throw.is_null_or_undefined() {
+                    // The iterator doesn't handle exceptions, so the delegation is a protocol
+                    // violation.
+                    let error = context.construct_type_error(
+                        "the iterator delegated to doesn't have a throw method",
+                    );
+                    return iterator.close(Err(error), context);
+                }
+                context.call(&throw, iterator.iterator_object(), &[exception])?
+            }
+            Resumption::Return(value) => {
+                let r#return = iterator.iterator_object().get_field("return", context)?;
+                if r#return.is_null_or_undefined() {
+                    return Self::start_return(value, context);
+                }
+                let result = context.call(&r#return, iterator.iterator_object(), &[value])?;
+                if !result.is_object() {
+                    return context.throw_type_error("iterator result is not an object");
+                }
+                if result.get_field("done", context)?.to_boolean() {
+                    let value = result.get_field("value", context)?;
+                    return Self::start_return(value, context);
+                }
+                return self.suspend_delegate(iterator, result, context);
+            }
+        };
+
+        if !result.is_object() {
+            return context.throw_type_error("iterator result is not an object");
+        }
+        if result.get_field("done", context)?.to_boolean() {
+            return result.get_field("value", context);
+        }
+        self.suspend_delegate(iterator, result, context)
+    }
+
+    /// Evaluates a `yield*` expression of an async generator, resuming the async iterator it
+    /// delegates to, or the expression awaiting one of its results.
+    fn run_async_delegate(&self, context: &mut Context) -> Result<Value> {
+        let (iterator, step, resumption) =
+            if let Some(ResumeState::AsyncDelegate { iterator, step }) =
+                context.executor().take_resume_state(self)
+            {
+                let resumption = context
+                    .executor()
+                    .generator_frame()
+                    .and_then(|generator| generator.take_resumption(self))
+                    .expect("a suspended yield* expression is resumed");
+                (iterator, step, resumption)
+            } else {
+                let iterable = match self.expr() {
+                    Some(expr) => expr.run(context)?,
+                    None => Value::undefined(),
+                };
+                let iterator = get_async_iterator(context, iterable)?;
+                let result = context.call(
+                    iterator.next_function(),
+                    iterator.iterator_object(),
+                    &[Value::undefined()],
+                )?;
+                return self.await_delegate(iterator, AsyncDelegateStep::Result, result, context);
+            };
+
+        let result = match (step, resumption) {
+            (AsyncDelegateStep::Yielded, Resumption::Next(value)) => {
+                let result = context.call(
+                    iterator.next_function(),
+                    iterator.iterator_object(),
+                    &[value],
+                )?;
+                return self.await_delegate(iterator, AsyncDelegateStep::Result, result, context);
+            }
+            (AsyncDelegateStep::Yielded, Resumption::Throw(exception)) => {
+                let throw = iterator.iterator_object().get_field("throw", context)?;
+                if !throw.is_null_or_undefined() {
+                    let result = context.call(&throw, iterator.iterator_object(), &[exception])?;
+                    return self.await_delegate(
+                        iterator,
+                        AsyncDelegateStep::Result,
+                        result,
+                        context,
+                    );
+                }
+                // The iterator doesn't handle exceptions, so it's closed before the protocol
+                // violation is reported.
+                let r#return = iterator.iterator_object().get_field("return", context)?;
+                if r#return.is_null_or_undefined() {
+                    return context
+                        .throw_type_error("the iterator delegated to doesn't have a throw method");
+                }
+                let result = context.call(&r#return, iterator.iterator_object(), &[])?;
+                return self.await_delegate(iterator, AsyncDelegateStep::Close, result, context);
+            }
+            (AsyncDelegateStep::Yielded, Resumption::Return(value)) => {
+                let r#return = iterator.iterator_object().get_field("return", context)?;
+                if r#return.is_null_or_undefined() {
+                    return Self::start_return(value, context);
+                }
+                let result = context.call(&r#return, iterator.iterator_object(), &[value])?;
+                return self.await_delegate(
+                    iterator,
+                    AsyncDelegateStep::ReturnResult,
+                    result,
+                    context,
+                );
+            }
+            // The awaited result of the iterator was rejected.
+            (_, Resumption::Throw(reason)) => return Err(reason),
+            (_, Resumption::Return(_)) => unreachable!("an await expression is resumed by return"),
+            (_, Resumption::Next(result)) => result,
+        };
+
+        if !result.is_object() {
+            return context.throw_type_error("iterator result is not an object");
+        }
+        if step == AsyncDelegateStep::Close {
+            return context
+                .throw_type_error("the iterator delegated to doesn't have a throw method");
+        }
+        let done = result.get_field("done", context)?.to_boolean();
+        let value = result.get_field("value", context)?;
+        match (done, step) {
+            (true, AsyncDelegateStep::ReturnResult) => Self::start_return(value, context),
+            (true, _) => Ok(value),
+            (false, _) => {
+                // The values of the iterator are not awaited again when they are produced.
+                let suspended = self.suspend(value, true, context);
+                context.executor().save_resume_state(
+                    self,
+                    ResumeState::AsyncDelegate {
+                        iterator,
+                        step: AsyncDelegateStep::Yielded,
+                    },
+                );
+                suspended
+            }
+        }
+    }
+
+    /// Suspends the async generator being executed until the given result of the iterator
+    /// delegated to is settled.
+    fn await_delegate(
+        &self,
+        iterator: IteratorRecord,
+        step: AsyncDelegateStep,
+        result: Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let suspended = await_value(self, result, context);
+        context
+            .executor()
+            .save_resume_state(self, ResumeState::AsyncDelegate { iterator, step });
+        suspended
+    }
+
+    /// Suspends the generator being executed with the result of the iterator delegated to.
+    fn suspend_delegate(
+        &self,
+        iterator: IteratorRecord,
+        result: Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let suspended = self.suspend(result, true, context);
+        context
+            .executor()
+            .save_resume_state(self, ResumeState::Delegate(iterator));
+        suspended
+    }
+}
+
+impl Executable for Yield {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Yield", "exec");
+        if context.executor().generator_frame().is_none() {
+            return context.throw_syntax_error("yield expression outside of a generator");
+        }
+
+        if self.delegate {
+            let is_async = context
+                .executor()
+                .generator_frame()
+                .map_or(false, |generator| generator.is_async());
+            return if is_async {
+                self.run_async_delegate(context)
+            } else {
+                self.run_delegate(context)
+            };
+        }
+
+        let resumption = context
+            .executor()
+            .generator_frame()
+            .and_then(|generator| generator.take_resumption(self));
+        match resumption {
+            Some(Resumption::Next(value)) => Ok(value),
+            Some(Resumption::Throw(exception)) => Err(exception),
+            Some(Resumption::Return(value)) => Self::start_return(value, context),
+            None => {
+                let value = match self.expr() {
+                    Some(expr) => expr.run(context)?,
+                    None => Value::undefined(),
+                };
+                self.suspend(value, false, context)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Yield {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(if self.delegate { "yield*" } else { "yield" })?;
+        if let Some(ref expr) = self.expr {
+            f.write_str(" ")?;
+            expr.display(f, 0)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Yield> for Node {
+    fn from(r#yield: Yield) -> Self {
+        Self::Yield(r#yield)
+    }
+}
@@ -0,0 +1,16 @@
+#[test]
+fn fmt() {
+    super::super::test_formatting(
+        r#"
+        function* gen(a, b) {
+            yield;
+            let c = yield a;
+            yield* b;
+        };
+        function* empty_gen() {};
+        let gen_expr = function*() {
+            yield yield 1;
+        };
+        "#,
+    );
+}
@@ -0,0 +1,790 @@
+//! This module implements the `Visitor` trait, which walks the AST without executing it.
+//!
+//! Each method of the trait visits one kind of node, and by default visits the children of the
+//! node by calling the `walk_*` function of the same name. An implementation only overrides the
+//! methods of the nodes it is interested in, and can call the matching `walk_*` function from the
+//! overridden method to keep visiting the children.
+//!
+//! # Example
+//! ```
+//! use boa::syntax::ast::{
+//!     node::Identifier,
+//!     visit::Visitor,
+//! };
+//!
+//! #[derive(Default)]
+//! struct Identifiers(Vec<String>);
+//!
+//! impl Visitor for Identifiers {
+//!     fn visit_identifier(&mut self, identifier: &Identifier) {
+//!         self.0.push(identifier.as_ref().to_owned());
+//!     }
+//! }
+//!
+//! let ast = boa::parse("let a = b + c(d);", false).unwrap();
+//! let mut identifiers = Identifiers::default();
+//! identifiers.visit_statement_list(&ast);
+//! assert_eq!(identifiers.0, ["b", "c", "d"]);
+//! ```
+
+use super::{
+    node::{
+        ArrayDecl, ArrowFunctionDecl, Assign, AssignmentTarget, AsyncFunctionDecl,
+        AsyncFunctionExpr, AsyncGeneratorDecl, AsyncGeneratorExpr, AwaitExpr, BinOp, Binding,
+        BindingPattern, Block, Break, Call, Class, ClassDecl, ClassExpr, ConditionalOp, Continue,
+        Declaration, DeclarationList, DoWhileLoop, ExportDecl, ForInLoop, ForLoop, ForOfLoop,
+        FormalParameter, FunctionDecl, FunctionExpr, GeneratorDecl, GeneratorExpr, GetConstField,
+        GetField, Identifier, If, ImportDecl, New, Object, Optional, OptionalOperationKind,
+        PropertyDefinition, PropertyName, Return, Spread, StatementList, Switch, TaggedTemplate,
+        TemplateElement, TemplateLit, Throw, Try, UnaryOp, WhileLoop, With, Yield,
+    },
+    Const, Node,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// A visitor of the nodes of the AST.
+///
+/// See the [module documentation](self) for more information.
+#[allow(unused_variables)]
+pub trait Visitor {
+    /// Visits a statement list, like the body of a script or of a function.
+    fn visit_statement_list(&mut self, list: &StatementList) {
+        walk_statement_list(self, list)
+    }
+
+    /// Visits any node.
+    fn visit_node(&mut self, node: &Node) {
+        walk_node(self, node)
+    }
+
+    /// Visits an array literal.
+    fn visit_array_decl(&mut self, node: &ArrayDecl) {
+        walk_array_decl(self, node)
+    }
+
+    /// Visits an arrow function.
+    fn visit_arrow_function_decl(&mut self, node: &ArrowFunctionDecl) {
+        walk_arrow_function_decl(self, node)
+    }
+
+    /// Visits an assignment.
+    fn visit_assign(&mut self, node: &Assign) {
+        walk_assign(self, node)
+    }
+
+    /// Visits the target of an assignment.
+    fn visit_assignment_target(&mut self, target: &AssignmentTarget) {
+        walk_assignment_target(self, target)
+    }
+
+    /// Visits an async function declaration.
+    fn visit_async_function_decl(&mut self, node: &AsyncFunctionDecl) {
+        walk_async_function_decl(self, node)
+    }
+
+    /// Visits an async function expression.
+    fn visit_async_function_expr(&mut self, node: &AsyncFunctionExpr) {
+        walk_async_function_expr(self, node)
+    }
+
+    /// Visits an async generator declaration.
+    fn visit_async_generator_decl(&mut self, node: &AsyncGeneratorDecl) {
+        walk_async_generator_decl(self, node)
+    }
+
+    /// Visits an async generator expression.
+    fn visit_async_generator_expr(&mut self, node: &AsyncGeneratorExpr) {
+        walk_async_generator_expr(self, node)
+    }
+
+    /// Visits an `await` expression.
+    fn visit_await_expr(&mut self, node: &AwaitExpr) {
+        walk_await_expr(self, node)
+    }
+
+    /// Visits a binary operation.
+    fn visit_bin_op(&mut self, node: &BinOp) {
+        walk_bin_op(self, node)
+    }
+
+    /// Visits the binding of a declaration or of a parameter.
+    fn visit_binding(&mut self, binding: &Binding) {
+        walk_binding(self, binding)
+    }
+
+    /// Visits a block statement.
+    fn visit_block(&mut self, node: &Block) {
+        walk_block(self, node)
+    }
+
+    /// Visits a `break` statement.
+    fn visit_break(&mut self, node: &Break) {}
+
+    /// Visits a function call.
+    fn visit_call(&mut self, node: &Call) {
+        walk_call(self, node)
+    }
+
+    /// Visits the body of a class declaration or expression.
+    fn visit_class(&mut self, class: &Class) {
+        walk_class(self, class)
+    }
+
+    /// Visits a class declaration.
+    fn visit_class_decl(&mut self, node: &ClassDecl) {
+        walk_class_decl(self, node)
+    }
+
+    /// Visits a class expression.
+    fn visit_class_expr(&mut self, node: &ClassExpr) {
+        walk_class_expr(self, node)
+    }
+
+    /// Visits a conditional operation, like `a ? b : c`.
+    fn visit_conditional_op(&mut self, node: &ConditionalOp) {
+        walk_conditional_op(self, node)
+    }
+
+    /// Visits a literal.
+    fn visit_const(&mut self, node: &Const) {}
+
+    /// Visits a `continue` statement.
+    fn visit_continue(&mut self, node: &Continue) {}
+
+    /// Visits a `var`, `let` or `const` declaration list.
+    fn visit_declaration_list(&mut self, node: &DeclarationList) {
+        walk_declaration_list(self, node)
+    }
+
+    /// Visits a single declaration of a declaration list.
+    fn visit_declaration(&mut self, declaration: &Declaration) {
+        walk_declaration(self, declaration)
+    }
+
+    /// Visits a `do...while` loop.
+    fn visit_do_while_loop(&mut self, node: &DoWhileLoop) {
+        walk_do_while_loop(self, node)
+    }
+
+    /// Visits an `export` declaration.
+    fn visit_export_decl(&mut self, node: &ExportDecl) {
+        walk_export_decl(self, node)
+    }
+
+    /// Visits a `for` loop.
+    fn visit_for_loop(&mut self, node: &ForLoop) {
+        walk_for_loop(self, node)
+    }
+
+    /// Visits a `for...in` loop.
+    fn visit_for_in_loop(&mut self, node: &ForInLoop) {
+        walk_for_in_loop(self, node)
+    }
+
+    /// Visits a `for...of` loop.
+    fn visit_for_of_loop(&mut self, node: &ForOfLoop) {
+        walk_for_of_loop(self, node)
+    }
+
+    /// Visits a formal parameter of a function.
+    fn visit_formal_parameter(&mut self, parameter: &FormalParameter) {
+        walk_formal_parameter(self, parameter)
+    }
+
+    /// Visits a function declaration.
+    fn visit_function_decl(&mut self, node: &FunctionDecl) {
+        walk_function_decl(self, node)
+    }
+
+    /// Visits a function expression, or the function of a method.
+    fn visit_function_expr(&mut self, node: &FunctionExpr) {
+        walk_function_expr(self, node)
+    }
+
+    /// Visits a generator declaration.
+    fn visit_generator_decl(&mut self, node: &GeneratorDecl) {
+        walk_generator_decl(self, node)
+    }
+
+    /// Visits a generator expression.
+    fn visit_generator_expr(&mut self, node: &GeneratorExpr) {
+        walk_generator_expr(self, node)
+    }
+
+    /// Visits a property access with a constant name, like `a.b`.
+    fn visit_get_const_field(&mut self, node: &GetConstField) {
+        walk_get_const_field(self, node)
+    }
+
+    /// Visits a computed property access, like `a[b]`.
+    fn visit_get_field(&mut self, node: &GetField) {
+        walk_get_field(self, node)
+    }
+
+    /// Visits an identifier reference.
+    fn visit_identifier(&mut self, node: &Identifier) {}
+
+    /// Visits an `if` statement.
+    fn visit_if(&mut self, node: &If) {
+        walk_if(self, node)
+    }
+
+    /// Visits an `import` declaration.
+    fn visit_import_decl(&mut self, node: &ImportDecl) {}
+
+    /// Visits a `new` expression.
+    fn visit_new(&mut self, node: &New) {
+        walk_new(self, node)
+    }
+
+    /// Visits the `new.target` meta property.
+    fn visit_new_target(&mut self) {}
+
+    /// Visits the `import.meta` meta property.
+    fn visit_import_meta(&mut self) {}
+
+    /// Visits the `super` keyword.
+    fn visit_super(&mut self) {}
+
+    /// Visits an object literal.
+    fn visit_object(&mut self, node: &Object) {
+        walk_object(self, node)
+    }
+
+    /// Visits an optional chain, like `a?.b()`.
+    fn visit_optional(&mut self, node: &Optional) {
+        walk_optional(self, node)
+    }
+
+    /// Visits a property definition of an object literal.
+    fn visit_property_definition(&mut self, definition: &PropertyDefinition) {
+        walk_property_definition(self, definition)
+    }
+
+    /// Visits a property name of an object literal or of a class.
+    fn visit_property_name(&mut self, name: &PropertyName) {
+        walk_property_name(self, name)
+    }
+
+    /// Visits a `return` statement.
+    fn visit_return(&mut self, node: &Return) {
+        walk_return(self, node)
+    }
+
+    /// Visits a spread element, like `...a`.
+    fn visit_spread(&mut self, node: &Spread) {
+        walk_spread(self, node)
+    }
+
+    /// Visits a `switch` statement.
+    fn visit_switch(&mut self, node: &Switch) {
+        walk_switch(self, node)
+    }
+
+    /// Visits a tagged template, like ``tag`a${b}` ``.
+    fn visit_tagged_template(&mut self, node: &TaggedTemplate) {
+        walk_tagged_template(self, node)
+    }
+
+    /// Visits a template literal.
+    fn visit_template_lit(&mut self, node: &TemplateLit) {
+        walk_template_lit(self, node)
+    }
+
+    /// Visits the `this` keyword.
+    fn visit_this(&mut self) {}
+
+    /// Visits a `throw` statement.
+    fn visit_throw(&mut self, node: &Throw) {
+        walk_throw(self, node)
+    }
+
+    /// Visits a `try` statement.
+    fn visit_try(&mut self, node: &Try) {
+        walk_try(self, node)
+    }
+
+    /// Visits a unary operation.
+    fn visit_unary_op(&mut self, node: &UnaryOp) {
+        walk_unary_op(self, node)
+    }
+
+    /// Visits a `while` loop.
+    fn visit_while_loop(&mut self, node: &WhileLoop) {
+        walk_while_loop(self, node)
+    }
+
+    /// Visits a `with` statement.
+    fn visit_with(&mut self, node: &With) {
+        walk_with(self, node)
+    }
+
+    /// Visits a `yield` expression.
+    fn visit_yield(&mut self, node: &Yield) {
+        walk_yield(self, node)
+    }
+}
+
+/// Visits the statements of a statement list.
+pub fn walk_statement_list<V: Visitor + ?Sized>(visitor: &mut V, list: &StatementList) {
+    walk_nodes(visitor, list.items())
+}
+
+/// Visits the nodes of a list, in order.
+fn walk_nodes<V: Visitor + ?Sized>(visitor: &mut V, nodes: &[Node]) {
+    for node in nodes {
+        visitor.visit_node(node);
+    }
+}
+
+/// Visits a node with the method of its kind.
+pub fn walk_node<V: Visitor + ?Sized>(visitor: &mut V, node: &Node) {
+    match node {
+        Node::ArrayDecl(node) => visitor.visit_array_decl(node),
+        Node::ArrowFunctionDecl(node) => visitor.visit_arrow_function_decl(node),
+        Node::Assign(node) => visitor.visit_assign(node),
+        Node::AsyncFunctionDecl(node) => visitor.visit_async_function_decl(node),
+        Node::AsyncFunctionExpr(node) => visitor.visit_async_function_expr(node),
+        Node::AsyncGeneratorDecl(node) => visitor.visit_async_generator_decl(node),
+        Node::AsyncGeneratorExpr(node) => visitor.visit_async_generator_expr(node),
+        Node::AwaitExpr(node) => visitor.visit_await_expr(node),
+        Node::BinOp(node) => visitor.visit_bin_op(node),
+        Node::Block(node) => visitor.visit_block(node),
+        Node::Break(node) => visitor.visit_break(node),
+        Node::Call(node) => visitor.visit_call(node),
+        Node::ClassDecl(node) => visitor.visit_class_decl(node),
+        Node::ClassExpr(node) => visitor.visit_class_expr(node),
+        Node::ConditionalOp(node) => visitor.visit_conditional_op(node),
+        Node::Const(node) => visitor.visit_const(node),
+        Node::ConstDeclList(node) | Node::LetDeclList(node) | Node::VarDeclList(node) => {
+            visitor.visit_declaration_list(node)
+        }
+        Node::Continue(node) => visitor.visit_continue(node),
+        Node::DoWhileLoop(node) => visitor.visit_do_while_loop(node),
+        Node::ExportDecl(node) => visitor.visit_export_decl(node),
+        Node::FunctionDecl(node) => visitor.visit_function_decl(node),
+        Node::FunctionExpr(node) => visitor.visit_function_expr(node),
+        Node::GeneratorDecl(node) => visitor.visit_generator_decl(node),
+        Node::GeneratorExpr(node) => visitor.visit_generator_expr(node),
+        Node::GetConstField(node) => visitor.visit_get_const_field(node),
+        Node::GetField(node) => visitor.visit_get_field(node),
+        Node::ForLoop(node) => visitor.visit_for_loop(node),
+        Node::ForInLoop(node) => visitor.visit_for_in_loop(node),
+        Node::ForOfLoop(node) => visitor.visit_for_of_loop(node),
+        Node::If(node) => visitor.visit_if(node),
+        Node::ImportDecl(node) => visitor.visit_import_decl(node),
+        Node::Identifier(node) => visitor.visit_identifier(node),
+        Node::New(node) => visitor.visit_new(node),
+        Node::NewTarget => visitor.visit_new_target(),
+        Node::ImportMeta => visitor.visit_import_meta(),
+        Node::Super => visitor.visit_super(),
+        Node::Object(node) => visitor.visit_object(node),
+        Node::Optional(node) => visitor.visit_optional(node),
+        Node::Return(node) => visitor.visit_return(node),
+        Node::Switch(node) => visitor.visit_switch(node),
+        Node::Spread(node) => visitor.visit_spread(node),
+        Node::TaggedTemplate(node) => visitor.visit_tagged_template(node),
+        Node::TemplateLit(node) => visitor.visit_template_lit(node),
+        Node::Throw(node) => visitor.visit_throw(node),
+        Node::Try(node) => visitor.visit_try(node),
+        Node::This => visitor.visit_this(),
+        Node::UnaryOp(node) => visitor.visit_unary_op(node),
+        Node::WhileLoop(node) => visitor.visit_while_loop(node),
+        Node::With(node) => visitor.visit_with(node),
+        Node::Yield(node) => visitor.visit_yield(node),
+        Node::Empty => {}
+    }
+}
+
+/// Visits the elements of an array literal.
+pub fn walk_array_decl<V: Visitor + ?Sized>(visitor: &mut V, node: &ArrayDecl) {
+    walk_nodes(visitor, node.as_ref())
+}
+
+/// Visits the parameters and the body of an arrow function.
+pub fn walk_arrow_function_decl<V: Visitor + ?Sized>(visitor: &mut V, node: &ArrowFunctionDecl) {
+    walk_function(visitor, node.params(), node.body())
+}
+
+/// Visits the target and the assigned value of an assignment.
+pub fn walk_assign<V: Visitor + ?Sized>(visitor: &mut V, node: &Assign) {
+    visitor.visit_assignment_target(node.lhs());
+    visitor.visit_node(node.rhs());
+}
+
+/// Visits the expression or the elements of an assignment target.
+pub fn walk_assignment_target<V: Visitor + ?Sized>(visitor: &mut V, target: &AssignmentTarget) {
+    match target {
+        AssignmentTarget::Expression(node) => visitor.visit_node(node),
+        AssignmentTarget::Array(pattern) => {
+            for element in pattern.elements().iter().flatten() {
+                visitor.visit_assignment_target(element.target());
+                if let Some(init) = element.init() {
+                    visitor.visit_node(init);
+                }
+            }
+            if let Some(rest) = pattern.rest() {
+                visitor.visit_assignment_target(rest);
+            }
+        }
+        AssignmentTarget::Object(pattern) => {
+            for property in pattern.properties() {
+                visitor.visit_assignment_target(property.element().target());
+                if let Some(init) = property.element().init() {
+                    visitor.visit_node(init);
+                }
+            }
+            if let Some(rest) = pattern.rest() {
+                visitor.visit_assignment_target(rest);
+            }
+        }
+    }
+}
+
+/// Visits the parameters and the body of an async function declaration.
+pub fn walk_async_function_decl<V: Visitor + ?Sized>(visitor: &mut V, node: &AsyncFunctionDecl) {
+    walk_function(visitor, node.parameters(), node.body())
+}
+
+/// Visits the parameters and the body of an async function expression.
+pub fn walk_async_function_expr<V: Visitor + ?Sized>(visitor: &mut V, node: &AsyncFunctionExpr) {
+    walk_function(visitor, node.parameters(), node.body())
+}
+
+/// Visits the parameters and the body of an async generator declaration.
+pub fn walk_async_generator_decl<V: Visitor + ?Sized>(visitor: &mut V, node: &AsyncGeneratorDecl) {
+    walk_function(visitor, node.parameters(), node.body())
+}
+
+/// Visits the parameters and the body of an async generator expression.
+pub fn walk_async_generator_expr<V: Visitor + ?Sized>(visitor: &mut V, node: &AsyncGeneratorExpr) {
+    walk_function(visitor, node.parameters(), node.body())
+}
+
+/// Visits the awaited expression.
+pub fn walk_await_expr<V: Visitor + ?Sized>(visitor: &mut V, node: &AwaitExpr) {
+    visitor.visit_node(node.expr())
+}
+
+/// Visits the operands of a binary operation.
+pub fn walk_bin_op<V: Visitor + ?Sized>(visitor: &mut V, node: &BinOp) {
+    visitor.visit_node(node.lhs());
+    visitor.visit_node(node.rhs());
+}
+
+/// Visits the default values and the nested patterns of a binding.
+pub fn walk_binding<V: Visitor + ?Sized>(visitor: &mut V, binding: &Binding) {
+    match binding {
+        Binding::Identifier(_) => {}
+        Binding::Pattern(BindingPattern::Object(pattern)) => {
+            for property in pattern.properties() {
+                visitor.visit_binding(property.element().binding());
+                if let Some(init) = property.element().init() {
+                    visitor.visit_node(init);
+                }
+            }
+        }
+        Binding::Pattern(BindingPattern::Array(pattern)) => {
+            for element in pattern.elements().iter().flatten() {
+                visitor.visit_binding(element.binding());
+                if let Some(init) = element.init() {
+                    visitor.visit_node(init);
+                }
+            }
+            if let Some(rest) = pattern.rest() {
+                visitor.visit_binding(rest);
+            }
+        }
+    }
+}
+
+/// Visits the statements of a block.
+pub fn walk_block<V: Visitor + ?Sized>(visitor: &mut V, node: &Block) {
+    walk_nodes(visitor, node.items())
+}
+
+/// Visits the callee and the arguments of a call.
+pub fn walk_call<V: Visitor + ?Sized>(visitor: &mut V, node: &Call) {
+    visitor.visit_node(node.expr());
+    walk_nodes(visitor, node.args());
+}
+
+/// Visits the constructor and the methods of a class.
+pub fn walk_class<V: Visitor + ?Sized>(visitor: &mut V, class: &Class) {
+    if let Some(constructor) = class.constructor() {
+        visitor.visit_function_expr(constructor);
+    }
+    for element in class.elements() {
+        visitor.visit_property_name(element.name());
+        visitor.visit_function_expr(element.function());
+    }
+}
+
+/// Visits the class of a class declaration.
+pub fn walk_class_decl<V: Visitor + ?Sized>(visitor: &mut V, node: &ClassDecl) {
+    visitor.visit_class(node.class())
+}
+
+/// Visits the class of a class expression.
+pub fn walk_class_expr<V: Visitor + ?Sized>(visitor: &mut V, node: &ClassExpr) {
+    visitor.visit_class(node.class())
+}
+
+/// Visits the condition and the branches of a conditional operation.
+pub fn walk_conditional_op<V: Visitor + ?Sized>(visitor: &mut V, node: &ConditionalOp) {
+    visitor.visit_node(node.cond());
+    visitor.visit_node(node.if_true());
+    visitor.visit_node(node.if_false());
+}
+
+/// Visits the declarations of a declaration list.
+pub fn walk_declaration_list<V: Visitor + ?Sized>(visitor: &mut V, node: &DeclarationList) {
+    for declaration in node.as_ref() {
+        visitor.visit_declaration(declaration);
+    }
+}
+
+/// Visits the initializer of a declaration.
+pub fn walk_declaration<V: Visitor + ?Sized>(visitor: &mut V, declaration: &Declaration) {
+    if let Some(init) = declaration.init() {
+        visitor.visit_node(init);
+    }
+}
+
+/// Visits the body and the condition of a `do...while` loop.
+pub fn walk_do_while_loop<V: Visitor + ?Sized>(visitor: &mut V, node: &DoWhileLoop) {
+    visitor.visit_node(node.body());
+    visitor.visit_node(node.cond());
+}
+
+/// Visits the exported declaration or expression of an `export` declaration.
+pub fn walk_export_decl<V: Visitor + ?Sized>(visitor: &mut V, node: &ExportDecl) {
+    match node {
+        ExportDecl::Declaration(node) | ExportDecl::Default(node) => visitor.visit_node(node),
+        ExportDecl::Named { .. } | ExportDecl::All { .. } => {}
+    }
+}
+
+/// Visits the initializer, the condition, the final expression and the body of a `for` loop.
+pub fn walk_for_loop<V: Visitor + ?Sized>(visitor: &mut V, node: &ForLoop) {
+    if let Some(init) = node.init() {
+        visitor.visit_node(init);
+    }
+    if let Some(condition) = node.condition() {
+        visitor.visit_node(condition);
+    }
+    if let Some(final_expr) = node.final_expr() {
+        visitor.visit_node(final_expr);
+    }
+    visitor.visit_node(node.body());
+}
+
+/// Visits the variable, the object and the body of a `for...in` loop.
+pub fn walk_for_in_loop<V: Visitor + ?Sized>(visitor: &mut V, node: &ForInLoop) {
+    visitor.visit_node(node.variable());
+    visitor.visit_node(node.expr());
+    visitor.visit_node(node.body());
+}
+
+/// Visits the variable, the iterable and the body of a `for...of` loop.
+pub fn walk_for_of_loop<V: Visitor + ?Sized>(visitor: &mut V, node: &ForOfLoop) {
+    visitor.visit_node(node.variable());
+    visitor.visit_node(node.iterable());
+    visitor.visit_node(node.body());
+}
+
+/// Visits the binding and the default value of a formal parameter.
+pub fn walk_formal_parameter<V: Visitor + ?Sized>(visitor: &mut V, parameter: &FormalParameter) {
+    visitor.visit_binding(parameter.binding());
+    if let Some(init) = parameter.init() {
+        visitor.visit_node(init);
+    }
+}
+
+/// Visits the parameters and the body of a function.
+fn walk_function<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    parameters: &[FormalParameter],
+    body: &[Node],
+) {
+    for parameter in parameters {
+        visitor.visit_formal_parameter(parameter);
+    }
+    walk_nodes(visitor, body);
+}
+
+/// Visits the parameters and the body of a function declaration.
+pub fn walk_function_decl<V: Visitor + ?Sized>(visitor: &mut V, node: &FunctionDecl) {
+    walk_function(visitor, node.parameters(), node.body())
+}
+
+/// Visits the parameters and the body of a function expression.
+pub fn walk_function_expr<V: Visitor + ?Sized>(visitor: &mut V, node: &FunctionExpr) {
+    walk_function(visitor, node.parameters(), node.body())
+}
+
+/// Visits the parameters and the body of a generator declaration.
+pub fn walk_generator_decl<V: Visitor + ?Sized>(visitor: &mut V, node: &GeneratorDecl) {
+    walk_function(visitor, node.parameters(), node.body())
+}
+
+/// Visits the parameters and the body of a generator expression.
+pub fn walk_generator_expr<V: Visitor + ?Sized>(visitor: &mut V, node: &GeneratorExpr) {
+    walk_function(visitor, node.parameters(), node.body())
+}
+
+/// Visits the object of a property access with a constant name.
+pub fn walk_get_const_field<V: Visitor + ?Sized>(visitor: &mut V, node: &GetConstField) {
+    visitor.visit_node(node.obj())
+}
+
+/// Visits the object and the key of a computed property access.
+pub fn walk_get_field<V: Visitor + ?Sized>(visitor: &mut V, node: &GetField) {
+    visitor.visit_node(node.obj());
+    visitor.visit_node(node.field());
+}
+
+/// Visits the condition and the branches of an `if` statement.
+pub fn walk_if<V: Visitor + ?Sized>(visitor: &mut V, node: &If) {
+    visitor.visit_node(node.cond());
+    visitor.visit_node(node.body());
+    if let Some(else_node) = node.else_node() {
+        visitor.visit_node(else_node);
+    }
+}
+
+/// Visits the constructor and the arguments of a `new` expression.
+pub fn walk_new<V: Visitor + ?Sized>(visitor: &mut V, node: &New) {
+    visitor.visit_node(node.expr());
+    walk_nodes(visitor, node.args());
+}
+
+/// Visits the property definitions of an object literal.
+pub fn walk_object<V: Visitor + ?Sized>(visitor: &mut V, node: &Object) {
+    for definition in node.properties() {
+        visitor.visit_property_definition(definition);
+    }
+}
+
+/// Visits the target and the operations of an optional chain.
+pub fn walk_optional<V: Visitor + ?Sized>(visitor: &mut V, node: &Optional) {
+    visitor.visit_node(node.target());
+    for operation in node.chain() {
+        match operation.kind() {
+            OptionalOperationKind::SimplePropertyAccess(_) => {}
+            OptionalOperationKind::PropertyAccess(field) => visitor.visit_node(field),
+            OptionalOperationKind::Call(args) => walk_nodes(visitor, args),
+        }
+    }
+}
+
+/// Visits the name and the value of a property definition.
+pub fn walk_property_definition<V: Visitor + ?Sized>(
+    visitor: &mut V,
+    definition: &PropertyDefinition,
+) {
+    match definition {
+        PropertyDefinition::IdentifierReference(_) => {}
+        PropertyDefinition::Property(name, value) => {
+            visitor.visit_property_name(name);
+            visitor.visit_node(value);
+        }
+        PropertyDefinition::MethodDefinition(_, name, function) => {
+            visitor.visit_property_name(name);
+            visitor.visit_function_expr(function);
+        }
+        PropertyDefinition::SpreadObject(node) => visitor.visit_node(node),
+    }
+}
+
+/// Visits the expression of a computed property name.
+pub fn walk_property_name<V: Visitor + ?Sized>(visitor: &mut V, name: &PropertyName) {
+    match name {
+        PropertyName::Literal(_) => {}
+        PropertyName::Computed(node) => visitor.visit_node(node),
+    }
+}
+
+/// Visits the returned expression.
+pub fn walk_return<V: Visitor + ?Sized>(visitor: &mut V, node: &Return) {
+    if let Some(expr) = node.expr() {
+        visitor.visit_node(expr);
+    }
+}
+
+/// Visits the spread expression.
+pub fn walk_spread<V: Visitor + ?Sized>(visitor: &mut V, node: &Spread) {
+    visitor.visit_node(node.val())
+}
+
+/// Visits the discriminant, the cases and the default clause of a `switch` statement.
+pub fn walk_switch<V: Visitor + ?Sized>(visitor: &mut V, node: &Switch) {
+    visitor.visit_node(node.val());
+    for case in node.cases() {
+        visitor.visit_node(case.condition());
+        visitor.visit_statement_list(case.body());
+    }
+    if let Some(default) = node.default() {
+        walk_nodes(visitor, default);
+    }
+}
+
+/// Visits the tag and the substituted expressions of a tagged template.
+pub fn walk_tagged_template<V: Visitor + ?Sized>(visitor: &mut V, node: &TaggedTemplate) {
+    visitor.visit_node(node.tag());
+    walk_nodes(visitor, node.exprs());
+}
+
+/// Visits the substituted expressions of a template literal.
+pub fn walk_template_lit<V: Visitor + ?Sized>(visitor: &mut V, node: &TemplateLit) {
+    for element in node.elements() {
+        if let TemplateElement::Expr(expr) = element {
+            visitor.visit_node(expr);
+        }
+    }
+}
+
+/// Visits the thrown expression.
+pub fn walk_throw<V: Visitor + ?Sized>(visitor: &mut V, node: &Throw) {
+    visitor.visit_node(node.expr())
+}
+
+/// Visits the blocks and the catch parameter of a `try` statement.
+pub fn walk_try<V: Visitor + ?Sized>(visitor: &mut V, node: &Try) {
+    visitor.visit_block(node.block());
+    if let Some(catch) = node.catch() {
+        if let Some(parameter) = catch.parameter() {
+            visitor.visit_binding(parameter);
+        }
+        visitor.visit_block(catch.block());
+    }
+    if let Some(finally) = node.finally() {
+        visitor.visit_block(finally);
+    }
+}
+
+/// Visits the operand of a unary operation.
+pub fn walk_unary_op<V: Visitor + ?Sized>(visitor: &mut V, node: &UnaryOp) {
+    visitor.visit_node(node.target())
+}
+
+/// Visits the condition and the body of a `while` loop.
+pub fn walk_while_loop<V: Visitor + ?Sized>(visitor: &mut V, node: &WhileLoop) {
+    visitor.visit_node(node.cond());
+    visitor.visit_node(node.expr());
+}
+
+/// Visits the object expression and the body of a `with` statement.
+pub fn walk_with<V: Visitor + ?Sized>(visitor: &mut V, node: &With) {
+    visitor.visit_node(node.expr());
+    visitor.visit_node(node.body());
+}
+
+/// Visits the yielded expression.
+pub fn walk_yield<V: Visitor + ?Sized>(visitor: &mut V, node: &Yield) {
+    if let Some(expr) = node.expr() {
+        visitor.visit_node(expr);
+    }
+}
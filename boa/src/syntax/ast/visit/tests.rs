@@ -0,0 +1,82 @@
+use super::{walk_function_decl, walk_if, Visitor};
+use crate::{
+    parse,
+    syntax::ast::node::{FunctionDecl, If},
+};
+
+#[derive(Default)]
+struct IfCounter {
+    ifs: usize,
+}
+
+impl Visitor for IfCounter {
+    fn visit_if(&mut self, node: &If) {
+        self.ifs += 1;
+        walk_if(self, node);
+    }
+}
+
+#[test]
+fn count_if_statements() {
+    let ast = parse(
+        r#"
+        function check(a, b = (() => { if (a) { return 1; } })()) {
+            if (a > b) {
+                return a;
+            } else if (a < b) {
+                for (let i = 0; i < 3; i++) {
+                    if (i === b) break;
+                }
+            }
+            try {
+                const f = function () { if (true) {} };
+            } catch ({ message = (() => { if (a) {} })() }) {}
+            return { m() { if (a) {} } };
+        }
+        if (check(1, 2)) {}
+        "#,
+        false,
+    )
+    .unwrap();
+
+    let mut counter = IfCounter::default();
+    counter.visit_statement_list(&ast);
+    assert_eq!(counter.ifs, 8);
+}
+
+#[test]
+fn skip_children() {
+    #[derive(Default)]
+    struct Functions {
+        names: Vec<String>,
+        ifs: usize,
+    }
+
+    impl Visitor for Functions {
+        fn visit_function_decl(&mut self, node: &FunctionDecl) {
+            self.names.push(node.name().to_owned());
+            if node.name() != "skipped" {
+                walk_function_decl(self, node);
+            }
+        }
+
+        fn visit_if(&mut self, node: &If) {
+            self.ifs += 1;
+            walk_if(self, node);
+        }
+    }
+
+    let ast = parse(
+        r#"
+        function visited() { function inner() { if (x) {} } }
+        function skipped() { if (x) {} }
+        "#,
+        false,
+    )
+    .unwrap();
+
+    let mut functions = Functions::default();
+    functions.visit_statement_list(&ast);
+    assert_eq!(functions.names, ["visited", "inner", "skipped"]);
+    assert_eq!(functions.ifs, 1);
+}
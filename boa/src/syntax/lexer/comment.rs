@@ -73,9 +73,9 @@ impl<R> Tokenizer<R> for MultiLineComment {
                     new_line = true;
                 }
             } else {
-                return Err(Error::syntax(
+                return Err(Error::abrupt_end(
                     "unterminated multiline comment",
-                    cursor.pos(),
+                    start_pos,
                 ));
             }
         }
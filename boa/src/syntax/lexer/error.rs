@@ -21,6 +21,12 @@ pub enum Error {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-native-error-types-used-in-this-standard-syntaxerror
     Syntax(Box<str>, Position),
+
+    /// Indicates that the source code ended in the middle of a token, like an unterminated string
+    /// literal, which starts at the given position.
+    ///
+    /// The source code might still be valid once more of it is available.
+    AbruptEnd(Box<str>, Position),
 }
 
 impl From<io::Error> for Error {
@@ -38,13 +44,24 @@ impl Error {
     {
         Self::Syntax(err.into(), pos.into())
     }
+
+    /// Creates a new error for a token that isn't terminated when the source code ends.
+    pub(super) fn abrupt_end<M, P>(err: M, pos: P) -> Self
+    where
+        M: Into<Box<str>>,
+        P: Into<Position>,
+    {
+        Self::AbruptEnd(err.into(), pos.into())
+    }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::IO(e) => write!(f, "I/O error: {}", e),
-            Self::Syntax(e, pos) => write!(f, "Syntax Error: {} at position: {}", e, pos),
+            Self::Syntax(e, pos) | Self::AbruptEnd(e, pos) => {
+                write!(f, "Syntax Error: {} at position: {}", e, pos)
+            }
         }
     }
 }
@@ -53,7 +70,7 @@ impl StdError for Error {
     fn source(&self) -> Option<&(dyn StdError + 'static)> {
         match self {
             Self::IO(err) => Some(err),
-            Self::Syntax(_, _) => None,
+            Self::Syntax(_, _) | Self::AbruptEnd(_, _) => None,
         }
     }
 }
@@ -163,7 +163,7 @@ impl<R> Lexer<R> {
                 }
             }
         } else {
-            Err(Error::syntax(
+            Err(Error::abrupt_end(
                 "Abrupt end: Expecting Token /,*,= or regex",
                 start,
             ))
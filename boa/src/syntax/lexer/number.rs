@@ -102,7 +102,7 @@ where
             }
         }
         None => {
-            return Err(Error::syntax(
+            return Err(Error::abrupt_end(
                 "Abrupt end: No exponential value found",
                 cursor.pos(),
             ));
@@ -397,6 +397,9 @@ impl<R> Tokenizer<R> for NumberLiteral {
             NumericKind::Integer(base) => {
                 if let Ok(num) = i32::from_str_radix(num_str, base) {
                     Numeric::Integer(num)
+                } else if base == 10 {
+                    // Adding the digits one by one would round the value many times.
+                    Numeric::Rational(fast_float::parse(num_str).expect("Failed to parse float after checks"))
                 } else {
                     let b = f64::from(base);
                     let mut result = 0.0_f64;
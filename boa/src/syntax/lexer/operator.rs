@@ -17,18 +17,16 @@ use std::io::Read;
 macro_rules! vop {
     ($cursor:ident, $assign_op:expr, $op:expr) => ({
         match $cursor.peek()? {
-            None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
             Some(b'=') => {
                 $cursor.next_byte()?.expect("= token vanished");
                 $cursor.next_column();
                 $assign_op
             }
-            Some(_) => $op,
+            _ => $op,
         }
     });
     ($cursor:ident, $assign_op:expr, $op:expr, {$($case:pat => $block:expr), +}) => ({
         match $cursor.peek()? {
-            None => Err(Error::syntax("abrupt end - could not preview next value as part of the operator", $cursor.pos())),
             Some(b'=') => {
                 $cursor.next_byte()?.expect("= token vanished");
                 $cursor.next_column();
@@ -57,15 +55,16 @@ macro_rules! vop {
 /// The `op` macro handles binary operations or assignment operations and converts them into tokens.
 macro_rules! op {
     ($cursor:ident, $start_pos:expr, $assign_op:expr, $op:expr) => ({
+        let punc: Result<Punctuator, Error> = vop!($cursor, $assign_op, $op);
         Ok(Token::new(
-            vop!($cursor, $assign_op, $op)?.into(),
+            punc?.into(),
             Span::new($start_pos, $cursor.pos()),
         ))
     });
     ($cursor:ident, $start_pos:expr, $assign_op:expr, $op:expr, {$($case:pat => $block:expr),+}) => ({
-        let punc: Punctuator = vop!($cursor, $assign_op, $op, {$($case => $block),+})?;
+        let punc: Result<Punctuator, Error> = vop!($cursor, $assign_op, $op, {$($case => $block),+});
         Ok(Token::new(
-            punc.into(),
+            punc?.into(),
             Span::new($start_pos, $cursor.pos()),
         ))
     });
@@ -134,6 +133,14 @@ impl<R> Tokenizer<R> for Operator {
                         Ok(Punctuator::Coalesce)
                     )
                 }
+                // `?.` not followed by a decimal digit, so that `a?.5:1` is still a conditional.
+                Some(b'.') if !((cursor.peek_n(2)? >> 8) as u8).is_ascii_digit() => {
+                    let _ = cursor.next_byte()?.expect(". vanished");
+                    Ok(Token::new(
+                        TokenKind::Punctuator(Punctuator::Optional),
+                        Span::new(start_pos, cursor.pos()),
+                    ))
+                }
                 _ => Ok(Token::new(
                     TokenKind::Punctuator(Punctuator::Question),
                     Span::new(start_pos, cursor.pos()),
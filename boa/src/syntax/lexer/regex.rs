@@ -48,9 +48,9 @@ impl<R> Tokenizer<R> for RegexLiteral {
             match cursor.next_byte()? {
                 None => {
                     // Abrupt end.
-                    return Err(Error::syntax(
+                    return Err(Error::abrupt_end(
                         "abrupt end on regular expression",
-                        cursor.pos(),
+                        start_pos,
                     ));
                 }
                 Some(b) => {
@@ -95,9 +95,9 @@ impl<R> Tokenizer<R> for RegexLiteral {
                                 }
                             } else {
                                 // Abrupt end of regex.
-                                return Err(Error::syntax(
+                                return Err(Error::abrupt_end(
                                     "abrupt end on regular expression",
-                                    cursor.pos(),
+                                    start_pos,
                                 ));
                             }
                         }
@@ -8,10 +8,7 @@ use crate::{
         lexer::{Token, TokenKind},
     },
 };
-use std::{
-    io::{self, ErrorKind, Read},
-    str,
-};
+use std::{io::Read, str};
 
 /// String literal lexing.
 ///
@@ -127,7 +124,12 @@ impl StringLiteral {
                     let _timer = BoaProfiler::global()
                         .start_event("StringLiteral - escape sequence", "Lexing");
 
-                    if let Some(escape_value) = Self::take_escape_sequence_or_line_continuation(cursor, ch_start_pos, is_strict_mode, false)? {
+                    if let Some(escape_value) = Self::take_escape_sequence_or_line_continuation(
+                        cursor,
+                        ch_start_pos,
+                        is_strict_mode,
+                        false,
+                    )? {
                         buf.push_code_point(escape_value);
                     }
                 }
@@ -136,11 +138,11 @@ impl StringLiteral {
                 Some(ch) if !Self::is_line_terminator(ch) => {
                     buf.push_code_point(ch);
                 }
-                _ => {
-                    return Err(Error::from(io::Error::new(
-                        ErrorKind::UnexpectedEof,
-                        "unterminated string literal",
-                    )));
+                Some(_) => {
+                    return Err(Error::syntax("unterminated string literal", ch_start_pos));
+                }
+                None => {
+                    return Err(Error::abrupt_end("unterminated string literal", start_pos));
                 }
             }
         }
@@ -159,10 +161,7 @@ impl StringLiteral {
         R: Read,
     {
         let escape_ch = cursor.next_char()?.ok_or_else(|| {
-            Error::from(io::Error::new(
-                ErrorKind::UnexpectedEof,
-                "unterminated escape sequence in literal",
-            ))
+            Error::abrupt_end("unterminated escape sequence in literal", start_pos)
         })?;
 
         let escape_value = match escape_ch {
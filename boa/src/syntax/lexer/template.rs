@@ -9,7 +9,7 @@ use crate::{
         lexer::{Token, TokenKind},
     },
 };
-use std::io::{self, ErrorKind, Read};
+use std::io::Read;
 
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
@@ -107,12 +107,9 @@ impl<R> Tokenizer<R> for TemplateLiteral {
 
         let mut buf = Vec::new();
         loop {
-            let ch = cursor.next_char()?.ok_or_else(|| {
-                Error::from(io::Error::new(
-                    ErrorKind::UnexpectedEof,
-                    "unterminated template literal",
-                ))
-            })?;
+            let ch = cursor
+                .next_char()?
+                .ok_or_else(|| Error::abrupt_end("unterminated template literal", start_pos))?;
 
             match ch {
                 0x0060 /* ` */ => {
@@ -135,10 +132,7 @@ impl<R> Tokenizer<R> for TemplateLiteral {
                 }
                 0x005C /* \ */ => {
                     let escape_ch = cursor.peek()?.ok_or_else(|| {
-                        Error::from(io::Error::new(
-                            ErrorKind::UnexpectedEof,
-                            "unterminated escape sequence in literal",
-                        ))
+                        Error::abrupt_end("unterminated template literal", start_pos)
                     })?;
 
                     buf.push(b'\\' as u16);
@@ -5,6 +5,7 @@ use super::regex::RegExpFlags;
 use super::token::Numeric;
 use super::*;
 use super::{Error, Position};
+use crate::builtins::BigInt;
 use crate::syntax::ast::Keyword;
 use crate::syntax::lexer::template::TemplateString;
 use std::str;
@@ -162,7 +163,7 @@ fn check_punctuators() {
     // https://tc39.es/ecma262/#sec-punctuators
     let s = "{ ( ) [ ] . ... ; , < > <= >= == != === !== \
              + - * % -- << >> >>> & | ^ ! ~ && || ? : \
-             = += -= *= &= **= ++ ** <<= >>= >>>= &= |= ^= => ?? ??= &&= ||=";
+             = += -= *= &= **= ++ ** <<= >>= >>>= &= |= ^= => ?? ??= &&= ||= ?.";
     let mut lexer = Lexer::new(s.as_bytes());
 
     let expected = [
@@ -219,6 +220,23 @@ fn check_punctuators() {
         TokenKind::Punctuator(Punctuator::AssignCoalesce),
         TokenKind::Punctuator(Punctuator::AssignBoolAnd),
         TokenKind::Punctuator(Punctuator::AssignBoolOr),
+        TokenKind::Punctuator(Punctuator::Optional),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn check_optional_chaining_followed_by_digit() {
+    let s = "a?.5:1";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let expected = [
+        TokenKind::identifier("a"),
+        TokenKind::Punctuator(Punctuator::Question),
+        TokenKind::numeric_literal(0.5),
+        TokenKind::Punctuator(Punctuator::Colon),
+        TokenKind::numeric_literal(1),
     ];
 
     expect_tokens(&mut lexer, &expected);
@@ -475,7 +493,8 @@ fn numbers_with_separators() {
 #[test]
 fn numbers_with_bad_separators() {
     let numbers = [
-        "0b_10", "0x_10", "10_", "1._10", "1e+_10", "1E_10", "10__00",
+        "0b_10", "0x_10", "10_", "1._10", "1e+_10", "1E_10", "10__00", "0o_17", "0b1_", "1_.5",
+        "1_e1", "0_1", "01_7", "08_1", "1_n",
     ];
 
     for n in numbers.iter() {
@@ -484,6 +503,19 @@ fn numbers_with_bad_separators() {
     }
 }
 
+#[test]
+fn bigints_with_separators() {
+    let mut lexer = Lexer::new(&b"1_0n 0x1_fn 0b1_0n"[..]);
+
+    let expected = [
+        TokenKind::NumericLiteral(Numeric::BigInt(BigInt::from(10))),
+        TokenKind::NumericLiteral(Numeric::BigInt(BigInt::from(31))),
+        TokenKind::NumericLiteral(Numeric::BigInt(BigInt::from(2))),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
 #[test]
 fn big_exp_numbers() {
     let mut lexer = Lexer::new(&b"1.0e25 1.0e36 9.0e50"[..]);
@@ -498,11 +530,15 @@ fn big_exp_numbers() {
 }
 
 #[test]
-#[ignore]
 fn big_literal_numbers() {
-    let mut lexer = Lexer::new(&b"10000000000000000000000000"[..]);
+    let mut lexer =
+        Lexer::new(&b"10000000000000000000000000 1000000000000000128 9007199254740993"[..]);
 
-    let expected = [TokenKind::numeric_literal(10000000000000000000000000.0)];
+    let expected = [
+        TokenKind::numeric_literal(10000000000000000000000000.0),
+        TokenKind::numeric_literal(1000000000000000128.0),
+        TokenKind::numeric_literal(9007199254740992.0),
+    ];
 
     expect_tokens(&mut lexer, &expected);
 }
@@ -972,6 +1008,34 @@ fn string_line_continuation() {
     expect_tokens(&mut lexer, &expected_tokens);
 }
 
+#[test]
+fn unterminated_literals_at_eof_have_a_position() {
+    let cases: [(&[u8], Position); 5] = [
+        (b"a = 'abc", Position::new(1, 5)),
+        (b"a = `abc", Position::new(1, 5)),
+        (b"a\n  /* abc", Position::new(2, 3)),
+        (b"a = /abc", Position::new(1, 5)),
+        (b"a = 'ab\\", Position::new(1, 8)),
+    ];
+
+    for (src, expected) in cases.iter() {
+        let mut lexer = Lexer::new(*src);
+        lexer.set_goal(InputElement::RegExp);
+        let err = loop {
+            match lexer.next() {
+                Ok(Some(_)) => lexer.set_goal(InputElement::RegExp),
+                Ok(None) => panic!("{:?} was lexed", str::from_utf8(src)),
+                Err(err) => break err,
+            }
+        };
+        if let Error::AbruptEnd(_, pos) = err {
+            assert_eq!(pos, *expected, "{:?}", str::from_utf8(src));
+        } else {
+            panic!("invalid error type: {:?}", err);
+        }
+    }
+}
+
 mod carriage_return {
     use super::*;
 
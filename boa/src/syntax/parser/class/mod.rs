@@ -0,0 +1,262 @@
+//! Class definition parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Classes
+//! [spec]: https://tc39.es/ecma262/#sec-class-definitions
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    syntax::{
+        ast::{
+            node::{self, Class, ClassDecl, ClassElement, ClassExpr, MethodDefinitionKind},
+            Keyword, Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            expression::{MethodDefinition, PropertyName},
+            statement::BindingIdentifier,
+            AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Class declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/class
+/// [spec]: https://tc39.es/ecma262/#prod-ClassDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ClassDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassDeclaration {
+    /// Creates a new `ClassDeclaration` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassDeclaration
+where
+    R: Read,
+{
+    type Output = ClassDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassDeclaration", "Parsing");
+        cursor.expect(Keyword::Class, "class declaration")?;
+        let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let (constructor, elements) =
+            ClassTail::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        Ok(ClassDecl::new(Class::new(name, constructor, elements)))
+    }
+}
+
+/// Class expression parsing.
+///
+/// This parses the class after the `class` keyword.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/class
+/// [spec]: https://tc39.es/ecma262/#prod-ClassExpression
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ClassExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassExpression {
+    /// Creates a new `ClassExpression` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassExpression
+where
+    R: Read,
+{
+    type Output = ClassExpr;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassExpression", "Parsing");
+        let name = match cursor.peek(0)?.map(|token| token.kind()) {
+            Some(TokenKind::Identifier(_))
+            | Some(TokenKind::Keyword(Keyword::Yield))
+            | Some(TokenKind::Keyword(Keyword::Await)) => {
+                Some(BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?)
+            }
+            _ => None,
+        };
+        let (constructor, elements) =
+            ClassTail::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        Ok(ClassExpr::new(Class::new(name, constructor, elements)))
+    }
+}
+
+/// Class tail parsing, which parses the body of the class with its braces.
+///
+/// Class heritage, with `extends`, is not supported yet.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ClassTail
+#[derive(Debug, Clone, Copy)]
+struct ClassTail {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ClassTail {
+    /// Creates a new `ClassTail` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ClassTail
+where
+    R: Read,
+{
+    /// The `constructor` method, and the other methods of the class.
+    type Output = (Option<node::FunctionExpr>, Vec<ClassElement>);
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ClassTail", "Parsing");
+        let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        match token.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {}
+            TokenKind::Keyword(Keyword::Extends) => {
+                return Err(ParseError::unexpected(
+                    token,
+                    "class inheritance is not supported yet",
+                ))
+            }
+            _ => {
+                return Err(ParseError::expected(
+                    vec![TokenKind::Punctuator(Punctuator::OpenBlock)],
+                    token,
+                    "class definition",
+                ))
+            }
+        }
+
+        let mut constructor = None;
+        let mut elements = Vec::new();
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+            if cursor.next_if(Punctuator::Semicolon)?.is_some() {
+                continue;
+            }
+
+            let element_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+            let is_static = match element_token.kind() {
+                TokenKind::Identifier(name) if name.as_ref() == "static" => !matches!(
+                    cursor.peek(1)?.map(|token| token.kind()),
+                    Some(TokenKind::Punctuator(Punctuator::OpenParen))
+                ),
+                _ => false,
+            };
+            if is_static {
+                cursor.next()?.expect("static token disappeared");
+            }
+
+            let name = PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            let next_is_paren = matches!(
+                cursor.peek(0)?.map(|token| token.kind()),
+                Some(TokenKind::Punctuator(Punctuator::OpenParen))
+            );
+            let (kind, name) = match name {
+                node::PropertyName::Literal(ref accessor) if !next_is_paren => {
+                    let kind = match accessor.as_ref() {
+                        "get" => MethodDefinitionKind::Get,
+                        "set" => MethodDefinitionKind::Set,
+                        _ => {
+                            let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                            return Err(ParseError::expected(
+                                vec![TokenKind::Punctuator(Punctuator::OpenParen)],
+                                token,
+                                "class element",
+                            ));
+                        }
+                    };
+                    (
+                        kind,
+                        PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?,
+                    )
+                }
+                name => (MethodDefinitionKind::Ordinary, name),
+            };
+            cursor.expect(Punctuator::OpenParen, "class element")?;
+            let function =
+                MethodDefinition::new(self.allow_yield, self.allow_await, kind).parse(cursor)?;
+
+            let is_named = |expected: &str| matches!(name, node::PropertyName::Literal(ref name) if name.as_ref() == expected);
+            if !is_static && is_named("constructor") {
+                if kind != MethodDefinitionKind::Ordinary {
+                    return Err(ParseError::unexpected(
+                        element_token,
+                        "class constructor can't be a getter or a setter",
+                    ));
+                }
+                if constructor.is_some() {
+                    return Err(ParseError::unexpected(
+                        element_token,
+                        "a class may only have one constructor",
+                    ));
+                }
+                constructor = Some(function);
+            } else if is_static && is_named("prototype") {
+                return Err(ParseError::unexpected(
+                    element_token,
+                    "classes may not have a static property named 'prototype'",
+                ));
+            } else {
+                elements.push(ClassElement::new(is_static, kind, name, function));
+            }
+        }
+
+        Ok((constructor, elements))
+    }
+}
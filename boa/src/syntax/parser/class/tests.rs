@@ -0,0 +1,125 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            Class, ClassDecl, ClassElement, ClassExpr, Declaration, DeclarationList,
+            FormalParameter, FunctionExpr, GetConstField, MethodDefinitionKind, Node, Return,
+        },
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks a class declaration with a constructor, a getter, a static method and a computed
+/// method name.
+#[test]
+fn check_class_declaration() {
+    check_parser(
+        "class A { constructor(a) {} get b() { return 1; } static c() {} ['d']() {} }",
+        vec![ClassDecl::new(Class::new(
+            Box::from("A"),
+            FunctionExpr::new(None, vec![FormalParameter::new("a", None, false)], vec![]),
+            vec![
+                ClassElement::new(
+                    false,
+                    MethodDefinitionKind::Get,
+                    "b",
+                    FunctionExpr::new(
+                        None,
+                        vec![],
+                        vec![Return::new::<_, _, Option<Box<str>>>(Const::from(1), None).into()],
+                    ),
+                ),
+                ClassElement::new(
+                    true,
+                    MethodDefinitionKind::Ordinary,
+                    "c",
+                    FunctionExpr::new(None, vec![], vec![]),
+                ),
+                ClassElement::new(
+                    false,
+                    MethodDefinitionKind::Ordinary,
+                    Node::from(Const::from("d")),
+                    FunctionExpr::new(None, vec![], vec![]),
+                ),
+            ],
+        ))
+        .into()],
+    );
+}
+
+/// Checks that `get`, `set` and `static` can be the names of methods.
+#[test]
+fn check_class_contextual_method_names() {
+    check_parser(
+        "class A { get() {} static set() {} static() {}; static static() {} }",
+        vec![ClassDecl::new(Class::new(
+            Box::from("A"),
+            None,
+            vec![
+                ClassElement::new(
+                    false,
+                    MethodDefinitionKind::Ordinary,
+                    "get",
+                    FunctionExpr::new(None, vec![], vec![]),
+                ),
+                ClassElement::new(
+                    true,
+                    MethodDefinitionKind::Ordinary,
+                    "set",
+                    FunctionExpr::new(None, vec![], vec![]),
+                ),
+                ClassElement::new(
+                    false,
+                    MethodDefinitionKind::Ordinary,
+                    "static",
+                    FunctionExpr::new(None, vec![], vec![]),
+                ),
+                ClassElement::new(
+                    true,
+                    MethodDefinitionKind::Ordinary,
+                    "static",
+                    FunctionExpr::new(None, vec![], vec![]),
+                ),
+            ],
+        ))
+        .into()],
+    );
+}
+
+/// Checks anonymous and named class expressions.
+#[test]
+fn check_class_expression() {
+    check_parser(
+        "let a = class {}, b = class B {};",
+        vec![DeclarationList::Let(
+            vec![
+                Declaration::new(
+                    "a",
+                    Node::from(ClassExpr::new(Class::new(None, None, vec![]))),
+                ),
+                Declaration::new(
+                    "b",
+                    Node::from(ClassExpr::new(Class::new(Box::from("B"), None, vec![]))),
+                ),
+            ]
+            .into(),
+        )
+        .into()],
+    );
+    check_parser(
+        "(class {}).name",
+        vec![GetConstField::new(ClassExpr::new(Class::new(None, None, vec![])), "name").into()],
+    );
+}
+
+/// Checks the early errors of class bodies.
+#[test]
+fn check_invalid_class() {
+    check_invalid("class A { constructor() {} constructor() {} }");
+    check_invalid("class A { get constructor() {} }");
+    check_invalid("class A { static prototype() {} }");
+    check_invalid("class A { b }");
+    check_invalid("class { }");
+    check_invalid("class A extends B {}");
+    check_invalid("class A {} class A {}");
+}
@@ -3,7 +3,7 @@ mod buffered_lexer;
 
 use super::ParseError;
 use crate::syntax::{
-    ast::Punctuator,
+    ast::{node::FormalParameter, Punctuator},
     lexer::{InputElement, Lexer, Position, Token, TokenKind},
 };
 use buffered_lexer::BufferedLexer;
@@ -22,6 +22,20 @@ pub(super) enum SemicolonResult<'s> {
 #[derive(Debug)]
 pub(super) struct Cursor<R> {
     buffered_lexer: BufferedLexer<R>,
+    /// The labels of the enclosing labelled statements, with the label of the iteration statement
+    /// they label, if they label one.
+    labels: Vec<(Box<str>, Option<Box<str>>)>,
+    /// Whether the code being parsed is inside a function other than an arrow function, where
+    /// `new.target` can be used.
+    in_function: bool,
+    /// Whether the code being parsed is inside a method, or an arrow function nested in a method,
+    /// where `super` property accesses can be used.
+    in_method: bool,
+    /// Whether the code being parsed is module code, where `import.meta` can be used.
+    in_module: bool,
+    /// The parameters of the arrow function starting at the given position, parsed as a
+    /// parenthesized expression followed by `=>`.
+    arrow_parameters: Option<(Position, Box<[FormalParameter]>)>,
 }
 
 impl<R> Cursor<R>
@@ -33,6 +47,11 @@ where
     pub(super) fn new(reader: R) -> Self {
         Self {
             buffered_lexer: Lexer::new(reader).into(),
+            labels: Vec::new(),
+            in_function: false,
+            in_method: false,
+            in_module: false,
+            arrow_parameters: None,
         }
     }
 
@@ -71,6 +90,113 @@ where
         self.buffered_lexer.set_strict_mode(strict_mode)
     }
 
+    /// Enters a labelled statement.
+    ///
+    /// If it labels an iteration statement, `iteration_label` is the label of the iteration
+    /// statement itself, which is the innermost of directly nested labels, like `b` in
+    /// `a: b: for (;;) {}`.
+    #[inline]
+    pub(super) fn push_label(&mut self, label: Box<str>, iteration_label: Option<Box<str>>) {
+        self.labels.push((label, iteration_label));
+    }
+
+    /// Leaves the innermost labelled statement.
+    #[inline]
+    pub(super) fn pop_label(&mut self) {
+        self.labels.pop();
+    }
+
+    /// Checks if the statement being parsed is inside a statement labelled `label`.
+    #[inline]
+    pub(super) fn has_label(&self, label: &str) -> bool {
+        self.labels.iter().any(|(name, _)| name.as_ref() == label)
+    }
+
+    /// Gets the label of the iteration statement labelled `label` that encloses the statement
+    /// being parsed, which is the target of a `continue` statement with the label.
+    #[inline]
+    pub(super) fn continue_target(&self, label: &str) -> Option<&str> {
+        self.labels
+            .iter()
+            .rev()
+            .find(|(name, _)| name.as_ref() == label)
+            .and_then(|(_, iteration_label)| iteration_label.as_deref())
+    }
+
+    /// Removes the labels of the enclosing statements, to parse a function body.
+    ///
+    /// Labels can't be referenced from inside a function, so they have to be restored with
+    /// `set_labels` once the body is parsed.
+    #[inline]
+    pub(super) fn take_labels(&mut self) -> Vec<(Box<str>, Option<Box<str>>)> {
+        std::mem::take(&mut self.labels)
+    }
+
+    /// Restores the labels removed by `take_labels`.
+    #[inline]
+    pub(super) fn set_labels(&mut self, labels: Vec<(Box<str>, Option<Box<str>>)>) {
+        self.labels = labels;
+    }
+
+    /// Saves the parameters of an arrow function, which were parsed as a parenthesized expression
+    /// starting at `start` before the `=>` token showed it's an arrow function.
+    #[inline]
+    pub(super) fn set_arrow_parameters(&mut self, start: Position, params: Box<[FormalParameter]>) {
+        self.arrow_parameters = Some((start, params));
+    }
+
+    /// Takes the parameters saved by `set_arrow_parameters`, if the parenthesized expression
+    /// started at `start`.
+    ///
+    /// An arrow function is an assignment expression, so its parameters are only valid if the
+    /// parenthesized expression starts the assignment expression being parsed.
+    #[inline]
+    pub(super) fn take_arrow_parameters(
+        &mut self,
+        start: Position,
+    ) -> Option<Box<[FormalParameter]>> {
+        match self.arrow_parameters.take() {
+            Some((position, params)) if position == start => Some(params),
+            _ => None,
+        }
+    }
+
+    /// Checks if the code being parsed is inside a function other than an arrow function.
+    #[inline]
+    pub(super) fn in_function(&self) -> bool {
+        self.in_function
+    }
+
+    /// Sets whether the code being parsed is inside a function other than an arrow function.
+    #[inline]
+    pub(super) fn set_in_function(&mut self, in_function: bool) {
+        self.in_function = in_function;
+    }
+
+    /// Checks if the code being parsed is inside a method.
+    #[inline]
+    pub(super) fn in_method(&self) -> bool {
+        self.in_method
+    }
+
+    /// Sets whether the code being parsed is inside a method.
+    #[inline]
+    pub(super) fn set_in_method(&mut self, in_method: bool) {
+        self.in_method = in_method;
+    }
+
+    /// Checks if the code being parsed is module code.
+    #[inline]
+    pub(super) fn in_module(&self) -> bool {
+        self.in_module
+    }
+
+    /// Sets whether the code being parsed is module code.
+    #[inline]
+    pub(super) fn set_in_module(&mut self, in_module: bool) {
+        self.in_module = in_module;
+    }
+
     /// Returns an error if the next token is not of kind `kind`.
     #[inline]
     pub(super) fn expect<K>(&mut self, kind: K, context: &'static str) -> Result<Token, ParseError>
@@ -153,6 +279,18 @@ where
         }
     }
 
+    /// Peeks the next token, if there is no line terminator before it.
+    ///
+    /// This is used by the restricted productions, like the postfix `++` and `--`, which can't be
+    /// split across lines.
+    #[inline]
+    pub(super) fn peek_same_line(&mut self) -> Result<Option<&Token>, ParseError> {
+        match self.buffered_lexer.peek(0, false)? {
+            Some(t) if t.kind() == &TokenKind::LineTerminator => Ok(None),
+            t => Ok(t),
+        }
+    }
+
     /// Advance the cursor to the next token and retrieve it, only if it's of `kind` type.
     ///
     /// When the next token is a `kind` token, get the token, otherwise return `None`.
@@ -1,8 +1,11 @@
 //! Error and result implementation for the parser.
 
-use crate::syntax::ast::{position::Position, Node};
+use crate::syntax::ast::{
+    position::{Position, Span},
+    Node,
+};
 use crate::syntax::lexer::{Error as LexError, Token, TokenKind};
-use std::fmt;
+use std::{fmt, io};
 
 /// Result of a parsing operation.
 pub type ParseResult = Result<Node, ParseError>;
@@ -97,6 +100,72 @@ impl ParseError {
         Self::Lex { err: e }
     }
 
+    /// Returns `true` if the error was caused by the end of the source code being reached
+    /// before a complete script could be parsed.
+    ///
+    /// More source code could turn this error into a successful parse.
+    pub fn is_incomplete(&self) -> bool {
+        match self {
+            Self::AbruptEnd
+            | Self::Lex {
+                err: LexError::AbruptEnd(_, _),
+            } => true,
+            Self::Lex {
+                err: LexError::IO(err),
+            } => err.kind() == io::ErrorKind::UnexpectedEof,
+            _ => false,
+        }
+    }
+
+    /// Gets the position of the error in the source code, if it has one.
+    pub(crate) fn position(&self) -> Option<Position> {
+        match self {
+            Self::Expected { found, .. } | Self::Unexpected { found, .. } => {
+                Some(found.span().start())
+            }
+            Self::Lex {
+                err: LexError::Syntax(_, position),
+            }
+            | Self::Lex {
+                err: LexError::AbruptEnd(_, position),
+            }
+            | Self::General { position, .. }
+            | Self::Unimplemented { position, .. } => Some(*position),
+            Self::AbruptEnd | Self::Lex { .. } => None,
+        }
+    }
+
+    /// Changes the position of the error in the source code, if it has one.
+    pub(crate) fn map_position<F>(self, f: F) -> Self
+    where
+        F: Fn(Position) -> Position,
+    {
+        let map_token = |found: Token| {
+            let span = Span::new(f(found.span().start()), f(found.span().end()));
+            Token::new(found.kind().clone(), span)
+        };
+        match self {
+            Self::Expected {
+                expected,
+                found,
+                context,
+            } => Self::expected(expected, map_token(found), context),
+            Self::Unexpected { found, message } => Self::unexpected(map_token(found), message),
+            Self::Lex {
+                err: LexError::Syntax(message, position),
+            } => Self::lex(LexError::Syntax(message, f(position))),
+            Self::Lex {
+                err: LexError::AbruptEnd(message, position),
+            } => Self::lex(LexError::AbruptEnd(message, f(position))),
+            Self::General { message, position } => Self::general(message, f(position)),
+            Self::Unimplemented { message, position } => Self::Unimplemented {
+                message,
+                position: f(position),
+            },
+            e => e,
+        }
+    }
+
     /// Creates a new `Unimplemented` parsing error.
     #[allow(dead_code)]
     pub(super) fn unimplemented(message: &'static str, position: Position) -> Self {
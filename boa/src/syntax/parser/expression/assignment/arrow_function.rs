@@ -11,13 +11,17 @@ use super::AssignmentExpression;
 use crate::{
     syntax::{
         ast::{
-            node::{ArrowFunctionDecl, FormalParameter, Node, Return, StatementList},
+            node::{
+                ArrayBindingPattern, ArrayDecl, ArrowFunctionDecl, AssignmentTarget, Binding,
+                BindingElement, BindingProperty, FormalParameter, Node, Object,
+                ObjectBindingPattern, PropertyDefinition, PropertyName, Return, StatementList,
+            },
             Punctuator,
         },
         lexer::{Error as LexError, Position, TokenKind},
         parser::{
             error::{ErrorContext, ParseError, ParseResult},
-            function::{FormalParameters, FunctionBody},
+            function::{check_duplicate_parameters, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             AllowAwait, AllowIn, AllowYield, Cursor, TokenParser,
         },
@@ -86,6 +90,21 @@ where
             Box::new([FormalParameter::new(param, None, false)])
         };
 
+        self.parse_with_parameters(cursor, params)
+    }
+}
+
+impl ArrowFunction {
+    /// Parses the rest of an arrow function whose parameters have already been parsed, starting
+    /// from the `=>` token.
+    pub(in crate::syntax::parser) fn parse_with_parameters<R>(
+        self,
+        cursor: &mut Cursor<R>,
+        params: Box<[FormalParameter]>,
+    ) -> Result<ArrowFunctionDecl, ParseError>
+    where
+        R: Read,
+    {
         cursor.peek_expect_no_lineterminator(0, "arrow function")?;
 
         cursor.expect(TokenKind::Punctuator(Punctuator::Arrow), "arrow function")?;
@@ -96,10 +115,10 @@ where
         // https://tc39.es/ecma262/#sec-arrow-function-definitions-static-semantics-early-errors
         {
             let lexically_declared_names = body.lexically_declared_names();
-            for param in params.as_ref() {
-                if lexically_declared_names.contains(param.name()) {
+            for param_name in params.iter().flat_map(|param| param.names()) {
+                if lexically_declared_names.contains(param_name) {
                     return Err(ParseError::lex(LexError::Syntax(
-                        format!("Redeclaration of formal parameter `{}`", param.name()).into(),
+                        format!("Redeclaration of formal parameter `{}`", param_name).into(),
                         match cursor.peek(0)? {
                             Some(token) => token.span().end(),
                             None => Position::new(1, 1),
@@ -109,10 +128,154 @@ where
             }
         }
 
+        check_duplicate_parameters(cursor, &params, &body, true)?;
+
         Ok(ArrowFunctionDecl::new(params, body))
     }
 }
 
+/// Converts the expressions of a parenthesized expression, and its trailing rest parameter, to the
+/// parameters of the arrow function they turn out to be the parameters of, if they are valid
+/// parameters.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arrow-function-definitions-static-semantics-early-errors
+pub(in crate::syntax::parser) fn arrow_parameters(
+    expressions: &[Node],
+    rest: Option<FormalParameter>,
+) -> Option<Box<[FormalParameter]>> {
+    expressions
+        .iter()
+        .map(|expression| match expression {
+            Node::Assign(assign) => Some(FormalParameter::new(
+                target_binding(assign.lhs())?,
+                Some(assign.rhs().clone()),
+                false,
+            )),
+            node => Some(FormalParameter::new(binding(node)?, None, false)),
+        })
+        .chain(rest.map(Some))
+        .collect()
+}
+
+/// Converts an expression to a binding element, which is a binding with an optional default
+/// value, like `a`, `[a] = []` or `{ a } = {}`.
+fn binding_element(node: &Node) -> Option<BindingElement> {
+    match node {
+        Node::Assign(assign) => Some(BindingElement::new(
+            target_binding(assign.lhs())?,
+            Some(assign.rhs().clone()),
+        )),
+        node => Some(BindingElement::new(binding(node)?, None)),
+    }
+}
+
+/// Converts an expression to a binding, like `a`, `[a]` or `{ a }`.
+fn binding(node: &Node) -> Option<Binding> {
+    match node {
+        Node::Identifier(name) => Some(Binding::Identifier(name.as_ref().into())),
+        Node::ArrayDecl(array) => array_binding_pattern(array).map(Binding::from),
+        Node::Object(object) => object_binding_pattern(object).map(Binding::from),
+        _ => None,
+    }
+}
+
+/// Converts the target of an assignment to a binding, which is possible if the target only
+/// contains identifiers.
+fn target_binding(target: &AssignmentTarget) -> Option<Binding> {
+    match target {
+        AssignmentTarget::Expression(node) => binding(node),
+        AssignmentTarget::Array(pattern) => {
+            let elements = pattern
+                .elements()
+                .iter()
+                .map(|element| match element {
+                    Some(element) => Some(Some(BindingElement::new(
+                        target_binding(element.target())?,
+                        element.init().cloned(),
+                    ))),
+                    None => Some(None),
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let rest = match pattern.rest() {
+                Some(rest) => Some(target_binding(rest)?),
+                None => None,
+            };
+            Some(ArrayBindingPattern::new(elements, rest).into())
+        }
+        AssignmentTarget::Object(pattern) => {
+            let properties = pattern
+                .properties()
+                .iter()
+                .map(|property| {
+                    Some(BindingProperty::new(
+                        property.name(),
+                        BindingElement::new(
+                            target_binding(property.element().target())?,
+                            property.element().init().cloned(),
+                        ),
+                    ))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            let rest = match pattern.rest() {
+                Some(AssignmentTarget::Expression(Node::Identifier(name))) => {
+                    Some(name.as_ref().into())
+                }
+                Some(_) => return None,
+                None => None,
+            };
+            Some(ObjectBindingPattern::new(properties, rest).into())
+        }
+    }
+}
+
+/// Converts an array literal to an array binding pattern.
+fn array_binding_pattern(array: &ArrayDecl) -> Option<ArrayBindingPattern> {
+    let (elements, rest) = match array.as_ref().split_last() {
+        Some((Node::Spread(spread), elements)) => (elements, Some(binding(spread.val())?)),
+        _ => (array.as_ref(), None),
+    };
+
+    let elements = elements
+        .iter()
+        .map(|element| match element {
+            // Elisions are parsed as empty nodes.
+            Node::Empty => Some(None),
+            node => Some(Some(binding_element(node)?)),
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(ArrayBindingPattern::new(elements, rest))
+}
+
+/// Converts an object literal to an object binding pattern.
+fn object_binding_pattern(object: &Object) -> Option<ObjectBindingPattern> {
+    let (properties, rest) = match object.properties().split_last() {
+        Some((PropertyDefinition::SpreadObject(Node::Identifier(name)), properties)) => {
+            (properties, Some(name.as_ref().into()))
+        }
+        _ => (object.properties(), None),
+    };
+
+    let properties = properties
+        .iter()
+        .map(|property| match property {
+            PropertyDefinition::IdentifierReference(name) => Some(BindingProperty::new(
+                name.clone(),
+                BindingElement::new(Binding::Identifier(name.clone()), None),
+            )),
+            PropertyDefinition::Property(PropertyName::Literal(name), node) => {
+                Some(BindingProperty::new(name.clone(), binding_element(node)?))
+            }
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(ObjectBindingPattern::new(properties, rest))
+}
+
 /// <https://tc39.es/ecma262/#prod-ConciseBody>
 #[derive(Debug, Clone, Copy)]
 struct ConciseBody {
@@ -138,18 +301,24 @@ where
     type Output = StatementList;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+        let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let position = token.span().start();
+        match token.kind() {
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 let _ = cursor.next();
-                let body = FunctionBody::new(false, false).parse(cursor)?;
+                let body = FunctionBody::arrow(false, false).parse(cursor)?;
                 cursor.expect(Punctuator::CloseBlock, "arrow function")?;
                 Ok(body)
             }
-            _ => Ok(StatementList::from(vec![Return::new(
-                ExpressionBody::new(self.allow_in, false).parse(cursor)?,
-                None,
+            _ => Ok(StatementList::with_positions(
+                vec![Return::new(
+                    ExpressionBody::new(self.allow_in, false).parse(cursor)?,
+                    None,
+                )
+                .into()],
+                vec![position],
             )
-            .into()])),
+            .with_strict(cursor.strict_mode())),
         }
     }
 }
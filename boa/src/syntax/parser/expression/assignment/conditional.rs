@@ -71,8 +71,10 @@ where
         if let Some(tok) = cursor.peek(0)? {
             if tok.kind() == &TokenKind::Punctuator(Punctuator::Question) {
                 cursor.next()?.expect("? character vanished"); // Consume the token.
+                                                               // The `in` operator is always allowed between `?` and `:`, even in the head of
+                                                               // a `for` loop, since the `:` ends the expression unambiguously.
                 let then_clause =
-                    AssignmentExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+                    AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                         .parse(cursor)?;
                 cursor.expect(Punctuator::Colon, "conditional expression")?;
 
@@ -63,6 +63,7 @@ where
         matches!(
             tok.kind(),
             TokenKind::Keyword(Keyword::Delete)
+                | TokenKind::Keyword(Keyword::Await)
                 | TokenKind::Keyword(Keyword::Void)
                 | TokenKind::Keyword(Keyword::TypeOf)
                 | TokenKind::Punctuator(Punctuator::Add)
@@ -85,7 +86,18 @@ where
         let _timer = BoaProfiler::global().start_event("ExponentiationExpression", "Parsing");
 
         if is_unary_expression(cursor)? {
-            return UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor);
+            let unary = UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            // A unary expression can't be the base of `**`, like in `-2 ** 2`, since it would be
+            // ambiguous whether the operator applies to the base or to the result.
+            if let Some(tok) = cursor.peek(0)? {
+                if let TokenKind::Punctuator(Punctuator::Exp) = tok.kind() {
+                    return Err(ParseError::unexpected(
+                        tok.clone(),
+                        "unparenthesized unary expression can't appear on the left-hand side of '**'",
+                    ));
+                }
+            }
+            return Ok(unary);
         }
 
         let lhs = UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
@@ -10,14 +10,22 @@
 mod arrow_function;
 mod conditional;
 mod exponentiation;
+mod r#yield;
 
-use self::{arrow_function::ArrowFunction, conditional::ConditionalExpression};
+pub(super) use self::arrow_function::arrow_parameters;
+use self::{
+    arrow_function::ArrowFunction, conditional::ConditionalExpression, r#yield::YieldExpression,
+};
 use crate::syntax::lexer::{Error as LexError, InputElement, TokenKind};
 use crate::{
     syntax::{
         ast::{
-            node::{Assign, BinOp, Node},
-            Keyword, Punctuator,
+            node::{
+                ArrayAssignmentPattern, ArrayDecl, Assign, AssignmentElement, AssignmentProperty,
+                AssignmentTarget, BinOp, Identifier, Node, Object, ObjectAssignmentPattern,
+                PropertyDefinition, PropertyName,
+            },
+            Keyword, Position, Punctuator,
         },
         parser::{AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser},
     },
@@ -32,7 +40,7 @@ use std::io::Read;
 /// This can be one of the following:
 ///
 ///  - [`ConditionalExpression`](../conditional_operator/struct.ConditionalExpression.html)
-///  - `YieldExpression`
+///  - [`YieldExpression`](./yield/struct.YieldExpression.html)
 ///  - [`ArrowFunction`](../../function/arrow_function/struct.ArrowFunction.html)
 ///  - `AsyncArrowFunction`
 ///  - [`LeftHandSideExpression`][lhs] `=` `AssignmentExpression`
@@ -83,7 +91,14 @@ where
         cursor.set_goal(InputElement::Div);
 
         // Arrow function
-        match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+        let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = token.span().start();
+        match token.kind() {
+            // yield a
+            TokenKind::Keyword(Keyword::Yield) if self.allow_yield.0 => {
+                return YieldExpression::new(self.allow_in, self.allow_await).parse(cursor);
+            }
+
             // a=>{}
             TokenKind::Identifier(_)
             | TokenKind::Keyword(Keyword::Yield)
@@ -175,14 +190,32 @@ where
         let mut lhs = ConditionalExpression::new(self.allow_in, self.allow_yield, self.allow_await)
             .parse(cursor)?;
 
+        // (a = 1, { b }) => {}
+        if let Some(params) = cursor.take_arrow_parameters(start) {
+            return ArrowFunction::new(self.allow_in, self.allow_yield, self.allow_await)
+                .parse_with_parameters(cursor, params)
+                .map(Node::ArrowFunctionDecl);
+        }
+
         // Review if we are trying to assign to an invalid left hand side expression.
         // TODO: can we avoid cloning?
         if let Some(tok) = cursor.peek(0)?.cloned() {
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Assign) => {
                     cursor.next()?.expect("= token vanished"); // Consume the token.
-                    if is_assignable(&lhs) {
-                        lhs = Assign::new(lhs, self.parse(cursor)?).into();
+                    check_strict_assignment_target(cursor, &lhs, tok.span().start())?;
+                    let target = match lhs {
+                        Node::ArrayDecl(ref array) => {
+                            array_assignment_pattern(array).map(AssignmentTarget::from)
+                        }
+                        Node::Object(ref object) => {
+                            object_assignment_pattern(object).map(AssignmentTarget::from)
+                        }
+                        _ if is_assignable(&lhs) => Some(lhs.into()),
+                        _ => None,
+                    };
+                    if let Some(target) = target {
+                        lhs = Assign::new(target, self.parse(cursor)?).into();
                     } else {
                         return Err(ParseError::lex(LexError::Syntax(
                             "Invalid left-hand side in assignment".into(),
@@ -192,6 +225,7 @@ where
                 }
                 TokenKind::Punctuator(p) if p.as_binop().is_some() && p != &Punctuator::Comma => {
                     cursor.next()?.expect("token vanished"); // Consume the token.
+                    check_strict_assignment_target(cursor, &lhs, tok.span().start())?;
                     if is_assignable(&lhs) {
                         let binop = p.as_binop().expect("binop disappeared");
                         let expr = self.parse(cursor)?;
@@ -227,3 +261,135 @@ pub(crate) fn is_assignable(node: &Node) -> bool {
             | Node::Object(_)
     )
 }
+
+/// Checks that the target of an assignment, or of an update expression, isn't `eval` or
+/// `arguments` in strict mode code.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-identifiers-static-semantics-early-errors
+pub(super) fn check_strict_assignment_target<R>(
+    cursor: &Cursor<R>,
+    target: &Node,
+    position: Position,
+) -> Result<(), ParseError>
+where
+    R: Read,
+{
+    match target {
+        Node::Identifier(name)
+            if cursor.strict_mode() && matches!(name.as_ref(), "eval" | "arguments") =>
+        {
+            Err(ParseError::lex(LexError::Syntax(
+                format!(
+                    "assignment to '{}' is not allowed in strict mode",
+                    name.as_ref()
+                )
+                .into(),
+                position,
+            )))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Converts an array literal on the left hand side of an assignment to an array assignment
+/// pattern, if it is a valid pattern.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-destructuring-assignment-static-semantics-early-errors
+fn array_assignment_pattern(array: &ArrayDecl) -> Option<ArrayAssignmentPattern> {
+    let (elements, rest) = match array.as_ref().split_last() {
+        Some((Node::Spread(spread), elements)) => {
+            (elements, Some(assignment_target(spread.val())?))
+        }
+        _ => (array.as_ref(), None),
+    };
+
+    let elements = elements
+        .iter()
+        .map(|element| match element {
+            // Elisions are parsed as empty nodes.
+            Node::Empty => Some(None),
+            node => Some(Some(assignment_element(node)?)),
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(ArrayAssignmentPattern::new(elements, rest))
+}
+
+/// Converts an object literal on the left hand side of an assignment to an object assignment
+/// pattern, if it is a valid pattern.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-destructuring-assignment-static-semantics-early-errors
+fn object_assignment_pattern(object: &Object) -> Option<ObjectAssignmentPattern> {
+    let (properties, rest) = match object.properties().split_last() {
+        Some((PropertyDefinition::SpreadObject(node), properties)) => {
+            if !is_simple_assignment_target(node) {
+                return None;
+            }
+            (properties, Some(node.clone().into()))
+        }
+        _ => (object.properties(), None),
+    };
+
+    let properties = properties
+        .iter()
+        .map(|property| match property {
+            PropertyDefinition::IdentifierReference(name) => Some(AssignmentProperty::new(
+                name.clone(),
+                AssignmentElement::new(Identifier::from(name.as_ref()), None),
+            )),
+            PropertyDefinition::Property(PropertyName::Literal(name), node) => Some(
+                AssignmentProperty::new(name.clone(), assignment_element(node)?),
+            ),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+
+    Some(ObjectAssignmentPattern::new(properties, rest))
+}
+
+/// Converts an element of an assignment pattern, with its default value, to an assignment
+/// element, if it is a valid element.
+fn assignment_element(node: &Node) -> Option<AssignmentElement> {
+    match node {
+        Node::Assign(assign) => match assign.lhs() {
+            AssignmentTarget::Expression(node) if !is_simple_assignment_target(node) => None,
+            target => Some(AssignmentElement::new(
+                target.clone(),
+                Some(assign.rhs().clone()),
+            )),
+        },
+        node => Some(AssignmentElement::new(assignment_target(node)?, None)),
+    }
+}
+
+/// Converts a node nested in an assignment pattern to an assignment target, if it is a valid
+/// target.
+fn assignment_target(node: &Node) -> Option<AssignmentTarget> {
+    match node {
+        Node::ArrayDecl(array) => array_assignment_pattern(array).map(AssignmentTarget::from),
+        Node::Object(object) => object_assignment_pattern(object).map(AssignmentTarget::from),
+        node if is_simple_assignment_target(node) => Some(node.clone().into()),
+        _ => None,
+    }
+}
+
+/// Returns true if the node is a reference that can be the target of a destructuring
+/// assignment.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-static-semantics-assignmenttargettype
+#[inline]
+fn is_simple_assignment_target(node: &Node) -> bool {
+    matches!(
+        node,
+        Node::Identifier(_) | Node::GetConstField(_) | Node::GetField(_)
+    )
+}
@@ -0,0 +1,89 @@
+//! Yield expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+//! [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+
+use super::AssignmentExpression;
+use crate::{
+    syntax::{
+        ast::{
+            node::{Node, Yield},
+            Keyword, Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            cursor::{Cursor, SemicolonResult},
+            AllowAwait, AllowIn, ParseResult, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Yield expression parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct YieldExpression {
+    allow_in: AllowIn,
+    allow_await: AllowAwait,
+}
+
+impl YieldExpression {
+    /// Creates a new `YieldExpression` parser.
+    pub(super) fn new<I, A>(allow_in: I, allow_await: A) -> Self
+    where
+        I: Into<AllowIn>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for YieldExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("YieldExpression", "Parsing");
+        cursor.expect(Keyword::Yield, "yield expression")?;
+
+        // The operand must start on the same line as the `yield` keyword.
+        let delegate = match cursor.peek_semicolon()? {
+            SemicolonResult::Found(_) => return Ok(Yield::new::<Node>(None, false).into()),
+            SemicolonResult::NotFound(tok) => match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Mul) => true,
+                // A `yield` without an operand, ending the expression it's part of.
+                TokenKind::Punctuator(Punctuator::CloseParen)
+                | TokenKind::Punctuator(Punctuator::CloseBracket)
+                | TokenKind::Punctuator(Punctuator::Colon)
+                | TokenKind::Punctuator(Punctuator::Comma) => {
+                    return Ok(Yield::new::<Node>(None, false).into())
+                }
+                _ => false,
+            },
+        };
+        if delegate {
+            cursor.next()?.expect("* punctuator token disappeared"); // We move the parser forward.
+        }
+
+        let expr =
+            AssignmentExpression::new(self.allow_in, true, self.allow_await).parse(cursor)?;
+        Ok(Yield::new(Some(expr), delegate).into())
+    }
+}
@@ -67,12 +67,118 @@ where
         let mut lhs = if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
             == &TokenKind::Keyword(Keyword::New)
         {
-            let _ = cursor.next().expect("new keyword disappeared");
-            let lhs = self.parse(cursor)?;
-            let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
-            let call_node = Call::new(lhs, args);
+            let new_token = cursor.next()?.expect("new keyword disappeared");
 
-            Node::from(New::from(call_node))
+            if cursor.next_if(Punctuator::Dot)?.is_some() {
+                // `new.target` is only allowed in functions other than arrow functions.
+                // <https://tc39.es/ecma262/#sec-scripts-static-semantics-early-errors>
+                let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                match token.kind() {
+                    TokenKind::Identifier(name) if name.as_ref() == "target" => {}
+                    _ => {
+                        return Err(ParseError::expected(
+                            vec![TokenKind::identifier("target")],
+                            token,
+                            "new.target",
+                        ))
+                    }
+                }
+                if !cursor.in_function() {
+                    return Err(ParseError::general(
+                        "new.target expression is not allowed here",
+                        new_token.span().start(),
+                    ));
+                }
+
+                Node::NewTarget
+            } else {
+                let lhs = self.parse(cursor)?;
+                let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let call_node = Call::new(lhs, args);
+
+                Node::from(New::from(call_node))
+            }
+        } else if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
+            == &TokenKind::Keyword(Keyword::Import)
+        {
+            let import_token = cursor.next()?.expect("import keyword disappeared");
+            cursor.expect(Punctuator::Dot, "import.meta")?;
+
+            // `import.meta` is only allowed in module code.
+            // <https://tc39.es/ecma262/#sec-scripts-static-semantics-early-errors>
+            let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+            match token.kind() {
+                TokenKind::Identifier(name) if name.as_ref() == "meta" => {}
+                _ => {
+                    return Err(ParseError::expected(
+                        vec![TokenKind::identifier("meta")],
+                        token,
+                        "import.meta",
+                    ))
+                }
+            }
+            if !cursor.in_module() {
+                return Err(ParseError::general(
+                    "Cannot use 'import.meta' outside a module",
+                    import_token.span().start(),
+                ));
+            }
+
+            Node::ImportMeta
+        } else if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
+            == &TokenKind::Keyword(Keyword::Super)
+        {
+            let super_token = cursor.next()?.expect("super keyword disappeared");
+
+            // `super` property accesses are only allowed in methods, and in the arrow functions
+            // nested in methods.
+            // <https://tc39.es/ecma262/#sec-scripts-static-semantics-early-errors>
+            if !cursor.in_method() {
+                return Err(ParseError::general(
+                    "'super' keyword unexpected here",
+                    super_token.span().start(),
+                ));
+            }
+
+            // `super` can only be used as the object of a property access, as `super()` calls
+            // need derived classes.
+            let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+            match token.kind() {
+                TokenKind::Punctuator(Punctuator::Dot) => {
+                    let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                    match token.kind() {
+                        TokenKind::Identifier(name) => {
+                            GetConstField::new(Node::Super, name.clone()).into()
+                        }
+                        TokenKind::Keyword(kw) => {
+                            GetConstField::new(Node::Super, kw.to_string()).into()
+                        }
+                        _ => {
+                            return Err(ParseError::expected(
+                                vec![TokenKind::identifier("identifier")],
+                                token,
+                                "super property",
+                            ));
+                        }
+                    }
+                }
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    let field =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "super property")?;
+                    GetField::new(Node::Super, field).into()
+                }
+                _ => {
+                    return Err(ParseError::expected(
+                        vec![
+                            TokenKind::punctuator(Punctuator::Dot),
+                            TokenKind::punctuator(Punctuator::OpenBracket),
+                        ],
+                        token,
+                        "super property",
+                    ))
+                }
+            }
         } else {
             PrimaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?
         };
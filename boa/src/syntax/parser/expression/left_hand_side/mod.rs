@@ -10,9 +10,10 @@
 mod arguments;
 mod call;
 mod member;
+mod optional;
 mod template;
 
-use self::{call::CallExpression, member::MemberExpression};
+use self::{call::CallExpression, member::MemberExpression, optional::OptionalExpression};
 use crate::{
     profiler::BoaProfiler,
     syntax::{
@@ -64,10 +65,16 @@ where
         cursor.set_goal(InputElement::TemplateTail);
 
         // TODO: Implement NewExpression: new MemberExpression
-        let lhs = MemberExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let mut lhs = MemberExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
         if let Some(tok) = cursor.peek(0)? {
             if tok.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
-                return CallExpression::new(self.allow_yield, self.allow_await, lhs).parse(cursor);
+                lhs = CallExpression::new(self.allow_yield, self.allow_await, lhs).parse(cursor)?;
+            }
+        }
+        if let Some(tok) = cursor.peek(0)? {
+            if tok.kind() == &TokenKind::Punctuator(Punctuator::Optional) {
+                lhs = OptionalExpression::new(self.allow_yield, self.allow_await, lhs)
+                    .parse(cursor)?;
             }
         }
         Ok(lhs)
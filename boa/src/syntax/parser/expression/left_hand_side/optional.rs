@@ -0,0 +1,126 @@
+//! Optional chain parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+//! [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+
+use super::arguments::Arguments;
+use crate::{
+    syntax::{
+        ast::{
+            node::{Node, Optional, OptionalOperation, OptionalOperationKind},
+            Punctuator,
+        },
+        lexer::{Error as LexError, TokenKind},
+        parser::{
+            expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
+            TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Parses an optional expression.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+#[derive(Debug)]
+pub(super) struct OptionalExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    target: Node,
+}
+
+impl OptionalExpression {
+    /// Creates a new `OptionalExpression` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, target: Node) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            target,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for OptionalExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("OptionalExpression", "Parsing");
+
+        let mut chain = Vec::new();
+
+        while let Some(tok) = cursor.peek(0)? {
+            let shorted = match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Optional) => {
+                    cursor.next()?.expect("?. punctuator token disappeared"); // We move the parser forward.
+                    true
+                }
+                TokenKind::Punctuator(Punctuator::Dot) => {
+                    cursor.next()?.expect("dot punctuator token disappeared"); // We move the parser forward.
+                    false
+                }
+                TokenKind::Punctuator(Punctuator::OpenBracket)
+                | TokenKind::Punctuator(Punctuator::OpenParen) => false,
+                TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        "Invalid tagged template on optional chain".into(),
+                        tok.span().start(),
+                    )));
+                }
+                _ => break,
+            };
+
+            let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            let kind = match token.kind() {
+                TokenKind::Punctuator(Punctuator::OpenParen) => OptionalOperationKind::Call(
+                    Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?,
+                ),
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    cursor
+                        .next()?
+                        .expect("open bracket punctuator token disappeared"); // We move the parser forward.
+                    let idx =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "optional chain")?;
+                    OptionalOperationKind::PropertyAccess(idx)
+                }
+                TokenKind::Identifier(name) => {
+                    let name = name.clone();
+                    cursor.next()?.expect("identifier token disappeared");
+                    OptionalOperationKind::SimplePropertyAccess(name)
+                }
+                TokenKind::Keyword(kw) => {
+                    let name = kw.to_string().into();
+                    cursor.next()?.expect("keyword token disappeared");
+                    OptionalOperationKind::SimplePropertyAccess(name)
+                }
+                _ => {
+                    return Err(ParseError::expected(
+                        vec![TokenKind::identifier("identifier")],
+                        cursor.next()?.expect("token disappeared"),
+                        "optional chain",
+                    ));
+                }
+            };
+
+            chain.push(OptionalOperation::new(kind, shorted));
+        }
+
+        Ok(Optional::new(self.target, chain).into())
+    }
+}
@@ -18,7 +18,10 @@ mod update;
 pub(in crate::syntax::parser) mod await_expr;
 
 use self::assignment::ExponentiationExpression;
-pub(super) use self::{assignment::AssignmentExpression, primary::Initializer};
+pub(super) use self::{
+    assignment::AssignmentExpression,
+    primary::{Initializer, MethodDefinition, PropertyName},
+};
 use super::{AllowAwait, AllowIn, AllowYield, Cursor, ParseResult, TokenParser};
 
 use crate::{
@@ -14,7 +14,7 @@ use crate::{
     syntax::{
         ast::{
             node::{ArrayDecl, Node, Spread},
-            Const, Punctuator,
+            Punctuator,
         },
         parser::{
             expression::AssignmentExpression, AllowAwait, AllowYield, Cursor, ParseError,
@@ -66,8 +66,9 @@ where
 
         loop {
             // TODO: Support all features.
+            // Elisions are holes in the array, that are represented by empty nodes.
             while cursor.next_if(Punctuator::Comma)?.is_some() {
-                elements.push(Node::Const(Const::Undefined));
+                elements.push(Node::Empty);
             }
 
             if cursor.next_if(Punctuator::CloseBracket)?.is_some() {
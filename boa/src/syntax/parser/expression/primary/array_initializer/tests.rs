@@ -1,7 +1,10 @@
 // ! Tests for array initializer parsing.
 
 use crate::syntax::{
-    ast::{node::ArrayDecl, Const},
+    ast::{
+        node::{ArrayDecl, Node},
+        Const,
+    },
     parser::tests::check_parser,
 };
 
@@ -14,10 +17,7 @@ fn check_empty() {
 /// Checks an array with empty slot.
 #[test]
 fn check_empty_slot() {
-    check_parser(
-        "[,]",
-        vec![ArrayDecl::from(vec![Const::Undefined.into()]).into()],
-    );
+    check_parser("[,]", vec![ArrayDecl::from(vec![Node::Empty]).into()]);
 }
 
 /// Checks a numeric array.
@@ -56,7 +56,7 @@ fn check_numeric_array_elision() {
         vec![ArrayDecl::from(vec![
             Const::from(1).into(),
             Const::from(2).into(),
-            Const::Undefined.into(),
+            Node::Empty,
             Const::from(3).into(),
         ])
         .into()],
@@ -71,8 +71,8 @@ fn check_numeric_array_repeated_elision() {
         vec![ArrayDecl::from(vec![
             Const::from(1).into(),
             Const::from(2).into(),
-            Const::Undefined.into(),
-            Const::Undefined.into(),
+            Node::Empty,
+            Node::Empty,
             Const::from(3).into(),
         ])
         .into()],
@@ -6,7 +6,7 @@ use crate::{
         ast::{node::AsyncFunctionExpr, Keyword, Punctuator},
         lexer::{Error as LexError, Position, TokenKind},
         parser::{
-            function::{FormalParameters, FunctionBody},
+            function::{check_duplicate_parameters, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             AllowYield, Cursor, ParseError, TokenParser,
         },
@@ -79,10 +79,10 @@ where
         // https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
         {
             let lexically_declared_names = body.lexically_declared_names();
-            for param in params.as_ref() {
-                if lexically_declared_names.contains(param.name()) {
+            for param_name in params.iter().flat_map(|param| param.names()) {
+                if lexically_declared_names.contains(param_name) {
                     return Err(ParseError::lex(LexError::Syntax(
-                        format!("Redeclaration of formal parameter `{}`", param.name()).into(),
+                        format!("Redeclaration of formal parameter `{}`", param_name).into(),
                         match cursor.peek(0)? {
                             Some(token) => token.span().end(),
                             None => Position::new(1, 1),
@@ -92,6 +92,8 @@ where
             }
         }
 
+        check_duplicate_parameters(cursor, &params, &body, false)?;
+
         Ok(AsyncFunctionExpr::new(name, params, body))
     }
 }
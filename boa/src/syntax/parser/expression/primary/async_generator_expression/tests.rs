@@ -0,0 +1,68 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            AsyncGeneratorExpr, AwaitExpr, Declaration, DeclarationList, Identifier, Node,
+            StatementList, Yield,
+        },
+        Const,
+    },
+    parser::tests::check_parser,
+};
+
+/// Checks async generator expression parsing.
+#[test]
+fn check_async_generator_expression() {
+    check_parser(
+        "const gen = async function*() {
+            yield 1;
+        };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "gen",
+                Some(
+                    AsyncGeneratorExpr::new::<Option<Box<str>>, _, StatementList>(
+                        None,
+                        [],
+                        vec![Yield::new(Some(Const::from(1)), false).into()].into(),
+                    )
+                    .into(),
+                ),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks that `await` expressions can be used in the body of a named async generator
+/// expression.
+#[test]
+fn check_async_generator_expression_await() {
+    check_parser(
+        "const gen = async function* name() {
+            yield await a;
+        };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "gen",
+                Some(
+                    AsyncGeneratorExpr::new::<_, _, StatementList>(
+                        Some(Box::from("name")),
+                        [],
+                        vec![Yield::new(
+                            Some(AwaitExpr::from(Node::from(Identifier::from("a")))),
+                            false,
+                        )
+                        .into()]
+                        .into(),
+                    )
+                    .into(),
+                ),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
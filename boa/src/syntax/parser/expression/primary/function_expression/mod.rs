@@ -15,7 +15,7 @@ use crate::{
         ast::{node::FunctionExpr, Keyword, Punctuator},
         lexer::{Error as LexError, Position, TokenKind},
         parser::{
-            function::{FormalParameters, FunctionBody},
+            function::{check_duplicate_parameters, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             Cursor, ParseError, TokenParser,
         },
@@ -74,10 +74,10 @@ where
         // https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
         {
             let lexically_declared_names = body.lexically_declared_names();
-            for param in params.as_ref() {
-                if lexically_declared_names.contains(param.name()) {
+            for param_name in params.iter().flat_map(|param| param.names()) {
+                if lexically_declared_names.contains(param_name) {
                     return Err(ParseError::lex(LexError::Syntax(
-                        format!("Redeclaration of formal parameter `{}`", param.name()).into(),
+                        format!("Redeclaration of formal parameter `{}`", param_name).into(),
                         match cursor.peek(0)? {
                             Some(token) => token.span().end(),
                             None => Position::new(1, 1),
@@ -87,6 +87,8 @@ where
             }
         }
 
+        check_duplicate_parameters(cursor, &params, &body, false)?;
+
         Ok(FunctionExpr::new(name, params, body))
     }
 }
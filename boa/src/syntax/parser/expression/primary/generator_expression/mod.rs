@@ -0,0 +1,95 @@
+//! Generator expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function*
+//! [spec]: https://tc39.es/ecma262/#prod-GeneratorExpression
+
+#[cfg(test)]
+mod tests;
+
+use crate::{
+    syntax::{
+        ast::{node::GeneratorExpr, Keyword, Punctuator},
+        lexer::{Error as LexError, Position, TokenKind},
+        parser::{
+            function::{check_duplicate_parameters, FormalParameters, FunctionBody},
+            statement::BindingIdentifier,
+            Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Generator expression parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/function*
+/// [spec]: https://tc39.es/ecma262/#prod-GeneratorExpression
+#[derive(Debug, Clone, Copy)]
+pub(super) struct GeneratorExpression;
+
+impl<R> TokenParser<R> for GeneratorExpression
+where
+    R: Read,
+{
+    type Output = GeneratorExpr;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("GeneratorExpression", "Parsing");
+        cursor.expect(Punctuator::Mul, "generator expression")?;
+
+        let name = if let Some(token) = cursor.peek(0)? {
+            match token.kind() {
+                TokenKind::Identifier(_)
+                | TokenKind::Keyword(Keyword::Yield)
+                | TokenKind::Keyword(Keyword::Await) => {
+                    Some(BindingIdentifier::new(true, false).parse(cursor)?)
+                }
+                _ => None,
+            }
+        } else {
+            None
+        };
+
+        cursor.expect(Punctuator::OpenParen, "generator expression")?;
+
+        let params = FormalParameters::new(true, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "generator expression")?;
+        cursor.expect(Punctuator::OpenBlock, "generator expression")?;
+
+        let body = FunctionBody::new(true, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseBlock, "generator expression")?;
+
+        // It is a Syntax Error if any element of the BoundNames of FormalParameters
+        // also occurs in the LexicallyDeclaredNames of FunctionBody.
+        // https://tc39.es/ecma262/#sec-generator-function-definitions-static-semantics-early-errors
+        {
+            let lexically_declared_names = body.lexically_declared_names();
+            for param_name in params.iter().flat_map(|param| param.names()) {
+                if lexically_declared_names.contains(param_name) {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        format!("Redeclaration of formal parameter `{}`", param_name).into(),
+                        match cursor.peek(0)? {
+                            Some(token) => token.span().end(),
+                            None => Position::new(1, 1),
+                        },
+                    )));
+                }
+            }
+        }
+
+        check_duplicate_parameters(cursor, &params, &body, false)?;
+
+        Ok(GeneratorExpr::new(name, params, body))
+    }
+}
@@ -0,0 +1,71 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            BinOp, Declaration, DeclarationList, GeneratorExpr, Identifier, StatementList, Yield,
+        },
+        op::NumOp,
+        Const,
+    },
+    parser::tests::check_parser,
+};
+
+/// Checks generator expression parsing.
+#[test]
+fn check_generator_expression() {
+    check_parser(
+        "const gen = function*() {
+            yield 1;
+        };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "gen",
+                Some(
+                    GeneratorExpr::new::<Option<Box<str>>, _, StatementList>(
+                        None,
+                        [],
+                        vec![Yield::new(Some(Const::from(1)), false).into()].into(),
+                    )
+                    .into(),
+                ),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks that the operand of a `yield` expression is an assignment expression.
+#[test]
+fn check_generator_expression_yield_operand() {
+    check_parser(
+        "const gen = function* name() {
+            yield a + 1;
+        };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "gen",
+                Some(
+                    GeneratorExpr::new::<_, _, StatementList>(
+                        Some(Box::from("name")),
+                        [],
+                        vec![Yield::new(
+                            Some(BinOp::new(
+                                NumOp::Add,
+                                Identifier::from("a"),
+                                Const::from(1),
+                            )),
+                            false,
+                        )
+                        .into()]
+                        .into(),
+                    )
+                    .into(),
+                ),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
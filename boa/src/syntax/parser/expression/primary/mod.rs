@@ -9,7 +9,9 @@
 
 mod array_initializer;
 mod async_function_expression;
+mod async_generator_expression;
 mod function_expression;
+mod generator_expression;
 mod object_initializer;
 mod template;
 #[cfg(test)]
@@ -17,24 +19,28 @@ mod tests;
 
 use self::{
     array_initializer::ArrayLiteral, async_function_expression::AsyncFunctionExpression,
-    function_expression::FunctionExpression, object_initializer::ObjectLiteral,
+    async_generator_expression::AsyncGeneratorExpression, function_expression::FunctionExpression,
+    generator_expression::GeneratorExpression, object_initializer::ObjectLiteral,
 };
-use super::Expression;
+use super::{assignment::arrow_parameters, AssignmentExpression};
 use crate::{
     profiler::BoaProfiler,
     syntax::{
         ast::{
-            node::{Call, Identifier, New, Node},
-            Const, Keyword, Punctuator,
+            node::{BinOp, Call, Identifier, New, Node},
+            op, Const, Keyword, Position, Punctuator,
         },
-        lexer::{token::Numeric, InputElement, TokenKind},
+        lexer::{token::Numeric, InputElement, Token, TokenKind},
         parser::{
-            expression::primary::template::TemplateLiteral, AllowAwait, AllowYield, Cursor,
-            ParseError, ParseResult, TokenParser,
+            class::ClassExpression, expression::primary::template::TemplateLiteral,
+            function::FunctionRestParameter, AllowAwait, AllowYield, Cursor, ParseError,
+            ParseResult, TokenParser,
         },
     },
 };
-pub(in crate::syntax::parser) use object_initializer::Initializer;
+pub(in crate::syntax::parser) use object_initializer::{
+    Initializer, MethodDefinition, PropertyName,
+};
 
 use std::io::Read;
 
@@ -66,6 +72,84 @@ impl PrimaryExpression {
     }
 }
 
+impl PrimaryExpression {
+    /// Parses a parenthesized expression, after its `(` token at `start`.
+    ///
+    /// The expression can also turn out to be the parameters of an arrow function, when it's
+    /// followed by `=>`. The parameters are then saved in the cursor, for the assignment
+    /// expression starting with them to parse the arrow function.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-CoverParenthesizedExpressionAndArrowParameterList
+    fn parse_parenthesized<R>(self, cursor: &mut Cursor<R>, start: Position) -> ParseResult
+    where
+        R: Read,
+    {
+        cursor.set_goal(InputElement::RegExp);
+
+        let mut expressions = Vec::new();
+        let mut rest = None;
+        let mut trailing_comma = false;
+        loop {
+            match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+                TokenKind::Punctuator(Punctuator::CloseParen)
+                    if expressions.is_empty() || trailing_comma =>
+                {
+                    break
+                }
+                TokenKind::Punctuator(Punctuator::Spread) => {
+                    rest = Some(
+                        FunctionRestParameter::new(self.allow_yield, self.allow_await)
+                            .parse(cursor)?,
+                    );
+                    break;
+                }
+                _ => {}
+            }
+
+            expressions.push(
+                AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                    .parse(cursor)?,
+            );
+            trailing_comma = cursor.next_if(Punctuator::Comma)?.is_some();
+            if !trailing_comma {
+                break;
+            }
+        }
+        cursor.expect(Punctuator::CloseParen, "primary expression")?;
+
+        if matches!(
+            cursor.peek_same_line()?.map(Token::kind),
+            Some(TokenKind::Punctuator(Punctuator::Arrow))
+        ) {
+            let params = arrow_parameters(&expressions, rest)
+                .ok_or_else(|| ParseError::general("invalid arrow function parameters", start))?;
+            cursor.set_arrow_parameters(start, params);
+            return Ok(Node::Empty);
+        }
+
+        // An empty list, a trailing comma or a rest parameter can only be arrow function
+        // parameters.
+        if expressions.is_empty() || trailing_comma || rest.is_some() {
+            return match cursor.next()? {
+                Some(token) => Err(ParseError::expected(
+                    vec![TokenKind::Punctuator(Punctuator::Arrow)],
+                    token,
+                    "arrow function",
+                )),
+                None => Err(ParseError::AbruptEnd),
+            };
+        }
+
+        Ok(expressions
+            .into_iter()
+            .reduce(|lhs, rhs| BinOp::new(op::BinOp::Comma, lhs, rhs).into())
+            .expect("the list of expressions is not empty"))
+    }
+}
+
 impl<R> TokenParser<R> for PrimaryExpression
 where
     R: Read,
@@ -79,18 +163,36 @@ where
 
         match tok.kind() {
             TokenKind::Keyword(Keyword::This) => Ok(Node::This),
+            TokenKind::Keyword(Keyword::Function)
+                if matches!(
+                    cursor.peek(0)?.map(Token::kind),
+                    Some(TokenKind::Punctuator(Punctuator::Mul))
+                ) =>
+            {
+                GeneratorExpression.parse(cursor).map(Node::from)
+            }
             TokenKind::Keyword(Keyword::Function) => {
                 FunctionExpression.parse(cursor).map(Node::from)
             }
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassExpression::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
+            // The `*` of an async generator follows `async function`.
+            TokenKind::Keyword(Keyword::Async)
+                if matches!(
+                    cursor.peek(1)?.map(Token::kind),
+                    Some(TokenKind::Punctuator(Punctuator::Mul))
+                ) =>
+            {
+                AsyncGeneratorExpression.parse(cursor).map(Node::from)
+            }
             TokenKind::Keyword(Keyword::Async) => AsyncFunctionExpression::new(self.allow_yield)
                 .parse(cursor)
                 .map(Node::from),
             TokenKind::Punctuator(Punctuator::OpenParen) => {
-                cursor.set_goal(InputElement::RegExp);
-                let expr =
-                    Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
-                cursor.expect(Punctuator::CloseParen, "primary expression")?;
-                Ok(expr)
+                self.parse_parenthesized(cursor, tok.span().start())
             }
             TokenKind::Punctuator(Punctuator::OpenBracket) => {
                 cursor.set_goal(InputElement::RegExp);
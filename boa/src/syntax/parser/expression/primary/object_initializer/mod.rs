@@ -18,7 +18,7 @@ use crate::{
         },
         parser::{
             expression::AssignmentExpression,
-            function::{FormalParameters, FunctionBody},
+            function::{check_duplicate_parameters, FormalParameters, FunctionBody},
             AllowAwait, AllowIn, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
@@ -134,7 +134,21 @@ where
             return Ok(node::PropertyDefinition::SpreadObject(node));
         }
 
-        let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
+        // IdentifierReference
+        let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        if let TokenKind::Identifier(ident) = token.kind() {
+            let ident = ident.clone();
+            if matches!(
+                cursor.peek(1)?.map(|t| t.kind()),
+                Some(TokenKind::Punctuator(Punctuator::Comma))
+                    | Some(TokenKind::Punctuator(Punctuator::CloseBlock))
+            ) {
+                cursor.next()?.expect("identifier token disappeared");
+                return Ok(node::PropertyDefinition::identifier_reference(ident));
+            }
+        }
+
+        let prop_name = PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
         if cursor.next_if(Punctuator::Colon)?.is_some() {
             let val = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                 .parse(cursor)?;
@@ -144,21 +158,53 @@ where
         // TODO GeneratorMethod
         // https://tc39.es/ecma262/#prod-GeneratorMethod
 
-        if prop_name.as_str() == "async" {
-            // TODO - AsyncMethod.
-            // https://tc39.es/ecma262/#prod-AsyncMethod
+        let accessor = match &prop_name {
+            node::PropertyName::Literal(name) => {
+                if name.as_ref() == "async" {
+                    // TODO - AsyncMethod.
+                    // https://tc39.es/ecma262/#prod-AsyncMethod
 
-            // TODO - AsyncGeneratorMethod
-            // https://tc39.es/ecma262/#prod-AsyncGeneratorMethod
-        }
+                    // TODO - AsyncGeneratorMethod
+                    // https://tc39.es/ecma262/#prod-AsyncGeneratorMethod
+                }
+
+                match name.as_ref() {
+                    "get" => Some(MethodDefinitionKind::Get),
+                    "set" => Some(MethodDefinitionKind::Set),
+                    _ => None,
+                }
+            }
+            node::PropertyName::Computed(_) => None,
+        };
 
         if cursor
             .next_if(TokenKind::Punctuator(Punctuator::OpenParen))?
             .is_some()
-            || ["get", "set"].contains(&prop_name.as_str())
         {
-            return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name)
-                .parse(cursor);
+            let function = MethodDefinition::new(
+                self.allow_yield,
+                self.allow_await,
+                MethodDefinitionKind::Ordinary,
+            )
+            .parse(cursor)?;
+            return Ok(node::PropertyDefinition::method_definition(
+                MethodDefinitionKind::Ordinary,
+                prop_name,
+                function,
+            ));
+        }
+
+        if let Some(kind) = accessor {
+            let prop_name = PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            cursor.expect(
+                TokenKind::Punctuator(Punctuator::OpenParen),
+                "property method definition",
+            )?;
+            let function =
+                MethodDefinition::new(self.allow_yield, self.allow_await, kind).parse(cursor)?;
+            return Ok(node::PropertyDefinition::method_definition(
+                kind, prop_name, function,
+            ));
         }
 
         let pos = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
@@ -166,31 +212,96 @@ where
     }
 }
 
+/// Parses a property name.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-PropertyName
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct PropertyName {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl PropertyName {
+    /// Creates a new `PropertyName` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for PropertyName
+where
+    R: Read,
+{
+    type Output = node::PropertyName;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("PropertyName", "Parsing");
+
+        // ComputedPropertyName
+        if cursor.next_if(Punctuator::OpenBracket)?.is_some() {
+            let node = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                .parse(cursor)?;
+            cursor.expect(Punctuator::CloseBracket, "computed property name")?;
+            return Ok(node.into());
+        }
+
+        // LiteralPropertyName
+        let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        match token.kind() {
+            TokenKind::Identifier(_)
+            | TokenKind::Keyword(_)
+            | TokenKind::BooleanLiteral(_)
+            | TokenKind::NullLiteral
+            | TokenKind::StringLiteral(_)
+            | TokenKind::NumericLiteral(_) => Ok(node::PropertyName::Literal(
+                token.kind().to_string().into_boxed_str(),
+            )),
+            _ => Err(ParseError::unexpected(token, "expected property name")),
+        }
+    }
+}
+
 /// Parses a method definition.
 ///
+/// This parses the parameters and the body of the method, after its name and the opening
+/// parenthesis of its parameters, into the function of the method.
+///
 /// More information:
 ///  - [ECMAScript specification][spec]
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
-#[derive(Debug, Clone)]
-struct MethodDefinition {
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct MethodDefinition {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
-    identifier: String,
+    kind: MethodDefinitionKind,
 }
 
 impl MethodDefinition {
     /// Creates a new `MethodDefinition` parser.
-    fn new<Y, A, I>(allow_yield: Y, allow_await: A, identifier: I) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        kind: MethodDefinitionKind,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
-        I: Into<String>,
     {
         Self {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
-            identifier: identifier.into(),
+            kind,
         }
     }
 }
@@ -199,74 +310,48 @@ impl<R> TokenParser<R> for MethodDefinition
 where
     R: Read,
 {
-    type Output = node::PropertyDefinition;
+    type Output = FunctionExpr;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("MethodDefinition", "Parsing");
 
-        let (methodkind, prop_name, params) = match self.identifier.as_str() {
-            idn @ "get" | idn @ "set"
-                if matches!(
-                    cursor.peek(0)?.map(|t| t.kind()),
-                    Some(&TokenKind::Identifier(_))
-                        | Some(&TokenKind::Keyword(_))
-                        | Some(&TokenKind::BooleanLiteral(_))
-                        | Some(&TokenKind::NullLiteral)
-                        | Some(&TokenKind::NumericLiteral(_))
-                ) =>
-            {
-                let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
-                cursor.expect(
-                    TokenKind::Punctuator(Punctuator::OpenParen),
-                    "property method definition",
-                )?;
-                let first_param = cursor.peek(0)?.expect("current token disappeared").clone();
-                let params = FormalParameters::new(false, false).parse(cursor)?;
-                cursor.expect(Punctuator::CloseParen, "method definition")?;
-                if idn == "get" {
-                    if !params.is_empty() {
-                        return Err(ParseError::unexpected(
-                            first_param,
-                            "getter functions must have no arguments",
-                        ));
-                    }
-                    (MethodDefinitionKind::Get, prop_name, params)
-                } else {
-                    if params.len() != 1 {
-                        return Err(ParseError::unexpected(
-                            first_param,
-                            "setter functions must have one argument",
-                        ));
-                    }
-                    (MethodDefinitionKind::Set, prop_name, params)
-                }
+        let first_param = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+        // The default values of the parameters can use `super` too.
+        let in_method = cursor.in_method();
+        cursor.set_in_method(true);
+        let params = FormalParameters::new(false, false).parse(cursor);
+        cursor.set_in_method(in_method);
+        let params = params?;
+        cursor.expect(Punctuator::CloseParen, "method definition")?;
+        match self.kind {
+            MethodDefinitionKind::Get if !params.is_empty() => {
+                return Err(ParseError::unexpected(
+                    first_param,
+                    "getter functions must have no arguments",
+                ));
             }
-            prop_name => {
-                let params = FormalParameters::new(false, false).parse(cursor)?;
-                cursor.expect(Punctuator::CloseParen, "method definition")?;
-                (
-                    MethodDefinitionKind::Ordinary,
-                    prop_name.to_string(),
-                    params,
-                )
+            MethodDefinitionKind::Set if params.len() != 1 => {
+                return Err(ParseError::unexpected(
+                    first_param,
+                    "setter functions must have one argument",
+                ));
             }
-        };
+            _ => {}
+        }
 
         cursor.expect(
             TokenKind::Punctuator(Punctuator::OpenBlock),
             "property method definition",
         )?;
-        let body = FunctionBody::new(false, false).parse(cursor)?;
+        let body = FunctionBody::method(false, false).parse(cursor)?;
         cursor.expect(
             TokenKind::Punctuator(Punctuator::CloseBlock),
             "property method definition",
         )?;
 
-        Ok(node::PropertyDefinition::method_definition(
-            methodkind,
-            prop_name,
-            FunctionExpr::new(None, params, body),
-        ))
+        check_duplicate_parameters(cursor, &params, &body, true)?;
+
+        Ok(FunctionExpr::new(None, params, body))
     }
 }
 
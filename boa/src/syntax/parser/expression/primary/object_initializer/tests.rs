@@ -1,8 +1,8 @@
 use crate::syntax::{
     ast::{
         node::{
-            Declaration, DeclarationList, FormalParameter, FunctionExpr, MethodDefinitionKind,
-            Object, PropertyDefinition,
+            Declaration, DeclarationList, FormalParameter, FunctionExpr, Identifier,
+            MethodDefinitionKind, Node, Object, PropertyDefinition,
         },
         Const,
     },
@@ -203,3 +203,37 @@ fn check_object_short_function_set() {
         .into()],
     );
 }
+
+#[test]
+fn check_object_computed_and_shorthand_properties() {
+    let object_properties = vec![
+        PropertyDefinition::identifier_reference("a"),
+        PropertyDefinition::property(Node::from(Identifier::from("b")), Const::from(1)),
+        PropertyDefinition::property("c d", Const::from(2)),
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Get,
+            Node::from(Const::from("e")),
+            FunctionExpr::new(None, vec![], vec![]),
+        ),
+        PropertyDefinition::identifier_reference("f"),
+    ];
+
+    check_parser(
+        "const x = {
+            a,
+            [b]: 1,
+            'c d': 2,
+            get ['e']() {},
+            f
+        };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "x",
+                Some(Object::from(object_properties).into()),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
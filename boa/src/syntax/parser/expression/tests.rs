@@ -1,7 +1,11 @@
 use crate::syntax::{
-    ast::op::{AssignOp, BitOp, CompOp, LogOp, NumOp},
+    ast::op::{self, AssignOp, BitOp, CompOp, LogOp, NumOp},
     ast::{
-        node::{BinOp, Identifier},
+        node::{
+            ArrayAssignmentPattern, ArrayDecl, Assign, AssignmentElement, AssignmentProperty,
+            BinOp, ConditionalOp, GetConstField, Identifier, ObjectAssignmentPattern, Optional,
+            OptionalOperation, OptionalOperationKind, UnaryOp,
+        },
         Const,
     },
     parser::tests::{check_invalid, check_parser},
@@ -82,6 +86,37 @@ fn check_complex_numeric_operations() {
     );
 }
 
+/// Checks that the exponentiation operator is right-associative.
+#[test]
+fn check_exponentiation_is_right_associative() {
+    check_parser(
+        "2 ** 3 ** 2",
+        vec![BinOp::new(
+            NumOp::Exp,
+            Const::from(2),
+            BinOp::new(NumOp::Exp, Const::from(3), Const::from(2)),
+        )
+        .into()],
+    );
+    check_parser(
+        "(-2) ** 2",
+        vec![BinOp::new(
+            NumOp::Exp,
+            UnaryOp::new(op::UnaryOp::Minus, Const::from(2)),
+            Const::from(2),
+        )
+        .into()],
+    );
+}
+
+/// Checks that an unparenthesized unary expression can't be the base of an exponentiation.
+#[test]
+fn check_exponentiation_with_unary_base() {
+    check_invalid("-2 ** 2");
+    check_invalid("typeof a ** 2");
+    check_invalid("a = !b ** 2");
+}
+
 /// Checks bitwise operations.
 #[test]
 fn check_bitwise_operations() {
@@ -281,3 +316,161 @@ fn check_logical_expressions() {
     check_invalid("a ?? b || c");
     check_invalid("a || b ?? c");
 }
+
+/// Checks optional chain parsing.
+#[test]
+fn check_optional_chain() {
+    check_parser(
+        "a?.b.c?.[d](e)",
+        vec![Optional::new(
+            Identifier::from("a"),
+            vec![
+                OptionalOperation::new(
+                    OptionalOperationKind::SimplePropertyAccess("b".into()),
+                    true,
+                ),
+                OptionalOperation::new(
+                    OptionalOperationKind::SimplePropertyAccess("c".into()),
+                    false,
+                ),
+                OptionalOperation::new(
+                    OptionalOperationKind::PropertyAccess(Identifier::from("d").into()),
+                    true,
+                ),
+                OptionalOperation::new(
+                    OptionalOperationKind::Call(vec![Identifier::from("e").into()].into()),
+                    false,
+                ),
+            ],
+        )
+        .into()],
+    );
+
+    check_parser(
+        "a?.5:1",
+        vec![ConditionalOp::new(Identifier::from("a"), Const::from(0.5), Const::from(1)).into()],
+    );
+
+    check_invalid("a?.b = 1");
+    check_invalid("a?.`b`");
+    check_invalid("new a?.b()");
+}
+
+/// Checks array destructuring assignment parsing.
+#[test]
+fn check_array_assignment_pattern() {
+    check_parser(
+        "[a, , b.c = 1, [d], ...e] = f",
+        vec![Assign::new(
+            ArrayAssignmentPattern::new(
+                vec![
+                    Some(AssignmentElement::new(Identifier::from("a"), None)),
+                    None,
+                    Some(AssignmentElement::new(
+                        GetConstField::new(Identifier::from("b"), "c"),
+                        Some(Const::from(1).into()),
+                    )),
+                    Some(AssignmentElement::new(
+                        ArrayAssignmentPattern::new(
+                            vec![Some(AssignmentElement::new(Identifier::from("d"), None))],
+                            None,
+                        ),
+                        None,
+                    )),
+                ],
+                Some(Identifier::from("e").into()),
+            ),
+            Identifier::from("f"),
+        )
+        .into()],
+    );
+
+    check_parser(
+        "[a, b] = [b, a]",
+        vec![Assign::new(
+            ArrayAssignmentPattern::new(
+                vec![
+                    Some(AssignmentElement::new(Identifier::from("a"), None)),
+                    Some(AssignmentElement::new(Identifier::from("b"), None)),
+                ],
+                None,
+            ),
+            ArrayDecl::from(vec![
+                Identifier::from("b").into(),
+                Identifier::from("a").into(),
+            ]),
+        )
+        .into()],
+    );
+
+    check_invalid("[1] = a");
+    check_invalid("[a + b] = c");
+    check_invalid("[f()] = a");
+    check_invalid("[...a, b] = c");
+    check_invalid("[...a = 1] = b");
+    check_invalid("[a] += b");
+}
+
+/// Checks object destructuring assignment parsing, nested in array patterns.
+#[test]
+fn check_object_assignment_pattern() {
+    check_parser(
+        "[{c: y}, [{d}] = e] = f",
+        vec![Assign::new(
+            ArrayAssignmentPattern::new(
+                vec![
+                    Some(AssignmentElement::new(
+                        ObjectAssignmentPattern::new(
+                            vec![AssignmentProperty::new(
+                                "c",
+                                AssignmentElement::new(Identifier::from("y"), None),
+                            )],
+                            None,
+                        ),
+                        None,
+                    )),
+                    Some(AssignmentElement::new(
+                        ArrayAssignmentPattern::new(
+                            vec![Some(AssignmentElement::new(
+                                ObjectAssignmentPattern::new(
+                                    vec![AssignmentProperty::new(
+                                        "d",
+                                        AssignmentElement::new(Identifier::from("d"), None),
+                                    )],
+                                    None,
+                                ),
+                                None,
+                            ))],
+                            None,
+                        ),
+                        Some(Identifier::from("e").into()),
+                    )),
+                ],
+                None,
+            ),
+            Identifier::from("f"),
+        )
+        .into()],
+    );
+
+    check_parser(
+        "({a: b.c, ...d} = e)",
+        vec![Assign::new(
+            ObjectAssignmentPattern::new(
+                vec![AssignmentProperty::new(
+                    "a",
+                    AssignmentElement::new(GetConstField::new(Identifier::from("b"), "c"), None),
+                )],
+                Some(Identifier::from("d").into()),
+            ),
+            Identifier::from("e"),
+        )
+        .into()],
+    );
+
+    check_invalid("[{a: 1}] = b");
+    check_invalid("[{a: b + c}] = d");
+    check_invalid("({...a, b} = c)");
+    check_invalid("({...[a]} = b)");
+    check_invalid("[{a() {}}] = b");
+}
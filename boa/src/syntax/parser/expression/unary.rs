@@ -17,8 +17,8 @@ use crate::{
         },
         lexer::{Error as LexError, TokenKind},
         parser::{
-            expression::update::UpdateExpression, AllowAwait, AllowYield, Cursor, ParseError,
-            ParseResult, TokenParser,
+            expression::{await_expr::AwaitExpression, update::UpdateExpression},
+            AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
 };
@@ -103,6 +103,11 @@ where
                 cursor.next()?.expect("! token vanished"); // Consume the token.
                 Ok(node::UnaryOp::new(UnaryOp::Not, self.parse(cursor)?).into())
             }
+            TokenKind::Keyword(Keyword::Await) if self.allow_await.0 => {
+                AwaitExpression::new(self.allow_yield)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             _ => UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor),
         }
     }
@@ -5,7 +5,7 @@
 //!
 //! [spec]: https://tc39.es/ecma262/#sec-update-expressions
 
-use super::left_hand_side::LeftHandSideExpression;
+use super::{assignment::check_strict_assignment_target, left_hand_side::LeftHandSideExpression};
 use crate::{
     profiler::BoaProfiler,
     syntax::{
@@ -58,33 +58,44 @@ where
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
         match tok.kind() {
             TokenKind::Punctuator(Punctuator::Inc) => {
-                cursor.next()?.expect("Punctuator::Inc token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::IncrementPre,
-                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                )
-                .into());
+                let position = cursor
+                    .next()?
+                    .expect("Punctuator::Inc token disappeared")
+                    .span()
+                    .start();
+                let target =
+                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                check_strict_assignment_target(cursor, &target, position)?;
+                return Ok(node::UnaryOp::new(UnaryOp::IncrementPre, target).into());
             }
             TokenKind::Punctuator(Punctuator::Dec) => {
-                cursor.next()?.expect("Punctuator::Dec token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::DecrementPre,
-                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                )
-                .into());
+                let position = cursor
+                    .next()?
+                    .expect("Punctuator::Dec token disappeared")
+                    .span()
+                    .start();
+                let target =
+                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                check_strict_assignment_target(cursor, &target, position)?;
+                return Ok(node::UnaryOp::new(UnaryOp::DecrementPre, target).into());
             }
             _ => {}
         }
 
         let lhs = LeftHandSideExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
-        if let Some(tok) = cursor.peek(0)? {
+        // A line terminator before a postfix `++` or `--` ends the expression, and the operator
+        // becomes the prefix operator of the next one.
+        if let Some(tok) = cursor.peek_same_line()? {
+            let position = tok.span().start();
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Inc) => {
                     cursor.next()?.expect("Punctuator::Inc token disappeared");
+                    check_strict_assignment_target(cursor, &lhs, position)?;
                     return Ok(node::UnaryOp::new(UnaryOp::IncrementPost, lhs).into());
                 }
                 TokenKind::Punctuator(Punctuator::Dec) => {
                     cursor.next()?.expect("Punctuator::Dec token disappeared");
+                    check_strict_assignment_target(cursor, &lhs, position)?;
                     return Ok(node::UnaryOp::new(UnaryOp::DecrementPost, lhs).into());
                 }
                 _ => {}
@@ -16,10 +16,10 @@ use crate::{
             node::{self},
             Punctuator,
         },
-        lexer::{InputElement, TokenKind},
+        lexer::{Error as LexError, InputElement, Position, TokenKind},
         parser::{
             expression::Initializer,
-            statement::{BindingIdentifier, StatementList},
+            statement::{Binding, StatementList},
             AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
         },
     },
@@ -100,6 +100,13 @@ where
             }
 
             cursor.expect(Punctuator::Comma, "parameter list")?;
+
+            // A trailing comma can follow the last parameter, if it's not a rest parameter.
+            if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
+                == &TokenKind::Punctuator(Punctuator::CloseParen)
+            {
+                break;
+            }
         }
 
         Ok(params.into_boxed_slice())
@@ -114,7 +121,7 @@ where
 ///
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/rest_parameters
 /// [spec]: https://tc39.es/ecma262/#prod-FunctionRestParameter
-type FunctionRestParameter = BindingRestElement;
+pub(in crate::syntax::parser) type FunctionRestParameter = BindingRestElement;
 
 /// Rest parameter parsing.
 ///
@@ -125,14 +132,14 @@ type FunctionRestParameter = BindingRestElement;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/rest_parameters
 /// [spec]: https://tc39.es/ecma262/#prod-BindingRestElement
 #[derive(Debug, Clone, Copy)]
-struct BindingRestElement {
+pub(in crate::syntax::parser) struct BindingRestElement {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl BindingRestElement {
     /// Creates a new `BindingRestElement` parser.
-    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -154,8 +161,7 @@ where
         let _timer = BoaProfiler::global().start_event("BindingRestElement", "Parsing");
         cursor.expect(Punctuator::Spread, "rest parameter")?;
 
-        let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-        // TODO: BindingPattern
+        let param = Binding::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         Ok(Self::Output::new(param, None, true))
     }
@@ -198,9 +204,7 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("FormalParameter", "Parsing");
 
-        // TODO: BindingPattern
-
-        let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let param = Binding::new(self.allow_yield, self.allow_await).parse(cursor)?;
 
         let init = if let Some(t) = cursor.peek(0)? {
             // Check that this is an initilizer before attempting parse.
@@ -238,6 +242,8 @@ const FUNCTION_BREAK_TOKENS: [TokenKind; 1] = [TokenKind::Punctuator(Punctuator:
 pub(in crate::syntax::parser) struct FunctionStatementList {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
+    arrow: bool,
+    method: bool,
 }
 
 impl FunctionStatementList {
@@ -250,6 +256,34 @@ impl FunctionStatementList {
         Self {
             allow_yield: allow_yield.into(),
             allow_await: allow_await.into(),
+            arrow: false,
+            method: false,
+        }
+    }
+
+    /// Creates a new `FunctionStatementList` parser for the body of an arrow function, which
+    /// shares `new.target` with the enclosing code.
+    pub(in crate::syntax::parser) fn arrow<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            arrow: true,
+            ..Self::new(allow_yield, allow_await)
+        }
+    }
+
+    /// Creates a new `FunctionStatementList` parser for the body of a method, where `super`
+    /// property accesses can be used.
+    pub(in crate::syntax::parser) fn method<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            method: true,
+            ..Self::new(allow_yield, allow_await)
         }
     }
 }
@@ -264,10 +298,16 @@ where
         let _timer = BoaProfiler::global().start_event("FunctionStatementList", "Parsing");
 
         let global_strict_mode = cursor.strict_mode();
+        let labels = cursor.take_labels();
+        let in_function = cursor.in_function();
+        let in_method = cursor.in_method();
         if let Some(tk) = cursor.peek(0)? {
             match tk.kind() {
                 TokenKind::Punctuator(Punctuator::CloseBlock) => {
-                    return Ok(Vec::new().into());
+                    cursor.set_labels(labels);
+                    let mut stmlist = node::StatementList::from(Vec::new());
+                    stmlist.set_strict(global_strict_mode);
+                    return Ok(stmlist);
                 }
                 TokenKind::StringLiteral(string) if string.as_ref() == "use strict" => {
                     cursor.set_strict_mode(true);
@@ -276,6 +316,8 @@ where
             }
         }
 
+        cursor.set_in_function(in_function || !self.arrow);
+        cursor.set_in_method(if self.arrow { in_method } else { self.method });
         let stmlist = StatementList::new(
             self.allow_yield,
             self.allow_await,
@@ -283,10 +325,57 @@ where
             true,
             &FUNCTION_BREAK_TOKENS,
         )
-        .parse(cursor);
+        .parse(cursor)
+        .map(|mut stmlist| {
+            stmlist.set_strict(cursor.strict_mode());
+            stmlist
+        });
 
-        // Reset strict mode back to the global scope.
+        // Reset strict mode, labels, `new.target` and `super` back to the enclosing scope.
         cursor.set_strict_mode(global_strict_mode);
+        cursor.set_labels(labels);
+        cursor.set_in_function(in_function);
+        cursor.set_in_method(in_method);
         stmlist
     }
 }
+
+/// Checks that no name is bound twice by the formal parameters of a function.
+///
+/// Duplicate parameter names are only allowed in sloppy mode functions with a simple parameter
+/// list, so `unique_parameters` has to be set for the functions that never allow them, like arrow
+/// functions and methods.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
+pub(in crate::syntax::parser) fn check_duplicate_parameters<R>(
+    cursor: &mut Cursor<R>,
+    params: &[node::FormalParameter],
+    body: &node::StatementList,
+    unique_parameters: bool,
+) -> Result<(), ParseError>
+where
+    R: Read,
+{
+    if !unique_parameters && !body.strict() && params.iter().all(node::FormalParameter::is_simple) {
+        return Ok(());
+    }
+
+    let mut names = Vec::new();
+    for name in params.iter().flat_map(|param| param.names()) {
+        if names.contains(&name) {
+            return Err(ParseError::lex(LexError::Syntax(
+                format!("Duplicate formal parameter `{}`", name).into(),
+                match cursor.peek(0)? {
+                    Some(token) => token.span().end(),
+                    None => Position::new(1, 1),
+                },
+            )));
+        }
+        names.push(name);
+    }
+
+    Ok(())
+}
@@ -1,10 +1,11 @@
 use crate::syntax::{
     ast::node::{
-        ArrowFunctionDecl, BinOp, Declaration, DeclarationList, FormalParameter, FunctionDecl,
-        Identifier, Node, Return,
+        ArrayBindingPattern, ArrowFunctionDecl, BinOp, BindingElement, BindingProperty,
+        Declaration, DeclarationList, FormalParameter, FunctionDecl, Identifier, Node,
+        ObjectBindingPattern, Return,
     },
-    ast::op::NumOp,
-    parser::tests::check_parser,
+    ast::{op::NumOp, Const},
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks basic function declaration parsing.
@@ -391,3 +392,109 @@ fn check_arrow_assignment_3arg_nobrackets() {
         .into()],
     );
 }
+
+/// Checks an arrow function with an object pattern parameter.
+#[test]
+fn check_arrow_object_pattern() {
+    check_parser(
+        "({a}) => a",
+        vec![ArrowFunctionDecl::new(
+            vec![FormalParameter::new(
+                ObjectBindingPattern::new(
+                    vec![BindingProperty::new("a", BindingElement::new("a", None))],
+                    None,
+                ),
+                None,
+                false,
+            )],
+            vec![Return::new(Identifier::from("a"), None).into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks an arrow function with an array pattern parameter.
+#[test]
+fn check_arrow_array_pattern() {
+    check_parser(
+        "([a]) => a",
+        vec![ArrowFunctionDecl::new(
+            vec![FormalParameter::new(
+                ArrayBindingPattern::new(vec![Some(BindingElement::new("a", None))], None),
+                None,
+                false,
+            )],
+            vec![Return::new(Identifier::from("a"), None).into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks an arrow function with a parameter with a default value.
+#[test]
+fn check_arrow_default_parameter() {
+    check_parser(
+        "(a = 1) => a",
+        vec![ArrowFunctionDecl::new(
+            vec![FormalParameter::new(
+                "a",
+                Some(Const::from(1).into()),
+                false,
+            )],
+            vec![Return::new(Identifier::from("a"), None).into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks an arrow function with a pattern parameter followed by a rest parameter.
+#[test]
+fn check_arrow_pattern_and_rest() {
+    check_parser(
+        "({a}, ...r) => a",
+        vec![ArrowFunctionDecl::new(
+            vec![
+                FormalParameter::new(
+                    ObjectBindingPattern::new(
+                        vec![BindingProperty::new("a", BindingElement::new("a", None))],
+                        None,
+                    ),
+                    None,
+                    false,
+                ),
+                FormalParameter::new("r", None, true),
+            ],
+            vec![Return::new(Identifier::from("a"), None).into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks an arrow function with a trailing comma after the parameters.
+#[test]
+fn check_arrow_trailing_comma() {
+    check_parser(
+        "(a = 1, b,) => a",
+        vec![ArrowFunctionDecl::new(
+            vec![
+                FormalParameter::new("a", Some(Const::from(1).into()), false),
+                FormalParameter::new("b", None, false),
+            ],
+            vec![Return::new(Identifier::from("a"), None).into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks that expressions that can't be parameters are not parsed as arrow functions.
+#[test]
+fn check_arrow_invalid_parameters() {
+    check_invalid("(a.b) => a");
+    check_invalid("([a + 1]) => a");
+    check_invalid("({a: 1}) => a");
+    check_invalid("x + ({a}) => a");
+    check_invalid("({a})\n=> a");
+    check_invalid("({a}, ...r)");
+    check_invalid("(a, b,)");
+    check_invalid("(...a, b) => a");
+}
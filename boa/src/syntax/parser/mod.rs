@@ -1,5 +1,6 @@
 //! Boa parser implementation.
 
+mod class;
 mod cursor;
 pub mod error;
 mod expression;
@@ -15,6 +16,18 @@ use cursor::Cursor;
 
 use std::io::Read;
 
+#[cfg(test)]
+thread_local! {
+    /// The number of scripts parsed in this thread, to test that parsing is not repeated.
+    static PARSE_COUNT: std::cell::Cell<usize> = std::cell::Cell::new(0);
+}
+
+/// Gets the number of scripts parsed in this thread.
+#[cfg(test)]
+pub(crate) fn parse_count() -> usize {
+    PARSE_COUNT.with(std::cell::Cell::get)
+}
+
 /// Trait implemented by parsers.
 ///
 /// This makes it possible to abstract over the underlying implementation of a parser.
@@ -102,8 +115,20 @@ impl<R> Parser<R> {
     where
         R: Read,
     {
+        #[cfg(test)]
+        PARSE_COUNT.with(|count| count.set(count.get() + 1));
+
         Script.parse(&mut self.cursor)
     }
+
+    /// Parses the full input as a module, which is always strict mode code and can contain
+    /// `import` and `export` declarations.
+    pub fn parse_module(&mut self) -> Result<StatementList, ParseError>
+    where
+        R: Read,
+    {
+        Module.parse(&mut self.cursor)
+    }
 }
 
 /// Parses a full script.
@@ -130,7 +155,9 @@ where
                     }
                     _ => {}
                 }
-                ScriptBody.parse(cursor)
+                let mut body = ScriptBody.parse(cursor)?;
+                body.set_strict(cursor.strict_mode());
+                Ok(body)
             }
             None => Ok(StatementList::from(Vec::new())),
         }
@@ -156,3 +183,27 @@ where
         self::statement::StatementList::new(false, false, false, true, &[]).parse(cursor)
     }
 }
+
+/// Parses a full module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-Module
+#[derive(Debug, Clone, Copy)]
+pub struct Module;
+
+impl<R> TokenParser<R> for Module
+where
+    R: Read,
+{
+    type Output = StatementList;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        cursor.set_strict_mode(true);
+        cursor.set_in_module(true);
+        let mut body = self::statement::ModuleItemList.parse(cursor)?;
+        body.set_strict(true);
+        Ok(body)
+    }
+}
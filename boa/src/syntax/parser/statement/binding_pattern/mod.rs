@@ -0,0 +1,290 @@
+//! Destructuring binding pattern parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+//! [spec]: https://tc39.es/ecma262/#sec-destructuring-binding-patterns
+
+#[cfg(test)]
+mod tests;
+
+use super::BindingIdentifier;
+use crate::{
+    syntax::{
+        ast::{node, Punctuator},
+        lexer::TokenKind,
+        parser::{
+            expression::Initializer, AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+use std::io::Read;
+
+/// Binding parsing, which is a binding identifier or a binding pattern.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ForBinding
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct Binding {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl Binding {
+    /// Creates a new `Binding` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for Binding
+where
+    R: Read,
+{
+    type Output = node::Binding;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("Binding", "Parsing");
+
+        match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock) => Ok(ObjectBindingPattern::new(
+                self.allow_yield,
+                self.allow_await,
+            )
+            .parse(cursor)?
+            .into()),
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                Ok(ArrayBindingPattern::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)?
+                    .into())
+            }
+            _ => Ok(BindingIdentifier::new(self.allow_yield, self.allow_await)
+                .parse(cursor)?
+                .into()),
+        }
+    }
+}
+
+/// Object binding pattern parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+#[derive(Debug, Clone, Copy)]
+struct ObjectBindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ObjectBindingPattern {
+    /// Creates a new `ObjectBindingPattern` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ObjectBindingPattern
+where
+    R: Read,
+{
+    type Output = node::BindingPattern;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ObjectBindingPattern", "Parsing");
+        cursor.expect(Punctuator::OpenBlock, "object binding pattern")?;
+
+        let mut properties = Vec::new();
+        let mut rest = None;
+
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+
+            // The rest property must be the last one, without a trailing comma.
+            if cursor.next_if(Punctuator::Spread)?.is_some() {
+                rest =
+                    Some(BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?);
+                cursor.expect(Punctuator::CloseBlock, "object binding pattern")?;
+                break;
+            }
+
+            let is_single_name = cursor.peek(1)?.ok_or(ParseError::AbruptEnd)?.kind()
+                != &TokenKind::Punctuator(Punctuator::Colon);
+            if is_single_name {
+                // SingleNameBinding: `name` or `name = init`
+                let name =
+                    BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let init = parse_initializer(cursor, self.allow_yield, self.allow_await)?;
+                properties.push(node::BindingProperty::new(
+                    name.clone(),
+                    node::BindingElement::new(name, init),
+                ));
+            } else {
+                // PropertyName `:` BindingElement
+                let name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
+                cursor.expect(Punctuator::Colon, "object binding pattern")?;
+                let element =
+                    BindingElement::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                properties.push(node::BindingProperty::new(name, element));
+            }
+
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+            cursor.expect(Punctuator::Comma, "object binding pattern")?;
+        }
+
+        Ok(node::ObjectBindingPattern::new(properties, rest).into())
+    }
+}
+
+/// Array binding pattern parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+#[derive(Debug, Clone, Copy)]
+struct ArrayBindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ArrayBindingPattern {
+    /// Creates a new `ArrayBindingPattern` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ArrayBindingPattern
+where
+    R: Read,
+{
+    type Output = node::BindingPattern;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ArrayBindingPattern", "Parsing");
+        cursor.expect(Punctuator::OpenBracket, "array binding pattern")?;
+
+        let mut elements = Vec::new();
+        let mut rest = None;
+
+        loop {
+            if cursor.next_if(Punctuator::CloseBracket)?.is_some() {
+                break;
+            }
+
+            // Elision
+            if cursor.next_if(Punctuator::Comma)?.is_some() {
+                elements.push(None);
+                continue;
+            }
+
+            // The rest element must be the last one, without a trailing comma.
+            if cursor.next_if(Punctuator::Spread)?.is_some() {
+                rest = Some(Binding::new(self.allow_yield, self.allow_await).parse(cursor)?);
+                cursor.expect(Punctuator::CloseBracket, "array binding pattern")?;
+                break;
+            }
+
+            elements.push(Some(
+                BindingElement::new(self.allow_yield, self.allow_await).parse(cursor)?,
+            ));
+
+            if cursor.next_if(Punctuator::CloseBracket)?.is_some() {
+                break;
+            }
+            cursor.expect(Punctuator::Comma, "array binding pattern")?;
+        }
+
+        Ok(node::ArrayBindingPattern::new(elements, rest).into())
+    }
+}
+
+/// Binding element parsing, which is a binding with an optional initializer.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingElement
+#[derive(Debug, Clone, Copy)]
+struct BindingElement {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl BindingElement {
+    /// Creates a new `BindingElement` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for BindingElement
+where
+    R: Read,
+{
+    type Output = node::BindingElement;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("BindingElement", "Parsing");
+
+        let binding = Binding::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let init = parse_initializer(cursor, self.allow_yield, self.allow_await)?;
+
+        Ok(node::BindingElement::new(binding, init))
+    }
+}
+
+/// Parses the initializer of a binding, if there is one.
+fn parse_initializer<R>(
+    cursor: &mut Cursor<R>,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+) -> Result<Option<node::Node>, ParseError>
+where
+    R: Read,
+{
+    match cursor.peek(0)? {
+        Some(tok) if tok.kind() == &TokenKind::Punctuator(Punctuator::Assign) => Ok(Some(
+            Initializer::new(true, allow_yield, allow_await).parse(cursor)?,
+        )),
+        _ => Ok(None),
+    }
+}
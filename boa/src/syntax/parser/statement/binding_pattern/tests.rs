@@ -0,0 +1,133 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            ArrayBindingPattern, BindingElement, BindingProperty, FormalParameter, FunctionDecl,
+            ObjectBindingPattern,
+        },
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks object binding pattern parsing.
+#[test]
+fn check_object_binding_pattern() {
+    check_parser(
+        "function f({ a, b: c, d = 1, 'e': { f }, ...g }) {}",
+        vec![FunctionDecl::new(
+            Box::from("f"),
+            vec![FormalParameter::new(
+                ObjectBindingPattern::new(
+                    vec![
+                        BindingProperty::new("a", BindingElement::new("a", None)),
+                        BindingProperty::new("b", BindingElement::new("c", None)),
+                        BindingProperty::new(
+                            "d",
+                            BindingElement::new("d", Some(Const::from(1).into())),
+                        ),
+                        BindingProperty::new(
+                            "e",
+                            BindingElement::new(
+                                ObjectBindingPattern::new(
+                                    vec![BindingProperty::new("f", BindingElement::new("f", None))],
+                                    None,
+                                ),
+                                None,
+                            ),
+                        ),
+                    ],
+                    Some("g".into()),
+                ),
+                None,
+                false,
+            )],
+            vec![],
+        )
+        .into()],
+    );
+}
+
+/// Checks array binding pattern parsing, with elisions.
+#[test]
+fn check_array_binding_pattern() {
+    check_parser(
+        "function f([, a, , b = 1, [c], ], ...[d, ...e]) {}",
+        vec![FunctionDecl::new(
+            Box::from("f"),
+            vec![
+                FormalParameter::new(
+                    ArrayBindingPattern::new(
+                        vec![
+                            None,
+                            Some(BindingElement::new("a", None)),
+                            None,
+                            Some(BindingElement::new("b", Some(Const::from(1).into()))),
+                            Some(BindingElement::new(
+                                ArrayBindingPattern::new(
+                                    vec![Some(BindingElement::new("c", None))],
+                                    None,
+                                ),
+                                None,
+                            )),
+                        ],
+                        None,
+                    ),
+                    None,
+                    false,
+                ),
+                FormalParameter::new(
+                    ArrayBindingPattern::new(
+                        vec![Some(BindingElement::new("d", None))],
+                        Some("e".into()),
+                    ),
+                    None,
+                    true,
+                ),
+            ],
+            vec![],
+        )
+        .into()],
+    );
+}
+
+/// Checks that the patterns of parameters can have a default value.
+#[test]
+fn check_pattern_parameter_initializer() {
+    check_parser(
+        "function f({} = 1, [] = 2) {}",
+        vec![FunctionDecl::new(
+            Box::from("f"),
+            vec![
+                FormalParameter::new(
+                    ObjectBindingPattern::new(vec![], None),
+                    Some(Const::from(1).into()),
+                    false,
+                ),
+                FormalParameter::new(
+                    ArrayBindingPattern::new(vec![], None),
+                    Some(Const::from(2).into()),
+                    false,
+                ),
+            ],
+            vec![],
+        )
+        .into()],
+    );
+}
+
+/// Checks that rest elements must be last, without a trailing comma.
+#[test]
+fn check_invalid_rest() {
+    check_invalid("function f({ ...a, b }) {}");
+    check_invalid("function f({ ...a, }) {}");
+    check_invalid("function f([...a, b]) {}");
+    check_invalid("function f([...a,]) {}");
+    check_invalid("function f({ ...{ a } }) {}");
+}
+
+/// Checks that the bound names of patterns can't be redeclared in the function body.
+#[test]
+fn check_invalid_redeclaration() {
+    check_invalid("function f({ a }) { let a; }");
+    check_invalid("function f([[a]]) { const a = 1; }");
+}
@@ -74,7 +74,11 @@ where
 
             None
         } else {
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
             let label = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            if !cursor.has_label(&label) {
+                return Err(ParseError::general("undefined break target", position));
+            }
             cursor.expect_semicolon("break statement")?;
 
             Some(label)
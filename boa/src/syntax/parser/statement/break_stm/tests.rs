@@ -3,7 +3,7 @@ use crate::syntax::{
         node::{Block, Break, Node, WhileLoop},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 #[test]
@@ -42,14 +42,13 @@ fn inline_block_semicolon_insertion() {
 #[test]
 fn new_line_semicolon_insertion() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             break test
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_while_loop(
+            "test",
             Block::from(vec![Break::new("test").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
@@ -68,39 +67,36 @@ fn inline_block() {
 #[test]
 fn new_line_block() {
     check_parser(
-        "while (true) {
+        "test: while (true) {
             break test;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_while_loop(
+            "test",
             Block::from(vec![Break::new("test").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
 #[test]
 fn reserved_label() {
     check_parser(
-        "while (true) {
+        "await: while (true) {
             break await;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_while_loop(
+            "await",
             Block::from(vec![Break::new("await").into()]),
-        )
-        .into()],
+        )],
     );
 
     check_parser(
-        "while (true) {
+        "yield: while (true) {
             break yield;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
+        vec![labelled_while_loop(
+            "yield",
             Block::from(vec![Break::new("yield").into()]),
-        )
-        .into()],
+        )],
     );
 }
 
@@ -131,3 +127,17 @@ fn new_line_block_empty_semicolon_insertion() {
         .into()],
     );
 }
+
+/// Creates a `while (true)` loop with the given label and body.
+fn labelled_while_loop(label: &str, body: Block) -> Node {
+    let mut while_loop = WhileLoop::new(Const::from(true), body);
+    while_loop.set_label(label.into());
+    while_loop.into()
+}
+
+#[test]
+fn undefined_label() {
+    check_invalid("while (true) { break test; }");
+    check_invalid("test: { while (true) { break other; } }");
+    check_invalid("test: while (true) { function f() { break test; } }");
+}
@@ -73,7 +73,14 @@ where
 
             None
         } else {
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
             let label = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            // The label has to be on an iteration statement. Directly nested labels all label
+            // the same statement, which only carries the innermost one.
+            let label = match cursor.continue_target(&label) {
+                Some(target) => Box::from(target),
+                None => return Err(ParseError::general("undefined continue target", position)),
+            };
             cursor.expect_semicolon("continue statement")?;
 
             Some(label)
@@ -0,0 +1,41 @@
+use crate::syntax::{
+    ast::{
+        node::{AsyncGeneratorDecl, AwaitExpr, Identifier, Node, Yield},
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Async generator declaration parsing.
+#[test]
+fn async_generator_declaration() {
+    check_parser(
+        "async function* gen() {}",
+        vec![AsyncGeneratorDecl::new(Box::from("gen"), vec![], vec![]).into()],
+    );
+}
+
+/// Async generator declaration parsing with `yield` and `await` expressions.
+#[test]
+fn async_generator_declaration_yield_await() {
+    check_parser(
+        "async function* gen() { yield; yield await 1; yield* a; }",
+        vec![AsyncGeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![
+                Yield::new::<Node>(None, false).into(),
+                Yield::new(Some(AwaitExpr::from(Node::from(Const::from(1)))), false).into(),
+                Yield::new(Some(Identifier::from("a")), true).into(),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// `yield` and `await` are not identifiers in the bodies of async generators.
+#[test]
+fn async_generator_declaration_keywords() {
+    check_invalid("async function* gen() { var yield = 1; }");
+    check_invalid("async function* gen() { var await = 1; }");
+}
@@ -4,6 +4,7 @@ mod tests;
 use crate::syntax::{
     ast::{node::FunctionDecl, Keyword, Punctuator},
     parser::{
+        function::check_duplicate_parameters,
         function::FormalParameters,
         function::FunctionBody,
         statement::{BindingIdentifier, LexError, Position},
@@ -63,7 +64,7 @@ where
         cursor.expect(Punctuator::CloseParen, "function declaration")?;
         cursor.expect(Punctuator::OpenBlock, "function declaration")?;
 
-        let body = FunctionBody::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let body = FunctionBody::new(false, false).parse(cursor)?;
 
         cursor.expect(Punctuator::CloseBlock, "function declaration")?;
 
@@ -72,10 +73,10 @@ where
         // https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
         {
             let lexically_declared_names = body.lexically_declared_names();
-            for param in params.as_ref() {
-                if lexically_declared_names.contains(param.name()) {
+            for param_name in params.iter().flat_map(|param| param.names()) {
+                if lexically_declared_names.contains(param_name) {
                     return Err(ParseError::lex(LexError::Syntax(
-                        format!("Redeclaration of formal parameter `{}`", param.name()).into(),
+                        format!("Redeclaration of formal parameter `{}`", param_name).into(),
                         match cursor.peek(0)? {
                             Some(token) => token.span().end(),
                             None => Position::new(1, 1),
@@ -85,6 +86,8 @@ where
             }
         }
 
+        check_duplicate_parameters(cursor, &params, &body, false)?;
+
         Ok(FunctionDecl::new(name, params, body))
     }
 }
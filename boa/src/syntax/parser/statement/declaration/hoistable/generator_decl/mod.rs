@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod tests;
+
+use crate::syntax::{
+    ast::{node::GeneratorDecl, Keyword, Punctuator},
+    parser::{
+        function::check_duplicate_parameters,
+        function::FormalParameters,
+        function::FunctionBody,
+        statement::{BindingIdentifier, LexError, Position},
+        AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, TokenParser,
+    },
+};
+use std::io::Read;
+
+/// Generator declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/function*
+/// [spec]: https://tc39.es/ecma262/#prod-GeneratorDeclaration
+#[derive(Debug, Clone, Copy)]
+pub(super) struct GeneratorDeclaration {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    is_default: AllowDefault,
+}
+
+impl GeneratorDeclaration {
+    /// Creates a new `GeneratorDeclaration` parser.
+    pub(super) fn new<Y, A, D>(allow_yield: Y, allow_await: A, is_default: D) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+        D: Into<AllowDefault>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            is_default: is_default.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for GeneratorDeclaration
+where
+    R: Read,
+{
+    type Output = GeneratorDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        cursor.expect(Keyword::Function, "generator declaration")?;
+        cursor.expect(Punctuator::Mul, "generator declaration")?;
+
+        // TODO: If self.is_default, then this can be empty.
+        let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        cursor.expect(Punctuator::OpenParen, "generator declaration")?;
+
+        let params = FormalParameters::new(true, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseParen, "generator declaration")?;
+        cursor.expect(Punctuator::OpenBlock, "generator declaration")?;
+
+        let body = FunctionBody::new(true, false).parse(cursor)?;
+
+        cursor.expect(Punctuator::CloseBlock, "generator declaration")?;
+
+        // It is a Syntax Error if any element of the BoundNames of FormalParameters
+        // also occurs in the LexicallyDeclaredNames of FunctionBody.
+        // https://tc39.es/ecma262/#sec-generator-function-definitions-static-semantics-early-errors
+        {
+            let lexically_declared_names = body.lexically_declared_names();
+            for param_name in params.iter().flat_map(|param| param.names()) {
+                if lexically_declared_names.contains(param_name) {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        format!("Redeclaration of formal parameter `{}`", param_name).into(),
+                        match cursor.peek(0)? {
+                            Some(token) => token.span().end(),
+                            None => Position::new(1, 1),
+                        },
+                    )));
+                }
+            }
+        }
+
+        check_duplicate_parameters(cursor, &params, &body, false)?;
+
+        Ok(GeneratorDecl::new(name, params, body))
+    }
+}
@@ -0,0 +1,45 @@
+use crate::syntax::{
+    ast::{
+        node::{FunctionDecl, GeneratorDecl, Identifier, Node, Yield},
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Generator declaration parsing.
+#[test]
+fn generator_declaration() {
+    check_parser(
+        "function* gen() {}",
+        vec![GeneratorDecl::new(Box::from("gen"), vec![], vec![]).into()],
+    );
+}
+
+/// Generator declaration parsing with `yield` expressions.
+#[test]
+fn generator_declaration_yield() {
+    check_parser(
+        "function* gen() { yield; yield 1; yield* a; }",
+        vec![GeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![
+                Yield::new::<Node>(None, false).into(),
+                Yield::new(Some(Const::from(1)), false).into(),
+                Yield::new(Some(Identifier::from("a")), true).into(),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// `yield` is an identifier outside of generators, but not in their bodies.
+#[test]
+fn generator_declaration_yield_identifier() {
+    check_parser(
+        "function yield() {}",
+        vec![FunctionDecl::new(Box::from("yield"), vec![], vec![]).into()],
+    );
+    check_invalid("function* gen() { var yield = 1; }");
+    check_invalid("function* gen() { function f() { yield 1; } }");
+}
@@ -9,15 +9,19 @@
 mod tests;
 
 mod async_function_decl;
+mod async_generator_decl;
 mod function_decl;
+mod generator_decl;
 
 use async_function_decl::AsyncFunctionDeclaration;
+use async_generator_decl::AsyncGeneratorDeclaration;
 use function_decl::FunctionDeclaration;
+use generator_decl::GeneratorDeclaration;
 
 use crate::{
     syntax::{
-        ast::{Keyword, Node},
-        lexer::TokenKind,
+        ast::{Keyword, Node, Punctuator},
+        lexer::{Token, TokenKind},
         parser::{
             AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
@@ -63,14 +67,33 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("HoistableDeclaration", "Parsing");
+        let is_generator = matches!(
+            cursor.peek(1)?.map(Token::kind),
+            Some(TokenKind::Punctuator(Punctuator::Mul))
+        );
+        // The `*` of an async generator follows `async function`.
+        let is_async_generator = matches!(
+            cursor.peek(2)?.map(Token::kind),
+            Some(TokenKind::Punctuator(Punctuator::Mul))
+        );
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         match tok.kind() {
+            TokenKind::Keyword(Keyword::Function) if is_generator => {
+                GeneratorDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Keyword(Keyword::Function) => {
                 FunctionDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
                     .parse(cursor)
                     .map(Node::from)
             }
+            TokenKind::Keyword(Keyword::Async) if is_async_generator => {
+                AsyncGeneratorDeclaration::new(self.allow_yield, self.allow_await, false)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Keyword(Keyword::Async) => {
                 AsyncFunctionDeclaration::new(self.allow_yield, self.allow_await, false)
                     .parse(cursor)
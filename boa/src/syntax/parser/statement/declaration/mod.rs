@@ -18,7 +18,9 @@ use crate::syntax::lexer::TokenKind;
 use crate::{
     syntax::{
         ast::{Keyword, Node},
-        parser::{AllowAwait, AllowYield, Cursor, ParseError, TokenParser},
+        parser::{
+            class::ClassDeclaration, AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
+        },
     },
     BoaProfiler,
 };
@@ -66,6 +68,11 @@ where
             TokenKind::Keyword(Keyword::Function) | TokenKind::Keyword(Keyword::Async) => {
                 HoistableDeclaration::new(self.allow_yield, self.allow_await, false).parse(cursor)
             }
+            TokenKind::Keyword(Keyword::Class) => {
+                ClassDeclaration::new(self.allow_yield, self.allow_await)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Keyword(Keyword::Const) | TokenKind::Keyword(Keyword::Let) => {
                 LexicalDeclaration::new(
                     true,
@@ -70,6 +70,10 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("ForStatement", "Parsing");
         cursor.expect(Keyword::For, "for statement")?;
+
+        // `for await` loops are only allowed in async functions, and must be `for...of` loops.
+        let r#await = self.allow_await.0 && cursor.next_if(Keyword::Await)?.is_some();
+
         cursor.expect(Punctuator::OpenParen, "for statement")?;
 
         let init = match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
@@ -89,7 +93,9 @@ where
         };
 
         match cursor.peek(0)? {
-            Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::In) && init.is_some() => {
+            Some(tok)
+                if tok.kind() == &TokenKind::Keyword(Keyword::In) && init.is_some() && !r#await =>
+            {
                 let _ = cursor.next();
                 let expr =
                     Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
@@ -105,7 +111,19 @@ where
                 cursor.expect(Punctuator::CloseParen, "for of statement")?;
                 let body = Statement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)?;
-                return Ok(ForOfLoop::new(init.unwrap(), iterable, body).into());
+                return Ok(if r#await {
+                    ForOfLoop::new_await(init.unwrap(), iterable, body)
+                } else {
+                    ForOfLoop::new(init.unwrap(), iterable, body)
+                }
+                .into());
+            }
+            Some(tok) if r#await => {
+                return Err(ParseError::expected(
+                    vec![TokenKind::Keyword(Keyword::Of)],
+                    tok.clone(),
+                    "for await...of statement",
+                ))
             }
             _ => {}
         }
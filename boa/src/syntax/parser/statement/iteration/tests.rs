@@ -1,13 +1,13 @@
 use crate::syntax::{
     ast::{
         node::{
-            field::GetConstField, BinOp, Block, Break, Call, Declaration, DeclarationList,
-            DoWhileLoop, Identifier, UnaryOp, WhileLoop,
+            field::GetConstField, AsyncFunctionDecl, BinOp, Block, Break, Call, Declaration,
+            DeclarationList, DoWhileLoop, ForOfLoop, Identifier, UnaryOp, WhileLoop,
         },
         op::{self, AssignOp, CompOp},
         Const,
     },
-    parser::tests::check_parser,
+    parser::{tests::check_parser, Parser},
 };
 
 /// Checks do-while statement parsing.
@@ -93,6 +93,22 @@ fn check_do_while_semicolon_insertion_no_space() {
     );
 }
 
+/// Checks do-while statement parsing with a body that isn't a block.
+#[test]
+fn check_do_while_statement_body() {
+    check_parser(
+        "do a++; while (false) a;",
+        vec![
+            DoWhileLoop::new(
+                UnaryOp::new(op::UnaryOp::IncrementPost, Identifier::from("a")),
+                Const::from(false),
+            )
+            .into(),
+            Identifier::from("a").into(),
+        ],
+    );
+}
+
 /// Checks parsing of a while statement which is seperated out with line terminators.
 #[test]
 fn while_spaces() {
@@ -138,3 +154,35 @@ fn do_while_spaces() {
         .into()],
     );
 }
+
+/// Checks `for await...of` statement parsing.
+#[test]
+fn for_await_of() {
+    check_parser(
+        "async function f() { for await (const x of xs) {} }",
+        vec![AsyncFunctionDecl::new(
+            Box::from("f"),
+            vec![],
+            vec![ForOfLoop::new_await(
+                DeclarationList::Const(vec![Declaration::new("x", None)].into()),
+                Identifier::from("xs"),
+                Block::from(vec![]),
+            )
+            .into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks that `for await` is only allowed for `for...of` loops in async functions.
+#[test]
+fn for_await_errors() {
+    for src in &[
+        "async function f() { for await (const x in xs) {} }",
+        "async function f() { for await (let i = 0; i < 1; i++) {} }",
+        "function f() { for await (const x of xs) {} }",
+    ] {
+        let result = Parser::new(src.as_bytes(), false).parse_all();
+        assert!(result.is_err(), "{} should not parse", src);
+    }
+}
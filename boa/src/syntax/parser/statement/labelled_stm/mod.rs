@@ -2,9 +2,10 @@ use std::io::Read;
 
 use super::{LabelIdentifier, Statement};
 use crate::{
-    syntax::ast::Node,
+    syntax::ast::{node::Block, Keyword, Node},
     syntax::{
         ast::Punctuator,
+        lexer::{InputElement, TokenKind},
         parser::{
             cursor::Cursor, error::ParseError, AllowAwait, AllowReturn, AllowYield, TokenParser,
         },
@@ -49,23 +50,95 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("Label", "Parsing");
-        let name = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-        cursor.expect(Punctuator::Colon, "Labelled Statement")?;
-        let mut stmt =
-            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
 
-        set_label_for_node(&mut stmt, name);
-        Ok(stmt)
+        // Directly nested labels, like `a: b: for (;;) {}`, label the same statement.
+        let mut names: Vec<Box<str>> = Vec::new();
+        loop {
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+            let name = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            if cursor.has_label(&name) || names.contains(&name) {
+                return Err(ParseError::general("duplicate label", position));
+            }
+            cursor.expect(Punctuator::Colon, "Labelled Statement")?;
+            names.push(name);
+
+            if !self.is_label_next(cursor)? {
+                break;
+            }
+        }
+
+        let iteration = matches!(
+            cursor.peek(0)?.map(|tok| tok.kind()),
+            Some(TokenKind::Keyword(Keyword::For))
+                | Some(TokenKind::Keyword(Keyword::While))
+                | Some(TokenKind::Keyword(Keyword::Do))
+        );
+        let iteration_label = names.last().filter(|_| iteration);
+        for name in names.iter() {
+            cursor.push_label(name.clone(), iteration_label.cloned());
+        }
+        let stmt =
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor);
+        for _ in names.iter() {
+            cursor.pop_label();
+        }
+
+        Ok(names.into_iter().rev().fold(stmt?, set_label_for_node))
     }
 }
 
-fn set_label_for_node(stmt: &mut Node, name: Box<str>) {
+impl LabelledStatement {
+    /// Checks if the next tokens are a label identifier followed by a colon.
+    fn is_label_next<R>(self, cursor: &mut Cursor<R>) -> Result<bool, ParseError>
+    where
+        R: Read,
+    {
+        let label = match cursor.peek(0)?.map(|tok| tok.kind()) {
+            Some(TokenKind::Identifier(_)) => true,
+            // `yield` and `await` can be used as labels where they are not reserved.
+            Some(TokenKind::Keyword(Keyword::Yield)) => !self.allow_yield.0,
+            Some(TokenKind::Keyword(Keyword::Await)) => !self.allow_await.0,
+            _ => false,
+        };
+        if !label {
+            return Ok(false);
+        }
+        cursor.set_goal(InputElement::Div);
+        Ok(matches!(
+            cursor.peek(1)?.map(|tok| tok.kind()),
+            Some(TokenKind::Punctuator(Punctuator::Colon))
+        ))
+    }
+}
+
+/// Labels the statement, so that it becomes the target of the `break` and `continue` statements
+/// with the label.
+///
+/// Statements that can't have a label of their own are wrapped in a labelled block, which is
+/// only a target for `break`.
+fn set_label_for_node(mut stmt: Node, name: Box<str>) -> Node {
     match stmt {
-        Node::ForLoop(ref mut for_loop) => for_loop.set_label(name),
-        Node::ForOfLoop(ref mut for_of_loop) => for_of_loop.set_label(name),
-        Node::ForInLoop(ref mut for_in_loop) => for_in_loop.set_label(name),
-        Node::DoWhileLoop(ref mut do_while_loop) => do_while_loop.set_label(name),
-        Node::WhileLoop(ref mut while_loop) => while_loop.set_label(name),
-        _ => (),
+        Node::ForLoop(ref mut for_loop) if for_loop.label().is_none() => for_loop.set_label(name),
+        Node::ForOfLoop(ref mut for_of_loop) if for_of_loop.label().is_none() => {
+            for_of_loop.set_label(name)
+        }
+        Node::ForInLoop(ref mut for_in_loop) if for_in_loop.label().is_none() => {
+            for_in_loop.set_label(name)
+        }
+        Node::DoWhileLoop(ref mut do_while_loop) if do_while_loop.label().is_none() => {
+            do_while_loop.set_label(name)
+        }
+        Node::WhileLoop(ref mut while_loop) if while_loop.label().is_none() => {
+            while_loop.set_label(name)
+        }
+        Node::Block(ref mut block) if block.label().is_none() => block.set_label(name),
+        // Labelled function declarations are not wrapped, to keep the function in scope.
+        Node::FunctionDecl(_) | Node::AsyncFunctionDecl(_) => {}
+        stmt => {
+            let mut block = Block::from(vec![stmt]);
+            block.set_label(name);
+            return block.into();
+        }
     }
+    stmt
 }
@@ -7,6 +7,7 @@
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements
 //! [spec]: https://tc39.es/ecma262/#sec-ecmascript-language-statements-and-declarations
 
+mod binding_pattern;
 mod block;
 mod break_stm;
 mod continue_stm;
@@ -15,11 +16,13 @@ mod expression;
 mod if_stm;
 mod iteration;
 mod labelled_stm;
+mod module;
 mod return_stm;
 mod switch;
 mod throw;
 mod try_stm;
 mod variable;
+mod with;
 
 use self::{
     block::BlockStatement,
@@ -34,6 +37,7 @@ use self::{
     throw::ThrowStatement,
     try_stm::TryStatement,
     variable::VariableStatement,
+    with::WithStatement,
 };
 
 use super::{AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser};
@@ -48,6 +52,9 @@ use crate::{
 };
 use labelled_stm::LabelledStatement;
 
+pub(super) use binding_pattern::Binding;
+pub(super) use module::ModuleItemList;
+
 use std::collections::HashSet;
 use std::io::Read;
 
@@ -111,10 +118,32 @@ where
         // TODO: add BreakableStatement and divide Whiles, fors and so on to another place.
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
-        match tok.kind() {
-            TokenKind::Keyword(Keyword::Await) => AwaitExpression::new(self.allow_yield)
+        // `yield` and `await` can be used as labels where they are not reserved.
+        let keyword_label = match tok.kind() {
+            TokenKind::Keyword(Keyword::Yield) => !self.allow_yield.0,
+            TokenKind::Keyword(Keyword::Await) => !self.allow_await.0,
+            _ => false,
+        };
+        if keyword_label
+            && matches!(
+                cursor.peek(1)?.map(|tok| tok.kind()),
+                Some(TokenKind::Punctuator(Punctuator::Colon))
+            )
+        {
+            return LabelledStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                 .parse(cursor)
-                .map(Node::from),
+                .map(Node::from);
+        }
+
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+
+        match tok.kind() {
+            // In async functions, `await` starts an expression statement.
+            TokenKind::Keyword(Keyword::Await) if !self.allow_await.0 => {
+                AwaitExpression::new(self.allow_yield)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Keyword(Keyword::If) => {
                 IfStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
@@ -169,6 +198,11 @@ where
                     .parse(cursor)
                     .map(Node::from)
             }
+            TokenKind::Keyword(Keyword::With) => {
+                WithStatement::new(self.allow_yield, self.allow_await, self.allow_return)
+                    .parse(cursor)
+                    .map(Node::from)
+            }
             TokenKind::Keyword(Keyword::Switch) => {
                 SwitchStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
@@ -269,12 +303,13 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("StatementList", "Parsing");
         let mut items = Vec::new();
+        let mut positions = Vec::new();
 
         loop {
             match cursor.peek(0)? {
                 Some(token) if self.break_nodes.contains(token.kind()) => break,
+                Some(token) => positions.push(token.span().start()),
                 None => break,
-                _ => {}
             }
 
             let item = StatementListItem::new(
@@ -316,6 +351,19 @@ where
                             }
                         }
                     }
+                    Node::ClassDecl(decl) => {
+                        if var_declared_names.contains(decl.name())
+                            || !lexically_declared_names.insert(decl.name())
+                        {
+                            return Err(ParseError::lex(LexError::Syntax(
+                                format!("Redeclaration of variable `{}`", decl.name()).into(),
+                                match cursor.peek(0)? {
+                                    Some(token) => token.span().end(),
+                                    None => Position::new(1, 1),
+                                },
+                            )));
+                        }
+                    }
                     Node::VarDeclList(decl_list) => {
                         for decl in decl_list.as_ref() {
                             // if name in LexicallyDeclaredNames, raise an error
@@ -337,9 +385,12 @@ where
             }
         }
 
-        items.sort_by(Node::hoistable_order);
+        // Hoisting moves the items, so their positions need to move with them.
+        let mut items: Vec<_> = items.into_iter().zip(positions).collect();
+        items.sort_by(|(a, _), (b, _)| Node::hoistable_order(a, b));
+        let (items, positions): (Vec<_>, Vec<_>) = items.into_iter().unzip();
 
-        Ok(items.into())
+        Ok(node::StatementList::with_positions(items, positions))
     }
 }
 
@@ -399,7 +450,9 @@ where
                 }
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
-            TokenKind::Keyword(Keyword::Const) | TokenKind::Keyword(Keyword::Let) => {
+            TokenKind::Keyword(Keyword::Const)
+            | TokenKind::Keyword(Keyword::Let)
+            | TokenKind::Keyword(Keyword::Class) => {
                 Declaration::new(self.allow_yield, self.allow_await, true).parse(cursor)
             }
             _ => {
@@ -458,6 +511,14 @@ where
         let next_token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
 
         match next_token.kind() {
+            TokenKind::Identifier(ref s)
+                if cursor.strict_mode() && matches!(s.as_ref(), "eval" | "arguments") =>
+            {
+                Err(ParseError::lex(LexError::Syntax(
+                    format!("binding '{}' is not allowed in strict mode", s).into(),
+                    next_token.span().start(),
+                )))
+            }
             TokenKind::Identifier(ref s) => Ok(s.clone()),
             TokenKind::Keyword(k @ Keyword::Yield) if !self.allow_yield.0 => {
                 if cursor.strict_mode() {
@@ -0,0 +1,382 @@
+//! Module item parsing.
+//!
+//! This parses the body of a module, which can contain `import` and `export` declarations in
+//! addition to the statements and declarations of a script.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Modules
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+
+#[cfg(test)]
+mod tests;
+
+use super::{declaration::Declaration, variable::VariableStatement, StatementListItem};
+use crate::{
+    syntax::{
+        ast::{
+            node::{
+                self, ExportDecl, ExportSpecifier, ImportDecl, ImportName, ImportSpecifier, Node,
+            },
+            Keyword, Punctuator,
+        },
+        lexer::{Token, TokenKind},
+        parser::{
+            expression::AssignmentExpression, statement::BindingIdentifier, Cursor, ParseError,
+            TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Reads the list of items of a module body.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItemList
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ModuleItemList;
+
+impl<R> TokenParser<R> for ModuleItemList
+where
+    R: Read,
+{
+    type Output = node::StatementList;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ModuleItemList", "Parsing");
+        let mut items = Vec::new();
+        let mut positions = Vec::new();
+
+        while let Some(token) = cursor.peek(0)? {
+            positions.push(token.span().start());
+
+            let item = match token.kind() {
+                TokenKind::Keyword(Keyword::Import) => {
+                    // `import.meta` starts an expression statement, not an import declaration.
+                    if cursor.peek(1)?.map(Token::kind)
+                        == Some(&TokenKind::Punctuator(Punctuator::Dot))
+                    {
+                        StatementListItem::new(false, false, false, false).parse(cursor)?
+                    } else {
+                        ImportDeclaration.parse(cursor)?
+                    }
+                }
+                TokenKind::Keyword(Keyword::Export) => ExportDeclaration.parse(cursor)?,
+                _ => StatementListItem::new(false, false, false, false).parse(cursor)?,
+            };
+            items.push(item);
+
+            // move the cursor forward for any consecutive semicolon.
+            while cursor.next_if(Punctuator::Semicolon)?.is_some() {}
+        }
+
+        // Hoisting moves the items, so their positions need to move with them.
+        let mut items: Vec<_> = items.into_iter().zip(positions).collect();
+        items.sort_by(|(a, _), (b, _)| Node::hoistable_order(a, b));
+        let (items, positions): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+
+        Ok(node::StatementList::with_positions(items, positions))
+    }
+}
+
+/// Import declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ImportDeclaration;
+
+impl<R> TokenParser<R> for ImportDeclaration
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ImportDeclaration", "Parsing");
+        cursor.expect(Keyword::Import, "import declaration")?;
+
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        if let TokenKind::StringLiteral(_) = tok.kind() {
+            let module = module_specifier(cursor, "import declaration")?;
+            cursor.expect_semicolon("import declaration")?;
+            return Ok(ImportDecl::new(Vec::new(), module).into());
+        }
+
+        let mut specifiers = Vec::new();
+        let named_imports = match tok.kind() {
+            TokenKind::Punctuator(Punctuator::Mul)
+            | TokenKind::Punctuator(Punctuator::OpenBlock) => true,
+            _ => {
+                let local_name = BindingIdentifier::new(false, false).parse(cursor)?;
+                specifiers.push(ImportSpecifier::new(
+                    ImportName::Name("default".into()),
+                    local_name,
+                ));
+                cursor.next_if(Punctuator::Comma)?.is_some()
+            }
+        };
+
+        if named_imports {
+            let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+            match tok.kind() {
+                TokenKind::Punctuator(Punctuator::Mul) => {
+                    expect_contextual(cursor, "as", "import declaration")?;
+                    let local_name = BindingIdentifier::new(false, false).parse(cursor)?;
+                    specifiers.push(ImportSpecifier::new(ImportName::Namespace, local_name));
+                }
+                TokenKind::Punctuator(Punctuator::OpenBlock) => loop {
+                    if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                        break;
+                    }
+
+                    let import_name = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+                    let name = module_export_name(cursor, "import declaration")?;
+                    let local_name = if next_is_contextual(cursor, "as")? {
+                        cursor.next()?;
+                        BindingIdentifier::new(false, false).parse(cursor)?
+                    } else if let TokenKind::Identifier(_) = import_name.kind() {
+                        name.clone()
+                    } else {
+                        return Err(ParseError::unexpected(
+                            import_name,
+                            "import declaration, expected `as`",
+                        ));
+                    };
+                    specifiers.push(ImportSpecifier::new(ImportName::Name(name), local_name));
+
+                    if cursor.next_if(Punctuator::Comma)?.is_none() {
+                        cursor.expect(Punctuator::CloseBlock, "import declaration")?;
+                        break;
+                    }
+                },
+                _ => {
+                    return Err(ParseError::expected(
+                        vec![
+                            TokenKind::Punctuator(Punctuator::Mul),
+                            TokenKind::Punctuator(Punctuator::OpenBlock),
+                        ],
+                        tok,
+                        "import declaration",
+                    ))
+                }
+            }
+        }
+
+        expect_contextual(cursor, "from", "import declaration")?;
+        let module = module_specifier(cursor, "import declaration")?;
+        cursor.expect_semicolon("import declaration")?;
+
+        Ok(ImportDecl::new(specifiers, module).into())
+    }
+}
+
+/// Export declaration parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ExportDeclaration;
+
+impl<R> TokenParser<R> for ExportDeclaration
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ExportDeclaration", "Parsing");
+        cursor.expect(Keyword::Export, "export declaration")?;
+
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let decl = match tok.kind() {
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                cursor.next()?;
+                let alias = if next_is_contextual(cursor, "as")? {
+                    cursor.next()?;
+                    Some(module_export_name(cursor, "export declaration")?)
+                } else {
+                    None
+                };
+                expect_contextual(cursor, "from", "export declaration")?;
+                let module = module_specifier(cursor, "export declaration")?;
+                cursor.expect_semicolon("export declaration")?;
+                ExportDecl::All { alias, module }
+            }
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                cursor.next()?;
+                let mut specifiers = Vec::new();
+                loop {
+                    if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                        break;
+                    }
+
+                    let local_name = module_export_name(cursor, "export declaration")?;
+                    let export_name = if next_is_contextual(cursor, "as")? {
+                        cursor.next()?;
+                        module_export_name(cursor, "export declaration")?
+                    } else {
+                        local_name.clone()
+                    };
+                    specifiers.push(ExportSpecifier::new(local_name, export_name));
+
+                    if cursor.next_if(Punctuator::Comma)?.is_none() {
+                        cursor.expect(Punctuator::CloseBlock, "export declaration")?;
+                        break;
+                    }
+                }
+                let module = if next_is_contextual(cursor, "from")? {
+                    cursor.next()?;
+                    Some(module_specifier(cursor, "export declaration")?)
+                } else {
+                    None
+                };
+                cursor.expect_semicolon("export declaration")?;
+                ExportDecl::Named {
+                    specifiers: specifiers.into(),
+                    module,
+                }
+            }
+            TokenKind::Keyword(Keyword::Var) => ExportDecl::Declaration(Box::new(
+                VariableStatement::new(false, false).parse(cursor)?.into(),
+            )),
+            TokenKind::Keyword(Keyword::Function)
+            | TokenKind::Keyword(Keyword::Async)
+            | TokenKind::Keyword(Keyword::Let)
+            | TokenKind::Keyword(Keyword::Const)
+            | TokenKind::Keyword(Keyword::Class) => ExportDecl::Declaration(Box::new(
+                Declaration::new(false, false, true).parse(cursor)?,
+            )),
+            TokenKind::Keyword(Keyword::Default) => {
+                cursor.next()?;
+                if is_named_function_declaration(cursor)? {
+                    ExportDecl::Default(Box::new(
+                        Declaration::new(false, false, true).parse(cursor)?,
+                    ))
+                } else {
+                    let expr = AssignmentExpression::new(true, false, false).parse(cursor)?;
+                    cursor.expect_semicolon("export declaration")?;
+                    ExportDecl::Default(Box::new(expr))
+                }
+            }
+            _ => return Err(ParseError::unexpected(tok.clone(), "export declaration")),
+        };
+
+        Ok(decl.into())
+    }
+}
+
+/// Checks if the next tokens start a function declaration with a name.
+///
+/// Anonymous functions are parsed as expressions, since declarations require a name.
+fn is_named_function_declaration<R>(cursor: &mut Cursor<R>) -> Result<bool, ParseError>
+where
+    R: Read,
+{
+    let mut index = match cursor.peek(0)?.map(Token::kind) {
+        Some(TokenKind::Keyword(Keyword::Function)) => 1,
+        Some(TokenKind::Keyword(Keyword::Async)) => match cursor.peek(1)?.map(Token::kind) {
+            Some(TokenKind::Keyword(Keyword::Function)) => 2,
+            _ => return Ok(false),
+        },
+        _ => return Ok(false),
+    };
+    if let Some(TokenKind::Punctuator(Punctuator::Mul)) = cursor.peek(index)?.map(Token::kind) {
+        index += 1;
+    }
+    Ok(matches!(
+        cursor.peek(index)?.map(Token::kind),
+        Some(TokenKind::Identifier(_))
+    ))
+}
+
+/// Checks if the next token is the contextual keyword `keyword`, which is lexed as an identifier.
+fn next_is_contextual<R>(cursor: &mut Cursor<R>, keyword: &str) -> Result<bool, ParseError>
+where
+    R: Read,
+{
+    Ok(matches!(
+        cursor.peek(0)?.map(Token::kind),
+        Some(TokenKind::Identifier(name)) if name.as_ref() == keyword
+    ))
+}
+
+/// Returns an error if the next token is not the contextual keyword `keyword`.
+fn expect_contextual<R>(
+    cursor: &mut Cursor<R>,
+    keyword: &'static str,
+    context: &'static str,
+) -> Result<(), ParseError>
+where
+    R: Read,
+{
+    cursor
+        .expect(TokenKind::identifier(keyword), context)
+        .map(|_| ())
+}
+
+/// Parses the string literal that specifies a module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleSpecifier
+fn module_specifier<R>(
+    cursor: &mut Cursor<R>,
+    context: &'static str,
+) -> Result<Box<str>, ParseError>
+where
+    R: Read,
+{
+    let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+    match tok.kind() {
+        TokenKind::StringLiteral(module) => Ok(module.clone()),
+        _ => Err(ParseError::expected(
+            vec![TokenKind::string_literal("module specifier")],
+            tok,
+            context,
+        )),
+    }
+}
+
+/// Parses the name of a binding that is exported by a module, which can be any identifier name,
+/// including reserved words, or a string literal.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleExportName
+fn module_export_name<R>(
+    cursor: &mut Cursor<R>,
+    context: &'static str,
+) -> Result<Box<str>, ParseError>
+where
+    R: Read,
+{
+    let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+    match tok.kind() {
+        TokenKind::Identifier(name) | TokenKind::StringLiteral(name) => Ok(name.clone()),
+        TokenKind::Keyword(keyword) => Ok(keyword.as_str().into()),
+        TokenKind::BooleanLiteral(value) => Ok(value.to_string().into()),
+        TokenKind::NullLiteral => Ok("null".into()),
+        _ => Err(ParseError::expected(
+            vec![TokenKind::identifier("identifier")],
+            tok,
+            context,
+        )),
+    }
+}
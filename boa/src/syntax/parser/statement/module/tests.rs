@@ -0,0 +1,170 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            Call, Declaration, DeclarationList, ExportDecl, ExportSpecifier, FunctionDecl,
+            FunctionExpr, Identifier, ImportDecl, ImportName, ImportSpecifier, Node,
+        },
+        Const,
+    },
+    parser::{tests::check_module_parser, Parser},
+};
+
+#[test]
+fn import_declarations() {
+    check_module_parser(
+        r#"
+        import "side-effect";
+        import first from "a";
+        import * as namespace from "b";
+        import second, { c, d as e, default as f, "string name" as g } from "c";
+        import third, * as other from "d";
+        "#,
+        vec![
+            ImportDecl::new(Vec::new(), "side-effect").into(),
+            ImportDecl::new(
+                vec![ImportSpecifier::new(
+                    ImportName::Name("default".into()),
+                    "first",
+                )],
+                "a",
+            )
+            .into(),
+            ImportDecl::new(
+                vec![ImportSpecifier::new(ImportName::Namespace, "namespace")],
+                "b",
+            )
+            .into(),
+            ImportDecl::new(
+                vec![
+                    ImportSpecifier::new(ImportName::Name("default".into()), "second"),
+                    ImportSpecifier::new(ImportName::Name("c".into()), "c"),
+                    ImportSpecifier::new(ImportName::Name("d".into()), "e"),
+                    ImportSpecifier::new(ImportName::Name("default".into()), "f"),
+                    ImportSpecifier::new(ImportName::Name("string name".into()), "g"),
+                ],
+                "c",
+            )
+            .into(),
+            ImportDecl::new(
+                vec![
+                    ImportSpecifier::new(ImportName::Name("default".into()), "third"),
+                    ImportSpecifier::new(ImportName::Namespace, "other"),
+                ],
+                "d",
+            )
+            .into(),
+        ],
+    );
+}
+
+#[test]
+fn export_declarations() {
+    check_module_parser(
+        r#"
+        export var a = 1;
+        export { a, a as b, a as default };
+        export { c as d } from "c";
+        export * from "e";
+        export * as f from "f";
+        export default g();
+        "#,
+        vec![
+            ExportDecl::Declaration(Box::new(
+                DeclarationList::Var(
+                    vec![Declaration::new("a", Some(Const::from(1).into()))].into(),
+                )
+                .into(),
+            ))
+            .into(),
+            ExportDecl::Named {
+                specifiers: vec![
+                    ExportSpecifier::new("a", "a"),
+                    ExportSpecifier::new("a", "b"),
+                    ExportSpecifier::new("a", "default"),
+                ]
+                .into(),
+                module: None,
+            }
+            .into(),
+            ExportDecl::Named {
+                specifiers: vec![ExportSpecifier::new("c", "d")].into(),
+                module: Some("c".into()),
+            }
+            .into(),
+            ExportDecl::All {
+                alias: None,
+                module: "e".into(),
+            }
+            .into(),
+            ExportDecl::All {
+                alias: Some("f".into()),
+                module: "f".into(),
+            }
+            .into(),
+            ExportDecl::Default(Box::new(
+                Call::new(Identifier::from("g"), Vec::new()).into(),
+            ))
+            .into(),
+        ],
+    );
+}
+
+#[test]
+fn export_default_functions() {
+    check_module_parser(
+        "export default function named() {}",
+        vec![ExportDecl::Default(Box::new(
+            FunctionDecl::new(Box::from("named"), vec![], vec![]).into(),
+        ))
+        .into()],
+    );
+    check_module_parser(
+        "export default function () {}",
+        vec![ExportDecl::Default(Box::new(FunctionExpr::new(None, vec![], vec![]).into())).into()],
+    );
+}
+
+#[test]
+fn exported_functions_are_hoisted() {
+    check_module_parser(
+        "let a = 1; export function f() {}",
+        vec![
+            ExportDecl::Declaration(Box::new(
+                FunctionDecl::new(Box::from("f"), vec![], vec![]).into(),
+            ))
+            .into(),
+            Node::from(DeclarationList::Let(
+                vec![Declaration::new("a", Some(Const::from(1).into()))].into(),
+            )),
+        ],
+    );
+}
+
+#[test]
+fn invalid_module_items() {
+    for source in &[
+        "import { a as 'b' } from 'c';",
+        "import { default } from 'c';",
+        "import a from b;",
+        "import * from 'c';",
+        "export * as a;",
+        "export return;",
+    ] {
+        assert!(
+            Parser::new(source.as_bytes(), false)
+                .parse_module()
+                .is_err(),
+            "{} should not parse",
+            source
+        );
+    }
+
+    // Modules are strict mode code.
+    assert!(Parser::new("with (a) {}".as_bytes(), false)
+        .parse_module()
+        .is_err());
+    // Scripts can't import or export bindings.
+    assert!(Parser::new("import a from 'b';".as_bytes(), false)
+        .parse_all()
+        .is_err());
+}
@@ -71,7 +71,15 @@ where
         let (cases, default) =
             CaseBlock::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
 
-        Ok(Switch::new(condition, cases, default))
+        let default_position = default
+            .as_ref()
+            .map_or(cases.len(), |(position, _)| *position);
+        Ok(Switch::with_default_position(
+            condition,
+            cases,
+            default.map(|(_, default)| default),
+            default_position,
+        ))
     }
 }
 
@@ -108,7 +116,8 @@ impl<R> TokenParser<R> for CaseBlock
 where
     R: Read,
 {
-    type Output = (Box<[node::Case]>, Option<node::StatementList>);
+    /// The cases, and the default clause with the number of cases before it.
+    type Output = (Box<[node::Case]>, Option<(usize, node::StatementList)>);
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         cursor.expect(Punctuator::OpenBlock, "switch case block")?;
@@ -156,7 +165,7 @@ where
                     )
                     .parse(cursor)?;
 
-                    default = Some(statement_list);
+                    default = Some((cases.len(), statement_list));
                 }
                 Some(token) if token.kind() == &TokenKind::Punctuator(Punctuator::CloseBlock) => {
                     break
@@ -189,3 +189,21 @@ fn check_seperated_switch() {
         ],
     );
 }
+
+/// Checks that the position of the default clause among the cases is kept.
+#[test]
+fn check_switch_default_position() {
+    check_parser(
+        "switch (a) { case 1: default: case 2: }",
+        vec![Switch::with_default_position(
+            Identifier::from("a"),
+            vec![
+                Case::new(Const::from(1), Vec::<Node>::new()),
+                Case::new(Const::from(2), Vec::<Node>::new()),
+            ],
+            Some(Vec::<Node>::new()),
+            1,
+        )
+        .into()],
+    );
+}
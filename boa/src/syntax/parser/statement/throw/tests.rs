@@ -1,6 +1,6 @@
 use crate::syntax::{
     ast::{node::Throw, Const},
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 #[test]
@@ -10,3 +10,21 @@ fn check_throw_parsing() {
         vec![Throw::new(Const::from("error")).into()],
     );
 }
+
+#[test]
+fn check_throw_without_semicolon() {
+    check_parser(
+        "throw 'error'\n",
+        vec![Throw::new(Const::from("error")).into()],
+    );
+}
+
+#[test]
+fn check_throw_line_terminator_invalid() {
+    check_invalid("throw\n'error';");
+}
+
+#[test]
+fn check_throw_missing_semicolon_invalid() {
+    check_invalid("throw 'error' 'other';");
+}
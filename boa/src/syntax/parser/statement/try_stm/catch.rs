@@ -1,11 +1,8 @@
 use crate::{
     syntax::{
-        ast::{
-            node::{self, Identifier},
-            Keyword, Punctuator,
-        },
+        ast::{node, Keyword, Punctuator},
         parser::{
-            statement::{block::Block, BindingIdentifier},
+            statement::{block::Block, Binding},
             AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser,
         },
     },
@@ -64,7 +61,7 @@ where
         };
 
         // Catch block
-        Ok(node::Catch::new::<_, Identifier, _>(
+        Ok(node::Catch::new::<_, node::Binding, _>(
             catch_param,
             Block::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?,
         ))
@@ -103,12 +100,26 @@ impl<R> TokenParser<R> for CatchParameter
 where
     R: Read,
 {
-    type Output = Identifier;
+    type Output = node::Binding;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("CatchParameter", "Parsing");
+        let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+        let binding = Binding::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+        // It is a Syntax Error if BoundNames of CatchParameter contains any duplicate elements.
+        let names = binding.names();
+        if names
+            .iter()
+            .enumerate()
+            .any(|(i, name)| names[..i].contains(name))
+        {
+            return Err(ParseError::general(
+                "duplicate name in catch parameter",
+                position,
+            ));
+        }
 
-    fn parse(self, cursor: &mut Cursor<R>) -> Result<Identifier, ParseError> {
-        // TODO: should accept BindingPattern
-        BindingIdentifier::new(self.allow_yield, self.allow_await)
-            .parse(cursor)
-            .map(Identifier::from)
+        Ok(binding)
     }
 }
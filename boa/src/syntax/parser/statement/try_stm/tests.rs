@@ -1,6 +1,9 @@
 use crate::syntax::{
     ast::{
-        node::{Block, Catch, Declaration, DeclarationList, Finally, Identifier, Try},
+        node::{
+            Binding, BindingElement, BindingProperty, Block, Catch, Declaration, DeclarationList,
+            Finally, ObjectBindingPattern, Try,
+        },
         Const,
     },
     parser::tests::{check_invalid, check_parser},
@@ -95,7 +98,7 @@ fn check_inline_empty_try_paramless_catch() {
         "try {} catch { var x = 1; }",
         vec![Try::new(
             Block::from(vec![]),
-            Some(Catch::new::<_, Identifier, _>(
+            Some(Catch::new::<_, Binding, _>(
                 None,
                 vec![DeclarationList::Var(
                     vec![Declaration::new("x", Some(Const::from(1).into()))].into(),
@@ -127,3 +130,34 @@ fn check_inline_invalid_catch_parameter() {
 fn check_invalide_try_no_catch_finally() {
     check_invalid("try {} let a = 10;");
 }
+
+#[test]
+fn check_inline_with_object_pattern_catch() {
+    check_parser(
+        "try {} catch ({ message, code: c = 1 }) {}",
+        vec![Try::new(
+            Block::from(vec![]),
+            Some(Catch::new::<_, Binding, _>(
+                Binding::from(ObjectBindingPattern::new(
+                    vec![
+                        BindingProperty::new("message", BindingElement::new("message", None)),
+                        BindingProperty::new(
+                            "code",
+                            BindingElement::new("c", Some(Const::from(1).into())),
+                        ),
+                    ],
+                    None,
+                )),
+                vec![],
+            )),
+            None,
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn check_inline_invalid_catch_duplicate_names() {
+    check_invalid("try {} catch({ a, b: a }) {}");
+    check_invalid("try {} catch([a, a]) {}");
+}
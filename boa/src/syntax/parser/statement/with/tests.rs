@@ -0,0 +1,42 @@
+use crate::syntax::{
+    ast::{
+        node::{Block, GetConstField, Identifier, With},
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+#[test]
+fn check_with_parsing() {
+    check_parser(
+        "with (obj) { a; }",
+        vec![With::new(
+            Identifier::from("obj"),
+            Block::from(vec![Identifier::from("a").into()]),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn check_with_statement_body() {
+    check_parser(
+        "with (obj.inner) 1;",
+        vec![With::new(
+            GetConstField::new(Identifier::from("obj"), "inner"),
+            Const::from(1),
+        )
+        .into()],
+    );
+}
+
+#[test]
+fn check_with_strict_mode_invalid() {
+    check_invalid("'use strict'; with (obj) {}");
+    check_invalid("function f() { 'use strict'; with (obj) {} }");
+}
+
+#[test]
+fn check_with_missing_parens_invalid() {
+    check_invalid("with obj {}");
+}
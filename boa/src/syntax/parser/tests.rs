@@ -25,6 +25,21 @@ where
     );
 }
 
+/// Checks that the given JavaScript module gives the expected items.
+#[allow(clippy::unwrap_used)]
+#[track_caller]
+pub(super) fn check_module_parser<L>(js: &str, expr: L)
+where
+    L: Into<Box<[Node]>>,
+{
+    assert_eq!(
+        Parser::new(js.as_bytes(), false)
+            .parse_module()
+            .expect("failed to parse"),
+        StatementList::from(expr)
+    );
+}
+
 /// Checks that the given javascript string creates a parse error.
 #[track_caller]
 pub(super) fn check_invalid(js: &str) {
@@ -338,3 +353,74 @@ fn empty_statement() {
         ],
     );
 }
+
+#[test]
+fn new_target() {
+    check_parser(
+        "function f() { return new.target; }",
+        vec![FunctionDecl::new(
+            Box::from("f"),
+            vec![],
+            vec![Return::new(Node::NewTarget, None).into()],
+        )
+        .into()],
+    );
+    check_parser(
+        "function f() { return () => new.target; }",
+        vec![FunctionDecl::new(
+            Box::from("f"),
+            vec![],
+            vec![Return::new(
+                Node::from(ArrowFunctionDecl::new::<
+                    Box<[FormalParameter]>,
+                    StatementList,
+                >(
+                    Box::new([]),
+                    vec![Return::new(Node::NewTarget, None).into()].into(),
+                )),
+                None,
+            )
+            .into()],
+        )
+        .into()],
+    );
+
+    check_invalid("new.target");
+    check_invalid("() => new.target");
+    check_invalid("() => { return new.target; }");
+    check_invalid("function f() { return new.foo; }");
+}
+
+#[test]
+fn super_outside_of_methods() {
+    check_invalid("super.x");
+    check_invalid("function f() { return super.x; }");
+    check_invalid("({ m() { function f() { return super.x; } } })");
+    check_invalid("({ f: function () { return super.x; } })");
+}
+
+#[test]
+fn strict_eval_and_arguments() {
+    let valid = |js: &str| Parser::new(js.as_bytes(), true).parse_all().is_ok();
+    assert!(valid(
+        "(function () { return arguments.length + arguments[0]; });"
+    ));
+    assert!(valid("eval('1'); arguments === undefined;"));
+    assert!(valid("({ arguments: 1, eval: 2 }).arguments;"));
+
+    let invalid = |js: &str| Parser::new(js.as_bytes(), true).parse_all().is_err();
+    assert!(invalid("var arguments;"));
+    assert!(invalid("let eval = 1;"));
+    assert!(invalid("(function (arguments) {});"));
+    assert!(invalid("(function eval() {});"));
+    assert!(invalid("try {} catch (arguments) {}"));
+    assert!(invalid("arguments = 1;"));
+    assert!(invalid("eval += 1;"));
+    assert!(invalid("arguments++;"));
+    assert!(invalid("--eval;"));
+
+    // Sloppy mode code can still bind and assign them.
+    assert!(Parser::new("var arguments; eval = 1;".as_bytes(), false)
+        .parse_all()
+        .is_ok());
+}
@@ -85,18 +85,25 @@ impl Value {
             // 9. If Type(y) is Boolean, return the result of the comparison x == ! ToNumber(y).
             (_, Self::Boolean(y)) => return self.equals(&Value::from(*y as i32), context),
 
+            // An object is never equal to `null` or `undefined`, and isn't converted to a primitive
+            // to compare it with them.
+            (Self::Object(_), Self::Null)
+            | (Self::Object(_), Self::Undefined)
+            | (Self::Null, Self::Object(_))
+            | (Self::Undefined, Self::Object(_)) => false,
+
             // 10. If Type(x) is either String, Number, BigInt, or Symbol and Type(y) is Object, return the result
             // of the comparison x == ? ToPrimitive(y).
-            (Self::Object(_), _) => {
-                let primitive = self.to_primitive(context, PreferredType::Default)?;
-                return primitive.equals(other, context);
+            (_, Self::Object(_)) => {
+                let primitive = other.to_primitive(context, PreferredType::Default)?;
+                return self.equals(&primitive, context);
             }
 
             // 11. If Type(x) is Object and Type(y) is either String, Number, BigInt, or Symbol, return the result
             // of the comparison ? ToPrimitive(x) == y.
-            (_, Self::Object(_)) => {
-                let primitive = other.to_primitive(context, PreferredType::Default)?;
-                return primitive.equals(self, context);
+            (Self::Object(_), _) => {
+                let primitive = self.to_primitive(context, PreferredType::Default)?;
+                return primitive.equals(other, context);
             }
 
             // 12. If Type(x) is BigInt and Type(y) is Number, or if Type(x) is Number and Type(y) is BigInt, then
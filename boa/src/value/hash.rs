@@ -23,7 +23,7 @@ struct RationalHashable(f64);
 impl PartialEq for RationalHashable {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        Number::same_value(self.0, other.0)
+        Number::same_value_zero(self.0, other.0)
     }
 }
 
@@ -32,7 +32,14 @@ impl Eq for RationalHashable {}
 impl Hash for RationalHashable {
     #[inline]
     fn hash<H: Hasher>(&self, state: &mut H) {
-        self.0.to_bits().hash(state);
+        // `+0` and `-0` are equal under `SameValueZero`, as are all `NaN`s.
+        if self.0 == 0.0 {
+            0f64.to_bits().hash(state);
+        } else if self.0.is_nan() {
+            f64::NAN.to_bits().hash(state);
+        } else {
+            self.0.to_bits().hash(state);
+        }
     }
 }
 
@@ -0,0 +1,274 @@
+//! Inspection of JavaScript values, formatted like the Node.js REPL does.
+//!
+//! Unlike `JSON.stringify`, the inspector shows functions, symbols and cyclic structures, and it
+//! never runs JavaScript code: getters are shown as `[Getter]` instead of being called.
+
+use super::*;
+
+/// The depth the inspector shows nested objects to, by default.
+const DEFAULT_DEPTH: usize = 2;
+
+/// This object is used for inspecting a `Value`, formatting it like the Node.js REPL.
+///
+/// It is created by [`Value::inspect`].
+#[derive(Debug, Clone, Copy)]
+pub struct ValueInspector<'value> {
+    value: &'value Value,
+    depth: usize,
+}
+
+impl<'value> ValueInspector<'value> {
+    /// Creates an inspector with the default depth.
+    pub(super) fn new(value: &'value Value) -> Self {
+        Self {
+            value,
+            depth: DEFAULT_DEPTH,
+        }
+    }
+
+    /// Sets how many levels of nested objects are shown. Objects nested deeper are shown as
+    /// `[Object]` or `[Array]`.
+    ///
+    /// The default depth is 2, and `usize::MAX` shows everything.
+    #[inline]
+    pub fn depth(mut self, depth: usize) -> Self {
+        self.depth = depth;
+        self
+    }
+}
+
+impl Display for ValueInspector<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut inspector = Inspector {
+            depth: self.depth,
+            parents: Vec::new(),
+        };
+        f.write_str(&inspector.inspect(self.value, 0))
+    }
+}
+
+/// The state of the formatting of a value.
+struct Inspector {
+    depth: usize,
+    /// The objects that contain the one being formatted, to detect cycles.
+    parents: Vec<GcObject>,
+}
+
+impl Inspector {
+    /// Formats a value nested `level` objects deep.
+    fn inspect(&mut self, value: &Value, level: usize) -> String {
+        match value {
+            Value::String(ref string) => quote(string),
+            Value::Object(ref object) => self.inspect_object(object, level),
+            _ => value.display().to_string(),
+        }
+    }
+
+    fn inspect_object(&mut self, object: &GcObject, level: usize) -> String {
+        if object.is_callable() {
+            return inspect_function(object);
+        }
+        if self
+            .parents
+            .iter()
+            .any(|parent| GcObject::equals(parent, object))
+        {
+            return String::from("[Circular]");
+        }
+        if level > self.depth {
+            return if object.is_array() {
+                String::from("[Array]")
+            } else {
+                String::from("[Object]")
+            };
+        }
+
+        self.parents.push(object.clone());
+        let result = self.inspect_contents(object, level + 1);
+        self.parents.pop();
+        result
+    }
+
+    /// Formats the contents of an object, whose properties are nested `level` objects deep.
+    fn inspect_contents(&mut self, object: &GcObject, level: usize) -> String {
+        let (prefix, mut entries) = match object.borrow().data {
+            ObjectData::Array => (None, Vec::new()),
+            ObjectData::String(ref string) => {
+                (Some(format!("[String: {}]", quote(string))), Vec::new())
+            }
+            ObjectData::Number(number) => (
+                Some(format!("[Number: {}]", Value::from(number).display())),
+                Vec::new(),
+            ),
+            ObjectData::Boolean(boolean) => (Some(format!("[Boolean: {}]", boolean)), Vec::new()),
+            ObjectData::Map(ref map) => {
+                let entries = map
+                    .iter()
+                    .map(|(key, value)| {
+                        format!(
+                            "{} => {}",
+                            self.inspect(key, level),
+                            self.inspect(value, level)
+                        )
+                    })
+                    .collect();
+                (Some(format!("Map({})", map.len())), entries)
+            }
+            ObjectData::Set(ref set) => {
+                let entries = set.iter().map(|value| self.inspect(value, level)).collect();
+                (Some(format!("Set({})", set.size())), entries)
+            }
+            ObjectData::Error(_) => (Some(inspect_error(object)), Vec::new()),
+            _ => (None, Vec::new()),
+        };
+
+        let is_array = object.is_array();
+        let mut keys = object.own_property_keys();
+        if let Some(string) = object.borrow().as_string() {
+            // The characters of a string object are shown by its prefix.
+            let length = string.encode_utf16().count();
+            keys.retain(
+                |key| !matches!(key, PropertyKey::Index(index) if (*index as usize) < length),
+            );
+        } else if object.borrow().is_error() {
+            // The name and the message of an error are shown by its prefix.
+            keys.retain(|key| !matches!(key, PropertyKey::String(ref string) if string.as_str() == "name" || string.as_str() == "message"));
+        }
+        if is_array {
+            let length = data_value(object, &"length".into())
+                .and_then(|length| length.as_number())
+                .unwrap_or(0.0) as u32;
+            entries.extend(self.inspect_elements(object, length, level));
+            keys.retain(|key| match key {
+                PropertyKey::Index(index) => *index >= length,
+                PropertyKey::String(ref string) => string.as_str() != "length",
+                PropertyKey::Symbol(_) => true,
+            });
+        }
+        for key in keys {
+            let property = match object.get_own_property(&key) {
+                Some(property) if property.enumerable() => property,
+                _ => continue,
+            };
+            let value = match property {
+                PropertyDescriptor::Data(ref data) => self.inspect(&data.value(), level),
+                PropertyDescriptor::Accessor(ref accessor) => {
+                    match (accessor.getter().is_some(), accessor.setter().is_some()) {
+                        (true, true) => String::from("[Getter/Setter]"),
+                        (true, false) => String::from("[Getter]"),
+                        (false, _) => String::from("[Setter]"),
+                    }
+                }
+            };
+            entries.push(format!("{}: {}", inspect_key(&key), value));
+        }
+
+        match (prefix, is_array) {
+            (Some(prefix), _) if entries.is_empty() => prefix,
+            (Some(prefix), _) => format!("{} {{ {} }}", prefix, entries.join(", ")),
+            (None, true) if entries.is_empty() => String::from("[]"),
+            (None, true) => format!("[ {} ]", entries.join(", ")),
+            (None, false) if entries.is_empty() => String::from("{}"),
+            (None, false) => format!("{{ {} }}", entries.join(", ")),
+        }
+    }
+
+    /// Formats the elements of an array, with runs of holes shown as `<n empty items>`.
+    fn inspect_elements(&mut self, array: &GcObject, length: u32, level: usize) -> Vec<String> {
+        let mut elements = Vec::new();
+        let mut holes = 0;
+        for index in 0..length {
+            match data_value(array, &index.into()) {
+                Some(element) => {
+                    if holes > 0 {
+                        elements.push(empty_items(holes));
+                        holes = 0;
+                    }
+                    elements.push(self.inspect(&element, level));
+                }
+                None => holes += 1,
+            }
+        }
+        if holes > 0 {
+            elements.push(empty_items(holes));
+        }
+        elements
+    }
+}
+
+/// Gets the value of an own data property of an object.
+///
+/// Accessor properties are shown as `undefined`, since their getter can't be called.
+fn data_value(object: &GcObject, key: &PropertyKey) -> Option<Value> {
+    object.get_own_property(key).map(|property| match property {
+        PropertyDescriptor::Data(ref data) => data.value(),
+        PropertyDescriptor::Accessor(_) => Value::undefined(),
+    })
+}
+
+fn empty_items(count: u32) -> String {
+    if count == 1 {
+        String::from("<1 empty item>")
+    } else {
+        format!("<{} empty items>", count)
+    }
+}
+
+fn inspect_function(function: &GcObject) -> String {
+    match data_value(function, &"name".into()) {
+        Some(Value::String(ref name)) if !name.is_empty() => format!("[Function: {}]", name),
+        _ => String::from("[Function (anonymous)]"),
+    }
+}
+
+fn inspect_error(error: &GcObject) -> String {
+    let name = data_value(error, &"name".into())
+        .or_else(|| {
+            error
+                .get_prototype_of()
+                .as_object()
+                .and_then(|prototype| data_value(&prototype, &"name".into()))
+        })
+        .and_then(|name| name.as_string().map(RcString::to_string))
+        .unwrap_or_else(|| String::from("Error"));
+    match data_value(error, &"message".into()) {
+        Some(Value::String(ref message)) if !message.is_empty() => {
+            format!("[{}: {}]", name, message)
+        }
+        _ => format!("[{}]", name),
+    }
+}
+
+/// Formats a property key, quoting string keys that aren't identifiers.
+fn inspect_key(key: &PropertyKey) -> String {
+    match key {
+        PropertyKey::Index(index) => quote(&index.to_string()),
+        PropertyKey::String(ref string) if is_identifier(string) => string.to_string(),
+        PropertyKey::String(ref string) => quote(string),
+        PropertyKey::Symbol(ref symbol) => format!("[{}]", symbol),
+    }
+}
+
+fn is_identifier(string: &str) -> bool {
+    let mut chars = string.chars();
+    matches!(chars.next(), Some(c) if c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+/// Quotes a string with single quotes, escaping the characters that need it.
+fn quote(string: &str) -> String {
+    let mut quoted = String::with_capacity(string.len() + 2);
+    quoted.push('\'');
+    for c in string.chars() {
+        match c {
+            '\'' => quoted.push_str("\\'"),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('\'');
+    quoted
+}
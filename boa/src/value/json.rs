@@ -0,0 +1,129 @@
+//! Conversion from a JavaScript `Value` into a [`serde_json::Value`].
+//!
+//! This conversion inspects the own data properties of objects without running any JavaScript
+//! code, so it needs no `Context`. The conversion in the other direction has to create objects
+//! with the right prototypes, which is done by [`Value::from_json`].
+
+use super::*;
+use crate::object::RecursionLimiter;
+use serde_json::Map;
+use std::result::Result as StdResult;
+
+/// The error returned when a `Value` can't be represented as JSON.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum TryFromValueError {
+    /// The value is `undefined`.
+    Undefined,
+    /// The value is a function.
+    Function,
+    /// The value is a symbol.
+    Symbol,
+    /// The value is a `BigInt`.
+    BigInt,
+    /// The value has a property with a getter or a setter, which can't be called without a
+    /// `Context`.
+    Accessor,
+    /// The value contains itself.
+    Cycle,
+}
+
+impl Display for TryFromValueError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            Self::Undefined => "undefined has no JSON representation",
+            Self::Function => "functions have no JSON representation",
+            Self::Symbol => "symbols have no JSON representation",
+            Self::BigInt => "BigInt values have no JSON representation",
+            Self::Accessor => "accessor properties can't be converted to JSON",
+            Self::Cycle => "cyclic object value",
+        };
+        write!(f, "Could not convert value to JSON: {}", reason)
+    }
+}
+
+impl std::error::Error for TryFromValueError {}
+
+impl TryFrom<&Value> for JSONValue {
+    type Error = TryFromValueError;
+
+    /// Converts the value to JSON.
+    ///
+    /// Like `JSON.stringify`, non-finite numbers become `null`, `undefined` properties are left
+    /// out of objects and array holes become `null`. Unlike `JSON.stringify`, `toJSON` methods
+    /// are not called, and values that JSON can't represent are an error instead of being
+    /// skipped.
+    fn try_from(value: &Value) -> StdResult<Self, Self::Error> {
+        match *value {
+            Value::Null => Ok(JSONValue::Null),
+            Value::Boolean(b) => Ok(JSONValue::Bool(b)),
+            Value::String(ref string) => Ok(JSONValue::String(string.to_string())),
+            Value::Integer(integer) => Ok(JSONValue::Number(JSONNumber::from(integer))),
+            Value::Rational(rational) if rational.is_finite() => Ok(JSONValue::Number(
+                JSONNumber::from_str(&Number::to_native_string(rational))
+                    .expect("invalid number found"),
+            )),
+            Value::Rational(_) => Ok(JSONValue::Null),
+            Value::Object(ref object) => object_to_json(object),
+            Value::Undefined => Err(TryFromValueError::Undefined),
+            Value::Symbol(_) => Err(TryFromValueError::Symbol),
+            Value::BigInt(_) => Err(TryFromValueError::BigInt),
+        }
+    }
+}
+
+/// Converts the own enumerable properties of an object to JSON.
+fn object_to_json(object: &GcObject) -> StdResult<JSONValue, TryFromValueError> {
+    let recursion_limiter = RecursionLimiter::new(object);
+    if recursion_limiter.live {
+        return Err(TryFromValueError::Cycle);
+    }
+    if object.is_callable() {
+        return Err(TryFromValueError::Function);
+    }
+
+    let property_value = |property: PropertyDescriptor| match property {
+        PropertyDescriptor::Data(ref data) => Ok(data.value()),
+        PropertyDescriptor::Accessor(_) => Err(TryFromValueError::Accessor),
+    };
+
+    if object.is_array() {
+        let length = object
+            .get_own_property(&"length".into())
+            .map(property_value)
+            .transpose()?
+            .and_then(|length| length.as_number())
+            .unwrap_or(0.0);
+        let mut array = Vec::with_capacity(length as usize);
+        for index in 0..length as u32 {
+            let element = object
+                .get_own_property(&index.into())
+                .map(property_value)
+                .transpose()?
+                .unwrap_or_default();
+            array.push(if element.is_undefined() {
+                JSONValue::Null
+            } else {
+                JSONValue::try_from(&element)?
+            });
+        }
+        return Ok(JSONValue::Array(array));
+    }
+
+    let keys: Vec<PropertyKey> = object
+        .own_property_keys()
+        .into_iter()
+        .filter(|key| !matches!(key, PropertyKey::Symbol(_)))
+        .collect();
+    let mut map = Map::new();
+    for key in keys {
+        let property = match object.get_own_property(&key) {
+            Some(property) if property.enumerable() => property,
+            _ => continue,
+        };
+        let value = property_value(property)?;
+        if !value.is_undefined() {
+            map.insert(key.to_string(), JSONValue::try_from(&value)?);
+        }
+    }
+    Ok(JSONValue::Object(map))
+}
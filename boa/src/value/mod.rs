@@ -7,6 +7,7 @@ mod tests;
 
 use crate::{
     builtins::{
+        error::ErrorKind,
         number::{f64_to_int32, f64_to_uint32},
         string::is_trimmable_whitespace,
         BigInt, Number,
@@ -29,6 +30,9 @@ mod conversions;
 pub(crate) mod display;
 mod equality;
 mod hash;
+mod inspect;
+#[cfg(feature = "json")]
+mod json;
 mod operations;
 mod rcbigint;
 mod rcstring;
@@ -38,6 +42,9 @@ pub use conversions::*;
 pub use display::ValueDisplay;
 pub use equality::*;
 pub use hash::*;
+pub use inspect::ValueInspector;
+#[cfg(feature = "json")]
+pub use json::TryFromValueError;
 pub use operations::*;
 pub use r#type::Type;
 pub use rcbigint::RcBigInt;
@@ -275,6 +282,27 @@ impl Value {
         }
     }
 
+    /// Returns the standard type of the error, if the value is an error object.
+    ///
+    /// This is useful to inspect the `Err` values returned by `Context::eval`.
+    #[inline]
+    pub fn as_error_kind(&self) -> Option<ErrorKind> {
+        match *self {
+            Self::Object(ref o) => o.borrow().as_error(),
+            _ => None,
+        }
+    }
+
+    /// Returns the `message` of the error, if the value is an error object.
+    pub fn error_message(&self, context: &mut Context) -> Result<Option<RcString>> {
+        if self.as_error_kind().is_none() {
+            return Ok(None);
+        }
+        Ok(Some(
+            self.get_field("message", context)?.to_string(context)?,
+        ))
+    }
+
     /// Returns true if the value is a symbol.
     #[inline]
     pub fn is_symbol(&self) -> bool {
@@ -294,6 +322,17 @@ impl Value {
         matches!(self, Self::Object(o) if o.is_function())
     }
 
+    /// Returns true if the value is an object with a `[[Call]]` internal method.
+    ///
+    /// More information:
+    /// - [EcmaScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-iscallable
+    #[inline]
+    pub fn is_callable(&self) -> bool {
+        matches!(self, Self::Object(o) if o.is_callable())
+    }
+
     /// Returns true if the value is undefined.
     #[inline]
     pub fn is_undefined(&self) -> bool {
@@ -467,7 +506,7 @@ impl Value {
     {
         let _timer = BoaProfiler::global().start_event("Value::has_field", "value");
         self.as_object()
-            .map(|object| object.has_property(&key.into()))
+            .map(|object| object.ordinary_has_property(&key.into()))
             .unwrap_or(false)
     }
 
@@ -537,6 +576,38 @@ impl Value {
         }
     }
 
+    /// Calls the value with the given `this` value and arguments, like a JavaScript call does.
+    ///
+    /// Throws a `TypeError` if the value is not a function.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-call
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{Context, Value};
+    /// let mut context = Context::new();
+    /// let add = context
+    ///     .eval("function add(a, b) { return this.base + a + b; } add")
+    ///     .unwrap();
+    ///
+    /// let this = context.eval("({ base: 10 })").unwrap();
+    /// let result = add.call(&this, &[Value::from(1), Value::from(2)], &mut context);
+    /// assert_eq!(result.unwrap().as_number(), Some(13.0));
+    ///
+    /// let error = Value::from(1).call(&Value::undefined(), &[], &mut context);
+    /// assert!(error.is_err());
+    /// ```
+    #[inline]
+    pub fn call(&self, this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        match *self {
+            Self::Object(ref object) => object.call(this, args, context),
+            _ => context.throw_type_error("not a function"),
+        }
+    }
+
     /// The abstract operation ToPrimitive takes an input argument and an optional argument PreferredType.
     ///
     /// <https://tc39.es/ecma262/#sec-toprimitive>
@@ -628,6 +699,34 @@ impl Value {
         ValueDisplay { value: self }
     }
 
+    /// Returns an object that implements `Display`, formatting the value like the Node.js REPL,
+    /// with nested objects shown up to a configurable depth.
+    ///
+    /// Functions are shown as `[Function: name]` and objects that contain themselves as
+    /// `[Circular]`. No JavaScript code is run to format the value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa::Context;
+    ///
+    /// let mut context = Context::new();
+    /// let value = context.eval("({ a: [1, 'two'], b: { c: {} } })").unwrap();
+    ///
+    /// assert_eq!(
+    ///     value.inspect().to_string(),
+    ///     "{ a: [ 1, 'two' ], b: { c: {} } }"
+    /// );
+    /// assert_eq!(
+    ///     value.inspect().depth(0).to_string(),
+    ///     "{ a: [Array], b: [Object] }"
+    /// );
+    /// ```
+    #[inline]
+    pub fn inspect(&self) -> ValueInspector<'_> {
+        ValueInspector::new(self)
+    }
+
     /// Converts the value to a string.
     ///
     /// This function is equivalent to `String(value)` in JavaScript.
@@ -737,7 +836,7 @@ impl Value {
         if let Some(bigint) = primitive.as_bigint() {
             return Ok(bigint.clone().into());
         }
-        Ok(self.to_number(context)?.into())
+        Ok(primitive.to_number(context)?.into())
     }
 
     /// Converts a value to an integral 32 bit unsigned integer.
@@ -847,20 +946,17 @@ impl Value {
                     "" => Ok(0.0),
                     "Infinity" | "+Infinity" => Ok(f64::INFINITY),
                     "-Infinity" => Ok(f64::NEG_INFINITY),
-                    _ if matches!(
-                        string
-                            .chars()
-                            .take(4)
-                            .collect::<String>()
-                            .to_ascii_lowercase()
-                            .as_str(),
-                        "inf" | "+inf" | "-inf" | "nan" | "+nan" | "-nan"
-                    ) =>
+                    // The only valid literal starting with a letter is `Infinity`, but fast_float
+                    // also parses the other spellings of "inf", "infinity" and "nan" in any case.
+                    _ if string
+                        .strip_prefix(|c| c == '+' || c == '-')
+                        .unwrap_or(string)
+                        .starts_with(|c: char| c.is_ascii_alphabetic()) =>
                     {
-                        // Prevent fast_float from parsing "inf", "+inf" as Infinity and "-inf" as -Infinity
                         Ok(f64::NAN)
                     }
-                    _ => Ok(fast_float::parse(string).unwrap_or(f64::NAN)),
+                    _ => Ok(parse_non_decimal_integer(string)
+                        .unwrap_or_else(|| fast_float::parse(string).unwrap_or(f64::NAN))),
                 }
             }
             Value::Rational(number) => Ok(number),
@@ -948,6 +1044,39 @@ impl Value {
     }
 }
 
+/// Parses a `NonDecimalIntegerLiteral` of a string being converted to a number, which is a `0x`,
+/// `0o` or `0b` prefix followed by hexadecimal, octal or binary digits.
+///
+/// Returns `None` if the string doesn't have one of the prefixes, and `NaN` if the digits are
+/// invalid. Unlike numeric literals in the source code, they can't contain separators.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-stringnumericvalue
+fn parse_non_decimal_integer(string: &str) -> Option<f64> {
+    let radix = match string.get(..2)? {
+        "0x" | "0X" => 16,
+        "0o" | "0O" => 8,
+        "0b" | "0B" => 2,
+        _ => return None,
+    };
+    let digits = &string[2..];
+    if digits.is_empty() {
+        return Some(f64::NAN);
+    }
+
+    Some(
+        digits
+            .chars()
+            .try_fold(0.0, |value: f64, c| {
+                c.to_digit(radix)
+                    .map(|digit| value * f64::from(radix) + f64::from(digit))
+            })
+            .unwrap_or(f64::NAN),
+    )
+}
+
 impl Default for Value {
     fn default() -> Self {
         Self::Undefined
@@ -6,14 +6,24 @@ impl Value {
     pub fn add(&self, other: &Self, context: &mut Context) -> Result<Value> {
         Ok(match (self, other) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => Self::rational(f64::from(*x) + f64::from(*y)),
+            // Small integers stay unboxed, unless the result overflows.
+            (Self::Integer(x), Self::Integer(y)) => match x.checked_add(*y) {
+                Some(result) => Self::integer(result),
+                None => Self::rational(f64::from(*x) + f64::from(*y)),
+            },
             (Self::Rational(x), Self::Rational(y)) => Self::rational(x + y),
             (Self::Integer(x), Self::Rational(y)) => Self::rational(f64::from(*x) + y),
             (Self::Rational(x), Self::Integer(y)) => Self::rational(x + f64::from(*y)),
 
             (Self::String(ref x), Self::String(ref y)) => Self::string(format!("{}{}", x, y)),
-            (Self::String(ref x), ref y) => Self::string(format!("{}{}", x, y.to_string(context)?)),
-            (ref x, Self::String(ref y)) => Self::string(format!("{}{}", x.to_string(context)?, y)),
+            // Objects are converted with the "default" hint, like in the slow path, instead of the
+            // "string" hint of `ToString`.
+            (Self::String(ref x), ref y) if !y.is_object() => {
+                Self::string(format!("{}{}", x, y.to_string(context)?))
+            }
+            (ref x, Self::String(ref y)) if !x.is_object() => {
+                Self::string(format!("{}{}", x.to_string(context)?, y))
+            }
             (Self::BigInt(ref n1), Self::BigInt(ref n2)) => {
                 Self::bigint(n1.as_inner().clone() + n2.as_inner().clone())
             }
@@ -48,7 +58,10 @@ impl Value {
     pub fn sub(&self, other: &Self, context: &mut Context) -> Result<Value> {
         Ok(match (self, other) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => Self::rational(f64::from(*x) - f64::from(*y)),
+            (Self::Integer(x), Self::Integer(y)) => match x.checked_sub(*y) {
+                Some(result) => Self::integer(result),
+                None => Self::rational(f64::from(*x) - f64::from(*y)),
+            },
             (Self::Rational(x), Self::Rational(y)) => Self::rational(x - y),
             (Self::Integer(x), Self::Rational(y)) => Self::rational(f64::from(*x) - y),
             (Self::Rational(x), Self::Integer(y)) => Self::rational(x - f64::from(*y)),
@@ -76,7 +89,11 @@ impl Value {
     pub fn mul(&self, other: &Self, context: &mut Context) -> Result<Value> {
         Ok(match (self, other) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => Self::rational(f64::from(*x) * f64::from(*y)),
+            // A zero product of a negative operand is `-0`, which can't be an integer.
+            (Self::Integer(x), Self::Integer(y)) => match x.checked_mul(*y) {
+                Some(result) if result != 0 || (*x >= 0 && *y >= 0) => Self::integer(result),
+                _ => Self::rational(f64::from(*x) * f64::from(*y)),
+            },
             (Self::Rational(x), Self::Rational(y)) => Self::rational(x * y),
             (Self::Integer(x), Self::Rational(y)) => Self::rational(f64::from(*x) * y),
             (Self::Rational(x), Self::Integer(y)) => Self::rational(x * f64::from(*y)),
@@ -138,13 +155,12 @@ impl Value {
     pub fn rem(&self, other: &Self, context: &mut Context) -> Result<Value> {
         Ok(match (self, other) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => {
-                if *y == 0 {
-                    Self::nan()
-                } else {
-                    Self::integer(x % *y)
-                }
-            }
+            // The remainder has the sign of the dividend, so a zero remainder of a negative
+            // dividend is `-0`.
+            (Self::Integer(x), Self::Integer(y)) => match x.checked_rem(*y) {
+                Some(result) if result != 0 || *x >= 0 => Self::integer(result),
+                _ => Self::rational(f64::from(*x) % f64::from(*y)),
+            },
             (Self::Rational(x), Self::Rational(y)) => Self::rational(x % y),
             (Self::Integer(x), Self::Rational(y)) => Self::rational(f64::from(*x) % y),
             (Self::Rational(x), Self::Integer(y)) => Self::rational(x % f64::from(*y)),
@@ -175,10 +191,16 @@ impl Value {
     pub fn pow(&self, other: &Self, context: &mut Context) -> Result<Value> {
         Ok(match (self, other) {
             // Fast path:
-            (Self::Integer(x), Self::Integer(y)) => Self::rational(f64::from(*x).powi(*y)),
-            (Self::Rational(x), Self::Rational(y)) => Self::rational(x.powf(*y)),
-            (Self::Integer(x), Self::Rational(y)) => Self::rational(f64::from(*x).powf(*y)),
-            (Self::Rational(x), Self::Integer(y)) => Self::rational(x.powi(*y)),
+            (Self::Integer(x), Self::Integer(y)) => {
+                Self::rational(Number::exponentiate(f64::from(*x), f64::from(*y)))
+            }
+            (Self::Rational(x), Self::Rational(y)) => Self::rational(Number::exponentiate(*x, *y)),
+            (Self::Integer(x), Self::Rational(y)) => {
+                Self::rational(Number::exponentiate(f64::from(*x), *y))
+            }
+            (Self::Rational(x), Self::Integer(y)) => {
+                Self::rational(Number::exponentiate(*x, f64::from(*y)))
+            }
 
             (Self::BigInt(ref a), Self::BigInt(ref b)) => Self::bigint(
                 a.as_inner()
@@ -189,7 +211,9 @@ impl Value {
 
             // Slow path:
             (_, _) => match (self.to_numeric(context)?, other.to_numeric(context)?) {
-                (Numeric::Number(a), Numeric::Number(b)) => Self::rational(a.powf(b)),
+                (Numeric::Number(a), Numeric::Number(b)) => {
+                    Self::rational(Number::exponentiate(a, b))
+                }
                 (Numeric::BigInt(ref a), Numeric::BigInt(ref b)) => Self::bigint(
                     a.as_inner()
                         .clone()
@@ -490,19 +514,10 @@ impl Value {
                 };
 
                 match (px, py) {
+                    // Strings are compared by their UTF-16 code units, not by their code points, so a
+                    // character outside of the BMP sorts before the characters after U+D7FF.
                     (Self::String(ref x), Self::String(ref y)) => {
-                        if x.starts_with(y.as_str()) {
-                            return Ok(AbstractRelation::False);
-                        }
-                        if y.starts_with(x.as_str()) {
-                            return Ok(AbstractRelation::True);
-                        }
-                        for (x, y) in x.chars().zip(y.chars()) {
-                            if x != y {
-                                return Ok((x < y).into());
-                            }
-                        }
-                        unreachable!()
+                        x.encode_utf16().lt(y.encode_utf16()).into()
                     }
                     (Self::BigInt(ref x), Self::String(ref y)) => {
                         if let Some(y) = string_to_bigint(&y) {
@@ -528,12 +543,8 @@ impl Value {
                             if y.is_infinite() {
                                 return Ok(y.is_sign_positive().into());
                             }
-                            let n = if y.is_sign_negative() {
-                                y.floor()
-                            } else {
-                                y.ceil()
-                            };
-                            (*x.as_inner() < BigInt::try_from(n).unwrap()).into()
+                            // An integer is less than `y` if it is less than the next integer.
+                            (*x.as_inner() < BigInt::try_from(y.ceil()).unwrap()).into()
                         }
                         (Numeric::Number(x), Numeric::BigInt(ref y)) => {
                             if x.is_nan() {
@@ -542,12 +553,8 @@ impl Value {
                             if x.is_infinite() {
                                 return Ok(x.is_sign_negative().into());
                             }
-                            let n = if x.is_sign_negative() {
-                                x.floor()
-                            } else {
-                                x.ceil()
-                            };
-                            (BigInt::try_from(n).unwrap() < *y.as_inner()).into()
+                            // `x` is less than an integer if the previous integer is.
+                            (BigInt::try_from(x.floor()).unwrap() < *y.as_inner()).into()
                         }
                     },
                 }
@@ -622,6 +629,41 @@ impl Value {
             AbstractRelation::True | AbstractRelation::Undefined => Ok(false),
         }
     }
+
+    /// The `instanceof` operator returns `true` if `target` considers the value to be one of its
+    /// instances, by default if the `prototype` of `target` is in the prototype chain of the value.
+    ///
+    /// More Information:
+    ///  - [MDN documentation][mdn]
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/instanceof
+    /// [spec]: https://tc39.es/ecma262/#sec-instanceofoperator
+    pub fn instance_of(&self, target: &Self, context: &mut Context) -> Result<bool> {
+        // 1. If Type(target) is not Object, throw a TypeError exception.
+        let object = match target.as_object() {
+            Some(object) => object,
+            None => {
+                return Err(context.construct_type_error(format!(
+                    "right-hand side of 'instanceof' should be an object, got {}",
+                    target.get_type().as_str()
+                )))
+            }
+        };
+
+        // 2. Let instOfHandler be ? GetMethod(target, @@hasInstance).
+        match object.get_method(context, WellKnownSymbols::has_instance())? {
+            // 3. If instOfHandler is not undefined, then
+            //     a. Return ! ToBoolean(? Call(instOfHandler, target, « V »)).
+            Some(handler) => Ok(handler.call(target, &[self.clone()], context)?.to_boolean()),
+            // 4. If IsCallable(target) is false, throw a TypeError exception.
+            None if !object.is_callable() => {
+                Err(context.construct_type_error("right-hand side of 'instanceof' is not callable"))
+            }
+            // 5. Return ? OrdinaryHasInstance(target, V).
+            None => object.ordinary_has_instance(context, self),
+        }
+    }
 }
 
 /// The result of the [Abstract Relational Comparison][arc].
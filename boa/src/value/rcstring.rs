@@ -19,6 +19,12 @@ impl RcString {
     pub fn as_str(&self) -> &str {
         &self.0
     }
+
+    /// Checks if there is no other reference to this string.
+    #[inline]
+    pub(crate) fn is_unique(&self) -> bool {
+        Rc::strong_count(&self.0) == 1
+    }
 }
 
 impl Default for RcString {
@@ -123,6 +123,75 @@ fn abstract_equality_comparison() {
     );
 }
 
+// https://tc39.es/ecma262/#sec-islooselyequal
+#[test]
+fn abstract_equality_matrix() {
+    let mut context = Context::new();
+    forward(
+        &mut context,
+        r#"
+        var sym = Symbol("s");
+        var values = [
+            undefined, null, true, false, 0, 1, NaN, "", "0", "1", "a", 1n, sym,
+            { valueOf() { return 1; } }, { toString() { return "a"; } },
+            { valueOf() { return null; } }, [], [0],
+        ];
+        "#,
+    );
+
+    // The pairs of indices of `values` that are loosely equal, besides each value with itself.
+    let equal = [
+        (0, 1),
+        (2, 5),
+        (2, 9),
+        (2, 11),
+        (2, 13),
+        (3, 4),
+        (3, 7),
+        (3, 8),
+        (3, 16),
+        (3, 17),
+        (4, 7),
+        (4, 8),
+        (4, 16),
+        (4, 17),
+        (5, 9),
+        (5, 11),
+        (5, 13),
+        (7, 16),
+        (8, 17),
+        (9, 11),
+        (9, 13),
+        (10, 14),
+        (11, 13),
+    ];
+
+    for x in 0..18 {
+        for y in 0..18 {
+            let expected = (x == y && x != 6) || equal.contains(&(x.min(y), x.max(y)));
+            let source = format!("values[{}] == values[{}]", x, y);
+            assert_eq!(
+                forward(&mut context, &source),
+                expected.to_string(),
+                "{}",
+                source
+            );
+        }
+    }
+
+    // Objects are only converted to primitives if they are compared to primitives.
+    forward(
+        &mut context,
+        "var calls = 0; var counted = { valueOf() { calls++; return 0; } };",
+    );
+    assert_eq!(forward(&mut context, "counted == null"), "false");
+    assert_eq!(forward(&mut context, "counted == undefined"), "false");
+    assert_eq!(forward(&mut context, "counted == counted"), "true");
+    assert_eq!(forward(&mut context, "calls"), "0");
+    assert_eq!(forward(&mut context, "counted == false"), "true");
+    assert_eq!(forward(&mut context, "calls"), "1");
+}
+
 /// Helper function to get the hash of a `Value`.
 fn hash_value(value: &Value) -> u64 {
     let mut hasher = DefaultHasher::new();
@@ -364,6 +433,56 @@ fn sub_string_and_number_object() {
     assert!(value.is_nan());
 }
 
+#[test]
+fn string_to_number() {
+    let mut context = Context::new();
+
+    let cases = [
+        ("''", 0.0),
+        ("'  \\t\\n '", 0.0),
+        ("' 42 '", 42.0),
+        ("'-1.5e3'", -1500.0),
+        ("'+.5'", 0.5),
+        ("'5.'", 5.0),
+        ("'  0x1A  '", 26.0),
+        ("'0XfF'", 255.0),
+        ("'0o17'", 15.0),
+        ("'0B101'", 5.0),
+        ("'Infinity'", f64::INFINITY),
+        ("' +Infinity'", f64::INFINITY),
+        ("'-Infinity '", f64::NEG_INFINITY),
+    ];
+    for (string, expected) in cases.iter() {
+        let value = forward_val(&mut context, &format!("Number({})", string)).unwrap();
+        assert_eq!(
+            value.to_number(&mut context).unwrap(),
+            *expected,
+            "{}",
+            string
+        );
+    }
+
+    let invalid = [
+        "'1_000'",
+        "'0x'",
+        "'0x1G'",
+        "'-0x10'",
+        "'0o8'",
+        "'0b2'",
+        "'infinity'",
+        "'inf'",
+        "'1 2'",
+    ];
+    for string in invalid.iter() {
+        let value = forward_val(&mut context, &format!("Number({})", string)).unwrap();
+        assert!(
+            value.to_number(&mut context).unwrap().is_nan(),
+            "{}",
+            string
+        );
+    }
+}
+
 #[test]
 fn div_by_zero() {
     let mut context = Context::new();
@@ -428,6 +547,28 @@ fn pow_number_and_string() {
     assert!(value.is_nan());
 }
 
+#[test]
+fn pow_is_right_associative() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "2 ** 3 ** 2 === 512"), "true");
+    assert_eq!(forward(&mut context, "(2 ** 3) ** 2"), "64");
+    assert_eq!(forward(&mut context, "let x = 2; x **= 3 ** 2; x"), "512");
+}
+
+#[test]
+fn pow_nan_and_infinity() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "1 ** NaN"), "NaN");
+    assert_eq!(forward(&mut context, "NaN ** 0"), "1");
+    assert_eq!(forward(&mut context, "1 ** Infinity"), "NaN");
+    assert_eq!(forward(&mut context, "(-1) ** -Infinity"), "NaN");
+    assert_eq!(forward(&mut context, "Math.pow(1, NaN)"), "NaN");
+    assert_eq!(forward(&mut context, "Math.pow(-1, Infinity)"), "NaN");
+    assert_eq!(forward(&mut context, "2 ** -Infinity"), "0");
+}
+
 #[test]
 fn assign_pow_number_and_string() {
     let mut context = Context::new();
@@ -560,6 +701,100 @@ toString: {
     );
 }
 
+mod inspect {
+    use super::*;
+
+    fn inspect(src: &str) -> String {
+        let mut context = Context::new();
+        forward_val(&mut context, src)
+            .unwrap()
+            .inspect()
+            .to_string()
+    }
+
+    #[test]
+    fn primitives() {
+        assert_eq!(inspect("'it\\'s'"), r"'it\'s'");
+        assert_eq!(inspect("-0"), "-0");
+        assert_eq!(inspect("10n"), "10n");
+        assert_eq!(inspect("Symbol('s')"), "Symbol(s)");
+        assert_eq!(inspect("undefined"), "undefined");
+    }
+
+    #[test]
+    fn nested_object() {
+        assert_eq!(
+            inspect("({ a: 1, 'b-c': 'two', d: { e: { f: { g: 3 } } }, [Symbol('h')]: null })"),
+            "{ a: 1, 'b-c': 'two', d: { e: { f: [Object] } }, [Symbol(h)]: null }"
+        );
+        assert_eq!(inspect("({})"), "{}");
+        assert_eq!(
+            inspect("({ get a() { return 1; }, set b(v) {} })"),
+            "{ a: [Getter], b: [Setter] }"
+        );
+    }
+
+    #[test]
+    fn depth() {
+        let mut context = Context::new();
+        let value = forward_val(&mut context, "({ a: { b: { c: { d: [1] } } } })").unwrap();
+
+        assert_eq!(value.inspect().depth(0).to_string(), "{ a: [Object] }");
+        assert_eq!(
+            value.inspect().depth(usize::MAX).to_string(),
+            "{ a: { b: { c: { d: [ 1 ] } } } }"
+        );
+    }
+
+    #[test]
+    fn array() {
+        assert_eq!(inspect("[]"), "[]");
+        assert_eq!(
+            inspect("[1, 'a', [true, [null, [[]]]]]"),
+            "[ 1, 'a', [ true, [ null, [Array] ] ] ]"
+        );
+        assert_eq!(
+            inspect("let a = [1]; a[3] = 4; a.length = 5; a.x = 5; a"),
+            "[ 1, <2 empty items>, 4, <1 empty item>, x: 5 ]"
+        );
+    }
+
+    #[test]
+    fn function() {
+        assert_eq!(inspect("function foo() {}; foo"), "[Function: foo]");
+        assert_eq!(inspect("(function () {})"), "[Function (anonymous)]");
+        assert_eq!(
+            inspect("({ f: Math.max, g: function g() {} })"),
+            "{ f: [Function: max], g: [Function: g] }"
+        );
+    }
+
+    #[test]
+    fn cyclic() {
+        assert_eq!(
+            inspect("let a = { b: {} }; a.b.a = a; a.c = [a]; a"),
+            "{ b: { a: [Circular] }, c: [ [Circular] ] }"
+        );
+        assert_eq!(
+            inspect("let shared = {}; ({ x: shared, y: shared })"),
+            "{ x: {}, y: {} }"
+        );
+    }
+
+    #[test]
+    fn builtin_objects() {
+        assert_eq!(
+            inspect("new Map([['a', 1], [{}, []]])"),
+            "Map(2) { 'a' => 1, {} => [] }"
+        );
+        assert_eq!(inspect("new Set([1, 'b'])"), "Set(2) { 1, 'b' }");
+        assert_eq!(inspect("new Map()"), "Map(0)");
+        assert_eq!(inspect("new Number(3)"), "[Number: 3]");
+        assert_eq!(inspect("new String('s')"), "[String: 's']");
+        assert_eq!(inspect("new TypeError('bad')"), "[TypeError: bad]");
+    }
+}
+
 #[test]
 fn to_integer_or_infinity() {
     let mut context = Context::new();
@@ -635,6 +870,58 @@ fn to_primitive() {
     assert_eq!(forward(&mut context, "primitive"), "42");
 }
 
+#[test]
+fn to_primitive_hints() {
+    let mut context = Context::new();
+    let src = r#"
+    let hints = [];
+    let a = {
+        [Symbol.toPrimitive](hint) {
+            hints.push(hint);
+            switch (hint) {
+                case "number": return 42;
+                case "string": return "forty-two";
+                default: return "default";
+            }
+        }
+    };
+    "#;
+    context.eval(src).unwrap();
+    assert_eq!(forward(&mut context, "a * 2"), "84");
+    assert_eq!(forward(&mut context, "+a"), "42");
+    assert_eq!(forward(&mut context, "a > 41"), "true");
+    assert_eq!(forward(&mut context, "`${a}`"), "\"forty-two\"");
+    assert_eq!(forward(&mut context, "String(a)"), "\"forty-two\"");
+    assert_eq!(forward(&mut context, "a + 1"), "\"default1\"");
+    assert_eq!(forward(&mut context, "'x' + a"), "\"xdefault\"");
+    assert_eq!(forward(&mut context, "a + 'x'"), "\"defaultx\"");
+    assert_eq!(forward(&mut context, "a == 'default'"), "true");
+    assert_eq!(
+        forward(&mut context, "hints.join()"),
+        "\"number,number,number,string,string,default,default,default,default\""
+    );
+}
+
+#[test]
+fn to_primitive_returning_object_throws() {
+    let mut context = Context::new();
+    let src = r#"
+    let a = {
+        [Symbol.toPrimitive]() {
+            return {};
+        }
+    };
+    "#;
+    context.eval(src).unwrap();
+    assert_eq!(
+        forward(
+            &mut context,
+            "try { a + 1 } catch (e) { e instanceof TypeError }"
+        ),
+        "true"
+    );
+}
+
 /// Test cyclic conversions that previously caused stack overflows
 /// Relevant mitigations for these are in `GcObject::ordinary_to_primitive` and
 /// `GcObject::to_json`
@@ -1354,4 +1641,114 @@ mod abstract_relational_comparison {
         check_comparison!(context, "'-1' >= 2n" => false);
         check_comparison!(context, "'InvalidBigInt' >= -100n" => false);
     }
+
+    #[test]
+    fn strings_compare_lexicographically() {
+        let mut context = Context::new();
+        check_comparison!(context, "'10' < '9'" => true);
+        check_comparison!(context, "10 < 9" => false);
+        check_comparison!(context, "'10' < 9" => false);
+        check_comparison!(context, "'10' > '9'" => false);
+        check_comparison!(context, "'a' < 'B'" => false);
+        check_comparison!(context, "'' < 'a'" => true);
+        check_comparison!(context, "'' <= ''" => true);
+    }
+
+    #[test]
+    fn strings_compare_by_code_units() {
+        let mut context = Context::new();
+        check_comparison!(context, "'\u{1F600}' < '\u{FF61}'" => true);
+        check_comparison!(context, "'\u{FF61}' < '\u{1F600}'" => false);
+        check_comparison!(context, "'\u{D7FF}' < '\u{1F600}'" => true);
+    }
+
+    #[test]
+    fn nan_comparisons_are_false() {
+        let mut context = Context::new();
+        check_comparison!(context, "NaN < 1" => false);
+        check_comparison!(context, "NaN > 1" => false);
+        check_comparison!(context, "NaN <= NaN" => false);
+        check_comparison!(context, "NaN >= NaN" => false);
+        check_comparison!(context, "1 <= NaN" => false);
+        check_comparison!(context, "'a' < 1" => false);
+        check_comparison!(context, "'a' >= 1" => false);
+        check_comparison!(context, "undefined <= undefined" => false);
+    }
+
+    #[test]
+    fn bigint_compared_with_fractions() {
+        let mut context = Context::new();
+        check_comparison!(context, "-1n < -0.5" => true);
+        check_comparison!(context, "0n < -0.5" => false);
+        check_comparison!(context, "0.5 < 1n" => true);
+        check_comparison!(context, "1.5 < 1n" => false);
+        check_comparison!(context, "-1.5 > -1n" => false);
+        check_comparison!(context, "1n > 0.5" => true);
+    }
+}
+
+#[cfg(feature = "json")]
+mod json_conversions {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn round_trip() {
+        let mut context = Context::new();
+        let json = json!({
+            "name": "boa",
+            "version": 0.11,
+            "stable": false,
+            "license": null,
+            "tags": ["javascript", { "nested": [1, 2.5, []] }],
+        });
+
+        let value = Value::from_json(json.clone(), &mut context);
+        context.register_global_property("data", value, Attribute::all());
+        let copy = forward_val(&mut context, "JSON.parse(JSON.stringify(data))").unwrap();
+        assert_eq!(JSONValue::try_from(&copy), Ok(json));
+
+        let value = forward_val(
+            &mut context,
+            r#"
+            data.tags[1].nested.push({ sum: 1 + 2 });
+            data.skipped = undefined;
+            data.hole = new Array(2);
+            data.hole[1] = NaN;
+            data
+            "#,
+        )
+        .unwrap();
+        assert_eq!(
+            JSONValue::try_from(&value),
+            Ok(json!({
+                "name": "boa",
+                "version": 0.11,
+                "stable": false,
+                "license": null,
+                "tags": ["javascript", { "nested": [1, 2.5, [], { "sum": 3 }] }],
+                "hole": [null, null],
+            }))
+        );
+    }
+
+    #[test]
+    fn unrepresentable_values() {
+        let mut context = Context::new();
+        let mut convert = |src| JSONValue::try_from(&forward_val(&mut context, src).unwrap());
+
+        assert_eq!(convert("undefined"), Err(TryFromValueError::Undefined));
+        assert_eq!(convert("[function() {}]"), Err(TryFromValueError::Function));
+        assert_eq!(convert("({ a: Symbol() })"), Err(TryFromValueError::Symbol));
+        assert_eq!(convert("10n"), Err(TryFromValueError::BigInt));
+        assert_eq!(
+            convert("({ get a() { return 1; } })"),
+            Err(TryFromValueError::Accessor)
+        );
+        assert_eq!(
+            convert("let a = { b: {} }; a.b.a = a; a"),
+            Err(TryFromValueError::Cycle)
+        );
+        assert_eq!(convert("let b = {}; [b, b]"), Ok(json!([{}, {}])));
+    }
 }
@@ -1,5 +1,8 @@
 #[derive(Debug)]
 pub enum Instruction {
+    /// Pops a value off the stack and discards it.
+    Pop,
+
     Undefined,
     Null,
     True,
@@ -78,6 +81,7 @@ pub enum Instruction {
 impl std::fmt::Display for Instruction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match *self {
+            Self::Pop => write!(f, "Pop"),
             Self::Undefined => write!(f, "Undefined"),
             Self::Null => write!(f, "Null"),
             Self::True => write!(f, "True"),
@@ -84,6 +84,7 @@ impl<'a> VM<'a> {
 
             let _timer =
                 BoaProfiler::global().start_event(&self.instructions[self.idx].to_string(), "vm");
+            self.ctx.count_instruction()?;
 
             macro_rules! bin_op {
                 ($op:ident) => {{
@@ -94,6 +95,10 @@ impl<'a> VM<'a> {
                 }};
             }
             let result = match self.instructions[self.idx] {
+                Instruction::Pop => {
+                    self.pop();
+                    None
+                }
                 Instruction::Undefined => Some(Value::undefined()),
                 Instruction::Null => Some(Value::null()),
                 Instruction::True => Some(Value::boolean(true)),
@@ -183,20 +188,12 @@ impl<'a> VM<'a> {
                         ));
                     }
                     let key = l.to_property_key(self.ctx)?;
-                    Some(self.ctx.has_property(&r, &key).into())
+                    Some(self.ctx.has_property(&r, &key)?.into())
                 }
                 Instruction::InstanceOf => {
                     let r = self.pop();
-                    let _l = self.pop();
-                    if !r.is_object() {
-                        return self.ctx.throw_type_error(format!(
-                            "right-hand side of 'instanceof' should be an object, got {}",
-                            r.get_type().as_str()
-                        ));
-                    }
-
-                    // spec: https://tc39.es/ecma262/#sec-instanceofoperator
-                    todo!("instanceof operator")
+                    let l = self.pop();
+                    Some(l.instance_of(&r, self.ctx)?.into())
                 }
                 Instruction::Void => {
                     let _value = self.pop();
@@ -27,3 +27,12 @@ fn basic_op() {
     "#;
     assert_eq!(&exec(basic_op), "3");
 }
+
+#[test]
+fn comma_operator() {
+    let comma_operator = r#"
+        const a = 1;
+        (a + 1, a + 2, a + 3)
+    "#;
+    assert_eq!(&exec(comma_operator), "4");
+}
@@ -28,7 +28,7 @@
 use boa::{syntax::ast::node::StatementList, Context};
 use colored::*;
 use rustyline::{config::Config, error::ReadlineError, EditMode, Editor};
-use std::{fs::read, path::PathBuf};
+use std::{fs::read, path::PathBuf, thread};
 use structopt::{clap::arg_enum, StructOpt};
 
 mod helper;
@@ -45,6 +45,12 @@ static CLI_HISTORY: &str = ".boa_history";
 
 const READLINE_COLOR: Color = Color::Cyan;
 
+/// The size of the stack of the thread running the scripts.
+///
+/// It is big enough for the default maximum call stack size of the context, even with a
+/// debug build.
+const STACK_SIZE: usize = 32 * 1024 * 1024;
+
 // Added #[allow(clippy::option_option)] because to StructOpt an Option<Option<T>>
 // is an optional argument that optionally takes a value ([--opt=[val]]).
 // https://docs.rs/structopt/0.3.11/structopt/#type-magic
@@ -146,6 +152,16 @@ fn dump<T: AsRef<[u8]>>(src: T, args: &Opt) -> Result<(), String> {
 pub fn main() -> Result<(), std::io::Error> {
     let args = Opt::from_args();
 
+    thread::Builder::new()
+        .name("boa".into())
+        .stack_size(STACK_SIZE)
+        .spawn(move || run(args))?
+        .join()
+        .expect("the script thread panicked")
+}
+
+/// Evaluates the files, or runs the REPL if there are none.
+fn run(args: Opt) -> Result<(), std::io::Error> {
     let mut context = Context::new();
 
     // Trace Output
@@ -7,6 +7,8 @@ use boa::{
 
 /// Initializes the object in the context.
 pub(super) fn init(context: &mut Context) -> GcObject {
+    // The global object is handed to the tests, which can inspect the lazy builtins through it.
+    context.init_lazy_builtins();
     let global_obj = context.global_object();
 
     let obj = ObjectInitializer::new(context)